@@ -0,0 +1,54 @@
+use std::{any::TypeId, time::Duration};
+
+use bevy_reflect::{ReflectFromPtr, TypeRegistry};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+criterion_group!(benches, type_registry_get, type_registry_get_type_data);
+criterion_main!(benches);
+
+const WARM_UP_TIME: Duration = Duration::from_millis(500);
+const MEASUREMENT_TIME: Duration = Duration::from_secs(4);
+
+/// Returns the ids of all the types registered by default, along with a few which are not.
+fn type_ids(registry: &TypeRegistry) -> Vec<TypeId> {
+    let mut type_ids: Vec<_> = registry
+        .iter()
+        .map(|registration| registration.type_id())
+        .collect();
+    type_ids.extend([TypeId::of::<()>(), TypeId::of::<Duration>()]);
+    type_ids
+}
+
+fn type_registry_get(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("type_registry_get");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+
+    let registry = TypeRegistry::new();
+    let type_ids = type_ids(&registry);
+    group.throughput(Throughput::Elements(type_ids.len() as u64));
+    group.bench_function("get", |bencher| {
+        bencher.iter(|| {
+            for type_id in &type_ids {
+                black_box(registry.get(black_box(*type_id)));
+            }
+        });
+    });
+}
+
+fn type_registry_get_type_data(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("type_registry_get_type_data");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+
+    let registry = TypeRegistry::new();
+    let type_ids = type_ids(&registry);
+    group.throughput(Throughput::Elements(type_ids.len() as u64));
+    group.bench_function("get_type_data", |bencher| {
+        bencher.iter(|| {
+            for type_id in &type_ids {
+                black_box(registry.get_type_data::<ReflectFromPtr>(black_box(*type_id)));
+            }
+        });
+    });
+}
@@ -104,6 +104,8 @@ impl HandleId {
 ///
 #[derive(Component, Reflect, FromReflect)]
 #[reflect(Component, Default)]
+// Assets are not required to implement `TypePath`, which the derived `TypePath` would need
+#[reflect(no_type_path)]
 pub struct Handle<T>
 where
     T: Asset,
@@ -5,22 +5,41 @@
 //! the derive helper attribute for `Reflect`, which looks like:
 //! `#[reflect(PartialEq, Default, ...)]` and `#[reflect_value(PartialEq, Default, ...)]`.
 
-use crate::fq_std::{FQAny, FQDefault, FQOption};
+use crate::fq_std::{FQAny, FQDefault, FQOption, FQResult};
 use crate::utility;
 use proc_macro2::{Ident, Span};
-use quote::quote_spanned;
+use quote::{quote, quote_spanned};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Meta, NestedMeta, Path};
+use syn::{Lit, Meta, NestedMeta, Path, Type};
 
 // The "special" trait idents that are used internally for reflection.
 // Received via attributes like `#[reflect(PartialEq, Hash, ...)]`
+const CLONE_ATTR: &str = "Clone";
 const DEBUG_ATTR: &str = "Debug";
 const PARTIAL_EQ_ATTR: &str = "PartialEq";
+const PARTIAL_ORD_ATTR: &str = "PartialOrd";
 const HASH_ATTR: &str = "Hash";
 
+// Received via the attribute `#[reflect(serde_compat)]`
+const SERDE_COMPAT_ATTR: &str = "serde_compat";
+
+// Received via the attribute `#[reflect(no_type_info)]`
+const NO_TYPE_INFO_ATTR: &str = "no_type_info";
+
+// Received via the attribute `#[reflect(drain)]`
+const DRAIN_ATTR: &str = "drain";
+
+// Received via the attribute `#[reflect(no_type_path)]`
+const NO_TYPE_PATH_ATTR: &str = "no_type_path";
+
+// The attributes used to reflect a type through an intermediate representation.
+// Received via attributes like `#[reflect(from = "Repr", into = "Repr")]`
+const FROM_ATTR: &str = "from";
+const INTO_ATTR: &str = "into";
+
 // The traits listed below are not considered "special" (i.e. they use the `ReflectMyTrait` syntax)
 // but useful to know exist nonetheless
 pub(crate) const REFLECT_DEFAULT: &str = "ReflectDefault";
@@ -66,9 +85,11 @@ impl TraitImpl {
 /// `Reflect` derive macro using the helper attribute: `#[reflect(...)]`.
 ///
 /// The list of special traits are as follows:
+/// * `Clone`
 /// * `Debug`
 /// * `Hash`
 /// * `PartialEq`
+/// * `PartialOrd`
 ///
 /// When registering a trait, there are a few things to keep in mind:
 /// * Traits must have a valid `Reflect{}` struct in scope. For example, `Default`
@@ -119,11 +140,33 @@ impl TraitImpl {
 ///
 /// > __Note:__ Registering a custom function only works for special traits.
 ///
+/// Additionally, a type may be reflected through an intermediate representation
+/// using `#[reflect(from = "Repr")]` and/or `#[reflect(into = "Repr")]`.
+/// See [`ReflectTraits::get_from_repr`] and [`ReflectTraits::get_into_repr`] for details.
+///
+/// The `TypeInfo` of a type can also be left out using `#[reflect(no_type_info)]`,
+/// to cut down on binary size. See [`ReflectTraits::no_type_info`] for details.
+///
+/// Owned field accessors which move the fields out, rather than cloning them, can be
+/// generated using `#[reflect(drain)]`. See [`ReflectTraits::drain`] for details.
+///
+/// Types whose `TypePath` is implemented elsewhere, such as with `impl_type_path!`,
+/// must opt out of the derived one using `#[reflect(no_type_path)]`.
+/// See [`ReflectTraits::no_type_path`] for details.
+///
 #[derive(Default, Clone)]
 pub(crate) struct ReflectTraits {
+    clone: TraitImpl,
     debug: TraitImpl,
     hash: TraitImpl,
     partial_eq: TraitImpl,
+    partial_ord: TraitImpl,
+    from_repr: Option<Type>,
+    into_repr: Option<Type>,
+    serde_compat: bool,
+    no_type_info: bool,
+    drain: bool,
+    no_type_path: bool,
     idents: Vec<Ident>,
 }
 
@@ -148,6 +191,9 @@ impl ReflectTraits {
                     let span = ident.span();
 
                     match ident_name.as_str() {
+                        CLONE_ATTR => {
+                            traits.clone = traits.clone.merge(TraitImpl::Implemented(span))?;
+                        }
                         DEBUG_ATTR => {
                             traits.debug = traits.debug.merge(TraitImpl::Implemented(span))?;
                         }
@@ -155,9 +201,25 @@ impl ReflectTraits {
                             traits.partial_eq =
                                 traits.partial_eq.merge(TraitImpl::Implemented(span))?;
                         }
+                        PARTIAL_ORD_ATTR => {
+                            traits.partial_ord =
+                                traits.partial_ord.merge(TraitImpl::Implemented(span))?;
+                        }
                         HASH_ATTR => {
                             traits.hash = traits.hash.merge(TraitImpl::Implemented(span))?;
                         }
+                        SERDE_COMPAT_ATTR => {
+                            traits.serde_compat = true;
+                        }
+                        NO_TYPE_INFO_ATTR => {
+                            traits.no_type_info = true;
+                        }
+                        DRAIN_ATTR => {
+                            traits.drain = true;
+                        }
+                        NO_TYPE_PATH_ATTR => {
+                            traits.no_type_path = true;
+                        }
                         // We only track reflected idents for traits not considered special
                         _ => {
                             // Create the reflect ident
@@ -186,12 +248,18 @@ impl ReflectTraits {
                         // This should be the path of the custom function
                         let trait_func_ident = TraitImpl::Custom(path.clone(), span);
                         match ident.as_str() {
+                            CLONE_ATTR => {
+                                traits.clone = traits.clone.merge(trait_func_ident)?;
+                            }
                             DEBUG_ATTR => {
                                 traits.debug = traits.debug.merge(trait_func_ident)?;
                             }
                             PARTIAL_EQ_ATTR => {
                                 traits.partial_eq = traits.partial_eq.merge(trait_func_ident)?;
                             }
+                            PARTIAL_ORD_ATTR => {
+                                traits.partial_ord = traits.partial_ord.merge(trait_func_ident)?;
+                            }
                             HASH_ATTR => {
                                 traits.hash = traits.hash.merge(trait_func_ident)?;
                             }
@@ -199,6 +267,25 @@ impl ReflectTraits {
                         }
                     }
                 }
+                // Handles `#[reflect( from = "Repr", into = "Repr" )]`
+                NestedMeta::Meta(Meta::NameValue(pair)) => {
+                    let slot = if pair.path.is_ident(FROM_ATTR) {
+                        &mut traits.from_repr
+                    } else if pair.path.is_ident(INTO_ATTR) {
+                        &mut traits.into_repr
+                    } else {
+                        continue;
+                    };
+
+                    let Lit::Str(lit) = &pair.lit else {
+                        return Err(syn::Error::new(
+                            pair.lit.span(),
+                            "expected a string literal containing the name of a type",
+                        ));
+                    };
+
+                    *slot = merge_repr(slot.take(), Some(lit.parse()?))?;
+                }
                 _ => {}
             }
         }
@@ -217,6 +304,97 @@ impl ReflectTraits {
         &self.idents
     }
 
+    /// Whether `#[reflect(serde_compat)]` was given, in which case the `rename`, `alias`, `skip`
+    /// and `default` serde attributes on fields and variants are mirrored by reflection.
+    pub fn serde_compat(&self) -> bool {
+        self.serde_compat
+    }
+
+    /// Whether `#[reflect(no_type_info)]` was given, in which case the type reports a bare
+    /// `ValueInfo`, without any docs, instead of the `TypeInfo` describing its fields or variants.
+    pub fn no_type_info(&self) -> bool {
+        self.no_type_info
+    }
+
+    /// Whether `#[reflect(drain)]` was given, in which case methods taking ownership of
+    /// the fields, such as `TupleStruct::drain`, move them out instead of cloning them.
+    ///
+    /// This is not allowed for types implementing `Drop`, whose fields cannot be moved out.
+    pub fn drain(&self) -> bool {
+        self.drain
+    }
+
+    /// Whether `#[reflect(no_type_path)]` was given, in which case `TypePath` is not
+    /// implemented for the type, and `Reflect::get_type_path` returns `None`.
+    ///
+    /// This allows the type's path to be given with `impl_type_path!` instead.
+    pub fn no_type_path(&self) -> bool {
+        self.no_type_path
+    }
+
+    /// The type this type can be created from, given by `#[reflect(from = "Repr")]`.
+    ///
+    /// Types with a `from` representation are reflected as values, and accept either
+    /// themselves or anything the representation can be created from (via `FromReflect`)
+    /// when applied to. The conversion uses `TryFrom<Repr>`, so `From<Repr>` suffices.
+    pub fn get_from_repr(&self) -> Option<&Type> {
+        self.from_repr.as_ref()
+    }
+
+    /// The type this type can be converted into, given by `#[reflect(into = "Repr")]`.
+    ///
+    /// Types with an `into` representation are reflected as values, and register
+    /// `ReflectRepr` type data which converts them into the representation (via `Into<Repr>`).
+    pub fn get_into_repr(&self) -> Option<&Type> {
+        self.into_repr.as_ref()
+    }
+
+    /// Returns an expression converting `value`, a `&dyn Reflect`, into an `Option<Self>`
+    /// through the `from` representation.
+    ///
+    /// If no `from` representation was given, returns `None`.
+    pub fn get_from_repr_conversion(
+        &self,
+        bevy_reflect_path: &Path,
+        value: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.from_repr.as_ref().map(|repr| {
+            quote! {
+                #FQOption::and_then(
+                    <#repr as #bevy_reflect_path::FromReflect>::from_reflect(#value),
+                    |repr| #FQResult::ok(<Self as ::core::convert::TryFrom<#repr>>::try_from(repr)),
+                )
+            }
+        })
+    }
+
+    /// Returns the statement registering `ReflectRepr` as a `TokenStream`.
+    ///
+    /// If neither a `from` nor an `into` representation was given, returns `None`.
+    pub fn get_repr_registration(
+        &self,
+        bevy_reflect_path: &Path,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.from_repr.is_none() && self.into_repr.is_none() {
+            return None;
+        }
+
+        let with_from = self
+            .from_repr
+            .as_ref()
+            .map(|repr| quote!(.with_from::<Self, #repr>()));
+        let with_into = self
+            .into_repr
+            .as_ref()
+            .map(|repr| quote!(.with_into::<Self, #repr>()));
+
+        Some(quote! {
+            registration.insert::<#bevy_reflect_path::ReflectRepr>(
+                #bevy_reflect_path::ReflectRepr::default() #with_from #with_into
+            );
+        })
+    }
+
     /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
@@ -267,6 +445,60 @@ impl ReflectTraits {
         }
     }
 
+    /// Returns the implementation of `Reflect::reflect_partial_cmp` as a `TokenStream`.
+    ///
+    /// If `PartialOrd` was not registered, returns `None`.
+    pub fn get_partial_ord_impl(
+        &self,
+        bevy_reflect_path: &Path,
+    ) -> Option<proc_macro2::TokenStream> {
+        match &self.partial_ord {
+            &TraitImpl::Implemented(span) => Some(quote_spanned! {span=>
+                fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering> {
+                    let value = <dyn #bevy_reflect_path::Reflect>::as_any(value);
+                    if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(value) {
+                        ::core::cmp::PartialOrd::partial_cmp(self, value)
+                    } else {
+                        #FQOption::None
+                    }
+                }
+            }),
+            &TraitImpl::Custom(ref impl_fn, span) => Some(quote_spanned! {span=>
+                fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering> {
+                    #impl_fn(self, value)
+                }
+            }),
+            TraitImpl::NotImplemented => None,
+        }
+    }
+
+    /// Returns the statements starting `Reflect::apply`, which assign `value`, a `&dyn Reflect`,
+    /// to `self` and return early if it is of the same concrete type as `self`.
+    ///
+    /// The assignment uses `Clone::clone_from`, or the custom function given with
+    /// `#[reflect(Clone(clone_from_fn))]`, taking `&mut Self` and `&Self`.
+    ///
+    /// If `Clone` was not registered, returns `None`.
+    pub fn get_apply_fast_path(
+        &self,
+        bevy_reflect_path: &Path,
+        value: &proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        let (clone_from, span) = match &self.clone {
+            &TraitImpl::Implemented(span) => (quote!(::core::clone::Clone::clone_from), span),
+            &TraitImpl::Custom(ref impl_fn, span) => (quote!(#impl_fn), span),
+            TraitImpl::NotImplemented => return None,
+        };
+        Some(quote_spanned! {span=>
+            if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(
+                <dyn #bevy_reflect_path::Reflect>::as_any(#value),
+            ) {
+                #clone_from(self, value);
+                return;
+            }
+        })
+    }
+
     /// Returns the implementation of `Reflect::debug` as a `TokenStream`.
     ///
     /// If `Debug` was not registered, returns `None`.
@@ -291,9 +523,17 @@ impl ReflectTraits {
     /// An error is returned if the two [`ReflectTraits`] have conflicting implementations.
     pub fn merge(self, other: ReflectTraits) -> Result<Self, syn::Error> {
         Ok(ReflectTraits {
+            clone: self.clone.merge(other.clone)?,
             debug: self.debug.merge(other.debug)?,
             hash: self.hash.merge(other.hash)?,
             partial_eq: self.partial_eq.merge(other.partial_eq)?,
+            partial_ord: self.partial_ord.merge(other.partial_ord)?,
+            from_repr: merge_repr(self.from_repr, other.from_repr)?,
+            into_repr: merge_repr(self.into_repr, other.into_repr)?,
+            serde_compat: self.serde_compat || other.serde_compat,
+            no_type_info: self.no_type_info || other.no_type_info,
+            drain: self.drain || other.drain,
+            no_type_path: self.no_type_path || other.no_type_path,
             idents: {
                 let mut idents = self.idents;
                 for ident in other.idents {
@@ -312,6 +552,19 @@ impl Parse for ReflectTraits {
     }
 }
 
+/// Merges two optional representation types.
+///
+/// Returns an error if both representations are present.
+fn merge_repr(repr: Option<Type>, other: Option<Type>) -> Result<Option<Type>, syn::Error> {
+    match (repr, other) {
+        (Some(_), Some(other)) => Err(syn::Error::new(
+            other.span(),
+            "conflicting representation types",
+        )),
+        (repr, other) => Ok(repr.or(other)),
+    }
+}
+
 /// Adds an identifier to a vector of identifiers if it is not already present.
 ///
 /// Returns an error if the identifier already exists in the list.
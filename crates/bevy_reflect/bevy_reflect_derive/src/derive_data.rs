@@ -1,13 +1,15 @@
 use crate::container_attributes::ReflectTraits;
-use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr};
+use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr, ReflectIgnoreBehavior};
+use crate::fq_std::{FQBox, FQDefault, FQOption, FQResult};
 use crate::utility::members_to_serialization_denylist;
 use bit_set::BitSet;
 use quote::quote;
 
 use crate::{utility, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME};
+use std::borrow::Cow;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Field, Fields, Generics, Ident, Meta, Path, Token, Variant};
+use syn::{Data, DeriveInput, Field, Fields, Generics, Ident, Member, Meta, Path, Token, Variant};
 
 pub(crate) enum ReflectDerive<'a> {
     Struct(ReflectStruct<'a>),
@@ -36,9 +38,13 @@ pub(crate) struct ReflectMeta<'a> {
     /// The name of this type.
     type_name: &'a Ident,
     /// The generics defined on this type.
-    generics: &'a Generics,
+    ///
+    /// These are bounded by `TypePath` if it is implemented for the type.
+    generics: Cow<'a, Generics>,
     /// A cached instance of the path to the `bevy_reflect` crate.
     bevy_reflect_path: Path,
+    /// Whether the input is the type's own definition, rather than one mirroring a foreign type.
+    is_definition: bool,
     /// The documentation for this type, if any
     #[cfg(feature = "documentation")]
     docs: crate::documentation::Documentation,
@@ -79,6 +85,8 @@ pub(crate) struct ReflectStruct<'a> {
 pub(crate) struct ReflectEnum<'a> {
     meta: ReflectMeta<'a>,
     variants: Vec<EnumVariant<'a>>,
+    /// Whether the enum has a primitive or `C` representation.
+    has_discriminant_repr: bool,
 }
 
 /// Represents a field on a struct or tuple struct.
@@ -127,7 +135,12 @@ enum ReflectMode {
 }
 
 impl<'a> ReflectDerive<'a> {
-    pub fn from_input(input: &'a DeriveInput) -> Result<Self, syn::Error> {
+    /// Parses the input of `#[derive(Reflect)]` (or a macro mirroring a type definition).
+    ///
+    /// `is_definition` should only be set if the input is the type's own definition,
+    /// since the `TypePath` implementation uses the module it is expanded in,
+    /// and the field offsets are computed from the fields of the input.
+    pub fn from_input(input: &'a DeriveInput, is_definition: bool) -> Result<Self, syn::Error> {
         let mut traits = ReflectTraits::default();
         // Should indicate whether `#[reflect_value]` was used
         let mut reflect_mode = None;
@@ -181,7 +194,8 @@ impl<'a> ReflectDerive<'a> {
             }
         }
 
-        let meta = ReflectMeta::new(&input.ident, &input.generics, traits);
+        let meta =
+            ReflectMeta::new(&input.ident, &input.generics, traits).with_definition(is_definition);
 
         #[cfg(feature = "documentation")]
         let meta = meta.with_docs(doc);
@@ -189,13 +203,26 @@ impl<'a> ReflectDerive<'a> {
         // Use normal reflection if unspecified
         let reflect_mode = reflect_mode.unwrap_or(ReflectMode::Normal);
 
-        if reflect_mode == ReflectMode::Value {
+        // Types reflected through a representation type are opaque to the reflection API
+        let has_repr =
+            meta.traits().get_from_repr().is_some() || meta.traits().get_into_repr().is_some();
+
+        if reflect_mode == ReflectMode::Value || has_repr {
             return Ok(Self::Value(meta));
         }
 
+        let serde_compat = meta.traits().serde_compat();
+
         return match &input.data {
             Data::Struct(data) => {
-                let fields = Self::collect_struct_fields(&data.fields)?;
+                let fields = Self::collect_struct_fields(&data.fields, serde_compat)?;
+                let converted = fields.iter().find(|field| field.attrs.convert.is_some());
+                if let (Fields::Unnamed(..), Some(field)) = (&data.fields, converted) {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        "converted fields are only supported on structs with named fields",
+                    ));
+                }
                 let reflect_struct = ReflectStruct {
                     meta,
                     serialization_denylist: members_to_serialization_denylist(
@@ -211,9 +238,13 @@ impl<'a> ReflectDerive<'a> {
                 }
             }
             Data::Enum(data) => {
-                let variants = Self::collect_enum_variants(&data.variants)?;
+                let variants = Self::collect_enum_variants(&data.variants, serde_compat)?;
 
-                let reflect_enum = ReflectEnum { meta, variants };
+                let reflect_enum = ReflectEnum {
+                    meta,
+                    variants,
+                    has_discriminant_repr: utility::has_discriminant_repr(&input.attrs),
+                };
                 Ok(Self::Enum(reflect_enum))
             }
             Data::Union(..) => Err(syn::Error::new(
@@ -223,12 +254,15 @@ impl<'a> ReflectDerive<'a> {
         };
     }
 
-    fn collect_struct_fields(fields: &'a Fields) -> Result<Vec<StructField<'a>>, syn::Error> {
+    fn collect_struct_fields(
+        fields: &'a Fields,
+        serde_compat: bool,
+    ) -> Result<Vec<StructField<'a>>, syn::Error> {
         let sifter: utility::ResultSifter<StructField<'a>> = fields
             .iter()
             .enumerate()
             .map(|(index, field)| -> Result<StructField, syn::Error> {
-                let attrs = parse_field_attrs(&field.attrs)?;
+                let attrs = parse_field_attrs(&field.attrs, serde_compat)?;
                 Ok(StructField {
                     index,
                     attrs,
@@ -247,12 +281,45 @@ impl<'a> ReflectDerive<'a> {
 
     fn collect_enum_variants(
         variants: &'a Punctuated<Variant, Token![,]>,
+        serde_compat: bool,
     ) -> Result<Vec<EnumVariant<'a>>, syn::Error> {
         let sifter: utility::ResultSifter<EnumVariant<'a>> = variants
             .iter()
             .enumerate()
             .map(|(index, variant)| -> Result<EnumVariant, syn::Error> {
-                let fields = Self::collect_struct_fields(&variant.fields)?;
+                let fields = Self::collect_struct_fields(&variant.fields, serde_compat)?;
+                if let Some(field) = fields.iter().find(|field| field.attrs.has_accessors()) {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        "getters and setters are not supported on enum variant fields",
+                    ));
+                }
+                if let Some(field) = fields.iter().find(|field| field.attrs.range.is_some()) {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        "ranges are not supported on enum variant fields",
+                    ));
+                }
+                if let Some(field) = fields.iter().find(|field| field.attrs.validate.is_some()) {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        "validators are not supported on enum variant fields",
+                    ));
+                }
+                if let Some(field) = fields.iter().find(|field| field.attrs.convert.is_some()) {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        "converted fields are not supported on enum variant fields",
+                    ));
+                }
+
+                let attrs = parse_field_attrs(&variant.attrs, serde_compat)?;
+                if let Some(attribute) = attrs.custom_attributes.first() {
+                    return Err(syn::Error::new(
+                        attribute.span(),
+                        "custom attributes are not supported on enum variants",
+                    ));
+                }
 
                 let fields = match variant.fields {
                     Fields::Named(..) => EnumVariantFields::Named(fields),
@@ -261,7 +328,7 @@ impl<'a> ReflectDerive<'a> {
                 };
                 Ok(EnumVariant {
                     fields,
-                    attrs: parse_field_attrs(&variant.attrs)?,
+                    attrs,
                     data: variant,
                     index,
                     #[cfg(feature = "documentation")]
@@ -282,13 +349,38 @@ impl<'a> ReflectMeta<'a> {
         Self {
             traits,
             type_name,
-            generics,
+            generics: Cow::Borrowed(generics),
             bevy_reflect_path: utility::get_bevy_reflect_path(),
+            is_definition: false,
             #[cfg(feature = "documentation")]
             docs: Default::default(),
         }
     }
 
+    /// Sets whether the input is the type's own definition.
+    ///
+    /// As the `TypePath` of a generic type is built from the paths of its type parameters,
+    /// they are then bounded by `TypePath` in every generated impl.
+    pub fn with_definition(self, is_definition: bool) -> Self {
+        let mut meta = Self {
+            is_definition,
+            ..self
+        };
+        if meta.impl_type_path() && meta.generics.type_params().next().is_some() {
+            let bevy_reflect_path = &meta.bevy_reflect_path;
+            let generics = meta.generics.to_mut();
+            let bounds = generics
+                .type_params()
+                .map(|param| {
+                    let ident = &param.ident;
+                    syn::parse_quote!(#ident: #bevy_reflect_path::TypePath)
+                })
+                .collect::<Vec<syn::WherePredicate>>();
+            generics.make_where_clause().predicates.extend(bounds);
+        }
+        meta
+    }
+
     /// Sets the documentation for this type.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: crate::documentation::Documentation) -> Self {
@@ -306,8 +398,8 @@ impl<'a> ReflectMeta<'a> {
     }
 
     /// The generics associated with this struct.
-    pub fn generics(&self) -> &'a Generics {
-        self.generics
+    pub fn generics(&self) -> &Generics {
+        &self.generics
     }
 
     /// The cached `bevy_reflect` path.
@@ -315,14 +407,31 @@ impl<'a> ReflectMeta<'a> {
         &self.bevy_reflect_path
     }
 
+    /// Whether the input is the type's own definition, rather than one mirroring a foreign type.
+    pub fn is_definition(&self) -> bool {
+        self.is_definition
+    }
+
+    /// Whether `TypePath` should be implemented for this type.
+    ///
+    /// This is only the case for types which are defined by the input,
+    /// and have not opted out with `#[reflect(no_type_path)]`.
+    pub fn impl_type_path(&self) -> bool {
+        self.is_definition && !self.traits.no_type_path()
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     pub fn get_type_registration(&self) -> proc_macro2::TokenStream {
         crate::registration::impl_get_type_registration(
             self.type_name,
             &self.bevy_reflect_path,
             self.traits.idents(),
-            self.generics,
+            &self.generics,
             None,
+            proc_macro2::TokenStream::new(),
+            self.traits
+                .get_repr_registration(&self.bevy_reflect_path)
+                .unwrap_or_default(),
         )
     }
 
@@ -333,6 +442,191 @@ impl<'a> ReflectMeta<'a> {
     }
 }
 
+impl<'a> StructField<'a> {
+    /// Returns an expression which converts `member`, a reference to this field,
+    /// into the reference that should be exposed to the reflection API.
+    ///
+    /// This is `member` itself unless the field uses `#[reflect(with = "...")]`,
+    /// or is a `Box<dyn Reflect>` marked with `#[reflect(dynamic)]`.
+    pub fn reflect_ref(&self, member: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match &self.attrs.with {
+            Some(module) => quote!(#module::as_reflect(#member)),
+            None if self.attrs.dynamic => quote!(&**#member),
+            None => member,
+        }
+    }
+
+    /// The mutable equivalent of [`StructField::reflect_ref`].
+    pub fn reflect_mut(&self, member: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match &self.attrs.with {
+            Some(module) => quote!(#module::as_reflect_mut(#member)),
+            None if self.attrs.dynamic => quote!(&mut **#member),
+            None => member,
+        }
+    }
+
+    /// Returns an expression for the reference to this field of `self` exposed to the
+    /// reflection API, going through its getter if it has one.
+    pub fn reflect_field_ref(&self, member: &Member) -> proc_macro2::TokenStream {
+        match &self.attrs.get {
+            Some(getter) => self.reflect_ref(quote!(#getter(self))),
+            None => self.reflect_ref(quote!(&self.#member)),
+        }
+    }
+
+    /// Returns an expression converting `value`, the owned value of this field,
+    /// into the `Box<dyn Reflect>` exposed to the reflection API.
+    ///
+    /// Fields reflected through a `with` module are cloned, as the module only lends them out.
+    pub fn owned_reflect(
+        &self,
+        bevy_reflect_path: &Path,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.attrs.with {
+            Some(module) => quote! {
+                #bevy_reflect_path::Reflect::clone_value(#module::as_reflect(&#value))
+            },
+            None if self.attrs.dynamic => value,
+            None => {
+                let fq_box = FQBox(bevy_reflect_path);
+                quote!(#fq_box::new(#value))
+            }
+        }
+    }
+
+    /// Returns an expression for the mutable reference to this field of `self` exposed to the
+    /// reflection API, or `None` if the field is only accessible through a getter or setter.
+    pub fn reflect_field_mut(&self, member: &Member) -> Option<proc_macro2::TokenStream> {
+        (!self.attrs.has_accessors()).then(|| self.reflect_mut(quote!(&mut self.#member)))
+    }
+
+    /// Returns a statement which sets this field of `this` to `value`, a `&dyn Reflect`,
+    /// if this field has a setter.
+    ///
+    /// The value is converted with `FromReflect` (or the `with` module), panicking on failure.
+    pub fn apply_with_setter(
+        &self,
+        bevy_reflect_path: &Path,
+        this: proc_macro2::TokenStream,
+        value: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        let setter = self.attrs.set.as_ref()?;
+        let construct = self.construct_from_reflect(bevy_reflect_path, quote!(#value));
+        Some(quote! {
+            match #construct {
+                #FQOption::Some(__value) => #setter(#this, __value),
+                #FQOption::None => panic!(
+                    "Attempted to apply a value of type `{}` to a field with a setter, but it could not be converted",
+                    #bevy_reflect_path::Reflect::type_name(#value)
+                ),
+            }
+        })
+    }
+
+    /// Returns an expression which creates an `Option` of this field's type from `value`,
+    /// a `&dyn Reflect`.
+    pub fn construct_from_reflect(
+        &self,
+        bevy_reflect_path: &Path,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.attrs.with {
+            Some(module) => quote!(#module::from_reflect(#value)),
+            None if self.attrs.dynamic => quote! {
+                #bevy_reflect_path::__macro_exports::dynamic_field_from_reflect(#value)
+            },
+            None => {
+                let ty = &self.data.ty;
+                quote!(<#ty as #bevy_reflect_path::FromReflect>::from_reflect(#value))
+            }
+        }
+    }
+
+    /// Returns an expression which creates the `NamedField` (or `UnnamedField` if `name` is an index)
+    /// describing this field.
+    ///
+    /// Fields using `#[reflect(with = "...")]` are described by the type they are reflected as,
+    /// and dynamic fields are described as `dyn Reflect`.
+    pub fn info(
+        &self,
+        bevy_reflect_path: &Path,
+        name: proc_macro2::TokenStream,
+        is_named: bool,
+    ) -> proc_macro2::TokenStream {
+        let info = match (&self.attrs.with, is_named) {
+            (Some(module), true) => quote! {
+                #bevy_reflect_path::__macro_exports::projected_named_field(#name, #module::as_reflect)
+            },
+            (Some(module), false) => quote! {
+                #bevy_reflect_path::__macro_exports::projected_unnamed_field(#name, #module::as_reflect)
+            },
+            (None, true) if self.attrs.dynamic => {
+                quote!(#bevy_reflect_path::NamedField::new::<dyn #bevy_reflect_path::Reflect>(#name))
+            }
+            (None, false) if self.attrs.dynamic => {
+                quote!(#bevy_reflect_path::UnnamedField::new::<dyn #bevy_reflect_path::Reflect>(#name))
+            }
+            (None, true) => {
+                let ty = &self.data.ty;
+                quote!(#bevy_reflect_path::NamedField::new::<#ty>(#name))
+            }
+            (None, false) => {
+                let ty = &self.data.ty;
+                quote!(#bevy_reflect_path::UnnamedField::new::<#ty>(#name))
+            }
+        };
+
+        let custom_attributes = &self.attrs.custom_attributes;
+        if custom_attributes.is_empty() {
+            return info;
+        }
+        // The attributes are kept in their own static, as a reference to them could not
+        // otherwise be promoted to `'static` when they are built by calling a `const fn`
+        let count = custom_attributes.len();
+        quote! {
+            #info.with_attributes({
+                static ATTRIBUTES: [&(dyn ::core::any::Any + ::core::marker::Send + ::core::marker::Sync); #count] =
+                    [#(&#custom_attributes),*];
+                #bevy_reflect_path::attributes::CustomAttributes::new(&ATTRIBUTES)
+            })
+        }
+    }
+
+    /// Returns the builder calls recording the offset of this field in `container`,
+    /// the struct it is declared in, and the layout of its type, to be appended to its [info].
+    ///
+    /// Returns `None` for fields using `#[reflect(with = "...")]` or `#[reflect(dynamic)]`,
+    /// as they are not described by the type they are stored as.
+    ///
+    /// The offset is computed without `core::mem::offset_of!`, which is newer than the
+    /// supported Rust version, but in the same way so that it can still be evaluated in a static.
+    ///
+    /// [info]: StructField::info
+    pub fn layout(
+        &self,
+        container: &proc_macro2::TokenStream,
+        member: &Member,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.attrs.with.is_some() || self.attrs.dynamic {
+            return None;
+        }
+        let ty = &self.data.ty;
+        Some(quote! {
+            .with_offset({
+                let uninit = ::core::mem::MaybeUninit::<#container>::uninit();
+                let base = uninit.as_ptr();
+                // SAFE: the field is only projected from `base` with `addr_of!`,
+                // which neither reads it nor creates a reference to it
+                unsafe {
+                    (::core::ptr::addr_of!((*base).#member) as *const u8).offset_from(base as *const u8) as usize
+                }
+            })
+            .with_layout(::core::alloc::Layout::new::<#ty>())
+        })
+    }
+}
+
 impl<'a> ReflectStruct<'a> {
     /// Access the metadata associated with this struct definition.
     pub fn meta(&self) -> &ReflectMeta<'a> {
@@ -353,26 +647,165 @@ impl<'a> ReflectStruct<'a> {
     pub fn get_type_registration(&self) -> proc_macro2::TokenStream {
         let reflect_path = self.meta.bevy_reflect_path();
 
+        // Named fields are ignored by name, which stays valid if the fields are reordered
+        let ignored_names = self
+            .active_fields()
+            .filter(|field| field.attrs.ignore == ReflectIgnoreBehavior::IgnoreSerialization)
+            .filter_map(|field| {
+                let ident = field.data.ident.as_ref()?;
+                Some(field.attrs.reflect_name(ident.to_string()))
+            })
+            .collect::<Vec<_>>();
+        let (serialization_denylist, ignored_names) = if !ignored_names.is_empty() {
+            (
+                BitSet::default(),
+                quote!(.with_ignored_names([#(#ignored_names),*])),
+            )
+        } else {
+            (
+                self.serialization_denylist.clone(),
+                proc_macro2::TokenStream::new(),
+            )
+        };
+        let serialization_fns = self.get_serialization_fns();
+        let repr_data = self.meta.traits().get_repr_registration(reflect_path);
+        let range_data = self.get_field_ranges();
+        let validator_data = self.get_field_validators();
+
         crate::registration::impl_get_type_registration(
             self.meta.type_name(),
             reflect_path,
             self.meta.traits().idents(),
             self.meta.generics(),
-            Some(&self.serialization_denylist),
+            Some(&serialization_denylist),
+            quote!(#ignored_names #serialization_fns),
+            quote!(#repr_data #range_data #validator_data),
         )
     }
 
-    /// Get a collection of types which are exposed to the reflection API
-    pub fn active_types(&self) -> Vec<syn::Type> {
+    /// Returns the registration of the type's `FieldRanges`,
+    /// if any of its fields has a `#[reflect(range = "...")]` attribute.
+    fn get_field_ranges(&self) -> Option<proc_macro2::TokenStream> {
+        let bevy_reflect_path = self.meta.bevy_reflect_path();
+        let ranges = self
+            .active_fields()
+            .enumerate()
+            .filter_map(|(index, field)| {
+                let range = field.attrs.range.as_ref()?;
+                let (start, end) = (&range.from, &range.to);
+                let inclusive = matches!(range.limits, syn::RangeLimits::Closed(..));
+                Some(quote! {
+                    .with_range(#index, #bevy_reflect_path::FieldRange::new(
+                        (#start) as f64,
+                        (#end) as f64,
+                        #inclusive,
+                    ))
+                })
+            })
+            .collect::<Vec<_>>();
+        if ranges.is_empty() {
+            return None;
+        }
+        Some(quote! {
+            registration.insert::<#bevy_reflect_path::FieldRanges>(
+                <#bevy_reflect_path::FieldRanges as #FQDefault>::default() #(#ranges)*
+            );
+        })
+    }
+
+    /// Returns the registration of the type's `FieldValidators`,
+    /// if any of its fields has a `#[reflect(validate = "...")]` attribute.
+    fn get_field_validators(&self) -> Option<proc_macro2::TokenStream> {
+        let bevy_reflect_path = self.meta.bevy_reflect_path();
+        let validators = self
+            .active_fields()
+            .enumerate()
+            .filter_map(|(index, field)| {
+                let validate = field.attrs.validate.as_ref()?;
+                let ty = &field.data.ty;
+                Some(quote! {
+                    .with_validator(#index, |value| {
+                        let value = <dyn #bevy_reflect_path::Reflect>::downcast_ref::<#ty>(value).unwrap();
+                        #validate(value).map_err(::core::convert::Into::into)
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        if validators.is_empty() {
+            return None;
+        }
+        Some(quote! {
+            registration.insert::<#bevy_reflect_path::FieldValidators>(
+                <#bevy_reflect_path::FieldValidators as #FQDefault>::default() #(#validators)*
+            );
+        })
+    }
+
+    /// Returns the calls registering the `serialize_with` and `deserialize_with` functions
+    /// of the fields on the type's `SerializationData`.
+    ///
+    /// Like ignored fields, named fields are registered by name and unnamed fields by index.
+    fn get_serialization_fns(&self) -> proc_macro2::TokenStream {
+        let bevy_reflect_path = self.meta.bevy_reflect_path();
+        let fq_box = FQBox(bevy_reflect_path);
+        self.active_fields()
+            .enumerate()
+            .map(|(index, field)| {
+                let ty = &field.data.ty;
+                let (with_serialize_fn, with_deserialize_fn, key) = match &field.data.ident {
+                    Some(ident) => {
+                        let name = field.attrs.reflect_name(ident.to_string());
+                        (
+                            quote!(with_named_serialize_fn),
+                            quote!(with_named_deserialize_fn),
+                            quote!(#name),
+                        )
+                    }
+                    None => (
+                        quote!(with_serialize_fn),
+                        quote!(with_deserialize_fn),
+                        quote!(#index),
+                    ),
+                };
+                let serialize_fn = field.attrs.serialize_with.as_ref().map(|serialize_with| {
+                    quote! {
+                        .#with_serialize_fn(#key, |value, serializer| {
+                            let value = <dyn #bevy_reflect_path::Reflect>::downcast_ref::<#ty>(value).unwrap();
+                            #serialize_with(value, serializer)
+                        })
+                    }
+                });
+                let deserialize_fn = field.attrs.deserialize_with.as_ref().map(|deserialize_with| {
+                    quote! {
+                        .#with_deserialize_fn(#key, |deserializer| {
+                            let value: #ty = #deserialize_with(deserializer)?;
+                            #FQResult::Ok(#fq_box::new(value))
+                        })
+                    }
+                });
+                quote!(#serialize_fn #deserialize_fn)
+            })
+            .collect()
+    }
+
+    /// Get an iterator of fields which are exposed to the reflection API by reference
+    pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
         self.fields
             .iter()
-            .filter(move |field| field.attrs.ignore.is_active())
-            .map(|field| field.data.ty.clone())
-            .collect::<Vec<_>>()
+            .filter(move |field| field.attrs.ignore.is_active() && field.attrs.convert.is_none())
     }
 
-    /// Get an iterator of fields which are exposed to the reflection API
-    pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
+    /// Get an iterator of fields which are exposed to the reflection API as an owned value,
+    /// through a `convert` module
+    pub fn converted_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
+        self.fields
+            .iter()
+            .filter(move |field| field.attrs.ignore.is_active() && field.attrs.convert.is_some())
+    }
+
+    /// Get an iterator of fields which are exposed to the reflection API,
+    /// whether by reference or through a `convert` module
+    pub fn reflected_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
         self.fields
             .iter()
             .filter(move |field| field.attrs.ignore.is_active())
@@ -386,7 +819,6 @@ impl<'a> ReflectStruct<'a> {
     }
 
     /// The complete set of fields in this struct.
-    #[allow(dead_code)]
     pub fn fields(&self) -> &[StructField<'a>] {
         &self.fields
     }
@@ -406,8 +838,96 @@ impl<'a> ReflectEnum<'a> {
         }
     }
 
+    /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
+    ///
+    /// Unlike the generic [`get_type_registration`](ReflectMeta::get_type_registration) method,
+    /// this also registers the enum's `ReflectEnumVariants`.
+    pub fn get_type_registration(&self) -> proc_macro2::TokenStream {
+        let bevy_reflect_path = self.meta.bevy_reflect_path();
+        let fq_box = FQBox(bevy_reflect_path);
+        let repr_data = self.meta.traits().get_repr_registration(bevy_reflect_path);
+        let unit_variants = self
+            .variants
+            .iter()
+            .filter(|variant| matches!(variant.fields, EnumVariantFields::Unit))
+            .map(|variant| {
+                let ident = &variant.data.ident;
+                let pattern = variant
+                    .attrs
+                    .name_pattern(&variant.attrs.reflect_name(ident.to_string()));
+                quote! {
+                    #pattern => #FQOption::Some(#fq_box::new(Self::#ident))
+                }
+            });
+
+        crate::registration::impl_get_type_registration(
+            self.meta.type_name(),
+            bevy_reflect_path,
+            self.meta.traits().idents(),
+            self.meta.generics(),
+            None,
+            proc_macro2::TokenStream::new(),
+            quote! {
+                #repr_data
+                if let #bevy_reflect_path::TypeInfo::Enum(info) = <Self as #bevy_reflect_path::Typed>::type_info() {
+                    registration.insert::<#bevy_reflect_path::ReflectEnumVariants>(
+                        #bevy_reflect_path::ReflectEnumVariants::new(info, |name| match name {
+                            #(#unit_variants,)*
+                            _ => #FQOption::None,
+                        }),
+                    );
+                }
+            },
+        )
+    }
+
+    /// Returns the discriminant of each variant as an `i128` expression,
+    /// or `None` if the enum neither has a primitive or `C` representation
+    /// nor gives a discriminant to any of its variants.
+    ///
+    /// Variants without an explicit discriminant follow the previous variant, as in Rust.
+    pub fn discriminants(&self) -> Option<Vec<proc_macro2::TokenStream>> {
+        let has_explicit = self
+            .variants
+            .iter()
+            .any(|variant| variant.data.discriminant.is_some());
+        if !self.has_discriminant_repr && !has_explicit {
+            return None;
+        }
+
+        let mut base = quote!(0);
+        let mut offset = 0_i128;
+        Some(
+            self.variants
+                .iter()
+                .map(|variant| {
+                    if let Some((_, expr)) = &variant.data.discriminant {
+                        base = quote!((#expr) as i128);
+                        offset = 0;
+                    }
+                    let discriminant = quote!(#base + #offset);
+                    offset += 1;
+                    discriminant
+                })
+                .collect(),
+        )
+    }
+
     /// The complete set of variants in this enum.
     pub fn variants(&self) -> &[EnumVariant<'a>] {
         &self.variants
     }
+
+    /// Get an iterator over the fields of every variant which are active in the reflection API.
+    pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
+        self.variants
+            .iter()
+            .flat_map(|variant| match &variant.fields {
+                EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields) => {
+                    fields.as_slice()
+                }
+                EnumVariantFields::Unit => &[],
+            })
+            .filter(|field| field.attrs.ignore.is_active())
+    }
 }
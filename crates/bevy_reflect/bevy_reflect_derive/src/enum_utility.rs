@@ -1,4 +1,4 @@
-use crate::fq_std::FQDefault;
+use crate::fq_std::{FQDefault, FQOption};
 use crate::{
     derive_data::{EnumVariantFields, ReflectEnum},
     utility::ident_or_index,
@@ -8,8 +8,8 @@ use quote::{quote, ToTokens};
 
 /// Contains all data needed to construct all variants within an enum.
 pub(crate) struct EnumVariantConstructors {
-    /// The names of each variant as a string.
-    pub variant_names: Vec<String>,
+    /// The patterns matching the names (and aliases) of each variant.
+    pub variant_names: Vec<proc_macro2::TokenStream>,
     /// The stream of tokens that will construct each variant.
     pub variant_constructors: Vec<proc_macro2::TokenStream>,
 }
@@ -27,7 +27,7 @@ pub(crate) fn get_variant_constructors(
 
     for variant in reflect_enum.variants() {
         let ident = &variant.data.ident;
-        let name = ident.to_string();
+        let name = variant.attrs.reflect_name(ident.to_string());
         let variant_constructor = reflect_enum.get_unit(ident);
 
         let fields = match &variant.fields {
@@ -55,18 +55,23 @@ pub(crate) fn get_variant_constructors(
                 } else {
                     quote!(?)
                 };
-                let field_accessor = match &field.data.ident {
+                let field_lookup = match &field.data.ident {
                     Some(ident) => {
-                        let name = ident.to_string();
-                        quote!(.field(#name))
+                        let name = field.attrs.reflect_name(ident.to_string());
+                        // Fall back to looking the field up by its aliases
+                        field.attrs.aliases.iter().fold(
+                            quote!(#ref_value .field(#name)),
+                            |lookup, alias| quote!(#FQOption::or_else(#lookup, || #ref_value .field(#alias))),
+                        )
                     }
-                    None => quote!(.field_at(#reflect_index)),
+                    None => quote!(#ref_value .field_at(#reflect_index)),
                 };
                 reflect_index += 1;
                 let missing_field_err_message = format!("the field {error_repr} was not declared");
-                let accessor = quote!(#field_accessor .expect(#missing_field_err_message));
+                let field_value = quote!(#field_lookup .expect(#missing_field_err_message));
+                let from_reflect = field.construct_from_reflect(bevy_reflect_path, field_value);
                 quote! {
-                    #bevy_reflect_path::FromReflect::from_reflect(#ref_value #accessor)
+                    #from_reflect
                     #unwrapper
                 }
             };
@@ -75,7 +80,7 @@ pub(crate) fn get_variant_constructors(
         variant_constructors.push(quote! {
             #variant_constructor { #( #constructor_fields ),* }
         });
-        variant_names.push(name);
+        variant_names.push(variant.attrs.name_pattern(&name));
     }
 
     EnumVariantConstructors {
@@ -5,15 +5,39 @@
 //! the derive helper attribute for `Reflect`, which looks like: `#[reflect(ignore)]`.
 
 use crate::REFLECT_ATTRIBUTE_NAME;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Attribute, Lit, Meta, NestedMeta};
+use syn::{Attribute, Lit, Meta, NestedMeta, Token};
 
 pub(crate) static IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
 pub(crate) static IGNORE_ALL_ATTR: &str = "ignore";
 
 pub(crate) static DEFAULT_ATTR: &str = "default";
 
+pub(crate) static WITH_ATTR: &str = "with";
+
+pub(crate) static CONVERT_ATTR: &str = "convert";
+
+pub(crate) static DYNAMIC_ATTR: &str = "dynamic";
+
+pub(crate) static GET_ATTR: &str = "get";
+pub(crate) static SET_ATTR: &str = "set";
+
+pub(crate) static SERIALIZE_WITH_ATTR: &str = "serialize_with";
+pub(crate) static DESERIALIZE_WITH_ATTR: &str = "deserialize_with";
+
+pub(crate) static RANGE_ATTR: &str = "range";
+
+pub(crate) static VALIDATE_ATTR: &str = "validate";
+
+// The serde attributes mirrored by `#[reflect(serde_compat)]`
+static SERDE_ATTRIBUTE_NAME: &str = "serde";
+static SERDE_RENAME_ATTR: &str = "rename";
+static SERDE_ALIAS_ATTR: &str = "alias";
+static SERDE_SKIP_ATTR: &str = "skip";
+
 /// Stores data about if the field should be visible via the Reflect and serialization interfaces
 ///
 /// Note the relationship between serialization and reflection is such that a member must be reflected in order to be serialized.
@@ -52,6 +76,93 @@ pub(crate) struct ReflectFieldAttr {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// A module used to reflect this field through another type, if any.
+    ///
+    /// The module is expected to contain the following functions, where `T` is
+    /// the field's type and `R` is the type it should be reflected as:
+    /// * `fn as_reflect(value: &T) -> &R`
+    /// * `fn as_reflect_mut(value: &mut T) -> &mut R`
+    /// * `fn from_reflect(reflect: &dyn Reflect) -> Option<T>` (only needed for `FromReflect`)
+    pub with: Option<syn::Path>,
+    /// A module used to reflect this field as an owned value of another type, if any.
+    ///
+    /// Unlike a `with` module, the reflected value need not be stored inside the field.
+    /// The module is expected to contain the following functions, where `T` is
+    /// the field's type and `R` is the type it should be reflected as:
+    /// * `fn to_reflect(value: &T) -> R`
+    /// * `fn from_reflect(reflect: &dyn Reflect) -> Option<T>`
+    ///
+    /// Such fields are only supported on structs with named fields.
+    pub convert: Option<syn::Path>,
+    /// Whether this field is a `Box<dyn Reflect>` reflected as the value it holds.
+    pub dynamic: bool,
+    /// The name this field or variant is reflected with, if it differs from its declared name.
+    ///
+    /// This is only set by `#[serde(rename = "...")]` when using `#[reflect(serde_compat)]`.
+    pub rename: Option<String>,
+    /// Alternative names this field or variant may be looked up with.
+    ///
+    /// This is only set by `#[serde(alias = "...")]` when using `#[reflect(serde_compat)]`.
+    pub aliases: Vec<String>,
+    /// A function used to get a reference to this field, if any.
+    ///
+    /// The function is expected to have the signature `fn(&Self) -> &T`, where `T` is the field's type.
+    pub get: Option<syn::ExprPath>,
+    /// A function used to set this field, if any.
+    ///
+    /// The function is expected to have the signature `fn(&mut Self, T)`, where `T` is the field's type.
+    pub set: Option<syn::ExprPath>,
+    /// A function used to serialize this field in place of its reflected representation, if any.
+    ///
+    /// The function is expected to have the signature
+    /// `fn<S: Serializer>(&T, S) -> Result<S::Ok, S::Error>`, where `T` is the field's type.
+    pub serialize_with: Option<syn::ExprPath>,
+    /// A function used to deserialize this field in place of its reflected representation, if any.
+    ///
+    /// The function is expected to have the signature
+    /// `fn<'de, D: Deserializer<'de>>(D) -> Result<T, D::Error>`, where `T` is the field's type.
+    pub deserialize_with: Option<syn::ExprPath>,
+    /// The range of values this field is expected to hold, if any.
+    ///
+    /// This is a range expression with both a start and an end, such as `0..10` or `0.0..=1.0`,
+    /// which is registered in the type's `FieldRanges`.
+    pub range: Option<syn::ExprRange>,
+    /// A function used to validate this field, if any.
+    ///
+    /// The function is expected to have the signature `fn(&T) -> Result<(), E>`, where `T` is the
+    /// field's type and `E` converts into `ValidationErrors`. It is registered in the type's `FieldValidators`.
+    pub validate: Option<syn::ExprPath>,
+    /// The custom attributes of this field, given as `#[reflect(@expr, ...)]`.
+    ///
+    /// Each expression is evaluated in a static, so it must be a constant expression
+    /// of a type which is `Send + Sync + 'static`.
+    pub custom_attributes: Vec<syn::Expr>,
+}
+
+impl ReflectFieldAttr {
+    /// Returns the name this field or variant is reflected with, given its declared name.
+    pub fn reflect_name(&self, declared_name: String) -> String {
+        self.rename.clone().unwrap_or(declared_name)
+    }
+
+    /// Returns `true` if this field is converted with its own type's `FromReflect` implementation,
+    /// rather than through a `with` or `convert` module or as a dynamic field.
+    pub fn uses_from_reflect(&self) -> bool {
+        self.with.is_none() && self.convert.is_none() && !self.dynamic
+    }
+
+    /// Returns `true` if this field is accessed through a getter or setter function.
+    ///
+    /// Such fields cannot be mutably borrowed through the reflection API.
+    pub fn has_accessors(&self) -> bool {
+        self.get.is_some() || self.set.is_some()
+    }
+
+    /// Returns a pattern matching the given reflected name or any of the aliases.
+    pub fn name_pattern(&self, reflect_name: &str) -> proc_macro2::TokenStream {
+        let aliases = &self.aliases;
+        quote!(#reflect_name #(| #aliases)*)
+    }
 }
 
 /// Controls how the default value is determined for a field.
@@ -67,17 +178,39 @@ pub(crate) enum DefaultBehavior {
     /// This assumes the function is in scope, is callable with zero arguments,
     /// and returns the expected type.
     Func(syn::ExprPath),
+    /// Field can be created using the given expression, such as `#[reflect(default(-1))]`,
+    /// or the given literal, such as `#[reflect(default = 42)]`.
+    ///
+    /// String literals are always treated as function names (see [`DefaultBehavior::Func`]).
+    Expr(syn::Expr),
 }
 
 /// Parse all field attributes marked "reflect" (such as `#[reflect(ignore)]`).
-pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr, syn::Error> {
+///
+/// If `serde_compat` is true, the relevant `#[serde(...)]` attributes are also parsed,
+/// though any explicit reflection attributes take precedence over them.
+pub(crate) fn parse_field_attrs(
+    attrs: &[Attribute],
+    serde_compat: bool,
+) -> Result<ReflectFieldAttr, syn::Error> {
     let mut args = ReflectFieldAttr::default();
     let mut errors: Option<syn::Error> = None;
 
-    let attrs = attrs
+    let reflect_attrs = attrs
         .iter()
         .filter(|a| a.path.is_ident(REFLECT_ATTRIBUTE_NAME));
-    for attr in attrs {
+    for attr in reflect_attrs {
+        if let Some(custom_attributes) = attr.parse_args_with(parse_custom_attributes)? {
+            args.custom_attributes.extend(custom_attributes);
+            continue;
+        }
+
+        // Expressions cannot be parsed as `Meta`, so `default(...)` is parsed separately
+        if let Some(expr) = attr.parse_args_with(parse_default_expr)? {
+            args.default = DefaultBehavior::Expr(expr);
+            continue;
+        }
+
         let meta = attr.parse_meta()?;
         if let Err(err) = parse_meta(&mut args, &meta) {
             if let Some(ref mut error) = errors {
@@ -89,13 +222,174 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
     }
 
     if let Some(error) = errors {
-        Err(error)
-    } else {
-        Ok(args)
+        return Err(error);
+    }
+
+    // Converted fields cannot be borrowed, and are only reachable through their converted value
+    if let Some(convert) = &args.convert {
+        let is_plain = args.with.is_none()
+            && !args.dynamic
+            && !args.has_accessors()
+            && args.serialize_with.is_none()
+            && args.deserialize_with.is_none()
+            && args.range.is_none()
+            && args.validate.is_none()
+            && args.ignore == ReflectIgnoreBehavior::None;
+        if !is_plain {
+            return Err(syn::Error::new(
+                convert.span(),
+                format!(
+                    "`{CONVERT_ATTR}` fields cannot be ignored or use any of `{WITH_ATTR}`, `{DYNAMIC_ATTR}`, `{GET_ATTR}`, `{SET_ATTR}`, `{SERIALIZE_WITH_ATTR}`, `{DESERIALIZE_WITH_ATTR}`, `{RANGE_ATTR}` or `{VALIDATE_ATTR}`"
+                ),
+            ));
+        }
+    }
+
+    if let (true, Some(with)) = (args.dynamic, &args.with) {
+        return Err(syn::Error::new(
+            with.span(),
+            format!("`{DYNAMIC_ATTR}` fields cannot also use `{WITH_ATTR}`"),
+        ));
+    }
+
+    // The custom functions are passed the field's own type, which these fields are not reflected as
+    let custom_serialization = args.serialize_with.iter().chain(&args.deserialize_with);
+    if let (false, Some(path)) = (args.uses_from_reflect(), custom_serialization.last()) {
+        return Err(syn::Error::new(
+            path.span(),
+            format!(
+                "`{DYNAMIC_ATTR}` and `{WITH_ATTR}` fields cannot also use `{SERIALIZE_WITH_ATTR}` or `{DESERIALIZE_WITH_ATTR}`"
+            ),
+        ));
+    }
+
+    // Without a setter, applying a value to the field would silently do nothing,
+    // as fields with accessors cannot be borrowed mutably
+    if let (Some(getter), None) = (&args.get, &args.set) {
+        return Err(syn::Error::new(
+            getter.span(),
+            format!("fields with a `{GET_ATTR}` function must also have a `{SET_ATTR}` function"),
+        ));
+    }
+
+    // Like the custom serialization functions, validators are passed the field's own type
+    if let (false, Some(path)) = (args.uses_from_reflect(), &args.validate) {
+        return Err(syn::Error::new(
+            path.span(),
+            format!("`{DYNAMIC_ATTR}` and `{WITH_ATTR}` fields cannot also use `{VALIDATE_ATTR}`"),
+        ));
     }
+
+    if serde_compat {
+        let serde_args = parse_serde_attrs(attrs)?;
+        if args.ignore == ReflectIgnoreBehavior::None {
+            args.ignore = serde_args.ignore;
+        }
+        if matches!(args.default, DefaultBehavior::Required) {
+            args.default = serde_args.default;
+        }
+        args.rename = serde_args.rename;
+        args.aliases = serde_args.aliases;
+    }
+
+    Ok(args)
 }
 
-/// Recursively parses attribute metadata for things like `#[reflect(ignore)]` and `#[reflect(default = "foo")]`
+/// Parses the custom attributes of an attribute such as `#[reflect(@Tooltip("..."), @Multiline)]`.
+///
+/// Returns `None` without reporting an error if the attribute does not start with an `@`,
+/// leaving it to be parsed as regular metadata.
+fn parse_custom_attributes(input: ParseStream) -> Result<Option<Vec<syn::Expr>>, syn::Error> {
+    if !input.peek(Token![@]) {
+        input.parse::<proc_macro2::TokenStream>()?;
+        return Ok(None);
+    }
+
+    let attributes = Punctuated::<syn::Expr, Token![,]>::parse_terminated_with(input, |input| {
+        input.parse::<Token![@]>()?;
+        input.parse()
+    })?;
+    Ok(Some(attributes.into_iter().collect()))
+}
+
+/// Parses the default expression of an attribute such as `#[reflect(default(-1))]`.
+///
+/// Returns `None` for any other attribute, which is then parsed as `Meta` instead.
+fn parse_default_expr(input: ParseStream) -> Result<Option<syn::Expr>, syn::Error> {
+    let fork = input.fork();
+    let is_default_expr = matches!(fork.parse::<syn::Ident>(), Ok(ident) if ident == DEFAULT_ATTR)
+        && fork.peek(syn::token::Paren);
+    if !is_default_expr {
+        input.parse::<proc_macro2::TokenStream>()?;
+        return Ok(None);
+    }
+
+    input.parse::<syn::Ident>()?;
+    let content;
+    syn::parenthesized!(content in input);
+    content.parse().map(Some)
+}
+
+/// Parse the `#[serde(...)]` attributes which have an equivalent reflection behavior.
+///
+/// * `#[serde(rename = "name")]` reflects the field or variant as `name`.
+/// * `#[serde(alias = "name")]` allows it to also be looked up (and built by `FromReflect`) as `name`.
+/// * `#[serde(skip)]` behaves as `#[reflect(skip_serializing, default)]`.
+/// * `#[serde(default)]` and `#[serde(default = "path")]` behave as their `#[reflect(...)]` counterparts.
+///
+/// All other serde attributes are left to serde and ignored.
+fn parse_serde_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr, syn::Error> {
+    let mut args = ReflectFieldAttr::default();
+    let mut has_default = false;
+
+    let attrs = attrs
+        .iter()
+        .filter(|a| a.path.is_ident(SERDE_ATTRIBUTE_NAME));
+    for attr in attrs {
+        let Meta::List(list) = attr.parse_meta()? else {
+            continue;
+        };
+
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident(SERDE_SKIP_ATTR) => {
+                    args.ignore = ReflectIgnoreBehavior::IgnoreSerialization;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident(DEFAULT_ATTR) => {
+                    args.default = DefaultBehavior::Default;
+                    has_default = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(pair)) => {
+                    let Lit::Str(lit_str) = &pair.lit else {
+                        continue;
+                    };
+
+                    if pair.path.is_ident(SERDE_RENAME_ATTR) {
+                        args.rename = Some(lit_str.value());
+                    } else if pair.path.is_ident(SERDE_ALIAS_ATTR) {
+                        args.aliases.push(lit_str.value());
+                    } else if pair.path.is_ident(DEFAULT_ATTR) {
+                        args.default = DefaultBehavior::Func(lit_str.parse()?);
+                        has_default = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Serde requires skipped fields to be defaultable
+    if args.ignore == ReflectIgnoreBehavior::IgnoreSerialization && !has_default {
+        args.default = DefaultBehavior::Default;
+    }
+
+    Ok(args)
+}
+
+/// Recursively parses attribute metadata for things like `#[reflect(ignore)]`, `#[reflect(default = "foo")]`
+/// and `#[reflect(default = 42)]`
+///
+/// Default expressions, such as `#[reflect(default(-1))]`, are parsed by [`parse_default_expr`] instead.
 fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error> {
     match meta {
         Meta::Path(path) if path.is_ident(IGNORE_SERIALIZATION_ATTR) => {
@@ -112,6 +406,10 @@ fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error
             args.default = DefaultBehavior::Default;
             Ok(())
         }
+        Meta::Path(path) if path.is_ident(DYNAMIC_ATTR) => {
+            args.dynamic = true;
+            Ok(())
+        }
         Meta::Path(path) => Err(syn::Error::new(
             path.span(),
             format!("unknown attribute parameter: {}", path.to_token_stream()),
@@ -123,14 +421,112 @@ fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error
                     args.default = DefaultBehavior::Func(lit_str.parse()?);
                     Ok(())
                 }
-                err => {
-                    Err(syn::Error::new(
-                        err.span(),
-                        format!("expected a string literal containing the name of a function, but found: {}", err.to_token_stream()),
+                lit => {
+                    args.default = DefaultBehavior::Expr(syn::Expr::Lit(syn::ExprLit {
+                        attrs: Vec::new(),
+                        lit: lit.clone(),
+                    }));
+                    Ok(())
+                }
+            }
+        }
+        Meta::NameValue(pair)
+            if pair.path.is_ident(WITH_ATTR) || pair.path.is_ident(CONVERT_ATTR) =>
+        {
+            match &pair.lit {
+                Lit::Str(lit_str) if pair.path.is_ident(WITH_ATTR) => {
+                    args.with = Some(lit_str.parse()?);
+                    Ok(())
+                }
+                Lit::Str(lit_str) => {
+                    args.convert = Some(lit_str.parse()?);
+                    Ok(())
+                }
+                lit => Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "expected a string literal containing the path to a module, but found: {}",
+                        lit.to_token_stream()
+                    ),
+                )),
+            }
+        }
+        Meta::NameValue(pair) if pair.path.is_ident(VALIDATE_ATTR) => match &pair.lit {
+            Lit::Str(lit_str) => {
+                args.validate = Some(lit_str.parse()?);
+                Ok(())
+            }
+            lit => Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "expected a string literal containing the path to a function, but found: {}",
+                    lit.to_token_stream()
+                ),
+            )),
+        },
+        Meta::NameValue(pair)
+            if pair.path.is_ident(SERIALIZE_WITH_ATTR)
+                || pair.path.is_ident(DESERIALIZE_WITH_ATTR) =>
+        {
+            let function = match &pair.lit {
+                Lit::Str(lit_str) => lit_str.parse()?,
+                lit => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!(
+                            "expected a string literal containing the path to a function, but found: {}",
+                            lit.to_token_stream()
+                        ),
                     ))
                 }
+            };
+            if pair.path.is_ident(SERIALIZE_WITH_ATTR) {
+                args.serialize_with = Some(function);
+            } else {
+                args.deserialize_with = Some(function);
             }
+            Ok(())
         }
+        Meta::NameValue(pair) if pair.path.is_ident(GET_ATTR) || pair.path.is_ident(SET_ATTR) => {
+            let accessor = match &pair.lit {
+                Lit::Str(lit_str) => lit_str.parse()?,
+                lit => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!(
+                            "expected a string literal containing the path to a function, but found: {}",
+                            lit.to_token_stream()
+                        ),
+                    ))
+                }
+            };
+            if pair.path.is_ident(GET_ATTR) {
+                args.get = Some(accessor);
+            } else {
+                args.set = Some(accessor);
+            }
+            Ok(())
+        }
+        Meta::NameValue(pair) if pair.path.is_ident(RANGE_ATTR) => match &pair.lit {
+            Lit::Str(lit_str) => {
+                let range = lit_str.parse::<syn::ExprRange>()?;
+                if range.from.is_none() || range.to.is_none() {
+                    return Err(syn::Error::new(
+                        lit_str.span(),
+                        "expected a range with both a start and an end, such as \"0..10\"",
+                    ));
+                }
+                args.range = Some(range);
+                Ok(())
+            }
+            lit => Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "expected a string literal containing a range, but found: {}",
+                    lit.to_token_stream()
+                ),
+            )),
+        },
         Meta::NameValue(pair) => {
             let path = &pair.path;
             Err(syn::Error::new(
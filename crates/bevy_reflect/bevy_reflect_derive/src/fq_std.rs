@@ -32,9 +32,12 @@
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Path;
 
 pub(crate) struct FQAny;
-pub(crate) struct FQBox;
+/// Unlike the other structs, this names `Box` through the `bevy_reflect` crate at the given path,
+/// as crates without `std` can't name it through `::std`.
+pub(crate) struct FQBox<'a>(pub &'a Path);
 pub(crate) struct FQClone;
 pub(crate) struct FQDefault;
 pub(crate) struct FQOption;
@@ -46,9 +49,10 @@ impl ToTokens for FQAny {
     }
 }
 
-impl ToTokens for FQBox {
+impl ToTokens for FQBox<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        quote!(::std::boxed::Box).to_tokens(tokens);
+        let bevy_reflect_path = self.0;
+        quote!(#bevy_reflect_path::__macro_exports::alloc_utils::Box).to_tokens(tokens);
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::container_attributes::REFLECT_DEFAULT;
-use crate::derive_data::ReflectEnum;
+use crate::derive_data::{ReflectEnum, StructField};
 use crate::enum_utility::{get_variant_constructors, EnumVariantConstructors};
 use crate::field_attributes::DefaultBehavior;
 use crate::fq_std::{FQAny, FQClone, FQDefault, FQOption};
@@ -7,7 +7,11 @@ use crate::{ReflectMeta, ReflectStruct};
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
-use syn::{Field, Ident, Index, Lit, LitInt, LitStr, Member};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, Field, Ident, Index, Lit, LitInt, LitStr, Member, Path, Type, WhereClause,
+    WherePredicate,
+};
 
 /// Implements `FromReflect` for the given struct
 pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
@@ -24,10 +28,24 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     let type_name = meta.type_name();
     let bevy_reflect_path = meta.bevy_reflect_path();
     let (impl_generics, ty_generics, where_clause) = meta.generics().split_for_impl();
+    let body = match meta
+        .traits()
+        .get_from_repr_conversion(bevy_reflect_path, quote!(reflect))
+    {
+        Some(conversion) => quote! {
+            if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<#type_name #ty_generics>(<dyn #bevy_reflect_path::Reflect>::as_any(reflect)) {
+                return #FQOption::Some(#FQClone::clone(value));
+            }
+            #conversion
+        },
+        None => quote! {
+            #FQOption::Some(#FQClone::clone(<dyn #FQAny>::downcast_ref::<#type_name #ty_generics>(<dyn #bevy_reflect_path::Reflect>::as_any(reflect))?))
+        },
+    };
     TokenStream::from(quote! {
         impl #impl_generics #bevy_reflect_path::FromReflect for #type_name #ty_generics #where_clause  {
             fn from_reflect(reflect: &dyn #bevy_reflect_path::Reflect) -> #FQOption<Self> {
-                #FQOption::Some(#FQClone::clone(<dyn #FQAny>::downcast_ref::<#type_name #ty_generics>(<dyn #bevy_reflect_path::Reflect>::as_any(reflect))?))
+                #body
             }
         }
     })
@@ -46,10 +64,19 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
         variant_constructors,
     } = get_variant_constructors(reflect_enum, &ref_value, false);
 
+    // Add FromReflect bound for each active field not converted through a `with` module or dynamically
+    let field_types = reflect_enum
+        .active_fields()
+        .filter(|field| field.attrs.uses_from_reflect())
+        .map(|field| field.data.ty.clone())
+        .collect::<Vec<_>>();
+
     let (impl_generics, ty_generics, where_clause) =
         reflect_enum.meta().generics().split_for_impl();
+    let where_from_reflect_clause =
+        extend_where_clause(where_clause, &field_types, bevy_reflect_path);
     TokenStream::from(quote! {
-        impl #impl_generics #bevy_reflect_path::FromReflect for #type_name #ty_generics #where_clause  {
+        impl #impl_generics #bevy_reflect_path::FromReflect for #type_name #ty_generics #where_from_reflect_clause  {
             fn from_reflect(#ref_value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<Self> {
                 if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
                     match #bevy_reflect_path::Enum::variant_name(#ref_value) {
@@ -64,6 +91,24 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
     })
 }
 
+/// Extends the given where clause with a `FromReflect` bound for each of the given types.
+fn extend_where_clause(
+    where_clause: Option<&WhereClause>,
+    field_types: &[Type],
+    bevy_reflect_path: &Path,
+) -> WhereClause {
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| WhereClause {
+        where_token: Default::default(),
+        predicates: Punctuated::new(),
+    });
+    where_clause.predicates.extend(
+        field_types
+            .iter()
+            .map(|ty| -> WherePredicate { parse_quote!(#ty: #bevy_reflect_path::FromReflect) }),
+    );
+    where_clause
+}
+
 /// Container for a struct's members (field name or index) and their
 /// corresponding values.
 struct MemberValuePair(Vec<Member>, Vec<proc_macro2::TokenStream>);
@@ -88,17 +133,30 @@ fn impl_struct_internal(reflect_struct: &ReflectStruct, is_tuple: bool) -> Token
         Ident::new("Struct", Span::call_site())
     };
 
-    let field_types = reflect_struct.active_types();
+    let field_types = reflect_struct
+        .active_fields()
+        .filter(|field| field.attrs.uses_from_reflect())
+        .map(|field| field.data.ty.clone())
+        .collect::<Vec<_>>();
     let MemberValuePair(active_members, active_values) =
         get_active_fields(reflect_struct, &ref_struct, &ref_struct_type, is_tuple);
 
     let constructor = if reflect_struct.meta().traits().contains(REFLECT_DEFAULT) {
+        // Fields with setters are set through them, so that the type's invariants are upheld
+        let active_assignments =
+            reflect_struct
+                .reflected_fields()
+                .zip(&active_members)
+                .map(|(field, member)| match &field.attrs.set {
+                    Some(setter) => quote!(#setter(&mut __this, __field)),
+                    None => quote!(__this.#member = __field),
+                });
         quote!(
             let mut __this: Self = #FQDefault::default();
             #(
                 if let #fqoption::Some(__field) = #active_values() {
                     // Iff field exists -> use its value
-                    __this.#active_members = __field;
+                    #active_assignments;
                 }
             )*
             #FQOption::Some(__this)
@@ -119,17 +177,9 @@ fn impl_struct_internal(reflect_struct: &ReflectStruct, is_tuple: bool) -> Token
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    // Add FromReflect bound for each active field
-    let mut where_from_reflect_clause = if where_clause.is_some() {
-        quote! {#where_clause}
-    } else if !active_members.is_empty() {
-        quote! {where}
-    } else {
-        quote! {}
-    };
-    where_from_reflect_clause.extend(quote! {
-        #(#field_types: #bevy_reflect_path::FromReflect,)*
-    });
+    // Add FromReflect bound for each active field not converted through a `with` module or dynamically
+    let where_from_reflect_clause =
+        extend_where_clause(where_clause, &field_types, bevy_reflect_path);
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_reflect_path::FromReflect for #struct_name #ty_generics #where_from_reflect_clause
@@ -158,6 +208,7 @@ fn get_ignored_fields(reflect_struct: &ReflectStruct, is_tuple: bool) -> MemberV
 
                 let value = match &field.attrs.default {
                     DefaultBehavior::Func(path) => quote! {#path()},
+                    DefaultBehavior::Expr(expr) => quote! {#expr},
                     _ => quote! {#FQDefault::default()},
                 };
 
@@ -181,37 +232,76 @@ fn get_active_fields(
 
     MemberValuePair::new(
         reflect_struct
-            .active_fields()
+            .reflected_fields()
             .map(|field| {
                 let member = get_ident(field.data, field.index, is_tuple);
-                let accessor = get_field_accessor(field.data, field.index, is_tuple);
-                let ty = field.data.ty.clone();
+                let accessor = get_field_accessor(field, is_tuple);
+
+                // Converted fields may not be borrowed from the struct, so they are looked up separately
+                if let Some(module) = &field.attrs.convert {
+                    let convert_field = quote! {
+                        #bevy_reflect_path::__macro_exports::convert_struct_field(#dyn_struct_name, #accessor, #module::from_reflect)
+                    };
+                    let value = match &field.attrs.default {
+                        DefaultBehavior::Func(path) => quote! {
+                            (|| #FQOption::unwrap_or_else(#convert_field, || #FQOption::Some(#path())))
+                        },
+                        DefaultBehavior::Expr(expr) => quote! {
+                            (|| #FQOption::unwrap_or_else(#convert_field, || #FQOption::Some(#expr)))
+                        },
+                        DefaultBehavior::Default => quote! {
+                            (|| #FQOption::unwrap_or_else(#convert_field, || #FQOption::Some(#FQDefault::default())))
+                        },
+                        DefaultBehavior::Required => quote! {
+                            (|| #convert_field?)
+                        },
+                    };
+                    return (member, value);
+                }
 
                 let get_field = quote! {
                     #bevy_reflect_path::#struct_type::field(#dyn_struct_name, #accessor)
                 };
+                // Fall back to looking the field up by its aliases (only named fields have any)
+                let get_field = field.attrs.aliases.iter().fold(get_field, |get_field, alias| {
+                    quote! {
+                        #FQOption::or_else(#get_field, || #bevy_reflect_path::#struct_type::field(#dyn_struct_name, #alias))
+                    }
+                });
+                let from_field = field.construct_from_reflect(bevy_reflect_path, quote!(field));
+                let from_get_field =
+                    field.construct_from_reflect(bevy_reflect_path, quote!(#get_field?));
 
                 let value = match &field.attrs.default {
                     DefaultBehavior::Func(path) => quote! {
                         (||
                             if let #FQOption::Some(field) = #get_field {
-                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                #from_field
                             } else {
                                 #FQOption::Some(#path())
                             }
                         )
                     },
+                    DefaultBehavior::Expr(expr) => quote! {
+                        (||
+                            if let #FQOption::Some(field) = #get_field {
+                                #from_field
+                            } else {
+                                #FQOption::Some(#expr)
+                            }
+                        )
+                    },
                     DefaultBehavior::Default => quote! {
                         (||
                             if let #FQOption::Some(field) = #get_field {
-                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                #from_field
                             } else {
                                 #FQOption::Some(#FQDefault::default())
                             }
                         )
                     },
                     DefaultBehavior::Required => quote! {
-                        (|| <#ty as #bevy_reflect_path::FromReflect>::from_reflect(#get_field?))
+                        (|| #from_get_field)
                     },
                 };
 
@@ -237,15 +327,21 @@ fn get_ident(field: &Field, index: usize, is_tuple: bool) -> Member {
 /// Returns the accessor for a given field of a struct or tuple struct.
 ///
 /// This differs from a member in that it needs to be a number for tuple structs
-/// and a string for standard structs.
-fn get_field_accessor(field: &Field, index: usize, is_tuple: bool) -> Lit {
+/// and a string (the field's reflected name) for standard structs.
+fn get_field_accessor(field: &StructField, is_tuple: bool) -> Lit {
+    let index = field.index;
     if is_tuple {
         Lit::Int(LitInt::new(&index.to_string(), Span::call_site()))
     } else {
-        field
+        let name = field
+            .data
             .ident
             .as_ref()
-            .map(|ident| Lit::Str(LitStr::new(&ident.to_string(), Span::call_site())))
-            .unwrap_or_else(|| Lit::Str(LitStr::new(&index.to_string(), Span::call_site())))
+            .map(|ident| ident.to_string())
+            .unwrap_or_else(|| index.to_string());
+        Lit::Str(LitStr::new(
+            &field.attrs.reflect_name(name),
+            Span::call_site(),
+        ))
     }
 }
@@ -1,14 +1,16 @@
 use crate::derive_data::{EnumVariant, EnumVariantFields, ReflectEnum, StructField};
 use crate::enum_utility::{get_variant_constructors, EnumVariantConstructors};
 use crate::fq_std::{FQAny, FQBox, FQOption, FQResult};
-use crate::impls::impl_typed;
+use crate::impls::{impl_get_type_path, impl_type_path, impl_typed, with_capabilities_calls};
+use crate::utility::{ident_or_index, sorted_name_indices};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::Fields;
 
 pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
     let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let fq_box = FQBox(bevy_reflect_path);
     let enum_name = reflect_enum.meta().type_name();
 
     let ref_name = Ident::new("__name_param", Span::call_site());
@@ -17,8 +19,12 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
 
     let EnumImpls {
         variant_info,
+        static_variant_info,
+        variant_info_names,
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
@@ -39,11 +45,15 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
         .unwrap_or_else(|| {
             quote! {
                 fn reflect_hash(&self) -> #FQOption<u64> {
-                    #bevy_reflect_path::enum_hash(self)
+                    #bevy_reflect_path::__macro_exports::enum_hash(self)
                 }
             }
         });
     let debug_fn = reflect_enum.meta().traits().get_debug_impl();
+    let apply_fast_path = reflect_enum
+        .meta()
+        .traits()
+        .get_apply_fast_path(bevy_reflect_path, &quote!(#ref_value));
     let partial_eq_fn = reflect_enum
         .meta()
         .traits()
@@ -51,7 +61,18 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
         .unwrap_or_else(|| {
             quote! {
                 fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
-                    #bevy_reflect_path::enum_partial_eq(self, value)
+                    #bevy_reflect_path::__macro_exports::enum_partial_eq(self, value)
+                }
+            }
+        });
+    let partial_ord_fn = reflect_enum
+        .meta()
+        .traits()
+        .get_partial_ord_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering> {
+                    #bevy_reflect_path::__macro_exports::enum_partial_cmp(self, value)
                 }
             }
         });
@@ -59,32 +80,46 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
     let string_name = enum_name.to_string();
 
     #[cfg(feature = "documentation")]
-    let info_generator = {
+    let with_docs = {
         let doc = reflect_enum.meta().doc();
-        quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants).with_docs(#doc)
-        }
+        Some(quote!(.with_docs(#doc)))
     };
-
     #[cfg(not(feature = "documentation"))]
-    let info_generator = {
-        quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants)
-        }
-    };
+    let with_docs: Option<proc_macro2::TokenStream> = None;
+
+    let sorted_variants = sorted_name_indices(&variant_info_names);
 
+    let type_path_impl = impl_type_path(reflect_enum.meta());
+    let get_type_path_fn = impl_get_type_path(reflect_enum.meta());
+
+    let (with_capabilities, const_with_capabilities) = with_capabilities_calls(reflect_enum.meta());
     let typed_impl = impl_typed(
-        enum_name,
-        reflect_enum.meta().generics(),
+        reflect_enum.meta(),
         quote! {
             let variants = [#(#variant_info),*];
-            let info = #info_generator;
+            let info = #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants) #with_capabilities #with_docs;
             #bevy_reflect_path::TypeInfo::Enum(info)
         },
-        bevy_reflect_path,
+        quote! {
+            {
+                // Variant infos own their fields when built at runtime, so cannot be promoted
+                // to a `'static` slice within the arguments below
+                static VARIANTS: &[#bevy_reflect_path::VariantInfo] = &[#(#static_variant_info),*];
+                #bevy_reflect_path::TypeInfo::Enum(
+                    #bevy_reflect_path::EnumInfo::from_static::<#enum_name>(
+                        #string_name,
+                        VARIANTS,
+                        &[#(#variant_info_names),*],
+                        #sorted_variants,
+                    ) #const_with_capabilities #with_docs
+                )
+            }
+        },
     );
 
-    let get_type_registration_impl = reflect_enum.meta().get_type_registration();
+    let into_variant_fields_fn = impl_into_variant_fields(reflect_enum);
+
+    let get_type_registration_impl = reflect_enum.get_type_registration();
     let (impl_generics, ty_generics, where_clause) =
         reflect_enum.meta().generics().split_for_impl();
 
@@ -93,6 +128,8 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
 
         #typed_impl
 
+        #type_path_impl
+
         impl #impl_generics #bevy_reflect_path::Enum for #enum_name #ty_generics #where_clause {
             fn field(&self, #ref_name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                  match self {
@@ -110,14 +147,14 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
 
             fn field_mut(&mut self, #ref_name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                  match self {
-                    #(#enum_field,)*
+                    #(#enum_field_mut,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at_mut(&mut self, #ref_index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match self {
-                    #(#enum_field_at,)*
+                    #(#enum_field_at_mut,)*
                     _ => #FQOption::None,
                 }
             }
@@ -172,6 +209,8 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
                 }
             }
 
+            #into_variant_fields_fn
+
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicEnum {
                 #bevy_reflect_path::DynamicEnum::from_ref::<Self>(self)
             }
@@ -188,8 +227,10 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
                 <Self as #bevy_reflect_path::Typed>::type_info()
             }
 
+            #get_type_path_fn
+
             #[inline]
-            fn into_any(self: #FQBox<Self>) -> #FQBox<dyn #FQAny> {
+            fn into_any(self: #fq_box<Self>) -> #fq_box<dyn #FQAny> {
                 self
             }
 
@@ -204,7 +245,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
             }
 
             #[inline]
-            fn into_reflect(self: #FQBox<Self>) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+            fn into_reflect(self: #fq_box<Self>) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
                 self
             }
 
@@ -219,46 +260,28 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
             }
 
             #[inline]
-            fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
-                #FQBox::new(#bevy_reflect_path::Enum::clone_dynamic(self))
+            fn clone_value(&self) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
+                #fq_box::new(#bevy_reflect_path::Enum::clone_dynamic(self))
             }
 
             #[inline]
-            fn set(&mut self, #ref_value: #FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #FQBox<dyn #bevy_reflect_path::Reflect>> {
+            fn set(&mut self, #ref_value: #fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #fq_box<dyn #bevy_reflect_path::Reflect>> {
                 *self = <dyn #bevy_reflect_path::Reflect>::take(#ref_value)?;
                 #FQResult::Ok(())
             }
 
             #[inline]
             fn apply(&mut self, #ref_value: &dyn #bevy_reflect_path::Reflect) {
-                if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
-                    if #bevy_reflect_path::Enum::variant_name(self) == #bevy_reflect_path::Enum::variant_name(#ref_value) {
-                        // Same variant -> just update fields
-                        match #bevy_reflect_path::Enum::variant_type(#ref_value) {
-                            #bevy_reflect_path::VariantType::Struct => {
-                                for field in #bevy_reflect_path::Enum::iter_fields(#ref_value) {
-                                    let name = field.name().unwrap();
-                                    #bevy_reflect_path::Enum::field_mut(self, name).map(|v| v.apply(field.value()));
-                                }
-                            }
-                            #bevy_reflect_path::VariantType::Tuple => {
-                                for (index, field) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Enum::iter_fields(#ref_value)) {
-                                    #bevy_reflect_path::Enum::field_at_mut(self, index).map(|v| v.apply(field.value()));
-                                }
-                            }
-                            _ => {}
-                        }
-                    } else {
-                        // New variant -> perform a switch
-                        match #bevy_reflect_path::Enum::variant_name(#ref_value) {
-                            #(#variant_names => {
-                                *self = #variant_constructors
-                            })*
-                            name => panic!("variant with name `{}` does not exist on enum `{}`", name, ::core::any::type_name::<Self>()),
-                        }
+                #apply_fast_path
+                // Same variant -> the fields are updated by the shared helper
+                if let #FQOption::Some(#ref_value) = #bevy_reflect_path::__macro_exports::enum_apply_fields(self, #ref_value) {
+                    // New variant -> perform a switch
+                    match #bevy_reflect_path::Enum::variant_name(#ref_value) {
+                        #(#variant_names => {
+                            *self = #variant_constructors
+                        })*
+                        name => panic!("variant with name `{}` does not exist on enum `{}`", name, ::core::any::type_name::<Self>()),
                     }
-                } else {
-                    panic!("`{}` is not an enum", #bevy_reflect_path::Reflect::type_name(#ref_value));
                 }
             }
 
@@ -270,7 +293,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
                 #bevy_reflect_path::ReflectMut::Enum(self)
             }
 
-            fn reflect_owned(self: #FQBox<Self>) -> #bevy_reflect_path::ReflectOwned {
+            fn reflect_owned(self: #fq_box<Self>) -> #bevy_reflect_path::ReflectOwned {
                 #bevy_reflect_path::ReflectOwned::Enum(self)
             }
 
@@ -278,6 +301,8 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     })
@@ -285,8 +310,13 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
 
 struct EnumImpls {
     variant_info: Vec<proc_macro2::TokenStream>,
+    /// The variant infos built in a `const` context, for non-generic enums.
+    static_variant_info: Vec<proc_macro2::TokenStream>,
+    variant_info_names: Vec<String>,
     enum_field: Vec<proc_macro2::TokenStream>,
+    enum_field_mut: Vec<proc_macro2::TokenStream>,
     enum_field_at: Vec<proc_macro2::TokenStream>,
+    enum_field_at_mut: Vec<proc_macro2::TokenStream>,
     enum_index_of: Vec<proc_macro2::TokenStream>,
     enum_name_at: Vec<proc_macro2::TokenStream>,
     enum_field_len: Vec<proc_macro2::TokenStream>,
@@ -295,12 +325,61 @@ struct EnumImpls {
     enum_variant_type: Vec<proc_macro2::TokenStream>,
 }
 
+/// Generates `Enum::into_variant_fields`, which moves the fields of the current variant out,
+/// if `#[reflect(drain)]` was given.
+fn impl_into_variant_fields(reflect_enum: &ReflectEnum) -> Option<proc_macro2::TokenStream> {
+    if !reflect_enum.meta().traits().drain() {
+        return None;
+    }
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let fq_box = FQBox(bevy_reflect_path);
+
+    let arms = reflect_enum.variants().iter().map(|variant| {
+        let unit = reflect_enum.get_unit(&variant.data.ident);
+        let fields = match &variant.fields {
+            EnumVariantFields::Unit => &[],
+            EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields) => {
+                fields.as_slice()
+            }
+        };
+        let (bindings, owned_fields): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .filter(|field| field.attrs.ignore.is_active())
+            .enumerate()
+            .map(|(index, field)| {
+                let member = ident_or_index(field.data.ident.as_ref(), field.index);
+                let binding = format_ident!("__field_{}", index);
+                let owned = field.owned_reflect(bevy_reflect_path, quote!(#binding));
+                (
+                    quote!(#member: #binding),
+                    quote!(#owned as #fq_box<dyn #bevy_reflect_path::Reflect>),
+                )
+            })
+            .unzip();
+        quote! {
+            #unit { #(#bindings,)* .. } => #bevy_reflect_path::__macro_exports::alloc_utils::vec![#(#owned_fields),*]
+        }
+    });
+
+    Some(quote! {
+        fn into_variant_fields(self: #fq_box<Self>) -> #bevy_reflect_path::__macro_exports::alloc_utils::Vec<#fq_box<dyn #bevy_reflect_path::Reflect>> {
+            match *self {
+                #(#arms,)*
+            }
+        }
+    })
+}
+
 fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Ident) -> EnumImpls {
     let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
 
     let mut variant_info = Vec::new();
+    let mut static_variant_info = Vec::new();
+    let mut variant_info_names = Vec::new();
     let mut enum_field = Vec::new();
+    let mut enum_field_mut = Vec::new();
     let mut enum_field_at = Vec::new();
+    let mut enum_field_at_mut = Vec::new();
     let mut enum_index_of = Vec::new();
     let mut enum_name_at = Vec::new();
     let mut enum_field_len = Vec::new();
@@ -308,10 +387,16 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
     let mut enum_variant_index = Vec::new();
     let mut enum_variant_type = Vec::new();
 
+    let discriminants = reflect_enum.discriminants();
+
     for (variant_index, variant) in reflect_enum.variants().iter().enumerate() {
         let ident = &variant.data.ident;
-        let name = ident.to_string();
+        let name = variant.attrs.reflect_name(ident.to_string());
         let unit = reflect_enum.get_unit(ident);
+        let with_discriminant = discriminants.as_ref().map(|discriminants| {
+            let discriminant = &discriminants[variant_index];
+            quote!(.with_discriminant(#discriminant))
+        });
 
         let variant_type_ident = match variant.data.fields {
             Fields::Unit => Ident::new("Unit", Span::call_site()),
@@ -349,39 +434,55 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             constructor_argument
         }
 
-        let mut push_variant =
-            |_variant: &EnumVariant, arguments: proc_macro2::TokenStream, field_len: usize| {
-                #[cfg(feature = "documentation")]
-                let with_docs = {
-                    let doc = quote::ToTokens::to_token_stream(&_variant.doc);
-                    Some(quote!(.with_docs(#doc)))
-                };
-                #[cfg(not(feature = "documentation"))]
-                let with_docs: Option<proc_macro2::TokenStream> = None;
-
-                variant_info.push(quote! {
-                    #bevy_reflect_path::VariantInfo::#variant_type_ident(
-                        #bevy_reflect_path::#variant_info_ident::new(#arguments)
-                        #with_docs
-                    )
-                });
-                enum_field_len.push(quote! {
-                    #unit{..} => #field_len
-                });
-                enum_variant_type.push(quote! {
-                    #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
-                });
+        let mut push_variant = |_variant: &EnumVariant,
+                                arguments: proc_macro2::TokenStream,
+                                static_info: proc_macro2::TokenStream,
+                                field_len: usize| {
+            #[cfg(feature = "documentation")]
+            let with_docs = {
+                let doc = quote::ToTokens::to_token_stream(&_variant.doc);
+                Some(quote!(.with_docs(#doc)))
             };
+            #[cfg(not(feature = "documentation"))]
+            let with_docs: Option<proc_macro2::TokenStream> = None;
+
+            variant_info.push(quote! {
+                #bevy_reflect_path::VariantInfo::#variant_type_ident(
+                    #bevy_reflect_path::#variant_info_ident::new(#arguments)
+                    #with_discriminant
+                    #with_docs
+                )
+            });
+            static_variant_info.push(quote! {
+                #bevy_reflect_path::VariantInfo::#variant_type_ident(
+                    #static_info
+                    #with_discriminant
+                    #with_docs
+                )
+            });
+            enum_field_len.push(quote! {
+                #unit{..} => #field_len
+            });
+            enum_variant_type.push(quote! {
+                #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
+            });
+        };
 
         match &variant.fields {
             EnumVariantFields::Unit => {
-                push_variant(variant, quote!(#name), 0);
+                let static_info = quote!(#bevy_reflect_path::UnitVariantInfo::new(#name));
+                push_variant(variant, quote!(#name), static_info, 0);
             }
             EnumVariantFields::Unnamed(fields) => {
                 let args = get_field_args(fields, |reflect_idx, declaration_index, field| {
                     let declar_field = syn::Index::from(declaration_index);
+                    let field_ref = field.reflect_ref(quote!(value));
+                    let field_mut = field.reflect_mut(quote!(value));
                     enum_field_at.push(quote! {
-                        #unit { #declar_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(value)
+                        #unit { #declar_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_ref)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #unit { #declar_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_mut)
                     });
 
                     #[cfg(feature = "documentation")]
@@ -392,25 +493,43 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
-                    let field_ty = &field.data.ty;
+                    let field_info = field.info(bevy_reflect_path, quote!(#reflect_idx), false);
                     quote! {
-                        #bevy_reflect_path::UnnamedField::new::<#field_ty>(#reflect_idx)
+                        #field_info
                         #with_docs
                     }
                 });
 
                 let field_len = args.len();
-                push_variant(variant, quote!(#name, &[ #(#args),* ]), field_len);
+                let static_info = quote! {
+                    #bevy_reflect_path::TupleVariantInfo::from_static(#name, &[ #(#args),* ])
+                };
+                push_variant(
+                    variant,
+                    quote!(#name, &[ #(#args),* ]),
+                    static_info,
+                    field_len,
+                );
             }
             EnumVariantFields::Named(fields) => {
+                let mut field_names = Vec::new();
                 let args = get_field_args(fields, |reflect_idx, _, field| {
                     let field_ident = field.data.ident.as_ref().unwrap();
-                    let field_name = field_ident.to_string();
+                    let field_name = field.attrs.reflect_name(field_ident.to_string());
+                    let field_pattern = field.attrs.name_pattern(&field_name);
+                    let field_ref = field.reflect_ref(quote!(#field_ident));
+                    let field_mut = field.reflect_mut(quote!(#field_ident));
                     enum_field.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident, .. } if ::core::matches!(#ref_name, #field_pattern) => #FQOption::Some(#field_ref)
+                    });
+                    enum_field_mut.push(quote! {
+                        #unit{ #field_ident, .. } if ::core::matches!(#ref_name, #field_pattern) => #FQOption::Some(#field_mut)
                     });
                     enum_field_at.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_ref)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_mut)
                     });
                     enum_index_of.push(quote! {
                         #unit{ .. } if #ref_name == #field_name => #FQOption::Some(#reflect_idx)
@@ -418,6 +537,7 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     enum_name_at.push(quote! {
                         #unit{ .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_name)
                     });
+                    field_names.push(field_name.clone());
 
                     #[cfg(feature = "documentation")]
                     let with_docs = {
@@ -427,23 +547,42 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
-                    let field_ty = &field.data.ty;
+                    let field_info = field.info(bevy_reflect_path, quote!(#field_name), true);
                     quote! {
-                        #bevy_reflect_path::NamedField::new::<#field_ty>(#field_name)
+                        #field_info
                         #with_docs
                     }
                 });
 
                 let field_len = args.len();
-                push_variant(variant, quote!(#name, &[ #(#args),* ]), field_len);
+                let sorted_fields = sorted_name_indices(&field_names);
+                let static_info = quote! {
+                    #bevy_reflect_path::StructVariantInfo::from_static(
+                        #name,
+                        &[ #(#args),* ],
+                        &[ #(#field_names),* ],
+                        #sorted_fields,
+                    )
+                };
+                push_variant(
+                    variant,
+                    quote!(#name, &[ #(#args),* ]),
+                    static_info,
+                    field_len,
+                );
             }
         };
+        variant_info_names.push(name);
     }
 
     EnumImpls {
         variant_info,
+        static_variant_info,
+        variant_info_names,
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
@@ -7,5 +7,7 @@ mod values;
 pub(crate) use enums::impl_enum;
 pub(crate) use structs::impl_struct;
 pub(crate) use tuple_structs::impl_tuple_struct;
-pub(crate) use typed::impl_typed;
+pub(crate) use typed::{
+    impl_get_type_path, impl_type_path, impl_typed, type_capabilities, with_capabilities_calls,
+};
 pub(crate) use values::impl_value;
@@ -1,5 +1,6 @@
 use crate::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
-use crate::impls::impl_typed;
+use crate::impls::{impl_get_type_path, impl_type_path, impl_typed, with_capabilities_calls};
+use crate::utility::sorted_name_indices;
 use crate::ReflectStruct;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
@@ -10,19 +11,27 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
     let fqoption = FQOption.into_token_stream();
 
     let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+    let fq_box = FQBox(bevy_reflect_path);
     let struct_name = reflect_struct.meta().type_name();
 
     let field_names = reflect_struct
         .active_fields()
         .map(|field| {
-            field
-                .data
-                .ident
-                .as_ref()
-                .map(|i| i.to_string())
-                .unwrap_or_else(|| field.index.to_string())
+            field.attrs.reflect_name(
+                field
+                    .data
+                    .ident
+                    .as_ref()
+                    .map(|i| i.to_string())
+                    .unwrap_or_else(|| field.index.to_string()),
+            )
         })
         .collect::<Vec<String>>();
+    let field_patterns = reflect_struct
+        .active_fields()
+        .zip(&field_names)
+        .map(|(field, name)| field.attrs.name_pattern(name))
+        .collect::<Vec<_>>();
     let field_idents = reflect_struct
         .active_fields()
         .map(|field| {
@@ -34,7 +43,118 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
                 .unwrap_or_else(|| Member::Unnamed(Index::from(field.index)))
         })
         .collect::<Vec<_>>();
-    let field_types = reflect_struct.active_types();
+    let field_refs = reflect_struct
+        .active_fields()
+        .zip(&field_idents)
+        .map(|(field, member)| field.reflect_field_ref(member))
+        .collect::<Vec<_>>();
+    let field_muts = reflect_struct
+        .active_fields()
+        .zip(&field_idents)
+        .map(|(field, member)| match field.reflect_field_mut(member) {
+            Some(field_mut) => quote!(#FQOption::Some(#field_mut)),
+            None => quote!(#FQOption::None),
+        })
+        .collect::<Vec<_>>();
+    let (_, container_generics, _) = reflect_struct.meta().generics().split_for_impl();
+    let container = quote!(#struct_name #container_generics);
+    // The fields of a mirrored foreign type may not match its actual fields
+    let is_definition = reflect_struct.meta().is_definition();
+    let field_infos = reflect_struct
+        .active_fields()
+        .zip(&field_names)
+        .zip(&field_idents)
+        .map(|((field, name), member)| {
+            let info = field.info(bevy_reflect_path, quote!(#name), true);
+            let layout = field.layout(&container, member).filter(|_| is_definition);
+            quote!(#info #layout)
+        })
+        .collect::<Vec<_>>();
+    // Fields reflected through a `convert` module are only exposed as owned values
+    let converted_names = reflect_struct
+        .converted_fields()
+        .map(|field| {
+            let ident = field.data.ident.as_ref().unwrap();
+            field.attrs.reflect_name(ident.to_string())
+        })
+        .collect::<Vec<_>>();
+    let converted_values = reflect_struct
+        .converted_fields()
+        .map(|field| {
+            let module = field.attrs.convert.as_ref().unwrap();
+            let ident = field.data.ident.as_ref().unwrap();
+            quote! {
+                #fq_box::new(#module::to_reflect(&self.#ident)) as #fq_box<dyn #bevy_reflect_path::Reflect>
+            }
+        })
+        .collect::<Vec<_>>();
+    let converted_infos = reflect_struct
+        .converted_fields()
+        .zip(&converted_names)
+        .map(|(field, name)| {
+            let module = field.attrs.convert.as_ref().unwrap();
+            quote!(#bevy_reflect_path::__macro_exports::converted_named_field(#name, #module::to_reflect))
+        })
+        .collect::<Vec<_>>();
+    let setter_arms = reflect_struct
+        .active_fields()
+        .zip(&field_patterns)
+        .filter_map(|(field, pattern)| {
+            let apply = field.apply_with_setter(bevy_reflect_path, quote!(self), quote!(value))?;
+            Some(quote!(#pattern => #apply,))
+        })
+        .chain(reflect_struct.converted_fields().zip(&converted_names).map(|(field, name)| {
+            let module = field.attrs.convert.as_ref().unwrap();
+            let ident = field.data.ident.as_ref().unwrap();
+            let pattern = field.attrs.name_pattern(name);
+            quote! {
+                #pattern => match #module::from_reflect(value) {
+                    #FQOption::Some(__value) => self.#ident = __value,
+                    #FQOption::None => panic!(
+                        "Attempted to apply a value of type `{}` to a converted field, but it could not be converted",
+                        #bevy_reflect_path::Reflect::type_name(value)
+                    ),
+                },
+            }
+        }))
+        .collect::<Vec<_>>();
+    // Without any setters or converted fields, applying goes through a shared helper
+    // rather than being generated for every type
+    let apply_fields = if setter_arms.is_empty() {
+        quote! {
+            #bevy_reflect_path::__macro_exports::struct_apply(self, value);
+        }
+    } else {
+        quote! {
+            if let #bevy_reflect_path::ReflectRef::Struct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
+                let converted = #bevy_reflect_path::Struct::converted_fields(struct_value);
+                let fields = ::core::iter::Iterator::map(0..#bevy_reflect_path::Struct::field_len(struct_value), |i| {
+                    (
+                        #bevy_reflect_path::Struct::name_at(struct_value, i).unwrap(),
+                        #bevy_reflect_path::Struct::field_at(struct_value, i).unwrap(),
+                    )
+                });
+                let converted = ::core::iter::Iterator::map(converted.iter(), |(name, value)| (*name, &**value));
+                for (name, value) in ::core::iter::Iterator::chain(fields, converted) {
+                    match name {
+                        #(#setter_arms)*
+                        _ => {
+                            #bevy_reflect_path::Struct::field_mut(self, name).map(|v| v.apply(value));
+                        }
+                    }
+                }
+            } else {
+                panic!("Attempted to apply non-struct type to struct type.");
+            }
+        }
+    };
+    let converted_fields_fn = (!converted_names.is_empty()).then(|| {
+        quote! {
+            fn converted_fields(&self) -> #bevy_reflect_path::__macro_exports::alloc_utils::Vec<(&str, #fq_box<dyn #bevy_reflect_path::Reflect>)> {
+                #bevy_reflect_path::__macro_exports::alloc_utils::vec![#((#converted_names, #converted_values)),*]
+            }
+        }
+    });
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
 
@@ -43,61 +163,119 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
         .traits()
         .get_hash_impl(bevy_reflect_path);
     let debug_fn = reflect_struct.meta().traits().get_debug_impl();
+    let apply_fast_path = reflect_struct
+        .meta()
+        .traits()
+        .get_apply_fast_path(bevy_reflect_path, &quote!(value));
     let partial_eq_fn = reflect_struct.meta()
         .traits()
         .get_partial_eq_impl(bevy_reflect_path)
         .unwrap_or_else(|| {
             quote! {
                 fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
-                    #bevy_reflect_path::struct_partial_eq(self, value)
+                    #bevy_reflect_path::__macro_exports::struct_partial_eq(self, value)
+                }
+            }
+        });
+    let partial_ord_fn = reflect_struct
+        .meta()
+        .traits()
+        .get_partial_ord_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering> {
+                    #bevy_reflect_path::__macro_exports::struct_partial_cmp(self, value)
                 }
             }
         });
 
     #[cfg(feature = "documentation")]
-    let field_generator = {
+    let (field_generator, converted_generator) = {
         let docs = reflect_struct
             .active_fields()
             .map(|field| quote::ToTokens::to_token_stream(&field.doc));
-        quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) ,)*
-        }
+        let converted_docs = reflect_struct
+            .converted_fields()
+            .map(|field| quote::ToTokens::to_token_stream(&field.doc));
+        (
+            quote!(#(#field_infos.with_docs(#docs) ,)*),
+            quote!(#(#converted_infos.with_docs(#converted_docs) ,)*),
+        )
     };
 
     #[cfg(not(feature = "documentation"))]
-    let field_generator = {
-        quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) ,)*
-        }
+    let (field_generator, converted_generator) =
+        (quote!(#(#field_infos ,)*), quote!(#(#converted_infos ,)*));
+
+    let with_converted_fields = if converted_names.is_empty() {
+        None
+    } else {
+        Some(quote!(.with_converted_fields(&[#converted_generator])))
     };
 
     let string_name = struct_name.to_string();
 
     #[cfg(feature = "documentation")]
-    let info_generator = {
+    let with_docs = {
         let doc = reflect_struct.meta().doc();
-        quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields).with_docs(#doc)
-        }
+        Some(quote!(.with_docs(#doc)))
     };
-
     #[cfg(not(feature = "documentation"))]
-    let info_generator = {
-        quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields)
-        }
+    let with_docs: Option<proc_macro2::TokenStream> = None;
+
+    let sorted_fields = sorted_name_indices(&field_names);
+
+    let mut reflected_before = 0_usize;
+    let ignored_fields = reflect_struct
+        .fields()
+        .iter()
+        .filter_map(|field| {
+            if field.attrs.convert.is_some() {
+                return None;
+            }
+            if field.attrs.ignore.is_active() {
+                reflected_before += 1;
+                return None;
+            }
+            let name = field.data.ident.as_ref()?.to_string();
+            Some(quote!((#reflected_before, #name)))
+        })
+        .collect::<Vec<_>>();
+    let with_ignored_fields = if ignored_fields.is_empty() {
+        None
+    } else {
+        Some(quote!(.with_ignored_fields(&[#(#ignored_fields),*])))
     };
 
-    let typed_impl = impl_typed(
-        struct_name,
-        reflect_struct.meta().generics(),
-        quote! {
-            let fields = [#field_generator];
-            let info = #info_generator;
-            #bevy_reflect_path::TypeInfo::Struct(info)
-        },
-        bevy_reflect_path,
-    );
+    let type_path_impl = impl_type_path(reflect_struct.meta());
+    let get_type_path_fn = impl_get_type_path(reflect_struct.meta());
+
+    let (with_capabilities, const_with_capabilities) =
+        with_capabilities_calls(reflect_struct.meta());
+    let generator = quote! {
+        let fields = [#field_generator];
+        let info = #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields) #with_capabilities #with_ignored_fields #with_converted_fields #with_docs;
+        #bevy_reflect_path::TypeInfo::Struct(info)
+    };
+    // The infos of converted fields are owned, so they cannot be built in a `const` context
+    let const_generator = if with_converted_fields.is_some() {
+        generator.clone()
+    } else {
+        // The fields are kept in their own static, as the blocks computing
+        // their offsets prevent the array from being promoted to a `'static` reference
+        quote! {{
+            static FIELDS: [#bevy_reflect_path::NamedField; #field_count] = [#field_generator];
+            #bevy_reflect_path::TypeInfo::Struct(
+                #bevy_reflect_path::StructInfo::from_static::<#struct_name>(
+                    #string_name,
+                    &FIELDS,
+                    &[#(#field_names),*],
+                    #sorted_fields,
+                ) #const_with_capabilities #with_ignored_fields #with_docs
+            )
+        }}
+    };
+    let typed_impl = impl_typed(reflect_struct.meta(), generator, const_generator);
 
     let get_type_registration_impl = reflect_struct.get_type_registration();
     let (impl_generics, ty_generics, where_clause) =
@@ -108,31 +286,33 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
 
         #typed_impl
 
+        #type_path_impl
+
         impl #impl_generics #bevy_reflect_path::Struct for #struct_name #ty_generics #where_clause {
             fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match name {
-                    #(#field_names => #fqoption::Some(&self.#field_idents),)*
+                    #(#field_patterns => #fqoption::Some(#field_refs),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_mut(&mut self, name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match name {
-                    #(#field_names => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#field_patterns => #field_muts,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at(&self, index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&self.#field_idents),)*
+                    #(#field_indices => #fqoption::Some(#field_refs),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at_mut(&mut self, index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#field_indices => #field_muts,)*
                     _ => #FQOption::None,
                 }
             }
@@ -154,10 +334,14 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
 
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicStruct {
                 let mut dynamic: #bevy_reflect_path::DynamicStruct = #FQDefault::default();
-                dynamic.set_name(::std::string::ToString::to_string(#bevy_reflect_path::Reflect::type_name(self)));
-                #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
+                dynamic.set_name(#bevy_reflect_path::__macro_exports::alloc_utils::ToString::to_string(#bevy_reflect_path::Reflect::type_name(self)));
+                dynamic.set_inline_values(true);
+                #(dynamic.insert_cloned(#field_names, #field_refs);)*
+                #(dynamic.insert_boxed(#converted_names, #converted_values);)*
                 dynamic
             }
+
+            #converted_fields_fn
         }
 
         impl #impl_generics #bevy_reflect_path::Reflect for #struct_name #ty_generics #where_clause {
@@ -171,8 +355,10 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
                 <Self as #bevy_reflect_path::Typed>::type_info()
             }
 
+            #get_type_path_fn
+
             #[inline]
-            fn into_any(self: #FQBox<Self>) -> #FQBox<dyn #FQAny> {
+            fn into_any(self: #fq_box<Self>) -> #fq_box<dyn #FQAny> {
                 self
             }
 
@@ -187,7 +373,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
             }
 
             #[inline]
-            fn into_reflect(self: #FQBox<Self>) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+            fn into_reflect(self: #fq_box<Self>) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
                 self
             }
 
@@ -202,26 +388,20 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
             }
 
             #[inline]
-            fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
-                #FQBox::new(#bevy_reflect_path::Struct::clone_dynamic(self))
+            fn clone_value(&self) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
+                #fq_box::new(#bevy_reflect_path::Struct::clone_dynamic(self))
             }
 
             #[inline]
-            fn set(&mut self, value: #FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #FQBox<dyn #bevy_reflect_path::Reflect>> {
+            fn set(&mut self, value: #fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #fq_box<dyn #bevy_reflect_path::Reflect>> {
                 *self = <dyn #bevy_reflect_path::Reflect>::take(value)?;
                 #FQResult::Ok(())
             }
 
             #[inline]
             fn apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) {
-                if let #bevy_reflect_path::ReflectRef::Struct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
-                    for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Struct::iter_fields(struct_value)) {
-                        let name = #bevy_reflect_path::Struct::name_at(struct_value, i).unwrap();
-                        #bevy_reflect_path::Struct::field_mut(self, name).map(|v| v.apply(value));
-                    }
-                } else {
-                    panic!("Attempted to apply non-struct type to struct type.");
-                }
+                #apply_fast_path
+                #apply_fields
             }
 
             fn reflect_ref(&self) -> #bevy_reflect_path::ReflectRef {
@@ -232,7 +412,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
                 #bevy_reflect_path::ReflectMut::Struct(self)
             }
 
-            fn reflect_owned(self: #FQBox<Self>) -> #bevy_reflect_path::ReflectOwned {
+            fn reflect_owned(self: #fq_box<Self>) -> #bevy_reflect_path::ReflectOwned {
                 #bevy_reflect_path::ReflectOwned::Struct(self)
             }
 
@@ -240,6 +420,8 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     })
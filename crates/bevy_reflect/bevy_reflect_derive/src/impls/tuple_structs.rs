@@ -1,5 +1,5 @@
 use crate::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
-use crate::impls::impl_typed;
+use crate::impls::{impl_get_type_path, impl_type_path, impl_typed, with_capabilities_calls};
 use crate::ReflectStruct;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
@@ -10,6 +10,7 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
     let fqoption = FQOption.into_token_stream();
 
     let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+    let fq_box = FQBox(bevy_reflect_path);
     let struct_name = reflect_struct.meta().type_name();
     let get_type_registration_impl = reflect_struct.get_type_registration();
 
@@ -17,15 +18,71 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
         .active_fields()
         .map(|field| Member::Unnamed(Index::from(field.index)))
         .collect::<Vec<_>>();
-    let field_types = reflect_struct.active_types();
+    let field_refs = reflect_struct
+        .active_fields()
+        .zip(&field_idents)
+        .map(|(field, member)| field.reflect_field_ref(member))
+        .collect::<Vec<_>>();
+    let field_muts = reflect_struct
+        .active_fields()
+        .zip(&field_idents)
+        .map(|(field, member)| match field.reflect_field_mut(member) {
+            Some(field_mut) => quote!(#FQOption::Some(#field_mut)),
+            None => quote!(#FQOption::None),
+        })
+        .collect::<Vec<_>>();
+    let (_, container_generics, _) = reflect_struct.meta().generics().split_for_impl();
+    let container = quote!(#struct_name #container_generics);
+    let field_infos = reflect_struct
+        .active_fields()
+        .zip(&field_idents)
+        .map(|(field, member)| {
+            let info = field.info(bevy_reflect_path, quote!(#member), false);
+            let layout = field.layout(&container, member);
+            quote!(#info #layout)
+        })
+        .collect::<Vec<_>>();
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
+    let setter_arms = reflect_struct
+        .active_fields()
+        .zip(&field_indices)
+        .filter_map(|(field, index)| {
+            let apply = field.apply_with_setter(bevy_reflect_path, quote!(self), quote!(value))?;
+            Some(quote!(#index => #apply,))
+        })
+        .collect::<Vec<_>>();
+    // Without any setters, applying goes through a shared helper rather than being generated for every type
+    let apply_fields = if setter_arms.is_empty() {
+        quote! {
+            #bevy_reflect_path::__macro_exports::tuple_struct_apply(self, value);
+        }
+    } else {
+        quote! {
+            if let #bevy_reflect_path::ReflectRef::TupleStruct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
+                for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::TupleStruct::iter_fields(struct_value)) {
+                    match i {
+                        #(#setter_arms)*
+                        _ => {
+                            #bevy_reflect_path::TupleStruct::field_mut(self, i).map(|v| v.apply(value));
+                        }
+                    }
+                }
+            } else {
+                panic!("Attempted to apply non-TupleStruct type to TupleStruct type.");
+            }
+        }
+    };
 
     let hash_fn = reflect_struct
         .meta()
         .traits()
         .get_hash_impl(bevy_reflect_path);
     let debug_fn = reflect_struct.meta().traits().get_debug_impl();
+    let apply_fast_path = reflect_struct
+        .meta()
+        .traits()
+        .get_apply_fast_path(bevy_reflect_path, &quote!(value));
     let partial_eq_fn = reflect_struct
         .meta()
         .traits()
@@ -33,7 +90,18 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
         .unwrap_or_else(|| {
             quote! {
                 fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
-                    #bevy_reflect_path::tuple_struct_partial_eq(self, value)
+                    #bevy_reflect_path::__macro_exports::tuple_struct_partial_eq(self, value)
+                }
+            }
+        });
+    let partial_ord_fn = reflect_struct
+        .meta()
+        .traits()
+        .get_partial_ord_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering> {
+                    #bevy_reflect_path::__macro_exports::tuple_struct_partial_cmp(self, value)
                 }
             }
         });
@@ -44,45 +112,80 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
             .active_fields()
             .map(|field| quote::ToTokens::to_token_stream(&field.doc));
         quote! {
-            #(#bevy_reflect_path::UnnamedField::new::<#field_types>(#field_idents).with_docs(#docs) ,)*
+            #(#field_infos.with_docs(#docs) ,)*
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let field_generator = {
         quote! {
-            #(#bevy_reflect_path::UnnamedField::new::<#field_types>(#field_idents) ,)*
+            #(#field_infos ,)*
         }
     };
 
     let string_name = struct_name.to_string();
 
     #[cfg(feature = "documentation")]
-    let info_generator = {
+    let with_docs = {
         let doc = reflect_struct.meta().doc();
-        quote! {
-           #bevy_reflect_path::TupleStructInfo::new::<Self>(#string_name, &fields).with_docs(#doc)
-        }
+        Some(quote!(.with_docs(#doc)))
     };
-
     #[cfg(not(feature = "documentation"))]
-    let info_generator = {
-        quote! {
-            #bevy_reflect_path::TupleStructInfo::new::<Self>(#string_name, &fields)
-        }
-    };
+    let with_docs: Option<proc_macro2::TokenStream> = None;
+
+    let type_path_impl = impl_type_path(reflect_struct.meta());
+    let get_type_path_fn = impl_get_type_path(reflect_struct.meta());
 
+    let (with_capabilities, const_with_capabilities) =
+        with_capabilities_calls(reflect_struct.meta());
     let typed_impl = impl_typed(
-        struct_name,
-        reflect_struct.meta().generics(),
+        reflect_struct.meta(),
         quote! {
             let fields = [#field_generator];
-            let info = #info_generator;
+            let info = #bevy_reflect_path::TupleStructInfo::new::<Self>(#string_name, &fields) #with_capabilities #with_docs;
             #bevy_reflect_path::TypeInfo::TupleStruct(info)
         },
-        bevy_reflect_path,
+        quote! {{
+            static FIELDS: [#bevy_reflect_path::UnnamedField; #field_count] = [#field_generator];
+            #bevy_reflect_path::TypeInfo::TupleStruct(
+                #bevy_reflect_path::TupleStructInfo::from_static::<#struct_name>(
+                    #string_name,
+                    &FIELDS,
+                ) #const_with_capabilities #with_docs
+            )
+        }},
     );
 
+    // Fields with a getter may not be stored in the struct, so are cloned before it is taken apart
+    let drain_fn = reflect_struct.meta().traits().drain().then(|| {
+        let mut getter_bindings = Vec::new();
+        let owned_fields = reflect_struct
+            .active_fields()
+            .zip(&field_idents)
+            .map(|(field, member)| match &field.attrs.get {
+                Some(getter) => {
+                    let binding = quote::format_ident!("__field_{}", getter_bindings.len());
+                    let field_ref = field.reflect_ref(quote!(#getter(&*self)));
+                    getter_bindings.push(quote! {
+                        let #binding = #bevy_reflect_path::Reflect::clone_value(#field_ref);
+                    });
+                    quote!(#binding)
+                }
+                None => {
+                    let owned = field.owned_reflect(bevy_reflect_path, quote!(__this.#member));
+                    quote!(#owned as #fq_box<dyn #bevy_reflect_path::Reflect>)
+                }
+            })
+            .collect::<Vec<_>>();
+        quote! {
+            fn drain(self: #fq_box<Self>) -> #bevy_reflect_path::__macro_exports::alloc_utils::Vec<#fq_box<dyn #bevy_reflect_path::Reflect>> {
+                #(#getter_bindings)*
+                let __this = *self;
+                #bevy_reflect_path::__macro_exports::alloc_utils::vec![#(#owned_fields),*]
+            }
+        }
+    });
+
     let (impl_generics, ty_generics, where_clause) =
         reflect_struct.meta().generics().split_for_impl();
 
@@ -91,17 +194,19 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
 
         #typed_impl
 
+        #type_path_impl
+
         impl #impl_generics #bevy_reflect_path::TupleStruct for #struct_name #ty_generics #where_clause {
             fn field(&self, index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&self.#field_idents),)*
+                    #(#field_indices => #fqoption::Some(#field_refs),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_mut(&mut self, index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#field_indices => #field_muts,)*
                     _ => #FQOption::None,
                 }
             }
@@ -114,10 +219,12 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
                 #bevy_reflect_path::TupleStructFieldIter::new(self)
             }
 
+            #drain_fn
+
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicTupleStruct {
                 let mut dynamic: #bevy_reflect_path::DynamicTupleStruct = #FQDefault::default();
-                dynamic.set_name(::std::string::ToString::to_string(#bevy_reflect_path::Reflect::type_name(self)));
-                #(dynamic.insert_boxed(#bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
+                dynamic.set_name(#bevy_reflect_path::__macro_exports::alloc_utils::ToString::to_string(#bevy_reflect_path::Reflect::type_name(self)));
+                #(dynamic.insert_cloned(#field_refs);)*
                 dynamic
             }
         }
@@ -133,8 +240,10 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
                 <Self as #bevy_reflect_path::Typed>::type_info()
             }
 
+            #get_type_path_fn
+
             #[inline]
-            fn into_any(self: #FQBox<Self>) -> #FQBox<dyn #FQAny> {
+            fn into_any(self: #fq_box<Self>) -> #fq_box<dyn #FQAny> {
                 self
             }
 
@@ -149,7 +258,7 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
             }
 
             #[inline]
-            fn into_reflect(self: #FQBox<Self>) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+            fn into_reflect(self: #fq_box<Self>) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
                 self
             }
 
@@ -164,25 +273,20 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
             }
 
             #[inline]
-            fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
-                #FQBox::new(#bevy_reflect_path::TupleStruct::clone_dynamic(self))
+            fn clone_value(&self) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
+                #fq_box::new(#bevy_reflect_path::TupleStruct::clone_dynamic(self))
             }
 
             #[inline]
-            fn set(&mut self, value: #FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #FQBox<dyn #bevy_reflect_path::Reflect>> {
+            fn set(&mut self, value: #fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #fq_box<dyn #bevy_reflect_path::Reflect>> {
                 *self = <dyn #bevy_reflect_path::Reflect>::take(value)?;
                 #FQResult::Ok(())
             }
 
             #[inline]
             fn apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) {
-                if let #bevy_reflect_path::ReflectRef::TupleStruct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
-                    for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::TupleStruct::iter_fields(struct_value)) {
-                        #bevy_reflect_path::TupleStruct::field_mut(self, i).map(|v| v.apply(value));
-                    }
-                } else {
-                    panic!("Attempted to apply non-TupleStruct type to TupleStruct type.");
-                }
+                #apply_fast_path
+                #apply_fields
             }
 
             fn reflect_ref(&self) -> #bevy_reflect_path::ReflectRef {
@@ -193,7 +297,7 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
                 #bevy_reflect_path::ReflectMut::TupleStruct(self)
             }
 
-            fn reflect_owned(self: #FQBox<Self>) -> #bevy_reflect_path::ReflectOwned {
+            fn reflect_owned(self: #fq_box<Self>) -> #bevy_reflect_path::ReflectOwned {
                 #bevy_reflect_path::ReflectOwned::TupleStruct(self)
             }
 
@@ -201,6 +305,8 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> TokenStream {
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     })
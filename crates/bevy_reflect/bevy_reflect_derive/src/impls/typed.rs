@@ -1,15 +1,39 @@
-use proc_macro2::Ident;
+use crate::fq_std::FQOption;
+use crate::utility::contains_self;
+use crate::ReflectMeta;
 use quote::quote;
-use syn::{Generics, Path};
+use syn::{GenericParam, Path};
 
+/// Implements `Typed` for the given type.
+///
+/// The `TypeInfo` of a non-generic type is built by `const_generator` into a static, so that it
+/// costs nothing to access the first time. As a nested static cannot refer to `Self`,
+/// `const_generator` must name the type instead, and `generator` is used if one of the
+/// field types refers to `Self`. Generic types are always built by `generator`, on first access.
+///
+/// Both generators are replaced by a bare `ValueInfo` if [`ReflectTraits::no_type_info`] is set.
+///
+/// [`ReflectTraits::no_type_info`]: crate::container_attributes::ReflectTraits::no_type_info
 pub(crate) fn impl_typed(
-    type_name: &Ident,
-    generics: &Generics,
+    meta: &ReflectMeta,
     generator: proc_macro2::TokenStream,
-    bevy_reflect_path: &Path,
+    const_generator: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let type_name = meta.type_name();
+    let generics = meta.generics();
     let is_generic = !generics.params.is_empty();
 
+    let (generator, const_generator) = if meta.traits().no_type_info() {
+        let (with_capabilities, const_with_capabilities) = with_capabilities_calls(meta);
+        (
+            quote!(#bevy_reflect_path::TypeInfo::Value(#bevy_reflect_path::ValueInfo::new::<Self>() #with_capabilities)),
+            quote!(#bevy_reflect_path::TypeInfo::Value(#bevy_reflect_path::ValueInfo::new::<#type_name>() #const_with_capabilities)),
+        )
+    } else {
+        (generator, const_generator)
+    };
+
     let static_generator = if is_generic {
         quote! {
             static CELL: #bevy_reflect_path::utility::GenericTypeInfoCell = #bevy_reflect_path::utility::GenericTypeInfoCell::new();
@@ -17,13 +41,18 @@ pub(crate) fn impl_typed(
                 #generator
             })
         }
-    } else {
+    } else if contains_self(const_generator.clone()) {
         quote! {
             static CELL: #bevy_reflect_path::utility::NonGenericTypeInfoCell = #bevy_reflect_path::utility::NonGenericTypeInfoCell::new();
             CELL.get_or_set(|| {
                 #generator
             })
         }
+    } else {
+        quote! {
+            static INFO: #bevy_reflect_path::TypeInfo = #const_generator;
+            &INFO
+        }
     };
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -36,3 +65,141 @@ pub(crate) fn impl_typed(
         }
     }
 }
+
+/// Returns the calls adding the `TypeCapabilities` of the type to its `TypeInfo`,
+/// to be appended to the info built by the generators given to [`impl_typed`].
+///
+/// The first call names the type as `Self`, to be used in `generator`,
+/// and the second one by its name, to be used in `const_generator`.
+pub(crate) fn with_capabilities_calls(
+    meta: &ReflectMeta,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let type_name = meta.type_name();
+    let capabilities = type_capabilities(bevy_reflect_path, &quote!(Self));
+    let const_capabilities = type_capabilities(bevy_reflect_path, &quote!(#type_name));
+    (
+        quote!(.with_capabilities(#capabilities)),
+        quote!(.with_capabilities(#const_capabilities)),
+    )
+}
+
+/// Returns an expression building the `TypeCapabilities` of `ty`.
+///
+/// Marker traits are detected through the inherent constants of `CapabilityProbe`,
+/// which only exist if `ty` implements the trait, falling back to the `false` constants
+/// of the `CapabilityFallback` trait otherwise.
+pub(crate) fn type_capabilities(
+    bevy_reflect_path: &Path,
+    ty: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let probe = quote!(#bevy_reflect_path::__macro_exports::CapabilityProbe<#ty>);
+    quote! {{
+        use #bevy_reflect_path::__macro_exports::CapabilityFallback as _;
+        #bevy_reflect_path::TypeCapabilities {
+            is_send: <#probe>::IS_SEND,
+            is_sync: <#probe>::IS_SYNC,
+            is_copy: <#probe>::IS_COPY,
+            needs_drop: ::core::mem::needs_drop::<#ty>(),
+        }
+    }}
+}
+
+/// Implements `TypePath` for the given type, if [`ReflectMeta::impl_type_path`] is set.
+///
+/// The path is made up of the module the macro is expanded in and the type's name.
+/// The paths of generic types also list their type and const parameters, and are built
+/// the first time they are requested.
+pub(crate) fn impl_type_path(meta: &ReflectMeta) -> Option<proc_macro2::TokenStream> {
+    if !meta.impl_type_path() {
+        return None;
+    }
+
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let type_name = meta.type_name();
+    let ident = type_name.to_string();
+    let (impl_generics, ty_generics, where_clause) = meta.generics().split_for_impl();
+
+    // Lifetimes are left out of the path, as reflected types can only use `'static`
+    let params = meta
+        .generics()
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some((&param.ident, true)),
+            GenericParam::Const(param) => Some((&param.ident, false)),
+            GenericParam::Lifetime(..) => None,
+        })
+        .collect::<Vec<_>>();
+    let param_paths = |path_fn: proc_macro2::TokenStream| {
+        params.iter().map(move |(ident, is_type)| {
+            if *is_type {
+                quote!(#bevy_reflect_path::__macro_exports::alloc_utils::ToString::to_string(<#ident as #bevy_reflect_path::TypePath>::#path_fn()))
+            } else {
+                quote!(#bevy_reflect_path::__macro_exports::alloc_utils::ToString::to_string(&#ident))
+            }
+        })
+    };
+
+    let (type_path, short_type_path) = if params.is_empty() {
+        (
+            quote!(::core::concat!(::core::module_path!(), "::", #ident)),
+            quote!(#ident),
+        )
+    } else {
+        let type_params = param_paths(quote!(type_path));
+        let short_params = param_paths(quote!(short_type_path));
+        (
+            quote! {
+                static CELL: #bevy_reflect_path::utility::GenericTypePathCell = #bevy_reflect_path::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    #bevy_reflect_path::__macro_exports::alloc_utils::format!("{}::{}<{}>", ::core::module_path!(), #ident, [#(#type_params),*].join(", "))
+                })
+            },
+            quote! {
+                static CELL: #bevy_reflect_path::utility::GenericTypePathCell = #bevy_reflect_path::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    #bevy_reflect_path::__macro_exports::alloc_utils::format!("{}<{}>", #ident, [#(#short_params),*].join(", "))
+                })
+            },
+        )
+    };
+
+    Some(quote! {
+        impl #impl_generics #bevy_reflect_path::TypePath for #type_name #ty_generics #where_clause {
+            fn type_path() -> &'static str {
+                #type_path
+            }
+
+            fn short_type_path() -> &'static str {
+                #short_type_path
+            }
+
+            fn type_ident() -> #FQOption<&'static str> {
+                #FQOption::Some(#ident)
+            }
+
+            fn crate_name() -> #FQOption<&'static str> {
+                ::core::module_path!().split("::").next()
+            }
+
+            fn module_path() -> #FQOption<&'static str> {
+                #FQOption::Some(::core::module_path!())
+            }
+        }
+    })
+}
+
+/// Returns the `Reflect::get_type_path` method for types implementing `TypePath`
+/// through [`impl_type_path`].
+pub(crate) fn impl_get_type_path(meta: &ReflectMeta) -> Option<proc_macro2::TokenStream> {
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    meta.impl_type_path().then(|| {
+        quote! {
+            #[inline]
+            fn get_type_path(&self) -> #FQOption<&dyn #bevy_reflect_path::DynamicTypePath> {
+                #FQOption::Some(self)
+            }
+        }
+    })
+}
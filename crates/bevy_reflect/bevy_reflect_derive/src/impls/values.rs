@@ -1,5 +1,5 @@
 use crate::fq_std::{FQAny, FQBox, FQClone, FQOption, FQResult};
-use crate::impls::impl_typed;
+use crate::impls::{impl_get_type_path, impl_type_path, impl_typed, with_capabilities_calls};
 use crate::ReflectMeta;
 use proc_macro::TokenStream;
 use quote::quote;
@@ -7,11 +7,23 @@ use quote::quote;
 /// Implements `GetTypeRegistration` and `Reflect` for the given type data.
 pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     let bevy_reflect_path = meta.bevy_reflect_path();
+    let fq_box = FQBox(bevy_reflect_path);
     let type_name = meta.type_name();
 
     let hash_fn = meta.traits().get_hash_impl(bevy_reflect_path);
     let partial_eq_fn = meta.traits().get_partial_eq_impl(bevy_reflect_path);
+    let partial_ord_fn = meta.traits().get_partial_ord_impl(bevy_reflect_path);
     let debug_fn = meta.traits().get_debug_impl();
+    let apply_from_repr = meta
+        .traits()
+        .get_from_repr_conversion(bevy_reflect_path, quote!(value))
+        .map(|conversion| {
+            quote! {
+                else if let #FQOption::Some(value) = #conversion {
+                    *self = value;
+                }
+            }
+        });
 
     #[cfg(feature = "documentation")]
     let with_docs = {
@@ -21,14 +33,21 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     #[cfg(not(feature = "documentation"))]
     let with_docs: Option<proc_macro2::TokenStream> = None;
 
+    let type_path_impl = impl_type_path(meta);
+    let get_type_path_fn = impl_get_type_path(meta);
+
+    let (with_capabilities, const_with_capabilities) = with_capabilities_calls(meta);
     let typed_impl = impl_typed(
-        type_name,
-        meta.generics(),
+        meta,
         quote! {
-            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs;
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_capabilities #with_docs;
             #bevy_reflect_path::TypeInfo::Value(info)
         },
-        bevy_reflect_path,
+        quote! {
+            #bevy_reflect_path::TypeInfo::Value(
+                #bevy_reflect_path::ValueInfo::new::<#type_name>() #const_with_capabilities #with_docs
+            )
+        },
     );
 
     let (impl_generics, ty_generics, where_clause) = meta.generics().split_for_impl();
@@ -39,6 +58,8 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
 
         #typed_impl
 
+        #type_path_impl
+
         impl #impl_generics #bevy_reflect_path::Reflect for #type_name #ty_generics #where_clause  {
             #[inline]
             fn type_name(&self) -> &str {
@@ -50,8 +71,10 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
                 <Self as #bevy_reflect_path::Typed>::type_info()
             }
 
+            #get_type_path_fn
+
             #[inline]
-            fn into_any(self: #FQBox<Self>) -> #FQBox<dyn #FQAny> {
+            fn into_any(self: #fq_box<Self>) -> #fq_box<dyn #FQAny> {
                 self
             }
 
@@ -66,7 +89,7 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
             }
 
             #[inline]
-            fn into_reflect(self: #FQBox<Self>) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+            fn into_reflect(self: #fq_box<Self>) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
                 self
             }
 
@@ -81,22 +104,21 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
             }
 
             #[inline]
-            fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
-                #FQBox::new(#FQClone::clone(self))
+            fn clone_value(&self) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
+                #fq_box::new(#FQClone::clone(self))
             }
 
             #[inline]
             fn apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) {
-                let value = #bevy_reflect_path::Reflect::as_any(value);
-                if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(value) {
+                if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(#bevy_reflect_path::Reflect::as_any(value)) {
                     *self = #FQClone::clone(value);
-                } else {
+                } #apply_from_repr else {
                     panic!("Value is not {}.", ::core::any::type_name::<Self>());
                 }
             }
 
             #[inline]
-            fn set(&mut self, value: #FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #FQBox<dyn #bevy_reflect_path::Reflect>> {
+            fn set(&mut self, value: #fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #fq_box<dyn #bevy_reflect_path::Reflect>> {
                 *self = <dyn #bevy_reflect_path::Reflect>::take(value)?;
                 #FQResult::Ok(())
             }
@@ -109,7 +131,7 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
                 #bevy_reflect_path::ReflectMut::Value(self)
             }
 
-            fn reflect_owned(self: #FQBox<Self>) -> #bevy_reflect_path::ReflectOwned {
+            fn reflect_owned(self: #fq_box<Self>) -> #bevy_reflect_path::ReflectOwned {
                 #bevy_reflect_path::ReflectOwned::Value(self)
             }
 
@@ -117,6 +139,8 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     })
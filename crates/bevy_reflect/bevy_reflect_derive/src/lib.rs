@@ -23,6 +23,7 @@ mod field_attributes;
 mod fq_std;
 mod from_reflect;
 mod impls;
+mod non_send;
 mod reflect_value;
 mod registration;
 mod trait_reflection;
@@ -39,11 +40,24 @@ use syn::{parse_macro_input, DeriveInput};
 pub(crate) static REFLECT_ATTRIBUTE_NAME: &str = "reflect";
 pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 
+/// Derives the `Reflect` trait.
+///
+/// Besides the attributes documented on [`FromReflect`](crate::derive_from_reflect),
+/// this macro supports the following field attributes:
+/// * `#[reflect(set = "some_fn")]`: Applies values to the field by passing them to
+///   `some_fn(&mut Self, T)`, converted with `FromReflect`, so that the type's invariants are upheld.
+///   Such fields cannot be borrowed mutably, so `Struct::field_mut` returns `None` for them,
+///   and applying a value which cannot be converted to the field's type panics.
+/// * `#[reflect(get = "some_fn")]`: Reads the field through `some_fn(&Self) -> &T`.
+///   This requires a `set` function as well.
+///
+/// Both functions must be given on an actual field of the type: fields which only exist
+/// through their accessors cannot be reflected.
 #[proc_macro_derive(Reflect, attributes(reflect, reflect_value, module))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
-    let derive_data = match ReflectDerive::from_input(&ast) {
+    let derive_data = match ReflectDerive::from_input(&ast, true) {
         Ok(data) => data,
         Err(err) => return err.into_compile_error().into(),
     };
@@ -64,12 +78,29 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
 /// * `#[reflect(ignore)]`: Ignores the field. This requires the field to implement [`Default`].
 /// * `#[reflect(default)]`: If the field's value cannot be read, uses its [`Default`] implementation.
 /// * `#[reflect(default = "some_func")]`: If the field's value cannot be read, uses the function with the given name.
+/// * `#[reflect(default = 42)]`: If the field's value cannot be read, uses the given (non-string) literal.
+/// * `#[reflect(default(-1))]`: If the field's value cannot be read, uses the given expression,
+///   such as a negative number or a constant. It must be the only argument of its attribute.
+/// * `#[reflect(with = "some_module")]`: Converts the field with `some_module::from_reflect`
+///   instead of requiring the field's type to implement `FromReflect`.
+///   The field is reflected through `some_module::as_reflect(&T) -> &R` and
+///   `some_module::as_reflect_mut(&mut T) -> &mut R`, so `R` must be stored inside the field.
+/// * `#[reflect(convert = "some_module")]`: Like `with`, but for fields of named structs whose
+///   reflected value is computed rather than stored: the field is reflected as the owned value
+///   of `some_module::to_reflect(&T) -> R`, which is serialized, cloned and debugged in its place,
+///   and converted back with `some_module::from_reflect` when applied or converted with `FromReflect`.
+///   Such fields are only available through `Struct::converted_fields`, not `Struct::field`.
+/// * `#[reflect(dynamic)]`: Creates a `Box<dyn Reflect>` field from the value it holds,
+///   converting it to its concrete type if it was deserialized and its type registered `ReflectFromReflect`,
+///   and cloning it with `Reflect::clone_value` otherwise.
+/// * `#[reflect(set = "some_fn")]`: If the type reflects `Default`, sets the field with the given
+///   function instead of assigning it directly.
 ///
 #[proc_macro_derive(FromReflect, attributes(reflect))]
 pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
-    let derive_data = match ReflectDerive::from_input(&ast) {
+    let derive_data = match ReflectDerive::from_input(&ast, false) {
         Ok(data) => data,
         Err(err) => return err.into_compile_error().into(),
     };
@@ -84,6 +115,33 @@ pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derives the `NonSendReflect` trait, for structs and tuple structs which are not `Send` or `Sync`.
+///
+/// The fields of the type must implement `NonSendReflect`, unless they are marked with
+/// `#[reflect(ignore)]`, in which case they are left out of the dynamic copies of the type.
+#[proc_macro_derive(NonSendReflect, attributes(reflect))]
+pub fn derive_non_send_reflect(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let derive_data = match ReflectDerive::from_input(&ast, false) {
+        Ok(data) => data,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    match derive_data {
+        ReflectDerive::Struct(struct_data) | ReflectDerive::UnitStruct(struct_data) => {
+            non_send::impl_struct(&struct_data, false)
+        }
+        ReflectDerive::TupleStruct(struct_data) => non_send::impl_struct(&struct_data, true),
+        ReflectDerive::Enum(_) | ReflectDerive::Value(_) => syn::Error::new(
+            ast.ident.span(),
+            "`NonSendReflect` can only be derived for structs",
+        )
+        .into_compile_error()
+        .into(),
+    }
+}
+
 // From https://github.com/randomPoison/type-uuid
 #[proc_macro_derive(TypeUuid, attributes(uuid))]
 pub fn derive_type_uuid(input: TokenStream) -> TokenStream {
@@ -92,7 +150,7 @@ pub fn derive_type_uuid(input: TokenStream) -> TokenStream {
 
 #[proc_macro_attribute]
 pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
-    trait_reflection::reflect_trait(&args, input)
+    trait_reflection::reflect_trait(args, input)
 }
 
 #[proc_macro]
@@ -124,6 +182,14 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
 /// error message and fail to compile. If the type does not implement `Default`, it may not
 /// be possible to reflect without extending the macro.
 ///
+/// Generic types may be mirrored along with their bounds and where clauses, in which case
+/// each monomorphization gets its own type info, just as with `#[derive(Reflect)]`.
+/// Generic field types are given a `FromReflect` bound automatically, but must be bounded
+/// by `Reflect` in the mirrored definition.
+///
+/// With the `documentation` feature, doc comments on the mirrored type and its fields
+/// are reflected just as they are by `#[derive(Reflect)]`.
+///
 /// # Example
 /// Implementing `Reflect` for `bevy::prelude::Vec3` as a struct type:
 /// ```ignore
@@ -141,7 +207,7 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn impl_reflect_struct(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    let derive_data = match ReflectDerive::from_input(&ast) {
+    let derive_data = match ReflectDerive::from_input(&ast, false) {
         Ok(data) => data,
         Err(err) => return err.into_compile_error().into(),
     };
@@ -176,6 +242,62 @@ pub fn impl_reflect_struct(input: TokenStream) -> TokenStream {
     }
 }
 
+/// A replacement for `#[derive(Reflect)]` to be used with foreign enums which
+/// the definitions of cannot be altered.
+///
+/// This is the enum counterpart to [`impl_reflect_struct!`], implementing foreign enums
+/// as `Enum` types rather than as Value types, along with `Typed`, `FromReflect` and
+/// `GetTypeRegistration`. The enum being reflected must be in scope, as you cannot
+/// qualify it in the macro as e.g. `glam::EulerRot`. Every variant must be mirrored
+/// exactly as it is declared.
+///
+/// Generic enums are supported like in [`impl_reflect_struct!`], except that generic
+/// field types must be bounded by `FromReflect`, since changing variants via `Reflect::apply`
+/// needs to construct them.
+///
+/// # Example
+/// Implementing `Reflect` for `glam::EulerRot` as an enum type:
+/// ```ignore
+/// use glam::EulerRot;
+///
+/// impl_reflect_enum!(
+///     #[reflect(Debug, Hash, PartialEq, Serialize, Deserialize, Default)]
+///     enum EulerRot {
+///         ZYX,
+///         ZXY,
+///         YXZ,
+///         YZX,
+///         XYZ,
+///         XZY,
+///     }
+/// );
+/// ```
+#[proc_macro]
+pub fn impl_reflect_enum(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let derive_data = match ReflectDerive::from_input(&ast, false) {
+        Ok(data) => data,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    match derive_data {
+        ReflectDerive::Enum(enum_data) => {
+            let impl_enum: proc_macro2::TokenStream = impls::impl_enum(&enum_data).into();
+            let impl_from_enum: proc_macro2::TokenStream =
+                from_reflect::impl_enum(&enum_data).into();
+
+            TokenStream::from(quote! {
+                #impl_enum
+
+                #impl_from_enum
+            })
+        }
+        _ => syn::Error::new(ast.span(), "impl_reflect_enum only supports enums")
+            .into_compile_error()
+            .into(),
+    }
+}
+
 #[proc_macro]
 pub fn impl_from_reflect_value(input: TokenStream) -> TokenStream {
     let def = parse_macro_input!(input as ReflectValueDef);
@@ -0,0 +1,137 @@
+use crate::fq_std::{FQBox, FQOption};
+use crate::impls::type_capabilities;
+use crate::ReflectStruct;
+use proc_macro::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Index, Member, WhereClause, WherePredicate};
+
+/// Implements `NonSendReflect` for the given struct or tuple struct.
+pub(crate) fn impl_struct(reflect_struct: &ReflectStruct, is_tuple: bool) -> TokenStream {
+    let fqoption = FQOption.into_token_stream();
+
+    let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+    let fq_box = FQBox(bevy_reflect_path);
+    let struct_name = reflect_struct.meta().type_name();
+    let string_name = struct_name.to_string();
+    let generics = reflect_struct.meta().generics();
+
+    let field_members = reflect_struct
+        .active_fields()
+        .map(|field| match &field.data.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(field.index)),
+        })
+        .collect::<Vec<_>>();
+    let field_types = reflect_struct
+        .active_fields()
+        .map(|field| field.data.ty.clone())
+        .collect::<Vec<_>>();
+
+    // Fields are looked up by name in structs, and by their index among the active fields
+    // in tuple structs, while tuple struct field infos keep the index of the field itself
+    let (field_keys, field_info_keys) = if is_tuple {
+        let indices = (0..field_members.len()).map(|index| quote!(#index));
+        let members = field_members.iter().map(|member| quote!(#member));
+        (indices.collect::<Vec<_>>(), members.collect::<Vec<_>>())
+    } else {
+        let names = reflect_struct.active_fields().map(|field| {
+            let name = field
+                .attrs
+                .reflect_name(field.data.ident.as_ref().unwrap().to_string());
+            quote!(#name)
+        });
+        let names = names.collect::<Vec<_>>();
+        (names.clone(), names)
+    };
+    let (ref_struct_type, dynamic_type, info_type, field_info_type, panic_message) = if is_tuple {
+        (
+            quote!(TupleStruct),
+            quote!(DynamicTupleStruct),
+            quote!(TupleStructInfo),
+            quote!(UnnamedField),
+            "Attempted to apply non-TupleStruct type to TupleStruct type.",
+        )
+    } else {
+        (
+            quote!(Struct),
+            quote!(DynamicStruct),
+            quote!(StructInfo),
+            quote!(NamedField),
+            "Attempted to apply non-struct type to struct type.",
+        )
+    };
+    let insert_fields = if is_tuple {
+        quote! {
+            #(dynamic.insert_boxed(#bevy_reflect_path::NonSendReflect::to_dynamic(&self.#field_members));)*
+        }
+    } else {
+        quote! {
+            #(dynamic.insert_boxed(#field_keys, #bevy_reflect_path::NonSendReflect::to_dynamic(&self.#field_members));)*
+        }
+    };
+
+    let capabilities = type_capabilities(bevy_reflect_path, &quote!(Self));
+    let generator = quote! {
+        let fields = [
+            #(#bevy_reflect_path::#field_info_type::new::<<#field_types as #bevy_reflect_path::NonSendReflect>::Represented>(#field_info_keys),)*
+        ];
+        let info = #bevy_reflect_path::#info_type::new::<Self>(#string_name, &fields)
+            .with_capabilities(#capabilities);
+        #bevy_reflect_path::TypeInfo::#ref_struct_type(info)
+    };
+    let type_info_cell = if generics.params.is_empty() {
+        quote! {
+            static CELL: #bevy_reflect_path::utility::NonGenericTypeInfoCell = #bevy_reflect_path::utility::NonGenericTypeInfoCell::new();
+            CELL.get_or_set(|| {
+                #generator
+            })
+        }
+    } else {
+        quote! {
+            static CELL: #bevy_reflect_path::utility::GenericTypeInfoCell = #bevy_reflect_path::utility::GenericTypeInfoCell::new();
+            CELL.get_or_insert::<Self, _>(|| {
+                #generator
+            })
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| WhereClause {
+        where_token: Default::default(),
+        predicates: Default::default(),
+    });
+    where_clause.predicates.extend(
+        field_types
+            .iter()
+            .map(|ty| -> WherePredicate { parse_quote!(#ty: #bevy_reflect_path::NonSendReflect) }),
+    );
+
+    TokenStream::from(quote! {
+        impl #impl_generics #bevy_reflect_path::NonSendReflect for #struct_name #ty_generics #where_clause {
+            type Represented = Self;
+
+            fn non_send_type_info() -> &'static #bevy_reflect_path::TypeInfo {
+                #type_info_cell
+            }
+
+            fn to_dynamic(&self) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
+                let mut dynamic = #bevy_reflect_path::#dynamic_type::default();
+                dynamic.set_name(#bevy_reflect_path::__macro_exports::alloc_utils::ToString::to_string(::core::any::type_name::<Self>()));
+                #insert_fields
+                #fq_box::new(dynamic)
+            }
+
+            fn apply_dynamic(&mut self, value: &dyn #bevy_reflect_path::Reflect) {
+                if let #bevy_reflect_path::ReflectRef::#ref_struct_type(__value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
+                    #(
+                        if let #fqoption::Some(field) = #bevy_reflect_path::#ref_struct_type::field(__value, #field_keys) {
+                            #bevy_reflect_path::NonSendReflect::apply_dynamic(&mut self.#field_members, field);
+                        }
+                    )*
+                } else {
+                    panic!(#panic_message);
+                }
+            }
+        }
+    })
+}
@@ -1,8 +1,14 @@
 use crate::fq_std::{FQBox, FQClone, FQOption, FQResult};
 use bevy_macro_utils::BevyManifest;
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse::Parse, parse_macro_input, Attribute, ItemTrait, Token};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse::Parse, parse_macro_input, parse_quote, Attribute, FnArg, GenericParam, Ident, ItemTrait,
+    Path, Token, TraitItem, Type, TypeParamBound, WherePredicate,
+};
+
+static UPCAST_ARG: &str = "upcast";
 
 pub(crate) struct TraitInfo {
     item_trait: ItemTrait,
@@ -22,17 +28,144 @@ impl Parse for TraitInfo {
     }
 }
 
+/// The arguments to `#[reflect_trait]`, such as `#[reflect_trait(upcast(Debug, Display))]`.
+#[derive(Default)]
+struct TraitArgs {
+    /// The supertraits to generate upcasting helpers for.
+    upcasts: Vec<Path>,
+}
+
+impl Parse for TraitArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = TraitArgs::default();
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if ident != UPCAST_ARG {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("unknown argument `{ident}`, expected `{UPCAST_ARG}`"),
+                ));
+            }
+
+            let content;
+            syn::parenthesized!(content in input);
+            args.upcasts
+                .extend(Punctuated::<Path, Token![,]>::parse_terminated(&content)?);
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Adds a `Self: Sized` bound to every method of the trait which is generic over types or constants,
+/// so that these methods do not prevent the trait from being made into an object.
+///
+/// Methods which are only generic over lifetimes are left untouched.
+fn exclude_generic_methods(item_trait: &mut ItemTrait) {
+    for item in &mut item_trait.items {
+        let TraitItem::Method(method) = item else {
+            continue;
+        };
+
+        let has_generic_params = method
+            .sig
+            .generics
+            .params
+            .iter()
+            .any(|param| !matches!(param, GenericParam::Lifetime(..)));
+        let has_impl_trait_args = method.sig.inputs.iter().any(
+            |input| matches!(input, FnArg::Typed(pat_type) if matches!(*pat_type.ty, Type::ImplTrait(..))),
+        );
+        if !has_generic_params && !has_impl_trait_args {
+            continue;
+        }
+
+        let sized_bound: WherePredicate = parse_quote!(Self: ::core::marker::Sized);
+        method
+            .sig
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(sized_bound);
+    }
+}
+
+/// Converts the name of an upcast trait, such as `DoSomething`, to `do_something`.
+fn to_snake_case(ident: &Ident) -> String {
+    let mut snake_case = String::new();
+    for (index, char) in ident.to_string().chars().enumerate() {
+        if char.is_uppercase() {
+            if index != 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(char.to_lowercase());
+        } else {
+            snake_case.push(char);
+        }
+    }
+    snake_case
+}
+
 /// A trait attribute macro that allows a reflected type to be downcast to a trait object.
 ///
 /// This generates a struct that takes the form `ReflectMyTrait`. An instance of this struct can then be
 /// used to perform the conversion.
-pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStream {
-    let trait_info = parse_macro_input!(input as TraitInfo);
+///
+/// Generic traits generate a generic struct, such that `ReflectMyTrait<T>` casts to `dyn MyTrait<T>`.
+/// Methods generic over types (including `impl Trait` arguments) are given a `Self: Sized` bound
+/// so that the trait remains object-safe.
+///
+/// Supertraits listed in `#[reflect_trait(upcast(...))]` get methods casting to them as well.
+/// For example, `upcast(Debug)` generates `get_debug`, `get_debug_mut` and `get_debug_boxed`.
+pub(crate) fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
+    let trait_args = parse_macro_input!(args as TraitArgs);
+    let mut trait_info = parse_macro_input!(input as TraitInfo);
+    exclude_generic_methods(&mut trait_info.item_trait);
+
     let item_trait = &trait_info.item_trait;
     let trait_ident = &item_trait.ident;
     let trait_vis = &item_trait.vis;
     let reflect_trait_ident = crate::utility::get_reflect_ident(&item_trait.ident.to_string());
     let bevy_reflect_path = BevyManifest::default().get_path("bevy_reflect");
+    let fq_box = FQBox(&bevy_reflect_path);
+
+    let (impl_generics, ty_generics, where_clause) = item_trait.generics.split_for_impl();
+    let generic_params = item_trait.generics.params.iter();
+    let trait_object = quote!(dyn #trait_ident #ty_generics + 'static);
+
+    // Type data must be `'static`, which generic type data is only for some generic arguments
+    let mut cast_where_clause = item_trait.generics.clone().make_where_clause().clone();
+    cast_where_clause
+        .predicates
+        .push(parse_quote!(Self: 'static));
+
+    // Boxes of traits which can be upcast to `Reflect` can be reflected themselves
+    let has_reflect_supertrait = item_trait.supertraits.iter().any(|bound| match bound {
+        TypeParamBound::Trait(bound) => {
+            matches!(bound.path.segments.last(), Some(segment) if segment.ident == "Reflect")
+        }
+        TypeParamBound::Lifetime(_) => false,
+    });
+    let impl_trait_object = has_reflect_supertrait.then(|| {
+        quote! {
+            impl #impl_generics #bevy_reflect_path::TraitObject for #trait_object #cast_where_clause {
+                fn as_reflect_object(&self) -> &dyn #bevy_reflect_path::Reflect {
+                    #bevy_reflect_path::Reflect::as_reflect(self)
+                }
+
+                fn as_reflect_object_mut(&mut self) -> &mut dyn #bevy_reflect_path::Reflect {
+                    #bevy_reflect_path::Reflect::as_reflect_mut(self)
+                }
+
+                fn into_reflect_object(self: #fq_box<Self>) -> #fq_box<dyn #bevy_reflect_path::Reflect> {
+                    #bevy_reflect_path::Reflect::into_reflect(self)
+                }
+            }
+        }
+    });
 
     let struct_doc = format!(
         " A type generated by the #[reflect_trait] macro for the `{trait_ident}` trait.\n\n This allows casting from `dyn Reflect` to `dyn {trait_ident}`.",
@@ -47,46 +180,128 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
         " Downcast a `Box<dyn Reflect>` type to `Box<dyn {trait_ident}>`.\n\n If the type cannot be downcast, this will return `Err(Box<dyn Reflect>)`.",
     );
 
+    let mut upcast_fields = Vec::new();
+    let mut upcast_methods = Vec::new();
+    let mut upcast_constructors = Vec::new();
+    for upcast in &trait_args.upcasts {
+        let Some(last_segment) = upcast.segments.last() else {
+            continue;
+        };
+        let name = to_snake_case(&last_segment.ident);
+        let get_func = format_ident!("get_{}_func", name);
+        let get_mut_func = format_ident!("get_{}_mut_func", name);
+        let get_boxed_func = format_ident!("get_{}_boxed_func", name);
+        let get = format_ident!("get_{}", name);
+        let get_mut = format_ident!("get_{}_mut", name);
+        let get_boxed = format_ident!("get_{}_boxed", name);
+        let upcast_name = quote!(#upcast).to_string().replace(' ', "");
+
+        let get_doc = format!(
+            " Downcast a `&dyn Reflect` type implementing `{trait_ident}` to `&dyn {upcast_name}`.\n\n If the type cannot be downcast, `None` is returned.",
+        );
+        let get_mut_doc = format!(
+            " Downcast a `&mut dyn Reflect` type implementing `{trait_ident}` to `&mut dyn {upcast_name}`.\n\n If the type cannot be downcast, `None` is returned.",
+        );
+        let get_box_doc = format!(
+            " Downcast a `Box<dyn Reflect>` type implementing `{trait_ident}` to `Box<dyn {upcast_name}>`.\n\n If the type cannot be downcast, this will return `Err(Box<dyn Reflect>)`.",
+        );
+
+        upcast_fields.push(quote! {
+            #get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&dyn #upcast>,
+            #get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut dyn #upcast>,
+            #get_boxed_func: fn(#fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#fq_box<dyn #upcast>, #fq_box<dyn #bevy_reflect_path::Reflect>>,
+        });
+        upcast_methods.push(quote! {
+            #[doc = #get_doc]
+            pub fn #get<'__r>(&self, reflect_value: &'__r dyn #bevy_reflect_path::Reflect) -> #FQOption<&'__r dyn #upcast> {
+                (self.#get_func)(reflect_value)
+            }
+
+            #[doc = #get_mut_doc]
+            pub fn #get_mut<'__r>(&self, reflect_value: &'__r mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&'__r mut dyn #upcast> {
+                (self.#get_mut_func)(reflect_value)
+            }
+
+            #[doc = #get_box_doc]
+            pub fn #get_boxed(&self, reflect_value: #fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#fq_box<dyn #upcast>, #fq_box<dyn #bevy_reflect_path::Reflect>> {
+                (self.#get_boxed_func)(reflect_value)
+            }
+        });
+        upcast_constructors.push(quote! {
+            #get_func: |reflect_value| {
+                <dyn #bevy_reflect_path::Reflect>::downcast_ref::<__ReflectType>(reflect_value).map(|value| value as &dyn #upcast)
+            },
+            #get_mut_func: |reflect_value| {
+                <dyn #bevy_reflect_path::Reflect>::downcast_mut::<__ReflectType>(reflect_value).map(|value| value as &mut dyn #upcast)
+            },
+            #get_boxed_func: |reflect_value| {
+                <dyn #bevy_reflect_path::Reflect>::downcast::<__ReflectType>(reflect_value).map(|value| value as #fq_box<dyn #upcast>)
+            },
+        });
+    }
+
     TokenStream::from(quote! {
         #item_trait
 
         #[doc = #struct_doc]
-        #[derive(#FQClone)]
-        #trait_vis struct #reflect_trait_ident {
-            get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&dyn #trait_ident>,
-            get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut dyn #trait_ident>,
-            get_boxed_func: fn(#FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#FQBox<dyn #trait_ident>, #FQBox<dyn #bevy_reflect_path::Reflect>>,
+        #trait_vis struct #reflect_trait_ident #impl_generics #where_clause {
+            get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&(#trait_object)>,
+            get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut (#trait_object)>,
+            get_boxed_func: fn(#fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#fq_box<#trait_object>, #fq_box<dyn #bevy_reflect_path::Reflect>>,
+            #(#upcast_fields)*
         }
 
-        impl #reflect_trait_ident {
+        // Implemented manually, as deriving would require the trait's generics to be `Clone`
+        impl #impl_generics #FQClone for #reflect_trait_ident #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #impl_generics ::core::marker::Copy for #reflect_trait_ident #ty_generics #where_clause {}
+
+        impl #impl_generics #reflect_trait_ident #ty_generics #where_clause {
             #[doc = #get_doc]
-            pub fn get<'a>(&self, reflect_value: &'a dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a dyn #trait_ident> {
+            pub fn get<'__r>(&self, reflect_value: &'__r dyn #bevy_reflect_path::Reflect) -> #FQOption<&'__r (#trait_object)> {
                 (self.get_func)(reflect_value)
             }
 
             #[doc = #get_mut_doc]
-            pub fn get_mut<'a>(&self, reflect_value: &'a mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a mut dyn #trait_ident> {
+            pub fn get_mut<'__r>(&self, reflect_value: &'__r mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&'__r mut (#trait_object)> {
                 (self.get_mut_func)(reflect_value)
             }
 
             #[doc = #get_box_doc]
-            pub fn get_boxed(&self, reflect_value: #FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#FQBox<dyn #trait_ident>, #FQBox<dyn #bevy_reflect_path::Reflect>> {
+            pub fn get_boxed(&self, reflect_value: #fq_box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#fq_box<#trait_object>, #fq_box<dyn #bevy_reflect_path::Reflect>> {
                 (self.get_boxed_func)(reflect_value)
             }
+
+            #(#upcast_methods)*
         }
 
-        impl<T: #trait_ident + #bevy_reflect_path::Reflect> #bevy_reflect_path::FromType<T> for #reflect_trait_ident {
+        impl #impl_generics #bevy_reflect_path::TraitCast for #reflect_trait_ident #ty_generics #cast_where_clause {
+            type Target = #trait_object;
+
+            fn caster(&self) -> #bevy_reflect_path::TraitCaster<Self::Target> {
+                #bevy_reflect_path::TraitCaster::new(self.get_func, self.get_mut_func, self.get_boxed_func)
+            }
+        }
+
+        #impl_trait_object
+
+        impl<#(#generic_params,)* __ReflectType: #trait_ident #ty_generics + #bevy_reflect_path::Reflect> #bevy_reflect_path::FromType<__ReflectType> for #reflect_trait_ident #ty_generics #where_clause {
             fn from_type() -> Self {
                 Self {
                     get_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast_ref::<T>(reflect_value).map(|value| value as &dyn #trait_ident)
+                        <dyn #bevy_reflect_path::Reflect>::downcast_ref::<__ReflectType>(reflect_value).map(|value| value as &(#trait_object))
                     },
                     get_mut_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast_mut::<T>(reflect_value).map(|value| value as &mut dyn #trait_ident)
+                        <dyn #bevy_reflect_path::Reflect>::downcast_mut::<__ReflectType>(reflect_value).map(|value| value as &mut (#trait_object))
                     },
                     get_boxed_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast::<T>(reflect_value).map(|value| value as #FQBox<dyn #trait_ident>)
-                    }
+                        <dyn #bevy_reflect_path::Reflect>::downcast::<__ReflectType>(reflect_value).map(|value| value as #fq_box<#trait_object>)
+                    },
+                    #(#upcast_constructors)*
                 }
             }
         }
@@ -3,14 +3,35 @@
 use crate::field_attributes::ReflectIgnoreBehavior;
 use bevy_macro_utils::BevyManifest;
 use bit_set::BitSet;
-use proc_macro2::{Ident, Span};
-use syn::{Member, Path};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::{Attribute, Member, Meta, NestedMeta, Path};
 
 /// Returns the correct path for `bevy_reflect`.
 pub(crate) fn get_bevy_reflect_path() -> Path {
     BevyManifest::get_path_direct("bevy_reflect")
 }
 
+/// Returns `true` if the attributes of an enum give it a primitive or `C` representation,
+/// such as `#[repr(u8)]`, which makes its discriminants meaningful even when none are explicit.
+pub(crate) fn has_discriminant_repr(attrs: &[Attribute]) -> bool {
+    const REPRS: &[&str] = &[
+        "C", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => REPRS.iter().any(|repr| path.is_ident(repr)),
+            _ => false,
+        })
+}
+
 /// Returns the "reflected" ident for a given string.
 ///
 /// # Example
@@ -24,6 +45,25 @@ pub(crate) fn get_reflect_ident(name: &str) -> Ident {
     Ident::new(&reflected, Span::call_site())
 }
 
+/// Returns `names` paired with their index and sorted by name, as a slice expression.
+///
+/// This is the lookup table expected by the `from_static` constructors of the type info structs.
+pub(crate) fn sorted_name_indices(names: &[String]) -> TokenStream {
+    let mut sorted: Vec<_> = names.iter().zip(0_usize..).collect();
+    sorted.sort();
+    let (names, indices): (Vec<_>, Vec<_>) = sorted.into_iter().unzip();
+    quote!(&[#((#names, #indices)),*])
+}
+
+/// Returns `true` if `Self` appears anywhere within `tokens`.
+pub(crate) fn contains_self(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => contains_self(group.stream()),
+        _ => false,
+    })
+}
+
 /// Helper struct used to process an iterator of `Result<Vec<T>, syn::Error>`,
 /// combining errors into one along the way.
 pub(crate) struct ResultSifter<T> {
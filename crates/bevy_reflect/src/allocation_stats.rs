@@ -0,0 +1,302 @@
+#[cfg(feature = "allocation_stats")]
+use std::cell::Cell;
+
+/// The reflection operations whose `Box<dyn Reflect>` allocations are counted.
+#[derive(Clone, Copy)]
+pub(crate) enum AllocationSource {
+    CloneDynamic,
+    Apply,
+    Serialize,
+    Deserialize,
+}
+
+#[cfg(feature = "allocation_stats")]
+thread_local! {
+    /// The allocations counted on this thread.
+    static COUNTS: Cell<AllocationStats> = const {
+        Cell::new(AllocationStats {
+            clone_dynamic: 0,
+            apply: 0,
+            serialize: 0,
+            deserialize: 0,
+        })
+    };
+}
+
+/// Records that `count` values were boxed by the given operation on this thread.
+///
+/// This does nothing unless the `allocation_stats` feature is enabled.
+#[inline]
+pub(crate) fn record_allocations(source: AllocationSource, count: usize) {
+    #[cfg(feature = "allocation_stats")]
+    COUNTS.with(|counts| {
+        let mut stats = counts.get();
+        let counter = match source {
+            AllocationSource::CloneDynamic => &mut stats.clone_dynamic,
+            AllocationSource::Apply => &mut stats.apply,
+            AllocationSource::Serialize => &mut stats.serialize,
+            AllocationSource::Deserialize => &mut stats.deserialize,
+        };
+        *counter += count as u64;
+        counts.set(stats);
+    });
+    #[cfg(not(feature = "allocation_stats"))]
+    let _ = (source, count);
+}
+
+/// Counts of the `Box<dyn Reflect>` allocations made by reflection operations,
+/// available with the `allocation_stats` feature.
+///
+/// The counts are kept per thread, from the start of the thread or from the last
+/// call to [`reset`](Self::reset) on it. To measure a section of code,
+/// take a [`snapshot`](Self::snapshot) before and after it and compare them with
+/// [`since`](Self::since).
+///
+/// Converting values with `FromReflect` builds them in place, so does not box any values.
+///
+/// The counts only cover the implementations in this crate, and those generated by
+/// `#[derive(Reflect)]`, not manual implementations of the reflection traits.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{AllocationStats, Reflect};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     scores: Vec<u32>,
+/// }
+///
+/// let player = Player { name: String::from("ferris"), scores: vec![1, 2, 3] };
+///
+/// let before = AllocationStats::snapshot();
+/// let _clone = player.clone_value();
+/// let stats = AllocationStats::snapshot().since(&before);
+/// // One box for each field of `Player` and each element of `scores`
+/// assert_eq!(5, stats.clone_dynamic);
+/// ```
+#[cfg(feature = "allocation_stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AllocationStats {
+    /// The values boxed by `clone_dynamic`, and so by `clone_value`, for each field,
+    /// element and map entry of the value being cloned.
+    pub clone_dynamic: u64,
+    /// The values boxed by [`Reflect::apply`] when inserting new list elements and map entries,
+    /// or when switching a dynamic enum to a different variant.
+    ///
+    /// [`Reflect::apply`]: crate::Reflect::apply
+    pub apply: u64,
+    /// The values boxed by the reflect serializers, one for each value converted into its
+    /// representation type (given by `#[reflect(into = "...")]`) to be serialized.
+    ///
+    /// Other values are serialized in place, so are not boxed.
+    pub serialize: u64,
+    /// The values boxed by the reflect deserializers, one for each value deserialized.
+    pub deserialize: u64,
+}
+
+#[cfg(feature = "allocation_stats")]
+impl AllocationStats {
+    /// Returns the current counts of this thread.
+    pub fn snapshot() -> Self {
+        COUNTS.with(Cell::get)
+    }
+
+    /// Sets all the counts of this thread back to zero.
+    pub fn reset() {
+        COUNTS.with(|counts| counts.set(Self::default()));
+    }
+
+    /// Returns the number of allocations counted between `earlier` and these counts.
+    pub fn since(&self, earlier: &Self) -> Self {
+        Self {
+            clone_dynamic: self.clone_dynamic.saturating_sub(earlier.clone_dynamic),
+            apply: self.apply.saturating_sub(earlier.apply),
+            serialize: self.serialize.saturating_sub(earlier.serialize),
+            deserialize: self.deserialize.saturating_sub(earlier.deserialize),
+        }
+    }
+
+    /// Returns the total number of allocations counted.
+    pub fn total(&self) -> u64 {
+        self.clone_dynamic + self.apply + self.serialize + self.deserialize
+    }
+}
+
+#[cfg(all(test, feature = "allocation_stats"))]
+mod tests {
+    use super::AllocationStats;
+    use crate::{self as bevy_reflect, DynamicList, FromReflect, List, Reflect, Struct};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        level: u32,
+        scores: Vec<u32>,
+    }
+
+    fn player() -> Player {
+        Player {
+            name: String::from("ferris"),
+            level: 3,
+            scores: vec![1, 2, 3],
+        }
+    }
+
+    /// Returns the allocations counted while running `f`.
+    fn count(f: impl FnOnce()) -> AllocationStats {
+        let before = AllocationStats::snapshot();
+        f();
+        AllocationStats::snapshot().since(&before)
+    }
+
+    #[test]
+    fn should_count_clone_value() {
+        let player = player();
+        // `name` and `scores` are boxed, along with each of the scores,
+        // while `level` is stored inline
+        let stats = count(|| drop(player.clone_value()));
+        assert_eq!(
+            AllocationStats {
+                clone_dynamic: 5,
+                ..Default::default()
+            },
+            stats
+        );
+
+        // Values are cloned without going through `clone_dynamic`
+        assert_eq!(0, count(|| drop(1.5_f32.clone_value())).total());
+    }
+
+    #[test]
+    fn should_count_clone_dynamic() {
+        let player = player();
+        let stats = count(|| drop(player.clone_dynamic()));
+        assert_eq!(5, stats.clone_dynamic);
+        assert_eq!(5, stats.total());
+
+        let scores = player.scores.clone();
+        assert_eq!(3, count(|| drop(scores.clone_dynamic())).total());
+
+        let mut map = HashMap::default();
+        map.insert(1_u8, String::from("one"));
+        map.insert(2_u8, String::from("two"));
+        // One box for each key and each value
+        assert_eq!(4, count(|| drop(crate::Map::clone_dynamic(&map))).total());
+    }
+
+    #[test]
+    fn should_count_apply() {
+        let mut scores = vec![1_u32];
+        let mut patch = DynamicList::default();
+        patch.push(4_u32);
+        patch.push(5_u32);
+        patch.push(6_u32);
+        // Only the new elements are boxed, and then moved into the `Vec`
+        let stats = count(|| scores.apply(&patch));
+        assert_eq!(
+            AllocationStats {
+                apply: 2,
+                ..Default::default()
+            },
+            stats
+        );
+        assert_eq!(vec![4, 5, 6], scores);
+
+        // Applying to existing elements boxes nothing
+        assert_eq!(0, count(|| scores.apply(&patch)).total());
+
+        let mut option: Option<u32> = None;
+        assert_eq!(0, count(|| option.apply(&Some(7_u32))).total());
+        assert_eq!(Some(7), option);
+    }
+
+    #[test]
+    fn should_count_from_reflect() {
+        let player = player();
+        let dynamic = player.clone_dynamic();
+        let stats = count(|| assert_eq!(Some(player.clone()), Player::from_reflect(&dynamic)));
+        assert_eq!(0, stats.total());
+
+        let option = Some(player.clone()).clone_value();
+        let stats = count(|| {
+            assert_eq!(
+                Some(Some(player)),
+                <Option<Player> as FromReflect>::from_reflect(&*option)
+            );
+        });
+        assert_eq!(0, stats.total());
+    }
+
+    #[test]
+    fn should_count_serialize_and_deserialize() {
+        use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+        use crate::TypeRegistry;
+        use serde::de::DeserializeSeed;
+
+        #[derive(Reflect, FromReflect)]
+        struct CelsiusRepr(f32);
+
+        #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+        #[reflect(from = "CelsiusRepr", into = "CelsiusRepr")]
+        struct Kelvin(f32);
+
+        impl From<CelsiusRepr> for Kelvin {
+            fn from(celsius: CelsiusRepr) -> Self {
+                Kelvin(celsius.0 + 273.15)
+            }
+        }
+
+        impl From<Kelvin> for CelsiusRepr {
+            fn from(kelvin: Kelvin) -> Self {
+                CelsiusRepr(kelvin.0 - 273.15)
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Kelvin>();
+        registry.register::<CelsiusRepr>();
+        registry.register::<String>();
+        registry.register::<u32>();
+        registry.register::<f32>();
+        registry.register::<Vec<u32>>();
+
+        let player = player();
+        let mut output = String::new();
+        let stats = count(|| {
+            let serializer = TypedReflectSerializer::new(&player, &registry);
+            output = ron::to_string(&serializer).unwrap();
+        });
+        assert_eq!(0, stats.total());
+
+        // The representation of `Kelvin` is boxed to be serialized
+        let stats = count(|| {
+            let serializer = TypedReflectSerializer::new(&Kelvin(0.0), &registry);
+            ron::to_string(&serializer).unwrap();
+        });
+        assert_eq!(
+            AllocationStats {
+                serialize: 1,
+                ..Default::default()
+            },
+            stats
+        );
+
+        let registration = registry.get(std::any::TypeId::of::<Player>()).unwrap();
+        let stats = count(|| {
+            let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+            TypedReflectDeserializer::new(registration, &registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+        });
+        // One box for the player, each of its fields and each of the scores
+        assert_eq!(
+            AllocationStats {
+                deserialize: 7,
+                ..Default::default()
+            },
+            stats
+        );
+    }
+}
@@ -0,0 +1,1137 @@
+use crate::{
+    Access, Enum, ParsedPath, Reflect, ReflectMut, ReflectRef, TypeInfo, VariantInfo, VariantType,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_utils::HashSet;
+use core::any::TypeId;
+use core::fmt;
+use thiserror::Error;
+
+/// An error returned when a value cannot be [applied] to another.
+///
+/// [applied]: Reflect::apply
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ApplyError {
+    #[error("attempted to apply a {from_kind} to a {to_kind}")]
+    MismatchedKinds {
+        from_kind: &'static str,
+        to_kind: &'static str,
+    },
+    #[error("attempted to apply a value of type `{from_type}` to a value of type `{to_type}`")]
+    MismatchedTypes { from_type: String, to_type: String },
+    #[error("attempted to apply an array of length {from_len} to an array of length {to_len}")]
+    DifferentSize { from_len: usize, to_len: usize },
+    #[error("the variant `{variant}` does not exist on enum `{enum_name}`")]
+    UnknownVariant { enum_name: String, variant: String },
+    #[error("attempted to apply a {from_kind} variant to the {to_kind} variant `{variant}` of enum `{enum_name}`")]
+    MismatchedVariantKinds {
+        enum_name: String,
+        variant: String,
+        from_kind: &'static str,
+        to_kind: &'static str,
+    },
+    #[error("the field `{field}` of variant `{variant}` of enum `{enum_name}` is missing")]
+    MissingVariantField {
+        enum_name: String,
+        variant: String,
+        field: String,
+    },
+    #[error("the value `{value}` of type `{from_type}` does not fit in a `{to_type}`")]
+    NumericOverflow {
+        value: String,
+        from_type: String,
+        to_type: String,
+    },
+}
+
+/// An error returned from [`apply_to_each`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ApplyToEachError {
+    #[error("expected a list, array, or map but found `{0}`")]
+    NotACollection(String),
+    /// Contains the index of each element that could not be applied to, along with the reason.
+    ///
+    /// For maps, the index refers to the position of the entry in [`Map::iter`](crate::Map::iter).
+    #[error("failed to apply the value to {} element(s)", .0.len())]
+    Elements(Vec<(usize, ApplyError)>),
+}
+
+/// How [`apply_with_coercion`] converts a number which is out of the range of its new type.
+///
+/// Regardless of the policy, floats converted to integers are truncated towards zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Clamps the number to the closest value the type can hold.
+    ///
+    /// `NaN` is converted to an integer as `0`.
+    #[default]
+    Saturate,
+    /// Wraps integers around as an `as` cast does, keeping their lowest bits.
+    ///
+    /// Floats which are too large for an `f32` become infinite,
+    /// while floats too large to be wrapped, or `NaN`, are saturated instead.
+    Wrap,
+    /// Fails with [`ApplyError::NumericOverflow`].
+    Error,
+}
+
+/// Checks whether `value` can be [applied] to `target` without panicking.
+///
+/// This walks both values the same way [`Reflect::apply`] would, checking that their kinds
+/// line up and that value types match exactly. Elements that `apply` would append or insert
+/// (such as the excess items of a list) are only checked when the container's
+/// [`TypeInfo`] describes a concrete item type.
+///
+/// When an enum is set to a different variant, every field of the new variant must be present
+/// with the type described by the enum's [`TypeInfo`], as the variant is built from them.
+/// Fields holding dynamic values, such as a [`DynamicStruct`](crate::DynamicStruct),
+/// are assumed to convert to their field's type.
+///
+/// [applied]: Reflect::apply
+pub fn check_apply(target: &dyn Reflect, value: &dyn Reflect) -> Result<(), ApplyError> {
+    check(target, value, None)
+}
+
+/// Checks that `value` can be applied to `target`, converting numbers with the given policy
+/// if `coercion` is set, as [`apply_with_coercion`] does.
+fn check(
+    target: &dyn Reflect,
+    value: &dyn Reflect,
+    coercion: Option<OverflowPolicy>,
+) -> Result<(), ApplyError> {
+    match (target.reflect_ref(), value.reflect_ref()) {
+        (ReflectRef::Struct(target), ReflectRef::Struct(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                let name = value.name_at(i).unwrap();
+                if let Some(target_field) = target.field(name) {
+                    check(target_field, value_field, coercion)?;
+                }
+            }
+            Ok(())
+        }
+        (ReflectRef::TupleStruct(target), ReflectRef::TupleStruct(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field(i) {
+                    check(target_field, value_field, coercion)?;
+                }
+            }
+            Ok(())
+        }
+        (ReflectRef::Tuple(target), ReflectRef::Tuple(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field(i) {
+                    check(target_field, value_field, coercion)?;
+                }
+            }
+            Ok(())
+        }
+        (ReflectRef::List(target_list), ReflectRef::List(value)) => {
+            for (i, value_item) in value.iter().enumerate() {
+                match target_list.get(i) {
+                    Some(target_item) => check(target_item, value_item, coercion)?,
+                    None => {
+                        if let TypeInfo::List(info) = target.get_type_info() {
+                            check_item_type(
+                                info.item_type_id(),
+                                info.item_type_name(),
+                                value_item,
+                                coercion,
+                            )?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+        (ReflectRef::Array(target), ReflectRef::Array(value)) => {
+            if target.len() != value.len() {
+                return Err(ApplyError::DifferentSize {
+                    from_len: value.len(),
+                    to_len: target.len(),
+                });
+            }
+            for (target_item, value_item) in target.iter().zip(value.iter()) {
+                check(target_item, value_item, coercion)?;
+            }
+            Ok(())
+        }
+        (ReflectRef::Map(target_map), ReflectRef::Map(value)) => {
+            for (key, value_item) in value.iter() {
+                match target_map.get(key) {
+                    Some(target_item) => check(target_item, value_item, coercion)?,
+                    None => {
+                        if let TypeInfo::Map(info) = target.get_type_info() {
+                            check_item_type(info.key_type_id(), info.key_type_name(), key, None)?;
+                            check_item_type(
+                                info.value_type_id(),
+                                info.value_type_name(),
+                                value_item,
+                                coercion,
+                            )?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+        (ReflectRef::Enum(target_enum), ReflectRef::Enum(value)) => {
+            if target_enum.variant_name() == value.variant_name() {
+                match value.variant_type() {
+                    VariantType::Struct => {
+                        for field in value.iter_fields() {
+                            if let Some(target_field) = target_enum.field(field.name().unwrap()) {
+                                check(target_field, field.value(), coercion)?;
+                            }
+                        }
+                    }
+                    VariantType::Tuple => {
+                        for (i, field) in value.iter_fields().enumerate() {
+                            if let Some(target_field) = target_enum.field_at(i) {
+                                check(target_field, field.value(), coercion)?;
+                            }
+                        }
+                    }
+                    VariantType::Unit => {}
+                }
+                Ok(())
+            } else {
+                match target.get_type_info() {
+                    TypeInfo::Enum(info) => match info.variant(value.variant_name()) {
+                        Some(variant) => check_new_variant(target.type_name(), variant, value),
+                        None => Err(ApplyError::UnknownVariant {
+                            enum_name: target.type_name().to_string(),
+                            variant: value.variant_name().to_string(),
+                        }),
+                    },
+                    _ => Ok(()),
+                }
+            }
+        }
+        (ReflectRef::Value(target), ReflectRef::Value(value)) => {
+            check_item_type(target.type_id(), target.type_name(), value, coercion)
+        }
+        (target, value) => Err(ApplyError::MismatchedKinds {
+            from_kind: kind_name(&value),
+            to_kind: kind_name(&target),
+        }),
+    }
+}
+
+/// Checks that the variant described by `info` can be built from the fields of `value`.
+///
+/// Numbers are not converted when switching variants, so the field types must match exactly.
+fn check_new_variant(
+    enum_name: &str,
+    info: &VariantInfo,
+    value: &dyn Enum,
+) -> Result<(), ApplyError> {
+    let check_field = |field_name: String, field: Option<&dyn Reflect>, type_id, type_name| {
+        let field = field.ok_or_else(|| ApplyError::MissingVariantField {
+            enum_name: enum_name.to_string(),
+            variant: info.name().to_string(),
+            field: field_name,
+        })?;
+        check_item_type(type_id, type_name, field, None)
+    };
+
+    match (info, value.variant_type()) {
+        (VariantInfo::Struct(info), VariantType::Struct) => {
+            for field in info.iter() {
+                check_field(
+                    field.name().to_string(),
+                    value.field(field.name()),
+                    field.type_id(),
+                    field.type_name(),
+                )?;
+            }
+            Ok(())
+        }
+        (VariantInfo::Tuple(info), VariantType::Tuple) => {
+            for (i, field) in info.iter().enumerate() {
+                check_field(
+                    i.to_string(),
+                    value.field_at(i),
+                    field.type_id(),
+                    field.type_name(),
+                )?;
+            }
+            Ok(())
+        }
+        (VariantInfo::Unit(..), VariantType::Unit) => Ok(()),
+        (info, variant_type) => Err(ApplyError::MismatchedVariantKinds {
+            enum_name: enum_name.to_string(),
+            variant: info.name().to_string(),
+            from_kind: variant_kind_name(variant_type),
+            to_kind: variant_kind_name(match info {
+                VariantInfo::Struct(..) => VariantType::Struct,
+                VariantInfo::Tuple(..) => VariantType::Tuple,
+                VariantInfo::Unit(..) => VariantType::Unit,
+            }),
+        }),
+    }
+}
+
+/// Applies `value` to every element of the given [`List`], [`Array`], or [`Map`].
+///
+/// Each element is first validated with [`check_apply`]. Elements which pass are updated
+/// using [`Reflect::apply`], while elements which fail are left untouched and reported
+/// in the returned [`ApplyToEachError::Elements`].
+/// For maps, `value` is applied to the map's values rather than its keys.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{apply_to_each, FromReflect, Reflect};
+/// #[derive(Reflect, FromReflect)]
+/// struct Light {
+///     intensity: f32,
+///     color: u32,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Patch {
+///     intensity: f32,
+/// }
+///
+/// let mut lights = vec![
+///     Light { intensity: 1.0, color: 0xff0000 },
+///     Light { intensity: 2.0, color: 0x00ff00 },
+/// ];
+///
+/// apply_to_each(&mut lights, &Patch { intensity: 5.0 }).unwrap();
+///
+/// assert_eq!(5.0, lights[0].intensity);
+/// assert_eq!(5.0, lights[1].intensity);
+/// assert_eq!(0x00ff00, lights[1].color);
+/// ```
+///
+/// [`List`]: crate::List
+/// [`Array`]: crate::Array
+/// [`Map`]: crate::Map
+pub fn apply_to_each(
+    collection: &mut dyn Reflect,
+    value: &dyn Reflect,
+) -> Result<(), ApplyToEachError> {
+    let mut errors = Vec::new();
+    let type_name = collection.type_name().to_string();
+    match collection.reflect_mut() {
+        ReflectMut::List(list) => {
+            for index in 0..list.len() {
+                let element = list.get_mut(index).unwrap();
+                apply_element(index, element, value, &mut errors);
+            }
+        }
+        ReflectMut::Array(array) => {
+            for index in 0..array.len() {
+                let element = array.get_mut(index).unwrap();
+                apply_element(index, element, value, &mut errors);
+            }
+        }
+        ReflectMut::Map(map) => {
+            let keys: Vec<_> = map.iter().map(|(key, _)| key.clone_value()).collect();
+            for (index, key) in keys.iter().enumerate() {
+                let element = map.get_mut(key.as_ref()).unwrap();
+                apply_element(index, element, value, &mut errors);
+            }
+        }
+        _ => return Err(ApplyToEachError::NotACollection(type_name)),
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ApplyToEachError::Elements(errors))
+    }
+}
+
+/// Applies `value` to `target`, returning the path of each value within `target`
+/// which was actually changed.
+///
+/// `value` is first validated with [`check_apply`], leaving `target` untouched if it fails.
+/// A value counts as changed if [`Reflect::reflect_partial_eq`] does not report it as equal
+/// to the value applied to it. Values which `apply` appends or inserts are reported too,
+/// as are enums which `apply` sets to a different variant, rather than any of their fields.
+/// As map entries cannot be accessed by a path, a change to any of them is reported
+/// as a change to the map itself.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{apply_and_track, ParsedPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     position: (f32, f32),
+///     items: Vec<u32>,
+/// }
+///
+/// let mut player = Player {
+///     name: String::from("ferris"),
+///     position: (0.0, 0.0),
+///     items: vec![1],
+/// };
+/// let mut patch = player.clone_value();
+/// patch.apply(&Player {
+///     name: String::from("ferris"),
+///     position: (0.0, 5.0),
+///     items: vec![1, 2],
+/// });
+///
+/// let changed = apply_and_track(&mut player, &*patch).unwrap();
+/// assert_eq!(2, changed.len());
+/// assert!(changed.contains(&ParsedPath::parse("position.1").unwrap()));
+/// assert!(changed.contains(&ParsedPath::parse("items[1]").unwrap()));
+/// assert_eq!(5.0, player.position.1);
+/// ```
+pub fn apply_and_track(
+    target: &mut dyn Reflect,
+    value: &dyn Reflect,
+) -> Result<HashSet<ParsedPath>, ApplyError> {
+    check_apply(target, value)?;
+    let mut changed = HashSet::default();
+    track_changes(target, value, &mut ParsedPath::default(), &mut changed);
+    target.apply(value);
+    Ok(changed)
+}
+
+/// Adds the path of each value within `target` that applying `value` would change to `changed`.
+fn track_changes(
+    target: &dyn Reflect,
+    value: &dyn Reflect,
+    path: &mut ParsedPath,
+    changed: &mut HashSet<ParsedPath>,
+) {
+    let mut track_child = |access: Access, target: Option<&dyn Reflect>, value: &dyn Reflect| {
+        path.push(access);
+        match target {
+            Some(target) => track_changes(target, value, path, changed),
+            None => {
+                changed.insert(path.clone());
+            }
+        }
+        path.pop();
+    };
+
+    match (target.reflect_ref(), value.reflect_ref()) {
+        (ReflectRef::Struct(target), ReflectRef::Struct(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                let name = value.name_at(i).unwrap();
+                if let Some(target_field) = target.field(name) {
+                    track_child(
+                        Access::Field(name.to_string()),
+                        Some(target_field),
+                        value_field,
+                    );
+                }
+            }
+        }
+        (ReflectRef::TupleStruct(target), ReflectRef::TupleStruct(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field(i) {
+                    track_child(Access::TupleIndex(i), Some(target_field), value_field);
+                }
+            }
+        }
+        (ReflectRef::Tuple(target), ReflectRef::Tuple(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field(i) {
+                    track_child(Access::TupleIndex(i), Some(target_field), value_field);
+                }
+            }
+        }
+        (ReflectRef::List(target), ReflectRef::List(value)) => {
+            for (i, value_item) in value.iter().enumerate() {
+                track_child(Access::ListIndex(i), target.get(i), value_item);
+            }
+        }
+        (ReflectRef::Array(target), ReflectRef::Array(value)) => {
+            for (i, value_item) in value.iter().enumerate() {
+                track_child(Access::ListIndex(i), target.get(i), value_item);
+            }
+        }
+        (ReflectRef::Map(target), ReflectRef::Map(value)) => {
+            let map_changed = value.iter().any(|(key, value_item)| {
+                let mut entry_changes = HashSet::default();
+                match target.get(key) {
+                    Some(target_item) => {
+                        track_changes(target_item, value_item, path, &mut entry_changes);
+                    }
+                    None => return true,
+                }
+                !entry_changes.is_empty()
+            });
+            if map_changed {
+                changed.insert(path.clone());
+            }
+        }
+        (ReflectRef::Enum(target), ReflectRef::Enum(value))
+            if target.variant_name() == value.variant_name() =>
+        {
+            for (i, field) in value.iter_fields().enumerate() {
+                let (access, target_field) = match field.name() {
+                    Some(name) => (Access::Field(name.to_string()), target.field(name)),
+                    None => (Access::TupleIndex(i), target.field_at(i)),
+                };
+                if let Some(target_field) = target_field {
+                    track_child(access, Some(target_field), field.value());
+                }
+            }
+        }
+        (ReflectRef::Value(target), ReflectRef::Value(value)) => {
+            if target.reflect_partial_eq(value) != Some(true) {
+                changed.insert(path.clone());
+            }
+        }
+        _ => {
+            changed.insert(path.clone());
+        }
+    }
+}
+
+/// Applies `value` to `target` like [`Reflect::apply`], converting each number in `value`
+/// to the type of the number it is applied to, rather than panicking.
+///
+/// Numbers are converted between any of the primitive integer and float types,
+/// with numbers out of the range of their new type handled according to `overflow`.
+/// This is useful when applying data from formats which don't distinguish number types,
+/// such as JSON, where every number is usually read as an `f64`.
+///
+/// `value` is first validated with [`check_apply`], allowing numbers of different types,
+/// leaving `target` untouched if it fails. Numbers appended to a list or inserted into a map
+/// are converted to its item type, but numbers nested within other appended or inserted values,
+/// or within an enum being set to a different variant, are not converted.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{apply_with_coercion, DynamicStruct, OverflowPolicy, Reflect};
+/// #[derive(Reflect)]
+/// struct Volume {
+///     level: u8,
+///     gain: f32,
+///     steps: Vec<u16>,
+/// }
+///
+/// let mut volume = Volume { level: 0, gain: 0.0, steps: vec![] };
+///
+/// let mut patch = DynamicStruct::default();
+/// patch.insert("level", 300.0_f64);
+/// patch.insert("gain", 0.5_f64);
+/// patch.insert("steps", vec![1.0_f64, 2.0]);
+///
+/// apply_with_coercion(&mut volume, &patch, OverflowPolicy::Saturate).unwrap();
+/// assert_eq!(255, volume.level);
+/// assert_eq!(0.5, volume.gain);
+/// assert_eq!(vec![1, 2], volume.steps);
+///
+/// assert!(apply_with_coercion(&mut volume, &patch, OverflowPolicy::Error).is_err());
+/// ```
+pub fn apply_with_coercion(
+    target: &mut dyn Reflect,
+    value: &dyn Reflect,
+    overflow: OverflowPolicy,
+) -> Result<(), ApplyError> {
+    check(target, value, Some(overflow))?;
+    apply_coerced(target, value, overflow);
+    Ok(())
+}
+
+/// Applies `value`, which has been checked, to `target`, converting numbers as it goes.
+fn apply_coerced(target: &mut dyn Reflect, value: &dyn Reflect, overflow: OverflowPolicy) {
+    let item_type = match target.get_type_info() {
+        TypeInfo::List(info) => Some((info.item_type_id(), info.item_type_name())),
+        TypeInfo::Map(info) => Some((info.value_type_id(), info.value_type_name())),
+        _ => None,
+    };
+    let new_item = |item: &dyn Reflect| {
+        item_type
+            .and_then(|(type_id, type_name)| coerce_number(item, type_id, type_name, overflow))
+            .and_then(Result::ok)
+            .unwrap_or_else(|| item.clone_value())
+    };
+
+    match (target.reflect_mut(), value.reflect_ref()) {
+        (ReflectMut::Struct(target), ReflectRef::Struct(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field_mut(value.name_at(i).unwrap()) {
+                    apply_coerced(target_field, value_field, overflow);
+                }
+            }
+        }
+        (ReflectMut::TupleStruct(target), ReflectRef::TupleStruct(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field_mut(i) {
+                    apply_coerced(target_field, value_field, overflow);
+                }
+            }
+        }
+        (ReflectMut::Tuple(target), ReflectRef::Tuple(value)) => {
+            for (i, value_field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field_mut(i) {
+                    apply_coerced(target_field, value_field, overflow);
+                }
+            }
+        }
+        (ReflectMut::List(target), ReflectRef::List(value)) => {
+            target.reserve(value.len().saturating_sub(target.len()));
+            for (i, value_item) in value.iter().enumerate() {
+                match target.get_mut(i) {
+                    Some(target_item) => apply_coerced(target_item, value_item, overflow),
+                    None => target.push(new_item(value_item)),
+                }
+            }
+        }
+        (ReflectMut::Array(target), ReflectRef::Array(value)) => {
+            for (i, value_item) in value.iter().enumerate() {
+                if let Some(target_item) = target.get_mut(i) {
+                    apply_coerced(target_item, value_item, overflow);
+                }
+            }
+        }
+        (ReflectMut::Map(target), ReflectRef::Map(value)) => {
+            target.reserve(value.len().saturating_sub(target.len()));
+            for (key, value_item) in value.iter() {
+                match target.get_mut(key) {
+                    Some(target_item) => apply_coerced(target_item, value_item, overflow),
+                    None => {
+                        target.insert_boxed(key.clone_value(), new_item(value_item));
+                    }
+                }
+            }
+        }
+        (ReflectMut::Enum(target), ReflectRef::Enum(value)) => {
+            if target.variant_name() != value.variant_name() {
+                target.apply(value.as_reflect());
+                return;
+            }
+            for (i, field) in value.iter_fields().enumerate() {
+                let target_field = match field.name() {
+                    Some(name) => target.field_mut(name),
+                    None => target.field_at_mut(i),
+                };
+                if let Some(target_field) = target_field {
+                    apply_coerced(target_field, field.value(), overflow);
+                }
+            }
+        }
+        (ReflectMut::Value(target), ReflectRef::Value(value)) => {
+            if target.type_id() != value.type_id() {
+                let converted =
+                    coerce_number(value, target.type_id(), target.type_name(), overflow);
+                if let Some(Ok(number)) = converted {
+                    target.apply(&*number);
+                    return;
+                }
+            }
+            target.apply(value);
+        }
+        _ => {}
+    }
+}
+
+fn apply_element(
+    index: usize,
+    element: &mut dyn Reflect,
+    value: &dyn Reflect,
+    errors: &mut Vec<(usize, ApplyError)>,
+) {
+    match check_apply(element, value) {
+        Ok(()) => element.apply(value),
+        Err(err) => errors.push((index, err)),
+    }
+}
+
+fn check_item_type(
+    type_id: TypeId,
+    type_name: &str,
+    value: &dyn Reflect,
+    coercion: Option<OverflowPolicy>,
+) -> Result<(), ApplyError> {
+    if matches!(value.get_type_info(), TypeInfo::Dynamic(..)) || value.type_id() == type_id {
+        return Ok(());
+    }
+    match coercion.and_then(|overflow| coerce_number(value, type_id, type_name, overflow)) {
+        Some(converted) => converted.map(drop),
+        None => Err(ApplyError::MismatchedTypes {
+            from_type: value.type_name().to_string(),
+            to_type: type_name.to_string(),
+        }),
+    }
+}
+
+/// A number read from any of the primitive number types.
+#[derive(Clone, Copy)]
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+const I128_BOUND: f64 = i128::MAX as f64;
+const U128_BOUND: f64 = u128::MAX as f64;
+
+impl Number {
+    fn read(value: &dyn Reflect) -> Option<Self> {
+        macro_rules! read {
+            ($($variant:ident: $($ty:ty),*;)*) => {
+                $($(
+                    if let Some(number) = value.downcast_ref::<$ty>() {
+                        return Some(Number::$variant(*number as _));
+                    }
+                )*)*
+            };
+        }
+
+        read! {
+            Signed: i8, i16, i32, i64, i128, isize;
+            Unsigned: u8, u16, u32, u64, u128, usize;
+            Float: f32, f64;
+        }
+        None
+    }
+
+    /// Truncates a float to an integer, returning `None` if it is `NaN`
+    /// or out of the range of both `i128` and `u128`.
+    fn trunc(self) -> Option<Self> {
+        match self {
+            Number::Float(number) => {
+                // The casts below truncate, and floats too large to have a fractional part
+                // are already whole, so the bounds hold for the truncated number too
+                if (-I128_BOUND..I128_BOUND).contains(&number) {
+                    Some(Number::Signed(number as i128))
+                } else if (0.0..U128_BOUND).contains(&number) {
+                    Some(Number::Unsigned(number as u128))
+                } else {
+                    None
+                }
+            }
+            integer => Some(integer),
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Number::Signed(number) => number as f64,
+            Number::Unsigned(number) => number as f64,
+            Number::Float(number) => number,
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Signed(number) => number.fmt(f),
+            Number::Unsigned(number) => number.fmt(f),
+            Number::Float(number) => number.fmt(f),
+        }
+    }
+}
+
+/// Converts `value` to the number type with the given [`TypeId`].
+///
+/// Returns `None` if either `value` or the type is not a primitive number.
+fn coerce_number(
+    value: &dyn Reflect,
+    type_id: TypeId,
+    type_name: &str,
+    overflow: OverflowPolicy,
+) -> Option<Result<Box<dyn Reflect>, ApplyError>> {
+    let number = Number::read(value)?;
+    let overflow_error = || ApplyError::NumericOverflow {
+        value: number.to_string(),
+        from_type: value.type_name().to_string(),
+        to_type: type_name.to_string(),
+    };
+
+    macro_rules! to_integer {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    let exact = match number.trunc() {
+                        Some(Number::Signed(number)) => <$ty>::try_from(number).ok(),
+                        Some(Number::Unsigned(number)) => <$ty>::try_from(number).ok(),
+                        _ => None,
+                    };
+                    let converted = match (exact, overflow) {
+                        (Some(converted), _) => converted,
+                        (None, OverflowPolicy::Wrap) => match number.trunc() {
+                            Some(Number::Signed(number)) => number as $ty,
+                            Some(Number::Unsigned(number)) => number as $ty,
+                            _ => number.as_f64() as $ty,
+                        },
+                        // Float to integer casts saturate.
+                        (None, OverflowPolicy::Saturate) => number.as_f64() as $ty,
+                        (None, OverflowPolicy::Error) => return Some(Err(overflow_error())),
+                    };
+                    return Some(Ok(Box::new(converted)));
+                }
+            )*
+        };
+    }
+
+    to_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    if type_id == TypeId::of::<f64>() {
+        return Some(Ok(Box::new(number.as_f64())));
+    }
+    if type_id == TypeId::of::<f32>() {
+        let wide = number.as_f64();
+        let narrow = wide as f32;
+        let converted = if wide.is_finite() && narrow.is_infinite() {
+            match overflow {
+                OverflowPolicy::Saturate => wide.clamp(f32::MIN as f64, f32::MAX as f64) as f32,
+                OverflowPolicy::Wrap => narrow,
+                OverflowPolicy::Error => return Some(Err(overflow_error())),
+            }
+        } else {
+            narrow
+        };
+        return Some(Ok(Box::new(converted)));
+    }
+    None
+}
+
+fn kind_name(reflect: &ReflectRef) -> &'static str {
+    match reflect {
+        ReflectRef::Struct(..) => "struct",
+        ReflectRef::TupleStruct(..) => "tuple struct",
+        ReflectRef::Tuple(..) => "tuple",
+        ReflectRef::List(..) => "list",
+        ReflectRef::Array(..) => "array",
+        ReflectRef::Map(..) => "map",
+        ReflectRef::Enum(..) => "enum",
+        ReflectRef::Value(..) => "value",
+    }
+}
+
+fn variant_kind_name(variant_type: VariantType) -> &'static str {
+    match variant_type {
+        VariantType::Struct => "struct",
+        VariantType::Tuple => "tuple",
+        VariantType::Unit => "unit",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_and_track, apply_to_each, apply_with_coercion, check_apply, ApplyError,
+        ApplyToEachError, OverflowPolicy,
+    };
+    use crate as bevy_reflect;
+    use crate::{
+        Array, DynamicEnum, DynamicList, DynamicStruct, DynamicTuple, FromReflect, ParsedPath,
+        Reflect,
+    };
+    use bevy_utils::{HashMap, HashSet};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Light {
+        intensity: f32,
+        enabled: bool,
+    }
+
+    #[test]
+    fn should_apply_to_each_list_element() {
+        let mut lights = vec![
+            Light {
+                intensity: 1.0,
+                enabled: false,
+            },
+            Light {
+                intensity: 2.0,
+                enabled: true,
+            },
+        ];
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("intensity", 10.0f32);
+
+        apply_to_each(&mut lights, &patch).unwrap();
+
+        assert_eq!(
+            vec![
+                Light {
+                    intensity: 10.0,
+                    enabled: false,
+                },
+                Light {
+                    intensity: 10.0,
+                    enabled: true,
+                },
+            ],
+            lights
+        );
+    }
+
+    #[test]
+    fn should_apply_to_each_map_value() {
+        let mut map = HashMap::default();
+        map.insert(1usize, 1i32);
+        map.insert(2usize, 2i32);
+
+        apply_to_each(&mut map, &7i32).unwrap();
+
+        assert_eq!(Some(&7), map.get(&1));
+        assert_eq!(Some(&7), map.get(&2));
+    }
+
+    #[test]
+    fn should_collect_element_errors() {
+        let mut list = DynamicList::default();
+        list.push(1i32);
+        list.push(String::from("two"));
+        list.push(3i32);
+
+        let result = apply_to_each(&mut list, &0i32);
+
+        assert_eq!(
+            Err(ApplyToEachError::Elements(vec![(
+                1,
+                ApplyError::MismatchedTypes {
+                    from_type: String::from("i32"),
+                    to_type: String::from("alloc::string::String"),
+                }
+            )])),
+            result
+        );
+        assert_eq!(Some(&0), list.get(0).unwrap().downcast_ref::<i32>());
+        assert_eq!(
+            Some(&String::from("two")),
+            list.get(1).unwrap().downcast_ref::<String>()
+        );
+        assert_eq!(Some(&0), list.get(2).unwrap().downcast_ref::<i32>());
+    }
+
+    #[test]
+    fn should_reject_non_collections() {
+        let mut value = 123usize;
+        assert_eq!(
+            Err(ApplyToEachError::NotACollection(String::from("usize"))),
+            apply_to_each(&mut value, &0usize)
+        );
+    }
+
+    #[test]
+    fn should_check_apply() {
+        let light = Light {
+            intensity: 1.0,
+            enabled: false,
+        };
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("enabled", 1u8);
+
+        assert_eq!(
+            Err(ApplyError::MismatchedTypes {
+                from_type: String::from("u8"),
+                to_type: String::from("bool"),
+            }),
+            check_apply(&light, &patch)
+        );
+        assert_eq!(
+            Err(ApplyError::MismatchedKinds {
+                from_kind: "value",
+                to_kind: "struct",
+            }),
+            check_apply(&light, &1.0f32)
+        );
+        assert_eq!(
+            Err(ApplyError::DifferentSize {
+                from_len: 2,
+                to_len: 3,
+            }),
+            check_apply(&[1, 2, 3], &[1, 2])
+        );
+    }
+
+    #[test]
+    fn should_check_fields_of_new_variants() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Shape {
+            Point,
+            Circle(f32),
+            Rect { width: f32, height: f32 },
+        }
+
+        let mut shape = Shape::Point;
+
+        let mut circle = DynamicEnum::new("Shape", "Circle", DynamicTuple::default());
+        assert_eq!(
+            Err(ApplyError::MissingVariantField {
+                enum_name: core::any::type_name::<Shape>().to_string(),
+                variant: String::from("Circle"),
+                field: String::from("0"),
+            }),
+            check_apply(&shape, &circle)
+        );
+
+        let mut radius = DynamicTuple::default();
+        radius.insert(1_u8);
+        circle.set_variant("Circle", radius);
+        assert_eq!(
+            Err(ApplyError::MismatchedTypes {
+                from_type: String::from("u8"),
+                to_type: String::from("f32"),
+            }),
+            check_apply(&shape, &circle)
+        );
+
+        let rect = DynamicEnum::new("Shape", "Rect", ());
+        assert_eq!(
+            Err(ApplyError::MismatchedVariantKinds {
+                enum_name: core::any::type_name::<Shape>().to_string(),
+                variant: String::from("Rect"),
+                from_kind: "unit",
+                to_kind: "struct",
+            }),
+            check_apply(&shape, &rect)
+        );
+
+        // Applying the checked values to every element doesn't panic
+        let mut shapes = vec![Shape::Point, Shape::Circle(2.0)];
+        assert!(apply_to_each(&mut shapes, &circle).is_err());
+        assert_eq!(vec![Shape::Point, Shape::Circle(2.0)], shapes);
+
+        let mut radius = DynamicTuple::default();
+        radius.insert(3.0_f32);
+        circle.set_variant("Circle", radius);
+        assert_eq!(Ok(()), check_apply(&shape, &circle));
+        shape.apply(&circle);
+        assert_eq!(Shape::Circle(3.0), shape);
+    }
+
+    #[test]
+    fn should_track_changed_paths() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Mode {
+            Off,
+            On(f32),
+        }
+
+        #[derive(Reflect)]
+        struct Room {
+            lights: Vec<Light>,
+            mode: Mode,
+            labels: HashMap<usize, String>,
+        }
+
+        let room = || Room {
+            lights: vec![Light {
+                intensity: 1.0,
+                enabled: false,
+            }],
+            mode: Mode::On(0.5),
+            labels: HashMap::from_iter([(0, String::from("door"))]),
+        };
+        let paths = |paths: &[&str]| {
+            paths
+                .iter()
+                .map(|path| ParsedPath::parse(path).unwrap())
+                .collect::<HashSet<_>>()
+        };
+
+        let mut target = room();
+        let mut patch = DynamicStruct::default();
+        patch.insert("mode", Mode::On(0.75));
+        patch.insert(
+            "lights",
+            vec![Light {
+                intensity: 1.0,
+                enabled: true,
+            }],
+        );
+        let changed = apply_and_track(&mut target, &patch).unwrap();
+        assert_eq!(paths(&["mode.0", "lights[0].enabled"]), changed);
+        assert!(target.lights[0].enabled);
+
+        let changed = apply_and_track(&mut target, &patch).unwrap();
+        assert!(changed.is_empty());
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("mode", Mode::Off);
+        patch.insert(
+            "labels",
+            HashMap::from_iter([(0_usize, String::from("window"))]),
+        );
+        let changed = apply_and_track(&mut target, &patch).unwrap();
+        assert_eq!(paths(&["mode", "labels"]), changed);
+        assert_eq!(Mode::Off, target.mode);
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("mode", 1_u8);
+        assert!(apply_and_track(&mut target, &patch).is_err());
+        assert_eq!("window", target.labels[&0]);
+    }
+
+    #[test]
+    fn should_apply_with_numeric_coercion() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Sample {
+            small: i8,
+            unsigned: u32,
+            ratio: f32,
+            lights: Vec<Light>,
+            counts: HashMap<u8, u64>,
+        }
+
+        let mut sample = Sample {
+            small: 0,
+            unsigned: 0,
+            ratio: 0.0,
+            lights: vec![Light {
+                intensity: 0.0,
+                enabled: false,
+            }],
+            counts: HashMap::default(),
+        };
+
+        let mut light = DynamicStruct::default();
+        light.insert("intensity", 2.5_f64);
+        let mut patch = DynamicStruct::default();
+        patch.insert("small", 200_i64);
+        patch.insert("unsigned", -1.9_f64);
+        patch.insert("ratio", 1e300_f64);
+        let mut lights = DynamicList::default();
+        lights.push(light);
+        patch.insert("lights", lights);
+        let mut counts = HashMap::default();
+        counts.insert(3_u8, 7.0_f64);
+        patch.insert("counts", counts);
+
+        apply_with_coercion(&mut sample, &patch, OverflowPolicy::Saturate).unwrap();
+        assert_eq!(127, sample.small);
+        assert_eq!(0, sample.unsigned);
+        assert_eq!(f32::MAX, sample.ratio);
+        assert_eq!(2.5, sample.lights[0].intensity);
+        assert_eq!(7, sample.counts[&3]);
+
+        apply_with_coercion(&mut sample, &patch, OverflowPolicy::Wrap).unwrap();
+        assert_eq!(-56, sample.small);
+        assert_eq!(u32::MAX, sample.unsigned);
+        assert_eq!(f32::INFINITY, sample.ratio);
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("small", 5_u8);
+        patch.insert("unsigned", f64::NAN);
+        assert_eq!(
+            apply_with_coercion(&mut sample, &patch, OverflowPolicy::Error),
+            Err(ApplyError::NumericOverflow {
+                value: String::from("NaN"),
+                from_type: String::from("f64"),
+                to_type: String::from("u32"),
+            })
+        );
+        assert_eq!(-56, sample.small);
+
+        patch.insert("unsigned", String::from("1"));
+        assert!(matches!(
+            apply_with_coercion(&mut sample, &patch, OverflowPolicy::Saturate),
+            Err(ApplyError::MismatchedTypes { .. })
+        ));
+    }
+}
@@ -0,0 +1,575 @@
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, FieldRange, FieldRanges, FromType, Map, Reflect, TypeInfo,
+    TypeRegistration, TypeRegistry, VariantInfo,
+};
+use rand::distributions::{Alphanumeric, Distribution, Standard};
+use rand::{Rng, RngCore};
+use std::any::TypeId;
+use thiserror::Error;
+
+/// Type data for generating random values of a type.
+///
+/// [`ReflectGenerator`] uses this in place of walking the [`TypeInfo`] of a type,
+/// and needs it for any value type other than the primitives it supports itself.
+/// It is registered with `#[reflect(Arbitrary)]` for types which `rand` can generate
+/// through its [`Standard`] distribution, and can otherwise be created with [`ReflectArbitrary::new`].
+#[derive(Clone)]
+pub struct ReflectArbitrary {
+    generate: fn(&mut dyn RngCore) -> Box<dyn Reflect>,
+}
+
+impl ReflectArbitrary {
+    /// Creates type data generating values with the given function.
+    pub fn new(generate: fn(&mut dyn RngCore) -> Box<dyn Reflect>) -> Self {
+        Self { generate }
+    }
+
+    /// Generates a random value of the type.
+    pub fn generate(&self, rng: &mut dyn RngCore) -> Box<dyn Reflect> {
+        (self.generate)(rng)
+    }
+}
+
+impl<T: Reflect> FromType<T> for ReflectArbitrary
+where
+    Standard: Distribution<T>,
+{
+    fn from_type() -> Self {
+        Self::new(|rng| Box::new(rng.gen::<T>()))
+    }
+}
+
+/// Limits on the size of the values made by a [`ReflectGenerator`].
+#[derive(Debug, Clone, Copy)]
+pub struct ArbitraryConfig {
+    max_len: usize,
+    max_depth: usize,
+}
+
+impl Default for ArbitraryConfig {
+    fn default() -> Self {
+        Self {
+            max_len: 8,
+            max_depth: 8,
+        }
+    }
+}
+
+impl ArbitraryConfig {
+    /// Sets the greatest number of elements of generated lists and maps,
+    /// and of characters of generated strings.
+    ///
+    /// Defaults to 8.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Sets how deeply values may be nested before lists and maps are left empty,
+    /// and enums are given unit variants where they have any.
+    ///
+    /// This keeps values of recursive types, such as trees, from growing without bound.
+    /// Defaults to 8.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// The greatest number of elements of generated lists and maps.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// How deeply values may be nested before lists and maps are left empty.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+/// An error returned by [`ReflectGenerator::generate`].
+#[derive(Debug, Clone, Error)]
+pub enum ArbitraryError {
+    /// A type within the value is not registered.
+    #[error("no registration found for type `{0}`")]
+    MissingRegistration(String),
+    /// A value type is neither a supported primitive nor has [`ReflectArbitrary`] type data.
+    #[error("cannot generate values of type `{0}`, as it has no `ReflectArbitrary` type data")]
+    Unsupported(&'static str),
+    /// The range of a field contains no values of its type.
+    #[error("the range {range:?} contains no values of type `{type_name}`")]
+    EmptyRange {
+        type_name: &'static str,
+        range: FieldRange,
+    },
+}
+
+/// Generates random values of registered types from their [`TypeInfo`],
+/// for fuzzing and property tests.
+///
+/// Structs, tuples, lists, arrays, maps and enums are generated field by field,
+/// picking the variants of enums and the lengths of lists and maps at random,
+/// and are returned as their dynamic equivalents, which [`FromReflect`] can convert
+/// back to the concrete type.
+/// Values of types with [`ReflectArbitrary`] type data are generated with it instead.
+///
+/// Primitive values are supported without any type data: booleans, integers, floats,
+/// characters and alphanumeric strings. Numbers held by fields with a [`FieldRange`],
+/// including within lists, maps, options and other containers, are generated within that range.
+/// Otherwise, integers may take any value, and floats are between `-1e6` and `1e6`.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, ReflectGenerator, TypeRegistry};
+/// # use rand::SeedableRng;
+/// #[derive(Reflect, FromReflect)]
+/// struct Stats {
+///     #[reflect(range = "1..=20")]
+///     strength: u8,
+///     skills: Vec<String>,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Stats>();
+/// registry.register::<Vec<String>>();
+/// registry.register::<String>();
+/// registry.register::<u8>();
+///
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+/// let registration = registry.get(std::any::TypeId::of::<Stats>()).unwrap();
+/// let generator = ReflectGenerator::new(&registry);
+/// for _ in 0..100 {
+///     let value = generator.generate(registration, &mut rng).unwrap();
+///     let stats = Stats::from_reflect(&*value).unwrap();
+///     assert!((1..=20).contains(&stats.strength));
+/// }
+/// ```
+///
+/// [`FromReflect`]: crate::FromReflect
+pub struct ReflectGenerator<'a> {
+    registry: &'a TypeRegistry,
+    config: ArbitraryConfig,
+}
+
+impl<'a> ReflectGenerator<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            config: ArbitraryConfig::default(),
+        }
+    }
+
+    /// Sets the limits on the size of generated values.
+    pub fn with_config(mut self, config: ArbitraryConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Generates a random value of the given type.
+    pub fn generate<R: Rng + ?Sized>(
+        &self,
+        registration: &TypeRegistration,
+        rng: &mut R,
+    ) -> Result<Box<dyn Reflect>, ArbitraryError> {
+        let mut rng = RngRef(rng);
+        self.generate_type(
+            registration.type_id(),
+            registration.type_name(),
+            None,
+            0,
+            &mut rng,
+        )
+    }
+
+    fn generate_type(
+        &self,
+        type_id: TypeId,
+        type_name: &str,
+        range: Option<&FieldRange>,
+        depth: usize,
+        rng: &mut dyn RngCore,
+    ) -> Result<Box<dyn Reflect>, ArbitraryError> {
+        let registration = self
+            .registry
+            .get(type_id)
+            .ok_or_else(|| ArbitraryError::MissingRegistration(type_name.to_string()))?;
+        if let Some(arbitrary) = registration.data::<ReflectArbitrary>() {
+            return Ok(arbitrary.generate(rng));
+        }
+
+        let depth = depth + 1;
+        let at_max_depth = depth > self.config.max_depth;
+        let ranges = registration.data::<FieldRanges>();
+        let field_range = |index| ranges.and_then(|ranges| ranges.get(index));
+
+        Ok(match registration.type_info() {
+            TypeInfo::Struct(info) => {
+                let mut value = DynamicStruct::default();
+                value.set_name(info.type_name().to_string());
+                for (index, field) in info.iter().enumerate() {
+                    let field_value = self.generate_type(
+                        field.type_id(),
+                        field.type_name(),
+                        field_range(index),
+                        depth,
+                        rng,
+                    )?;
+                    value.insert_boxed(field.name(), field_value);
+                }
+                Box::new(value)
+            }
+            TypeInfo::TupleStruct(info) => {
+                let mut value = DynamicTupleStruct::default();
+                value.set_name(info.type_name().to_string());
+                for (index, field) in info.iter().enumerate() {
+                    value.insert_boxed(self.generate_type(
+                        field.type_id(),
+                        field.type_name(),
+                        field_range(index),
+                        depth,
+                        rng,
+                    )?);
+                }
+                Box::new(value)
+            }
+            TypeInfo::Tuple(info) => {
+                let mut value = DynamicTuple::default();
+                value.set_name(info.type_name().to_string());
+                for field in info.iter() {
+                    value.insert_boxed(self.generate_type(
+                        field.type_id(),
+                        field.type_name(),
+                        range,
+                        depth,
+                        rng,
+                    )?);
+                }
+                Box::new(value)
+            }
+            TypeInfo::List(info) => {
+                let mut value = DynamicList::default();
+                value.set_name(info.type_name().to_string());
+                for _ in 0..self.gen_len(at_max_depth, rng) {
+                    value.push_box(self.generate_type(
+                        info.item_type_id(),
+                        info.item_type_name(),
+                        range,
+                        depth,
+                        rng,
+                    )?);
+                }
+                Box::new(value)
+            }
+            TypeInfo::Array(info) => {
+                let items = (0..info.capacity())
+                    .map(|_| {
+                        self.generate_type(
+                            info.item_type_id(),
+                            info.item_type_name(),
+                            range,
+                            depth,
+                            rng,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut value = DynamicArray::new(items.into_boxed_slice());
+                value.set_name(info.type_name().to_string());
+                Box::new(value)
+            }
+            TypeInfo::Map(info) => {
+                let mut value = DynamicMap::default();
+                value.set_name(info.type_name().to_string());
+                // Entries with duplicate keys replace each other, so the map may end up shorter
+                for _ in 0..self.gen_len(at_max_depth, rng) {
+                    let key = self.generate_type(
+                        info.key_type_id(),
+                        info.key_type_name(),
+                        range,
+                        depth,
+                        rng,
+                    )?;
+                    let entry = self.generate_type(
+                        info.value_type_id(),
+                        info.value_type_name(),
+                        range,
+                        depth,
+                        rng,
+                    )?;
+                    value.insert_boxed(key, entry);
+                }
+                Box::new(value)
+            }
+            TypeInfo::Enum(info) => {
+                let unit_variants = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, variant)| matches!(variant, VariantInfo::Unit(..)))
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>();
+                let index = if at_max_depth && !unit_variants.is_empty() {
+                    unit_variants[rng.gen_range(0..unit_variants.len())]
+                } else if info.variant_len() == 0 {
+                    return Err(ArbitraryError::Unsupported(info.type_name()));
+                } else {
+                    rng.gen_range(0..info.variant_len())
+                };
+
+                let variant = match info.variant_at(index).unwrap() {
+                    VariantInfo::Struct(variant) => {
+                        let mut fields = DynamicStruct::default();
+                        for field in variant.iter() {
+                            let field_value = self.generate_type(
+                                field.type_id(),
+                                field.type_name(),
+                                range,
+                                depth,
+                                rng,
+                            )?;
+                            fields.insert_boxed(field.name(), field_value);
+                        }
+                        DynamicVariant::Struct(fields)
+                    }
+                    VariantInfo::Tuple(variant) => {
+                        let mut fields = DynamicTuple::default();
+                        for field in variant.iter() {
+                            fields.insert_boxed(self.generate_type(
+                                field.type_id(),
+                                field.type_name(),
+                                range,
+                                depth,
+                                rng,
+                            )?);
+                        }
+                        DynamicVariant::Tuple(fields)
+                    }
+                    VariantInfo::Unit(..) => DynamicVariant::Unit,
+                };
+                let variant_name = info.variant_names()[index];
+                Box::new(DynamicEnum::new_with_index(
+                    info.type_name(),
+                    index,
+                    variant_name,
+                    variant,
+                ))
+            }
+            TypeInfo::Value(info) => generate_primitive(info.type_id(), range, self.config, rng)
+                .unwrap_or_else(|| Err(ArbitraryError::Unsupported(info.type_name())))?,
+            TypeInfo::Dynamic(info) => return Err(ArbitraryError::Unsupported(info.type_name())),
+        })
+    }
+
+    fn gen_len(&self, at_max_depth: bool, rng: &mut dyn RngCore) -> usize {
+        if at_max_depth {
+            0
+        } else {
+            rng.gen_range(0..=self.config.max_len)
+        }
+    }
+}
+
+/// Generates a primitive value, or returns `None` if the type is not a supported primitive.
+fn generate_primitive(
+    type_id: TypeId,
+    range: Option<&FieldRange>,
+    config: ArbitraryConfig,
+    rng: &mut dyn RngCore,
+) -> Option<Result<Box<dyn Reflect>, ArbitraryError>> {
+    macro_rules! generate_integers {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    let Some(range) = range else {
+                        return Some(Ok(Box::new(rng.gen::<$ty>())));
+                    };
+                    // Casting from a float saturates, keeping the bounds within the type
+                    let start = range.start().ceil() as $ty;
+                    let end = if range.is_inclusive() || range.end() > <$ty>::MAX as f64 {
+                        range.end().floor() as $ty
+                    } else {
+                        (range.end().ceil() as $ty).saturating_sub(1)
+                    };
+                    if range.is_empty() || start > end {
+                        return Some(Err(ArbitraryError::EmptyRange {
+                            type_name: std::any::type_name::<$ty>(),
+                            range: *range,
+                        }));
+                    }
+                    return Some(Ok(Box::new(rng.gen_range(start..=end))));
+                }
+            )*
+        };
+    }
+    macro_rules! generate_floats {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    let range = range.copied().unwrap_or(FieldRange::new(-1e6, 1e6, false));
+                    if range.is_empty() || !range.start().is_finite() || !range.end().is_finite() {
+                        return Some(Err(ArbitraryError::EmptyRange {
+                            type_name: std::any::type_name::<$ty>(),
+                            range,
+                        }));
+                    }
+                    let value = if range.is_inclusive() {
+                        rng.gen_range(range.start()..=range.end())
+                    } else {
+                        rng.gen_range(range.start()..range.end())
+                    };
+                    return Some(Ok(Box::new(value as $ty)));
+                }
+            )*
+        };
+    }
+
+    generate_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    generate_floats!(f32, f64);
+    if type_id == TypeId::of::<bool>() {
+        return Some(Ok(Box::new(rng.gen::<bool>())));
+    }
+    if type_id == TypeId::of::<char>() {
+        return Some(Ok(Box::new(rng.gen::<char>())));
+    }
+    if type_id == TypeId::of::<String>() {
+        let len = rng.gen_range(0..=config.max_len);
+        let string = (0..len)
+            .map(|_| char::from(Alphanumeric.sample(rng)))
+            .collect::<String>();
+        return Some(Ok(Box::new(string)));
+    }
+    None
+}
+
+/// Lets a generic [`Rng`] be passed around as a `&mut dyn RngCore`, even if it is unsized.
+struct RngRef<'a, R: ?Sized>(&'a mut R);
+
+impl<'a, R: RngCore + ?Sized> RngCore for RngRef<'a, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArbitraryConfig, ArbitraryError, ReflectArbitrary, ReflectGenerator};
+    use crate::{self as bevy_reflect, FromReflect, Reflect, TypeRegistry};
+    use bevy_utils::HashMap;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::any::TypeId;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    enum Shape {
+        Point,
+        Circle { radius: f32 },
+        Polygon(Vec<f32>),
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Level {
+        #[reflect(range = "1..10")]
+        difficulty: i32,
+        #[reflect(range = "0.0..=1.0")]
+        brightness: Vec<f64>,
+        shapes: Vec<Shape>,
+        spawns: HashMap<String, (u8, bool)>,
+        boss: Option<Boss>,
+        color: [u8; 3],
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    #[reflect(Arbitrary)]
+    struct Boss(u16);
+
+    impl rand::distributions::Distribution<Boss> for rand::distributions::Standard {
+        fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Boss {
+            Boss(rng.gen_range(100..200))
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry.register::<Shape>();
+        registry.register::<Boss>();
+        registry.register::<Vec<f64>>();
+        registry.register::<Vec<f32>>();
+        registry.register::<Vec<Shape>>();
+        registry.register::<HashMap<String, (u8, bool)>>();
+        registry.register::<(u8, bool)>();
+        registry.register::<Option<Boss>>();
+        registry.register::<[u8; 3]>();
+        registry.register::<String>();
+        registry.register::<i32>();
+        registry.register::<f32>();
+        registry.register::<f64>();
+        registry.register::<u8>();
+        registry.register::<bool>();
+        registry
+    }
+
+    #[test]
+    fn should_generate_values_within_ranges() {
+        let registry = registry();
+        let registration = registry.get(TypeId::of::<Level>()).unwrap();
+        let generator = ReflectGenerator::new(&registry)
+            .with_config(ArbitraryConfig::default().with_max_len(4).with_max_depth(4));
+        let mut rng = StdRng::seed_from_u64(1234);
+
+        let mut saw_boss = false;
+        for _ in 0..200 {
+            let value = generator.generate(registration, &mut rng).unwrap();
+            let level = Level::from_reflect(&*value).unwrap();
+            assert!((1..10).contains(&level.difficulty));
+            assert!(level.brightness.len() <= 4);
+            assert!(level
+                .brightness
+                .iter()
+                .all(|brightness| (0.0..=1.0).contains(brightness)));
+            assert!(level.spawns.len() <= 4);
+            if let Some(Boss(health)) = level.boss {
+                assert!((100..200).contains(&health));
+                saw_boss = true;
+            }
+        }
+        assert!(saw_boss);
+    }
+
+    #[test]
+    fn should_fail_on_missing_registration_or_empty_range() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vec<Shape>>();
+        let registration = registry.get(TypeId::of::<Vec<Shape>>()).unwrap();
+        let generator = ReflectGenerator::new(&registry);
+        let mut rng = StdRng::seed_from_u64(0);
+        let error = (0..10)
+            .find_map(|_| generator.generate(registration, &mut rng).err())
+            .unwrap();
+        assert!(
+            matches!(error, ArbitraryError::MissingRegistration(name) if name == std::any::type_name::<Shape>())
+        );
+
+        #[derive(Reflect)]
+        struct Empty(#[reflect(range = "0.2..0.8")] u8);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Empty>();
+        registry.register::<u8>();
+        let registration = registry.get(TypeId::of::<Empty>()).unwrap();
+        let error = ReflectGenerator::new(&registry)
+            .generate(registration, &mut rng)
+            .unwrap_err();
+        assert!(matches!(error, ArbitraryError::EmptyRange { .. }));
+    }
+}
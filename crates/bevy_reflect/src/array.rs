@@ -1,12 +1,23 @@
+use crate::allocation_stats::{record_allocations, AllocationSource};
+use crate::cycle::{debug_guarded, expect_guarded};
+use crate::debug_options::{shown_elements, write_type_path, Omitted};
+use crate::reflect::iter_partial_cmp;
 use crate::{
-    utility::NonGenericTypeInfoCell, DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef,
-    TypeInfo, Typed,
+    utility::NonGenericTypeInfoCell, DynamicInfo, FromReflect, Reflect, ReflectMut, ReflectOwned,
+    ReflectRef, TypeInfo, Typed,
 };
-use std::{
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
     any::{Any, TypeId},
+    cmp::Ordering,
     fmt::Debug,
     hash::{Hash, Hasher},
 };
+use thiserror::Error;
 
 /// A static-sized array of [`Reflect`] items.
 ///
@@ -34,14 +45,107 @@ pub trait Array: Reflect {
     /// Drain the elements of this array to get a vector of owned values.
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>>;
 
+    /// Replaces the element at `index` with `value`.
+    ///
+    /// Fails without modifying the array if `index` is out of bounds,
+    /// or if `value` cannot be stored in the array.
+    /// Dynamic arrays accept values of any type.
+    ///
+    /// This isn't named `set` as it would be ambiguous with [`Reflect::set`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{Array, ArrayError};
+    /// let mut buffer = [1u8, 2, 3];
+    /// buffer.set_element(0, Box::new(4u8)).unwrap();
+    /// assert_eq!([4, 2, 3], buffer);
+    ///
+    /// assert!(matches!(
+    ///     buffer.set_element(3, Box::new(5u8)),
+    ///     Err(ArrayError::OutOfBounds { index: 3, len: 3 })
+    /// ));
+    /// assert!(matches!(
+    ///     buffer.set_element(0, Box::new(5u32)),
+    ///     Err(ArrayError::MismatchedTypes { .. })
+    /// ));
+    /// ```
+    fn set_element(&mut self, index: usize, value: Box<dyn Reflect>) -> Result<(), ArrayError> {
+        let len = self.len();
+        let element = self
+            .get_mut(index)
+            .ok_or(ArrayError::OutOfBounds { index, len })?;
+        element
+            .set(value)
+            .map_err(|value| ArrayError::MismatchedTypes {
+                from_type: value.type_name().to_string(),
+                to_type: element.type_name().to_string(),
+            })
+    }
+
+    /// Swaps the elements at indices `a` and `b`.
+    ///
+    /// Fails without modifying the array if either index is out of bounds.
+    ///
+    /// This isn't named `swap` so that it doesn't shadow `<[T]>::swap` for `Vec`s and arrays
+    /// whenever this trait is in scope.
+    ///
+    /// The default implementation swaps the elements by [applying](Reflect::apply)
+    /// clones of each to the other.
+    fn swap_elements(&mut self, a: usize, b: usize) -> Result<(), ArrayError> {
+        check_index(a, self.len())?;
+        check_index(b, self.len())?;
+        if a != b {
+            let value_a = self.get(a).unwrap().clone_value();
+            let value_b = self.get(b).unwrap().clone_value();
+            self.get_mut(a).unwrap().apply(&*value_b);
+            self.get_mut(b).unwrap().apply(&*value_a);
+        }
+        Ok(())
+    }
+
     fn clone_dynamic(&self) -> DynamicArray {
-        DynamicArray {
+        record_allocations(AllocationSource::CloneDynamic, self.len());
+        expect_guarded(self.as_reflect(), "clone", || DynamicArray {
             name: self.type_name().to_string(),
             values: self.iter().map(|value| value.clone_value()).collect(),
-        }
+        })
     }
 }
 
+/// An error returned when editing an [`Array`] through [`Array::set_element`] or [`Array::swap_elements`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ArrayError {
+    #[error("index {index} is out of bounds for an array of length {len}")]
+    OutOfBounds { index: usize, len: usize },
+    #[error("attempted to store a value of type `{from_type}` in an array of `{to_type}`")]
+    MismatchedTypes { from_type: String, to_type: String },
+}
+
+#[inline]
+pub(crate) fn check_index(index: usize, len: usize) -> Result<(), ArrayError> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(ArrayError::OutOfBounds { index, len })
+    }
+}
+
+/// Stores `value` in `element`, the element at `index` of an array of `T`s,
+/// converting it with [`FromReflect`] if needed.
+pub(crate) fn store_element<T: FromReflect>(
+    element: Option<&mut T>,
+    index: usize,
+    len: usize,
+    value: Box<dyn Reflect>,
+) -> Result<(), ArrayError> {
+    let element = element.ok_or(ArrayError::OutOfBounds { index, len })?;
+    *element = T::take_from_reflect(value).map_err(|value| ArrayError::MismatchedTypes {
+        from_type: value.type_name().to_string(),
+        to_type: core::any::type_name::<T>().to_string(),
+    })?;
+    Ok(())
+}
+
 /// A container for compile-time array info.
 #[derive(Clone, Debug)]
 pub struct ArrayInfo {
@@ -63,9 +167,9 @@ impl ArrayInfo {
     ///
     pub fn new<TArray: Array, TItem: Reflect>(capacity: usize) -> Self {
         Self {
-            type_name: std::any::type_name::<TArray>(),
+            type_name: core::any::type_name::<TArray>(),
             type_id: TypeId::of::<TArray>(),
-            item_type_name: std::any::type_name::<TItem>(),
+            item_type_name: core::any::type_name::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
             capacity,
             #[cfg(feature = "documentation")]
@@ -250,6 +354,10 @@ impl Reflect for DynamicArray {
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         array_partial_eq(self, value)
     }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        array_partial_cmp(self, value)
+    }
 }
 
 impl Array for DynamicArray {
@@ -281,16 +389,30 @@ impl Array for DynamicArray {
         self.values.into_vec()
     }
 
+    fn set_element(&mut self, index: usize, value: Box<dyn Reflect>) -> Result<(), ArrayError> {
+        check_index(index, self.values.len())?;
+        self.values[index] = value;
+        Ok(())
+    }
+
+    fn swap_elements(&mut self, a: usize, b: usize) -> Result<(), ArrayError> {
+        check_index(a, self.values.len())?;
+        check_index(b, self.values.len())?;
+        self.values.swap(a, b);
+        Ok(())
+    }
+
     #[inline]
     fn clone_dynamic(&self) -> DynamicArray {
-        DynamicArray {
+        record_allocations(AllocationSource::CloneDynamic, self.values.len());
+        expect_guarded(self.as_reflect(), "clone", || DynamicArray {
             name: self.name.clone(),
             values: self
                 .values
                 .iter()
                 .map(|value| value.clone_value())
                 .collect(),
-        }
+        })
     }
 }
 
@@ -330,7 +452,7 @@ impl<'a> ExactSizeIterator for ArrayIter<'a> {}
 #[inline]
 pub fn array_hash<A: Array>(array: &A) -> Option<u64> {
     let mut hasher = crate::ReflectHasher::default();
-    std::any::Any::type_id(array).hash(&mut hasher);
+    core::any::Any::type_id(array).hash(&mut hasher);
     array.len().hash(&mut hasher);
     for value in array.iter() {
         hasher.write_u64(value.reflect_hash()?);
@@ -347,17 +469,19 @@ pub fn array_hash<A: Array>(array: &A) -> Option<u64> {
 ///
 #[inline]
 pub fn array_apply<A: Array>(array: &mut A, reflect: &dyn Reflect) {
-    if let ReflectRef::Array(reflect_array) = reflect.reflect_ref() {
-        if array.len() != reflect_array.len() {
-            panic!("Attempted to apply different sized `Array` types.");
-        }
-        for (i, value) in reflect_array.iter().enumerate() {
-            let v = array.get_mut(i).unwrap();
-            v.apply(value);
+    expect_guarded(reflect, "apply", || {
+        if let ReflectRef::Array(reflect_array) = reflect.reflect_ref() {
+            if array.len() != reflect_array.len() {
+                panic!("Attempted to apply different sized `Array` types.");
+            }
+            for (i, value) in reflect_array.iter().enumerate() {
+                let v = array.get_mut(i).unwrap();
+                v.apply(value);
+            }
+        } else {
+            panic!("Attempted to apply a non-`Array` type to an `Array` type.");
         }
-    } else {
-        panic!("Attempted to apply a non-`Array` type to an `Array` type.");
-    }
+    });
 }
 
 /// Compares two [arrays](Array) (one concrete and one reflected) to see if they
@@ -381,6 +505,18 @@ pub fn array_partial_eq<A: Array>(array: &A, reflect: &dyn Reflect) -> Option<bo
     Some(true)
 }
 
+/// Compares an [`Array`] with a [`Reflect`] value lexicographically.
+///
+/// Returns [`None`] if `b` is not an array, or if [`Reflect::reflect_partial_cmp`]
+/// returns `None` for a pair of elements compared.
+#[inline]
+pub fn array_partial_cmp<A: Array>(a: &A, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Array(array) = b.reflect_ref() else {
+        return None;
+    };
+    iter_partial_cmp(a.iter(), array.iter())
+}
+
 /// The default debug formatter for [`Array`] types.
 ///
 /// # Example
@@ -399,10 +535,17 @@ pub fn array_partial_eq<A: Array>(array: &A, reflect: &dyn Reflect) -> Option<bo
 /// // ]
 /// ```
 #[inline]
-pub fn array_debug(dyn_array: &dyn Array, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut debug = f.debug_list();
-    for item in dyn_array.iter() {
-        debug.entry(&item as &dyn Debug);
-    }
-    debug.finish()
+pub fn array_debug(dyn_array: &dyn Array, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    debug_guarded(dyn_array.as_reflect(), f, |f| {
+        write_type_path(dyn_array.as_reflect(), f)?;
+        let mut debug = f.debug_list();
+        let shown = shown_elements(dyn_array.len());
+        for item in dyn_array.iter().take(shown) {
+            debug.entry(&item as &dyn Debug);
+        }
+        if shown < dyn_array.len() {
+            debug.entry(&Omitted(dyn_array.len() - shown));
+        }
+        debug.finish()
+    })
 }
@@ -0,0 +1,132 @@
+//! Custom attributes attached to the fields of reflected types,
+//! along with the standard attributes used by editors and inspectors.
+//!
+//! Attributes are added with `#[reflect(@...)]`, where `...` is an expression which
+//! can be evaluated in a constant, such as a call to a `const fn`.
+//! Several attributes may be given at once, separated by commas:
+//!
+//! ```
+//! # use bevy_reflect::{Reflect, TypeInfo, Typed};
+//! use bevy_reflect::attributes::{Category, Range, Step, Tooltip};
+//!
+//! #[derive(Reflect)]
+//! struct Light {
+//!     #[reflect(@Range::new(0.0, 10.0), @Step(0.5))]
+//!     #[reflect(@Tooltip("The brightness of the light"), @Category("Lighting"))]
+//!     intensity: f32,
+//! }
+//!
+//! let TypeInfo::Struct(info) = Light::type_info() else { unreachable!() };
+//! let intensity = info.field("intensity").unwrap();
+//! assert_eq!(Some(&Range::new(0.0, 10.0)), intensity.range());
+//! assert_eq!(Some("The brightness of the light"), intensity.tooltip());
+//! assert_eq!(Some("Lighting"), intensity.category());
+//! assert!(!intensity.is_multiline());
+//! ```
+//!
+//! Any type which is `Send + Sync + 'static` can be used as an attribute,
+//! and retrieved with [`CustomAttributes::get`].
+
+use core::any::{Any, TypeId};
+use core::slice::Iter;
+
+/// The custom attributes of a field, added with `#[reflect(@...)]`.
+///
+/// Attributes are identified by their type, so a field may only have one attribute of each type.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomAttributes {
+    attributes: &'static [&'static (dyn Any + Send + Sync)],
+}
+
+impl CustomAttributes {
+    /// Attributes of a field which has none.
+    pub const EMPTY: Self = Self::new(&[]);
+
+    /// Creates a set of attributes from a static slice.
+    pub const fn new(attributes: &'static [&'static (dyn Any + Send + Sync)]) -> Self {
+        Self { attributes }
+    }
+
+    /// Returns the attribute of type `T`, if there is one.
+    pub fn get<T: Any>(&self) -> Option<&'static T> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| attribute.downcast_ref::<T>())
+    }
+
+    /// Returns the attribute with the given [`TypeId`], if there is one.
+    pub fn get_by_id(&self, type_id: TypeId) -> Option<&'static (dyn Any + Send + Sync)> {
+        self.attributes
+            .iter()
+            .copied()
+            .find(|attribute| (**attribute).type_id() == type_id)
+    }
+
+    /// Returns `true` if there is an attribute of type `T`.
+    pub fn contains<T: Any>(&self) -> bool {
+        self.get::<T>().is_some()
+    }
+
+    /// Returns an iterator over the attributes, in the order they were declared.
+    pub fn iter(&self) -> Iter<'static, &'static (dyn Any + Send + Sync)> {
+        self.attributes.iter()
+    }
+
+    /// Returns the number of attributes.
+    pub const fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns `true` if there are no attributes.
+    pub const fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+impl Default for CustomAttributes {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// The inclusive range of values a numeric field should be edited within.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Range {
+    /// The smallest value of the range.
+    pub min: f64,
+    /// The largest value of the range.
+    pub max: f64,
+}
+
+impl Range {
+    /// Creates a range from `min` to `max`, both included.
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `true` if `value` is within the range.
+    pub fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    /// Returns `value` limited to the range.
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+}
+
+/// The amount a numeric field should change by for each step, such as when dragged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step(pub f64);
+
+/// A description of a field, shown when hovering over it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tooltip(pub &'static str);
+
+/// The name of a group of fields which should be shown together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Category(pub &'static str);
+
+/// Marks a text field as holding several lines, to be edited in a larger text box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Multiline;
@@ -0,0 +1,169 @@
+use crate::{Reflect, TypeData};
+use alloc::{boxed::Box, sync::Arc};
+use bevy_utils::HashMap;
+use core::any::{Any, TypeId};
+
+type CastRef<T> = fn(&dyn Reflect) -> Option<&T>;
+type CastMut<T> = fn(&mut dyn Reflect) -> Option<&mut T>;
+type CastBoxed<T> = fn(Box<dyn Reflect>) -> Result<Box<T>, Box<dyn Reflect>>;
+
+/// The functions used to cast a `dyn Reflect` to the trait object `T` (such as `dyn MyTrait`).
+///
+/// A caster is only valid for the concrete type it was created for.
+/// Casters are usually obtained from the type data generated by [`#[reflect_trait]`][0]
+/// through [`TraitCast::caster`].
+///
+/// [0]: crate::reflect_trait
+pub struct TraitCaster<T: ?Sized> {
+    get: CastRef<T>,
+    get_mut: CastMut<T>,
+    get_boxed: CastBoxed<T>,
+}
+
+impl<T: ?Sized> TraitCaster<T> {
+    /// Creates a caster from functions casting a reference, a mutable reference and a box.
+    pub fn new(get: CastRef<T>, get_mut: CastMut<T>, get_boxed: CastBoxed<T>) -> Self {
+        Self {
+            get,
+            get_mut,
+            get_boxed,
+        }
+    }
+
+    /// Casts a `&dyn Reflect` to `&T`, returning `None` if it is of the wrong type.
+    pub fn cast_ref<'a>(&self, value: &'a dyn Reflect) -> Option<&'a T> {
+        (self.get)(value)
+    }
+
+    /// Casts a `&mut dyn Reflect` to `&mut T`, returning `None` if it is of the wrong type.
+    pub fn cast_mut<'a>(&self, value: &'a mut dyn Reflect) -> Option<&'a mut T> {
+        (self.get_mut)(value)
+    }
+
+    /// Casts a `Box<dyn Reflect>` to `Box<T>`, returning the original box if it is of the wrong type.
+    pub fn cast_boxed(&self, value: Box<dyn Reflect>) -> Result<Box<T>, Box<dyn Reflect>> {
+        (self.get_boxed)(value)
+    }
+}
+
+impl<T: ?Sized> Clone for TraitCaster<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for TraitCaster<T> {}
+
+/// Type data which can cast a `dyn Reflect` to a trait object.
+///
+/// This is implemented by the type data generated by [`#[reflect_trait]`][0],
+/// such that `ReflectMyTrait` has a `Target` of `dyn MyTrait`.
+///
+/// [0]: crate::reflect_trait
+pub trait TraitCast: TypeData {
+    /// The trait object this type data casts to.
+    type Target: ?Sized + 'static;
+
+    /// Returns the caster for the type this type data was created for.
+    fn caster(&self) -> TraitCaster<Self::Target>;
+}
+
+/// Type data for casting a reflected value to any of its registered trait objects.
+///
+/// Unlike type data such as `ReflectMyTrait`, which has to be known statically,
+/// this allows casting to a trait object given only the trait object type,
+/// such as `&dyn Damageable`, making it possible to work generically over
+/// capability traits discovered at runtime.
+///
+/// Casts are added with [`TypeRegistry::register_trait_cast`], and can be performed
+/// directly with [`TypeRegistry::cast_ref`] and friends.
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{reflect_trait, Reflect, ReflectCast, TypeRegistry};
+/// #[reflect_trait]
+/// trait Damageable {
+///     fn damage(&mut self, amount: u32);
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Health(u32);
+///
+/// impl Damageable for Health {
+///     fn damage(&mut self, amount: u32) {
+///         self.0 = self.0.saturating_sub(amount);
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+/// registry.register_trait_cast::<Health, ReflectDamageable>();
+///
+/// let mut value: Box<dyn Reflect> = Box::new(Health(10));
+/// registry
+///     .cast_mut::<dyn Damageable>(value.as_mut())
+///     .unwrap()
+///     .damage(3);
+/// assert_eq!(7, value.downcast_ref::<Health>().unwrap().0);
+///
+/// let reflect_cast = registry
+///     .get_type_data::<ReflectCast>(TypeId::of::<Health>())
+///     .unwrap();
+/// assert!(reflect_cast.can_cast::<dyn Damageable>());
+/// ```
+///
+/// [`TypeRegistry::register_trait_cast`]: crate::TypeRegistry::register_trait_cast
+/// [`TypeRegistry::cast_ref`]: crate::TypeRegistry::cast_ref
+#[derive(Clone, Default)]
+pub struct ReflectCast {
+    casters: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl ReflectCast {
+    /// Adds a caster to the trait object `T`, replacing any existing one.
+    pub fn insert<T: ?Sized + 'static>(&mut self, caster: TraitCaster<T>) {
+        self.casters.insert(TypeId::of::<T>(), Arc::new(caster));
+    }
+
+    /// Returns `true` if a caster to the trait object `T` has been added.
+    pub fn can_cast<T: ?Sized + 'static>(&self) -> bool {
+        self.casters.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the caster to the trait object `T`, if it has been added.
+    pub fn caster<T: ?Sized + 'static>(&self) -> Option<&TraitCaster<T>> {
+        self.casters.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Casts a `&dyn Reflect` to the trait object `T`.
+    ///
+    /// Returns `None` if no caster to `T` has been added or the value is of the wrong type.
+    pub fn cast_ref<'a, T: ?Sized + 'static>(&self, value: &'a dyn Reflect) -> Option<&'a T> {
+        self.caster::<T>()?.cast_ref(value)
+    }
+
+    /// Casts a `&mut dyn Reflect` to the trait object `T`.
+    ///
+    /// Returns `None` if no caster to `T` has been added or the value is of the wrong type.
+    pub fn cast_mut<'a, T: ?Sized + 'static>(
+        &self,
+        value: &'a mut dyn Reflect,
+    ) -> Option<&'a mut T> {
+        self.caster::<T>()?.cast_mut(value)
+    }
+
+    /// Casts a `Box<dyn Reflect>` to the trait object `T`.
+    ///
+    /// Returns the original box if no caster to `T` has been added or the value is of the wrong type.
+    pub fn cast_boxed<T: ?Sized + 'static>(
+        &self,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<T>, Box<dyn Reflect>> {
+        match self.caster::<T>() {
+            Some(caster) => caster.cast_boxed(value),
+            None => Err(value),
+        }
+    }
+}
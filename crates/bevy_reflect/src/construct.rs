@@ -0,0 +1,400 @@
+use crate::std_traits::ReflectDefault;
+use crate::{
+    check_apply, ApplyError, DynamicStruct, DynamicTupleStruct, GetPath, Reflect,
+    ReflectFromReflect, TypeInfo, TypeRegistry,
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::TypeId;
+use thiserror::Error;
+
+/// An error returned from [`Construct::build`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConstructError {
+    #[error("no type with the path `{0}` is registered")]
+    UnknownType(String),
+    /// Contains the path of each field which has no default and was not given a value.
+    #[error("missing values for the required field(s) {}", fmt_paths(.0))]
+    MissingFields(Vec<String>),
+    #[error("the override path `{path}` is invalid: {message}")]
+    InvalidPath { path: String, message: String },
+    #[error("the override at `{path}` cannot be applied: {error}")]
+    InvalidOverride { path: String, error: ApplyError },
+    #[error("the type `{0}` is not registered with `ReflectFromReflect`")]
+    MissingFromReflect(&'static str),
+    #[error("the constructed value could not be converted to `{0}`")]
+    InvalidConversion(&'static str),
+}
+
+fn fmt_paths(paths: &[String]) -> String {
+    paths
+        .iter()
+        .map(|path| format!("`{path}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A description of a value, made of a type and values for some of its fields,
+/// from which the concrete value can be built.
+///
+/// [`build`](Self::build) starts from the default of each field, as registered with
+/// [`ReflectDefault`], and then applies each override at its [path].
+/// Fields which have no default are required, and must be given an override of their own.
+/// The result is converted to the concrete type using its [`ReflectFromReflect`] type data.
+///
+/// A [`Construct`] can be built any number of times, making it suitable as the template of a prefab,
+/// with variants of it created by cloning it and adding further overrides.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Construct, FromReflect, Reflect, ReflectFromReflect, TypeRegistry};
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// #[reflect(FromReflect)]
+/// struct Enemy {
+///     name: String,
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Enemy>();
+/// registry.register::<String>();
+///
+/// let goblin = Construct::new(std::any::type_name::<Enemy>())
+///     .with("name", String::from("Goblin"))
+///     .with("health", 30_u32);
+/// let value = goblin.build(&registry).unwrap();
+///
+/// let expected = Enemy { name: String::from("Goblin"), health: 30 };
+/// assert_eq!(Some(&expected), value.downcast_ref::<Enemy>());
+/// ```
+///
+/// [path]: GetPath
+pub struct Construct {
+    type_path: String,
+    overrides: Vec<(String, Box<dyn Reflect>)>,
+}
+
+impl Clone for Construct {
+    fn clone(&self) -> Self {
+        Self {
+            type_path: self.type_path.clone(),
+            overrides: self
+                .overrides
+                .iter()
+                .map(|(path, value)| (path.clone(), value.clone_value()))
+                .collect(),
+        }
+    }
+}
+
+impl Construct {
+    /// Creates a description of a value of the type with the given [type path], or type name.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn new(type_path: impl Into<String>) -> Self {
+        Self {
+            type_path: type_path.into(),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Returns the type path or type name of the described value.
+    pub fn type_path(&self) -> &str {
+        &self.type_path
+    }
+
+    /// Sets the value at `path` to `value`, replacing any earlier override of the same path.
+    pub fn with(self, path: impl Into<String>, value: impl Reflect) -> Self {
+        self.with_boxed(path, Box::new(value))
+    }
+
+    /// Sets the value at `path` to the boxed `value`, replacing any earlier override
+    /// of the same path.
+    pub fn with_boxed(mut self, path: impl Into<String>, value: Box<dyn Reflect>) -> Self {
+        let path = path.into();
+        self.overrides.retain(|(existing, _)| *existing != path);
+        self.overrides.push((path, value));
+        self
+    }
+
+    /// Returns the overridden paths, along with their values, in the order they were added.
+    pub fn overrides(&self) -> impl Iterator<Item = (&str, &dyn Reflect)> {
+        self.overrides
+            .iter()
+            .map(|(path, value)| (path.as_str(), &**value))
+    }
+
+    /// Builds the concrete value described by this [`Construct`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type is not registered, if required fields have no override,
+    /// if an override does not match the value at its path, or if the result cannot be
+    /// converted to the concrete type.
+    pub fn build(&self, registry: &TypeRegistry) -> Result<Box<dyn Reflect>, ConstructError> {
+        let registration = registry
+            .get_with_type_path(&self.type_path)
+            .or_else(|| registry.get_with_name(&self.type_path))
+            .ok_or_else(|| ConstructError::UnknownType(self.type_path.clone()))?;
+
+        let mut missing = Vec::new();
+        let value = self.seed(
+            registration.type_id(),
+            registration.type_name(),
+            "",
+            registry,
+            &mut missing,
+        );
+        let mut value = match value {
+            Some(value) if missing.is_empty() => value,
+            _ => return Err(ConstructError::MissingFields(missing)),
+        };
+
+        for (path, override_value) in &self.overrides {
+            let target = value
+                .path_mut(path)
+                .map_err(|error| ConstructError::InvalidPath {
+                    path: path.clone(),
+                    message: error.to_string(),
+                })?;
+            check_apply(target, &**override_value).map_err(|error| {
+                ConstructError::InvalidOverride {
+                    path: path.clone(),
+                    error,
+                }
+            })?;
+            target.apply(&**override_value);
+        }
+
+        let type_name = registration.type_name();
+        registration
+            .data::<ReflectFromReflect>()
+            .ok_or(ConstructError::MissingFromReflect(type_name))?
+            .from_reflect(&*value)
+            .ok_or(ConstructError::InvalidConversion(type_name))
+    }
+
+    /// Returns the starting value of the type at `path`, before overrides are applied.
+    ///
+    /// Types without a default are taken from the override of their own path, if there is one,
+    /// and otherwise their path is added to `missing`.
+    fn seed(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+        path: &str,
+        registry: &TypeRegistry,
+        missing: &mut Vec<String>,
+    ) -> Option<Box<dyn Reflect>> {
+        let registration = registry.get(type_id);
+        if let Some(default) =
+            registration.and_then(|registration| registration.data::<ReflectDefault>())
+        {
+            return Some(default.default());
+        }
+
+        match registration.map(|registration| registration.type_info()) {
+            Some(TypeInfo::Struct(info)) => {
+                let mut dynamic = DynamicStruct::default();
+                dynamic.set_name(type_name.to_string());
+                for field in info.iter() {
+                    let field_path = join_path(path, field.name());
+                    if let Some(value) = self.seed(
+                        field.type_id(),
+                        field.type_name(),
+                        &field_path,
+                        registry,
+                        missing,
+                    ) {
+                        dynamic.insert_boxed(field.name(), value);
+                    }
+                }
+                return Some(Box::new(dynamic));
+            }
+            Some(TypeInfo::TupleStruct(info)) => {
+                let mut dynamic = DynamicTupleStruct::default();
+                dynamic.set_name(type_name.to_string());
+                for field in info.iter() {
+                    let field_path = join_path(path, &field.index().to_string());
+                    if let Some(value) = self.seed(
+                        field.type_id(),
+                        field.type_name(),
+                        &field_path,
+                        registry,
+                        missing,
+                    ) {
+                        dynamic.insert_boxed(value);
+                    }
+                }
+                return Some(Box::new(dynamic));
+            }
+            _ => {}
+        }
+
+        let value = self
+            .overrides
+            .iter()
+            .find(|(override_path, _)| {
+                override_path.strip_prefix('.').unwrap_or(override_path) == path
+            })
+            .map(|(_, value)| value.clone_value());
+        if value.is_none() {
+            missing.push(if path.is_empty() {
+                type_name.to_string()
+            } else {
+                path.to_string()
+            });
+        }
+        value
+    }
+}
+
+/// Appends the field `field` to the path `path`.
+fn join_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Construct, ConstructError};
+    use crate::std_traits::ReflectDefault;
+    use crate::{self as bevy_reflect, FromReflect, Reflect, ReflectFromReflect, TypeRegistry};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    #[reflect(FromReflect)]
+    struct Enemy {
+        name: String,
+        stats: Stats,
+        team: Team,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Stats(u32, Weapon);
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq, Default)]
+    #[reflect(Default)]
+    enum Team {
+        #[default]
+        Neutral,
+        Red,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Weapon {
+        damage: f32,
+        element: Element,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    enum Element {
+        Fire,
+        Ice,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Enemy>();
+        registry.register::<Stats>();
+        registry.register::<Team>();
+        registry.register::<Weapon>();
+        registry.register::<Element>();
+        registry.register::<String>();
+        registry
+    }
+
+    #[test]
+    fn should_build_from_defaults_and_overrides() {
+        let registry = registry();
+        let goblin = Construct::new(core::any::type_name::<Enemy>())
+            .with("name", String::from("Goblin"))
+            .with("stats.1.element", Element::Fire)
+            .with("stats.0", 12_u32);
+
+        let value = goblin.build(&registry).unwrap();
+        let expected = Enemy {
+            name: String::from("Goblin"),
+            stats: Stats(
+                12,
+                Weapon {
+                    damage: 0.0,
+                    element: Element::Fire,
+                },
+            ),
+            team: Team::Neutral,
+        };
+        assert_eq!(Some(&expected), value.downcast_ref::<Enemy>());
+
+        // Variants of a construct replace or add to its overrides
+        let red_goblin = goblin
+            .clone()
+            .with("team", Team::Red)
+            .with("stats.1.element", Element::Ice);
+        let value = red_goblin.build(&registry).unwrap();
+        let expected = Enemy {
+            name: String::from("Goblin"),
+            stats: Stats(
+                12,
+                Weapon {
+                    damage: 0.0,
+                    element: Element::Ice,
+                },
+            ),
+            team: Team::Red,
+        };
+        assert_eq!(Some(&expected), value.downcast_ref::<Enemy>());
+        assert_eq!(4, red_goblin.overrides().count());
+    }
+
+    #[test]
+    fn should_report_construct_errors() {
+        let registry = registry();
+
+        let result = Construct::new("Unknown").build(&registry);
+        assert_eq!(
+            Some(ConstructError::UnknownType(String::from("Unknown"))),
+            result.err()
+        );
+
+        let enemy = Construct::new(core::any::type_name::<Enemy>());
+        assert_eq!(
+            Some(ConstructError::MissingFields(vec![String::from(
+                "stats.1.element"
+            )])),
+            enemy.build(&registry).err()
+        );
+
+        let enemy = enemy.with("stats.1.element", Element::Fire);
+        let result = enemy.with("stats.1.damage", 1_u32).build(&registry);
+        assert!(matches!(
+            result,
+            Err(ConstructError::InvalidOverride { path, .. }) if path == "stats.1.damage"
+        ));
+
+        let result = Construct::new(core::any::type_name::<Stats>())
+            .with("1.element", Element::Ice)
+            .build(&registry);
+        assert_eq!(
+            Some(ConstructError::MissingFromReflect(core::any::type_name::<
+                Stats,
+            >())),
+            result.err()
+        );
+
+        let result = Construct::new(core::any::type_name::<Enemy>())
+            .with("stats.1.element", Element::Fire)
+            .with("stats.2", 1_u32)
+            .build(&registry);
+        assert!(matches!(
+            result,
+            Err(ConstructError::InvalidPath { path, .. }) if path == "stats.2"
+        ));
+    }
+}
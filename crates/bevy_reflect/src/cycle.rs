@@ -0,0 +1,157 @@
+use crate::debug_options::max_depth_reached;
+use crate::Reflect;
+#[cfg(feature = "std")]
+use bevy_utils::HashSet;
+#[cfg(feature = "std")]
+use core::any::{Any, TypeId};
+#[cfg(feature = "std")]
+use core::cell::{Cell, RefCell};
+use core::fmt::{self, Formatter};
+use thiserror::Error;
+
+/// How many values [`guard_recursion`] lets be visited at once before it starts
+/// tracking them to detect cycles.
+///
+/// Visiting a value nested less deeply than this only costs a counter increment.
+pub const CYCLE_CHECK_DEPTH: usize = 64;
+
+/// An error returned by [`guard_recursion`] when a value is already being visited,
+/// meaning it (directly or indirectly) contains itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the value contains itself")]
+pub struct CycleError;
+
+/// The address and type of a visited value.
+#[cfg(feature = "std")]
+type VisitKey = (usize, TypeId);
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// How many values are being visited on this thread.
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// The values being visited on this thread past [`CYCLE_CHECK_DEPTH`].
+    static TRACKED: RefCell<HashSet<VisitKey>> = RefCell::new(HashSet::default());
+}
+
+/// Ends the visit started by [`guard_recursion`], even by panicking.
+struct VisitGuard(#[cfg(feature = "std")] Option<VisitKey>);
+
+impl VisitGuard {
+    /// Starts visiting `value`, unless it is tracked as being visited further up the stack.
+    #[cfg(feature = "std")]
+    fn enter(value: &dyn Reflect) -> Result<Self, CycleError> {
+        let depth = DEPTH.with(Cell::get);
+        let key = if depth >= CYCLE_CHECK_DEPTH {
+            let key = (
+                value as *const dyn Reflect as *const () as usize,
+                Any::type_id(value.as_any()),
+            );
+            if !TRACKED.with(|tracked| tracked.borrow_mut().insert(key)) {
+                return Err(CycleError);
+            }
+            Some(key)
+        } else {
+            None
+        };
+        DEPTH.with(|current| current.set(depth + 1));
+        Ok(Self(key))
+    }
+
+    /// Without `std` there is no thread-local storage to track visits in,
+    /// so every visit is allowed.
+    #[cfg(not(feature = "std"))]
+    fn enter(_value: &dyn Reflect) -> Result<Self, CycleError> {
+        Ok(Self())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for VisitGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+        if let Some(key) = self.0 {
+            TRACKED.with(|tracked| tracked.borrow_mut().remove(&key));
+        }
+    }
+}
+
+/// Calls `visit` while marking `value` as being visited on this thread.
+///
+/// Recursive functions which walk a value's fields should wrap their body in this,
+/// so that values which contain themselves, such as through a custom [`Reflect`]
+/// implementation, cause an error instead of overflowing the stack.
+///
+/// Values are only tracked once [`CYCLE_CHECK_DEPTH`] values are being visited,
+/// so that walking shallow values stays cheap. As a value containing itself keeps
+/// being visited, its cycle is then detected within a few levels past that depth.
+/// Values which are merely nested deeply are never rejected.
+///
+/// Values are identified by both their address and their type,
+/// as a struct may share its address with its first field.
+///
+/// Values are only tracked with the `std` feature, which provides the thread-local
+/// storage to track them in. Without it, `visit` is always called.
+///
+/// # Errors
+///
+/// Returns an error without calling `visit` if `value` is tracked as being visited
+/// further up the stack.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{guard_recursion, CycleError, Reflect};
+/// fn visit_forever(value: &dyn Reflect) -> Result<(), CycleError> {
+///     guard_recursion(value, || visit_forever(value))?
+/// }
+///
+/// assert_eq!(Err(CycleError), visit_forever(&123_usize));
+/// ```
+pub fn guard_recursion<R>(value: &dyn Reflect, visit: impl FnOnce() -> R) -> Result<R, CycleError> {
+    let _guard = VisitGuard::enter(value)?;
+    Ok(visit())
+}
+
+/// Returns how many values are being visited on this thread.
+#[cfg(feature = "std")]
+pub(crate) fn visiting_depth() -> usize {
+    DEPTH.with(Cell::get)
+}
+
+/// Without `std`, visits are not counted, so this is always zero.
+#[cfg(not(feature = "std"))]
+pub(crate) fn visiting_depth() -> usize {
+    0
+}
+
+/// Calls `debug` while visiting `value` with [`guard_recursion`],
+/// writing `...` instead of the value if it cannot be visited.
+///
+/// Writes `..` instead if the value is deeper than the active
+/// [`max_depth`](crate::ReflectDebugOptions::max_depth).
+pub(crate) fn debug_guarded(
+    value: &dyn Reflect,
+    f: &mut Formatter<'_>,
+    debug: impl FnOnce(&mut Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    if max_depth_reached() {
+        return f.write_str("..");
+    }
+    match guard_recursion(value, || debug(f)) {
+        Ok(result) => result,
+        Err(_) => f.write_str("..."),
+    }
+}
+
+/// Calls `visit` while visiting `value` with [`guard_recursion`].
+///
+/// # Panics
+///
+/// Panics if the value contains itself, naming the `action` being performed.
+pub(crate) fn expect_guarded<R>(value: &dyn Reflect, action: &str, visit: impl FnOnce() -> R) -> R {
+    guard_recursion(value, visit).unwrap_or_else(|error| {
+        panic!(
+            "Attempted to {action} a value of type `{}`, but {error}.",
+            value.type_name()
+        )
+    })
+}
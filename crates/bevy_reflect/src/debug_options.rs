@@ -0,0 +1,322 @@
+use crate::cycle::visiting_depth;
+use crate::Reflect;
+use alloc::string::String;
+use bevy_utils::get_short_name;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::fmt::{self, Debug, Formatter};
+
+/// Limits on how much of a value [`debug_with_options`] prints.
+///
+/// Limits are off by default, in which case the output is the same as that of [`Reflect::debug`].
+/// Applying them needs the `std` feature, as the active options are kept per thread.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{debug_with_options, ReflectDebugOptions};
+/// let options = ReflectDebugOptions {
+///     max_elements: Some(2),
+///     ..Default::default()
+/// };
+/// let output = format!("{:?}", debug_with_options(&vec![1, 2, 3, 4], options));
+/// assert_eq!("[1, 2, .. (2 more)]", output);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReflectDebugOptions {
+    /// How many levels of structs, lists, maps and other containers to print.
+    ///
+    /// Containers nested deeper than this are printed as `..`.
+    pub max_depth: Option<usize>,
+    /// How many elements of each list, array or map to print.
+    ///
+    /// The remaining elements are replaced by a single `.. (N more)` element,
+    /// or a `..: (N more)` entry in maps.
+    pub max_elements: Option<usize>,
+    /// Whether to print the short type path of every value.
+    ///
+    /// Structs, tuple structs and enum variants are named with their type path,
+    /// other containers are preceded by it, and other values are wrapped in it, as in `u32(1)`.
+    /// Dynamic values are printed with the type path of the type they represent,
+    /// within the name of their own type.
+    ///
+    /// Types which implement [`Reflect::debug`] themselves only have their type path printed
+    /// if they are not a struct, tuple struct or enum.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{debug_with_options, DynamicStruct, ReflectDebugOptions};
+    /// let mut value = DynamicStruct::default();
+    /// value.set_name(String::from("my_crate::Player"));
+    /// value.insert("scores", vec![1_u32]);
+    ///
+    /// let options = ReflectDebugOptions {
+    ///     type_paths: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     "DynamicStruct(Player { scores: Vec<u32> [u32(1)] })",
+    ///     format!("{:?}", debug_with_options(&value, options))
+    /// );
+    /// ```
+    pub type_paths: bool,
+    /// Whether to print the fields of structs which are not reflected, such as those marked with
+    /// `#[reflect(ignore)]`, as `field: <ignored>`.
+    ///
+    /// This makes the output line up with that of `#[derive(Debug)]`.
+    /// Custom [`Reflect::debug`] implementations can show them too by calling
+    /// [`struct_debug`](crate::struct_debug).
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{debug_with_options, Reflect, ReflectDebugOptions};
+    /// #[derive(Reflect)]
+    /// struct Player {
+    ///     #[reflect(ignore)]
+    ///     id: u64,
+    ///     health: u32,
+    /// }
+    ///
+    /// let options = ReflectDebugOptions {
+    ///     ignored_fields: true,
+    ///     ..Default::default()
+    /// };
+    /// let output = format!("{:?}", debug_with_options(&Player { id: 1, health: 10 }, options));
+    /// assert!(output.ends_with("Player { id: <ignored>, health: 10 }"));
+    /// ```
+    pub ignored_fields: bool,
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// The options of the innermost [`debug_with_options`] being formatted on this thread,
+    /// along with the [visiting depth](visiting_depth) of its value.
+    static ACTIVE_OPTIONS: Cell<Option<(ReflectDebugOptions, usize)>> = const { Cell::new(None) };
+}
+
+/// Returns the options of the innermost [`debug_with_options`] being formatted on this thread,
+/// along with the [visiting depth](visiting_depth) of its value.
+#[cfg(feature = "std")]
+fn active_options() -> Option<(ReflectDebugOptions, usize)> {
+    ACTIVE_OPTIONS.with(Cell::get)
+}
+
+/// Without `std` there is no thread-local storage to keep options in, so none are ever active.
+#[cfg(not(feature = "std"))]
+fn active_options() -> Option<(ReflectDebugOptions, usize)> {
+    None
+}
+
+/// Restores the options of the enclosing [`debug_with_options`], even when formatting panics.
+#[cfg(feature = "std")]
+struct OptionsGuard(Option<(ReflectDebugOptions, usize)>);
+
+#[cfg(feature = "std")]
+impl Drop for OptionsGuard {
+    fn drop(&mut self) {
+        ACTIVE_OPTIONS.with(|active| active.set(self.0));
+    }
+}
+
+/// Returns a wrapper which debug formats `value` with [`Reflect::debug`],
+/// within the limits set by `options`.
+///
+/// This is meant for logging values which may be very large, such as whole scenes.
+/// See [`ReflectDebugOptions`] for an example.
+#[cfg(feature = "std")]
+pub fn debug_with_options(value: &dyn Reflect, options: ReflectDebugOptions) -> DebugWithOptions {
+    DebugWithOptions { value, options }
+}
+
+/// A value formatted with [`ReflectDebugOptions`], as returned by [`debug_with_options`].
+#[cfg(feature = "std")]
+pub struct DebugWithOptions<'a> {
+    value: &'a dyn Reflect,
+    options: ReflectDebugOptions,
+}
+
+#[cfg(feature = "std")]
+impl<'a> Debug for DebugWithOptions<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let active = Some((self.options, visiting_depth()));
+        let _guard = OptionsGuard(ACTIVE_OPTIONS.with(|options| options.replace(active)));
+        Debug::fmt(self.value, f)
+    }
+}
+
+/// Returns the type path to print for `value`, if the active options enable
+/// [`ReflectDebugOptions::type_paths`].
+pub(crate) fn debug_type_path(value: &dyn Reflect) -> Option<String> {
+    let enabled = matches!(
+        active_options(),
+        Some((
+            ReflectDebugOptions {
+                type_paths: true,
+                ..
+            },
+            _
+        ))
+    );
+    if !enabled {
+        return None;
+    }
+    Some(get_short_name(value.type_name()))
+}
+
+/// Returns `true` if the active options enable [`ReflectDebugOptions::ignored_fields`].
+pub(crate) fn show_ignored_fields() -> bool {
+    matches!(
+        active_options(),
+        Some((
+            ReflectDebugOptions {
+                ignored_fields: true,
+                ..
+            },
+            _
+        ))
+    )
+}
+
+/// Writes the type path of `value` followed by a space, if the active options enable
+/// [`ReflectDebugOptions::type_paths`].
+pub(crate) fn write_type_path(value: &dyn Reflect, f: &mut Formatter<'_>) -> fmt::Result {
+    match debug_type_path(value) {
+        Some(type_path) => write!(f, "{type_path} "),
+        None => Ok(()),
+    }
+}
+
+/// Returns `true` if the container about to be printed is nested deeper than the active
+/// [`ReflectDebugOptions::max_depth`].
+pub(crate) fn max_depth_reached() -> bool {
+    match active_options() {
+        Some((
+            ReflectDebugOptions {
+                max_depth: Some(max_depth),
+                ..
+            },
+            base_depth,
+        )) => visiting_depth() - base_depth >= max_depth,
+        _ => false,
+    }
+}
+
+/// Returns how many of the `len` elements of a collection should be printed,
+/// according to the active [`ReflectDebugOptions::max_elements`].
+pub(crate) fn shown_elements(len: usize) -> usize {
+    match active_options() {
+        Some((
+            ReflectDebugOptions {
+                max_elements: Some(max_elements),
+                ..
+            },
+            _,
+        )) => len.min(max_elements),
+        _ => len,
+    }
+}
+
+/// Stands in for the elements of a collection left out by [`shown_elements`].
+pub(crate) struct Omitted(pub usize);
+
+impl Debug for Omitted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, ".. ({} more)", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{debug_with_options, ReflectDebugOptions};
+    use crate::{self as bevy_reflect, Reflect};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect)]
+    struct Node {
+        value: u32,
+        children: Vec<Vec<u32>>,
+    }
+
+    #[test]
+    fn should_limit_depth_and_elements() {
+        let tree = Node {
+            value: 0,
+            children: vec![vec![1], vec![2]],
+        };
+        let options = ReflectDebugOptions {
+            max_depth: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            format!(
+                "{} {{ value: 0, children: [.., ..] }}",
+                core::any::type_name::<Node>()
+            ),
+            format!("{:?}", debug_with_options(&tree, options))
+        );
+        assert_eq!(
+            "..",
+            format!(
+                "{:?}",
+                debug_with_options(
+                    &tree,
+                    ReflectDebugOptions {
+                        max_depth: Some(0),
+                        ..Default::default()
+                    }
+                )
+            )
+        );
+
+        let mut map = HashMap::default();
+        map.insert(1_u32, 2_u32);
+        map.insert(3_u32, 4_u32);
+        let options = ReflectDebugOptions {
+            max_elements: Some(1),
+            ..Default::default()
+        };
+        let output = format!("{:?}", debug_with_options(&map, options));
+        assert!(output.ends_with(", ..: (1 more)}"), "{output}");
+
+        let full = format!("{:?}", &map as &dyn Reflect);
+        assert!(!full.contains(".."), "{full}");
+    }
+
+    #[test]
+    fn should_print_ignored_fields() {
+        #[derive(Reflect)]
+        struct Wrapper<T: Reflect> {
+            value: T,
+            #[reflect(ignore)]
+            _cache: (),
+        }
+
+        let wrapper = Wrapper {
+            value: 1_u8,
+            _cache: (),
+        };
+        let options = ReflectDebugOptions {
+            ignored_fields: true,
+            ..Default::default()
+        };
+        let output = format!("{:?}", debug_with_options(&wrapper, options));
+        assert!(
+            output.ends_with("{ value: 1, _cache: <ignored> }"),
+            "{output}"
+        );
+        let output = format!("{:?}", &wrapper as &dyn Reflect);
+        assert!(output.ends_with("{ value: 1 }"), "{output}");
+    }
+
+    #[test]
+    fn should_print_type_paths() {
+        let options = ReflectDebugOptions {
+            type_paths: true,
+            ..Default::default()
+        };
+        let value = (Some(1_u8), [false]);
+        assert_eq!(
+            "(Option<u8>, [bool; 1]) (Option<u8>::Some(u8(1)), [bool; 1] [bool(false)])",
+            format!("{:?}", debug_with_options(&value, options))
+        );
+    }
+}
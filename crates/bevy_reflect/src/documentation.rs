@@ -0,0 +1,314 @@
+use crate::{NamedField, TypeInfo, TypeRegistry, UnnamedField, VariantInfo};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// The documentation of every type in a [`TypeRegistry`], gathered from their doc comments
+/// and the [standard attributes] of their fields.
+///
+/// This is meant to be exported by editors and tools, such as to show tooltips
+/// or generate manuals, so it can be serialized with any serde format.
+/// Types are sorted by their type name, so the report only changes when the types do.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{DocumentationReport, Reflect, TypeRegistry};
+/// /// A light shining in every direction.
+/// #[derive(Reflect)]
+/// struct PointLight {
+///     /// How far the light reaches.
+///     range: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::empty();
+/// registry.register::<PointLight>();
+///
+/// let report = DocumentationReport::new(&registry);
+/// let light = &report.types[0];
+/// assert_eq!(Some(" A light shining in every direction."), light.docs);
+/// assert_eq!(Some(" How far the light reaches."), light.fields[0].docs);
+/// ```
+///
+/// [standard attributes]: crate::attributes
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentationReport {
+    /// The documentation of each registered type.
+    pub types: Vec<TypeDocs>,
+}
+
+impl DocumentationReport {
+    /// Gathers the documentation of every type registered in `registry`.
+    pub fn new(registry: &TypeRegistry) -> Self {
+        let mut types = registry
+            .iter()
+            .map(|registration| TypeDocs::new(registration.type_info()))
+            .collect::<Vec<_>>();
+        types.sort_by(|a, b| a.type_name.cmp(b.type_name));
+        Self { types }
+    }
+}
+
+/// The documentation of a single type, within a [`DocumentationReport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDocs {
+    /// The [type name] of the type.
+    ///
+    /// [type name]: std::any::type_name
+    pub type_name: &'static str,
+    /// The kind of type, such as `"struct"` or `"enum"`.
+    pub kind: &'static str,
+    /// The doc comment of the type.
+    pub docs: Option<&'static str>,
+    /// The fields of a struct or tuple struct, in declaration order.
+    pub fields: Vec<FieldDocs>,
+    /// The variants of an enum, in declaration order.
+    pub variants: Vec<VariantDocs>,
+}
+
+impl TypeDocs {
+    fn new(info: &'static TypeInfo) -> Self {
+        let (kind, fields, variants) = match info {
+            TypeInfo::Struct(info) => ("struct", named_fields(info.iter()), Vec::new()),
+            TypeInfo::TupleStruct(info) => {
+                ("tuple struct", unnamed_fields(info.iter()), Vec::new())
+            }
+            TypeInfo::Tuple(info) => ("tuple", unnamed_fields(info.iter()), Vec::new()),
+            TypeInfo::List(_) => ("list", Vec::new(), Vec::new()),
+            TypeInfo::Array(_) => ("array", Vec::new(), Vec::new()),
+            TypeInfo::Map(_) => ("map", Vec::new(), Vec::new()),
+            TypeInfo::Enum(info) => (
+                "enum",
+                Vec::new(),
+                info.iter().map(VariantDocs::new).collect(),
+            ),
+            TypeInfo::Value(_) => ("value", Vec::new(), Vec::new()),
+            TypeInfo::Dynamic(_) => ("dynamic", Vec::new(), Vec::new()),
+        };
+        Self {
+            type_name: info.type_name(),
+            kind,
+            docs: type_docs(info),
+            fields,
+            variants,
+        }
+    }
+}
+
+/// Returns the doc comment of a type, which is always static for the types of a registry.
+fn type_docs(info: &'static TypeInfo) -> Option<&'static str> {
+    match info {
+        TypeInfo::Struct(info) => info.docs(),
+        TypeInfo::TupleStruct(info) => info.docs(),
+        TypeInfo::Tuple(info) => info.docs(),
+        TypeInfo::List(info) => info.docs(),
+        TypeInfo::Array(info) => info.docs(),
+        TypeInfo::Map(info) => info.docs(),
+        TypeInfo::Enum(info) => info.docs(),
+        TypeInfo::Value(info) => info.docs(),
+        TypeInfo::Dynamic(info) => info.docs(),
+    }
+}
+
+/// The documentation of an enum variant, within a [`TypeDocs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantDocs {
+    /// The name of the variant.
+    pub name: &'static str,
+    /// The doc comment of the variant.
+    pub docs: Option<&'static str>,
+    /// The fields of the variant, in declaration order.
+    pub fields: Vec<FieldDocs>,
+}
+
+impl VariantDocs {
+    fn new(info: &'static VariantInfo) -> Self {
+        let (docs, fields) = match info {
+            VariantInfo::Struct(info) => (info.docs(), named_fields(info.iter())),
+            VariantInfo::Tuple(info) => (info.docs(), unnamed_fields(info.iter())),
+            VariantInfo::Unit(info) => (info.docs(), Vec::new()),
+        };
+        Self {
+            name: info.name(),
+            docs,
+            fields,
+        }
+    }
+}
+
+/// The documentation of a field, within a [`TypeDocs`] or [`VariantDocs`].
+///
+/// Along with its doc comment, this holds the values of the [standard attributes] of the field.
+///
+/// [standard attributes]: crate::attributes
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDocs {
+    /// The name of a named field, or the index of an unnamed one.
+    pub name: String,
+    /// The [type name] of the field.
+    ///
+    /// [type name]: std::any::type_name
+    pub type_name: &'static str,
+    /// The doc comment of the field.
+    pub docs: Option<&'static str>,
+    /// The text of the field's [`Tooltip`](crate::attributes::Tooltip).
+    pub tooltip: Option<&'static str>,
+    /// The name of the field's [`Category`](crate::attributes::Category).
+    pub category: Option<&'static str>,
+    /// The smallest and largest values of the field's [`Range`](crate::attributes::Range).
+    pub range: Option<(f64, f64)>,
+    /// The amount of the field's [`Step`](crate::attributes::Step).
+    pub step: Option<f64>,
+    /// Whether the field has the [`Multiline`](crate::attributes::Multiline) attribute.
+    pub multiline: bool,
+}
+
+fn named_fields<'a>(fields: impl Iterator<Item = &'a NamedField>) -> Vec<FieldDocs> {
+    fields
+        .map(|field| FieldDocs {
+            name: field.name().to_string(),
+            type_name: field.type_name(),
+            docs: field.docs(),
+            tooltip: field.tooltip(),
+            category: field.category(),
+            range: field.range().map(|range| (range.min, range.max)),
+            step: field.step(),
+            multiline: field.is_multiline(),
+        })
+        .collect()
+}
+
+fn unnamed_fields<'a>(fields: impl Iterator<Item = &'a UnnamedField>) -> Vec<FieldDocs> {
+    fields
+        .map(|field| FieldDocs {
+            name: field.index().to_string(),
+            type_name: field.type_name(),
+            docs: field.docs(),
+            tooltip: field.tooltip(),
+            category: field.category(),
+            range: field.range().map(|range| (range.min, range.max)),
+            step: field.step(),
+            multiline: field.is_multiline(),
+        })
+        .collect()
+}
+
+impl Serialize for DocumentationReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DocumentationReport", 1)?;
+        state.serialize_field("types", &self.types)?;
+        state.end()
+    }
+}
+
+impl Serialize for TypeDocs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("TypeDocs", 5)?;
+        state.serialize_field("type_name", self.type_name)?;
+        state.serialize_field("kind", self.kind)?;
+        state.serialize_field("docs", &self.docs)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("variants", &self.variants)?;
+        state.end()
+    }
+}
+
+impl Serialize for VariantDocs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("VariantDocs", 3)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("docs", &self.docs)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+
+impl Serialize for FieldDocs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("FieldDocs", 8)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("type_name", self.type_name)?;
+        state.serialize_field("docs", &self.docs)?;
+        state.serialize_field("tooltip", &self.tooltip)?;
+        state.serialize_field("category", &self.category)?;
+        state.serialize_field("range", &self.range)?;
+        state.serialize_field("step", &self.step)?;
+        state.serialize_field("multiline", &self.multiline)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocumentationReport, FieldDocs};
+    use crate::attributes::{Multiline, Range, Tooltip};
+    use crate::{self as bevy_reflect, Reflect, TypeRegistry};
+
+    /// An item which can be picked up.
+    #[derive(Reflect)]
+    struct Item {
+        /// The name shown in the inventory.
+        #[reflect(@Tooltip("Shown in the inventory"), @Multiline)]
+        name: String,
+        #[reflect(@Range::new(0.0, 99.0))]
+        count: u8,
+    }
+
+    /// How rare an item is.
+    #[derive(Reflect)]
+    enum Rarity {
+        /// Found everywhere.
+        Common,
+        Unique(
+            /// The only owner of the item.
+            String,
+        ),
+    }
+
+    #[test]
+    fn should_report_registered_docs() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Rarity>();
+        registry.register::<Item>();
+
+        let report = DocumentationReport::new(&registry);
+        let type_names = report
+            .types
+            .iter()
+            .map(|docs| docs.type_name)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                core::any::type_name::<Item>(),
+                core::any::type_name::<Rarity>()
+            ],
+            type_names
+        );
+
+        let item = &report.types[0];
+        assert_eq!("struct", item.kind);
+        assert_eq!(Some(" An item which can be picked up."), item.docs);
+        assert_eq!(
+            FieldDocs {
+                name: String::from("name"),
+                type_name: core::any::type_name::<String>(),
+                docs: Some(" The name shown in the inventory."),
+                tooltip: Some("Shown in the inventory"),
+                category: None,
+                range: None,
+                step: None,
+                multiline: true,
+            },
+            item.fields[0]
+        );
+        assert_eq!(Some((0.0, 99.0)), item.fields[1].range);
+
+        let rarity = &report.types[1];
+        assert_eq!("enum", rarity.kind);
+        assert_eq!(Some(" Found everywhere."), rarity.variants[0].docs);
+        assert_eq!(
+            Some(" The only owner of the item."),
+            rarity.variants[1].fields[0].docs
+        );
+
+        let serialized = ron::to_string(&report).unwrap();
+        assert!(serialized.contains("tooltip:Some(\"Shown in the inventory\")"));
+    }
+}
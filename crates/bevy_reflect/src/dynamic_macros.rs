@@ -0,0 +1,116 @@
+/// Creates a [`DynamicStruct`](crate::DynamicStruct) from a list of `field: value` pairs.
+///
+/// Values can be any [`Reflect`](crate::Reflect) type, as well as string literals,
+/// which are converted to [`String`]s.
+/// Nested dynamic values can be built by nesting the macros.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{dynamic_list, dynamic_struct, Reflect};
+/// #[derive(Reflect, Default)]
+/// struct Player {
+///     health: f32,
+///     name: String,
+///     scores: Vec<u32>,
+/// }
+///
+/// let mut player = Player::default();
+/// player.apply(&dynamic_struct! {
+///     health: 10.0_f32,
+///     name: "bob",
+///     scores: dynamic_list![3_u32, 5_u32],
+/// });
+/// assert_eq!(10.0, player.health);
+/// assert_eq!("bob", player.name);
+/// assert_eq!(vec![3, 5], player.scores);
+/// ```
+#[macro_export]
+macro_rules! dynamic_struct {
+    ($($field:ident : $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut dynamic_struct = $crate::DynamicStruct::default();
+        $(
+            dynamic_struct.insert_boxed(
+                stringify!($field),
+                $crate::__macro_exports::IntoReflectValue::into_reflect_value($value),
+            );
+        )*
+        dynamic_struct
+    }};
+}
+
+/// Creates a [`DynamicList`](crate::DynamicList) from a list of values.
+///
+/// Values are converted as in [`dynamic_struct!`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{dynamic_list, Reflect};
+/// let mut names = vec![String::new()];
+/// names.apply(&dynamic_list!["alice", "bob"]);
+/// assert_eq!(vec!["alice", "bob"], names);
+/// ```
+#[macro_export]
+macro_rules! dynamic_list {
+    ($($value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut dynamic_list = $crate::DynamicList::default();
+        $(
+            dynamic_list.push_box(
+                $crate::__macro_exports::IntoReflectValue::into_reflect_value($value),
+            );
+        )*
+        dynamic_list
+    }};
+}
+
+/// Creates a [`DynamicEnum`](crate::DynamicEnum) from a variant written as in a pattern:
+/// `Variant`, `Variant(values...)`, or `Variant { field: value, ... }`.
+///
+/// Values are converted as in [`dynamic_struct!`].
+/// The type name of the enum is left empty, as [applying](crate::Reflect::apply) the result to
+/// an enum only looks at the variant.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{dynamic_enum, Reflect};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// enum Shape {
+///     Point,
+///     Circle(f32),
+///     Rect { width: f32, height: f32 },
+/// }
+///
+/// let mut shape = Shape::Point;
+/// shape.apply(&dynamic_enum!(Circle(2.0_f32)));
+/// assert_eq!(Shape::Circle(2.0), shape);
+///
+/// shape.apply(&dynamic_enum!(Rect { width: 1.0_f32, height: 3.0_f32 }));
+/// assert_eq!(Shape::Rect { width: 1.0, height: 3.0 }, shape);
+///
+/// shape.apply(&dynamic_enum!(Point));
+/// assert_eq!(Shape::Point, shape);
+/// ```
+#[macro_export]
+macro_rules! dynamic_enum {
+    ($variant:ident) => {
+        $crate::DynamicEnum::new("", stringify!($variant), ())
+    };
+    ($variant:ident ( $($value:expr),* $(,)? )) => {{
+        #[allow(unused_mut)]
+        let mut dynamic_tuple = $crate::DynamicTuple::default();
+        $(
+            dynamic_tuple.insert_boxed(
+                $crate::__macro_exports::IntoReflectValue::into_reflect_value($value),
+            );
+        )*
+        $crate::DynamicEnum::new("", stringify!($variant), dynamic_tuple)
+    }};
+    ($variant:ident { $($field:ident : $value:expr),* $(,)? }) => {
+        $crate::DynamicEnum::new(
+            "",
+            stringify!($variant),
+            $crate::dynamic_struct! { $($field: $value),* },
+        )
+    };
+}
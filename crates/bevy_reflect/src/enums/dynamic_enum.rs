@@ -1,11 +1,19 @@
+use crate::allocation_stats::{record_allocations, AllocationSource};
+use crate::cycle::expect_guarded;
+use crate::std_traits::{default_value, DefaultValueError, ReflectDefault};
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    enum_debug, enum_hash, enum_partial_eq, DynamicInfo, DynamicStruct, DynamicTuple, Enum,
-    Reflect, ReflectMut, ReflectOwned, ReflectRef, Struct, Tuple, TypeInfo, Typed,
-    VariantFieldIter, VariantType,
+    enum_debug, enum_hash, enum_partial_cmp, enum_partial_eq, DynamicInfo, DynamicStruct,
+    DynamicTuple, Enum, EnumInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, Struct, Tuple,
+    TypeInfo, TypeRegistry, Typed, VariantFieldIter, VariantInfo, VariantType,
 };
-use std::any::Any;
-use std::fmt::Formatter;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::Any;
+use core::fmt::Formatter;
 
 /// A dynamic representation of an enum variant.
 #[derive(Debug)]
@@ -155,6 +163,109 @@ impl DynamicEnum {
         self.variant = variant.into();
     }
 
+    /// Create a [`DynamicEnum`] holding the default value of the enum described by `info`.
+    ///
+    /// The default comes from the [`ReflectDefault`] registered for the enum.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the enum is not registered or has no registered default.
+    pub fn from_type_info(
+        info: &EnumInfo,
+        registry: &TypeRegistry,
+    ) -> Result<Self, DefaultValueError> {
+        let default = registry
+            .get(info.type_id())
+            .ok_or(DefaultValueError::NotRegistered(info.type_name()))?
+            .data::<ReflectDefault>()
+            .ok_or(DefaultValueError::NoDefault(info.type_name()))?
+            .default();
+        match default.reflect_ref() {
+            ReflectRef::Enum(value) => Ok(value.clone_dynamic()),
+            _ => Err(DefaultValueError::NoDefault(info.type_name())),
+        }
+    }
+
+    /// Create a [`DynamicEnum`] holding the variant called `name` of the enum described by `info`,
+    /// with each of its fields set to a default value.
+    ///
+    /// If the [`ReflectDefault`] registered for the enum is the same variant, its fields are used.
+    /// Otherwise, each field is defaulted on its own, in the same way as the fields of
+    /// [`DynamicStruct::from_type_info`].
+    ///
+    /// This makes it possible to switch a value to another variant, such as from an editor,
+    /// while keeping it valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the enum has no such variant, or if the type of a field
+    /// is not registered or cannot be defaulted.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{DynamicEnum, Enum, FromReflect, Reflect, TypeInfo, TypeRegistry, Typed};
+    /// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    /// enum Shape {
+    ///     Point,
+    ///     Circle { radius: f32 },
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Shape>();
+    ///
+    /// let TypeInfo::Enum(info) = Shape::type_info() else { unreachable!() };
+    /// let circle = DynamicEnum::from_variant_name(info, &registry, "Circle").unwrap();
+    /// assert_eq!("Circle", circle.variant_name());
+    /// assert_eq!(Some(Shape::Circle { radius: 0.0 }), Shape::from_reflect(&circle));
+    /// ```
+    pub fn from_variant_name(
+        info: &EnumInfo,
+        registry: &TypeRegistry,
+        name: &str,
+    ) -> Result<Self, DefaultValueError> {
+        let (index, variant) = info.index_of(name).zip(info.variant(name)).ok_or_else(|| {
+            DefaultValueError::UnknownVariant {
+                type_name: info.type_name(),
+                variant: name.to_string(),
+            }
+        })?;
+
+        if let Ok(default) = Self::from_type_info(info, registry) {
+            if default.variant_name() == variant.name() {
+                return Ok(default);
+            }
+        }
+
+        let dynamic_variant = match variant {
+            VariantInfo::Struct(variant) => {
+                let mut dynamic = DynamicStruct::default();
+                for field in variant.iter() {
+                    let value = default_value(field.type_id(), field.type_name(), registry)?;
+                    dynamic.insert_boxed(field.name(), value);
+                }
+                DynamicVariant::Struct(dynamic)
+            }
+            VariantInfo::Tuple(variant) => {
+                let mut dynamic = DynamicTuple::default();
+                for field in variant.iter() {
+                    dynamic.insert_boxed(default_value(
+                        field.type_id(),
+                        field.type_name(),
+                        registry,
+                    )?);
+                }
+                DynamicVariant::Tuple(dynamic)
+            }
+            VariantInfo::Unit(_) => DynamicVariant::Unit,
+        };
+        Ok(Self::new_with_index(
+            info.type_name(),
+            index,
+            variant.name(),
+            dynamic_variant,
+        ))
+    }
+
     /// Create a [`DynamicEnum`] from an existing one.
     ///
     /// This is functionally the same as [`DynamicEnum::from_ref`] except it takes an owned value.
@@ -176,7 +287,7 @@ impl DynamicEnum {
             VariantType::Tuple => {
                 let mut data = DynamicTuple::default();
                 for field in value.iter_fields() {
-                    data.insert_boxed(field.value().clone_value());
+                    data.insert_cloned(field.value());
                 }
                 DynamicEnum::new_with_index(
                     value.type_name(),
@@ -187,9 +298,9 @@ impl DynamicEnum {
             }
             VariantType::Struct => {
                 let mut data = DynamicStruct::default();
+                data.set_inline_values(true);
                 for field in value.iter_fields() {
-                    let name = field.name().unwrap();
-                    data.insert_boxed(name, field.value().clone_value());
+                    data.insert_cloned(field.name().unwrap(), field.value());
                 }
                 DynamicEnum::new_with_index(
                     value.type_name(),
@@ -279,6 +390,14 @@ impl Enum for DynamicEnum {
         }
     }
 
+    fn into_variant_fields(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        match self.variant {
+            DynamicVariant::Unit => Vec::new(),
+            DynamicVariant::Tuple(data) => Box::new(data).drain(),
+            DynamicVariant::Struct(data) => data.into_values(),
+        }
+    }
+
     fn clone_dynamic(&self) -> DynamicEnum {
         Self {
             name: self.name.clone(),
@@ -332,52 +451,57 @@ impl Reflect for DynamicEnum {
 
     #[inline]
     fn apply(&mut self, value: &dyn Reflect) {
-        if let ReflectRef::Enum(value) = value.reflect_ref() {
-            if Enum::variant_name(self) == value.variant_name() {
-                // Same variant -> just update fields
-                match value.variant_type() {
-                    VariantType::Struct => {
-                        for field in value.iter_fields() {
-                            let name = field.name().unwrap();
-                            if let Some(v) = Enum::field_mut(self, name) {
-                                v.apply(field.value());
+        expect_guarded(value, "apply", || {
+            if let ReflectRef::Enum(value) = value.reflect_ref() {
+                if Enum::variant_name(self) == value.variant_name() {
+                    // Same variant -> just update fields
+                    match value.variant_type() {
+                        VariantType::Struct => {
+                            for field in value.iter_fields() {
+                                let name = field.name().unwrap();
+                                if let Some(v) = Enum::field_mut(self, name) {
+                                    v.apply(field.value());
+                                }
                             }
                         }
-                    }
-                    VariantType::Tuple => {
-                        for (index, field) in value.iter_fields().enumerate() {
-                            if let Some(v) = Enum::field_at_mut(self, index) {
-                                v.apply(field.value());
+                        VariantType::Tuple => {
+                            for (index, field) in value.iter_fields().enumerate() {
+                                if let Some(v) = Enum::field_at_mut(self, index) {
+                                    v.apply(field.value());
+                                }
                             }
                         }
+                        _ => {}
                     }
-                    _ => {}
-                }
-            } else {
-                // New variant -> perform a switch
-                let dyn_variant = match value.variant_type() {
-                    VariantType::Unit => DynamicVariant::Unit,
-                    VariantType::Tuple => {
-                        let mut dyn_tuple = DynamicTuple::default();
-                        for field in value.iter_fields() {
-                            dyn_tuple.insert_boxed(field.value().clone_value());
+                } else {
+                    // New variant -> perform a switch
+                    record_allocations(AllocationSource::Apply, value.field_len());
+                    let dyn_variant = match value.variant_type() {
+                        VariantType::Unit => DynamicVariant::Unit,
+                        VariantType::Tuple => {
+                            let mut dyn_tuple = DynamicTuple::default();
+                            for field in value.iter_fields() {
+                                dyn_tuple.insert_boxed(field.value().clone_value());
+                            }
+                            DynamicVariant::Tuple(dyn_tuple)
                         }
-                        DynamicVariant::Tuple(dyn_tuple)
-                    }
-                    VariantType::Struct => {
-                        let mut dyn_struct = DynamicStruct::default();
-                        for field in value.iter_fields() {
-                            dyn_struct
-                                .insert_boxed(field.name().unwrap(), field.value().clone_value());
+                        VariantType::Struct => {
+                            let mut dyn_struct = DynamicStruct::default();
+                            for field in value.iter_fields() {
+                                dyn_struct.insert_boxed(
+                                    field.name().unwrap(),
+                                    field.value().clone_value(),
+                                );
+                            }
+                            DynamicVariant::Struct(dyn_struct)
                         }
-                        DynamicVariant::Struct(dyn_struct)
-                    }
-                };
-                self.set_variant(value.variant_name(), dyn_variant);
+                    };
+                    self.set_variant(value.variant_name(), dyn_variant);
+                }
+            } else {
+                panic!("`{}` is not an enum", value.type_name());
             }
-        } else {
-            panic!("`{}` is not an enum", value.type_name());
-        }
+        });
     }
 
     #[inline]
@@ -416,8 +540,12 @@ impl Reflect for DynamicEnum {
         enum_partial_eq(self, value)
     }
 
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<core::cmp::Ordering> {
+        enum_partial_cmp(self, value)
+    }
+
     #[inline]
-    fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "DynamicEnum(")?;
         enum_debug(self, f)?;
         write!(f, ")")
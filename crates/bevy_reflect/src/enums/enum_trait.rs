@@ -1,7 +1,8 @@
-use crate::{DynamicEnum, Reflect, VariantInfo, VariantType};
-use bevy_utils::HashMap;
-use std::any::{Any, TypeId};
-use std::slice::Iter;
+use crate::type_info::{str_eq, NameIndex, TypeCapabilities, TypeIdentity};
+use crate::{DynamicEnum, Reflect, TypeInfo, VariantInfo, VariantType};
+use alloc::{borrow::Cow, boxed::Box, format, string::String, vec::Vec};
+use core::any::{Any, TypeId};
+use core::slice::Iter;
 
 /// A trait representing a [reflected] enum.
 ///
@@ -117,6 +118,17 @@ pub trait Enum: Reflect {
     fn variant_index(&self) -> usize;
     /// The type of the current variant.
     fn variant_type(&self) -> VariantType;
+    /// Takes the enum apart into the owned values of the current variant's fields,
+    /// in the order of their indices.
+    ///
+    /// By default, the fields are cloned with [`Reflect::clone_value`].
+    /// [`DynamicEnum`] moves them out instead, as do enums deriving [`Reflect`]
+    /// with `#[reflect(drain)]`, which cannot be used on types implementing [`Drop`].
+    fn into_variant_fields(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.iter_fields()
+            .map(|field| field.value().clone_value())
+            .collect()
+    }
     // Clones the enum into a [`DynamicEnum`].
     fn clone_dynamic(&self) -> DynamicEnum;
     /// Returns true if the current variant's type matches the given one.
@@ -127,17 +139,26 @@ pub trait Enum: Reflect {
     fn variant_path(&self) -> String {
         format!("{}::{}", self.type_name(), self.variant_name())
     }
+    /// Returns the discriminant of the current variant, if it is known.
+    ///
+    /// This is read from the [`VariantInfo`] of the current variant,
+    /// so is always `None` for dynamic enums. See [`VariantInfo::discriminant`] for details.
+    fn variant_discriminant(&self) -> Option<i128> {
+        match self.get_type_info() {
+            TypeInfo::Enum(info) => info.variant_at(self.variant_index())?.discriminant(),
+            _ => None,
+        }
+    }
 }
 
 /// A container for compile-time enum info, used by [`TypeInfo`](crate::TypeInfo).
 #[derive(Clone, Debug)]
 pub struct EnumInfo {
     name: &'static str,
-    type_name: &'static str,
-    type_id: TypeId,
-    variants: Box<[VariantInfo]>,
-    variant_names: Box<[&'static str]>,
-    variant_indices: HashMap<&'static str, usize>,
+    ty: TypeIdentity,
+    capabilities: Option<TypeCapabilities>,
+    variants: Cow<'static, [VariantInfo]>,
+    variant_names: NameIndex,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -151,42 +172,83 @@ impl EnumInfo {
     /// * `variants`: The variants of this enum in the order they are defined
     ///
     pub fn new<TEnum: Enum>(name: &'static str, variants: &[VariantInfo]) -> Self {
-        let variant_indices = variants
-            .iter()
-            .enumerate()
-            .map(|(index, variant)| (variant.name(), index))
-            .collect::<HashMap<_, _>>();
-
-        let variant_names = variants.iter().map(|variant| variant.name()).collect();
+        Self {
+            name,
+            ty: TypeIdentity::of::<TEnum>(),
+            capabilities: None,
+            variants: Cow::Owned(variants.to_vec()),
+            variant_names: NameIndex::new(variants.iter().map(VariantInfo::name)),
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
 
+    /// Create a new [`EnumInfo`] from static data, so that it can be built in `const` contexts.
+    ///
+    /// This is used by `#[derive(Reflect)]` for non-generic enums.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The name of this enum (_without_ generics or lifetimes)
+    /// * `variants`: The variants of this enum in the order they are defined
+    /// * `variant_names`: The names of the variants, in the same order
+    /// * `sorted_variants`: The names of the variants paired with their index, sorted by name
+    ///
+    /// # Panics
+    ///
+    /// Panics, or fails to compile in a `const` context, if the names do not match the variants.
+    pub const fn from_static<TEnum: Enum>(
+        name: &'static str,
+        variants: &'static [VariantInfo],
+        variant_names: &'static [&'static str],
+        sorted_variants: &'static [(&'static str, usize)],
+    ) -> Self {
+        assert!(
+            variants.len() == variant_names.len(),
+            "variant names must be given in the order of the variants"
+        );
+        let mut i = 0;
+        while i < variants.len() {
+            assert!(
+                str_eq(variants[i].name(), variant_names[i]),
+                "variant names must be given in the order of the variants"
+            );
+            i += 1;
+        }
         Self {
             name,
-            type_name: std::any::type_name::<TEnum>(),
-            type_id: TypeId::of::<TEnum>(),
-            variants: variants.to_vec().into_boxed_slice(),
-            variant_names,
-            variant_indices,
+            ty: TypeIdentity::of::<TEnum>(),
+            capabilities: None,
+            variants: Cow::Borrowed(variants),
+            variant_names: NameIndex::from_static(variant_names, sorted_variants),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the [capabilities] of this enum.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn with_capabilities(mut self, capabilities: TypeCapabilities) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
     /// Sets the docstring for this enum.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
     }
 
     /// A slice containing the names of all variants in order.
     pub fn variant_names(&self) -> &[&'static str] {
-        &self.variant_names
+        self.variant_names.names()
     }
 
     /// Get a variant with the given name.
     pub fn variant(&self, name: &str) -> Option<&VariantInfo> {
-        self.variant_indices
-            .get(name)
-            .map(|index| &self.variants[*index])
+        self.index_of(name).map(|index| &self.variants[index])
     }
 
     /// Get a variant at the given index.
@@ -196,7 +258,7 @@ impl EnumInfo {
 
     /// Get the index of the variant with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
-        self.variant_indices.get(name).copied()
+        self.variant_names.index_of(name)
     }
 
     /// Returns the full path to the given variant.
@@ -206,9 +268,17 @@ impl EnumInfo {
         format!("{}::{name}", self.type_name())
     }
 
+    /// Get the variant with the given discriminant.
+    ///
+    /// See [`VariantInfo::discriminant`] for when discriminants are known.
+    pub fn variant_with_discriminant(&self, discriminant: i128) -> Option<&VariantInfo> {
+        self.iter()
+            .find(|variant| variant.discriminant() == Some(discriminant))
+    }
+
     /// Checks if a variant with the given name exists within this enum.
     pub fn contains_variant(&self, name: &str) -> bool {
-        self.variant_indices.contains_key(name)
+        self.index_of(name).is_some()
     }
 
     /// Iterate over the variants of this enum.
@@ -234,17 +304,24 @@ impl EnumInfo {
     ///
     /// [type name]: std::any::type_name
     pub fn type_name(&self) -> &'static str {
-        self.type_name
+        self.ty.type_name()
     }
 
     /// The [`TypeId`] of the enum.
     pub fn type_id(&self) -> TypeId {
-        self.type_id
+        self.ty.type_id()
     }
 
     /// Check if the given type matches the enum type.
     pub fn is<T: Any>(&self) -> bool {
-        TypeId::of::<T>() == self.type_id
+        TypeId::of::<T>() == self.ty.type_id()
+    }
+
+    /// The [capabilities] of this enum, if they are known.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn capabilities(&self) -> Option<TypeCapabilities> {
+        self.capabilities
     }
 
     /// The docstring of this enum, if any.
@@ -0,0 +1,116 @@
+use crate::{
+    DynamicEnum, DynamicVariant, Enum, EnumInfo, FromReflect, FromType, Reflect, TypeInfo, Typed,
+    VariantInfo,
+};
+use alloc::boxed::Box;
+
+/// Type data exposing the variants of an enum, and constructing its unit variants by name.
+///
+/// This is meant for UIs showing enums as a dropdown, which only need the names of the variants
+/// and a way to switch to one of them.
+///
+/// It is registered automatically for enums which derive [`Reflect`]. Other enums can register it
+/// with [`FromType`], which requires them to implement [`FromReflect`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectEnumVariants, TypeRegistry};
+/// # use std::any::TypeId;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// enum Quality {
+///     Low,
+///     High,
+///     Custom(u32),
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Quality>();
+///
+/// let variants = registry
+///     .get_type_data::<ReflectEnumVariants>(TypeId::of::<Quality>())
+///     .unwrap();
+/// assert_eq!(3, variants.variant_count());
+/// assert_eq!(vec!["Low", "High"], variants.unit_variant_names().collect::<Vec<_>>());
+///
+/// let high = variants.from_variant_name("High").unwrap();
+/// assert_eq!(Some(&Quality::High), high.downcast_ref::<Quality>());
+/// assert!(variants.from_variant_name("Custom").is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReflectEnumVariants {
+    info: &'static EnumInfo,
+    from_unit_variant: fn(&str) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectEnumVariants {
+    /// Creates the type data of the enum described by `info`.
+    ///
+    /// `from_unit_variant` must return the unit variant with the given name,
+    /// or `None` if there is no such unit variant.
+    pub fn new(
+        info: &'static EnumInfo,
+        from_unit_variant: fn(&str) -> Option<Box<dyn Reflect>>,
+    ) -> Self {
+        Self {
+            info,
+            from_unit_variant,
+        }
+    }
+
+    /// Returns the [`EnumInfo`] of the enum.
+    pub fn info(&self) -> &'static EnumInfo {
+        self.info
+    }
+
+    /// Returns the number of variants of the enum.
+    pub fn variant_count(&self) -> usize {
+        self.info.variant_len()
+    }
+
+    /// Returns the names of the variants, in declaration order.
+    pub fn variant_names(&self) -> &'static [&'static str] {
+        self.info.variant_names()
+    }
+
+    /// Returns an iterator over the names of the unit variants, in declaration order.
+    pub fn unit_variant_names(&self) -> impl Iterator<Item = &'static str> {
+        self.info.iter().filter_map(|variant| match variant {
+            VariantInfo::Unit(variant) => Some(variant.name()),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the unit variants, in declaration order.
+    pub fn unit_variants(&self) -> impl Iterator<Item = Box<dyn Reflect>> + '_ {
+        self.unit_variant_names()
+            .filter_map(|name| (self.from_unit_variant)(name))
+    }
+
+    /// Returns the unit variant with the given name.
+    ///
+    /// Returns `None` if the enum has no variant with that name,
+    /// or if the variant has fields.
+    pub fn from_variant_name(&self, name: &str) -> Option<Box<dyn Reflect>> {
+        (self.from_unit_variant)(name)
+    }
+}
+
+impl<T: Enum + Typed + FromReflect> FromType<T> for ReflectEnumVariants {
+    fn from_type() -> Self {
+        let TypeInfo::Enum(info) = T::type_info() else {
+            panic!("`{}` is not an enum", core::any::type_name::<T>())
+        };
+        Self::new(info, from_unit_variant::<T>)
+    }
+}
+
+fn from_unit_variant<T: Enum + Typed + FromReflect>(name: &str) -> Option<Box<dyn Reflect>> {
+    let TypeInfo::Enum(info) = T::type_info() else {
+        return None;
+    };
+    let VariantInfo::Unit(variant) = info.variant(name)? else {
+        return None;
+    };
+    let dynamic = DynamicEnum::new(info.type_name(), variant.name(), DynamicVariant::Unit);
+    Some(Box::new(T::from_reflect(&dynamic)?))
+}
@@ -1,12 +1,17 @@
+use crate::cycle::debug_guarded;
+use crate::debug_options::debug_type_path;
+use crate::reflect::iter_partial_cmp;
 use crate::{Enum, Reflect, ReflectRef, VariantType};
-use std::fmt::Debug;
-use std::hash::{Hash, Hasher};
+use alloc::{borrow::Cow, format, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
 
 /// Returns the `u64` hash of the given [enum](Enum).
 #[inline]
-pub fn enum_hash<TEnum: Enum>(value: &TEnum) -> Option<u64> {
+pub fn enum_hash<TEnum: Enum + ?Sized>(value: &TEnum) -> Option<u64> {
     let mut hasher = crate::ReflectHasher::default();
-    std::any::Any::type_id(value).hash(&mut hasher);
+    value.as_any().type_id().hash(&mut hasher);
     value.variant_name().hash(&mut hasher);
     value.variant_type().hash(&mut hasher);
     for field in value.iter_fields() {
@@ -24,7 +29,7 @@ pub fn enum_hash<TEnum: Enum>(value: &TEnum) -> Option<u64> {
 ///   [`Reflect::reflect_partial_eq`] returns `Some(true)` for the two field
 ///   values.
 #[inline]
-pub fn enum_partial_eq<TEnum: Enum>(a: &TEnum, b: &dyn Reflect) -> Option<bool> {
+pub fn enum_partial_eq<TEnum: Enum + ?Sized>(a: &TEnum, b: &dyn Reflect) -> Option<bool> {
     // Both enums?
     let ReflectRef::Enum(b) = b.reflect_ref() else {
         return Some(false);
@@ -76,6 +81,44 @@ pub fn enum_partial_eq<TEnum: Enum>(a: &TEnum, b: &dyn Reflect) -> Option<bool>
     }
 }
 
+/// Compares an [`Enum`] with a [`Reflect`] value.
+///
+/// Values of the same variant are compared lexicographically by their fields,
+/// while values of different variants are ordered by their [variant index](Enum::variant_index).
+///
+/// Returns [`None`] if `b` is not an enum, if it has a different variant at the same index,
+/// or if [`Reflect::reflect_partial_cmp`] returns `None` for a pair of fields compared.
+#[inline]
+pub fn enum_partial_cmp<TEnum: Enum + ?Sized>(a: &TEnum, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Enum(b) = b.reflect_ref() else {
+        return None;
+    };
+
+    if a.variant_name() != b.variant_name() {
+        return match a.variant_index().cmp(&b.variant_index()) {
+            Ordering::Equal => None,
+            ordering => Some(ordering),
+        };
+    }
+
+    if !a.is_variant(b.variant_type()) || a.field_len() != b.field_len() {
+        return None;
+    }
+
+    let b_fields = a
+        .iter_fields()
+        .enumerate()
+        .map(|(i, field)| match field.name() {
+            Some(name) => b.field(name),
+            None => b.field_at(i),
+        })
+        .collect::<Option<Vec<_>>>()?;
+    iter_partial_cmp(
+        a.iter_fields().map(|field| field.value()),
+        b_fields.into_iter(),
+    )
+}
+
 /// The default debug formatter for [`Enum`] types.
 ///
 /// # Example
@@ -98,22 +141,28 @@ pub fn enum_partial_eq<TEnum: Enum>(a: &TEnum, b: &dyn Reflect) -> Option<bool>
 /// // )
 /// ```
 #[inline]
-pub fn enum_debug(dyn_enum: &dyn Enum, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    match dyn_enum.variant_type() {
-        VariantType::Unit => f.write_str(dyn_enum.variant_name()),
-        VariantType::Tuple => {
-            let mut debug = f.debug_tuple(dyn_enum.variant_name());
-            for field in dyn_enum.iter_fields() {
-                debug.field(&field.value() as &dyn Debug);
+pub fn enum_debug(dyn_enum: &dyn Enum, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    debug_guarded(dyn_enum.as_reflect(), f, |f| {
+        let variant_name = match debug_type_path(dyn_enum.as_reflect()) {
+            Some(type_path) => Cow::Owned(format!("{type_path}::{}", dyn_enum.variant_name())),
+            None => Cow::Borrowed(dyn_enum.variant_name()),
+        };
+        match dyn_enum.variant_type() {
+            VariantType::Unit => f.write_str(&variant_name),
+            VariantType::Tuple => {
+                let mut debug = f.debug_tuple(&variant_name);
+                for field in dyn_enum.iter_fields() {
+                    debug.field(&field.value() as &dyn Debug);
+                }
+                debug.finish()
             }
-            debug.finish()
-        }
-        VariantType::Struct => {
-            let mut debug = f.debug_struct(dyn_enum.variant_name());
-            for field in dyn_enum.iter_fields() {
-                debug.field(field.name().unwrap(), &field.value() as &dyn Debug);
+            VariantType::Struct => {
+                let mut debug = f.debug_struct(&variant_name);
+                for field in dyn_enum.iter_fields() {
+                    debug.field(field.name().unwrap(), &field.value() as &dyn Debug);
+                }
+                debug.finish()
             }
-            debug.finish()
         }
-    }
+    })
 }
@@ -1,10 +1,12 @@
 mod dynamic_enum;
 mod enum_trait;
+mod enum_variants;
 mod helpers;
 mod variants;
 
 pub use dynamic_enum::*;
 pub use enum_trait::*;
+pub use enum_variants::*;
 pub use helpers::*;
 pub use variants::*;
 
@@ -25,7 +27,7 @@ mod tests {
         let info = MyEnum::type_info();
         if let TypeInfo::Enum(info) = info {
             assert!(info.is::<MyEnum>(), "expected type to be `MyEnum`");
-            assert_eq!(std::any::type_name::<MyEnum>(), info.type_name());
+            assert_eq!(core::any::type_name::<MyEnum>(), info.type_name());
 
             // === MyEnum::A === //
             assert_eq!("A", info.variant_at(0).unwrap().name());
@@ -342,14 +344,14 @@ mod tests {
         // === Tuple === //
         let mut data = DynamicTuple::default();
         data.insert(1.23_f32);
-        let dyn_enum = DynamicEnum::new(std::any::type_name::<TestEnum<f32>>(), "B", data);
+        let dyn_enum = DynamicEnum::new(core::any::type_name::<TestEnum<f32>>(), "B", data);
         value.apply(&dyn_enum);
         assert_eq!(TestEnum::B(1.23), value);
 
         // === Struct === //
         let mut data = DynamicStruct::default();
         data.insert("value", 1.23_f32);
-        let dyn_enum = DynamicEnum::new(std::any::type_name::<TestEnum<f32>>(), "C", data);
+        let dyn_enum = DynamicEnum::new(core::any::type_name::<TestEnum<f32>>(), "C", data);
         value.apply(&dyn_enum);
         assert_eq!(TestEnum::C { value: 1.23 }, value);
     }
@@ -371,14 +373,14 @@ mod tests {
         // === Tuple === //
         let mut data = DynamicTuple::default();
         data.insert(TestStruct(123));
-        let dyn_enum = DynamicEnum::new(std::any::type_name::<TestEnum>(), "B", data);
+        let dyn_enum = DynamicEnum::new(core::any::type_name::<TestEnum>(), "B", data);
         value.apply(&dyn_enum);
         assert_eq!(TestEnum::B(TestStruct(123)), value);
 
         // === Struct === //
         let mut data = DynamicStruct::default();
         data.insert("value", TestStruct(123));
-        let dyn_enum = DynamicEnum::new(std::any::type_name::<TestEnum>(), "C", data);
+        let dyn_enum = DynamicEnum::new(core::any::type_name::<TestEnum>(), "C", data);
         value.apply(&dyn_enum);
         assert_eq!(
             TestEnum::C {
@@ -409,14 +411,14 @@ mod tests {
         // === Tuple === //
         let mut data = DynamicTuple::default();
         data.insert(OtherEnum::B(123));
-        let dyn_enum = DynamicEnum::new(std::any::type_name::<TestEnum>(), "B", data);
+        let dyn_enum = DynamicEnum::new(core::any::type_name::<TestEnum>(), "B", data);
         value.apply(&dyn_enum);
         assert_eq!(TestEnum::B(OtherEnum::B(123)), value);
 
         // === Struct === //
         let mut data = DynamicStruct::default();
         data.insert("value", OtherEnum::C { value: 1.23 });
-        let dyn_enum = DynamicEnum::new(std::any::type_name::<TestEnum>(), "C", data);
+        let dyn_enum = DynamicEnum::new(core::any::type_name::<TestEnum>(), "C", data);
         value.apply(&dyn_enum);
         assert_eq!(
             TestEnum::C {
@@ -624,4 +626,131 @@ mod tests {
             "expected TestEnum::C{{value: 123}} != TestEnum::C2{{value: 1.23}}"
         );
     }
+
+    #[test]
+    fn should_register_enum_variants() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq, ::serde::Serialize)]
+        #[reflect(serde_compat)]
+        enum Mode {
+            Off,
+            #[serde(rename = "on", alias = "enabled")]
+            On,
+            Timed(f32),
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Mode>();
+        let variants = registry
+            .get_type_data::<ReflectEnumVariants>(core::any::TypeId::of::<Mode>())
+            .unwrap();
+        assert_eq!(&["Off", "on", "Timed"], variants.variant_names());
+        let values = variants
+            .unit_variants()
+            .map(|value| value.take::<Mode>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Mode::Off, Mode::On], values);
+        let on = variants.from_variant_name("enabled").unwrap();
+        assert_eq!(Some(&Mode::On), on.downcast_ref::<Mode>());
+        assert!(variants.from_variant_name("On").is_none());
+        assert!(variants.from_variant_name("Timed").is_none());
+
+        // Enums which don't derive `Reflect` construct their unit variants with `FromReflect`
+        let variants = <ReflectEnumVariants as FromType<Mode>>::from_type();
+        assert_eq!(
+            vec!["Off", "on"],
+            variants.unit_variant_names().collect::<Vec<_>>()
+        );
+        let off = variants.from_variant_name("Off").unwrap();
+        assert_eq!(Some(&Mode::Off), off.downcast_ref::<Mode>());
+        assert!(variants.from_variant_name("Timed").is_none());
+    }
+
+    #[test]
+    fn should_capture_discriminants() {
+        #[derive(Reflect)]
+        #[repr(u8)]
+        enum Opcode {
+            Nop,
+            Load = 0x10,
+            Store,
+            Jump(u16) = 0x20,
+        }
+
+        #[derive(Reflect)]
+        enum Sign {
+            Negative = -1,
+            Zero,
+            Positive,
+        }
+
+        let TypeInfo::Enum(info) = Opcode::type_info() else {
+            panic!("expected enum info");
+        };
+        let discriminants = info
+            .iter()
+            .map(VariantInfo::discriminant)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Some(0), Some(0x10), Some(0x11), Some(0x20)],
+            discriminants
+        );
+        assert_eq!(Some(0x11), Opcode::Store.variant_discriminant());
+        assert_eq!(Some(0x20), Opcode::Jump(1).variant_discriminant());
+        assert_eq!(
+            Some("Load"),
+            info.variant_with_discriminant(0x10).map(VariantInfo::name)
+        );
+        assert!(info.variant_with_discriminant(0x12).is_none());
+
+        assert_eq!(Some(-1), Sign::Negative.variant_discriminant());
+        assert_eq!(Some(1), Sign::Positive.variant_discriminant());
+
+        // Discriminants are only captured when the enum makes use of them
+        assert_eq!(None, MyEnum::A.variant_discriminant());
+        assert_eq!(None, MyEnum::A.clone_dynamic().variant_discriminant());
+        let _ = (Opcode::Nop, Opcode::Load, Sign::Zero);
+    }
+
+    #[test]
+    fn should_move_variant_fields_out() {
+        #[derive(Reflect)]
+        #[reflect(drain)]
+        enum Message {
+            Quit,
+            Write(String, #[reflect(ignore)] ()),
+            Move { x: i32, y: i32 },
+        }
+
+        #[derive(Reflect)]
+        enum Guarded {
+            Text(String),
+        }
+
+        impl Drop for Guarded {
+            fn drop(&mut self) {}
+        }
+
+        let text = String::from("moved");
+        let text_ptr = text.as_ptr();
+        let message: Box<dyn Enum> = Box::new(Message::Write(text, ()));
+        let fields = message.into_variant_fields();
+        assert_eq!(1, fields.len());
+        assert_eq!(
+            text_ptr,
+            fields[0].downcast_ref::<String>().unwrap().as_ptr()
+        );
+
+        let fields = Box::new(Message::Move { x: 1, y: 2 }).into_variant_fields();
+        assert_eq!(Some(&2), fields[1].downcast_ref::<i32>());
+        assert!(Box::new(Message::Quit).into_variant_fields().is_empty());
+
+        let guarded = Box::new(Guarded::Text(String::from("cloned")));
+        let fields = guarded.into_variant_fields();
+        assert_eq!(Some(&String::from("cloned")), fields[0].downcast_ref());
+
+        let dynamic = Box::new(Message::Move { x: 3, y: 4 }.clone_dynamic());
+        let fields = dynamic.into_variant_fields();
+        assert_eq!(Some(&3), fields[0].downcast_ref::<i32>());
+        assert_eq!(Some(&4), fields[1].downcast_ref::<i32>());
+    }
 }
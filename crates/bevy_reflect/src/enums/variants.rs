@@ -1,6 +1,7 @@
+use crate::type_info::{fields_match_names, NameIndex};
 use crate::{NamedField, UnnamedField};
-use bevy_utils::HashMap;
-use std::slice::Iter;
+use alloc::borrow::Cow;
+use core::slice::Iter;
 
 /// Describes the form of an enum variant.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -65,7 +66,7 @@ pub enum VariantInfo {
 }
 
 impl VariantInfo {
-    pub fn name(&self) -> &'static str {
+    pub const fn name(&self) -> &'static str {
         match self {
             Self::Struct(info) => info.name(),
             Self::Tuple(info) => info.name(),
@@ -73,6 +74,20 @@ impl VariantInfo {
         }
     }
 
+    /// The discriminant of the underlying variant.
+    ///
+    /// This is only known for variants of enums which give a discriminant to any of their
+    /// variants, or which have a primitive or `C` representation, such as `#[repr(u8)]`.
+    /// Variants without an explicit discriminant then have the one following the previous variant,
+    /// as in Rust.
+    pub const fn discriminant(&self) -> Option<i128> {
+        match self {
+            Self::Struct(info) => info.discriminant(),
+            Self::Tuple(info) => info.discriminant(),
+            Self::Unit(info) => info.discriminant(),
+        }
+    }
+
     /// The docstring of the underlying variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -88,9 +103,9 @@ impl VariantInfo {
 #[derive(Clone, Debug)]
 pub struct StructVariantInfo {
     name: &'static str,
-    fields: Box<[NamedField]>,
-    field_names: Box<[&'static str]>,
-    field_indices: HashMap<&'static str, usize>,
+    fields: Cow<'static, [NamedField]>,
+    field_names: NameIndex,
+    discriminant: Option<i128>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -98,39 +113,78 @@ pub struct StructVariantInfo {
 impl StructVariantInfo {
     /// Create a new [`StructVariantInfo`].
     pub fn new(name: &'static str, fields: &[NamedField]) -> Self {
-        let field_indices = Self::collect_field_indices(fields);
-        let field_names = fields.iter().map(|field| field.name()).collect();
         Self {
             name,
-            fields: fields.to_vec().into_boxed_slice(),
-            field_names,
-            field_indices,
+            fields: Cow::Owned(fields.to_vec()),
+            field_names: NameIndex::new(fields.iter().map(NamedField::name)),
+            discriminant: None,
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
+    /// Create a new [`StructVariantInfo`] from static data, so that it can be built in `const` contexts.
+    ///
+    /// See [`StructInfo::from_static`] for details on the arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics, or fails to compile in a `const` context, if the names do not match the fields.
+    ///
+    /// [`StructInfo::from_static`]: crate::StructInfo::from_static
+    pub const fn from_static(
+        name: &'static str,
+        fields: &'static [NamedField],
+        field_names: &'static [&'static str],
+        sorted_fields: &'static [(&'static str, usize)],
+    ) -> Self {
+        assert!(
+            fields_match_names(fields, field_names),
+            "field names must be given in the order of the fields"
+        );
+        Self {
+            name,
+            fields: Cow::Borrowed(fields),
+            field_names: NameIndex::from_static(field_names, sorted_fields),
+            discriminant: None,
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the discriminant of this variant.
+    pub const fn with_discriminant(mut self, discriminant: i128) -> Self {
+        self.discriminant = Some(discriminant);
+        self
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
     }
 
     /// The name of this variant.
-    pub fn name(&self) -> &'static str {
+    pub const fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The discriminant of this variant.
+    ///
+    /// See [`VariantInfo::discriminant`] for when this is known.
+    pub const fn discriminant(&self) -> Option<i128> {
+        self.discriminant
+    }
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
-        &self.field_names
+        self.field_names.names()
     }
 
     /// Get the field with the given name.
     pub fn field(&self, name: &str) -> Option<&NamedField> {
-        self.field_indices
-            .get(name)
-            .map(|index| &self.fields[*index])
+        self.index_of(name).map(|index| &self.fields[index])
     }
 
     /// Get the field at the given index.
@@ -140,7 +194,7 @@ impl StructVariantInfo {
 
     /// Get the index of the field with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
-        self.field_indices.get(name).copied()
+        self.field_names.index_of(name)
     }
 
     /// Iterate over the fields of this variant.
@@ -153,14 +207,6 @@ impl StructVariantInfo {
         self.fields.len()
     }
 
-    fn collect_field_indices(fields: &[NamedField]) -> HashMap<&'static str, usize> {
-        fields
-            .iter()
-            .enumerate()
-            .map(|(index, field)| (field.name(), index))
-            .collect()
-    }
-
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -172,7 +218,8 @@ impl StructVariantInfo {
 #[derive(Clone, Debug)]
 pub struct TupleVariantInfo {
     name: &'static str,
-    fields: Box<[UnnamedField]>,
+    fields: Cow<'static, [UnnamedField]>,
+    discriminant: Option<i128>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -182,23 +229,49 @@ impl TupleVariantInfo {
     pub fn new(name: &'static str, fields: &[UnnamedField]) -> Self {
         Self {
             name,
-            fields: fields.to_vec().into_boxed_slice(),
+            fields: Cow::Owned(fields.to_vec()),
+            discriminant: None,
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
+    /// Create a new [`TupleVariantInfo`] from static data, so that it can be built in `const` contexts.
+    pub const fn from_static(name: &'static str, fields: &'static [UnnamedField]) -> Self {
+        Self {
+            name,
+            fields: Cow::Borrowed(fields),
+            discriminant: None,
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the discriminant of this variant.
+    pub const fn with_discriminant(mut self, discriminant: i128) -> Self {
+        self.discriminant = Some(discriminant);
+        self
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
     }
 
     /// The name of this variant.
-    pub fn name(&self) -> &'static str {
+    pub const fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The discriminant of this variant.
+    ///
+    /// See [`VariantInfo::discriminant`] for when this is known.
+    pub const fn discriminant(&self) -> Option<i128> {
+        self.discriminant
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
@@ -225,31 +298,47 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    discriminant: Option<i128>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl UnitVariantInfo {
     /// Create a new [`UnitVariantInfo`].
-    pub fn new(name: &'static str) -> Self {
+    pub const fn new(name: &'static str) -> Self {
         Self {
             name,
+            discriminant: None,
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the discriminant of this variant.
+    pub const fn with_discriminant(mut self, discriminant: i128) -> Self {
+        self.discriminant = Some(discriminant);
+        self
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
     }
 
     /// The name of this variant.
-    pub fn name(&self) -> &'static str {
+    pub const fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The discriminant of this variant.
+    ///
+    /// See [`VariantInfo::discriminant`] for when this is known.
+    pub const fn discriminant(&self) -> Option<i128> {
+        self.discriminant
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
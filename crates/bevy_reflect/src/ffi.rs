@@ -0,0 +1,520 @@
+//! A C interface to the reflection data of a [`TypeRegistry`], for native editor plugins
+//! and other languages.
+//!
+//! Types are looked up by the [hash of their type path](type_path_hash), which only
+//! finds types whose path was registered with [`TypeRegistry::register_type_path`].
+//! A type is then referred to by a pointer to its [`TypeRegistration`], which stays valid
+//! as long as the registry is neither changed nor dropped.
+//!
+//! Values are accessed through the [`ReflectFromPtr`] of their type, so only types
+//! registered with it can be read and written.
+//!
+//! Strings are passed as [`FfiStr`]s, which are not nul-terminated.
+//! Every function accepts null pointers, for which it returns an empty result,
+//! such as null, `false` or `0`.
+
+use crate::{
+    Reflect, ReflectFromPtr, ReflectMut, ReflectRef, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use bevy_ptr::{Ptr, PtrMut};
+use std::ffi::c_void;
+use std::panic::catch_unwind;
+use std::panic::AssertUnwindSafe;
+use std::ptr::{self, NonNull};
+
+/// A string borrowed from Rust, as a pointer to its UTF-8 bytes and their length.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FfiStr {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl FfiStr {
+    /// An empty string.
+    pub const EMPTY: Self = Self::new("");
+
+    const fn new(value: &'static str) -> Self {
+        Self {
+            ptr: value.as_ptr(),
+            len: value.len(),
+        }
+    }
+}
+
+/// The kind of a reflected type, mirroring the variants of [`TypeInfo`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FfiTypeKind {
+    Struct,
+    TupleStruct,
+    Tuple,
+    List,
+    Array,
+    Map,
+    Enum,
+    Value,
+    Dynamic,
+}
+
+/// A field of a struct, tuple struct or tuple, as described by its [`NamedField`] or [`UnnamedField`].
+///
+/// [`NamedField`]: crate::NamedField
+/// [`UnnamedField`]: crate::UnnamedField
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FfiField {
+    /// The name of the field, which is empty for unnamed fields.
+    pub name: FfiStr,
+    /// The [type name](std::any::type_name) of the field.
+    pub type_name: FfiStr,
+    /// The registration of the field's type, or null if it is not registered.
+    pub registration: *const TypeRegistration,
+    /// The offset in bytes of the field, or `usize::MAX` if it is not known.
+    pub offset: usize,
+    /// The size in bytes of the field's type, or `0` if it is not known.
+    pub size: usize,
+    /// The alignment of the field's type, or `0` if it is not known.
+    pub align: usize,
+}
+
+/// Returns the hash under which the type with the given [type path] is looked up.
+///
+/// This is the 64-bit FNV-1a hash of the path's UTF-8 bytes.
+///
+/// [type path]: crate::TypePath::type_path
+pub const fn type_path_hash(type_path: &str) -> u64 {
+    let bytes = type_path.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Returns the [`type_path_hash`] of the `len` bytes at `type_path`.
+///
+/// # Safety
+///
+/// `type_path` must be null or point to `len` bytes of valid UTF-8.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_type_path_hash(type_path: *const u8, len: usize) -> u64 {
+    if type_path.is_null() {
+        return type_path_hash("");
+    }
+    let bytes = std::slice::from_raw_parts(type_path, len);
+    type_path_hash(std::str::from_utf8_unchecked(bytes))
+}
+
+/// Returns the registration of the type whose path has the given [`type_path_hash`],
+/// or null if there is none.
+///
+/// If the paths of several registered types share this hash, returns null as well.
+///
+/// # Safety
+///
+/// `registry` must be null or point to a valid [`TypeRegistry`].
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_get_with_type_path_hash(
+    registry: *const TypeRegistry,
+    hash: u64,
+) -> *const TypeRegistration {
+    let Some(registry) = registry.as_ref() else {
+        return ptr::null();
+    };
+    registry
+        .get_type_path_with_hash(hash)
+        .and_then(|type_path| registry.get_with_type_path(type_path))
+        .map_or(ptr::null(), |registration| registration as *const _)
+}
+
+/// Returns the [type name](std::any::type_name) of the registered type.
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_type_name(registration: *const TypeRegistration) -> FfiStr {
+    registration.as_ref().map_or(FfiStr::EMPTY, |registration| {
+        FfiStr::new(registration.type_info().type_name())
+    })
+}
+
+/// Returns the kind of the registered type, or [`FfiTypeKind::Dynamic`] if `registration` is null.
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_type_kind(
+    registration: *const TypeRegistration,
+) -> FfiTypeKind {
+    let Some(registration) = registration.as_ref() else {
+        return FfiTypeKind::Dynamic;
+    };
+    match registration.type_info() {
+        TypeInfo::Struct(_) => FfiTypeKind::Struct,
+        TypeInfo::TupleStruct(_) => FfiTypeKind::TupleStruct,
+        TypeInfo::Tuple(_) => FfiTypeKind::Tuple,
+        TypeInfo::List(_) => FfiTypeKind::List,
+        TypeInfo::Array(_) => FfiTypeKind::Array,
+        TypeInfo::Map(_) => FfiTypeKind::Map,
+        TypeInfo::Enum(_) => FfiTypeKind::Enum,
+        TypeInfo::Value(_) => FfiTypeKind::Value,
+        TypeInfo::Dynamic(_) => FfiTypeKind::Dynamic,
+    }
+}
+
+/// Returns the number of fields of the registered struct, tuple struct or tuple,
+/// or `0` for other kinds of types.
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_field_count(registration: *const TypeRegistration) -> usize {
+    match registration.as_ref().map(TypeRegistration::type_info) {
+        Some(TypeInfo::Struct(info)) => info.field_len(),
+        Some(TypeInfo::TupleStruct(info)) => info.field_len(),
+        Some(TypeInfo::Tuple(info)) => info.field_len(),
+        _ => 0,
+    }
+}
+
+/// Writes the field at `index` of the registered struct, tuple struct or tuple to `out`.
+///
+/// Returns `false`, leaving `out` untouched, if the type has no such field.
+///
+/// # Safety
+///
+/// `registry` must be null or point to a valid [`TypeRegistry`], `registration` must be null
+/// or a registration returned by this module from it, and `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_field(
+    registry: *const TypeRegistry,
+    registration: *const TypeRegistration,
+    index: usize,
+    out: *mut FfiField,
+) -> bool {
+    let (Some(registry), Some(registration)) = (registry.as_ref(), registration.as_ref()) else {
+        return false;
+    };
+    if out.is_null() {
+        return false;
+    }
+    let field = match registration.type_info() {
+        TypeInfo::Struct(info) => info.field_at(index).map(|field| {
+            (
+                field.name(),
+                field.type_name(),
+                field.type_id(),
+                field.offset(),
+                field.layout(),
+            )
+        }),
+        TypeInfo::TupleStruct(info) => info.field_at(index).map(|field| {
+            (
+                "",
+                field.type_name(),
+                field.type_id(),
+                field.offset(),
+                field.layout(),
+            )
+        }),
+        TypeInfo::Tuple(info) => info.field_at(index).map(|field| {
+            (
+                "",
+                field.type_name(),
+                field.type_id(),
+                field.offset(),
+                field.layout(),
+            )
+        }),
+        _ => None,
+    };
+    let Some((name, type_name, type_id, offset, layout)) = field else {
+        return false;
+    };
+    out.write(FfiField {
+        name: FfiStr::new(name),
+        type_name: FfiStr::new(type_name),
+        registration: registry
+            .get(type_id)
+            .map_or(ptr::null(), |registration| registration as *const _),
+        offset: offset.unwrap_or(usize::MAX),
+        size: layout.map_or(0, |layout| layout.size()),
+        align: layout.map_or(0, |layout| layout.align()),
+    });
+    true
+}
+
+/// Returns the number of variants of the registered enum, or `0` for other kinds of types.
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_variant_count(
+    registration: *const TypeRegistration,
+) -> usize {
+    match registration.as_ref().map(TypeRegistration::type_info) {
+        Some(TypeInfo::Enum(info)) => info.variant_len(),
+        _ => 0,
+    }
+}
+
+/// Returns the name of the variant at `index` of the registered enum,
+/// or an empty string if it has no such variant.
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_variant_name(
+    registration: *const TypeRegistration,
+    index: usize,
+) -> FfiStr {
+    match registration.as_ref().map(TypeRegistration::type_info) {
+        Some(TypeInfo::Enum(info)) => info
+            .variant_at(index)
+            .map_or(FfiStr::EMPTY, |variant| FfiStr::new(variant.name())),
+        _ => FfiStr::EMPTY,
+    }
+}
+
+/// Returns a pointer to the field at `index` of `value`, a value of the registered
+/// struct, tuple struct or tuple, or null if it has no such field.
+///
+/// The field can be read like a value of the type given by [`bevy_reflect_field`].
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module,
+/// and `value` must point to a valid value of its type.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_field_ptr(
+    registration: *const TypeRegistration,
+    value: *const c_void,
+    index: usize,
+) -> *const c_void {
+    let Some(value) = reflect_ref(registration, value) else {
+        return ptr::null();
+    };
+    let field = match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.field_at(index),
+        ReflectRef::TupleStruct(value) => value.field(index),
+        ReflectRef::Tuple(value) => value.field(index),
+        _ => None,
+    };
+    field.map_or(ptr::null(), |field| {
+        field.as_any() as *const _ as *const c_void
+    })
+}
+
+/// The mutable equivalent of [`bevy_reflect_field_ptr`].
+///
+/// Returns null for fields which cannot be borrowed mutably, such as fields with a setter.
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module,
+/// and `value` must point to a valid value of its type which is not borrowed elsewhere.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_field_ptr_mut(
+    registration: *const TypeRegistration,
+    value: *mut c_void,
+    index: usize,
+) -> *mut c_void {
+    let Some(value) = reflect_mut(registration, value) else {
+        return ptr::null_mut();
+    };
+    let field = match value.reflect_mut() {
+        ReflectMut::Struct(value) => value.field_at_mut(index),
+        ReflectMut::TupleStruct(value) => value.field_mut(index),
+        ReflectMut::Tuple(value) => value.field_mut(index),
+        _ => None,
+    };
+    field.map_or(ptr::null_mut(), |field| {
+        field.as_any_mut() as *mut _ as *mut c_void
+    })
+}
+
+/// [Applies](Reflect::apply) `source` to `target`, which are both values of the registered type.
+///
+/// Returns `false` if the values could not be accessed or if applying them panicked.
+///
+/// # Safety
+///
+/// `registration` must be null or a registration returned by this module,
+/// `source` must point to a valid value of its type, and `target` must point to
+/// another valid value of its type which is not borrowed elsewhere.
+#[no_mangle]
+pub unsafe extern "C" fn bevy_reflect_apply(
+    registration: *const TypeRegistration,
+    target: *mut c_void,
+    source: *const c_void,
+) -> bool {
+    let (Some(target), Some(source)) = (
+        reflect_mut(registration, target),
+        reflect_ref(registration, source),
+    ) else {
+        return false;
+    };
+    // Unwinding into foreign code is undefined behavior
+    catch_unwind(AssertUnwindSafe(|| target.apply(source))).is_ok()
+}
+
+/// Accesses `value` through the [`ReflectFromPtr`] of the registered type.
+///
+/// # Safety
+///
+/// `registration` must be null or valid, and `value` must be null or point to a valid value
+/// of the registered type.
+unsafe fn reflect_ref<'a>(
+    registration: *const TypeRegistration,
+    value: *const c_void,
+) -> Option<&'a dyn Reflect> {
+    let reflect_from_ptr = registration.as_ref()?.data::<ReflectFromPtr>()?;
+    let value = NonNull::new(value as *mut u8)?;
+    Some(reflect_from_ptr.as_reflect_ptr(Ptr::new(value)))
+}
+
+/// The mutable equivalent of [`reflect_ref`].
+///
+/// # Safety
+///
+/// As for [`reflect_ref`], and `value` must not be borrowed elsewhere.
+unsafe fn reflect_mut<'a>(
+    registration: *const TypeRegistration,
+    value: *mut c_void,
+) -> Option<&'a mut dyn Reflect> {
+    let reflect_from_ptr = registration.as_ref()?.data::<ReflectFromPtr>()?;
+    let value = NonNull::new(value as *mut u8)?;
+    Some(reflect_from_ptr.as_reflect_ptr_mut(PtrMut::new(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: f32,
+        kind: Kind,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Kind {
+        Human,
+        Robot,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Kind>();
+        registry.register::<f32>();
+        registry.register::<String>();
+        registry.register_type_path::<Player>();
+        registry
+    }
+
+    unsafe fn str(value: FfiStr) -> &'static str {
+        std::str::from_utf8_unchecked(std::slice::from_raw_parts(value.ptr, value.len))
+    }
+
+    #[test]
+    fn should_describe_types() {
+        let registry = registry();
+        let path = <Player as crate::TypePath>::type_path();
+        unsafe {
+            let hash = bevy_reflect_type_path_hash(path.as_ptr(), path.len());
+            assert_eq!(type_path_hash(path), hash);
+            let player = bevy_reflect_get_with_type_path_hash(&registry, hash);
+            assert!(!player.is_null());
+            assert_eq!(
+                std::any::type_name::<Player>(),
+                str(bevy_reflect_type_name(player))
+            );
+            assert_eq!(FfiTypeKind::Struct, bevy_reflect_type_kind(player));
+            assert_eq!(3, bevy_reflect_field_count(player));
+
+            let mut field = std::mem::MaybeUninit::uninit();
+            assert!(bevy_reflect_field(&registry, player, 2, field.as_mut_ptr()));
+            let field = field.assume_init();
+            assert_eq!("kind", str(field.name));
+            assert_eq!(std::mem::size_of::<Kind>(), field.size);
+
+            let kind = field.registration;
+            assert_eq!(FfiTypeKind::Enum, bevy_reflect_type_kind(kind));
+            assert_eq!(2, bevy_reflect_variant_count(kind));
+            assert_eq!("Robot", str(bevy_reflect_variant_name(kind, 1)));
+            assert_eq!("", str(bevy_reflect_variant_name(kind, 2)));
+
+            let mut field = std::mem::MaybeUninit::uninit();
+            assert!(!bevy_reflect_field(
+                &registry,
+                player,
+                3,
+                field.as_mut_ptr()
+            ));
+            assert!(bevy_reflect_get_with_type_path_hash(&registry, hash + 1).is_null());
+        }
+    }
+
+    #[test]
+    fn should_not_look_up_colliding_type_path_hashes() {
+        let mut registry = registry();
+        let path = <Player as crate::TypePath>::type_path();
+        let hash = type_path_hash(path);
+        registry.register_type_path::<Player>();
+        unsafe {
+            assert!(!bevy_reflect_get_with_type_path_hash(&registry, hash).is_null());
+        }
+
+        registry.register_type_path_hash(hash, "my_crate::Colliding");
+        unsafe {
+            assert!(bevy_reflect_get_with_type_path_hash(&registry, hash).is_null());
+        }
+        registry.register_type_path::<Player>();
+        unsafe {
+            assert!(bevy_reflect_get_with_type_path_hash(&registry, hash).is_null());
+        }
+    }
+
+    #[test]
+    fn should_access_values() {
+        let registry = registry();
+        let player = registry.get(std::any::TypeId::of::<Player>()).unwrap() as *const _;
+        let mut value = Player {
+            name: String::from("player"),
+            health: 100.0,
+            kind: Kind::Human,
+        };
+        let other = Player {
+            name: String::from("robot"),
+            health: 50.0,
+            kind: Kind::Robot,
+        };
+        unsafe {
+            let health = bevy_reflect_field_ptr(player, &value as *const _ as *const c_void, 1);
+            assert_eq!(100.0, *(health as *const f32));
+
+            let health = bevy_reflect_field_ptr_mut(player, &mut value as *mut _ as *mut c_void, 1);
+            *(health as *mut f32) = 75.0;
+            assert_eq!(75.0, value.health);
+
+            assert!(bevy_reflect_apply(
+                player,
+                &mut value as *mut _ as *mut c_void,
+                &other as *const _ as *const c_void,
+            ));
+        }
+        assert_eq!(other, value);
+    }
+}
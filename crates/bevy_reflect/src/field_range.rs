@@ -0,0 +1,120 @@
+use bevy_utils::HashMap;
+use core::ops::{Range, RangeInclusive};
+
+/// A range of values a numeric field is expected to hold.
+///
+/// Ranges are attached to the fields of a type through its [`FieldRanges`] type data,
+/// such as with `#[reflect(range = "0..10")]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldRange {
+    start: f64,
+    end: f64,
+    inclusive: bool,
+}
+
+impl FieldRange {
+    /// Creates a range from `start` to `end`, which includes `end` if `inclusive` is true.
+    pub fn new(start: f64, end: f64, inclusive: bool) -> Self {
+        Self {
+            start,
+            end,
+            inclusive,
+        }
+    }
+
+    /// The lower bound of the range, which is always included.
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// The upper bound of the range.
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+
+    /// Returns `true` if the upper bound is part of the range.
+    pub fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    /// Returns `true` if the range contains no values.
+    pub fn is_empty(&self) -> bool {
+        !self.contains(self.start)
+    }
+
+    /// Returns `true` if the given value is within the range.
+    pub fn contains(&self, value: f64) -> bool {
+        if self.inclusive {
+            (self.start..=self.end).contains(&value)
+        } else {
+            (self.start..self.end).contains(&value)
+        }
+    }
+}
+
+impl<T: Into<f64>> From<Range<T>> for FieldRange {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start.into(), range.end.into(), false)
+    }
+}
+
+impl<T: Into<f64>> From<RangeInclusive<T>> for FieldRange {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(start.into(), end.into(), true)
+    }
+}
+
+/// Type data holding the [ranges] of the fields of a struct or tuple struct.
+///
+/// This is registered by `#[derive(Reflect)]` for types with fields marked
+/// `#[reflect(range = "...")]`, where the range is any range expression with a start
+/// and an end which can be cast to `f64`. Fields are identified by their index
+/// among the reflected fields of the type.
+///
+/// ```
+/// # use bevy_reflect::{FieldRanges, GetTypeRegistration, Reflect};
+/// #[derive(Reflect)]
+/// struct Enemy {
+///     #[reflect(range = "1..=100")]
+///     health: u32,
+///     #[reflect(range = "0.0..1.0")]
+///     dodge_chance: f32,
+///     name: String,
+/// }
+///
+/// let registration = Enemy::get_type_registration();
+/// let ranges = registration.data::<FieldRanges>().unwrap();
+/// assert!(ranges.get(0).unwrap().contains(100.0));
+/// assert!(!ranges.get(1).unwrap().contains(1.0));
+/// assert!(ranges.get(2).is_none());
+/// ```
+///
+/// [ranges]: FieldRange
+#[derive(Debug, Clone, Default)]
+pub struct FieldRanges {
+    ranges: HashMap<usize, FieldRange>,
+}
+
+impl FieldRanges {
+    /// Sets the range of the field at the given index.
+    pub fn with_range(mut self, index: usize, range: impl Into<FieldRange>) -> Self {
+        self.ranges.insert(index, range.into());
+        self
+    }
+
+    /// Returns the range of the field at the given index, if it has one.
+    pub fn get(&self, index: usize) -> Option<&FieldRange> {
+        self.ranges.get(&index)
+    }
+
+    /// Returns the number of fields with a range.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if no field has a range.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
@@ -1,36 +1,63 @@
-use crate::Reflect;
-use std::any::{Any, TypeId};
+use crate::attributes::{Category, CustomAttributes, Multiline, Range, Step, Tooltip};
+use crate::type_info::TypeIdentity;
+use core::alloc::Layout;
+use core::any::{Any, TypeId};
 
 /// The named field of a reflected struct.
 #[derive(Clone, Debug)]
 pub struct NamedField {
     name: &'static str,
-    type_name: &'static str,
-    type_id: TypeId,
+    ty: TypeIdentity,
+    offset: Option<usize>,
+    layout: Option<Layout>,
+    attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl NamedField {
     /// Create a new [`NamedField`].
-    pub fn new<T: Reflect>(name: &'static str) -> Self {
+    pub const fn new<T: ?Sized + 'static>(name: &'static str) -> Self {
         Self {
             name,
-            type_name: std::any::type_name::<T>(),
-            type_id: TypeId::of::<T>(),
+            ty: TypeIdentity::of::<T>(),
+            offset: None,
+            layout: None,
+            attributes: CustomAttributes::EMPTY,
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the offset in bytes of this field from the start of its container.
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the [`Layout`] of this field's type.
+    pub const fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Sets the [custom attributes] of this field.
+    ///
+    /// [custom attributes]: CustomAttributes
+    pub const fn with_attributes(mut self, attributes: CustomAttributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
     }
 
     /// The name of the field.
-    pub fn name(&self) -> &'static str {
+    pub const fn name(&self) -> &'static str {
         self.name
     }
 
@@ -38,17 +65,94 @@ impl NamedField {
     ///
     /// [type name]: std::any::type_name
     pub fn type_name(&self) -> &'static str {
-        self.type_name
+        self.ty.type_name()
     }
 
     /// The [`TypeId`] of the field.
     pub fn type_id(&self) -> TypeId {
-        self.type_id
+        self.ty.type_id()
     }
 
     /// Check if the given type matches the field type.
     pub fn is<T: Any>(&self) -> bool {
-        TypeId::of::<T>() == self.type_id
+        TypeId::of::<T>() == self.ty.type_id()
+    }
+
+    /// The offset in bytes of this field from the start of its container, if known.
+    ///
+    /// `#[derive(Reflect)]` sets it for the fields of structs and tuple structs, along with
+    /// the [layout], except for the fields using `#[reflect(with = "...")]` or `#[reflect(dynamic)]`,
+    /// which are not described by the type they are stored as.
+    /// Together with [`ReflectFromPtr`], it gives access to a field from a pointer to its container.
+    /// The field may be unaligned if the container is `#[repr(packed)]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{Reflect, ReflectFromPtr, TypeInfo, Typed, TypeRegistry};
+    /// # use bevy_ptr::Ptr;
+    /// #[derive(Reflect)]
+    /// struct Player {
+    ///     name: String,
+    ///     health: f32,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<f32>();
+    ///
+    /// let TypeInfo::Struct(info) = Player::type_info() else { unreachable!() };
+    /// let field = info.field("health").unwrap();
+    /// let reflect_from_ptr = registry.get_type_data::<ReflectFromPtr>(field.type_id()).unwrap();
+    ///
+    /// let player = Player { name: String::from("player"), health: 100.0 };
+    /// // SAFE: the offset of `health` points to an `f32`, which the `ReflectFromPtr` was created for
+    /// let health = unsafe {
+    ///     let ptr = Ptr::from(&player).byte_add(field.offset().unwrap());
+    ///     reflect_from_ptr.as_reflect_ptr(ptr)
+    /// };
+    /// assert_eq!(Some(&100.0), health.downcast_ref::<f32>());
+    /// ```
+    ///
+    /// [layout]: NamedField::layout
+    /// [`ReflectFromPtr`]: crate::ReflectFromPtr
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// The [`Layout`] of this field's type, if known.
+    pub const fn layout(&self) -> Option<Layout> {
+        self.layout
+    }
+
+    /// The [custom attributes] of this field, added with `#[reflect(@...)]`.
+    ///
+    /// [custom attributes]: CustomAttributes
+    pub const fn attributes(&self) -> &CustomAttributes {
+        &self.attributes
+    }
+
+    /// The [`Range`] attribute of this field, if it has one.
+    pub fn range(&self) -> Option<&'static Range> {
+        self.attributes.get()
+    }
+
+    /// The [`Step`] attribute of this field, if it has one.
+    pub fn step(&self) -> Option<f64> {
+        self.attributes.get::<Step>().map(|step| step.0)
+    }
+
+    /// The text of the [`Tooltip`] attribute of this field, if it has one.
+    pub fn tooltip(&self) -> Option<&'static str> {
+        self.attributes.get::<Tooltip>().map(|tooltip| tooltip.0)
+    }
+
+    /// The name of the [`Category`] attribute of this field, if it has one.
+    pub fn category(&self) -> Option<&'static str> {
+        self.attributes.get::<Category>().map(|category| category.0)
+    }
+
+    /// Returns `true` if this field has the [`Multiline`] attribute.
+    pub fn is_multiline(&self) -> bool {
+        self.attributes.contains::<Multiline>()
     }
 
     /// The docstring of this field, if any.
@@ -62,31 +166,56 @@ impl NamedField {
 #[derive(Clone, Debug)]
 pub struct UnnamedField {
     index: usize,
-    type_name: &'static str,
-    type_id: TypeId,
+    ty: TypeIdentity,
+    offset: Option<usize>,
+    layout: Option<Layout>,
+    attributes: CustomAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl UnnamedField {
-    pub fn new<T: Reflect>(index: usize) -> Self {
+    pub const fn new<T: ?Sized + 'static>(index: usize) -> Self {
         Self {
             index,
-            type_name: std::any::type_name::<T>(),
-            type_id: TypeId::of::<T>(),
+            ty: TypeIdentity::of::<T>(),
+            offset: None,
+            layout: None,
+            attributes: CustomAttributes::EMPTY,
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the offset in bytes of this field from the start of its container.
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the [`Layout`] of this field's type.
+    pub const fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Sets the [custom attributes] of this field.
+    ///
+    /// [custom attributes]: CustomAttributes
+    pub const fn with_attributes(mut self, attributes: CustomAttributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
     }
 
     /// Returns the index of the field.
-    pub fn index(&self) -> usize {
+    pub const fn index(&self) -> usize {
         self.index
     }
 
@@ -94,17 +223,61 @@ impl UnnamedField {
     ///
     /// [type name]: std::any::type_name
     pub fn type_name(&self) -> &'static str {
-        self.type_name
+        self.ty.type_name()
     }
 
     /// The [`TypeId`] of the field.
     pub fn type_id(&self) -> TypeId {
-        self.type_id
+        self.ty.type_id()
     }
 
     /// Check if the given type matches the field type.
     pub fn is<T: Any>(&self) -> bool {
-        TypeId::of::<T>() == self.type_id
+        TypeId::of::<T>() == self.ty.type_id()
+    }
+
+    /// The offset in bytes of this field from the start of its container, if known.
+    ///
+    /// See [`NamedField::offset`] for when it is set.
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// The [`Layout`] of this field's type, if known.
+    pub const fn layout(&self) -> Option<Layout> {
+        self.layout
+    }
+
+    /// The [custom attributes] of this field, added with `#[reflect(@...)]`.
+    ///
+    /// [custom attributes]: CustomAttributes
+    pub const fn attributes(&self) -> &CustomAttributes {
+        &self.attributes
+    }
+
+    /// The [`Range`] attribute of this field, if it has one.
+    pub fn range(&self) -> Option<&'static Range> {
+        self.attributes.get()
+    }
+
+    /// The [`Step`] attribute of this field, if it has one.
+    pub fn step(&self) -> Option<f64> {
+        self.attributes.get::<Step>().map(|step| step.0)
+    }
+
+    /// The text of the [`Tooltip`] attribute of this field, if it has one.
+    pub fn tooltip(&self) -> Option<&'static str> {
+        self.attributes.get::<Tooltip>().map(|tooltip| tooltip.0)
+    }
+
+    /// The name of the [`Category`] attribute of this field, if it has one.
+    pub fn category(&self) -> Option<&'static str> {
+        self.attributes.get::<Category>().map(|category| category.0)
+    }
+
+    /// Returns `true` if this field has the [`Multiline`] attribute.
+    pub fn is_multiline(&self) -> bool {
+        self.attributes.contains::<Multiline>()
     }
 
     /// The docstring of this field, if any.
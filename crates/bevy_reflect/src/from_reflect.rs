@@ -1,4 +1,5 @@
 use crate::{FromType, Reflect};
+use alloc::boxed::Box;
 
 /// A trait for types which can be constructed from a reflected type.
 ///
@@ -0,0 +1,320 @@
+use crate::{Reflect, ReflectMut, ReflectRef};
+use alloc::collections::VecDeque;
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+
+/// An undo/redo history of the states of a reflected value.
+///
+/// Each state is recorded as a snapshot made with [`Reflect::clone_value`],
+/// and undoing or redoing restores the value to the recorded state exactly,
+/// removing any list elements or map entries which were added since.
+///
+/// To bound the memory used, only the latest [`max_len`](Self::with_max_len) states are kept,
+/// and consecutive edits belonging to the same group (such as the steps of dragging a slider)
+/// can be coalesced into one with [`record_coalesced`](Self::record_coalesced).
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectHistory};
+/// #[derive(Reflect)]
+/// struct Brush {
+///     size: f32,
+///     layers: Vec<String>,
+/// }
+///
+/// let mut brush = Brush { size: 1.0, layers: vec![] };
+/// let mut history = ReflectHistory::new(&brush);
+///
+/// for size in [1.5, 2.0, 2.5] {
+///     brush.size = size;
+///     history.record_coalesced(&brush, "size");
+/// }
+/// brush.layers.push(String::from("ink"));
+/// history.record(&brush);
+///
+/// assert!(history.undo(&mut brush));
+/// assert!(brush.layers.is_empty());
+/// assert!(history.undo(&mut brush));
+/// assert_eq!(1.0, brush.size);
+/// assert!(!history.undo(&mut brush));
+///
+/// assert!(history.redo(&mut brush));
+/// assert_eq!(2.5, brush.size);
+/// ```
+pub struct ReflectHistory {
+    states: VecDeque<HistoryState>,
+    /// The index of the state the value is currently in.
+    current: usize,
+    max_len: usize,
+}
+
+struct HistoryState {
+    snapshot: Box<dyn Reflect>,
+    group: Option<Cow<'static, str>>,
+}
+
+impl ReflectHistory {
+    /// The number of states kept by default.
+    pub const DEFAULT_MAX_LEN: usize = 100;
+
+    /// Creates a history whose first state is the given value.
+    pub fn new(value: &dyn Reflect) -> Self {
+        Self {
+            states: VecDeque::from([HistoryState {
+                snapshot: value.clone_value(),
+                group: None,
+            }]),
+            current: 0,
+            max_len: Self::DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Sets the number of states to keep, including the current one.
+    ///
+    /// Once there are more states than this, the oldest are forgotten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is `0`.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        assert!(max_len > 0, "a history must keep at least one state");
+        self.max_len = max_len;
+        self.trim();
+        self
+    }
+
+    /// Records the given value as a new state, after the current one.
+    ///
+    /// Any states which were undone are forgotten, so they can no longer be redone.
+    /// Nothing is recorded if the value is equal to the current state,
+    /// as reported by [`Reflect::reflect_partial_eq`].
+    pub fn record(&mut self, value: &dyn Reflect) {
+        self.push(value, None);
+    }
+
+    /// Records the given value as a new state, or replaces the current state with it
+    /// if the current state was also recorded with the same `group`.
+    ///
+    /// This merges a series of small edits into a single step of the history.
+    /// The current state only ever belongs to a group while it is the latest state,
+    /// so undoing and then recording starts a new step.
+    pub fn record_coalesced(&mut self, value: &dyn Reflect, group: impl Into<Cow<'static, str>>) {
+        let group = group.into();
+        let latest = self.current + 1 == self.states.len();
+        let state = &mut self.states[self.current];
+        if latest && state.group.as_ref() == Some(&group) {
+            state.snapshot = value.clone_value();
+        } else {
+            self.push(value, Some(group));
+        }
+    }
+
+    /// Restores the given value to the state before the current one.
+    ///
+    /// Returns `false`, leaving the value untouched, if there is no earlier state.
+    pub fn undo(&mut self, value: &mut dyn Reflect) -> bool {
+        if !self.can_undo() {
+            return false;
+        }
+        self.current -= 1;
+        self.restore(value);
+        true
+    }
+
+    /// Restores the given value to the state after the current one, which was undone.
+    ///
+    /// Returns `false`, leaving the value untouched, if there is no undone state.
+    pub fn redo(&mut self, value: &mut dyn Reflect) -> bool {
+        if !self.can_redo() {
+            return false;
+        }
+        self.current += 1;
+        self.restore(value);
+        true
+    }
+
+    /// Returns true if there is a state before the current one.
+    pub fn can_undo(&self) -> bool {
+        self.current > 0
+    }
+
+    /// Returns true if there is an undone state after the current one.
+    pub fn can_redo(&self) -> bool {
+        self.current + 1 < self.states.len()
+    }
+
+    /// Returns the snapshot of the current state.
+    pub fn current(&self) -> &dyn Reflect {
+        &*self.states[self.current].snapshot
+    }
+
+    /// Returns the number of states kept, including the current one.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Always returns `false`, as a history keeps at least its current state.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    fn push(&mut self, value: &dyn Reflect, group: Option<Cow<'static, str>>) {
+        if self.current().reflect_partial_eq(value) == Some(true) {
+            return;
+        }
+        self.states.truncate(self.current + 1);
+        self.states.push_back(HistoryState {
+            snapshot: value.clone_value(),
+            group,
+        });
+        self.current += 1;
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        while self.states.len() > self.max_len {
+            self.states.pop_front();
+            self.current = self.current.saturating_sub(1);
+        }
+    }
+
+    fn restore(&self, value: &mut dyn Reflect) {
+        let snapshot = self.current();
+        value.apply(snapshot);
+        remove_excess(value, snapshot);
+    }
+}
+
+/// Removes the list elements and map entries of `value` which are not in `snapshot`,
+/// which [`Reflect::apply`] leaves in place.
+fn remove_excess(value: &mut dyn Reflect, snapshot: &dyn Reflect) {
+    match (value.reflect_mut(), snapshot.reflect_ref()) {
+        (ReflectMut::Struct(value), ReflectRef::Struct(snapshot)) => {
+            for (i, snapshot_field) in snapshot.iter_fields().enumerate() {
+                if let Some(field) = value.field_mut(snapshot.name_at(i).unwrap()) {
+                    remove_excess(field, snapshot_field);
+                }
+            }
+        }
+        (ReflectMut::TupleStruct(value), ReflectRef::TupleStruct(snapshot)) => {
+            for (i, snapshot_field) in snapshot.iter_fields().enumerate() {
+                if let Some(field) = value.field_mut(i) {
+                    remove_excess(field, snapshot_field);
+                }
+            }
+        }
+        (ReflectMut::Tuple(value), ReflectRef::Tuple(snapshot)) => {
+            for (i, snapshot_field) in snapshot.iter_fields().enumerate() {
+                if let Some(field) = value.field_mut(i) {
+                    remove_excess(field, snapshot_field);
+                }
+            }
+        }
+        (ReflectMut::List(value), ReflectRef::List(snapshot)) => {
+            while value.len() > snapshot.len() {
+                value.pop();
+            }
+            for (i, snapshot_item) in snapshot.iter().enumerate() {
+                if let Some(item) = value.get_mut(i) {
+                    remove_excess(item, snapshot_item);
+                }
+            }
+        }
+        (ReflectMut::Array(value), ReflectRef::Array(snapshot)) => {
+            for (i, snapshot_item) in snapshot.iter().enumerate() {
+                if let Some(item) = value.get_mut(i) {
+                    remove_excess(item, snapshot_item);
+                }
+            }
+        }
+        (ReflectMut::Map(value), ReflectRef::Map(snapshot)) => {
+            let excess = value
+                .iter()
+                .filter(|(key, _)| snapshot.get(*key).is_none())
+                .map(|(key, _)| key.clone_value())
+                .collect::<Vec<_>>();
+            for key in excess {
+                value.remove(&*key);
+            }
+            for (key, snapshot_value) in snapshot.iter() {
+                if let Some(entry) = value.get_mut(key) {
+                    remove_excess(entry, snapshot_value);
+                }
+            }
+        }
+        (ReflectMut::Enum(value), ReflectRef::Enum(snapshot))
+            if value.variant_name() == snapshot.variant_name() =>
+        {
+            for (i, snapshot_field) in snapshot.iter_fields().enumerate() {
+                let field = match snapshot_field.name() {
+                    Some(name) => value.field_mut(name),
+                    None => value.field_at_mut(i),
+                };
+                if let Some(field) = field {
+                    remove_excess(field, snapshot_field.value());
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReflectHistory;
+    use crate as bevy_reflect;
+    use crate::Reflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Document {
+        title: String,
+        tags: HashMap<String, Vec<u32>>,
+    }
+
+    #[test]
+    fn should_undo_and_redo() {
+        let mut document = Document {
+            title: String::from("draft"),
+            tags: HashMap::default(),
+        };
+        let original = document.clone();
+        let mut history = ReflectHistory::new(&document).with_max_len(3);
+
+        document.tags.insert(String::from("a"), vec![1, 2]);
+        history.record(&document);
+        let tagged = document.clone();
+        history.record(&document);
+        assert_eq!(2, history.len());
+
+        document.tags.get_mut("a").unwrap().pop();
+        document.tags.insert(String::from("b"), vec![3]);
+        history.record(&document);
+        let edited = document.clone();
+
+        assert!(history.undo(&mut document));
+        assert_eq!(tagged, document);
+        assert!(history.undo(&mut document));
+        assert_eq!(original, document);
+        assert!(!history.can_undo());
+        assert!(history.redo(&mut document));
+        assert!(history.redo(&mut document));
+        assert_eq!(edited, document);
+        assert!(!history.redo(&mut document));
+
+        document.title = String::from("final");
+        history.record(&document);
+        assert_eq!(3, history.len());
+        assert!(history.undo(&mut document));
+        assert!(history.undo(&mut document));
+        assert_eq!(tagged, document);
+        assert!(!history.undo(&mut document));
+
+        document.title = String::from("renamed");
+        history.record_coalesced(&document, "title");
+        assert!(!history.can_redo());
+        document.title = String::from("renamed again");
+        history.record_coalesced(&document, "title");
+        assert!(history.undo(&mut document));
+        assert_eq!(tagged, document);
+    }
+}
@@ -1,18 +1,19 @@
 use crate as bevy_reflect;
 use crate::prelude::ReflectDefault;
+use crate::std_traits::{ReflectAdd, ReflectMul, ReflectNeg};
 use crate::{ReflectDeserialize, ReflectSerialize};
-use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_struct, impl_reflect_value};
+use bevy_reflect_derive::{impl_reflect_enum, impl_reflect_struct, impl_reflect_value};
 use glam::*;
 
 impl_reflect_struct!(
-    #[reflect(Debug, Hash, PartialEq, Default)]
+    #[reflect(Debug, Hash, PartialEq, Default, Add, Mul, Neg)]
     struct IVec2 {
         x: i32,
         y: i32,
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, Hash, PartialEq, Default)]
+    #[reflect(Debug, Hash, PartialEq, Default, Add, Mul, Neg)]
     struct IVec3 {
         x: i32,
         y: i32,
@@ -20,7 +21,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, Hash, PartialEq, Default)]
+    #[reflect(Debug, Hash, PartialEq, Default, Add, Mul, Neg)]
     struct IVec4 {
         x: i32,
         y: i32,
@@ -30,14 +31,14 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
-    #[reflect(Debug, Hash, PartialEq, Default)]
+    #[reflect(Debug, Hash, PartialEq, Default, Add, Mul)]
     struct UVec2 {
         x: u32,
         y: u32,
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, Hash, PartialEq, Default)]
+    #[reflect(Debug, Hash, PartialEq, Default, Add, Mul)]
     struct UVec3 {
         x: u32,
         y: u32,
@@ -45,7 +46,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, Hash, PartialEq, Default)]
+    #[reflect(Debug, Hash, PartialEq, Default, Add, Mul)]
     struct UVec4 {
         x: u32,
         y: u32,
@@ -55,14 +56,14 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Vec2 {
         x: f32,
         y: f32,
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Vec3 {
         x: f32,
         y: f32,
@@ -70,7 +71,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Vec3A {
         x: f32,
         y: f32,
@@ -78,7 +79,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Vec4 {
         x: f32,
         y: f32,
@@ -113,14 +114,14 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct DVec2 {
         x: f64,
         y: f64,
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct DVec3 {
         x: f64,
         y: f64,
@@ -128,7 +129,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct DVec4 {
         x: f64,
         y: f64,
@@ -138,14 +139,14 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Mat2 {
         x_axis: Vec2,
         y_axis: Vec2,
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Mat3 {
         x_axis: Vec3,
         y_axis: Vec3,
@@ -153,7 +154,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Mat3A {
         x_axis: Vec3A,
         y_axis: Vec3A,
@@ -161,7 +162,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct Mat4 {
         x_axis: Vec4,
         y_axis: Vec4,
@@ -171,14 +172,14 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct DMat2 {
         x_axis: DVec2,
         y_axis: DVec2,
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct DMat3 {
         x_axis: DVec3,
         y_axis: DVec3,
@@ -186,7 +187,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
     struct DMat4 {
         x_axis: DVec4,
         y_axis: DVec4,
@@ -225,16 +226,38 @@ impl_reflect_struct!(
     }
 );
 
-// Quat fields are read-only (as of now), and reflection is currently missing
-// mechanisms for read-only fields. I doubt those mechanisms would be added,
-// so for now quaternions will remain as values. They are represented identically
-// to Vec4 and DVec4, so you may use those instead and convert between.
-impl_reflect_value!(Quat(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(DQuat(Debug, PartialEq, Serialize, Deserialize, Default));
+// Quaternions don't have public fields, but deref to a struct with `x`, `y`, `z` and `w` fields,
+// which is what these go through. Editing a component doesn't renormalize the quaternion.
+impl_reflect_struct!(
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
+    struct Quat {
+        x: f32,
+        y: f32,
+        z: f32,
+        w: f32,
+    }
+);
+impl_reflect_struct!(
+    #[reflect(Debug, PartialEq, Default, Add, Mul, Neg)]
+    struct DQuat {
+        x: f64,
+        y: f64,
+        z: f64,
+        w: f64,
+    }
+);
 
-impl_from_reflect_value!(Quat);
-impl_from_reflect_value!(DQuat);
+impl_reflect_enum!(
+    #[reflect(Debug, Hash, PartialEq, Serialize, Deserialize, Default)]
+    enum EulerRot {
+        ZYX,
+        ZXY,
+        YXZ,
+        YZX,
+        XYZ,
+        XZY,
+    }
+);
 
-impl_reflect_value!(EulerRot(Debug, Default));
 impl_reflect_value!(BVec3A(Debug, Default));
 impl_reflect_value!(BVec4A(Debug, Default));
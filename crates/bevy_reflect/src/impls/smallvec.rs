@@ -1,10 +1,13 @@
+use core::any::Any;
+use core::cmp::Ordering;
 use smallvec::SmallVec;
-use std::any::Any;
 
+use crate::array::{check_index, store_element};
 use crate::utility::GenericTypeInfoCell;
 use crate::{
-    Array, ArrayIter, FromReflect, FromType, GetTypeRegistration, List, ListInfo, Reflect,
-    ReflectFromPtr, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypeRegistration, Typed,
+    Array, ArrayError, ArrayIter, FromReflect, FromType, GetTypeRegistration, List, ListInfo,
+    Reflect, ReflectFromPtr, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypeRegistration,
+    Typed,
 };
 
 impl<T: smallvec::Array + Send + Sync + 'static> Array for SmallVec<T>
@@ -43,6 +46,18 @@ where
             .map(|value| Box::new(value) as Box<dyn Reflect>)
             .collect()
     }
+
+    fn set_element(&mut self, index: usize, value: Box<dyn Reflect>) -> Result<(), ArrayError> {
+        let len = SmallVec::len(self);
+        store_element(self.as_mut_slice().get_mut(index), index, len, value)
+    }
+
+    fn swap_elements(&mut self, a: usize, b: usize) -> Result<(), ArrayError> {
+        check_index(a, SmallVec::len(self))?;
+        check_index(b, SmallVec::len(self))?;
+        self.as_mut_slice().swap(a, b);
+        Ok(())
+    }
 }
 
 impl<T: smallvec::Array + Send + Sync + 'static> List for SmallVec<T>
@@ -80,6 +95,25 @@ where
     fn pop(&mut self) -> Option<Box<dyn Reflect>> {
         self.pop().map(|value| Box::new(value) as Box<dyn Reflect>)
     }
+
+    fn reserve(&mut self, additional: usize) {
+        SmallVec::reserve(self, additional);
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        SmallVec::retain(self, |value| keep(value));
+    }
+
+    fn extend_boxed(&mut self, values: Vec<Box<dyn Reflect>>) {
+        SmallVec::reserve(self, values.len());
+        for value in values {
+            List::push(self, value);
+        }
+    }
+
+    fn sort_by_reflect(&mut self, compare: &mut dyn FnMut(&dyn Reflect, &dyn Reflect) -> Ordering) {
+        self.as_mut_slice().sort_by(|a, b| compare(a, b));
+    }
 }
 
 impl<T: smallvec::Array + Send + Sync + 'static> Reflect for SmallVec<T>
@@ -87,7 +121,7 @@ where
     T::Item: FromReflect,
 {
     fn type_name(&self) -> &str {
-        std::any::type_name::<Self>()
+        core::any::type_name::<Self>()
     }
 
     fn get_type_info(&self) -> &'static TypeInfo {
@@ -146,6 +180,10 @@ where
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         crate::list_partial_eq(self, value)
     }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<core::cmp::Ordering> {
+        crate::list_partial_cmp(self, value)
+    }
 }
 
 impl<T: smallvec::Array + Send + Sync + 'static> Typed for SmallVec<T>
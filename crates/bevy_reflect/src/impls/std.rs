@@ -1,28 +1,41 @@
-use crate::std_traits::ReflectDefault;
+use crate::allocation_stats::{record_allocations, AllocationSource};
+use crate::array::{check_index, store_element};
+use crate::std_traits::{ReflectAdd, ReflectDefault, ReflectMul, ReflectNeg};
 use crate::{self as bevy_reflect, ReflectFromPtr, ReflectOwned};
 use crate::{
-    map_apply, map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum, DynamicMap, Enum,
-    EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo, MapIter,
-    Reflect, ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize, TupleVariantInfo,
-    TypeInfo, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter,
-    VariantInfo, VariantType,
+    map_apply, map_partial_eq, Array, ArrayError, ArrayInfo, ArrayIter, DynamicEnum, DynamicMap,
+    Enum, EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo,
+    MapIter, Reflect, ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize,
+    TupleVariantInfo, TypeInfo, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo,
+    VariantFieldIter, VariantInfo, VariantType,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
 };
 
 use crate::utility::{GenericTypeInfoCell, NonGenericTypeInfoCell};
+use alloc::{borrow::Cow, collections::VecDeque, sync::Arc};
 use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_value};
-use bevy_utils::{Duration, Instant};
+#[cfg(feature = "std")]
+use bevy_utils::Instant;
 use bevy_utils::{HashMap, HashSet};
-use std::{
-    any::Any,
-    borrow::Cow,
-    collections::VecDeque,
-    ffi::OsString,
+use core::time::Duration;
+use core::{
+    any::{Any, TypeId},
+    cmp::Ordering,
     hash::{Hash, Hasher},
     num::{
         NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
         NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
     },
     ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+#[cfg(feature = "std")]
+use std::{
+    ffi::OsString,
     path::{Path, PathBuf},
 };
 
@@ -30,6 +43,7 @@ impl_reflect_value!(bool(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
@@ -38,64 +52,186 @@ impl_reflect_value!(char(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
 ));
-impl_reflect_value!(u8(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u16(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u32(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u64(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
+impl_reflect_value!(u8(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul
+));
+impl_reflect_value!(u16(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul
+));
+impl_reflect_value!(u32(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul
+));
+impl_reflect_value!(u64(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul
+));
 impl_reflect_value!(u128(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Add,
+    Mul
 ));
 impl_reflect_value!(usize(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Add,
+    Mul
+));
+impl_reflect_value!(i8(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul,
+    Neg
+));
+impl_reflect_value!(i16(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul,
+    Neg
+));
+impl_reflect_value!(i32(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul,
+    Neg
+));
+impl_reflect_value!(i64(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul,
+    Neg
 ));
-impl_reflect_value!(i8(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i16(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i32(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i64(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
 impl_reflect_value!(i128(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Add,
+    Mul,
+    Neg
 ));
 impl_reflect_value!(isize(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Add,
+    Mul,
+    Neg
+));
+impl_reflect_value!(f32(
+    Debug,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul,
+    Neg
+));
+impl_reflect_value!(f64(
+    Debug,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Add,
+    Mul,
+    Neg
 ));
-impl_reflect_value!(f32(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(f64(Debug, PartialEq, Serialize, Deserialize, Default));
 impl_reflect_value!(String(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
 ));
+#[cfg(feature = "std")]
 impl_reflect_value!(PathBuf(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
@@ -112,30 +248,124 @@ impl_reflect_value!(Duration(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Add
+));
+#[cfg(feature = "std")]
+impl_reflect_value!(Instant(Debug, Hash, PartialEq, PartialOrd));
+impl_reflect_value!(NonZeroI128(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroU128(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroIsize(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroUsize(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroI64(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroU64(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroU32(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroI32(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroI16(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroU16(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroU8(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(NonZeroI8(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
 ));
-impl_reflect_value!(Instant(Debug, Hash, PartialEq));
-impl_reflect_value!(NonZeroI128(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU128(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroIsize(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroUsize(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI64(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU64(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU32(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI32(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI16(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU16(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU8(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI8(Debug, Hash, PartialEq, Serialize, Deserialize));
 
 // `Serialize` and `Deserialize` only for platforms supported by serde:
 // https://github.com/serde-rs/serde/blob/3ffb86fc70efd3d329519e2dddfa306cc04f167c/serde/src/de/impls.rs#L1732
-#[cfg(any(unix, windows))]
-impl_reflect_value!(OsString(Debug, Hash, PartialEq, Serialize, Deserialize));
-#[cfg(not(any(unix, windows)))]
-impl_reflect_value!(OsString(Debug, Hash, PartialEq));
+#[cfg(all(feature = "std", any(unix, windows)))]
+impl_reflect_value!(OsString(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize
+));
+#[cfg(all(feature = "std", not(any(unix, windows))))]
+impl_reflect_value!(OsString(Debug, Hash, PartialEq, PartialOrd));
 
 impl_from_reflect_value!(bool);
 impl_from_reflect_value!(char);
@@ -154,7 +384,9 @@ impl_from_reflect_value!(isize);
 impl_from_reflect_value!(f32);
 impl_from_reflect_value!(f64);
 impl_from_reflect_value!(String);
+#[cfg(feature = "std")]
 impl_from_reflect_value!(PathBuf);
+#[cfg(feature = "std")]
 impl_from_reflect_value!(OsString);
 impl_from_reflect_value!(HashSet<T: Hash + Eq + Clone + Send + Sync + 'static>);
 impl_from_reflect_value!(Range<T: Clone + Send + Sync + 'static>);
@@ -164,6 +396,7 @@ impl_from_reflect_value!(RangeTo<T: Clone + Send + Sync + 'static>);
 impl_from_reflect_value!(RangeToInclusive<T: Clone + Send + Sync + 'static>);
 impl_from_reflect_value!(RangeFull);
 impl_from_reflect_value!(Duration);
+#[cfg(feature = "std")]
 impl_from_reflect_value!(Instant);
 impl_from_reflect_value!(NonZeroI128);
 impl_from_reflect_value!(NonZeroU128);
@@ -179,7 +412,7 @@ impl_from_reflect_value!(NonZeroU8);
 impl_from_reflect_value!(NonZeroI8);
 
 macro_rules! impl_reflect_for_veclike {
-    ($ty:ty, $insert:expr, $remove:expr, $push:expr, $pop:expr, $sub:ty) => {
+    ($ty:ty, $insert:expr, $remove:expr, $push:expr, $pop:expr, $as_slice:expr, $sub:ty) => {
         impl<T: FromReflect> Array for $ty {
             #[inline]
             fn get(&self, index: usize) -> Option<&dyn Reflect> {
@@ -210,6 +443,22 @@ macro_rules! impl_reflect_for_veclike {
                     .map(|value| Box::new(value) as Box<dyn Reflect>)
                     .collect()
             }
+
+            fn set_element(
+                &mut self,
+                index: usize,
+                value: Box<dyn Reflect>,
+            ) -> Result<(), ArrayError> {
+                let len = <$sub>::len(self);
+                store_element(<$sub>::get_mut(self, index), index, len, value)
+            }
+
+            fn swap_elements(&mut self, a: usize, b: usize) -> Result<(), ArrayError> {
+                check_index(a, <$sub>::len(self))?;
+                check_index(b, <$sub>::len(self))?;
+                <$sub>::swap(self, a, b);
+                Ok(())
+            }
         }
 
         impl<T: FromReflect> List for $ty {
@@ -242,11 +491,33 @@ macro_rules! impl_reflect_for_veclike {
             fn pop(&mut self) -> Option<Box<dyn Reflect>> {
                 $pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
             }
+
+            fn reserve(&mut self, additional: usize) {
+                <$ty>::reserve(self, additional);
+            }
+
+            fn retain(&mut self, keep: &mut dyn FnMut(&dyn Reflect) -> bool) {
+                <$ty>::retain(self, |value| keep(value));
+            }
+
+            fn extend_boxed(&mut self, values: Vec<Box<dyn Reflect>>) {
+                <$ty>::reserve(self, values.len());
+                for value in values {
+                    List::push(self, value);
+                }
+            }
+
+            fn sort_by_reflect(
+                &mut self,
+                compare: &mut dyn FnMut(&dyn Reflect, &dyn Reflect) -> Ordering,
+            ) {
+                $as_slice(self).sort_by(|a, b| compare(a, b));
+            }
         }
 
         impl<T: FromReflect> Reflect for $ty {
             fn type_name(&self) -> &str {
-                std::any::type_name::<Self>()
+                core::any::type_name::<Self>()
             }
 
             fn get_type_info(&self) -> &'static TypeInfo {
@@ -309,6 +580,10 @@ macro_rules! impl_reflect_for_veclike {
             fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
                 crate::list_partial_eq(self, value)
             }
+
+            fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+                crate::list_partial_cmp(self, value)
+            }
         }
 
         impl<T: FromReflect> Typed for $ty {
@@ -342,13 +617,22 @@ macro_rules! impl_reflect_for_veclike {
     };
 }
 
-impl_reflect_for_veclike!(Vec<T>, Vec::insert, Vec::remove, Vec::push, Vec::pop, [T]);
+impl_reflect_for_veclike!(
+    Vec<T>,
+    Vec::insert,
+    Vec::remove,
+    Vec::push,
+    Vec::pop,
+    Vec::as_mut_slice,
+    [T]
+);
 impl_reflect_for_veclike!(
     VecDeque<T>,
     VecDeque::insert,
     VecDeque::remove,
     VecDeque::push_back,
     VecDeque::pop_back,
+    VecDeque::make_contiguous,
     VecDeque::<T>
 );
 
@@ -394,6 +678,7 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
     }
 
     fn clone_dynamic(&self) -> DynamicMap {
+        record_allocations(AllocationSource::CloneDynamic, self.len() * 2);
         let mut dynamic_map = DynamicMap::default();
         dynamic_map.set_name(self.type_name().to_string());
         for (k, v) in self {
@@ -433,11 +718,40 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
             .and_then(|key| self.remove(key))
             .map(|value| Box::new(value) as Box<dyn Reflect>)
     }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(&dyn Reflect, &dyn Reflect) -> bool) {
+        HashMap::retain(self, |key, value| keep(key, value));
+    }
+
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+            panic!(
+                "Attempted to insert invalid key of type {}.",
+                key.type_name()
+            )
+        });
+        self.entry(key).or_insert_with(|| {
+            V::take_from_reflect(default()).unwrap_or_else(|value| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    value.type_name()
+                )
+            })
+        })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        HashMap::reserve(self, additional);
+    }
 }
 
 impl<K: FromReflect + Eq + Hash, V: FromReflect> Reflect for HashMap<K, V> {
     fn type_name(&self) -> &str {
-        std::any::type_name::<Self>()
+        core::any::type_name::<Self>()
     }
 
     fn get_type_info(&self) -> &'static TypeInfo {
@@ -564,12 +878,31 @@ impl<T: Reflect, const N: usize> Array for [T; N] {
             .map(|value| Box::new(value) as Box<dyn Reflect>)
             .collect()
     }
+
+    fn set_element(&mut self, index: usize, value: Box<dyn Reflect>) -> Result<(), ArrayError> {
+        let element =
+            <[T]>::get_mut(self, index).ok_or(ArrayError::OutOfBounds { index, len: N })?;
+        *element = value
+            .take::<T>()
+            .map_err(|value| ArrayError::MismatchedTypes {
+                from_type: value.type_name().to_string(),
+                to_type: core::any::type_name::<T>().to_string(),
+            })?;
+        Ok(())
+    }
+
+    fn swap_elements(&mut self, a: usize, b: usize) -> Result<(), ArrayError> {
+        check_index(a, N)?;
+        check_index(b, N)?;
+        <[T]>::swap(self, a, b);
+        Ok(())
+    }
 }
 
 impl<T: Reflect, const N: usize> Reflect for [T; N] {
     #[inline]
     fn type_name(&self) -> &str {
-        std::any::type_name::<Self>()
+        core::any::type_name::<Self>()
     }
 
     fn get_type_info(&self) -> &'static TypeInfo {
@@ -646,6 +979,11 @@ impl<T: Reflect, const N: usize> Reflect for [T; N] {
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         crate::array_partial_eq(self, value)
     }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        crate::array_partial_cmp(self, value)
+    }
 }
 
 impl<T: FromReflect, const N: usize> FromReflect for [T; N] {
@@ -765,6 +1103,13 @@ impl<T: FromReflect> Enum for Option<T> {
         }
     }
 
+    fn into_variant_fields(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        match *self {
+            Some(value) => vec![Box::new(value)],
+            None => Vec::new(),
+        }
+    }
+
     fn clone_dynamic(&self) -> DynamicEnum {
         DynamicEnum::from_ref::<Self>(self)
     }
@@ -773,7 +1118,7 @@ impl<T: FromReflect> Enum for Option<T> {
 impl<T: FromReflect> Reflect for Option<T> {
     #[inline]
     fn type_name(&self) -> &str {
-        std::any::type_name::<Self>()
+        core::any::type_name::<Self>()
     }
 
     #[inline]
@@ -823,22 +1168,17 @@ impl<T: FromReflect> Reflect for Option<T> {
                 // New variant -> perform a switch
                 match value.variant_name() {
                     "Some" => {
-                        let field = T::take_from_reflect(
-                            value
-                                .field_at(0)
-                                .unwrap_or_else(|| {
-                                    panic!(
-                                        "Field in `Some` variant of {} should exist",
-                                        std::any::type_name::<Option<T>>()
-                                    )
-                                })
-                                .clone_value(),
-                        )
-                        .unwrap_or_else(|_| {
+                        let field = T::from_reflect(value.field_at(0).unwrap_or_else(|| {
+                            panic!(
+                                "Field in `Some` variant of {} should exist",
+                                core::any::type_name::<Option<T>>()
+                            )
+                        }))
+                        .unwrap_or_else(|| {
                             panic!(
                                 "Field in `Some` variant of {} should be of type {}",
-                                std::any::type_name::<Option<T>>(),
-                                std::any::type_name::<T>()
+                                core::any::type_name::<Option<T>>(),
+                                core::any::type_name::<T>()
                             )
                         });
                         *self = Some(field);
@@ -846,7 +1186,7 @@ impl<T: FromReflect> Reflect for Option<T> {
                     "None" => {
                         *self = None;
                     }
-                    _ => panic!("Enum is not a {}.", std::any::type_name::<Self>()),
+                    _ => panic!("Enum is not a {}.", core::any::type_name::<Self>()),
                 }
             }
         }
@@ -882,6 +1222,10 @@ impl<T: FromReflect> Reflect for Option<T> {
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         crate::enum_partial_eq(self, value)
     }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        crate::enum_partial_cmp(self, value)
+    }
 }
 
 impl<T: FromReflect> FromReflect for Option<T> {
@@ -889,22 +1233,17 @@ impl<T: FromReflect> FromReflect for Option<T> {
         if let ReflectRef::Enum(dyn_enum) = reflect.reflect_ref() {
             match dyn_enum.variant_name() {
                 "Some" => {
-                    let field = T::take_from_reflect(
-                        dyn_enum
-                            .field_at(0)
-                            .unwrap_or_else(|| {
-                                panic!(
-                                    "Field in `Some` variant of {} should exist",
-                                    std::any::type_name::<Option<T>>()
-                                )
-                            })
-                            .clone_value(),
-                    )
-                    .unwrap_or_else(|_| {
+                    let field = T::from_reflect(dyn_enum.field_at(0).unwrap_or_else(|| {
+                        panic!(
+                            "Field in `Some` variant of {} should exist",
+                            core::any::type_name::<Option<T>>()
+                        )
+                    }))
+                    .unwrap_or_else(|| {
                         panic!(
                             "Field in `Some` variant of {} should be of type {}",
-                            std::any::type_name::<Option<T>>(),
-                            std::any::type_name::<T>()
+                            core::any::type_name::<Option<T>>(),
+                            core::any::type_name::<T>()
                         )
                     });
                     Some(Some(field))
@@ -913,7 +1252,7 @@ impl<T: FromReflect> FromReflect for Option<T> {
                 name => panic!(
                     "variant with name `{}` does not exist on enum `{}`",
                     name,
-                    std::any::type_name::<Self>()
+                    core::any::type_name::<Self>()
                 ),
             }
         } else {
@@ -939,7 +1278,7 @@ impl<T: FromReflect> Typed for Option<T> {
 
 impl Reflect for Cow<'static, str> {
     fn type_name(&self) -> &str {
-        std::any::type_name::<Self>()
+        core::any::type_name::<Self>()
     }
 
     fn get_type_info(&self) -> &'static TypeInfo {
@@ -975,7 +1314,7 @@ impl Reflect for Cow<'static, str> {
         if let Some(value) = value.downcast_ref::<Self>() {
             *self = value.clone();
         } else {
-            panic!("Value is not a {}.", std::any::type_name::<Self>());
+            panic!("Value is not a {}.", core::any::type_name::<Self>());
         }
     }
 
@@ -1002,7 +1341,7 @@ impl Reflect for Cow<'static, str> {
 
     fn reflect_hash(&self) -> Option<u64> {
         let mut hasher = crate::ReflectHasher::default();
-        Hash::hash(&std::any::Any::type_id(self), &mut hasher);
+        Hash::hash(&core::any::Any::type_id(self), &mut hasher);
         Hash::hash(self, &mut hasher);
         Some(hasher.finish())
     }
@@ -1010,11 +1349,20 @@ impl Reflect for Cow<'static, str> {
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         let value = value.as_any();
         if let Some(value) = value.downcast_ref::<Self>() {
-            Some(std::cmp::PartialEq::eq(self, value))
+            Some(core::cmp::PartialEq::eq(self, value))
         } else {
             Some(false)
         }
     }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        let value = value.as_any();
+        if let Some(value) = value.downcast_ref::<Self>() {
+            core::cmp::PartialOrd::partial_cmp(self, value)
+        } else {
+            None
+        }
+    }
 }
 
 impl Typed for Cow<'static, str> {
@@ -1045,9 +1393,10 @@ impl FromReflect for Cow<'static, str> {
     }
 }
 
+#[cfg(feature = "std")]
 impl Reflect for &'static Path {
     fn type_name(&self) -> &str {
-        std::any::type_name::<Self>()
+        core::any::type_name::<Self>()
     }
 
     fn get_type_info(&self) -> &'static TypeInfo {
@@ -1083,7 +1432,7 @@ impl Reflect for &'static Path {
         if let Some(&value) = value.downcast_ref::<Self>() {
             *self = value;
         } else {
-            panic!("Value is not a {}.", std::any::type_name::<Self>());
+            panic!("Value is not a {}.", core::any::type_name::<Self>());
         }
     }
 
@@ -1110,7 +1459,7 @@ impl Reflect for &'static Path {
 
     fn reflect_hash(&self) -> Option<u64> {
         let mut hasher = crate::ReflectHasher::default();
-        Hash::hash(&std::any::Any::type_id(self), &mut hasher);
+        Hash::hash(&core::any::Any::type_id(self), &mut hasher);
         Hash::hash(self, &mut hasher);
         Some(hasher.finish())
     }
@@ -1118,13 +1467,23 @@ impl Reflect for &'static Path {
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         let value = value.as_any();
         if let Some(value) = value.downcast_ref::<Self>() {
-            Some(std::cmp::PartialEq::eq(self, value))
+            Some(core::cmp::PartialEq::eq(self, value))
         } else {
             Some(false)
         }
     }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        let value = value.as_any();
+        if let Some(value) = value.downcast_ref::<Self>() {
+            core::cmp::PartialOrd::partial_cmp(self, value)
+        } else {
+            None
+        }
+    }
 }
 
+#[cfg(feature = "std")]
 impl Typed for &'static Path {
     fn type_info() -> &'static TypeInfo {
         static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
@@ -1132,6 +1491,7 @@ impl Typed for &'static Path {
     }
 }
 
+#[cfg(feature = "std")]
 impl GetTypeRegistration for &'static Path {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Self>();
@@ -1140,22 +1500,209 @@ impl GetTypeRegistration for &'static Path {
     }
 }
 
+#[cfg(feature = "std")]
 impl FromReflect for &'static Path {
     fn from_reflect(reflect: &dyn crate::Reflect) -> Option<Self> {
         reflect.as_any().downcast_ref::<Self>().copied()
     }
 }
 
+/// Returns the value inside the given `Arc<T>`, or the given value itself if it is not one.
+fn unwrap_arc<T: Reflect + Typed + Clone>(value: &dyn Reflect) -> &dyn Reflect {
+    match value.downcast_ref::<Arc<T>>() {
+        Some(arc) => arc.as_ref(),
+        None => value,
+    }
+}
+
+/// `Arc<T>` is reflected as the value it points to, except for its type name and info.
+///
+/// Mutating the value through reflection clones it first if it is shared,
+/// like [`Arc::make_mut`].
+impl<T: Reflect + Typed + Clone> Reflect for Arc<T> {
+    fn type_name(&self) -> &str {
+        core::any::type_name::<Self>()
+    }
+
+    fn get_type_info(&self) -> &'static TypeInfo {
+        <Self as Typed>::type_info()
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        if let Some(arc) = value.downcast_ref::<Self>() {
+            if !Arc::ptr_eq(self, arc) {
+                *self = arc.clone();
+            }
+        } else {
+            Arc::make_mut(self).apply(value);
+        }
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        self.as_ref().reflect_ref()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        Arc::make_mut(self).reflect_mut()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        let value = Arc::try_unwrap(*self).unwrap_or_else(|arc| T::clone(&arc));
+        Box::new(value).reflect_owned()
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        self.as_ref().reflect_hash()
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        self.as_ref().reflect_partial_eq(unwrap_arc::<T>(value))
+    }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        self.as_ref().reflect_partial_cmp(unwrap_arc::<T>(value))
+    }
+
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_ref().debug(f)
+    }
+}
+
+impl<T: Reflect + Typed + Clone> Typed for Arc<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> GetTypeRegistration for Arc<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectArc>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> FromReflect for Arc<T> {
+    /// Clones the given value if it is an `Arc<T>`, keeping it shared,
+    /// and converts it with `T::from_reflect` otherwise.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(arc) = reflect.downcast_ref::<Self>() {
+            return Some(arc.clone());
+        }
+        T::from_reflect(reflect).map(Arc::new)
+    }
+}
+
+/// Type data for values shared through an [`Arc`].
+///
+/// This is registered automatically for `Arc<T>`, and lets the reflect (de)serializers
+/// write values which are shared between several `Arc`s only once when enabled
+/// with [`SerializerConfig::with_shared_references`].
+///
+/// [`SerializerConfig::with_shared_references`]: crate::serde::SerializerConfig::with_shared_references
+#[derive(Clone)]
+pub struct ReflectArc {
+    value_type_id: TypeId,
+    get: fn(&dyn Reflect) -> Option<&dyn Reflect>,
+    address: fn(&dyn Reflect) -> Option<usize>,
+    wrap: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectArc {
+    /// Returns the [`TypeId`] of the value the `Arc` points to.
+    pub fn value_type_id(&self) -> TypeId {
+        self.value_type_id
+    }
+
+    /// Returns the value inside the given `Arc`, or `None` if it is not the `Arc` this was created for.
+    pub fn get<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn Reflect> {
+        (self.get)(value)
+    }
+
+    /// Returns the address of the value inside the given `Arc`,
+    /// which is the same for every clone of it.
+    ///
+    /// Returns `None` if the value is not the `Arc` this was created for.
+    pub fn address(&self, value: &dyn Reflect) -> Option<usize> {
+        (self.address)(value)
+    }
+
+    /// Converts the given value with [`FromReflect`] and puts it in a new `Arc`.
+    ///
+    /// Returns `None` if the value could not be converted.
+    pub fn wrap(&self, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.wrap)(value)
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> FromType<Arc<T>> for ReflectArc {
+    fn from_type() -> Self {
+        Self {
+            value_type_id: TypeId::of::<T>(),
+            get: |value| {
+                value
+                    .downcast_ref::<Arc<T>>()
+                    .map(|arc| arc.as_ref() as &dyn Reflect)
+            },
+            address: |value| {
+                value
+                    .downcast_ref::<Arc<T>>()
+                    .map(|arc| Arc::as_ptr(arc) as usize)
+            },
+            wrap: |value| {
+                let arc = <Arc<T> as FromReflect>::from_reflect(value)?;
+                Some(Box::new(arc))
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate as bevy_reflect;
+    use crate::std_traits::{ReflectAdd, ReflectMul, ReflectNeg};
     use crate::{
         Enum, FromReflect, Reflect, ReflectSerialize, TypeInfo, TypeRegistry, Typed, VariantInfo,
         VariantType,
     };
     use bevy_utils::HashMap;
     use bevy_utils::{Duration, Instant};
-    use std::f32::consts::{PI, TAU};
+    use core::any::TypeId;
+    use core::f32::consts::{PI, TAU};
     use std::path::Path;
 
     #[test]
@@ -1164,7 +1711,7 @@ mod tests {
         type_registry.register::<Duration>();
 
         let reflect_serialize = type_registry
-            .get_type_data::<ReflectSerialize>(std::any::TypeId::of::<Duration>())
+            .get_type_data::<ReflectSerialize>(core::any::TypeId::of::<Duration>())
             .unwrap();
         let _serializable = reflect_serialize.get_serializable(&Duration::ZERO);
     }
@@ -1196,6 +1743,43 @@ mod tests {
         assert!(!a.reflect_partial_eq(c).unwrap_or_default());
     }
 
+    #[test]
+    fn should_combine_with_arithmetic_type_data() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<i32>();
+        registry.register::<u8>();
+        registry.register::<Duration>();
+
+        let add = registry
+            .get_type_data::<ReflectAdd>(TypeId::of::<i32>())
+            .unwrap();
+        let mul = registry
+            .get_type_data::<ReflectMul>(TypeId::of::<i32>())
+            .unwrap();
+        let neg = registry
+            .get_type_data::<ReflectNeg>(TypeId::of::<i32>())
+            .unwrap();
+        let sum = add.add(&3_i32, &4_i32).unwrap();
+        let product = mul.mul(&*sum, &-2_i32).unwrap();
+        assert_eq!(Some(&-14), product.downcast_ref::<i32>());
+        assert_eq!(Some(&14), neg.neg(&*product).unwrap().downcast_ref::<i32>());
+        assert!(add.add(&3_i32, &4_u8).is_none());
+
+        assert!(registry
+            .get_type_data::<ReflectNeg>(TypeId::of::<u8>())
+            .is_none());
+        let add = registry
+            .get_type_data::<ReflectAdd>(TypeId::of::<Duration>())
+            .unwrap();
+        let sum = add
+            .add(&Duration::from_secs(1), &Duration::from_millis(500))
+            .unwrap();
+        assert_eq!(
+            Some(&Duration::from_millis(1500)),
+            sum.downcast_ref::<Duration>()
+        );
+    }
+
     #[test]
     fn should_partial_eq_string() {
         let a: &dyn Reflect = &String::from("Hello");
@@ -1343,11 +1927,11 @@ mod tests {
     }
     #[test]
     fn nonzero_usize_impl_reflect_from_reflect() {
-        let a: &dyn Reflect = &std::num::NonZeroUsize::new(42).unwrap();
-        let b: &dyn Reflect = &std::num::NonZeroUsize::new(42).unwrap();
+        let a: &dyn Reflect = &core::num::NonZeroUsize::new(42).unwrap();
+        let b: &dyn Reflect = &core::num::NonZeroUsize::new(42).unwrap();
         assert!(a.reflect_partial_eq(b).unwrap_or_default());
-        let forty_two: std::num::NonZeroUsize = crate::FromReflect::from_reflect(a).unwrap();
-        assert_eq!(forty_two, std::num::NonZeroUsize::new(42).unwrap());
+        let forty_two: core::num::NonZeroUsize = crate::FromReflect::from_reflect(a).unwrap();
+        assert_eq!(forty_two, core::num::NonZeroUsize::new(42).unwrap());
     }
 
     #[test]
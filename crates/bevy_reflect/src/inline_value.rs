@@ -0,0 +1,114 @@
+use crate::allocation_stats::{record_allocations, AllocationSource};
+use crate::Reflect;
+use alloc::boxed::Box;
+use core::fmt::{Debug, Formatter};
+
+macro_rules! impl_field_value {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        /// A field of a dynamic type, stored in place if it is a small primitive, or boxed otherwise.
+        ///
+        /// As the type of an inline value is fixed by its variant, and values are only ever
+        /// handed out as `dyn Reflect`, an inline value can never change type.
+        pub(crate) enum FieldValue {
+            Boxed(Box<dyn Reflect>),
+            $($variant($ty),)*
+        }
+
+        impl FieldValue {
+            /// Copies `value` into an inline field, if it is one of the small primitives.
+            fn inline(value: &dyn Reflect) -> Option<Self> {
+                $(
+                    if let Some(value) = value.downcast_ref::<$ty>() {
+                        return Some(FieldValue::$variant(*value));
+                    }
+                )*
+                None
+            }
+
+            pub(crate) fn as_reflect(&self) -> &dyn Reflect {
+                match self {
+                    FieldValue::Boxed(value) => &**value,
+                    $(FieldValue::$variant(value) => value,)*
+                }
+            }
+
+            pub(crate) fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                match self {
+                    FieldValue::Boxed(value) => &mut **value,
+                    $(FieldValue::$variant(value) => value,)*
+                }
+            }
+
+            pub(crate) fn into_boxed(self) -> Box<dyn Reflect> {
+                match self {
+                    FieldValue::Boxed(value) => value,
+                    $(FieldValue::$variant(value) => Box::new(value),)*
+                }
+            }
+        }
+    };
+}
+
+impl_field_value!(
+    Bool(bool),
+    Char(char),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    Usize(usize),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    Isize(isize),
+    F32(f32),
+    F64(f64),
+);
+
+impl FieldValue {
+    /// Stores `value` inline if `inline` is set and it is a small primitive, or boxes it.
+    pub(crate) fn new<T: Reflect>(value: T, inline: bool) -> Self {
+        match inline.then(|| Self::inline(&value)).flatten() {
+            Some(field) => field,
+            None => FieldValue::Boxed(Box::new(value)),
+        }
+    }
+
+    /// Moves `value` inline if `inline` is set and it is a small primitive, or keeps it boxed.
+    pub(crate) fn from_boxed(value: Box<dyn Reflect>, inline: bool) -> Self {
+        match inline.then(|| Self::inline(&*value)).flatten() {
+            Some(field) => field,
+            None => FieldValue::Boxed(value),
+        }
+    }
+
+    /// Clones `value` inline if `inline` is set and it is a small primitive,
+    /// or boxes a clone of it made with [`Reflect::clone_value`].
+    ///
+    /// Only the boxed clones are counted as allocations made by `clone_dynamic`.
+    pub(crate) fn cloned(value: &dyn Reflect, inline: bool) -> Self {
+        match inline.then(|| Self::inline(value)).flatten() {
+            Some(field) => field,
+            None => {
+                record_allocations(AllocationSource::CloneDynamic, 1);
+                FieldValue::Boxed(value.clone_value())
+            }
+        }
+    }
+
+    /// Moves the value inline or into a box, as for a field stored with the given setting.
+    pub(crate) fn with_inline(self, inline: bool) -> Self {
+        match self {
+            FieldValue::Boxed(value) => Self::from_boxed(value, inline),
+            field if inline => field,
+            field => FieldValue::Boxed(field.into_boxed()),
+        }
+    }
+}
+
+impl Debug for FieldValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.as_reflect().debug(f)
+    }
+}
@@ -0,0 +1,148 @@
+use crate::Reflect;
+use alloc::sync::Arc;
+use bevy_utils::HashMap;
+use core::any::{Any, TypeId};
+use core::fmt::{self, Debug, Formatter};
+
+type InspectFn = Arc<dyn Fn(&mut dyn Reflect, &mut dyn Any) -> bool + Send + Sync>;
+
+/// Type data holding custom callbacks which draw values of a type in an inspector.
+///
+/// Each callback is written for one kind of UI context, such as the UI handle of a
+/// particular UI library, which is passed to it as `&mut dyn Any`.
+/// This lets inspector frontends delegate the drawing of special types to the types themselves,
+/// without this crate depending on any UI library, and lets a type support several frontends
+/// by registering a callback for each of their contexts.
+///
+/// Callbacks return `true` if they changed the value.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectInspect, TypeRegistry};
+/// # use std::any::TypeId;
+/// #[derive(Reflect)]
+/// struct Color(u8, u8, u8);
+///
+/// // The context of a text-based inspector
+/// struct TextUi {
+///     lines: Vec<String>,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Color>();
+/// registry
+///     .get_mut(TypeId::of::<Color>())
+///     .unwrap()
+///     .insert(ReflectInspect::default().with(|color: &mut Color, ui: &mut TextUi| {
+///         ui.lines.push(format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2));
+///         false
+///     }));
+///
+/// let mut color = Color(255, 128, 0);
+/// let mut ui = TextUi { lines: Vec::new() };
+/// let inspect = registry.get_type_data::<ReflectInspect>(TypeId::of::<Color>()).unwrap();
+/// assert_eq!(Some(false), inspect.inspect(&mut color, &mut ui));
+/// assert_eq!(vec!["#ff8000"], ui.lines);
+/// ```
+#[derive(Clone, Default)]
+pub struct ReflectInspect {
+    callbacks: HashMap<TypeId, InspectFn>,
+}
+
+impl ReflectInspect {
+    /// Adds the callback drawing values of type `T` in a UI context of type `C`,
+    /// replacing any earlier callback for the same context.
+    ///
+    /// The callback is only called with values of type `T`,
+    /// so this should be inserted into the registration of `T`.
+    pub fn with<T: Reflect, C: Any>(
+        self,
+        inspect: impl Fn(&mut T, &mut C) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.with_erased(TypeId::of::<C>(), move |value, context| {
+            match (value.downcast_mut::<T>(), context.downcast_mut::<C>()) {
+                (Some(value), Some(context)) => inspect(value, context),
+                _ => false,
+            }
+        })
+    }
+
+    /// Adds a callback for the UI context with the given [`TypeId`], which receives
+    /// the value and the context without them being downcast, replacing any earlier callback
+    /// for the same context.
+    pub fn with_erased(
+        mut self,
+        context_type_id: TypeId,
+        inspect: impl Fn(&mut dyn Reflect, &mut dyn Any) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.callbacks.insert(context_type_id, Arc::new(inspect));
+        self
+    }
+
+    /// Returns `true` if there is a callback for UI contexts of the given type.
+    pub fn supports(&self, context_type_id: TypeId) -> bool {
+        self.callbacks.contains_key(&context_type_id)
+    }
+
+    /// Draws `value` with the callback for the type of `context`.
+    ///
+    /// Returns whether the callback changed the value,
+    /// or `None` if there is no callback for the context,
+    /// in which case the frontend should draw the value itself.
+    pub fn inspect(&self, value: &mut dyn Reflect, context: &mut dyn Any) -> Option<bool> {
+        let inspect = self.callbacks.get(&(*context).type_id())?;
+        Some(inspect(value, context))
+    }
+}
+
+impl Debug for ReflectInspect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectInspect")
+            .field("contexts", &self.callbacks.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReflectInspect;
+    use core::any::TypeId;
+
+    struct SliderUi {
+        dragged_by: f32,
+    }
+
+    struct LabelUi {
+        text: String,
+    }
+
+    #[test]
+    fn should_pick_callback_by_context() {
+        let inspect = ReflectInspect::default()
+            .with(|value: &mut f32, ui: &mut SliderUi| {
+                *value += ui.dragged_by;
+                ui.dragged_by != 0.0
+            })
+            .with(|value: &mut f32, ui: &mut LabelUi| {
+                ui.text = value.to_string();
+                false
+            });
+        assert!(inspect.supports(TypeId::of::<SliderUi>()));
+        assert!(!inspect.supports(TypeId::of::<()>()));
+
+        let mut value = 1.5_f32;
+        let mut slider = SliderUi { dragged_by: 1.0 };
+        assert_eq!(Some(true), inspect.inspect(&mut value, &mut slider));
+        assert_eq!(2.5, value);
+
+        let mut label = LabelUi {
+            text: String::new(),
+        };
+        assert_eq!(Some(false), inspect.inspect(&mut value, &mut label));
+        assert_eq!("2.5", label.text);
+
+        assert_eq!(None, inspect.inspect(&mut value, &mut ()));
+        // Typed callbacks leave values of other types unchanged
+        assert_eq!(Some(false), inspect.inspect(&mut 1_u8, &mut slider));
+    }
+}
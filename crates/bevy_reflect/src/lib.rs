@@ -1,18 +1,48 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+mod allocation_stats;
+mod apply;
+#[cfg(feature = "rand")]
+mod arbitrary;
 mod array;
+mod cast;
+mod construct;
+mod cycle;
+mod debug_options;
+#[cfg(feature = "documentation")]
+mod documentation;
+mod dynamic_macros;
+mod field_range;
 mod fields;
 mod from_reflect;
+mod history;
+mod inline_value;
+mod inspect;
 mod list;
 mod map;
+mod memory;
+mod non_send;
+#[cfg(feature = "rayon")]
+mod par_iter;
 mod path;
 mod reflect;
+mod repr;
+mod shrink;
 mod struct_trait;
+mod structural_hash;
+mod tracked;
+mod trait_object;
 mod tuple;
 mod tuple_struct;
 mod type_info;
+mod type_path;
 mod type_registry;
 mod type_uuid;
+mod validate;
+mod visit;
 mod impls {
     #[cfg(feature = "glam")]
     mod glam;
@@ -31,9 +61,14 @@ mod impls {
     pub use self::std::*;
 }
 
+pub mod attributes;
 mod enums;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod serde;
 pub mod std_traits;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod utility;
 
 pub mod prelude {
@@ -45,28 +80,94 @@ pub mod prelude {
     };
 }
 
+#[cfg(feature = "allocation_stats")]
+pub use allocation_stats::AllocationStats;
+pub use apply::*;
+#[cfg(feature = "rand")]
+pub use arbitrary::*;
 pub use array::*;
+pub use cast::*;
+pub use construct::*;
+pub use cycle::*;
+pub use debug_options::*;
+#[cfg(feature = "documentation")]
+pub use documentation::*;
 pub use enums::*;
+pub use field_range::*;
 pub use fields::*;
 pub use from_reflect::*;
+pub use history::*;
 pub use impls::*;
+pub use inspect::*;
 pub use list::*;
 pub use map::*;
+pub use memory::*;
+pub use non_send::*;
+#[cfg(feature = "rayon")]
+pub use par_iter::*;
 pub use path::*;
 pub use reflect::*;
+pub use repr::*;
+pub use shrink::*;
 pub use struct_trait::*;
+pub use structural_hash::*;
+pub use tracked::*;
+pub use trait_object::*;
 pub use tuple::*;
 pub use tuple_struct::*;
 pub use type_info::*;
+pub use type_path::*;
 pub use type_registry::*;
 pub use type_uuid::*;
+pub use validate::*;
+pub use visit::*;
 
 pub use bevy_reflect_derive::*;
 pub use erased_serde;
 
 #[doc(hidden)]
 pub mod __macro_exports {
-    use crate::Uuid;
+    use crate::{
+        DynamicTraitObject, Enum, NamedField, Reflect, ReflectRef, Struct, TupleStruct,
+        UnnamedField, Uuid, VariantType,
+    };
+    use alloc::{boxed::Box, string::ToString};
+    use core::cmp::Ordering;
+    use core::marker::PhantomData;
+
+    /// The `alloc` items named by the derive output, which crates without `std` can't reach
+    /// through `::std`.
+    pub mod alloc_utils {
+        pub use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
+    }
+
+    /// A type whose associated constants tell which capabilities `T` has, used by the derive
+    /// to build the [`TypeCapabilities`](crate::TypeCapabilities) of types.
+    ///
+    /// Each constant is defined by an impl bounded by the capability, which takes precedence
+    /// over the `false` default of [`CapabilityFallback`] when the bound holds.
+    pub struct CapabilityProbe<T: ?Sized>(PhantomData<T>);
+
+    /// Provides the `false` constants of [`CapabilityProbe`], and must be in scope for them to be used.
+    pub trait CapabilityFallback {
+        const IS_SEND: bool = false;
+        const IS_SYNC: bool = false;
+        const IS_COPY: bool = false;
+    }
+
+    impl<T: ?Sized> CapabilityFallback for T {}
+
+    impl<T: ?Sized + Send> CapabilityProbe<T> {
+        pub const IS_SEND: bool = true;
+    }
+
+    impl<T: ?Sized + Sync> CapabilityProbe<T> {
+        pub const IS_SYNC: bool = true;
+    }
+
+    impl<T: Copy> CapabilityProbe<T> {
+        pub const IS_COPY: bool = true;
+    }
 
     /// Generates a new UUID from the given UUIDs `a` and `b`,
     /// where the bytes are generated by a bitwise `a ^ b.rotate_right(1)`.
@@ -92,6 +193,168 @@ pub mod __macro_exports {
 
         Uuid::from_bytes(new)
     }
+
+    /// Creates a [`NamedField`] for the type returned by `as_reflect`.
+    ///
+    /// This is used for fields marked with `#[reflect(with = "...")]`, where the
+    /// reflected type can only be inferred from the module's functions.
+    ///
+    /// Since `as_reflect` borrows from the field, the reflected value must be stored
+    /// inside of it; fields converted to an owned value use `#[reflect(convert = "...")]`
+    /// and [`converted_named_field`] instead.
+    pub const fn projected_named_field<T: ?Sized, R: Reflect>(
+        name: &'static str,
+        _as_reflect: fn(&T) -> &R,
+    ) -> NamedField {
+        NamedField::new::<R>(name)
+    }
+
+    /// Creates a [`NamedField`] for the type returned by `to_reflect`.
+    ///
+    /// This is used for fields marked with `#[reflect(convert = "...")]`,
+    /// which are reflected as the owned value returned by the module's `to_reflect`.
+    pub const fn converted_named_field<T: ?Sized, R: Reflect>(
+        name: &'static str,
+        _to_reflect: fn(&T) -> R,
+    ) -> NamedField {
+        NamedField::new::<R>(name)
+    }
+
+    /// Converts the field named `name` of `struct_value` with `from_reflect`,
+    /// for fields marked with `#[reflect(convert = "...")]`.
+    ///
+    /// The field is looked up among the [converted fields](Struct::converted_fields) of the struct
+    /// if it cannot be borrowed, as is the case when `struct_value` is the type declaring it.
+    ///
+    /// Returns `None` if the struct has no such field, and `Some(None)` if it cannot be converted.
+    pub fn convert_struct_field<T>(
+        struct_value: &dyn Struct,
+        name: &str,
+        from_reflect: fn(&dyn Reflect) -> Option<T>,
+    ) -> Option<Option<T>> {
+        if let Some(field) = struct_value.field(name) {
+            return Some(from_reflect(field));
+        }
+        let converted = struct_value.converted_fields();
+        let (_, field) = converted
+            .iter()
+            .find(|(field_name, _)| *field_name == name)?;
+        Some(from_reflect(&**field))
+    }
+
+    /// Creates the value of a `Box<dyn Reflect>` field marked with `#[reflect(dynamic)]`.
+    ///
+    /// A [`DynamicTraitObject`], as returned by the reflect deserializers for such fields,
+    /// is converted to its concrete type. Any other value is cloned with [`Reflect::clone_value`].
+    pub fn dynamic_field_from_reflect(value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        match value.downcast_ref::<DynamicTraitObject>() {
+            Some(dynamic) => dynamic.construct_reflect(),
+            None => Some(value.clone_value()),
+        }
+    }
+
+    /// Converts the values given to the `dynamic_*!` macros into boxed [`Reflect`] values.
+    ///
+    /// Besides any [`Reflect`] type, this accepts string slices, which are converted to [`String`]s.
+    pub trait IntoReflectValue {
+        fn into_reflect_value(self) -> Box<dyn Reflect>;
+    }
+
+    impl<T: Reflect> IntoReflectValue for T {
+        fn into_reflect_value(self) -> Box<dyn Reflect> {
+            Box::new(self)
+        }
+    }
+
+    impl IntoReflectValue for &str {
+        fn into_reflect_value(self) -> Box<dyn Reflect> {
+            Box::new(self.to_string())
+        }
+    }
+
+    /// Creates an [`UnnamedField`] for the type returned by `as_reflect`.
+    ///
+    /// See [`projected_named_field`] for details.
+    pub const fn projected_unnamed_field<T: ?Sized, R: Reflect>(
+        index: usize,
+        _as_reflect: fn(&T) -> &R,
+    ) -> UnnamedField {
+        UnnamedField::new::<R>(index)
+    }
+
+    // The functions below are called by the derived implementations of the reflection traits,
+    // so that their logic is compiled once here, rather than once for every derived type.
+
+    pub fn struct_apply(a: &mut dyn Struct, b: &dyn Reflect) {
+        crate::struct_apply(a, b);
+    }
+
+    pub fn struct_partial_eq(a: &dyn Struct, b: &dyn Reflect) -> Option<bool> {
+        crate::struct_partial_eq(a, b)
+    }
+
+    pub fn struct_partial_cmp(a: &dyn Struct, b: &dyn Reflect) -> Option<Ordering> {
+        crate::struct_partial_cmp(a, b)
+    }
+
+    pub fn tuple_struct_apply(a: &mut dyn TupleStruct, b: &dyn Reflect) {
+        crate::tuple_struct_apply(a, b);
+    }
+
+    pub fn tuple_struct_partial_eq(a: &dyn TupleStruct, b: &dyn Reflect) -> Option<bool> {
+        crate::tuple_struct_partial_eq(a, b)
+    }
+
+    pub fn tuple_struct_partial_cmp(a: &dyn TupleStruct, b: &dyn Reflect) -> Option<Ordering> {
+        crate::tuple_struct_partial_cmp(a, b)
+    }
+
+    pub fn enum_hash(value: &dyn Enum) -> Option<u64> {
+        crate::enum_hash(value)
+    }
+
+    pub fn enum_partial_eq(a: &dyn Enum, b: &dyn Reflect) -> Option<bool> {
+        crate::enum_partial_eq(a, b)
+    }
+
+    pub fn enum_partial_cmp(a: &dyn Enum, b: &dyn Reflect) -> Option<Ordering> {
+        crate::enum_partial_cmp(a, b)
+    }
+
+    /// Applies the fields of `b` to `a` if it is the same variant.
+    ///
+    /// Otherwise, returns `b` as an enum, so that `a` can be switched to its variant
+    /// with the constructors of the derived type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is not an enum.
+    pub fn enum_apply_fields<'a>(a: &mut dyn Enum, b: &'a dyn Reflect) -> Option<&'a dyn Enum> {
+        let ReflectRef::Enum(value) = b.reflect_ref() else {
+            panic!("`{}` is not an enum", b.type_name());
+        };
+        if a.variant_name() != value.variant_name() {
+            return Some(value);
+        }
+        match value.variant_type() {
+            VariantType::Struct => {
+                for field in value.iter_fields() {
+                    if let Some(v) = a.field_mut(field.name().unwrap()) {
+                        v.apply(field.value());
+                    }
+                }
+            }
+            VariantType::Tuple => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    if let Some(v) = a.field_at_mut(index) {
+                        v.apply(field.value());
+                    }
+                }
+            }
+            VariantType::Unit => {}
+        }
+        None
+    }
 }
 
 #[cfg(test)]
@@ -100,13 +363,14 @@ mod tests {
     #[cfg(feature = "glam")]
     use ::glam::{vec3, Vec3};
     use ::serde::{de::DeserializeSeed, Deserialize, Serialize};
+    use alloc::collections::VecDeque;
     use bevy_utils::HashMap;
+    use core::any::TypeId;
+    use core::fmt::{Debug, Formatter};
     use ron::{
         ser::{to_string_pretty, PrettyConfig},
         Deserializer,
     };
-    use std::any::TypeId;
-    use std::fmt::{Debug, Formatter};
 
     use super::prelude::*;
     use super::*;
@@ -313,6 +577,1114 @@ mod tests {
         assert_eq!(Some(expected), my_struct);
     }
 
+    #[test]
+    fn from_reflect_should_use_literal_default_field_attributes() {
+        #[derive(Reflect, FromReflect, PartialEq, Debug)]
+        struct MyStruct {
+            #[reflect(default = 42)]
+            foo: usize,
+            #[reflect(default = 1.5)]
+            #[reflect(ignore)]
+            bar: f32,
+            #[reflect(default = true)]
+            baz: bool,
+        }
+
+        let expected = MyStruct {
+            foo: 42,
+            bar: 1.5,
+            baz: true,
+        };
+
+        let dyn_struct = DynamicStruct::default();
+        let my_struct = <MyStruct as FromReflect>::from_reflect(&dyn_struct);
+
+        assert_eq!(Some(expected), my_struct);
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 7usize);
+        let my_struct = <MyStruct as FromReflect>::from_reflect(&dyn_struct).unwrap();
+
+        assert_eq!(7, my_struct.foo);
+    }
+
+    #[test]
+    fn from_reflect_should_use_expression_default_field_attributes() {
+        const MAX_SPEED: f32 = 12.5;
+
+        #[derive(Reflect, FromReflect, PartialEq, Debug)]
+        struct MyStruct {
+            #[reflect(default(-1))]
+            foo: i32,
+            #[reflect(default(MAX_SPEED))]
+            bar: f32,
+            #[reflect(ignore)]
+            #[reflect(default(MAX_SPEED * 2.0))]
+            baz: f32,
+        }
+
+        let expected = MyStruct {
+            foo: -1,
+            bar: 12.5,
+            baz: 25.0,
+        };
+
+        let dyn_struct = DynamicStruct::default();
+        let my_struct = <MyStruct as FromReflect>::from_reflect(&dyn_struct);
+
+        assert_eq!(Some(expected), my_struct);
+    }
+
+    #[test]
+    fn should_look_up_types_by_type_path() {
+        mod foreign {
+            pub mod inner {
+                use crate as bevy_reflect;
+                use crate::Reflect;
+
+                #[derive(Reflect)]
+                #[reflect(no_type_path)]
+                pub struct Opaque;
+            }
+            pub struct Unreflected;
+        }
+        use foreign::{inner::Opaque, Unreflected};
+
+        impl_type_path!((in foreign::inner) Opaque);
+        impl_type_path!((in foreign) Unreflected);
+
+        assert_eq!("foreign::inner::Opaque", Opaque::type_path());
+        assert_eq!("Opaque", Opaque::short_type_path());
+        assert_eq!(Some("foreign"), Opaque::crate_name());
+        assert_eq!(Some("foreign::inner"), Opaque::module_path());
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Opaque>();
+        registry.register_type_path::<Opaque>();
+        registry.register_type_path::<Unreflected>();
+
+        let registration = registry
+            .get_with_type_path("foreign::inner::Opaque")
+            .unwrap();
+        assert_eq!(TypeId::of::<Opaque>(), registration.type_id());
+
+        // Unreflected types can still be looked up by path, but have no registration
+        assert_eq!(
+            Some(TypeId::of::<Unreflected>()),
+            registry.get_type_id_with_type_path("foreign::Unreflected")
+        );
+        assert!(registry
+            .get_with_type_path("foreign::Unreflected")
+            .is_none());
+        assert!(registry.get_with_type_path("foreign::Opaque").is_none());
+    }
+
+    #[test]
+    fn should_derive_type_path() {
+        #[derive(Reflect)]
+        struct Reflected;
+
+        // `TypePath` is derived from the type's own module
+        assert_eq!("bevy_reflect::tests::Reflected", Reflected::type_path());
+        assert_eq!("Reflected", Reflected::short_type_path());
+        assert_eq!(Some("bevy_reflect"), Reflected::crate_name());
+        assert_eq!(Some("bevy_reflect::tests"), Reflected::module_path());
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Reflected>();
+        registry.register_type_path::<Reflected>();
+
+        let registration = registry
+            .get_with_type_path("bevy_reflect::tests::Reflected")
+            .unwrap();
+        assert_eq!(TypeId::of::<Reflected>(), registration.type_id());
+
+        // Types opting out of the derived `TypePath` have no path dynamically
+        #[derive(Reflect)]
+        #[reflect(no_type_path)]
+        struct Unpathed;
+
+        assert!(Reflect::get_type_path(&Unpathed).is_none());
+    }
+
+    #[test]
+    fn should_derive_generic_type_path() {
+        #[derive(Reflect)]
+        struct Pair<A: Reflect, B: Reflect>(A, B);
+
+        #[derive(Reflect)]
+        struct Grid<T: Reflect, const N: usize> {
+            cells: [T; N],
+        }
+
+        assert_eq!(
+            "bevy_reflect::tests::Pair<u32, alloc::string::String>",
+            <Pair<u32, String>>::type_path()
+        );
+        assert_eq!("Pair<u32, String>", <Pair<u32, String>>::short_type_path());
+        assert_eq!(Some("Pair"), <Pair<u32, String>>::type_ident());
+        assert_eq!(
+            Some("bevy_reflect::tests"),
+            <Pair<u32, String>>::module_path()
+        );
+        assert_eq!(
+            "Grid<Pair<f32, bool>, 4>",
+            <Grid<Pair<f32, bool>, 4>>::short_type_path()
+        );
+
+        // The path is found dynamically rather than falling back to the type name
+        let value: &dyn Reflect = &Pair(1_u8, 2_u16);
+        assert_eq!(
+            "bevy_reflect::tests::Pair<u8, u16>",
+            value.reflect_type_path()
+        );
+    }
+
+    #[test]
+    fn should_intern_generic_type_paths() {
+        #[derive(Reflect)]
+        struct Item;
+
+        assert_eq!(
+            "alloc::vec::Vec<core::option::Option<bevy_reflect::tests::Item>>",
+            <Vec<Option<Item>>>::type_path()
+        );
+        assert_eq!("Vec<Option<Item>>", <Vec<Option<Item>>>::short_type_path());
+        assert_eq!(Some("alloc::vec"), <Vec<Option<Item>>>::module_path());
+        assert_eq!(
+            "HashMap<String, Item>",
+            <HashMap<String, Item>>::short_type_path()
+        );
+
+        // Registering the path interns it, and later requests share the same string
+        let mut registry = TypeRegistry::default();
+        registry.register_type_path::<VecDeque<Item>>();
+        let path = "alloc::collections::vec_deque::VecDeque<bevy_reflect::tests::Item>";
+        assert_eq!(
+            Some(TypeId::of::<VecDeque<Item>>()),
+            registry.get_type_id_with_type_path(path)
+        );
+        let stats = interned_type_paths();
+        assert!(core::ptr::eq(
+            intern_type_path(path),
+            <VecDeque<Item>>::type_path()
+        ));
+        assert!(core::ptr::eq(
+            intern_type_path(&String::from(path)),
+            <VecDeque<Item>>::type_path()
+        ));
+        // Other tests may intern paths concurrently, so these are only lower bounds
+        assert!(stats.count >= 6);
+        assert!(stats.bytes >= path.len());
+    }
+
+    #[test]
+    fn should_get_type_path_dynamically() {
+        #[derive(Reflect)]
+        struct Foo(usize);
+
+        #[derive(Reflect)]
+        #[reflect(no_type_path)]
+        struct Generic<T: Reflect>(T);
+
+        let foo: Box<dyn Reflect> = Box::new(Foo(1));
+        assert_eq!("bevy_reflect::tests::Foo", foo.reflect_type_path());
+        let type_path = foo.get_type_path().unwrap();
+        assert_eq!("Foo", type_path.dyn_short_type_path());
+        assert_eq!(Some("bevy_reflect::tests"), type_path.dyn_module_path());
+
+        // Types without a `TypePath` fall back to their type name
+        let generic: Box<dyn Reflect> = Box::new(Generic(1_usize));
+        assert!(generic.get_type_path().is_none());
+        assert_eq!(
+            core::any::type_name::<Generic<usize>>(),
+            generic.reflect_type_path()
+        );
+
+        // Dynamic types fall back to the name of the type they represent
+        let dynamic = foo.clone_value();
+        assert_eq!(core::any::type_name::<Foo>(), dynamic.reflect_type_path());
+    }
+
+    #[test]
+    fn should_reflect_generic_foreign_types() {
+        mod foreign {
+            #[derive(Debug, PartialEq)]
+            pub struct Pair<T, U> {
+                pub first: T,
+                pub second: U,
+            }
+
+            #[derive(Debug, PartialEq)]
+            pub enum Either<L, R> {
+                Left(L),
+                Right { value: R },
+            }
+        }
+
+        use foreign::{Either, Pair};
+
+        impl_reflect_struct!(
+            struct Pair<T: Reflect, U>
+            where
+                U: Reflect,
+            {
+                first: T,
+                second: U,
+            }
+        );
+
+        impl_reflect_enum!(
+            enum Either<L: FromReflect, R>
+            where
+                R: FromReflect,
+            {
+                Left(L),
+                Right { value: R },
+            }
+        );
+
+        let TypeInfo::Struct(info) = <Pair<u8, String>>::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("first").unwrap().is::<u8>());
+        assert!(info.field("second").unwrap().is::<String>());
+
+        // Each monomorphization gets its own type info
+        let TypeInfo::Struct(info) = <Pair<u16, u32>>::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("first").unwrap().is::<u16>());
+
+        let mut pair = Pair {
+            first: 1u8,
+            second: String::from("a"),
+        };
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("second", String::from("b"));
+        pair.apply(&dyn_struct);
+        assert_eq!("b", pair.second);
+
+        dyn_struct.insert("first", 2u8);
+        assert_eq!(
+            Some(Pair {
+                first: 2u8,
+                second: String::from("b"),
+            }),
+            <Pair<u8, String> as FromReflect>::from_reflect(&dyn_struct)
+        );
+
+        let TypeInfo::Enum(info) = <Either<u8, String>>::type_info() else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Struct(variant) = info.variant("Right").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert!(variant.field("value").unwrap().is::<String>());
+
+        let either = Either::<u8, String>::Left(3);
+        let dyn_enum = either.clone_dynamic();
+        assert_eq!(
+            Some(Either::Left(3)),
+            <Either<u8, String> as FromReflect>::from_reflect(&dyn_enum)
+        );
+    }
+
+    #[test]
+    fn should_reflect_fields_through_accessors() {
+        /// A temperature which can never be below absolute zero.
+        #[derive(Reflect, FromReflect, Default, Debug, PartialEq)]
+        #[reflect(Default)]
+        struct Temperature {
+            #[reflect(get = "Self::kelvin", set = "Self::set_kelvin")]
+            kelvin: f32,
+            label: String,
+        }
+
+        impl Temperature {
+            fn kelvin(&self) -> &f32 {
+                &self.kelvin
+            }
+
+            fn set_kelvin(&mut self, kelvin: f32) {
+                self.kelvin = kelvin.max(0.0);
+            }
+        }
+
+        #[derive(Reflect)]
+        struct Bounded(#[reflect(set = "Bounded::set")] u8);
+
+        impl Bounded {
+            fn set(&mut self, value: u8) {
+                self.0 = value.min(10);
+            }
+        }
+
+        let mut temperature = Temperature {
+            kelvin: 300.0,
+            label: String::from("room"),
+        };
+
+        // Fields with accessors can be read, but not borrowed mutably
+        assert_eq!(Some(&300.0), temperature.get_field::<f32>("kelvin"));
+        assert!(temperature.field_mut("kelvin").is_none());
+        assert!(temperature.field_at_mut(0).is_none());
+        assert!(temperature.field_mut("label").is_some());
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("kelvin", -5.0_f32);
+        patch.insert("label", String::from("impossible"));
+        temperature.apply(&patch);
+        assert_eq!(0.0, temperature.kelvin);
+        assert_eq!("impossible", temperature.label);
+
+        // `FromReflect` goes through the setter when building from a default value
+        patch.insert("kelvin", -1.0_f32);
+        let temperature = Temperature::from_reflect(&patch).unwrap();
+        assert_eq!(0.0, temperature.kelvin);
+
+        let mut bounded = Bounded(0);
+        assert!(bounded.field_mut(0).is_none());
+        let mut patch = DynamicTupleStruct::default();
+        patch.insert(100_u8);
+        bounded.apply(&patch);
+        assert_eq!(10, bounded.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "could not be converted")]
+    fn should_panic_applying_invalid_value_through_setter() {
+        #[derive(Reflect)]
+        struct Foo {
+            #[reflect(set = "Foo::set_value")]
+            value: usize,
+        }
+
+        impl Foo {
+            fn set_value(&mut self, value: usize) {
+                self.value = value;
+            }
+        }
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("value", String::from("not a number"));
+        Foo { value: 0 }.apply(&patch);
+    }
+
+    #[test]
+    fn should_reflect_generic_traits() {
+        #[reflect_trait(upcast(core::fmt::Debug))]
+        trait Shape<T>: core::fmt::Debug {
+            fn area(&self) -> T;
+
+            fn name(&self) -> &str;
+
+            fn first_of<'a>(&self, names: &'a [&'a str]) -> &'a str;
+
+            // Generic methods are excluded from the trait object
+            fn scaled_area<S: Into<T>>(&self, scale: S) -> T;
+
+            fn describe(&self, prefix: impl core::fmt::Display) -> String;
+        }
+
+        #[derive(Reflect, Debug)]
+        struct Square(f32);
+
+        impl Shape<f32> for Square {
+            fn area(&self) -> f32 {
+                self.0 * self.0
+            }
+
+            fn name(&self) -> &str {
+                "square"
+            }
+
+            fn first_of<'a>(&self, names: &'a [&'a str]) -> &'a str {
+                names[0]
+            }
+
+            fn scaled_area<S: Into<f32>>(&self, scale: S) -> f32 {
+                self.area() * scale.into()
+            }
+
+            fn describe(&self, prefix: impl core::fmt::Display) -> String {
+                format!("{prefix} {}", self.name())
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Square>();
+        registry.register_type_data::<Square, ReflectShape<f32>>();
+
+        let reflect_shape = registry
+            .get_type_data::<ReflectShape<f32>>(TypeId::of::<Square>())
+            .unwrap();
+
+        let mut value: Box<dyn Reflect> = Box::new(Square(2.0));
+        assert_eq!(4.0, reflect_shape.get(&*value).unwrap().area());
+        let shape = reflect_shape.get_mut(&mut *value).unwrap();
+        assert_eq!("square", shape.name());
+        assert_eq!("a", shape.first_of(&["a", "b"]));
+        assert_eq!(
+            "Square(2.0)",
+            format!("{:?}", reflect_shape.get_debug(&*value).unwrap())
+        );
+        assert!(reflect_shape.get_debug_mut(&mut *value).is_some());
+
+        let debug = reflect_shape.get_debug_boxed(value).unwrap();
+        assert_eq!("Square(2.0)", format!("{debug:?}"));
+
+        let shape = reflect_shape.get_boxed(Box::new(Square(3.0))).unwrap();
+        assert_eq!(9.0, shape.area());
+        assert!(reflect_shape.get_boxed(Box::new(1_u8)).is_err());
+        assert_eq!(18.0, Square(3.0).scaled_area(2.0));
+        assert_eq!("a square", Square(1.0).describe("a"));
+    }
+
+    #[test]
+    fn should_cast_to_registered_traits() {
+        #[reflect_trait]
+        trait Damageable {
+            fn damage(&mut self, amount: u32);
+        }
+
+        #[reflect_trait]
+        trait Named {
+            fn name(&self) -> String;
+        }
+
+        #[derive(Reflect)]
+        struct Player {
+            health: u32,
+        }
+
+        impl Damageable for Player {
+            fn damage(&mut self, amount: u32) {
+                self.health = self.health.saturating_sub(amount);
+            }
+        }
+
+        impl Named for Player {
+            fn name(&self) -> String {
+                String::from("player")
+            }
+        }
+
+        #[derive(Reflect)]
+        struct Wall;
+
+        impl Damageable for Wall {
+            fn damage(&mut self, _amount: u32) {}
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Wall>();
+        registry.register_trait_cast::<Player, ReflectDamageable>();
+        registry.register_trait_cast::<Player, ReflectNamed>();
+        registry.register_trait_cast::<Wall, ReflectDamageable>();
+
+        // The trait's type data is registered as well
+        assert!(registry
+            .get_type_data::<ReflectNamed>(TypeId::of::<Player>())
+            .is_some());
+
+        let mut values: Vec<Box<dyn Reflect>> =
+            vec![Box::new(Player { health: 10 }), Box::new(Wall)];
+        for value in &mut values {
+            registry
+                .cast_mut::<dyn Damageable>(value.as_mut())
+                .unwrap()
+                .damage(4);
+        }
+
+        let names = values
+            .iter()
+            .filter_map(|value| registry.cast_ref::<dyn Named>(value.as_ref()))
+            .map(|named| named.name())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![String::from("player")], names);
+
+        let player = registry
+            .cast_boxed::<dyn Named>(values.remove(0))
+            .unwrap_or_else(|_| panic!("`Player` should be castable to `dyn Named`"));
+        assert_eq!("player", player.name());
+
+        let wall = registry.cast_boxed::<dyn Named>(values.remove(0));
+        assert!(wall.err().unwrap().is::<Wall>());
+        assert!(registry.cast_ref::<dyn Damageable>(&5_u32).is_none());
+    }
+
+    #[test]
+    fn should_mirror_serde_attributes() {
+        use ::serde::{Deserialize, Serialize};
+
+        fn default_count() -> u32 {
+            5
+        }
+
+        #[derive(Reflect, FromReflect, Serialize, Deserialize, Debug, PartialEq)]
+        #[reflect(serde_compat)]
+        struct MyStruct {
+            #[serde(rename = "renamed", alias = "aliased")]
+            value: i32,
+            #[serde(default = "default_count")]
+            count: u32,
+            #[serde(skip)]
+            cache: Vec<u8>,
+        }
+
+        #[derive(Reflect, FromReflect, Serialize, Deserialize, Debug, PartialEq)]
+        #[reflect(serde_compat)]
+        enum MyEnum {
+            #[serde(rename = "renamed", alias = "aliased")]
+            Variant {
+                #[serde(alias = "v")]
+                value: i32,
+            },
+        }
+
+        let TypeInfo::Struct(info) = MyStruct::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(Some(0), info.index_of("renamed"));
+        assert!(info.field("value").is_none());
+
+        let mut value = MyStruct {
+            value: 1,
+            count: 2,
+            cache: vec![3],
+        };
+        assert!(value.field("value").is_none());
+        assert_eq!(Some(&1), value.get_field::<i32>("renamed"));
+        *value.get_field_mut::<i32>("aliased").unwrap() = 4;
+        assert_eq!(4, value.value);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<MyStruct>();
+        let serializer = serde::ReflectSerializer::new(&value, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert!(output.contains("renamed:4"));
+        assert!(!output.contains("cache"));
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("aliased", 6);
+        let expected = MyStruct {
+            value: 6,
+            count: 5,
+            cache: Vec::new(),
+        };
+        assert_eq!(
+            Some(expected),
+            <MyStruct as FromReflect>::from_reflect(&dyn_struct)
+        );
+
+        let value = MyEnum::Variant { value: 1 };
+        assert_eq!("renamed", value.variant_name());
+        assert_eq!(Some(&1), value.field("v").unwrap().downcast_ref::<i32>());
+
+        let mut dyn_enum = DynamicStruct::default();
+        dyn_enum.insert("v", 7);
+        let dyn_enum = DynamicEnum::new(Reflect::type_name(&value), "aliased", dyn_enum);
+        assert_eq!(
+            Some(MyEnum::Variant { value: 7 }),
+            <MyEnum as FromReflect>::from_reflect(&dyn_enum)
+        );
+    }
+
+    #[test]
+    fn should_reflect_through_repr_type() {
+        #[derive(Reflect, FromReflect)]
+        struct RangeRepr {
+            start: u32,
+            end: u32,
+        }
+
+        #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+        #[reflect(from = "RangeRepr", into = "RangeRepr")]
+        struct OrderedRange {
+            start: u32,
+            end: u32,
+        }
+
+        impl TryFrom<RangeRepr> for OrderedRange {
+            type Error = ();
+
+            fn try_from(repr: RangeRepr) -> Result<Self, ()> {
+                if repr.start <= repr.end {
+                    Ok(OrderedRange {
+                        start: repr.start,
+                        end: repr.end,
+                    })
+                } else {
+                    Err(())
+                }
+            }
+        }
+
+        impl From<OrderedRange> for RangeRepr {
+            fn from(range: OrderedRange) -> Self {
+                RangeRepr {
+                    start: range.start,
+                    end: range.end,
+                }
+            }
+        }
+
+        assert!(matches!(OrderedRange::type_info(), TypeInfo::Value(_)));
+
+        let mut range = OrderedRange { start: 0, end: 1 };
+        let mut repr = DynamicStruct::default();
+        repr.insert("start", 2u32);
+        repr.insert("end", 4u32);
+        range.apply(&repr);
+        assert_eq!(OrderedRange { start: 2, end: 4 }, range);
+
+        repr.insert("start", 8u32);
+        assert_eq!(None, <OrderedRange as FromReflect>::from_reflect(&repr));
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<OrderedRange>();
+        let reflect_repr = registry
+            .get_type_data::<ReflectRepr>(core::any::TypeId::of::<OrderedRange>())
+            .unwrap();
+
+        let value = reflect_repr.into_repr(&range).unwrap();
+        let value = value.downcast_ref::<RangeRepr>().unwrap();
+        assert_eq!((2, 4), (value.start, value.end));
+
+        let value = reflect_repr
+            .from_repr(&RangeRepr { start: 1, end: 3 })
+            .unwrap();
+        assert_eq!(
+            Some(&OrderedRange { start: 1, end: 3 }),
+            value.downcast_ref::<OrderedRange>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value is not")]
+    fn should_panic_applying_invalid_repr() {
+        #[derive(Reflect, FromReflect)]
+        struct NonZeroRepr(u32);
+
+        #[derive(Reflect, FromReflect, Clone)]
+        #[reflect(from = "NonZeroRepr")]
+        #[allow(dead_code)]
+        struct NonZero(u32);
+
+        impl TryFrom<NonZeroRepr> for NonZero {
+            type Error = ();
+
+            fn try_from(repr: NonZeroRepr) -> Result<Self, ()> {
+                (repr.0 != 0).then_some(NonZero(repr.0)).ok_or(())
+            }
+        }
+
+        NonZero(1).apply(&NonZeroRepr(0));
+    }
+
+    #[test]
+    fn should_reflect_fields_with_conversion_module() {
+        mod meters {
+            use crate::Reflect;
+
+            #[derive(Debug, PartialEq)]
+            pub struct Meters(pub f32);
+
+            pub fn as_reflect(value: &Meters) -> &f32 {
+                &value.0
+            }
+
+            pub fn as_reflect_mut(value: &mut Meters) -> &mut f32 {
+                &mut value.0
+            }
+
+            pub fn from_reflect(reflect: &dyn Reflect) -> Option<Meters> {
+                reflect.downcast_ref::<f32>().copied().map(Meters)
+            }
+        }
+
+        use meters::Meters;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct MyStruct {
+            #[reflect(with = "meters")]
+            distance: Meters,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum MyEnum {
+            Tuple(#[reflect(with = "meters")] Meters),
+        }
+
+        let mut value = MyStruct {
+            distance: Meters(1.0),
+        };
+        assert_eq!(
+            Some(&1.0),
+            value.field("distance").unwrap().downcast_ref::<f32>()
+        );
+
+        let TypeInfo::Struct(info) = MyStruct::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("distance").unwrap().is::<f32>());
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("distance", 2.0f32);
+        value.apply(&dyn_struct);
+        assert_eq!(Meters(2.0), value.distance);
+        assert_eq!(
+            Some(value),
+            <MyStruct as FromReflect>::from_reflect(&dyn_struct)
+        );
+
+        let mut value = MyEnum::Tuple(Meters(1.0));
+        *value
+            .field_at_mut(0)
+            .unwrap()
+            .downcast_mut::<f32>()
+            .unwrap() = 3.0;
+        assert_eq!(MyEnum::Tuple(Meters(3.0)), value);
+
+        let TypeInfo::Enum(info) = MyEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Tuple(variant) = info.variant("Tuple").unwrap() else {
+            panic!("expected tuple variant");
+        };
+        assert!(variant.field_at(0).unwrap().is::<f32>());
+
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(
+            Some(MyEnum::Tuple(Meters(3.0))),
+            <MyEnum as FromReflect>::from_reflect(&dyn_enum)
+        );
+    }
+
+    #[test]
+    fn should_reflect_converted_fields() {
+        mod hex {
+            use crate::Reflect;
+
+            #[derive(Debug, PartialEq)]
+            pub struct Rgb(pub u8, pub u8, pub u8);
+
+            pub fn to_reflect(value: &Rgb) -> String {
+                format!("#{:02x}{:02x}{:02x}", value.0, value.1, value.2)
+            }
+
+            pub fn from_reflect(reflect: &dyn Reflect) -> Option<Rgb> {
+                let hex = reflect.downcast_ref::<String>()?.strip_prefix('#')?;
+                let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+                Some(Rgb(channel(0)?, channel(2)?, channel(4)?))
+            }
+        }
+
+        use hex::Rgb;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Light {
+            intensity: f32,
+            #[reflect(convert = "hex")]
+            color: Rgb,
+        }
+
+        let mut value = Light {
+            intensity: 0.5,
+            color: Rgb(255, 128, 0),
+        };
+
+        // The string is not stored in the field, so it cannot be borrowed
+        assert_eq!(1, value.field_len());
+        assert!(value.field("color").is_none());
+        let converted = value.converted_fields();
+        assert_eq!("color", converted[0].0);
+        assert_eq!(
+            Some("#ff8000"),
+            converted[0].1.downcast_ref::<String>().map(String::as_str)
+        );
+
+        let TypeInfo::Struct(info) = Light::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(1, info.field_len());
+        assert!(info.converted_field("color").unwrap().is::<String>());
+
+        let clone = value.clone_value();
+        let ReflectRef::Struct(clone_struct) = clone.reflect_ref() else {
+            panic!("expected struct");
+        };
+        assert_eq!(
+            Some(&"#ff8000".to_string()),
+            clone_struct
+                .field("color")
+                .unwrap()
+                .downcast_ref::<String>()
+        );
+        assert_eq!(Some(true), value.reflect_partial_eq(&*clone));
+        assert_eq!(Some(true), clone.reflect_partial_eq(&value));
+        assert_eq!(
+            Some(&value),
+            <Light as FromReflect>::from_reflect(&*clone).as_ref()
+        );
+        assert_eq!(
+            Some(&value),
+            <Light as FromReflect>::from_reflect(&value).as_ref()
+        );
+
+        let debug = format!("{:?}", &value as &dyn Reflect);
+        assert!(debug.contains(r##"color: "#ff8000""##), "{debug}");
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Light>();
+        registry.register::<String>();
+        registry.register::<f32>();
+
+        let serializer = ReflectSerializer::new(&value, &registry);
+        let serialized = ron::to_string(&serializer).unwrap();
+        assert!(serialized.contains(r##"color:"#ff8000""##), "{serialized}");
+        let serialized = serialized.replace("ff8000", "00ff40");
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let deserialized = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        value.apply(&*deserialized);
+        assert_eq!(Rgb(0, 255, 64), value.color);
+        assert_eq!(
+            Some(Light {
+                intensity: 0.5,
+                color: Rgb(0, 255, 64),
+            }),
+            <Light as FromReflect>::from_reflect(&*deserialized)
+        );
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("color", "#102030".to_string());
+        value.apply(&patch);
+        assert_eq!(Rgb(16, 32, 48), value.color);
+        assert_eq!(0.5, value.intensity);
+
+        let mut deserializer = Deserializer::from_str(r##"(color: "#000000")"##).unwrap();
+        crate::serde::ReflectApplyDeserializer::new(&mut value, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Rgb(0, 0, 0), value.color);
+    }
+
+    #[test]
+    fn should_apply_same_type_with_clone() {
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        #[reflect(Clone)]
+        struct Foo {
+            a: u32,
+            #[reflect(ignore)]
+            b: u32,
+        }
+
+        let mut foo = Foo { a: 1, b: 2 };
+        foo.apply(&Foo { a: 3, b: 4 });
+        assert_eq!(Foo { a: 3, b: 4 }, foo);
+
+        // Other values are still applied field by field
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("a", 5_u32);
+        foo.apply(&dyn_struct);
+        assert_eq!(Foo { a: 5, b: 4 }, foo);
+
+        fn clone_from_counting(this: &mut Bar, other: &Bar) {
+            *this = Bar(other.0, this.1 + 1);
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Clone(clone_from_counting))]
+        struct Bar(u32, #[reflect(ignore)] usize);
+
+        let mut bar = Bar(0, 0);
+        bar.apply(&Bar(7, 0));
+        bar.apply(&Bar(8, 0));
+        let dynamic = bar.clone_value();
+        bar.apply(&*dynamic);
+        assert_eq!(Bar(8, 2), bar);
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        #[reflect(Clone)]
+        enum Baz {
+            A(String),
+            B { value: f32 },
+        }
+
+        let mut baz = Baz::A(String::from("a"));
+        baz.apply(&Baz::B { value: 1.0 });
+        assert_eq!(Baz::B { value: 1.0 }, baz);
+    }
+
+    #[test]
+    fn should_apply_and_compare_through_shared_helpers() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo {
+            a: u32,
+            b: Bar,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Bar(String, u8);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Baz {
+            A(u32),
+            B { value: f32 },
+            C,
+        }
+
+        let mut foo = Foo {
+            a: 1,
+            b: Bar(String::from("a"), 2),
+        };
+        let mut patch = DynamicStruct::default();
+        patch.insert("a", 3_u32);
+        patch.insert("c", 4_u32);
+        foo.apply(&patch);
+        foo.b.apply(&Bar(String::from("b"), 5));
+        assert_eq!(
+            Foo {
+                a: 3,
+                b: Bar(String::from("b"), 5)
+            },
+            foo
+        );
+        assert_eq!(Some(true), foo.reflect_partial_eq(&*foo.clone_value()));
+        assert_eq!(Some(false), foo.reflect_partial_eq(&patch));
+        assert_eq!(
+            Some(core::cmp::Ordering::Less),
+            Bar(String::from("a"), 1).reflect_partial_cmp(&Bar(String::from("a"), 2))
+        );
+
+        let mut baz = Baz::A(1);
+        baz.apply(&Baz::A(2));
+        assert_eq!(Baz::A(2), baz);
+        baz.apply(&Baz::B { value: 1.0 });
+        assert_eq!(Baz::B { value: 1.0 }, baz);
+        baz.apply(&*Baz::C.clone_value());
+        assert_eq!(Baz::C, baz);
+        assert_eq!(Baz::C.reflect_hash(), baz.reflect_hash());
+        assert_ne!(Baz::A(0).reflect_hash(), Baz::A(1).reflect_hash());
+    }
+
+    #[test]
+    fn should_reflect_dynamic_fields() {
+        #[derive(Reflect, FromReflect)]
+        struct Foo {
+            #[reflect(dynamic)]
+            value: Box<dyn Reflect>,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Bar(usize);
+
+        let mut foo = Foo {
+            value: Box::new(Bar(1)),
+        };
+        assert!(foo.field("value").unwrap().is::<Bar>());
+
+        let TypeInfo::Struct(info) = Foo::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(
+            TypeId::of::<dyn Reflect>(),
+            info.field("value").unwrap().type_id()
+        );
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", Bar(2));
+        foo.apply(&dyn_struct);
+        assert_eq!(Some(&Bar(2)), foo.value.downcast_ref::<Bar>());
+
+        // Values are cloned with `clone_value`, so only represent their original type
+        let foo = Foo::from_reflect(&foo.clone_dynamic()).unwrap();
+        assert!(foo.value.represents::<Bar>());
+        assert_eq!(Some(Bar(2)), Bar::from_reflect(foo.value.as_ref()));
+    }
+
+    #[test]
+    fn should_store_dynamic_struct_values_inline() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Point {
+            x: f32,
+            visible: bool,
+            label: String,
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.set_inline_values(true);
+        dyn_struct.insert("x", 1.0_f32);
+        dyn_struct.insert_boxed("visible", Box::new(false));
+        dyn_struct.insert("label", String::from("a"));
+        assert!(!dyn_struct.is_field_boxed(0));
+        assert!(!dyn_struct.is_field_boxed(1));
+        assert!(dyn_struct.is_field_boxed(2));
+
+        *dyn_struct.get_field_mut::<f32>("x").unwrap() = 2.0;
+        dyn_struct.field_mut("visible").unwrap().apply(&true);
+        dyn_struct.insert("x", 3.0_f32);
+        assert_eq!(3, dyn_struct.field_len());
+
+        let clone = dyn_struct.clone_dynamic();
+        assert!(clone.inline_values());
+        assert!(!clone.is_field_boxed(0));
+        let expected = Point {
+            x: 3.0,
+            visible: true,
+            label: String::from("a"),
+        };
+        assert_eq!(Some(&expected), Point::from_reflect(&clone).as_ref());
+
+        dyn_struct.set_inline_values(false);
+        assert!(dyn_struct.is_field_boxed(0));
+        assert!(dyn_struct.is_field_boxed(1));
+        assert_eq!(Some(expected), Point::from_reflect(&dyn_struct));
+    }
+
+    #[test]
+    fn should_clone_small_values_inline() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Scale(f32, String);
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Transform {
+            scale: Scale,
+            offset: (i32, u8),
+            enabled: bool,
+        }
+
+        let transform = Transform {
+            scale: Scale(2.0, String::from("x")),
+            offset: (-1, 4),
+            enabled: true,
+        };
+        let clone = transform.clone_dynamic();
+        assert!(clone.inline_values());
+        assert!(clone.is_field_boxed(0));
+        assert!(clone.is_field_boxed(1));
+        assert!(!clone.is_field_boxed(2));
+
+        let scale = clone.field("scale").unwrap();
+        let scale = scale.downcast_ref::<DynamicTupleStruct>().unwrap();
+        assert!(!scale.is_field_boxed(0));
+        assert!(scale.is_field_boxed(1));
+        let offset = clone.field("offset").unwrap();
+        let offset = offset.downcast_ref::<DynamicTuple>().unwrap();
+        assert!(!offset.is_field_boxed(0));
+        assert!(!offset.is_field_boxed(1));
+        assert_eq!("(i32, u8)", offset.type_name());
+
+        assert_eq!(Some(transform), Transform::from_reflect(&clone));
+        let fields = Box::new(offset.clone_dynamic()).drain();
+        assert_eq!(Some(&4), fields[1].downcast_ref::<u8>());
+    }
+
     #[test]
     fn from_reflect_should_use_default_container_attribute() {
         #[derive(Reflect, FromReflect, Eq, PartialEq, Debug)]
@@ -568,6 +1940,111 @@ mod tests {
         assert_eq!(foo, *foo2.downcast::<Foo>().unwrap());
     }
 
+    #[test]
+    fn should_access_single_kinds() {
+        let mut value: Box<dyn Reflect> = Box::new(vec![1_u32, 2]);
+        assert_eq!(2, value.try_as_list().unwrap().len());
+        assert!(value.try_as_array().is_none());
+        assert!(value.try_as_struct().is_none());
+        value.try_as_list_mut().unwrap().push(Box::new(3_u32));
+        assert_eq!(Some(&vec![1, 2, 3]), value.downcast_ref::<Vec<u32>>());
+
+        let mut value: Box<dyn Reflect> = Box::new(Some(1_u32));
+        assert_eq!("Some", value.try_as_enum().unwrap().variant_name());
+        assert!(value.try_as_map_mut().is_none());
+    }
+
+    #[test]
+    fn should_get_fields_of_any_value() {
+        #[derive(Reflect)]
+        struct Point(f32, f32);
+
+        #[derive(Reflect)]
+        enum Shape {
+            Circle { radius: f32 },
+        }
+
+        let mut point: Box<dyn Reflect> = Box::new(Point(1.0, 2.0));
+        *point.get_field_at_mut::<f32>(1).unwrap() = 3.0;
+        assert_eq!(Some(&3.0), point.get_field_at::<f32>(1));
+        assert_eq!(None, point.get_field::<f32>("0"));
+        assert_eq!(None, point.get_field_at::<u32>(0));
+
+        let mut shape: Box<dyn Reflect> = Box::new(Shape::Circle { radius: 1.0 });
+        *shape.get_field_mut::<f32>("radius").unwrap() = 2.0;
+        assert_eq!(Some(&2.0), shape.get_field_at::<f32>(0));
+
+        let list: &dyn Reflect = &vec![1_u32];
+        assert_eq!(None, list.get_field_at::<u32>(0));
+    }
+
+    #[test]
+    fn should_collect_dynamics() {
+        let mut list: DynamicList = (0..2_u32)
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect();
+        list.extend([Box::new(2_u32) as Box<dyn Reflect>]);
+        assert!(list.reflect_partial_eq(&vec![0_u32, 1, 2]).unwrap());
+
+        let mut map: DynamicMap = [(0_u32, "a"), (1, "b"), (0, "c")]
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    Box::new(key) as Box<dyn Reflect>,
+                    Box::new(value.to_string()) as Box<dyn Reflect>,
+                )
+            })
+            .collect();
+        map.extend([(
+            Box::new(2_u32) as Box<dyn Reflect>,
+            Box::new(String::from("d")) as Box<dyn Reflect>,
+        )]);
+        assert_eq!(3, map.len());
+        assert_eq!(
+            Some(&String::from("c")),
+            map.get(&0_u32).unwrap().downcast_ref::<String>()
+        );
+
+        let mut dynamic_struct: DynamicStruct = [("x", Box::new(1.0_f32) as Box<dyn Reflect>)]
+            .into_iter()
+            .collect();
+        dynamic_struct.extend([(String::from("y"), Box::new(2.0_f32) as Box<dyn Reflect>)]);
+        assert_eq!(Some(&2.0), dynamic_struct.get_field::<f32>("y"));
+        assert_eq!(2, dynamic_struct.field_len());
+    }
+
+    #[test]
+    fn should_set_and_swap_array_elements() {
+        let mut list: Box<dyn Array> = Box::new(vec![1_u32, 2, 3]);
+        list.set_element(0, Box::new(DynamicTuple::default()))
+            .unwrap_err();
+        list.set_element(2, Box::new(4_u32)).unwrap();
+        list.swap_elements(0, 2).unwrap();
+        assert_eq!(
+            Err(ArrayError::OutOfBounds { index: 3, len: 3 }),
+            list.swap_elements(0, 3)
+        );
+        assert_eq!(
+            Some(&vec![4, 2, 1]),
+            list.as_reflect().downcast_ref::<Vec<u32>>()
+        );
+
+        let mut dynamic = DynamicArray::from_vec(vec![1_u32, 2]);
+        dynamic
+            .set_element(0, Box::new(String::from("one")))
+            .unwrap();
+        dynamic.swap_elements(0, 1).unwrap();
+        assert_eq!(Some(&2), dynamic.get(0).unwrap().downcast_ref::<u32>());
+        assert_eq!(
+            Some("one"),
+            dynamic
+                .get(1)
+                .unwrap()
+                .downcast_ref::<String>()
+                .map(String::as_str)
+        );
+    }
+
     #[test]
     fn should_drain_fields() {
         let array_value: Box<dyn Array> = Box::new([123_i32, 321_i32]);
@@ -608,17 +2085,17 @@ mod tests {
     fn dynamic_names() {
         let list = Vec::<usize>::new();
         let dyn_list = List::clone_dynamic(&list);
-        assert_eq!(dyn_list.type_name(), std::any::type_name::<Vec<usize>>());
+        assert_eq!(dyn_list.type_name(), core::any::type_name::<Vec<usize>>());
 
         let array = [b'0'; 4];
         let dyn_array = Array::clone_dynamic(&array);
-        assert_eq!(dyn_array.type_name(), std::any::type_name::<[u8; 4]>());
+        assert_eq!(dyn_array.type_name(), core::any::type_name::<[u8; 4]>());
 
         let map = HashMap::<usize, String>::default();
         let dyn_map = map.clone_dynamic();
         assert_eq!(
             dyn_map.type_name(),
-            std::any::type_name::<HashMap<usize, String>>()
+            core::any::type_name::<HashMap<usize, String>>()
         );
 
         let tuple = (0usize, "1".to_string(), 2.0f32);
@@ -626,7 +2103,7 @@ mod tests {
         dyn_tuple.insert::<usize>(3);
         assert_eq!(
             dyn_tuple.type_name(),
-            std::any::type_name::<(usize, String, f32, usize)>()
+            core::any::type_name::<(usize, String, f32, usize)>()
         );
 
         #[derive(Reflect)]
@@ -635,7 +2112,7 @@ mod tests {
         }
         let struct_ = TestStruct { a: 0 };
         let dyn_struct = struct_.clone_dynamic();
-        assert_eq!(dyn_struct.type_name(), std::any::type_name::<TestStruct>());
+        assert_eq!(dyn_struct.type_name(), core::any::type_name::<TestStruct>());
 
         #[derive(Reflect)]
         struct TestTupleStruct(usize);
@@ -643,7 +2120,7 @@ mod tests {
         let dyn_tuple_struct = tuple_struct.clone_dynamic();
         assert_eq!(
             dyn_tuple_struct.type_name(),
-            std::any::type_name::<TestTupleStruct>()
+            core::any::type_name::<TestTupleStruct>()
         );
     }
 
@@ -651,12 +2128,12 @@ mod tests {
     fn reflect_type_info() {
         // TypeInfo
         let info = i32::type_info();
-        assert_eq!(std::any::type_name::<i32>(), info.type_name());
-        assert_eq!(std::any::TypeId::of::<i32>(), info.type_id());
+        assert_eq!(core::any::type_name::<i32>(), info.type_name());
+        assert_eq!(core::any::TypeId::of::<i32>(), info.type_id());
 
         // TypeInfo (unsized)
         assert_eq!(
-            std::any::TypeId::of::<dyn Reflect>(),
+            core::any::TypeId::of::<dyn Reflect>(),
             <dyn Reflect as Typed>::type_info().type_id()
         );
 
@@ -675,19 +2152,19 @@ mod tests {
         let info = MyStruct::type_info();
         if let TypeInfo::Struct(info) = info {
             assert!(info.is::<MyStruct>());
-            assert_eq!(std::any::type_name::<MyStruct>(), info.type_name());
+            assert_eq!(core::any::type_name::<MyStruct>(), info.type_name());
             assert_eq!(
-                std::any::type_name::<i32>(),
+                core::any::type_name::<i32>(),
                 info.field("foo").unwrap().type_name()
             );
             assert_eq!(
-                std::any::TypeId::of::<i32>(),
+                core::any::TypeId::of::<i32>(),
                 info.field("foo").unwrap().type_id()
             );
             assert!(info.field("foo").unwrap().is::<i32>());
             assert_eq!("foo", info.field("foo").unwrap().name());
             assert_eq!(
-                std::any::type_name::<usize>(),
+                core::any::type_name::<usize>(),
                 info.field_at(1).unwrap().type_name()
             );
         } else {
@@ -709,16 +2186,16 @@ mod tests {
         if let TypeInfo::Struct(info) = info {
             assert!(info.is::<MyGenericStruct<i32>>());
             assert_eq!(
-                std::any::type_name::<MyGenericStruct<i32>>(),
+                core::any::type_name::<MyGenericStruct<i32>>(),
                 info.type_name()
             );
             assert_eq!(
-                std::any::type_name::<i32>(),
+                core::any::type_name::<i32>(),
                 info.field("foo").unwrap().type_name()
             );
             assert_eq!("foo", info.field("foo").unwrap().name());
             assert_eq!(
-                std::any::type_name::<usize>(),
+                core::any::type_name::<usize>(),
                 info.field_at(1).unwrap().type_name()
             );
         } else {
@@ -739,9 +2216,9 @@ mod tests {
         let info = MyTupleStruct::type_info();
         if let TypeInfo::TupleStruct(info) = info {
             assert!(info.is::<MyTupleStruct>());
-            assert_eq!(std::any::type_name::<MyTupleStruct>(), info.type_name());
+            assert_eq!(core::any::type_name::<MyTupleStruct>(), info.type_name());
             assert_eq!(
-                std::any::type_name::<i32>(),
+                core::any::type_name::<i32>(),
                 info.field_at(1).unwrap().type_name()
             );
             assert!(info.field_at(1).unwrap().is::<i32>());
@@ -755,9 +2232,9 @@ mod tests {
         let info = MyTuple::type_info();
         if let TypeInfo::Tuple(info) = info {
             assert!(info.is::<MyTuple>());
-            assert_eq!(std::any::type_name::<MyTuple>(), info.type_name());
+            assert_eq!(core::any::type_name::<MyTuple>(), info.type_name());
             assert_eq!(
-                std::any::type_name::<f32>(),
+                core::any::type_name::<f32>(),
                 info.field_at(1).unwrap().type_name()
             );
         } else {
@@ -775,8 +2252,8 @@ mod tests {
         if let TypeInfo::List(info) = info {
             assert!(info.is::<MyList>());
             assert!(info.item_is::<usize>());
-            assert_eq!(std::any::type_name::<MyList>(), info.type_name());
-            assert_eq!(std::any::type_name::<usize>(), info.item_type_name());
+            assert_eq!(core::any::type_name::<MyList>(), info.type_name());
+            assert_eq!(core::any::type_name::<usize>(), info.item_type_name());
         } else {
             panic!("Expected `TypeInfo::List`");
         }
@@ -794,8 +2271,8 @@ mod tests {
             if let TypeInfo::List(info) = info {
                 assert!(info.is::<MySmallVec>());
                 assert!(info.item_is::<String>());
-                assert_eq!(std::any::type_name::<MySmallVec>(), info.type_name());
-                assert_eq!(std::any::type_name::<String>(), info.item_type_name());
+                assert_eq!(core::any::type_name::<MySmallVec>(), info.type_name());
+                assert_eq!(core::any::type_name::<String>(), info.item_type_name());
             } else {
                 panic!("Expected `TypeInfo::List`");
             }
@@ -813,8 +2290,8 @@ mod tests {
         if let TypeInfo::Array(info) = info {
             assert!(info.is::<MyArray>());
             assert!(info.item_is::<usize>());
-            assert_eq!(std::any::type_name::<MyArray>(), info.type_name());
-            assert_eq!(std::any::type_name::<usize>(), info.item_type_name());
+            assert_eq!(core::any::type_name::<MyArray>(), info.type_name());
+            assert_eq!(core::any::type_name::<usize>(), info.item_type_name());
             assert_eq!(3, info.capacity());
         } else {
             panic!("Expected `TypeInfo::Array`");
@@ -832,9 +2309,9 @@ mod tests {
             assert!(info.is::<MyMap>());
             assert!(info.key_is::<usize>());
             assert!(info.value_is::<f32>());
-            assert_eq!(std::any::type_name::<MyMap>(), info.type_name());
-            assert_eq!(std::any::type_name::<usize>(), info.key_type_name());
-            assert_eq!(std::any::type_name::<f32>(), info.value_type_name());
+            assert_eq!(core::any::type_name::<MyMap>(), info.type_name());
+            assert_eq!(core::any::type_name::<usize>(), info.key_type_name());
+            assert_eq!(core::any::type_name::<f32>(), info.value_type_name());
         } else {
             panic!("Expected `TypeInfo::Map`");
         }
@@ -849,7 +2326,7 @@ mod tests {
         let info = MyValue::type_info();
         if let TypeInfo::Value(info) = info {
             assert!(info.is::<MyValue>());
-            assert_eq!(std::any::type_name::<MyValue>(), info.type_name());
+            assert_eq!(core::any::type_name::<MyValue>(), info.type_name());
         } else {
             panic!("Expected `TypeInfo::Value`");
         }
@@ -864,7 +2341,7 @@ mod tests {
         let info = MyDynamic::type_info();
         if let TypeInfo::Dynamic(info) = info {
             assert!(info.is::<MyDynamic>());
-            assert_eq!(std::any::type_name::<MyDynamic>(), info.type_name());
+            assert_eq!(core::any::type_name::<MyDynamic>(), info.type_name());
         } else {
             panic!("Expected `TypeInfo::Dynamic`");
         }
@@ -874,6 +2351,545 @@ mod tests {
         assert!(info.is::<MyDynamic>());
     }
 
+    #[test]
+    fn should_reflect_non_send_types() {
+        use alloc::rc::Rc;
+        use core::cell::{Cell, RefCell};
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        struct Texture {
+            width: u32,
+        }
+
+        #[derive(NonSendReflect)]
+        struct Handle(Rc<Texture>, #[reflect(ignore)] Rc<()>);
+
+        #[derive(NonSendReflect)]
+        struct Sprite {
+            name: RefCell<String>,
+            frame: Cell<u8>,
+            handle: Handle,
+        }
+
+        let shared = Rc::new(Texture { width: 16 });
+        let marker = Rc::new(());
+        let mut sprite = Sprite {
+            name: RefCell::new(String::from("player")),
+            frame: Cell::new(1),
+            handle: Handle(shared.clone(), marker.clone()),
+        };
+
+        let TypeInfo::Struct(info) = Sprite::non_send_type_info() else {
+            panic!("expected `TypeInfo::Struct`");
+        };
+        assert!(info.is::<Sprite>());
+        assert!(info.field("name").unwrap().is::<String>());
+        assert!(info.field("frame").unwrap().is::<u8>());
+        assert!(info.field("handle").unwrap().is::<Handle>());
+
+        let mut registry = TypeRegistry::default();
+        registry.register_non_send::<Sprite>();
+        registry.register_non_send::<Handle>();
+        registry.register::<Texture>();
+        registry.register::<String>();
+
+        let dynamic = sprite.to_dynamic();
+        let serializer = ReflectSerializer::new(&*dynamic, &registry);
+        let serialized = ron::to_string(&serializer).unwrap();
+        let serialized = serialized.replace("16", "32").replace("player", "enemy");
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let value = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        sprite.apply_dynamic(&*value);
+
+        assert_eq!("enemy", *sprite.name.borrow());
+        assert_eq!(1, sprite.frame.get());
+        assert_eq!(32, sprite.handle.0.width);
+        // The shared texture is cloned rather than changed in place
+        assert_eq!(16, shared.width);
+        // Ignored fields are left untouched
+        assert!(Rc::ptr_eq(&marker, &sprite.handle.1));
+    }
+
+    #[test]
+    fn should_record_field_layouts() {
+        use core::alloc::Layout;
+
+        #[derive(Reflect)]
+        struct Foo<T: Reflect> {
+            a: u8,
+            #[reflect(ignore)]
+            _b: u16,
+            c: T,
+            #[reflect(dynamic)]
+            d: Box<dyn Reflect>,
+        }
+
+        #[derive(Reflect)]
+        struct Bar(u8, u64);
+
+        fn offset_of<T, F>(container: &T, field: &F) -> usize {
+            field as *const F as usize - container as *const T as usize
+        }
+
+        let foo = Foo {
+            a: 1,
+            _b: 2,
+            c: 3_u32,
+            d: Box::new(4_i32),
+        };
+        let TypeInfo::Struct(info) = foo.get_type_info() else {
+            panic!("expected `TypeInfo::Struct`");
+        };
+        let a = info.field("a").unwrap();
+        assert_eq!(Some(offset_of(&foo, &foo.a)), a.offset());
+        assert_eq!(Some(Layout::new::<u8>()), a.layout());
+        let c = info.field("c").unwrap();
+        assert_eq!(Some(offset_of(&foo, &foo.c)), c.offset());
+        assert_eq!(Some(Layout::new::<u32>()), c.layout());
+        // Dynamic fields are not described by the type they are stored as
+        assert_eq!(None, info.field("d").unwrap().offset());
+
+        let bar = Bar(1, 2);
+        let TypeInfo::TupleStruct(info) = Bar::type_info() else {
+            panic!("expected `TypeInfo::TupleStruct`");
+        };
+        let field = info.field_at(1).unwrap();
+        assert_eq!(Some(offset_of(&bar, &bar.1)), field.offset());
+        assert_eq!(Some(Layout::new::<u64>()), field.layout());
+    }
+
+    #[test]
+    fn should_record_type_capabilities() {
+        use alloc::rc::Rc;
+
+        #[derive(Reflect, Clone, Copy)]
+        struct Foo<T: Reflect>(T);
+
+        #[derive(Reflect)]
+        enum Bar {
+            A(String),
+        }
+
+        #[derive(NonSendReflect)]
+        struct Baz {
+            #[reflect(ignore)]
+            _handle: Rc<()>,
+        }
+
+        let capabilities = Foo::<u8>::type_info().capabilities().unwrap();
+        assert!(capabilities.is_send && capabilities.is_sync);
+        // Generic types are only known to be `Copy` if their bounds say so
+        assert!(!capabilities.is_copy);
+        assert!(!capabilities.needs_drop);
+
+        let capabilities = Bar::type_info().capabilities().unwrap();
+        assert!(!capabilities.is_copy);
+        assert!(capabilities.needs_drop);
+
+        let capabilities = Baz::non_send_type_info().capabilities().unwrap();
+        assert!(!capabilities.is_send && !capabilities.is_sync);
+        assert!(capabilities.needs_drop);
+
+        assert!(Vec::<u8>::type_info().capabilities().is_none());
+    }
+
+    #[test]
+    fn should_build_dynamics_from_type_info() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Foo {
+            a: u32,
+            b: Bar,
+            c: Baz,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Bar(String, f32);
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq, Default)]
+        #[reflect(Default)]
+        enum Baz {
+            #[default]
+            A,
+            B(u32),
+        }
+
+        #[derive(Reflect)]
+        struct Qux(Baz, Quux);
+
+        #[derive(Reflect)]
+        enum Quux {
+            A,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<u32>();
+        registry.register::<f32>();
+        registry.register::<String>();
+        registry.register::<Bar>();
+        registry.register::<Baz>();
+        registry.register::<Quux>();
+
+        let TypeInfo::Struct(info) = Foo::type_info() else {
+            panic!("expected struct info");
+        };
+        let mut foo = DynamicStruct::from_type_info(info, &registry).unwrap();
+        assert_eq!(Foo::type_info().type_name(), foo.name());
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("a", 123_u32);
+        foo.apply(&patch);
+
+        let expected = Foo {
+            a: 123,
+            b: Bar(String::new(), 0.0),
+            c: Baz::A,
+        };
+        assert_eq!(Some(expected), Foo::from_reflect(&foo));
+
+        let TypeInfo::Enum(baz_info) = Baz::type_info() else {
+            panic!("expected enum info");
+        };
+        let baz = DynamicEnum::from_type_info(baz_info, &registry).unwrap();
+        assert_eq!(Some(Baz::A), Baz::from_reflect(&baz));
+
+        let TypeInfo::TupleStruct(info) = Qux::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert_eq!(
+            Err(DefaultValueError::NoDefault(Quux::type_info().type_name())),
+            DynamicTupleStruct::from_type_info(info, &registry).map(|_| ())
+        );
+
+        assert_eq!(
+            Err(DefaultValueError::NotRegistered(baz_info.type_name())),
+            DynamicEnum::from_type_info(baz_info, &TypeRegistry::default()).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn should_take_fields_from_dynamic_structs() {
+        #[derive(Reflect)]
+        struct Player {
+            name: String,
+        }
+
+        let name = String::from("moved");
+        let name_ptr = name.as_ptr();
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_inline_values(true);
+        dynamic.insert("name", name);
+        dynamic.insert("level", 3_u8);
+        dynamic.insert("score", 10_u32);
+
+        let value: &mut dyn Struct = &mut dynamic;
+        let name = value.take_field("name").unwrap();
+        assert_eq!(name_ptr, name.downcast_ref::<String>().unwrap().as_ptr());
+        // Inline values are boxed when taken
+        assert_eq!(
+            Some(&3),
+            value.take_field("level").unwrap().downcast_ref::<u8>()
+        );
+        assert!(value.take_field("level").is_none());
+
+        assert_eq!(1, dynamic.field_len());
+        assert_eq!(Some("score"), dynamic.name_at(0));
+        assert_eq!(Some(&10), dynamic.get_field::<u32>("score"));
+        dynamic.insert("level", 4_u8);
+        assert_eq!(Some(1), dynamic.index_of("level"));
+
+        let mut player = Player {
+            name: String::from("kept"),
+        };
+        assert!(player.take_field("name").is_none());
+        assert_eq!("kept", player.name);
+    }
+
+    #[test]
+    fn should_drain_tuple_struct_fields() {
+        #[derive(Reflect)]
+        #[reflect(drain)]
+        struct Label(
+            String,
+            #[reflect(ignore)] (),
+            #[reflect(get = "Label::size", set = "Label::set_size")] u32,
+            #[reflect(dynamic)] Box<dyn Reflect>,
+        );
+
+        impl Label {
+            fn size(&self) -> &u32 {
+                &self.2
+            }
+
+            fn set_size(&mut self, size: u32) {
+                self.2 = size;
+            }
+        }
+
+        #[derive(Reflect)]
+        struct Guard(String);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {}
+        }
+
+        let text = String::from("moved");
+        let text_ptr = text.as_ptr();
+        let label: Box<dyn TupleStruct> = Box::new(Label(text, (), 2, Box::new(3_i8)));
+        let fields = label.drain();
+        assert_eq!(3, fields.len());
+        // The owned fields are moved out rather than cloned
+        let text = fields[0].downcast_ref::<String>().unwrap();
+        assert_eq!(text_ptr, text.as_ptr());
+        assert_eq!(Some(&2), fields[1].downcast_ref::<u32>());
+        assert_eq!(Some(&3), fields[2].downcast_ref::<i8>());
+
+        let guard: Box<dyn TupleStruct> = Box::new(Guard(String::from("cloned")));
+        let fields = guard.drain();
+        assert_eq!(Some(&String::from("cloned")), fields[0].downcast_ref());
+
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.insert(String::from("dynamic"));
+        dynamic.insert(4_u64);
+        let fields = Box::new(dynamic).drain();
+        assert_eq!(Some(&String::from("dynamic")), fields[0].downcast_ref());
+        assert_eq!(Some(&4), fields[1].downcast_ref::<u64>());
+    }
+
+    #[test]
+    fn should_build_enum_variants_from_name() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        #[reflect(Default)]
+        enum Brush {
+            Eraser,
+            Round(f32, Color),
+            Pattern { color: Color, name: String },
+        }
+
+        impl Default for Brush {
+            fn default() -> Self {
+                Brush::Round(4.0, Color(255))
+            }
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq, Default)]
+        #[reflect(Default)]
+        struct Color(u8);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Brush>();
+        registry.register::<Color>();
+        registry.register::<String>();
+
+        let TypeInfo::Enum(info) = Brush::type_info() else {
+            panic!("expected enum info");
+        };
+        let from_name = |name| {
+            DynamicEnum::from_variant_name(info, &registry, name)
+                .map(|value| Brush::from_reflect(&value).unwrap())
+        };
+
+        assert_eq!(Ok(Brush::Eraser), from_name("Eraser"));
+        // The registered default provides the fields of its own variant
+        assert_eq!(Ok(Brush::Round(4.0, Color(255))), from_name("Round"));
+        assert_eq!(
+            Ok(Brush::Pattern {
+                color: Color(0),
+                name: String::new()
+            }),
+            from_name("Pattern")
+        );
+        assert_eq!(
+            2,
+            DynamicEnum::from_variant_name(info, &registry, "Pattern")
+                .unwrap()
+                .variant_index()
+        );
+
+        assert_eq!(
+            Err(DefaultValueError::UnknownVariant {
+                type_name: info.type_name(),
+                variant: String::from("Square"),
+            }),
+            from_name("Square")
+        );
+        assert_eq!(
+            Err(DefaultValueError::NotRegistered(
+                Color::type_info().type_name()
+            )),
+            DynamicEnum::from_variant_name(info, &TypeRegistry::default(), "Pattern").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn should_record_custom_attributes() {
+        use crate::attributes::{Category, Multiline, Range, Step, Tooltip};
+
+        #[derive(Debug, PartialEq)]
+        struct Unit(&'static str);
+
+        #[derive(Reflect)]
+        struct Foo {
+            #[reflect(@Range::new(0.0, 1.0), @Step(0.1))]
+            #[reflect(@Unit("m"))]
+            a: f32,
+            #[reflect(@Tooltip("The name"), @Category("General"), @Multiline)]
+            b: String,
+            c: u8,
+        }
+
+        #[derive(Reflect)]
+        struct Bar<T: Reflect>(#[reflect(@Tooltip("The value"))] T);
+
+        #[derive(Reflect)]
+        enum Baz {
+            A {
+                #[reflect(@Category("Variant"))]
+                value: u32,
+            },
+        }
+
+        let TypeInfo::Struct(info) = Foo::type_info() else {
+            panic!("expected struct info");
+        };
+        let a = info.field("a").unwrap();
+        assert_eq!(Some(&Range::new(0.0, 1.0)), a.range());
+        assert_eq!(Some(0.1), a.step());
+        assert_eq!(Some(&Unit("m")), a.attributes().get::<Unit>());
+        assert_eq!(3, a.attributes().len());
+        assert_eq!(None, a.tooltip());
+
+        let b = info.field("b").unwrap();
+        assert_eq!(Some("The name"), b.tooltip());
+        assert_eq!(Some("General"), b.category());
+        assert!(b.is_multiline());
+        assert!(info.field("c").unwrap().attributes().is_empty());
+
+        let TypeInfo::TupleStruct(info) = Bar::<u8>::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert_eq!(Some("The value"), info.field_at(0).unwrap().tooltip());
+
+        let TypeInfo::Enum(info) = Baz::type_info() else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Struct(variant) = info.variant("A").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert_eq!(Some("Variant"), variant.field("value").unwrap().category());
+    }
+
+    #[test]
+    fn should_reflect_without_type_info() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(no_type_info)]
+        struct Foo {
+            a: u32,
+            b: Bar<String>,
+        }
+
+        /// A generic tuple struct.
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(no_type_info)]
+        struct Bar<T: Reflect>(T);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(no_type_info)]
+        enum Baz {
+            A,
+            B(u32),
+        }
+
+        assert!(matches!(Foo::type_info(), TypeInfo::Value(info) if info.is::<Foo>()));
+        assert!(matches!(
+            Bar::<String>::type_info(),
+            TypeInfo::Value(info) if info.is::<Bar<String>>()
+        ));
+        assert!(matches!(Baz::type_info(), TypeInfo::Value(info) if info.is::<Baz>()));
+        #[cfg(feature = "documentation")]
+        assert_eq!(None, Bar::<String>::type_info().docs());
+
+        // The rest of the reflection traits are unaffected
+        let mut foo = Foo {
+            a: 1,
+            b: Bar(String::from("a")),
+        };
+        let mut patch = DynamicStruct::default();
+        patch.insert("a", 2_u32);
+        foo.apply(&patch);
+        assert_eq!(2, foo.a);
+        assert_eq!(Some(true), foo.reflect_partial_eq(&*foo.clone_value()));
+
+        let mut baz = Baz::A;
+        baz.apply(&Baz::B(3));
+        assert_eq!(Baz::B(3), baz);
+    }
+
+    #[test]
+    fn should_build_type_info_statically() {
+        #[derive(Reflect)]
+        struct Settings {
+            volume: f32,
+            fullscreen: bool,
+            #[reflect(ignore)]
+            _dirty: bool,
+            name: String,
+        }
+
+        #[derive(Reflect)]
+        enum Shape {
+            Circle { radius: f32 },
+            Square(f32),
+            Empty,
+        }
+
+        #[derive(Reflect)]
+        struct Buffer {
+            data: [u8; Self::SIZE],
+        }
+
+        impl Buffer {
+            const SIZE: usize = 4;
+        }
+
+        let TypeInfo::Struct(info) = Settings::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(&["volume", "fullscreen", "name"], info.field_names());
+        assert_eq!(Some(1), info.index_of("fullscreen"));
+        assert_eq!(Some(2), info.index_of("name"));
+        assert_eq!(None, info.index_of("_dirty"));
+        assert!(info.field("volume").unwrap().is::<f32>());
+        assert!(info.is::<Settings>());
+
+        let TypeInfo::Enum(info) = Shape::type_info() else {
+            panic!("expected enum info");
+        };
+        assert_eq!(&["Circle", "Square", "Empty"], info.variant_names());
+        assert_eq!(Some(2), info.index_of("Empty"));
+        assert!(info.contains_variant("Square"));
+        let VariantInfo::Struct(variant) = info.variant("Circle").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert!(variant.field("radius").unwrap().is::<f32>());
+
+        // Types referring to `Self` in their fields build their info on first access instead
+        let TypeInfo::Struct(info) = Buffer::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("data").unwrap().is::<[u8; 4]>());
+        assert!(core::ptr::eq(Buffer::type_info(), Buffer::type_info()));
+    }
+
+    #[test]
+    #[should_panic(expected = "names must be sorted")]
+    fn should_reject_unsorted_static_type_info() {
+        static FIELDS: &[NamedField] = &[NamedField::new::<u8>("a"), NamedField::new::<u8>("b")];
+        StructInfo::from_static::<DynamicStruct>("Foo", FIELDS, &["a", "b"], &[("b", 1), ("a", 0)]);
+    }
+
     #[cfg(feature = "documentation")]
     mod docstrings {
         use super::*;
@@ -1026,6 +3042,47 @@ mod tests {
                 panic!("expected enum info");
             }
         }
+
+        #[test]
+        fn mirrored_types_should_contain_docs() {
+            #[derive(Default)]
+            struct Foreign {
+                value: f32,
+            }
+
+            impl_reflect_struct!(
+                /// Some foreign struct.
+                #[reflect(Default)]
+                struct Foreign {
+                    /// The value
+                    value: f32,
+                }
+            );
+
+            enum ForeignEnum {
+                A(u8),
+            }
+
+            impl_reflect_enum!(
+                /// Some foreign enum.
+                enum ForeignEnum {
+                    /// Option A
+                    A(u8),
+                }
+            );
+
+            let TypeInfo::Struct(info) = <Foreign as Typed>::type_info() else {
+                panic!("expected struct info");
+            };
+            assert_eq!(Some(" Some foreign struct."), info.docs());
+            assert_eq!(Some(" The value"), info.field("value").unwrap().docs());
+
+            let TypeInfo::Enum(info) = <ForeignEnum as Typed>::type_info() else {
+                panic!("expected enum info");
+            };
+            assert_eq!(Some(" Some foreign enum."), info.docs());
+            assert_eq!(Some(" Option A"), info.variant("A").unwrap().docs());
+        }
     }
 
     #[test]
@@ -1096,7 +3153,7 @@ mod tests {
         #[reflect(Debug)]
         struct CustomDebug;
         impl Debug for CustomDebug {
-            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
                 f.write_str("Cool debug!")
             }
         }
@@ -1156,6 +3213,44 @@ bevy_reflect::tests::should_reflect_debug::Test {
         assert_eq!(expected, format!("\n{reflected:#?}"));
     }
 
+    fn nested_list(depth: usize) -> DynamicList {
+        let mut list = DynamicList::default();
+        for _ in 1..depth {
+            let mut outer = DynamicList::default();
+            outer.push_box(Box::new(list));
+            list = outer;
+        }
+        list
+    }
+
+    #[test]
+    fn should_visit_deeply_nested_values() {
+        let list = nested_list(4 * CYCLE_CHECK_DEPTH);
+        assert!(!format!("{:?}", &list as &dyn Reflect).contains("..."));
+
+        let mut clone = list.clone_value();
+        assert!(clone.reflect_partial_eq(&list).unwrap_or_default());
+        clone.apply(&list);
+    }
+
+    #[test]
+    fn should_detect_values_containing_themselves() {
+        fn visit(value: &dyn Reflect, visited: &mut usize) -> Result<(), CycleError> {
+            *visited += 1;
+            guard_recursion(value, || visit(value, visited))?
+        }
+
+        let mut visited = 0;
+        assert_eq!(Err(CycleError), visit(&1_u8, &mut visited));
+        // The cycle is found as soon as the value is tracked twice
+        assert_eq!(CYCLE_CHECK_DEPTH + 2, visited);
+
+        // Nothing is left tracked once the visits end
+        let list = nested_list(4 * CYCLE_CHECK_DEPTH);
+        assert!(guard_recursion(&list, || ()).is_ok());
+        assert!(!format!("{:?}", &list as &dyn Reflect).contains("..."));
+    }
+
     #[test]
     fn multiple_reflect_lists() {
         #[derive(Hash, PartialEq, Reflect)]
@@ -1164,7 +3259,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
         struct Foo(i32);
 
         impl Debug for Foo {
-            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
                 write!(f, "Foo")
             }
         }
@@ -1185,7 +3280,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
         struct Foo(i32);
 
         impl Debug for Foo {
-            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
                 write!(f, "Foo")
             }
         }
@@ -1294,5 +3389,58 @@ bevy_reflect::tests::should_reflect_debug::Test {
 
             assert_eq!(v, vec3(4.0, 2.0, 1.0));
         }
+
+        #[test]
+        fn quat_field_access() {
+            use ::glam::Quat;
+
+            let mut q = Quat::IDENTITY;
+            assert!(matches!(Quat::type_info(), TypeInfo::Struct(_)));
+            assert_eq!(*q.get_field::<f32>("w").unwrap(), 1.0);
+
+            *q.path_mut("x").unwrap().downcast_mut::<f32>().unwrap() = 1.0;
+            *q.get_field_mut::<f32>("w").unwrap() = 0.0;
+            assert_eq!(q, Quat::from_xyzw(1.0, 0.0, 0.0, 0.0));
+
+            let mut d = DynamicStruct::default();
+            d.insert("y", 1.0f32);
+            d.insert("x", 0.0f32);
+            q.apply(&d);
+            assert_eq!(q, Quat::from_xyzw(0.0, 1.0, 0.0, 0.0));
+            assert_eq!(
+                Some(q),
+                <Quat as FromReflect>::from_reflect(&*q.clone_value())
+            );
+        }
+
+        #[test]
+        fn matrix_path_access() {
+            use ::glam::{Affine2, Mat2};
+
+            let mut m = Affine2::IDENTITY;
+            *m.path_mut("matrix2.x_axis.y")
+                .unwrap()
+                .downcast_mut::<f32>()
+                .unwrap() = 2.0;
+            assert_eq!(m.matrix2, Mat2::from_cols_array(&[1.0, 2.0, 0.0, 1.0]));
+        }
+
+        #[test]
+        fn euler_rot_enum_reflection() {
+            use ::glam::EulerRot;
+
+            let mut rot = EulerRot::XYZ;
+            assert_eq!("XYZ", rot.variant_name());
+            assert!(matches!(EulerRot::type_info(), TypeInfo::Enum(_)));
+
+            let dyn_enum = DynamicEnum::new(Reflect::type_name(&rot), "ZYX", ());
+            rot.apply(&dyn_enum);
+            assert_eq!(EulerRot::ZYX, rot);
+
+            assert_eq!(
+                Some(EulerRot::ZYX),
+                <EulerRot as FromReflect>::from_reflect(&dyn_enum)
+            );
+        }
     }
 }
@@ -1,10 +1,21 @@
-use std::any::{Any, TypeId};
-use std::fmt::{Debug, Formatter};
+use crate::allocation_stats::{record_allocations, AllocationSource};
+use crate::cycle::{debug_guarded, expect_guarded};
+use crate::debug_options::{shown_elements, write_type_path, Omitted};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::{Any, TypeId};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
 
+use crate::array::check_index;
+use crate::reflect::iter_partial_cmp;
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    Array, ArrayIter, DynamicArray, DynamicInfo, FromReflect, Reflect, ReflectMut, ReflectOwned,
-    ReflectRef, TypeInfo, Typed,
+    Array, ArrayError, ArrayIter, DynamicArray, DynamicInfo, FromReflect, Reflect, ReflectMut,
+    ReflectOwned, ReflectRef, TypeInfo, Typed,
 };
 
 /// An ordered, mutable list of [Reflect] items. This corresponds to types like [`std::vec::Vec`].
@@ -47,12 +58,71 @@ pub trait List: Reflect + Array {
         }
     }
 
+    /// Reserves capacity for at least `additional` more elements to be inserted into the list.
+    ///
+    /// This is only a hint, so the default implementation does nothing.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Retains only the elements for which `keep` returns `true`, keeping their order.
+    fn retain(&mut self, keep: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        let mut index = 0;
+        while let Some(value) = self.get(index) {
+            if keep(value) {
+                index += 1;
+            } else {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Appends each of `values` to the _back_ of the list, in order.
+    ///
+    /// This is not called `extend`, so as not to be ambiguous with [`Extend::extend`]
+    /// for lists which implement both.
+    ///
+    /// # Panics
+    /// Panics if a value cannot be [pushed](List::push) to the list.
+    fn extend_boxed(&mut self, values: Vec<Box<dyn Reflect>>) {
+        self.reserve(values.len());
+        for value in values {
+            self.push(value);
+        }
+    }
+
+    /// Sorts the list with the comparator `compare`.
+    ///
+    /// The sort is stable, so equal elements keep their order.
+    /// Unlike [`list_sort`], this does not require the elements to implement
+    /// [`Reflect::reflect_partial_cmp`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{List, Reflect};
+    /// let mut list: Box<dyn List> = Box::new(vec![3_u32, 1, 2]);
+    /// list.sort_by_reflect(&mut |a, b| {
+    ///     a.reflect_partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+    /// });
+    /// assert_eq!(Some(&vec![1, 2, 3]), list.as_any().downcast_ref::<Vec<u32>>());
+    /// ```
+    fn sort_by_reflect(&mut self, compare: &mut dyn FnMut(&dyn Reflect, &dyn Reflect) -> Ordering) {
+        let mut values = Vec::with_capacity(self.len());
+        while let Some(value) = self.pop() {
+            values.push(value);
+        }
+        values.reverse();
+        values.sort_by(|a, b| compare(&**a, &**b));
+        List::extend_boxed(self, values);
+    }
+
     /// Clones the list, producing a [`DynamicList`].
     fn clone_dynamic(&self) -> DynamicList {
-        DynamicList {
+        record_allocations(AllocationSource::CloneDynamic, self.len());
+        expect_guarded(self.as_reflect(), "clone", || DynamicList {
             name: self.type_name().to_string(),
             values: self.iter().map(|value| value.clone_value()).collect(),
-        }
+        })
     }
 }
 
@@ -71,9 +141,9 @@ impl ListInfo {
     /// Create a new [`ListInfo`].
     pub fn new<TList: List, TItem: FromReflect>() -> Self {
         Self {
-            type_name: std::any::type_name::<TList>(),
+            type_name: core::any::type_name::<TList>(),
             type_id: TypeId::of::<TList>(),
-            item_type_name: std::any::type_name::<TItem>(),
+            item_type_name: core::any::type_name::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -186,15 +256,29 @@ impl Array for DynamicList {
         self.values
     }
 
+    fn set_element(&mut self, index: usize, value: Box<dyn Reflect>) -> Result<(), ArrayError> {
+        check_index(index, self.values.len())?;
+        self.values[index] = value;
+        Ok(())
+    }
+
+    fn swap_elements(&mut self, a: usize, b: usize) -> Result<(), ArrayError> {
+        check_index(a, self.values.len())?;
+        check_index(b, self.values.len())?;
+        self.values.swap(a, b);
+        Ok(())
+    }
+
     fn clone_dynamic(&self) -> DynamicArray {
-        DynamicArray {
+        record_allocations(AllocationSource::CloneDynamic, self.values.len());
+        expect_guarded(self.as_reflect(), "clone", || DynamicArray {
             name: self.name.clone(),
             values: self
                 .values
                 .iter()
                 .map(|value| value.clone_value())
                 .collect(),
-        }
+        })
     }
 }
 
@@ -215,15 +299,32 @@ impl List for DynamicList {
         self.values.pop()
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        self.values.retain(|value| keep(&**value));
+    }
+
+    fn extend_boxed(&mut self, values: Vec<Box<dyn Reflect>>) {
+        self.values.extend(values);
+    }
+
+    fn sort_by_reflect(&mut self, compare: &mut dyn FnMut(&dyn Reflect, &dyn Reflect) -> Ordering) {
+        self.values.sort_by(|a, b| compare(&**a, &**b));
+    }
+
     fn clone_dynamic(&self) -> DynamicList {
-        DynamicList {
+        record_allocations(AllocationSource::CloneDynamic, self.values.len());
+        expect_guarded(self.as_reflect(), "clone", || DynamicList {
             name: self.name.clone(),
             values: self
                 .values
                 .iter()
                 .map(|value| value.clone_value())
                 .collect(),
-        }
+        })
     }
 }
 
@@ -307,7 +408,11 @@ impl Reflect for DynamicList {
         list_partial_eq(self, value)
     }
 
-    fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, value)
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "DynamicList(")?;
         list_debug(self, f)?;
         write!(f, ")")
@@ -315,7 +420,7 @@ impl Reflect for DynamicList {
 }
 
 impl Debug for DynamicList {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.debug(f)
     }
 }
@@ -329,36 +434,55 @@ impl Typed for DynamicList {
 
 impl IntoIterator for DynamicList {
     type Item = Box<dyn Reflect>;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.values.into_iter()
     }
 }
 
+impl FromIterator<Box<dyn Reflect>> for DynamicList {
+    fn from_iter<I: IntoIterator<Item = Box<dyn Reflect>>>(values: I) -> Self {
+        Self {
+            name: String::default(),
+            values: values.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Box<dyn Reflect>> for DynamicList {
+    fn extend<I: IntoIterator<Item = Box<dyn Reflect>>>(&mut self, values: I) {
+        self.values.extend(values);
+    }
+}
+
 /// Applies the elements of `b` to the corresponding elements of `a`.
 ///
 /// If the length of `b` is greater than that of `a`, the excess elements of `b`
-/// are cloned and appended to `a`.
+/// are cloned and appended to `a`, after reserving space for all of them.
 ///
 /// # Panics
 ///
 /// This function panics if `b` is not a list.
 #[inline]
 pub fn list_apply<L: List>(a: &mut L, b: &dyn Reflect) {
-    if let ReflectRef::List(list_value) = b.reflect_ref() {
-        for (i, value) in list_value.iter().enumerate() {
-            if i < a.len() {
-                if let Some(v) = a.get_mut(i) {
-                    v.apply(value);
+    expect_guarded(b, "apply", || {
+        if let ReflectRef::List(list_value) = b.reflect_ref() {
+            List::reserve(a, list_value.len().saturating_sub(a.len()));
+            for (i, value) in list_value.iter().enumerate() {
+                if i < a.len() {
+                    if let Some(v) = a.get_mut(i) {
+                        v.apply(value);
+                    }
+                } else {
+                    record_allocations(AllocationSource::Apply, 1);
+                    List::push(a, value.clone_value());
                 }
-            } else {
-                List::push(a, value.clone_value());
             }
+        } else {
+            panic!("Attempted to apply a non-list type to a list type.");
         }
-    } else {
-        panic!("Attempted to apply a non-list type to a list type.");
-    }
+    });
 }
 
 /// Compares a [`List`] with a [`Reflect`] value.
@@ -389,6 +513,56 @@ pub fn list_partial_eq<L: List>(a: &L, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Compares a [`List`] with a [`Reflect`] value lexicographically,
+/// like [`PartialOrd`] does for slices.
+///
+/// Returns [`None`] if `b` is not a list, or if [`Reflect::reflect_partial_cmp`]
+/// returns `None` for a pair of elements compared.
+#[inline]
+pub fn list_partial_cmp<L: List>(a: &L, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::List(list) = b.reflect_ref() else {
+        return None;
+    };
+    iter_partial_cmp(a.iter(), list.iter())
+}
+
+/// Sorts the elements of a [`List`] with [`Reflect::reflect_partial_cmp`].
+///
+/// The sort is stable. Returns `false`, leaving the list unchanged,
+/// if any two elements cannot be compared.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{list_sort, List};
+/// let mut list = vec![3_u32, 1, 2];
+/// assert!(list_sort(&mut list));
+/// assert_eq!(vec![1, 2, 3], list);
+/// ```
+pub fn list_sort(list: &mut dyn List) -> bool {
+    let mut order = (0..list.len()).collect::<Vec<_>>();
+    let mut comparable = true;
+    order.sort_by(|a, b| {
+        let a = list.get(*a).unwrap();
+        a.reflect_partial_cmp(list.get(*b).unwrap())
+            .unwrap_or_else(|| {
+                comparable = false;
+                Ordering::Equal
+            })
+    });
+    if !comparable {
+        return false;
+    }
+
+    let mut items = (0..list.len()).map(|_| None).collect::<Vec<_>>();
+    for index in (0..list.len()).rev() {
+        items[index] = Some(list.remove(index));
+    }
+    for index in order {
+        list.push(items[index].take().unwrap());
+    }
+    true
+}
+
 /// The default debug formatter for [`List`] types.
 ///
 /// # Example
@@ -407,17 +581,30 @@ pub fn list_partial_eq<L: List>(a: &L, b: &dyn Reflect) -> Option<bool> {
 /// // ]
 /// ```
 #[inline]
-pub fn list_debug(dyn_list: &dyn List, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut debug = f.debug_list();
-    for item in dyn_list.iter() {
-        debug.entry(&item as &dyn Debug);
-    }
-    debug.finish()
+pub fn list_debug(dyn_list: &dyn List, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    debug_guarded(dyn_list.as_reflect(), f, |f| {
+        write_type_path(dyn_list.as_reflect(), f)?;
+        let mut debug = f.debug_list();
+        let shown = shown_elements(dyn_list.len());
+        for item in dyn_list.iter().take(shown) {
+            debug.entry(&item as &dyn Debug);
+        }
+        if shown < dyn_list.len() {
+            debug.entry(&Omitted(dyn_list.len() - shown));
+        }
+        debug.finish()
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::DynamicList;
+    use crate::{
+        self as bevy_reflect, list_apply, list_sort, Array, ArrayIter, FromReflect, List, Reflect,
+        ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+    };
+    use core::any::Any;
+    use core::cmp::Ordering;
     use std::assert_eq;
 
     #[test]
@@ -432,4 +619,240 @@ mod tests {
             assert_eq!(index, value);
         }
     }
+
+    /// A list recording the space it is asked to reserve.
+    #[derive(Default)]
+    struct ReservingList {
+        list: DynamicList,
+        reserved: Vec<usize>,
+    }
+
+    impl Array for ReservingList {
+        fn get(&self, index: usize) -> Option<&dyn Reflect> {
+            self.list.get(index)
+        }
+
+        fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+            self.list.get_mut(index)
+        }
+
+        fn len(&self) -> usize {
+            self.list.len()
+        }
+
+        fn iter(&self) -> ArrayIter {
+            ArrayIter {
+                array: self,
+                index: 0,
+            }
+        }
+
+        fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+            Box::new(self.list).drain()
+        }
+    }
+
+    impl List for ReservingList {
+        fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+            self.list.insert(index, element);
+        }
+
+        fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+            self.list.remove(index)
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.reserved.push(additional);
+            self.list.reserve(additional);
+        }
+
+        fn clone_dynamic(&self) -> DynamicList {
+            List::clone_dynamic(&self.list)
+        }
+    }
+
+    impl Reflect for ReservingList {
+        fn type_name(&self) -> &str {
+            core::any::type_name::<Self>()
+        }
+
+        fn get_type_info(&self) -> &'static TypeInfo {
+            self.list.get_type_info()
+        }
+
+        fn into_any(self: Box<Self>) -> Box<dyn Any> {
+            self
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+
+        fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+            self
+        }
+
+        fn as_reflect(&self) -> &dyn Reflect {
+            self
+        }
+
+        fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+            self
+        }
+
+        fn apply(&mut self, value: &dyn Reflect) {
+            list_apply(self, value);
+        }
+
+        fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+            *self = value.take()?;
+            Ok(())
+        }
+
+        fn reflect_ref(&self) -> ReflectRef {
+            ReflectRef::List(self)
+        }
+
+        fn reflect_mut(&mut self) -> ReflectMut {
+            ReflectMut::List(self)
+        }
+
+        fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+            ReflectOwned::List(self)
+        }
+
+        fn clone_value(&self) -> Box<dyn Reflect> {
+            Box::new(List::clone_dynamic(self))
+        }
+    }
+
+    #[test]
+    fn should_reserve_space_when_applying() {
+        let mut list = ReservingList::default();
+        list.list.push(0_u64);
+        list.apply(&(0..100_u64).collect::<Vec<_>>());
+        assert_eq!(100, list.len());
+        // Space for all missing elements is reserved at once, rather than one at a time
+        assert_eq!(vec![99], list.reserved);
+
+        list.reserved.clear();
+        list.apply(&vec![1_u64; 10]);
+        assert_eq!(vec![0], list.reserved);
+    }
+
+    #[test]
+    fn should_sort_reflected_list() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Priority {
+            Low,
+            High(u8),
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Task {
+            priority: Priority,
+            name: String,
+        }
+
+        #[derive(Reflect, Debug, PartialEq, PartialOrd)]
+        #[reflect(PartialOrd)]
+        struct Version(u32, u32);
+
+        fn reverse_cmp(a: &Reversed, b: &dyn Reflect) -> Option<Ordering> {
+            Some(b.downcast_ref::<Reversed>()?.0.cmp(&a.0))
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        #[reflect(PartialOrd(reverse_cmp))]
+        struct Reversed(i32);
+
+        let task = |priority, name: &str| Task {
+            priority,
+            name: name.to_string(),
+        };
+        let mut tasks = vec![
+            task(Priority::High(2), "b"),
+            task(Priority::Low, "c"),
+            task(Priority::High(1), "d"),
+            task(Priority::High(2), "a"),
+        ];
+        assert!(list_sort(&mut tasks));
+        assert_eq!(
+            vec![
+                task(Priority::Low, "c"),
+                task(Priority::High(1), "d"),
+                task(Priority::High(2), "a"),
+                task(Priority::High(2), "b"),
+            ],
+            tasks
+        );
+
+        let mut list = DynamicList::default();
+        list.push(Version(1, 2));
+        list.push(Version(0, 9));
+        list.push(Version(1, 0));
+        assert!(list_sort(&mut list));
+        let versions = list.iter().map(|v| v.downcast_ref::<Version>().unwrap());
+        assert_eq!(
+            vec![&Version(0, 9), &Version(1, 0), &Version(1, 2)],
+            versions.collect::<Vec<_>>()
+        );
+
+        let mut reversed = vec![Reversed(1), Reversed(3), Reversed(2)];
+        assert!(list_sort(&mut reversed));
+        assert_eq!(vec![Reversed(3), Reversed(2), Reversed(1)], reversed);
+
+        let mut floats = vec![1.0, f32::NAN, 0.5];
+        assert!(!list_sort(&mut floats));
+        assert_eq!(1.0, floats[0]);
+        assert_eq!(
+            Some(Ordering::Less),
+            vec![1, 2].reflect_partial_cmp(&*vec![1, 2, 0].clone_value())
+        );
+    }
+
+    #[test]
+    fn should_edit_lists_in_bulk() {
+        use crate::List;
+        use alloc::collections::VecDeque;
+
+        fn is_even(value: &dyn Reflect) -> bool {
+            !matches!(value.downcast_ref::<i32>(), Some(value) if value % 2 != 0)
+        }
+
+        fn descending(a: &dyn Reflect, b: &dyn Reflect) -> Ordering {
+            b.reflect_partial_cmp(a).unwrap()
+        }
+
+        let mut list: Vec<i32> = vec![4, 1, 2, 7, 6];
+        List::retain(&mut list, &mut is_even);
+        List::extend_boxed(&mut list, vec![Box::new(8_i32), Box::new(0_i32)]);
+        List::sort_by_reflect(&mut list, &mut descending);
+        assert_eq!(vec![8, 6, 4, 2, 0], list);
+
+        let mut deque: VecDeque<i32> = [3, 1, 2].into_iter().collect();
+        deque.push_front(5);
+        List::sort_by_reflect(&mut deque, &mut descending);
+        List::retain(&mut deque, &mut |value| !is_even(value));
+        assert_eq!(vec![5, 3, 1], deque.into_iter().collect::<Vec<_>>());
+
+        let mut list = DynamicList::default();
+        list.push(2_i32);
+        list.push(String::from("kept"));
+        list.push(1_i32);
+        list.retain(&mut is_even);
+        List::extend_boxed(&mut list, vec![Box::new(-1_i32)]);
+        list.sort_by_reflect(&mut |a, b| {
+            let (a, b) = (a.downcast_ref::<i32>(), b.downcast_ref::<i32>());
+            a.cmp(&b)
+        });
+        assert_eq!(3, list.len());
+        assert!(list.get(0).unwrap().is::<String>());
+        assert_eq!(Some(&-1), list.get(1).unwrap().downcast_ref::<i32>());
+        assert_eq!(Some(&2), list.get(2).unwrap().downcast_ref::<i32>());
+    }
 }
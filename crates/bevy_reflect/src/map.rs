@@ -1,6 +1,10 @@
-use std::any::{Any, TypeId};
-use std::fmt::{Debug, Formatter};
-use std::hash::Hash;
+use crate::allocation_stats::{record_allocations, AllocationSource};
+use crate::cycle::{debug_guarded, expect_guarded};
+use crate::debug_options::{shown_elements, write_type_path};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::any::{Any, TypeId};
+use core::fmt::{Debug, Formatter};
+use core::hash::Hash;
 
 use bevy_utils::{Entry, HashMap};
 
@@ -63,6 +67,49 @@ pub trait Map: Reflect {
     /// If the map did not have this key present, `None` is returned.
     /// If the map did have this key present, the removed value is returned.
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>>;
+
+    /// Reserves capacity for at least `additional` more entries to be inserted into the map.
+    ///
+    /// This is only a hint, so the default implementation does nothing.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Retains only the entries for which `keep` returns `true`, removing the rest in place.
+    ///
+    /// The default implementation clones the keys of the rejected entries in order to
+    /// [`remove`](Map::remove) them afterwards.
+    fn retain(&mut self, keep: &mut dyn FnMut(&dyn Reflect, &dyn Reflect) -> bool) {
+        let rejected: Vec<Box<dyn Reflect>> = self
+            .iter()
+            .filter(|(key, value)| !keep(*key, *value))
+            .map(|(key, _)| key.clone_value())
+            .collect();
+        for key in rejected {
+            self.remove(&*key);
+        }
+    }
+
+    /// Returns a mutable reference to the value associated with `key`,
+    /// first inserting the value returned by `default` if there is none.
+    ///
+    /// `default` is only called when the key is missing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key or the default value cannot be inserted into the map,
+    /// as [`insert_boxed`](Map::insert_boxed) would.
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        if self.get(&*key).is_none() {
+            self.insert_boxed(key.clone_value(), default());
+        }
+        self.get_mut(&*key)
+            .expect("the key should have just been inserted")
+    }
 }
 
 /// A container for compile-time map info.
@@ -82,11 +129,11 @@ impl MapInfo {
     /// Create a new [`MapInfo`].
     pub fn new<TMap: Map, TKey: Hash + Reflect, TValue: Reflect>() -> Self {
         Self {
-            type_name: std::any::type_name::<TMap>(),
+            type_name: core::any::type_name::<TMap>(),
             type_id: TypeId::of::<TMap>(),
-            key_type_name: std::any::type_name::<TKey>(),
+            key_type_name: core::any::type_name::<TKey>(),
             key_type_id: TypeId::of::<TKey>(),
-            value_type_name: std::any::type_name::<TValue>(),
+            value_type_name: core::any::type_name::<TValue>(),
             value_type_id: TypeId::of::<TValue>(),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -208,8 +255,14 @@ impl Map for DynamicMap {
         self.values.len()
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.indices.reserve(additional);
+    }
+
     fn clone_dynamic(&self) -> DynamicMap {
-        DynamicMap {
+        record_allocations(AllocationSource::CloneDynamic, self.values.len() * 2);
+        expect_guarded(self.as_reflect(), "clone", || DynamicMap {
             name: self.name.clone(),
             values: self
                 .values
@@ -217,7 +270,7 @@ impl Map for DynamicMap {
                 .map(|(key, value)| (key.clone_value(), value.clone_value()))
                 .collect(),
             indices: self.indices.clone(),
-        }
+        })
     }
 
     fn iter(&self) -> MapIter {
@@ -241,7 +294,7 @@ impl Map for DynamicMap {
         match self.indices.entry(key.reflect_hash().expect(HASH_ERROR)) {
             Entry::Occupied(entry) => {
                 let (_old_key, old_value) = self.values.get_mut(*entry.get()).unwrap();
-                std::mem::swap(old_value, &mut value);
+                core::mem::swap(old_value, &mut value);
                 Some(value)
             }
             Entry::Vacant(entry) => {
@@ -257,9 +310,39 @@ impl Map for DynamicMap {
             .indices
             .remove(&key.reflect_hash().expect(HASH_ERROR))?;
         let (_key, value) = self.values.remove(index);
+        for later in self.indices.values_mut() {
+            if *later > index {
+                *later -= 1;
+            }
+        }
         Some(value)
     }
 
+    fn retain(&mut self, keep: &mut dyn FnMut(&dyn Reflect, &dyn Reflect) -> bool) {
+        self.values.retain(|(key, value)| keep(&**key, &**value));
+        self.indices.clear();
+        for (index, (key, _)) in self.values.iter().enumerate() {
+            self.indices
+                .insert(key.reflect_hash().expect(HASH_ERROR), index);
+        }
+    }
+
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        let index = match self.indices.entry(key.reflect_hash().expect(HASH_ERROR)) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                entry.insert(self.values.len());
+                self.values.push((key, default()));
+                self.values.len() - 1
+            }
+        };
+        &mut *self.values[index].1
+    }
+
     fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
         self.values
     }
@@ -331,7 +414,7 @@ impl Reflect for DynamicMap {
         map_partial_eq(self, value)
     }
 
-    fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "DynamicMap(")?;
         map_debug(self, f)?;
         write!(f, ")")
@@ -339,7 +422,7 @@ impl Reflect for DynamicMap {
 }
 
 impl Debug for DynamicMap {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.debug(f)
     }
 }
@@ -374,13 +457,41 @@ impl<'a> Iterator for MapIter<'a> {
 
 impl IntoIterator for DynamicMap {
     type Item = (Box<dyn Reflect>, Box<dyn Reflect>);
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.values.into_iter()
     }
 }
 
+/// Collects key-value pairs into a map, where later pairs overwrite earlier ones with the same key.
+///
+/// # Panics
+///
+/// Panics if a key does not support hashing.
+impl FromIterator<(Box<dyn Reflect>, Box<dyn Reflect>)> for DynamicMap {
+    fn from_iter<I: IntoIterator<Item = (Box<dyn Reflect>, Box<dyn Reflect>)>>(entries: I) -> Self {
+        let mut map = Self::default();
+        map.extend(entries);
+        map
+    }
+}
+
+/// Inserts key-value pairs into the map, overwriting the values of existing keys.
+///
+/// # Panics
+///
+/// Panics if a key does not support hashing.
+impl Extend<(Box<dyn Reflect>, Box<dyn Reflect>)> for DynamicMap {
+    fn extend<I: IntoIterator<Item = (Box<dyn Reflect>, Box<dyn Reflect>)>>(&mut self, entries: I) {
+        let entries = entries.into_iter();
+        self.reserve(entries.size_hint().0);
+        for (key, value) in entries {
+            self.insert_boxed(key, value);
+        }
+    }
+}
+
 impl<'a> ExactSizeIterator for MapIter<'a> {}
 
 /// Compares a [`Map`] with a [`Reflect`] value.
@@ -434,12 +545,20 @@ pub fn map_partial_eq<M: Map>(a: &M, b: &dyn Reflect) -> Option<bool> {
 /// // }
 /// ```
 #[inline]
-pub fn map_debug(dyn_map: &dyn Map, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut debug = f.debug_map();
-    for (key, value) in dyn_map.iter() {
-        debug.entry(&key as &dyn Debug, &value as &dyn Debug);
-    }
-    debug.finish()
+pub fn map_debug(dyn_map: &dyn Map, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    debug_guarded(dyn_map.as_reflect(), f, |f| {
+        write_type_path(dyn_map.as_reflect(), f)?;
+        let mut debug = f.debug_map();
+        let shown = shown_elements(dyn_map.len());
+        for (key, value) in dyn_map.iter().take(shown) {
+            debug.entry(&key as &dyn Debug, &value as &dyn Debug);
+        }
+        if shown < dyn_map.len() {
+            let omitted = dyn_map.len() - shown;
+            debug.entry(&format_args!(".."), &format_args!("({omitted} more)"));
+        }
+        debug.finish()
+    })
 }
 
 /// Applies the elements of reflected map `b` to the corresponding elements of map `a`.
@@ -451,22 +570,160 @@ pub fn map_debug(dyn_map: &dyn Map, f: &mut std::fmt::Formatter<'_>) -> std::fmt
 /// This function panics if `b` is not a reflected map.
 #[inline]
 pub fn map_apply<M: Map>(a: &mut M, b: &dyn Reflect) {
-    if let ReflectRef::Map(map_value) = b.reflect_ref() {
-        for (key, b_value) in map_value.iter() {
-            if let Some(a_value) = a.get_mut(key) {
-                a_value.apply(b_value);
-            } else {
-                a.insert_boxed(key.clone_value(), b_value.clone_value());
+    expect_guarded(b, "apply", || {
+        if let ReflectRef::Map(map_value) = b.reflect_ref() {
+            // At least this many keys of `b` are missing from `a`, and will be inserted
+            Map::reserve(a, map_value.len().saturating_sub(a.len()));
+            for (key, b_value) in map_value.iter() {
+                if let Some(a_value) = a.get_mut(key) {
+                    a_value.apply(b_value);
+                } else {
+                    record_allocations(AllocationSource::Apply, 2);
+                    a.insert_boxed(key.clone_value(), b_value.clone_value());
+                }
             }
+        } else {
+            panic!("Attempted to apply a non-map type to a map type.");
         }
-    } else {
-        panic!("Attempted to apply a non-map type to a map type.");
-    }
+    });
 }
 
 #[cfg(test)]
 mod tests {
-    use super::DynamicMap;
+    use super::{map_apply, DynamicMap, MapIter};
+    use crate::{Map, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo};
+    use bevy_utils::HashMap;
+    use core::any::Any;
+
+    /// A map recording the space it is asked to reserve.
+    #[derive(Default)]
+    struct ReservingMap {
+        map: DynamicMap,
+        reserved: Vec<usize>,
+    }
+
+    impl Map for ReservingMap {
+        fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+            self.map.get(key)
+        }
+
+        fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+            self.map.get_mut(key)
+        }
+
+        fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+            self.map.get_at(index)
+        }
+
+        fn len(&self) -> usize {
+            self.map.len()
+        }
+
+        fn iter(&self) -> MapIter {
+            MapIter {
+                map: self,
+                index: 0,
+            }
+        }
+
+        fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+            Box::new(self.map).drain()
+        }
+
+        fn clone_dynamic(&self) -> DynamicMap {
+            self.map.clone_dynamic()
+        }
+
+        fn insert_boxed(
+            &mut self,
+            key: Box<dyn Reflect>,
+            value: Box<dyn Reflect>,
+        ) -> Option<Box<dyn Reflect>> {
+            self.map.insert_boxed(key, value)
+        }
+
+        fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+            self.map.remove(key)
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.reserved.push(additional);
+            self.map.reserve(additional);
+        }
+    }
+
+    impl Reflect for ReservingMap {
+        fn type_name(&self) -> &str {
+            core::any::type_name::<Self>()
+        }
+
+        fn get_type_info(&self) -> &'static TypeInfo {
+            self.map.get_type_info()
+        }
+
+        fn into_any(self: Box<Self>) -> Box<dyn Any> {
+            self
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+
+        fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+            self
+        }
+
+        fn as_reflect(&self) -> &dyn Reflect {
+            self
+        }
+
+        fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+            self
+        }
+
+        fn apply(&mut self, value: &dyn Reflect) {
+            map_apply(self, value);
+        }
+
+        fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+            *self = value.take()?;
+            Ok(())
+        }
+
+        fn reflect_ref(&self) -> ReflectRef {
+            ReflectRef::Map(self)
+        }
+
+        fn reflect_mut(&mut self) -> ReflectMut {
+            ReflectMut::Map(self)
+        }
+
+        fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+            ReflectOwned::Map(self)
+        }
+
+        fn clone_value(&self) -> Box<dyn Reflect> {
+            Box::new(self.map.clone_dynamic())
+        }
+    }
+
+    #[test]
+    fn should_reserve_space_when_applying() {
+        let mut map = ReservingMap::default();
+        map.map.insert(0_u32, 0_u32);
+        map.apply(&(0..100).map(|i| (i, i)).collect::<HashMap<u32, u32>>());
+        assert_eq!(100, map.len());
+        // At least this many keys are missing, so space for them is reserved at once
+        assert_eq!(vec![99], map.reserved);
+
+        map.reserved.clear();
+        map.apply(&(0..10).map(|i| (i, i)).collect::<HashMap<u32, u32>>());
+        assert_eq!(vec![0], map.reserved);
+    }
 
     #[test]
     fn test_into_iter() {
@@ -487,4 +744,44 @@ mod tests {
             assert_eq!(expected[index], value);
         }
     }
+
+    #[test]
+    fn should_edit_maps_in_place() {
+        let mut map = DynamicMap::default();
+        map.insert(0usize, 10usize);
+        map.insert(1usize, 11usize);
+        map.insert(2usize, 12usize);
+        map.insert(3usize, 13usize);
+
+        map.remove(&1usize);
+        assert_eq!(Some(&12), map.get(&2usize).unwrap().downcast_ref::<usize>());
+
+        map.retain(&mut |key, _| key.downcast_ref::<usize>() != Some(&2));
+        assert_eq!(2, map.len());
+        assert!(map.get(&2usize).is_none());
+        assert_eq!(Some(&13), map.get(&3usize).unwrap().downcast_ref::<usize>());
+
+        let mut calls = 0;
+        let mut default = || {
+            calls += 1;
+            Box::new(0usize) as Box<dyn Reflect>
+        };
+        map.get_or_insert_with(Box::new(3usize), &mut default)
+            .apply(&14usize);
+        map.get_or_insert_with(Box::new(4usize), &mut default)
+            .apply(&15usize);
+        assert_eq!(1, calls);
+        assert_eq!(Some(&14), map.get(&3usize).unwrap().downcast_ref::<usize>());
+        assert_eq!(Some(&15), map.get(&4usize).unwrap().downcast_ref::<usize>());
+
+        let mut hash_map: HashMap<usize, usize> = HashMap::default();
+        hash_map.insert(0, 0);
+        hash_map.insert(1, 1);
+        Map::retain(&mut hash_map, &mut |_, value| {
+            value.downcast_ref() == Some(&1usize)
+        });
+        hash_map.get_or_insert_with(Box::new(2usize), &mut || Box::new(2usize));
+        assert_eq!(2, hash_map.len());
+        assert_eq!(None, hash_map.get(&0));
+    }
 }
@@ -0,0 +1,234 @@
+use crate::{
+    Access, DynamicStruct, DynamicTuple, DynamicTupleStruct, ParsedPath, Reflect, ReflectRef,
+    TypeInfo,
+};
+use alloc::collections::BTreeMap;
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::mem;
+#[cfg(feature = "std")]
+use std::{ffi::OsString, path::PathBuf};
+
+/// An estimate of the memory used by a reflected value, made by [`reflect_size_of`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The size of the value itself, as given by [`mem::size_of_val`].
+    pub shallow: usize,
+    /// The number of bytes allocated on the heap by the value and everything within it.
+    pub heap: usize,
+    /// The number of heap bytes allocated by each value which allocates any, keyed by its path
+    /// within the measured value, including the measured value itself at the empty path.
+    ///
+    /// Each entry includes the bytes allocated by everything within that value.
+    /// As map entries cannot be accessed by a path, they are only counted towards their map.
+    pub by_path: BTreeMap<ParsedPath, usize>,
+}
+
+impl MemoryUsage {
+    /// Returns the total number of bytes used by the value, both inline and on the heap.
+    pub fn total(&self) -> usize {
+        self.shallow + self.heap
+    }
+}
+
+/// Estimates the memory used by a reflected value, by walking everything within it.
+///
+/// The heap usage of each value is estimated as follows:
+/// * lists and maps allocate their elements, keys and values inline,
+///   without accounting for any spare capacity or the overhead of a hash table,
+/// * dynamic types, such as [`DynamicStruct`], allocate each of their fields in its own box,
+///   other than the small primitive fields of structs and tuples, which may be stored inline,
+/// * [`String`], [`Cow<str>`], [`PathBuf`] and [`OsString`] allocate their capacity,
+/// * any other value is assumed not to allocate, as there is no way to tell through reflection.
+///
+/// This is meant for finding which parts of a large value, such as a scene,
+/// use the most memory, rather than for measuring it exactly.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{reflect_size_of, ParsedPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Mesh {
+///     name: String,
+///     indices: Vec<u32>,
+/// }
+///
+/// let mesh = Mesh { name: String::from("cube"), indices: vec![0; 36] };
+/// let usage = reflect_size_of(&mesh);
+///
+/// assert_eq!(std::mem::size_of::<Mesh>(), usage.shallow);
+/// assert_eq!(4 + 36 * 4, usage.heap);
+/// assert_eq!(Some(&144), usage.by_path.get(&ParsedPath::parse("indices").unwrap()));
+/// ```
+///
+/// [`DynamicStruct`]: crate::DynamicStruct
+pub fn reflect_size_of(value: &dyn Reflect) -> MemoryUsage {
+    let mut by_path = BTreeMap::new();
+    let heap = measure_heap(value, &mut ParsedPath::default(), &mut by_path);
+    MemoryUsage {
+        shallow: mem::size_of_val(value),
+        heap,
+        by_path,
+    }
+}
+
+/// Returns the heap bytes allocated by `value` and everything within it,
+/// adding those of each value which allocates any to `by_path`.
+fn measure_heap(
+    value: &dyn Reflect,
+    path: &mut ParsedPath,
+    by_path: &mut BTreeMap<ParsedPath, usize>,
+) -> usize {
+    let boxed = matches!(value.get_type_info(), TypeInfo::Dynamic(..));
+    let mut heap = 0;
+    // Fields of concrete structs, tuples and arrays are stored inline in the value.
+    let mut measure_field = |access: Access, field: &dyn Reflect, boxed: bool| {
+        if boxed {
+            heap += mem::size_of::<Box<dyn Reflect>>() + mem::size_of_val(field);
+        }
+        path.push(access);
+        heap += measure_heap(field, path, by_path);
+        path.pop();
+    };
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let dynamic = value.as_any().downcast_ref::<DynamicStruct>();
+            for (i, field) in value.iter_fields().enumerate() {
+                let access = Access::Field(value.name_at(i).unwrap().to_string());
+                let boxed = dynamic.map_or(boxed, |dynamic| dynamic.is_field_boxed(i));
+                measure_field(access, field, boxed);
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            let dynamic = value.as_any().downcast_ref::<DynamicTupleStruct>();
+            for (i, field) in value.iter_fields().enumerate() {
+                let boxed = dynamic.map_or(boxed, |dynamic| dynamic.is_field_boxed(i));
+                measure_field(Access::TupleIndex(i), field, boxed);
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            let dynamic = value.as_any().downcast_ref::<DynamicTuple>();
+            for (i, field) in value.iter_fields().enumerate() {
+                let boxed = dynamic.map_or(boxed, |dynamic| dynamic.is_field_boxed(i));
+                measure_field(Access::TupleIndex(i), field, boxed);
+            }
+        }
+        ReflectRef::Array(value) => {
+            for (i, item) in value.iter().enumerate() {
+                measure_field(Access::ListIndex(i), item, boxed);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            for (i, field) in value.iter_fields().enumerate() {
+                let access = match field.name() {
+                    Some(name) => Access::Field(name.to_string()),
+                    None => Access::TupleIndex(i),
+                };
+                measure_field(access, field.value(), boxed);
+            }
+        }
+        ReflectRef::List(list) => {
+            for (i, item) in list.iter().enumerate() {
+                heap += element_size(item, boxed);
+                path.push(Access::ListIndex(i));
+                heap += measure_heap(item, path, by_path);
+                path.pop();
+            }
+        }
+        ReflectRef::Map(map) => {
+            for (key, value) in map.iter() {
+                heap += element_size(key, boxed) + element_size(value, boxed);
+                heap += measure_heap(key, path, &mut BTreeMap::new());
+                heap += measure_heap(value, path, &mut BTreeMap::new());
+            }
+        }
+        ReflectRef::Value(value) => heap += value_heap(value),
+    }
+
+    if heap > 0 {
+        by_path.insert(path.clone(), heap);
+    }
+    heap
+}
+
+/// Returns the bytes a list or map allocates for one of its elements.
+fn element_size(element: &dyn Reflect, boxed: bool) -> usize {
+    if boxed {
+        mem::size_of::<Box<dyn Reflect>>() + mem::size_of_val(element)
+    } else {
+        mem::size_of_val(element)
+    }
+}
+
+/// Returns the heap bytes allocated by the value types known to allocate.
+fn value_heap(value: &dyn Reflect) -> usize {
+    let any = value.as_any();
+    if let Some(string) = any.downcast_ref::<String>() {
+        return string.capacity();
+    }
+    if let Some(Cow::Owned(string)) = any.downcast_ref::<Cow<'static, str>>() {
+        return string.capacity();
+    }
+    #[cfg(feature = "std")]
+    if let Some(path) = any.downcast_ref::<PathBuf>() {
+        return path.capacity();
+    }
+    #[cfg(feature = "std")]
+    if let Some(string) = any.downcast_ref::<OsString>() {
+        return string.capacity();
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reflect_size_of;
+    use crate as bevy_reflect;
+    use crate::{DynamicStruct, ParsedPath, Reflect};
+    use bevy_utils::HashMap;
+    use core::mem::size_of;
+
+    #[derive(Reflect)]
+    struct Level {
+        name: String,
+        tags: Vec<String>,
+        scores: HashMap<u32, String>,
+    }
+
+    #[test]
+    fn should_measure_heap_usage() {
+        let mut scores = HashMap::default();
+        scores.insert(1, String::with_capacity(8));
+        let level = Level {
+            name: String::with_capacity(10),
+            tags: vec![String::with_capacity(4), String::new()],
+            scores,
+        };
+
+        let usage = reflect_size_of(&level);
+        let path = |path| ParsedPath::parse(path).unwrap();
+        let tags = 2 * size_of::<String>() + 4;
+        let scores = size_of::<u32>() + size_of::<String>() + 8;
+        assert_eq!(size_of::<Level>(), usage.shallow);
+        assert_eq!(10 + tags + scores, usage.heap);
+        assert_eq!(usage.shallow + usage.heap, usage.total());
+        assert_eq!(5, usage.by_path.len());
+        assert_eq!(Some(&usage.heap), usage.by_path.get(&ParsedPath::default()));
+        assert_eq!(Some(&10), usage.by_path.get(&path("name")));
+        assert_eq!(Some(&tags), usage.by_path.get(&path("tags")));
+        assert_eq!(Some(&4), usage.by_path.get(&path("tags[0]")));
+        assert_eq!(Some(&scores), usage.by_path.get(&path("scores")));
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("value", 0_u64);
+        let usage = reflect_size_of(&dynamic);
+        assert_eq!(size_of::<Box<dyn Reflect>>() + 8, usage.heap);
+
+        dynamic.set_inline_values(true);
+        assert_eq!(0, reflect_size_of(&dynamic).heap);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{Reflect, TypeInfo, Typed};
+use alloc::{boxed::Box, rc::Rc};
+use core::any::Any;
+use core::cell::{Cell, RefCell};
+
+/// A trait for types which cannot implement [`Reflect`], as they are not [`Send`] and [`Sync`],
+/// such as types holding an [`Rc`] or a handle which must stay on the main thread.
+///
+/// Such types are reflected through a dynamic copy of their data, made by [`to_dynamic`],
+/// which can be inspected, serialized and sent to other threads like any other reflected value.
+/// Changes can then be written back onto the original value with [`apply_dynamic`].
+///
+/// This trait is implemented for every type implementing [`Reflect`] and [`Typed`],
+/// as well as [`Rc`], [`RefCell`] and [`Cell`] holding a type implementing this trait,
+/// which are represented by the type they hold.
+/// It can be derived on structs and tuple structs whose fields implement this trait.
+/// Fields marked with `#[reflect(ignore)]` are left out.
+///
+/// To be serialized and deserialized, a type must be registered with
+/// [`TypeRegistry::register_non_send`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{GetPath, NonSendReflect, Reflect};
+/// # use std::rc::Rc;
+/// #[derive(Reflect, Clone)]
+/// struct Texture {
+///     width: u32,
+/// }
+///
+/// #[derive(NonSendReflect)]
+/// struct Sprite {
+///     name: String,
+///     texture: Rc<Texture>,
+/// }
+///
+/// let mut sprite = Sprite {
+///     name: String::from("player"),
+///     texture: Rc::new(Texture { width: 16 }),
+/// };
+///
+/// let mut dynamic = sprite.to_dynamic();
+/// dynamic.path_mut("texture.width").unwrap().apply(&32_u32);
+///
+/// sprite.apply_dynamic(&*dynamic);
+/// assert_eq!(32, sprite.texture.width);
+/// ```
+///
+/// [`to_dynamic`]: NonSendReflect::to_dynamic
+/// [`apply_dynamic`]: NonSendReflect::apply_dynamic
+/// [`TypeRegistry::register_non_send`]: crate::TypeRegistry::register_non_send
+pub trait NonSendReflect: Any {
+    /// The type whose [`TypeInfo`] describes the dynamic copies of this type.
+    ///
+    /// This is `Self` for all but the wrappers, such as [`Rc`], which are represented by
+    /// the type they hold.
+    type Represented: ?Sized + 'static;
+
+    /// Returns the [`TypeInfo`] describing the dynamic copies of this type.
+    fn non_send_type_info() -> &'static TypeInfo;
+
+    /// Returns a dynamic copy of this value.
+    fn to_dynamic(&self) -> Box<dyn Reflect>;
+
+    /// Applies a reflected value, such as one returned by [`to_dynamic`], to this value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be applied, as for [`Reflect::apply`].
+    ///
+    /// [`to_dynamic`]: NonSendReflect::to_dynamic
+    fn apply_dynamic(&mut self, value: &dyn Reflect);
+}
+
+impl<T: Reflect + Typed> NonSendReflect for T {
+    type Represented = T;
+
+    fn non_send_type_info() -> &'static TypeInfo {
+        <T as Typed>::type_info()
+    }
+
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        self.clone_value()
+    }
+
+    fn apply_dynamic(&mut self, value: &dyn Reflect) {
+        self.apply(value);
+    }
+}
+
+impl<T: NonSendReflect + Clone> NonSendReflect for Rc<T> {
+    type Represented = T::Represented;
+
+    fn non_send_type_info() -> &'static TypeInfo {
+        T::non_send_type_info()
+    }
+
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        (**self).to_dynamic()
+    }
+
+    /// Applies `value` to the value held by the [`Rc`], cloning it first if it is shared.
+    fn apply_dynamic(&mut self, value: &dyn Reflect) {
+        Rc::make_mut(self).apply_dynamic(value);
+    }
+}
+
+impl<T: NonSendReflect> NonSendReflect for RefCell<T> {
+    type Represented = T::Represented;
+
+    fn non_send_type_info() -> &'static TypeInfo {
+        T::non_send_type_info()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        self.borrow().to_dynamic()
+    }
+
+    fn apply_dynamic(&mut self, value: &dyn Reflect) {
+        self.get_mut().apply_dynamic(value);
+    }
+}
+
+impl<T: NonSendReflect + Copy> NonSendReflect for Cell<T> {
+    type Represented = T::Represented;
+
+    fn non_send_type_info() -> &'static TypeInfo {
+        T::non_send_type_info()
+    }
+
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        self.get().to_dynamic()
+    }
+
+    fn apply_dynamic(&mut self, value: &dyn Reflect) {
+        self.get_mut().apply_dynamic(value);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Array, Map, Reflect};
+use rayon::prelude::*;
+
+/// Returns a parallel iterator over the elements of an [`Array`] or [`List`],
+/// splitting them across the threads of the [rayon] thread pool.
+///
+/// This is useful for bulk operations over large reflected lists,
+/// such as validating or diffing their elements.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{array_par_iter, List, Reflect};
+/// use rayon::prelude::*;
+///
+/// let list: &dyn List = &(0..1000_u32).collect::<Vec<_>>();
+/// let sum: u32 = array_par_iter(list)
+///     .map(|item| *item.downcast_ref::<u32>().unwrap())
+///     .sum();
+/// assert_eq!(499_500, sum);
+/// ```
+///
+/// [`List`]: crate::List
+pub fn array_par_iter<A: Array + ?Sized>(
+    array: &A,
+) -> impl IndexedParallelIterator<Item = &dyn Reflect> + '_ {
+    (0..array.len())
+        .into_par_iter()
+        .map(move |index| array.get(index).unwrap())
+}
+
+/// Returns a parallel iterator over the entries of a [`Map`],
+/// splitting them across the threads of the [rayon] thread pool.
+///
+/// Entries are produced in the same order as [`Map::iter`] produces them.
+pub fn map_par_iter<M: Map + ?Sized>(
+    map: &M,
+) -> impl IndexedParallelIterator<Item = (&dyn Reflect, &dyn Reflect)> + '_ {
+    (0..map.len())
+        .into_par_iter()
+        .map(move |index| map.get_at(index).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{array_par_iter, map_par_iter};
+    use crate::DynamicMap;
+    use rayon::prelude::*;
+
+    #[test]
+    fn should_iterate_in_parallel() {
+        let array = [1.5_f32; 64];
+        let items = array_par_iter(&array)
+            .map(|item| item.clone_value())
+            .collect::<Vec<_>>();
+        assert_eq!(64, items.len());
+        assert!(items
+            .iter()
+            .all(|item| item.reflect_partial_eq(&1.5_f32).unwrap()));
+
+        let mut map = DynamicMap::default();
+        for index in 0..100_usize {
+            map.insert(index, index * 2);
+        }
+        let pairs = map_par_iter(&map)
+            .map(|(key, value)| {
+                let key = *key.downcast_ref::<usize>().unwrap();
+                (key, *value.downcast_ref::<usize>().unwrap())
+            })
+            .collect::<Vec<_>>();
+        let expected = (0..100).map(|index| (index, index * 2)).collect::<Vec<_>>();
+        assert_eq!(expected, pairs);
+    }
+}
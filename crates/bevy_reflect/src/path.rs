@@ -1,30 +1,59 @@
-use std::num::ParseIntError;
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+use core::num::ParseIntError;
 
-use crate::{Array, Reflect, ReflectMut, ReflectRef, VariantType};
+use crate::{
+    Array, Enum, Reflect, ReflectFromReflect, ReflectKind, ReflectMut, ReflectRef, Struct,
+    TypeRegistry, VariantType,
+};
 use thiserror::Error;
 
 /// An error returned from a failed path string query.
+///
+/// Most variants carry the `index` of the offending access within the path string,
+/// which is also available through [`ReflectPathError::index`].
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum ReflectPathError<'a> {
     #[error("expected an identifier at index {index}")]
     ExpectedIdent { index: usize },
-    #[error("the current struct doesn't have a field with the name `{field}`")]
-    InvalidField { index: usize, field: &'a str },
+    /// The struct or struct variant has no field with the given name.
+    ///
+    /// If one of its fields has a similar name, it is given as the `suggestion`.
+    #[error(
+        "the current struct doesn't have a field with the name `{field}`{}",
+        fmt_suggestion(.suggestion)
+    )]
+    InvalidField {
+        index: usize,
+        field: &'a str,
+        suggestion: Option<String>,
+    },
     #[error("the current tuple struct doesn't have a field with the index {tuple_struct_index}")]
     InvalidTupleStructIndex {
         index: usize,
         tuple_struct_index: usize,
     },
-    #[error("the current list doesn't have a value at the index {list_index}")]
-    InvalidListIndex { index: usize, list_index: usize },
+    #[error("the current list of length {len} doesn't have a value at the index {list_index}")]
+    InvalidListIndex {
+        index: usize,
+        list_index: usize,
+        len: usize,
+    },
     #[error("encountered an unexpected token `{token}`")]
     UnexpectedToken { index: usize, token: &'a str },
     #[error("expected token `{token}`, but it wasn't there.")]
     ExpectedToken { index: usize, token: &'a str },
-    #[error("expected a struct, but found a different reflect value")]
-    ExpectedStruct { index: usize },
-    #[error("expected a list, but found a different reflect value")]
-    ExpectedList { index: usize },
+    #[error("expected a struct at index {index}, but found a {found}")]
+    ExpectedStruct { index: usize, found: ReflectKind },
+    #[error("expected a list at index {index}, but found a {found}")]
+    ExpectedList { index: usize, found: ReflectKind },
     #[error("failed to parse a usize")]
     IndexParseError(#[from] ParseIntError),
     #[error("failed to downcast to the path result to the given type")]
@@ -33,6 +62,42 @@ pub enum ReflectPathError<'a> {
     InvalidVariantAccess { index: usize, accessor: &'a str },
 }
 
+impl<'a> ReflectPathError<'a> {
+    /// Returns the index within the path string at which the error occurred, if known.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            ReflectPathError::ExpectedIdent { index }
+            | ReflectPathError::InvalidField { index, .. }
+            | ReflectPathError::InvalidTupleStructIndex { index, .. }
+            | ReflectPathError::InvalidListIndex { index, .. }
+            | ReflectPathError::UnexpectedToken { index, .. }
+            | ReflectPathError::ExpectedToken { index, .. }
+            | ReflectPathError::ExpectedStruct { index, .. }
+            | ReflectPathError::ExpectedList { index, .. }
+            | ReflectPathError::InvalidVariantAccess { index, .. } => Some(*index),
+            ReflectPathError::IndexParseError(_) | ReflectPathError::InvalidDowncast => None,
+        }
+    }
+}
+
+/// An error returned from [`GetPath::set_path`].
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum SetPathError<'a> {
+    #[error("{0}")]
+    Path(ReflectPathError<'a>),
+    #[error("the type `{type_name}` is not registered with `ReflectFromReflect`")]
+    MissingFromReflect { type_name: String },
+    #[error("a value of type `{from_type}` cannot be converted to `{to_type}`")]
+    InvalidConversion { from_type: String, to_type: String },
+}
+
+fn fmt_suggestion(suggestion: &Option<String>) -> String {
+    match suggestion {
+        Some(suggestion) => format!("; did you mean `{suggestion}`?"),
+        None => String::new(),
+    }
+}
+
 /// A trait which allows nested values to be retrieved with path strings.
 ///
 /// Path strings use Rust syntax:
@@ -89,6 +154,70 @@ pub trait GetPath {
                 .ok_or(ReflectPathError::InvalidDowncast)
         })
     }
+
+    /// Replaces the value specified by `path` with `value`.
+    ///
+    /// If `value` is not of the same type as the value at `path`, it is converted
+    /// using the [`ReflectFromReflect`] type data registered for that type.
+    /// This allows setting a field from a dynamic value, such as a [`DynamicStruct`],
+    /// without knowing the field's concrete type.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{DynamicStruct, FromReflect, GetPath, Reflect, ReflectFromReflect, TypeRegistry};
+    /// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    /// #[reflect(FromReflect)]
+    /// struct Stats {
+    ///     health: f64,
+    /// }
+    ///
+    /// #[derive(Reflect)]
+    /// struct Player {
+    ///     stats: Stats,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Stats>();
+    ///
+    /// let mut player = Player { stats: Stats { health: 10.0 } };
+    /// player.set_path("stats.health", 5.0_f64, &registry).unwrap();
+    /// assert_eq!(5.0, player.stats.health);
+    ///
+    /// let mut stats = DynamicStruct::default();
+    /// stats.insert("health", 2.5_f64);
+    /// player.set_path("stats", stats, &registry).unwrap();
+    /// assert_eq!(Stats { health: 2.5 }, player.stats);
+    /// ```
+    ///
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    fn set_path<'p, T: Reflect>(
+        &mut self,
+        path: &'p str,
+        value: T,
+        registry: &TypeRegistry,
+    ) -> Result<(), SetPathError<'p>> {
+        let target = self.path_mut(path).map_err(SetPathError::Path)?;
+        let Err(value) = target.set(Box::new(value)) else {
+            return Ok(());
+        };
+
+        let from_reflect = registry
+            .get_type_data::<ReflectFromReflect>(target.as_any().type_id())
+            .ok_or_else(|| SetPathError::MissingFromReflect {
+                type_name: target.type_name().to_owned(),
+            })?;
+        let converted =
+            from_reflect
+                .from_reflect(&*value)
+                .ok_or_else(|| SetPathError::InvalidConversion {
+                    from_type: value.type_name().to_owned(),
+                    to_type: target.type_name().to_owned(),
+                })?;
+        target
+            .set(converted)
+            .expect("`ReflectFromReflect` should produce a value of its own type");
+        Ok(())
+    }
 }
 
 impl<T: Reflect> GetPath for T {
@@ -129,9 +258,10 @@ impl GetPath for dyn Reflect {
                             ReflectRef::Array(reflect_arr) => {
                                 current = read_array_entry(reflect_arr, value, current_index)?;
                             }
-                            _ => {
+                            reflect_ref => {
                                 return Err(ReflectPathError::ExpectedList {
                                     index: current_index,
+                                    found: reflect_ref.kind(),
                                 })
                             }
                         }
@@ -191,9 +321,10 @@ impl GetPath for dyn Reflect {
                             ReflectMut::Array(reflect_arr) => {
                                 current = read_array_entry_mut(reflect_arr, value, current_index)?;
                             }
-                            _ => {
-                                return Err(ReflectPathError::ExpectedStruct {
+                            reflect_mut => {
+                                return Err(ReflectPathError::ExpectedList {
                                     index: current_index,
+                                    found: reflect_mut.kind(),
                                 })
                             }
                         }
@@ -240,6 +371,7 @@ where
         .ok_or(ReflectPathError::InvalidListIndex {
             index: current_index,
             list_index,
+            len: list.len(),
         })
 }
 
@@ -252,10 +384,12 @@ where
     T: Array + ?Sized,
 {
     let list_index = value.parse::<usize>()?;
+    let len = list.len();
     list.get_mut(list_index)
         .ok_or(ReflectPathError::InvalidListIndex {
             index: current_index,
             list_index,
+            len,
         })
 }
 
@@ -266,12 +400,13 @@ fn read_field<'r, 'p>(
 ) -> Result<&'r dyn Reflect, ReflectPathError<'p>> {
     match current.reflect_ref() {
         ReflectRef::Struct(reflect_struct) => {
-            Ok(reflect_struct
+            reflect_struct
                 .field(field)
-                .ok_or(ReflectPathError::InvalidField {
+                .ok_or_else(|| ReflectPathError::InvalidField {
                     index: current_index,
                     field,
-                })?)
+                    suggestion: suggest_field(field, struct_field_names(reflect_struct)),
+                })
         }
         ReflectRef::TupleStruct(reflect_struct) => {
             let tuple_index = field.parse::<usize>()?;
@@ -282,14 +417,24 @@ fn read_field<'r, 'p>(
                 },
             )?)
         }
+        ReflectRef::Tuple(reflect_tuple) => {
+            let tuple_index = field.parse::<usize>()?;
+            Ok(reflect_tuple.field(tuple_index).ok_or(
+                ReflectPathError::InvalidTupleStructIndex {
+                    index: current_index,
+                    tuple_struct_index: tuple_index,
+                },
+            )?)
+        }
         ReflectRef::Enum(reflect_enum) => match reflect_enum.variant_type() {
             VariantType::Struct => {
-                Ok(reflect_enum
+                reflect_enum
                     .field(field)
-                    .ok_or(ReflectPathError::InvalidField {
+                    .ok_or_else(|| ReflectPathError::InvalidField {
                         index: current_index,
                         field,
-                    })?)
+                        suggestion: suggest_field(field, variant_field_names(reflect_enum)),
+                    })
             }
             VariantType::Tuple => {
                 let tuple_index = field.parse::<usize>()?;
@@ -298,6 +443,7 @@ fn read_field<'r, 'p>(
                     .ok_or(ReflectPathError::InvalidField {
                         index: current_index,
                         field,
+                        suggestion: None,
                     })?)
             }
             _ => Err(ReflectPathError::InvalidVariantAccess {
@@ -305,8 +451,9 @@ fn read_field<'r, 'p>(
                 accessor: field,
             }),
         },
-        _ => Err(ReflectPathError::ExpectedStruct {
+        reflect_ref => Err(ReflectPathError::ExpectedStruct {
             index: current_index,
+            found: reflect_ref.kind(),
         }),
     }
 }
@@ -318,12 +465,16 @@ fn read_field_mut<'r, 'p>(
 ) -> Result<&'r mut dyn Reflect, ReflectPathError<'p>> {
     match current.reflect_mut() {
         ReflectMut::Struct(reflect_struct) => {
-            Ok(reflect_struct
-                .field_mut(field)
-                .ok_or(ReflectPathError::InvalidField {
+            // The field names are only needed on failure, but can't be read once
+            // `field_mut` has borrowed the struct, so check for the field up front.
+            if reflect_struct.field(field).is_none() {
+                return Err(ReflectPathError::InvalidField {
                     index: current_index,
                     field,
-                })?)
+                    suggestion: suggest_field(field, struct_field_names(reflect_struct)),
+                });
+            }
+            Ok(reflect_struct.field_mut(field).unwrap())
         }
         ReflectMut::TupleStruct(reflect_struct) => {
             let tuple_index = field.parse::<usize>()?;
@@ -334,14 +485,25 @@ fn read_field_mut<'r, 'p>(
                 },
             )?)
         }
+        ReflectMut::Tuple(reflect_tuple) => {
+            let tuple_index = field.parse::<usize>()?;
+            Ok(reflect_tuple.field_mut(tuple_index).ok_or(
+                ReflectPathError::InvalidTupleStructIndex {
+                    index: current_index,
+                    tuple_struct_index: tuple_index,
+                },
+            )?)
+        }
         ReflectMut::Enum(reflect_enum) => match reflect_enum.variant_type() {
             VariantType::Struct => {
-                Ok(reflect_enum
-                    .field_mut(field)
-                    .ok_or(ReflectPathError::InvalidField {
+                if reflect_enum.field(field).is_none() {
+                    return Err(ReflectPathError::InvalidField {
                         index: current_index,
                         field,
-                    })?)
+                        suggestion: suggest_field(field, variant_field_names(reflect_enum)),
+                    });
+                }
+                Ok(reflect_enum.field_mut(field).unwrap())
             }
             VariantType::Tuple => {
                 let tuple_index = field.parse::<usize>()?;
@@ -349,6 +511,7 @@ fn read_field_mut<'r, 'p>(
                     ReflectPathError::InvalidField {
                         index: current_index,
                         field,
+                        suggestion: None,
                     },
                 )?)
             }
@@ -357,12 +520,262 @@ fn read_field_mut<'r, 'p>(
                 accessor: field,
             }),
         },
-        _ => Err(ReflectPathError::ExpectedStruct {
+        reflect_mut => Err(ReflectPathError::ExpectedStruct {
             index: current_index,
+            found: reflect_mut.kind(),
         }),
     }
 }
 
+fn struct_field_names(reflect_struct: &dyn Struct) -> impl Iterator<Item = &str> {
+    (0..reflect_struct.field_len()).filter_map(|index| reflect_struct.name_at(index))
+}
+
+fn variant_field_names(reflect_enum: &dyn Enum) -> impl Iterator<Item = &str> {
+    (0..reflect_enum.field_len()).filter_map(|index| reflect_enum.name_at(index))
+}
+
+/// Returns the name closest to `field` by edit distance,
+/// if it is close enough to plausibly be what was meant.
+fn suggest_field<'n>(field: &str, names: impl Iterator<Item = &'n str>) -> Option<String> {
+    let max_distance = (field.chars().count() / 3).max(1);
+    names
+        .map(|name| (edit_distance(field, name), name))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name.to_owned())
+}
+
+/// The edit distance between two strings, counting the insertion, removal
+/// or substitution of a character, or the swap of two adjacent characters, as one edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    // `distances[i][j]` is the distance between the first `i` chars of `a` and `j` chars of `b`.
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = distances[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = substitution
+                .min(distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+/// A single step of a [`ParsedPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Access {
+    /// A named field of a struct or struct variant, written `.field_name`.
+    Field(String),
+    /// An unnamed field of a tuple, tuple struct or tuple variant, written `.0`.
+    TupleIndex(usize),
+    /// An element of a list or array, written `[0]`.
+    ListIndex(usize),
+}
+
+impl Access {
+    fn from_ident(ident: &str) -> Self {
+        match ident.parse::<usize>() {
+            Ok(index) => Access::TupleIndex(index),
+            Err(_) => Access::Field(ident.to_string()),
+        }
+    }
+
+    fn element<'r>(&self, current: &'r dyn Reflect) -> Option<&'r dyn Reflect> {
+        match (self, current.reflect_ref()) {
+            (Access::Field(name), ReflectRef::Struct(reflect_struct)) => reflect_struct.field(name),
+            (Access::Field(name), ReflectRef::Enum(reflect_enum)) => reflect_enum.field(name),
+            (Access::TupleIndex(index), ReflectRef::TupleStruct(reflect_struct)) => {
+                reflect_struct.field(*index)
+            }
+            (Access::TupleIndex(index), ReflectRef::Tuple(reflect_tuple)) => {
+                reflect_tuple.field(*index)
+            }
+            (Access::TupleIndex(index), ReflectRef::Enum(reflect_enum)) => {
+                reflect_enum.field_at(*index)
+            }
+            (Access::ListIndex(index), ReflectRef::List(reflect_list)) => reflect_list.get(*index),
+            (Access::ListIndex(index), ReflectRef::Array(reflect_arr)) => reflect_arr.get(*index),
+            _ => None,
+        }
+    }
+
+    fn element_mut<'r>(&self, current: &'r mut dyn Reflect) -> Option<&'r mut dyn Reflect> {
+        match (self, current.reflect_mut()) {
+            (Access::Field(name), ReflectMut::Struct(reflect_struct)) => {
+                reflect_struct.field_mut(name)
+            }
+            (Access::Field(name), ReflectMut::Enum(reflect_enum)) => reflect_enum.field_mut(name),
+            (Access::TupleIndex(index), ReflectMut::TupleStruct(reflect_struct)) => {
+                reflect_struct.field_mut(*index)
+            }
+            (Access::TupleIndex(index), ReflectMut::Tuple(reflect_tuple)) => {
+                reflect_tuple.field_mut(*index)
+            }
+            (Access::TupleIndex(index), ReflectMut::Enum(reflect_enum)) => {
+                reflect_enum.field_at_mut(*index)
+            }
+            (Access::ListIndex(index), ReflectMut::List(reflect_list)) => {
+                reflect_list.get_mut(*index)
+            }
+            (Access::ListIndex(index), ReflectMut::Array(reflect_arr)) => {
+                reflect_arr.get_mut(*index)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Access::Field(name) => write!(f, ".{name}"),
+            Access::TupleIndex(index) => write!(f, ".{index}"),
+            Access::ListIndex(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+/// A path string which has been parsed into its [accesses](Access) ahead of time.
+///
+/// This is useful when the same path is used many times, or when a path is built up
+/// step by step, such as by [`visit`](crate::visit()). It is displayed using the same
+/// syntax as [`GetPath`] parses, omitting the initial `.`.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Access, ParsedPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Inventory {
+///     items: Vec<(String, u32)>,
+/// }
+///
+/// let inventory = Inventory { items: vec![(String::from("apple"), 3)] };
+/// let path = ParsedPath::parse("items[0].1").unwrap();
+/// assert_eq!(Some(&Access::ListIndex(0)), path.accesses().get(1));
+/// assert_eq!(Some(&3), path.element(&inventory).and_then(|count| count.downcast_ref::<u32>()));
+/// assert_eq!("items[0].1", path.to_string());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ParsedPath(Vec<Access>);
+
+impl ParsedPath {
+    /// Parses a path string, using the syntax described in [`GetPath`].
+    pub fn parse(path: &str) -> Result<Self, ReflectPathError<'_>> {
+        let mut index = 0;
+        let mut accesses = Vec::new();
+        while let Some(token) = next_token(path, &mut index) {
+            let current_index = index;
+            match token {
+                Token::Dot => {
+                    let Some(Token::Ident(value)) = next_token(path, &mut index) else {
+                        return Err(ReflectPathError::ExpectedIdent {
+                            index: current_index,
+                        });
+                    };
+                    accesses.push(Access::from_ident(value));
+                }
+                Token::OpenBracket => {
+                    let Some(Token::Ident(value)) = next_token(path, &mut index) else {
+                        return Err(ReflectPathError::ExpectedIdent {
+                            index: current_index,
+                        });
+                    };
+                    accesses.push(Access::ListIndex(value.parse()?));
+
+                    let Some(Token::CloseBracket) = next_token(path, &mut index) else {
+                        return Err(ReflectPathError::ExpectedToken {
+                            index: current_index,
+                            token: "]",
+                        });
+                    };
+                }
+                Token::CloseBracket => {
+                    return Err(ReflectPathError::UnexpectedToken {
+                        index: current_index,
+                        token: "]",
+                    })
+                }
+                Token::Ident(value) => accesses.push(Access::from_ident(value)),
+            }
+        }
+
+        Ok(ParsedPath(accesses))
+    }
+
+    /// The accesses making up this path, outermost first.
+    pub fn accesses(&self) -> &[Access] {
+        &self.0
+    }
+
+    /// Returns the number of accesses in this path.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if this path has no accesses, meaning it refers to the root value itself.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Appends an access to the end of this path.
+    pub fn push(&mut self, access: Access) {
+        self.0.push(access);
+    }
+
+    /// Removes the last access of this path, returning it.
+    pub fn pop(&mut self) -> Option<Access> {
+        self.0.pop()
+    }
+
+    /// Returns a reference to the value this path refers to within `root`,
+    /// or `None` if there is no such value.
+    pub fn element<'r>(&self, root: &'r dyn Reflect) -> Option<&'r dyn Reflect> {
+        self.0
+            .iter()
+            .try_fold(root, |current, access| access.element(current))
+    }
+
+    /// Returns a mutable reference to the value this path refers to within `root`,
+    /// or `None` if there is no such value.
+    pub fn element_mut<'r>(&self, root: &'r mut dyn Reflect) -> Option<&'r mut dyn Reflect> {
+        self.0
+            .iter()
+            .try_fold(root, |current, access| access.element_mut(current))
+    }
+}
+
+impl From<Vec<Access>> for ParsedPath {
+    fn from(accesses: Vec<Access>) -> Self {
+        ParsedPath(accesses)
+    }
+}
+
+impl fmt::Display for ParsedPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, access) in self.0.iter().enumerate() {
+            match access {
+                Access::Field(name) if index == 0 => write!(f, "{name}")?,
+                Access::TupleIndex(tuple_index) if index == 0 => write!(f, "{tuple_index}")?,
+                access => write!(f, "{access}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
 enum Token<'a> {
     Dot,
     OpenBracket,
@@ -526,7 +939,40 @@ mod tests {
             a.path("x.notreal").err().unwrap(),
             ReflectPathError::InvalidField {
                 index: 2,
-                field: "notreal"
+                field: "notreal",
+                suggestion: None,
+            }
+        );
+
+        let error = a.path("x.bra").err().unwrap();
+        assert_eq!(
+            error,
+            ReflectPathError::InvalidField {
+                index: 2,
+                field: "bra",
+                suggestion: Some(String::from("bar")),
+            }
+        );
+        assert_eq!(
+            "the current struct doesn't have a field with the name `bra`; did you mean `bar`?",
+            error.to_string()
+        );
+
+        assert_eq!(
+            a.path_mut("struct_variant.valeu").err().unwrap(),
+            ReflectPathError::InvalidField {
+                index: 15,
+                field: "valeu",
+                suggestion: Some(String::from("value")),
+            }
+        );
+
+        assert_eq!(
+            a.path("y[5]").err().unwrap(),
+            ReflectPathError::InvalidListIndex {
+                index: 2,
+                list_index: 5,
+                len: 2,
             }
         );
 
@@ -545,12 +991,26 @@ mod tests {
 
         assert_eq!(
             a.path("x[0]").err().unwrap(),
-            ReflectPathError::ExpectedList { index: 2 }
+            ReflectPathError::ExpectedList {
+                index: 2,
+                found: ReflectKind::Struct,
+            }
         );
 
         assert_eq!(
             a.path("y.x").err().unwrap(),
-            ReflectPathError::ExpectedStruct { index: 2 }
+            ReflectPathError::ExpectedStruct {
+                index: 2,
+                found: ReflectKind::List,
+            }
+        );
+
+        assert_eq!(
+            a.path_mut("w[0]").err().unwrap(),
+            ReflectPathError::ExpectedList {
+                index: 2,
+                found: ReflectKind::Value,
+            }
         );
 
         assert!(matches!(
@@ -558,4 +1018,57 @@ mod tests {
             Err(ReflectPathError::IndexParseError(_))
         ));
     }
+
+    #[test]
+    fn set_path_converts_values() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        #[reflect(FromReflect)]
+        struct Point(i32, i32);
+
+        #[derive(Reflect)]
+        struct Shape {
+            points: Vec<Point>,
+            sides: usize,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Point>();
+
+        let mut shape = Shape {
+            points: vec![Point(0, 0)],
+            sides: 3,
+        };
+
+        let mut point = DynamicTupleStruct::default();
+        point.insert(2_i32);
+        point.insert(4_i32);
+        shape.set_path("points[0]", point, &registry).unwrap();
+        assert_eq!(vec![Point(2, 4)], shape.points);
+
+        shape.set_path("sides", 4_usize, &registry).unwrap();
+        assert_eq!(4, shape.sides);
+        assert_eq!(
+            shape.set_path("sides", 5_u8, &registry),
+            Err(SetPathError::MissingFromReflect {
+                type_name: String::from("usize"),
+            })
+        );
+
+        registry.register_type_data::<usize, ReflectFromReflect>();
+        assert_eq!(
+            shape.set_path("sides", 5_u8, &registry),
+            Err(SetPathError::InvalidConversion {
+                from_type: String::from("u8"),
+                to_type: String::from("usize"),
+            })
+        );
+        assert_eq!(
+            shape.set_path("points[1]", Point(1, 1), &registry),
+            Err(SetPathError::Path(ReflectPathError::InvalidListIndex {
+                index: 7,
+                list_index: 1,
+                len: 1,
+            }))
+        );
+    }
 }
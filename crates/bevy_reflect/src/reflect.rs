@@ -1,13 +1,16 @@
 use crate::{
     array_debug, enum_debug, list_debug, map_debug, serde::Serializable, struct_debug, tuple_debug,
-    tuple_struct_debug, Array, Enum, List, Map, Struct, Tuple, TupleStruct, TypeInfo, Typed,
-    ValueInfo,
+    tuple_struct_debug, Array, DynamicTypePath, Enum, List, Map, Struct, Tuple, TupleStruct,
+    TypeInfo, Typed, ValueInfo,
 };
-use std::{
+use alloc::boxed::Box;
+use core::{
     any::{self, Any, TypeId},
-    fmt::Debug,
+    cmp::Ordering,
+    fmt::{self, Debug},
 };
 
+use crate::debug_options::debug_type_path;
 use crate::utility::NonGenericTypeInfoCell;
 pub use bevy_utils::AHasher as ReflectHasher;
 
@@ -62,6 +65,111 @@ pub enum ReflectOwned {
     Value(Box<dyn Reflect>),
 }
 
+/// A fieldless enumeration of "kinds" of reflected type.
+///
+/// Unlike [`ReflectRef`] and [`ReflectMut`], this does not borrow the value,
+/// so it may be kept around while the value is being modified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReflectKind {
+    Struct,
+    TupleStruct,
+    Tuple,
+    List,
+    Array,
+    Map,
+    Enum,
+    Value,
+}
+
+impl fmt::Display for ReflectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ReflectKind::Struct => "struct",
+            ReflectKind::TupleStruct => "tuple struct",
+            ReflectKind::Tuple => "tuple",
+            ReflectKind::List => "list",
+            ReflectKind::Array => "array",
+            ReflectKind::Map => "map",
+            ReflectKind::Enum => "enum",
+            ReflectKind::Value => "value",
+        })
+    }
+}
+
+impl<'a> ReflectRef<'a> {
+    /// Returns the [kind](ReflectKind) of the reflected value.
+    pub fn kind(&self) -> ReflectKind {
+        match self {
+            ReflectRef::Struct(_) => ReflectKind::Struct,
+            ReflectRef::TupleStruct(_) => ReflectKind::TupleStruct,
+            ReflectRef::Tuple(_) => ReflectKind::Tuple,
+            ReflectRef::List(_) => ReflectKind::List,
+            ReflectRef::Array(_) => ReflectKind::Array,
+            ReflectRef::Map(_) => ReflectKind::Map,
+            ReflectRef::Enum(_) => ReflectKind::Enum,
+            ReflectRef::Value(_) => ReflectKind::Value,
+        }
+    }
+}
+
+impl<'a> ReflectMut<'a> {
+    /// Returns the [kind](ReflectKind) of the reflected value.
+    pub fn kind(&self) -> ReflectKind {
+        match self {
+            ReflectMut::Struct(_) => ReflectKind::Struct,
+            ReflectMut::TupleStruct(_) => ReflectKind::TupleStruct,
+            ReflectMut::Tuple(_) => ReflectKind::Tuple,
+            ReflectMut::List(_) => ReflectKind::List,
+            ReflectMut::Array(_) => ReflectKind::Array,
+            ReflectMut::Map(_) => ReflectKind::Map,
+            ReflectMut::Enum(_) => ReflectKind::Enum,
+            ReflectMut::Value(_) => ReflectKind::Value,
+        }
+    }
+}
+
+impl ReflectOwned {
+    /// Returns the [kind](ReflectKind) of the reflected value.
+    pub fn kind(&self) -> ReflectKind {
+        match self {
+            ReflectOwned::Struct(_) => ReflectKind::Struct,
+            ReflectOwned::TupleStruct(_) => ReflectKind::TupleStruct,
+            ReflectOwned::Tuple(_) => ReflectKind::Tuple,
+            ReflectOwned::List(_) => ReflectKind::List,
+            ReflectOwned::Array(_) => ReflectKind::Array,
+            ReflectOwned::Map(_) => ReflectKind::Map,
+            ReflectOwned::Enum(_) => ReflectKind::Enum,
+            ReflectOwned::Value(_) => ReflectKind::Value,
+        }
+    }
+
+    /// Converts the value back into the box it was obtained from
+    /// with [`Reflect::reflect_owned`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{Reflect, ReflectKind};
+    /// let value: Box<dyn Reflect> = Box::new(vec![1_u32, 2]);
+    /// let owned = value.reflect_owned();
+    /// assert_eq!(ReflectKind::List, owned.kind());
+    ///
+    /// let value = owned.into_reflect();
+    /// assert_eq!(Some(&vec![1, 2]), value.downcast_ref::<Vec<u32>>());
+    /// ```
+    pub fn into_reflect(self) -> Box<dyn Reflect> {
+        match self {
+            ReflectOwned::Struct(value) => value.into_reflect(),
+            ReflectOwned::TupleStruct(value) => value.into_reflect(),
+            ReflectOwned::Tuple(value) => value.into_reflect(),
+            ReflectOwned::List(value) => value.into_reflect(),
+            ReflectOwned::Array(value) => value.into_reflect(),
+            ReflectOwned::Map(value) => value.into_reflect(),
+            ReflectOwned::Enum(value) => value.into_reflect(),
+            ReflectOwned::Value(value) => value,
+        }
+    }
+}
+
 /// A reflected Rust type.
 ///
 /// Methods for working with particular kinds of Rust type are available using the [`Array`], [`List`],
@@ -83,6 +191,29 @@ pub trait Reflect: Any + Send + Sync {
     /// [`TypeRegistry::get_type_info`]: crate::TypeRegistry::get_type_info
     fn get_type_info(&self) -> &'static TypeInfo;
 
+    /// Returns the [`TypePath`] of the underlying type as a [`DynamicTypePath`], if it has one.
+    ///
+    /// This is implemented by types deriving `Reflect`, unless they use `#[reflect(no_type_path)]`.
+    ///
+    /// [`TypePath`]: crate::TypePath
+    fn get_type_path(&self) -> Option<&dyn DynamicTypePath> {
+        None
+    }
+
+    /// Returns the [type path] of the underlying type.
+    ///
+    /// If the type has no [`TypePath`] (see [`Reflect::get_type_path`]), this falls back
+    /// to its [type name](Reflect::type_name).
+    ///
+    /// [type path]: crate::TypePath::type_path
+    /// [`TypePath`]: crate::TypePath
+    fn reflect_type_path(&self) -> &str {
+        match self.get_type_path() {
+            Some(type_path) => type_path.dyn_type_path(),
+            None => self.type_name(),
+        }
+    }
+
     /// Returns the value as a [`Box<dyn Any>`][std::any::Any].
     fn into_any(self: Box<Self>) -> Box<dyn Any>;
 
@@ -130,6 +261,11 @@ pub trait Reflect: Any + Send + Sync {
     /// or none of the above depending on the kind of type. For lists and maps, use the
     /// [`list_apply`] and [`map_apply`] helper functions when implementing this method.
     ///
+    /// Derived implementations of types which reflect `Clone`, with `#[reflect(Clone)]`,
+    /// skip the field-by-field walk when `value` is of the same concrete type as `self`,
+    /// and assign it with [`Clone::clone_from`] instead. Note that this also overwrites
+    /// any ignored fields, and bypasses any field setters.
+    ///
     /// [`list_apply`]: crate::list_apply
     /// [`map_apply`]: crate::map_apply
     ///
@@ -187,6 +323,24 @@ pub trait Reflect: Any + Send + Sync {
         None
     }
 
+    /// Returns a "partial ordering" comparison result.
+    ///
+    /// This is the ordering counterpart of [`reflect_partial_eq`](Reflect::reflect_partial_eq),
+    /// which lets reflected values be sorted without knowing their type:
+    ///
+    /// ```
+    /// # use bevy_reflect::Reflect;
+    /// let mut values: Vec<Box<dyn Reflect>> = vec![Box::new(3_u8), Box::new(1_u8), Box::new(2_u8)];
+    /// values.sort_by(|a, b| a.reflect_partial_cmp(&**b).unwrap());
+    /// assert_eq!(Some(&1), values[0].downcast_ref::<u8>());
+    /// ```
+    ///
+    /// If the underlying type does not support ordering, or the values cannot be compared,
+    /// returns `None`.
+    fn reflect_partial_cmp(&self, _value: &dyn Reflect) -> Option<Ordering> {
+        None
+    }
+
     /// Debug formatter for the value.
     ///
     /// Any value that is not an implementor of other `Reflect` subtraits
@@ -194,7 +348,7 @@ pub trait Reflect: Any + Send + Sync {
     /// where `type_name` is the [type name] of the underlying type.
     ///
     /// [type name]: Self::type_name
-    fn debug(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self.reflect_ref() {
             ReflectRef::Struct(dyn_struct) => struct_debug(dyn_struct, f),
             ReflectRef::TupleStruct(dyn_tuple_struct) => tuple_struct_debug(dyn_tuple_struct, f),
@@ -216,7 +370,14 @@ pub trait Reflect: Any + Send + Sync {
 }
 
 impl Debug for dyn Reflect {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let ReflectRef::Value(_) = self.reflect_ref() {
+            if let Some(type_path) = debug_type_path(self) {
+                write!(f, "{type_path}(")?;
+                self.debug(f)?;
+                return f.write_str(")");
+            }
+        }
         self.debug(f)
     }
 }
@@ -288,3 +449,69 @@ impl dyn Reflect {
         self.as_any_mut().downcast_mut::<T>()
     }
 }
+
+macro_rules! impl_try_as {
+    ($($kind:ident: $try_as:ident, $try_as_mut:ident, $name:literal;)*) => {
+        #[deny(rustdoc::broken_intra_doc_links)]
+        impl dyn Reflect {
+            $(
+                #[doc = concat!(
+                    "Returns the value as a [`", stringify!($kind), "`], ",
+                    "or `None` if it is not ", $name, "."
+                )]
+                #[inline]
+                pub fn $try_as(&self) -> Option<&dyn $kind> {
+                    match self.reflect_ref() {
+                        ReflectRef::$kind(value) => Some(value),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!(
+                    "Returns the value as a mutable [`", stringify!($kind), "`], ",
+                    "or `None` if it is not ", $name, "."
+                )]
+                #[inline]
+                pub fn $try_as_mut(&mut self) -> Option<&mut dyn $kind> {
+                    match self.reflect_mut() {
+                        ReflectMut::$kind(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+// Note that lists are not arrays here, even though every `List` is an `Array`:
+// `try_as_array` only succeeds for values whose kind is `ReflectRef::Array`.
+impl_try_as! {
+    Struct: try_as_struct, try_as_struct_mut, "a struct";
+    TupleStruct: try_as_tuple_struct, try_as_tuple_struct_mut, "a tuple struct";
+    Tuple: try_as_tuple, try_as_tuple_mut, "a tuple";
+    List: try_as_list, try_as_list_mut, "a list";
+    Array: try_as_array, try_as_array_mut, "an array";
+    Map: try_as_map, try_as_map_mut, "a map";
+    Enum: try_as_enum, try_as_enum_mut, "an enum";
+}
+
+/// Compares two sequences of reflected values lexicographically,
+/// with [`Reflect::reflect_partial_cmp`].
+///
+/// Returns `None` if any pair of values compared cannot be ordered.
+pub(crate) fn iter_partial_cmp<'a>(
+    mut a: impl Iterator<Item = &'a dyn Reflect>,
+    mut b: impl Iterator<Item = &'a dyn Reflect>,
+) -> Option<Ordering> {
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Some(Ordering::Equal),
+            (None, Some(_)) => return Some(Ordering::Less),
+            (Some(_), None) => return Some(Ordering::Greater),
+            (Some(a), Some(b)) => match a.reflect_partial_cmp(b)? {
+                Ordering::Equal => {}
+                ordering => return Some(ordering),
+            },
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{FromReflect, Reflect};
+use alloc::boxed::Box;
+use core::any::TypeId;
+
+type ReprConversion = fn(&dyn Reflect) -> Option<Box<dyn Reflect>>;
+
+/// Type data for types which are reflected through an intermediate representation type.
+///
+/// This is registered by the `Reflect` derive macro for types using the
+/// `#[reflect(from = "Repr")]` and/or `#[reflect(into = "Repr")]` container attributes.
+/// Such types are reflected as values, but can still be built from, or broken down into,
+/// a reflectable representation (usually a plain struct without the type's invariants).
+///
+/// The reflection serializers write such types as their `into` representation,
+/// and the deserializers read them as their `from` representation,
+/// so both representation types need to be registered.
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{DynamicStruct, FromReflect, Reflect, ReflectRepr, TypeRegistry};
+/// #[derive(Reflect, FromReflect)]
+/// struct PositiveRepr {
+///     value: i32,
+/// }
+///
+/// /// An `i32` that is always positive.
+/// #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+/// #[reflect(from = "PositiveRepr", into = "PositiveRepr")]
+/// struct Positive(i32);
+///
+/// impl TryFrom<PositiveRepr> for Positive {
+///     type Error = ();
+///
+///     fn try_from(repr: PositiveRepr) -> Result<Self, ()> {
+///         if repr.value > 0 { Ok(Positive(repr.value)) } else { Err(()) }
+///     }
+/// }
+///
+/// impl From<Positive> for PositiveRepr {
+///     fn from(positive: Positive) -> Self {
+///         PositiveRepr { value: positive.0 }
+///     }
+/// }
+///
+/// let mut repr = DynamicStruct::default();
+/// repr.insert("value", 5);
+/// assert_eq!(Some(Positive(5)), <Positive as FromReflect>::from_reflect(&repr));
+///
+/// repr.insert("value", -5);
+/// assert_eq!(None, <Positive as FromReflect>::from_reflect(&repr));
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Positive>();
+///
+/// let reflect_repr = registry
+///     .get_type_data::<ReflectRepr>(TypeId::of::<Positive>())
+///     .unwrap();
+/// let repr = reflect_repr.into_repr(&Positive(3)).unwrap();
+/// assert_eq!(Some(&3), repr.downcast_ref::<PositiveRepr>().map(|repr| &repr.value));
+/// ```
+#[derive(Clone, Default)]
+pub struct ReflectRepr {
+    from_repr: Option<(TypeId, ReprConversion)>,
+    into_repr: Option<(TypeId, ReprConversion)>,
+}
+
+impl ReflectRepr {
+    /// Allows `T` to be created from anything its representation `R` can be created from.
+    pub fn with_from<T: Reflect + TryFrom<R>, R: FromReflect>(mut self) -> Self {
+        self.from_repr = Some((TypeId::of::<R>(), |reflect| {
+            let repr = R::from_reflect(reflect)?;
+            let value = T::try_from(repr).ok()?;
+            Some(Box::new(value))
+        }));
+        self
+    }
+
+    /// Allows `T` to be converted into its representation `R`.
+    pub fn with_into<T: Reflect + Clone + Into<R>, R: Reflect>(mut self) -> Self {
+        self.into_repr = Some((TypeId::of::<R>(), |reflect| {
+            let value = reflect.downcast_ref::<T>()?.clone();
+            Some(Box::new(value.into()))
+        }));
+        self
+    }
+
+    /// Creates the reflected type from a value of (or dynamically representing) its
+    /// representation type.
+    ///
+    /// Returns `None` if the type has no `from` representation, the value could not be
+    /// converted into the representation type, or the representation was rejected.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_repr(&self, repr: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.from_repr?.1)(repr)
+    }
+
+    /// The [`TypeId`] of the `from` representation type, if there is one.
+    pub fn from_repr_type_id(&self) -> Option<TypeId> {
+        self.from_repr.map(|(type_id, _)| type_id)
+    }
+
+    /// Converts a value of the reflected type into its representation type.
+    ///
+    /// Returns `None` if the type has no `into` representation or `value` is not of the
+    /// reflected type.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_repr(&self, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.into_repr?.1)(value)
+    }
+
+    /// The [`TypeId`] of the `into` representation type, if there is one.
+    pub fn into_repr_type_id(&self) -> Option<TypeId> {
+        self.into_repr.map(|(type_id, _)| type_id)
+    }
+}
@@ -0,0 +1,619 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{
+    self, Deserialize, Deserializer, Error, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde::ser::{self, Serialize};
+
+/// A value read from a self-describing format and kept in memory,
+/// so that it can be deserialized again once more of its surroundings is known.
+///
+/// This is used for enum representations where the variant is not known before its content,
+/// such as untagged enums, which are read by trying every variant in turn.
+/// It is also used to compare values by their serialized form, such as to sort the keys of maps.
+#[derive(Clone, Debug)]
+pub(crate) enum Content {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Content>),
+    Unit,
+    Seq(Vec<Content>),
+    Map(Vec<(Content, Content)>),
+}
+
+impl Content {
+    /// Returns the string held by this content, if it is one.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Content::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Serializes the given value into a [`Content`].
+    pub fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<Content, ContentError> {
+        value.serialize(ContentSerializer)
+    }
+
+    /// The position of the kind of this content in the [order](Ord) of contents of different kinds.
+    fn rank(&self) -> u8 {
+        match self {
+            Content::Unit => 0,
+            Content::None => 1,
+            Content::Some(_) => 2,
+            Content::Bool(_) => 3,
+            Content::U64(_) => 4,
+            Content::I64(_) => 5,
+            Content::F64(_) => 6,
+            Content::Char(_) => 7,
+            Content::String(_) => 8,
+            Content::Bytes(_) => 9,
+            Content::Seq(_) => 10,
+            Content::Map(_) => 11,
+        }
+    }
+}
+
+/// Contents are ordered by kind, then by value, with floats in their [total order](f64::total_cmp).
+impl Ord for Content {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Content::Bool(a), Content::Bool(b)) => a.cmp(b),
+            (Content::U64(a), Content::U64(b)) => a.cmp(b),
+            (Content::I64(a), Content::I64(b)) => a.cmp(b),
+            (Content::F64(a), Content::F64(b)) => a.total_cmp(b),
+            (Content::Char(a), Content::Char(b)) => a.cmp(b),
+            (Content::String(a), Content::String(b)) => a.cmp(b),
+            (Content::Bytes(a), Content::Bytes(b)) => a.cmp(b),
+            (Content::Some(a), Content::Some(b)) => a.cmp(b),
+            (Content::Seq(a), Content::Seq(b)) => a.cmp(b),
+            (Content::Map(a), Content::Map(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for Content {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Content {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Content {}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn visit_char<E: Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(Content::Char(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Content::String(v.to_string()))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Content::String(v))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(v))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        Ok(Content::Some(Box::new(Content::deserialize(deserializer)?)))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        Content::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Content::Seq(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Content::Map(entries))
+    }
+}
+
+impl<'de, E: Error> IntoDeserializer<'de, E> for Content {
+    type Deserializer = ContentDeserializer<E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ContentDeserializer::new(self)
+    }
+}
+
+/// A deserializer reading a [`Content`] as if it came from the format it was read from.
+pub(crate) struct ContentDeserializer<E> {
+    content: Content,
+    marker: PhantomData<E>,
+}
+
+impl<E> ContentDeserializer<E> {
+    pub fn new(content: Content) -> Self {
+        Self {
+            content,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: Error> Deserializer<'de> for ContentDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::Char(v) => visitor.visit_char(v),
+            Content::String(v) => visitor.visit_string(v),
+            Content::Bytes(v) => visitor.visit_byte_buf(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Seq(items) => {
+                let mut seq = SeqDeserializer::new(items.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(entries) => {
+                let mut map = MapDeserializer::new(entries.into_iter());
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_unit(),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Content::Map(entries) if entries.len() == 1 => visitor.visit_enum(
+                MapAccessDeserializer::new(MapDeserializer::new(entries.into_iter())),
+            ),
+            _ => Err(de::Error::invalid_type(
+                de::Unexpected::Other("non-enum value"),
+                &"a string or a map with a single key",
+            )),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// The error returned when a value cannot be serialized into a [`Content`].
+#[derive(Debug)]
+pub(crate) struct ContentError(String);
+
+impl Display for ContentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl ser::StdError for ContentError {}
+
+impl ser::Error for ContentError {
+    fn custom<T: Display>(msg: T) -> Self {
+        ContentError(msg.to_string())
+    }
+}
+
+/// A serializer writing values into a [`Content`].
+///
+/// Enum variants are written like externally tagged enums,
+/// as their name alone or as a map from their name to their content.
+struct ContentSerializer;
+
+impl ser::Serializer for ContentSerializer {
+    type Ok = Content;
+    type Error = ContentError;
+    type SerializeSeq = SerializeSeq;
+    type SerializeTuple = SerializeSeq;
+    type SerializeTupleStruct = SerializeSeq;
+    type SerializeTupleVariant = SerializeSeq;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeMap;
+
+    fn serialize_bool(self, v: bool) -> Result<Content, ContentError> {
+        Ok(Content::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Content, ContentError> {
+        Ok(Content::I64(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Content, ContentError> {
+        Ok(Content::I64(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Content, ContentError> {
+        Ok(Content::I64(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Content, ContentError> {
+        Ok(Content::I64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Content, ContentError> {
+        Ok(Content::U64(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Content, ContentError> {
+        Ok(Content::U64(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Content, ContentError> {
+        Ok(Content::U64(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Content, ContentError> {
+        Ok(Content::U64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Content, ContentError> {
+        Ok(Content::F64(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Content, ContentError> {
+        Ok(Content::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Content, ContentError> {
+        Ok(Content::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Content, ContentError> {
+        Ok(Content::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Content, ContentError> {
+        Ok(Content::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Content, ContentError> {
+        Ok(Content::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Content, ContentError> {
+        Ok(Content::Some(Box::new(value.serialize(self)?)))
+    }
+
+    fn serialize_unit(self) -> Result<Content, ContentError> {
+        Ok(Content::Unit)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Content, ContentError> {
+        Ok(Content::Unit)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Content, ContentError> {
+        Ok(Content::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Content, ContentError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Content, ContentError> {
+        Ok(with_variant(Some(variant), value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeSeq, ContentError> {
+        Ok(SerializeSeq {
+            variant: None,
+            items: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeSeq, ContentError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, ContentError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, ContentError> {
+        Ok(SerializeSeq {
+            variant: Some(variant),
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, ContentError> {
+        Ok(SerializeMap {
+            variant: None,
+            entries: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeMap, ContentError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeMap, ContentError> {
+        Ok(SerializeMap {
+            variant: Some(variant),
+            entries: Vec::with_capacity(len),
+            key: None,
+        })
+    }
+}
+
+/// Wraps the content of a variant in a map from the name of the variant, if there is one.
+fn with_variant(variant: Option<&'static str>, content: Content) -> Content {
+    match variant {
+        Some(variant) => Content::Map(vec![(Content::String(variant.to_string()), content)]),
+        None => content,
+    }
+}
+
+struct SerializeSeq {
+    variant: Option<&'static str>,
+    items: Vec<Content>,
+}
+
+impl ser::SerializeSeq for SerializeSeq {
+    type Ok = Content;
+    type Error = ContentError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ContentError> {
+        self.items.push(value.serialize(ContentSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Content, ContentError> {
+        Ok(with_variant(self.variant, Content::Seq(self.items)))
+    }
+}
+
+impl ser::SerializeTuple for SerializeSeq {
+    type Ok = Content;
+    type Error = ContentError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ContentError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Content, ContentError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeSeq {
+    type Ok = Content;
+    type Error = ContentError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ContentError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Content, ContentError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeSeq {
+    type Ok = Content;
+    type Error = ContentError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ContentError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Content, ContentError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct SerializeMap {
+    variant: Option<&'static str>,
+    entries: Vec<(Content, Content)>,
+    /// The key of the entry whose value is serialized next.
+    key: Option<Content>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Content;
+    type Error = ContentError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ContentError> {
+        self.key = Some(key.serialize(ContentSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ContentError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ser::Error::custom("map value serialized before its key"))?;
+        self.entries
+            .push((key, value.serialize(ContentSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Content, ContentError> {
+        Ok(with_variant(self.variant, Content::Map(self.entries)))
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = Content;
+    type Error = ContentError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ContentError> {
+        ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<Content, ContentError> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeMap {
+    type Ok = Content;
+    type Error = ContentError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ContentError> {
+        ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<Content, ContentError> {
+        ser::SerializeMap::end(self)
+    }
+}
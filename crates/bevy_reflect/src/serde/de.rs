@@ -1,20 +1,38 @@
-use crate::serde::SerializationData;
+use crate::allocation_stats::{record_allocations, AllocationSource};
+use crate::serde::content::{Content, ContentDeserializer};
+use crate::serde::{
+    DeserializeFieldFn, EnumRepresentation, MapKeyEncoder, NonFiniteFloatPolicy,
+    ReflectDeserializerProcessor, ReflectSerializeBytes, ReflectVersion, SerializationData,
+    TypeIndices, SHARED_ID, SHARED_REF, SHARED_VALUE, VERSION_FIELD,
+};
 use crate::{
-    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, Tuple, TupleInfo, TupleStruct,
-    TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField,
-    VariantInfo,
+    Array, ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct,
+    DynamicTraitObject, DynamicTuple, DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map,
+    MapInfo, NamedField, Reflect, ReflectArc, ReflectCast, ReflectDeserialize, ReflectFromReflect,
+    ReflectMut, ReflectRepr, ReflectTraitObject, Struct, StructInfo, StructVariantInfo, Tuple,
+    TupleInfo, TupleStruct, TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration,
+    TypeRegistry, UnnamedField, VariantInfo,
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
 };
+use bevy_utils::HashMap;
+use core::any::TypeId;
+use core::cell::{Cell, RefCell};
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter, Write};
+use core::slice::Iter;
 use erased_serde::Deserializer;
+use once_cell::race::OnceBox;
 use serde::de::{
-    self, DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor,
+    self, DeserializeSeed, EnumAccess, Error, IgnoredAny, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
 };
 use serde::Deserialize;
-use std::any::TypeId;
-use std::fmt;
-use std::fmt::{Debug, Display, Formatter};
-use std::slice::Iter;
 
 pub trait DeserializeValue {
     fn deserialize(
@@ -26,6 +44,7 @@ pub trait DeserializeValue {
 trait StructLikeInfo {
     fn get_name(&self) -> &str;
     fn get_field(&self, name: &str) -> Option<&NamedField>;
+    fn get_field_index(&self, name: &str) -> Option<usize>;
     fn iter_fields(&self) -> Iter<'_, NamedField>;
 }
 
@@ -49,7 +68,11 @@ impl StructLikeInfo for StructInfo {
     }
 
     fn get_field(&self, name: &str) -> Option<&NamedField> {
-        self.field(name)
+        self.field(name).or_else(|| self.converted_field(name))
+    }
+
+    fn get_field_index(&self, name: &str) -> Option<usize> {
+        self.index_of(name)
     }
 
     fn iter_fields(&self) -> Iter<'_, NamedField> {
@@ -83,6 +106,10 @@ impl StructLikeInfo for StructVariantInfo {
         self.field(name)
     }
 
+    fn get_field_index(&self, name: &str) -> Option<usize> {
+        self.index_of(name)
+    }
+
     fn iter_fields(&self) -> Iter<'_, NamedField> {
         self.iter()
     }
@@ -210,6 +237,252 @@ impl<'de> Visitor<'de> for U32Visitor {
     }
 }
 
+/// Options for deserializing reflected values.
+///
+/// These must match the [`SerializerConfig`] the data was serialized with.
+///
+/// [`SerializerConfig`]: crate::serde::SerializerConfig
+#[derive(Clone, Debug, Default)]
+pub struct DeserializerConfig {
+    shared_references: bool,
+    unknown_fields: UnknownFieldPolicy,
+    positional: bool,
+    omit_none: bool,
+    non_finite_floats: NonFiniteFloatPolicy,
+    map_key_encoder: Option<Arc<dyn MapKeyEncoder>>,
+    type_indices: Option<Arc<TypeIndices>>,
+    processor: Option<Arc<dyn ReflectDeserializerProcessor>>,
+    enum_representation: EnumRepresentation,
+    transparent_structs: bool,
+}
+
+impl DeserializerConfig {
+    /// Sets what to do with the fields of structs and struct variants
+    /// which are present in the input but not in their [`TypeInfo`].
+    ///
+    /// Defaults to [`UnknownFieldPolicy::Deny`].
+    pub fn with_unknown_fields(mut self, unknown_fields: UnknownFieldPolicy) -> Self {
+        self.unknown_fields = unknown_fields;
+        self
+    }
+
+    /// Sets whether values shared between several `Arc`s were written only once,
+    /// as described in [`SerializerConfig::with_shared_references`].
+    ///
+    /// When enabled, every `Arc` referring to a shared value is deserialized as a clone
+    /// of the same `Arc`, which is kept by the `FromReflect` implementation of `Arc<T>`.
+    ///
+    /// [`SerializerConfig::with_shared_references`]: crate::serde::SerializerConfig::with_shared_references
+    pub fn with_shared_references(mut self, shared_references: bool) -> Self {
+        self.shared_references = shared_references;
+        self
+    }
+
+    /// Sets whether structs and struct variants were written as tuples of their fields,
+    /// as described in [`SerializerConfig::with_positional`].
+    ///
+    /// [`SerializerConfig::with_positional`]: crate::serde::SerializerConfig::with_positional
+    pub fn with_positional(mut self, positional: bool) -> Self {
+        self.positional = positional;
+        self
+    }
+
+    /// Sets whether `Option` fields missing from structs and struct variants are deserialized as `None`,
+    /// such as when they were left out with [`SerializerConfig::with_omit_none`].
+    ///
+    /// [`SerializerConfig::with_omit_none`]: crate::serde::SerializerConfig::with_omit_none
+    pub fn with_omit_none(mut self, omit_none: bool) -> Self {
+        self.omit_none = omit_none;
+        self
+    }
+
+    /// Sets how `f32` and `f64` values which are NaN or infinite were written,
+    /// as described in [`SerializerConfig::with_non_finite_floats`].
+    ///
+    /// With [`NonFiniteFloatPolicy::Null`] or [`NonFiniteFloatPolicy::String`],
+    /// floats are read with [`deserialize_any`], and numbers, null values and
+    /// the strings `NaN`, `inf` and `-inf` are all accepted.
+    ///
+    /// [`SerializerConfig::with_non_finite_floats`]: crate::serde::SerializerConfig::with_non_finite_floats
+    /// [`deserialize_any`]: serde::Deserializer::deserialize_any
+    pub fn with_non_finite_floats(mut self, non_finite_floats: NonFiniteFloatPolicy) -> Self {
+        self.non_finite_floats = non_finite_floats;
+        self
+    }
+
+    /// Sets the encoder converting the keys of maps back from strings,
+    /// as described in [`SerializerConfig::with_map_key_encoder`].
+    ///
+    /// [`SerializerConfig::with_map_key_encoder`]: crate::serde::SerializerConfig::with_map_key_encoder
+    pub fn with_map_key_encoder(mut self, encoder: impl MapKeyEncoder + 'static) -> Self {
+        self.map_key_encoder = Some(Arc::new(encoder));
+        self
+    }
+
+    /// Sets the indices which were written instead of type names,
+    /// as described in [`SerializerConfig::with_type_indices`].
+    ///
+    /// [`SerializerConfig::with_type_indices`]: crate::serde::SerializerConfig::with_type_indices
+    pub fn with_type_indices(mut self, type_indices: Arc<TypeIndices>) -> Self {
+        self.type_indices = Some(type_indices);
+        self
+    }
+
+    /// Sets the processor given the chance to deserialize each type before the deserializer does.
+    pub fn with_processor(
+        mut self,
+        processor: impl ReflectDeserializerProcessor + 'static,
+    ) -> Self {
+        self.processor = Some(Arc::new(processor));
+        self
+    }
+
+    /// Sets how the variant of enums was written,
+    /// as described in [`SerializerConfig::with_enum_representation`].
+    ///
+    /// Every representation other than [`EnumRepresentation::External`] is read with
+    /// [`deserialize_any`], which requires a self-describing format.
+    ///
+    /// [`SerializerConfig::with_enum_representation`]: crate::serde::SerializerConfig::with_enum_representation
+    /// [`deserialize_any`]: serde::Deserializer::deserialize_any
+    pub fn with_enum_representation(mut self, enum_representation: EnumRepresentation) -> Self {
+        self.enum_representation = enum_representation;
+        self
+    }
+
+    /// Sets whether tuple structs with a single field and unit structs were written without
+    /// their wrappers, as described in [`SerializerConfig::with_transparent_structs`].
+    ///
+    /// [`SerializerConfig::with_transparent_structs`]: crate::serde::SerializerConfig::with_transparent_structs
+    pub fn with_transparent_structs(mut self, transparent_structs: bool) -> Self {
+        self.transparent_structs = transparent_structs;
+        self
+    }
+}
+
+/// What the reflect deserializers do with fields which are not part of the type being deserialized,
+/// such as fields removed from a type since the data was written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnknownFieldPolicy {
+    /// Fails with an error naming the unknown field.
+    #[default]
+    Deny,
+    /// Skips the unknown field.
+    Ignore,
+    /// Keeps the unknown field in the deserialized [`DynamicStruct`] alongside the known ones,
+    /// so that it can be inspected or migrated.
+    ///
+    /// As its type is not known, the value is read as a self-describing value,
+    /// which requires a self-describing format such as RON or JSON.
+    /// Numbers, strings and booleans become `i64`, `u64`, `f64`, `String` and `bool` values,
+    /// sequences become [`DynamicList`]s, and maps become [`DynamicStruct`]s
+    /// if all of their keys are strings, or [`DynamicMap`]s otherwise.
+    ///
+    /// Collected fields are ignored by the derived [`FromReflect`] implementations.
+    ///
+    /// [`FromReflect`]: crate::FromReflect
+    Collect,
+}
+
+/// The state shared by the deserializers of a value and all of the values it contains.
+struct DeserializerContext<'a> {
+    registry: &'a TypeRegistry,
+    config: &'a DeserializerConfig,
+    /// The shared values read so far, by reference ID.
+    shared_values: RefCell<HashMap<u64, Box<dyn Reflect>>>,
+    /// The path to the value being deserialized, such as `Player.inventory[3].name`.
+    path: RefCell<String>,
+    /// Whether the error being returned already contains the path to where it occurred.
+    error_located: Cell<bool>,
+}
+
+impl<'a> DeserializerContext<'a> {
+    fn new(registry: &'a TypeRegistry, config: &'a DeserializerConfig) -> Self {
+        Self {
+            registry,
+            config,
+            shared_values: RefCell::default(),
+            path: RefCell::default(),
+            error_located: Cell::new(false),
+        }
+    }
+
+    /// Calls `visit` with `segment` appended to the current path,
+    /// adding the path and the kind of value expected there to the error it returns,
+    /// unless the error was already located by the deserializer of a nested value.
+    fn visit_path<T, E: Error>(
+        &self,
+        registration: &TypeRegistration,
+        segment: Option<&PathSegment>,
+        visit: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let path_len = {
+            let mut path = self.path.borrow_mut();
+            let path_len = path.len();
+            if path_len == 0 {
+                self.error_located.set(false);
+                path.push_str(registration.short_name());
+            }
+            if let Some(segment) = segment {
+                write!(path, "{segment}").unwrap();
+            }
+            path_len
+        };
+
+        let result = visit().map_err(|error| {
+            if self.error_located.replace(true) {
+                return error;
+            }
+            E::custom(format_args!(
+                "{error} at `{}` (expected {} `{}`)",
+                self.path.borrow(),
+                type_info_kind(registration.type_info()),
+                registration.type_name(),
+            ))
+        });
+        self.path.borrow_mut().truncate(path_len);
+        result
+    }
+}
+
+/// A step in the path to a value within its container, used to locate deserialization errors.
+enum PathSegment {
+    /// A named field of a struct or struct variant.
+    Field(&'static str),
+    /// An unnamed field of a tuple, tuple struct or tuple variant.
+    TupleField(usize),
+    /// An element of a list or array.
+    Index(usize),
+    /// The value of a map entry, by the debug representation of its key.
+    Key(String),
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Field(name) => write!(f, ".{name}"),
+            PathSegment::TupleField(index) => write!(f, ".{index}"),
+            PathSegment::Index(index) => write!(f, "[{index}]"),
+            PathSegment::Key(key) => write!(f, "[{key}]"),
+        }
+    }
+}
+
+/// Returns the kind of value described by the given [`TypeInfo`], for error messages.
+fn type_info_kind(type_info: &TypeInfo) -> &'static str {
+    match type_info {
+        TypeInfo::Struct(_) => "struct",
+        TypeInfo::TupleStruct(_) => "tuple struct",
+        TypeInfo::Tuple(_) => "tuple",
+        TypeInfo::List(_) => "list",
+        TypeInfo::Array(_) => "array",
+        TypeInfo::Map(_) => "map",
+        TypeInfo::Enum(_) => "enum",
+        TypeInfo::Value(_) => "value",
+        TypeInfo::Dynamic(_) => "dynamic value",
+    }
+}
+
 /// A general purpose deserializer for reflected types.
 ///
 /// This will return a [`Box<dyn Reflect>`] containing the deserialized data.
@@ -236,11 +509,21 @@ impl<'de> Visitor<'de> for U32Visitor {
 /// [type name]: std::any::type_name
 pub struct UntypedReflectDeserializer<'a> {
     registry: &'a TypeRegistry,
+    config: DeserializerConfig,
 }
 
 impl<'a> UntypedReflectDeserializer<'a> {
     pub fn new(registry: &'a TypeRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            config: DeserializerConfig::default(),
+        }
+    }
+
+    /// Sets the options to deserialize the value with.
+    pub fn with_config(mut self, config: DeserializerConfig) -> Self {
+        self.config = config;
+        self
     }
 }
 
@@ -251,9 +534,8 @@ impl<'a, 'de> DeserializeSeed<'de> for UntypedReflectDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
-            registry: self.registry,
-        })
+        let context = DeserializerContext::new(self.registry, &self.config);
+        deserializer.deserialize_map(UntypedReflectDeserializerVisitor { context: &context })
     }
 }
 
@@ -306,13 +588,13 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
 }
 
 struct UntypedReflectDeserializerVisitor<'a> {
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
     type Value = Box<dyn Reflect>;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("map containing `type` and `value` entries for the reflected value")
     }
 
@@ -320,12 +602,18 @@ impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
-        let registration = map
-            .next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-            .ok_or_else(|| Error::invalid_length(0, &"at least one entry"))?;
-        let value = map.next_value_seed(TypedReflectDeserializer {
+        let registration = match &self.context.config.type_indices {
+            Some(type_indices) => map
+                .next_key::<u32>()?
+                .map(|index| get_indexed_registration(index, type_indices, self.context.registry))
+                .transpose()?,
+            None => map.next_key_seed(TypeRegistrationDeserializer::new(self.context.registry))?,
+        }
+        .ok_or_else(|| Error::invalid_length(0, &"at least one entry"))?;
+        let value = map.next_value_seed(ValueDeserializer {
             registration,
-            registry: self.registry,
+            context: self.context,
+            segment: None,
         })?;
         Ok(value)
     }
@@ -352,6 +640,7 @@ impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
 pub struct TypedReflectDeserializer<'a> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    config: DeserializerConfig,
 }
 
 impl<'a> TypedReflectDeserializer<'a> {
@@ -359,19 +648,442 @@ impl<'a> TypedReflectDeserializer<'a> {
         Self {
             registration,
             registry,
+            config: DeserializerConfig::default(),
         }
     }
+
+    /// Sets the options to deserialize the value with.
+    pub fn with_config(mut self, config: DeserializerConfig) -> Self {
+        self.config = config;
+        self
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
     type Value = Box<dyn Reflect>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let context = DeserializerContext::new(self.registry, &self.config);
+        ValueDeserializer {
+            registration: self.registration,
+            context: &context,
+            segment: None,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+/// A deserializer which applies the data it reads onto an existing value,
+/// like [`Reflect::apply`] but without building a dynamic value for the whole input first.
+///
+/// Only the fields present in the input are changed, so it may contain any subset of the
+/// value's fields, at any depth. This makes it possible to overlay a partial config file,
+/// or to patch a live value, without knowing the rest of its contents.
+///
+/// Structs and maps are applied field by field and entry by entry, with map entries that are
+/// missing from the value being inserted. Every other kind of value, including enums and lists,
+/// is deserialized in full and then applied with [`Reflect::apply`].
+///
+/// Unknown fields are handled according to the [`UnknownFieldPolicy`] of the
+/// [`DeserializerConfig`], except that [`UnknownFieldPolicy::Collect`] ignores them,
+/// as there is nowhere to put them.
+///
+/// The type of the value must be registered in the given [`TypeRegistry`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, serde::ReflectApplyDeserializer};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect)]
+/// struct Window {
+///     title: String,
+///     width: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Window>();
+///
+/// let mut window = Window { title: String::from("Game"), width: 800.0 };
+/// let mut deserializer = ron::de::Deserializer::from_str("(width: 1280.0)").unwrap();
+/// ReflectApplyDeserializer::new(&mut window, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// assert_eq!("Game", window.title);
+/// assert_eq!(1280.0, window.width);
+/// ```
+pub struct ReflectApplyDeserializer<'a> {
+    value: &'a mut dyn Reflect,
+    registry: &'a TypeRegistry,
+    config: DeserializerConfig,
+}
+
+impl<'a> ReflectApplyDeserializer<'a> {
+    pub fn new(value: &'a mut dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self {
+            value,
+            registry,
+            config: DeserializerConfig::default(),
+        }
+    }
+
+    /// Sets the options to deserialize the value with.
+    pub fn with_config(mut self, config: DeserializerConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectApplyDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let type_name = self.value.type_name();
+        let registration = self.registry.get_with_name(type_name).ok_or_else(|| {
+            Error::custom(format_args!("no registration found for `{type_name}`"))
+        })?;
+        let context = DeserializerContext::new(self.registry, &self.config);
+        ApplyDeserializer {
+            value: self.value,
+            registration,
+            context: &context,
+            segment: None,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+/// The deserializer behind [`ReflectApplyDeserializer`], used for the value and everything it contains.
+struct ApplyDeserializer<'a, 'b> {
+    value: &'b mut dyn Reflect,
+    registration: &'a TypeRegistration,
+    context: &'a DeserializerContext<'a>,
+    /// Where the value is within its container, if it is part of one.
+    segment: Option<PathSegment>,
+}
+
+impl<'a, 'b, 'de> DeserializeSeed<'de> for ApplyDeserializer<'a, 'b> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ApplyDeserializer {
+            value,
+            registration,
+            context,
+            segment,
+        } = self;
+        context.visit_path(registration, segment.as_ref(), || {
+            // Values with a custom `ReflectDeserialize` or claimed by the processor
+            // have their own serialized form
+            let is_claimed = matches!(
+                &context.config.processor,
+                Some(processor) if processor.claims(registration)
+            );
+            if registration.data::<ReflectDeserialize>().is_none() && !is_claimed {
+                match (registration.type_info(), value.reflect_mut()) {
+                    (TypeInfo::Struct(struct_info), ReflectMut::Struct(value))
+                        if !is_transparent_unit_struct(struct_info, registration, context) =>
+                    {
+                        return deserializer.deserialize_struct(
+                            struct_info.name(),
+                            struct_info.field_names(),
+                            ApplyStructVisitor {
+                                value,
+                                struct_info,
+                                serialization_data: registration.data::<SerializationData>(),
+                                context,
+                            },
+                        );
+                    }
+                    (TypeInfo::Map(map_info), ReflectMut::Map(value)) => {
+                        return deserializer.deserialize_map(ApplyMapVisitor {
+                            value,
+                            map_info,
+                            context,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            let new_value = ValueDeserializer {
+                registration,
+                context,
+                segment: None,
+            }
+            .deserialize_value(deserializer)?;
+            value.apply(&*new_value);
+            Ok(())
+        })
+    }
+}
+
+struct ApplyStructVisitor<'a, 'b> {
+    value: &'b mut dyn Struct,
+    struct_info: &'static StructInfo,
+    serialization_data: Option<&'a SerializationData>,
+    context: &'a DeserializerContext<'a>,
+}
+
+impl<'a, 'b, 'de> Visitor<'de> for ApplyStructVisitor<'a, 'b> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map containing fields of a reflected struct")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            let Some(field) = self.struct_info.get_field(&key) else {
+                match self.context.config.unknown_fields {
+                    // Versions are not checked when applying data
+                    _ if key == VERSION_FIELD => {
+                        map.next_value::<IgnoredAny>()?;
+                        continue;
+                    }
+                    UnknownFieldPolicy::Deny => return Err(unknown_field(&key, self.struct_info)),
+                    UnknownFieldPolicy::Ignore | UnknownFieldPolicy::Collect => {
+                        map.next_value::<IgnoredAny>()?;
+                        continue;
+                    }
+                }
+            };
+            // Converted fields cannot be borrowed, so their values are applied to the whole struct
+            if self.struct_info.converted_field(field.name()).is_some() {
+                let value = map.next_value_seed(ValueDeserializer {
+                    registration: get_registration(
+                        field.type_id(),
+                        field.type_name(),
+                        self.context.registry,
+                    )?,
+                    context: self.context,
+                    segment: Some(PathSegment::Field(field.name())),
+                })?;
+                let mut patch = DynamicStruct::default();
+                patch.insert_boxed(field.name(), value);
+                self.value.apply(&patch);
+                continue;
+            }
+            let value = self.value.field_mut(field.name()).ok_or_else(|| {
+                Error::custom(format_args!(
+                    "missing field `{}` on the value",
+                    field.name()
+                ))
+            })?;
+            let index = self.struct_info.index_of(field.name());
+            let is_ignored = matches!(
+                (self.serialization_data, index),
+                (Some(data), Some(index))
+                    if data.is_ignored_field(index) || data.is_ignored_name(field.name())
+            );
+            if is_ignored {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+            let deserialize_fn = self
+                .serialization_data
+                .and_then(|data| data.named_deserialize_fn(field.name()));
+            if let Some(deserialize_fn) = deserialize_fn {
+                value.apply(
+                    map.next_value_seed(DeserializeWith(deserialize_fn))?
+                        .as_ref(),
+                );
+                continue;
+            }
+            let registration =
+                get_registration(field.type_id(), field.type_name(), self.context.registry)?;
+            map.next_value_seed(ApplyDeserializer {
+                value,
+                registration,
+                context: self.context,
+                segment: Some(PathSegment::Field(field.name())),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+struct ApplyMapVisitor<'a, 'b> {
+    value: &'b mut dyn Map,
+    map_info: &'static MapInfo,
+    context: &'a DeserializerContext<'a>,
+}
+
+impl<'a, 'b, 'de> Visitor<'de> for ApplyMapVisitor<'a, 'b> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map containing entries of a reflected map")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let key_registration = get_registration(
+            self.map_info.key_type_id(),
+            self.map_info.key_type_name(),
+            self.context.registry,
+        )?;
+        let value_registration = get_registration(
+            self.map_info.value_type_id(),
+            self.map_info.value_type_name(),
+            self.context.registry,
+        )?;
+        while let Some(key) = map.next_key_seed(MapKeyDeserializer {
+            registration: key_registration,
+            context: self.context,
+        })? {
+            let segment = Some(PathSegment::Key(format!("{key:?}")));
+            match self.value.get_mut(&*key) {
+                Some(value) => map.next_value_seed(ApplyDeserializer {
+                    value,
+                    registration: value_registration,
+                    context: self.context,
+                    segment,
+                })?,
+                None => {
+                    let value = map.next_value_seed(ValueDeserializer {
+                        registration: value_registration,
+                        context: self.context,
+                        segment,
+                    })?;
+                    self.value.insert_boxed(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The deserializer behind [`TypedReflectDeserializer`], used for the value and everything it contains.
+struct ValueDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    context: &'a DeserializerContext<'a>,
+    /// Where the value is within its container, if it is part of one.
+    segment: Option<PathSegment>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ValueDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = self
+            .context
+            .visit_path(self.registration, self.segment.as_ref(), || {
+                self.deserialize_value(deserializer)
+            })?;
+        record_allocations(AllocationSource::Deserialize, 1);
+        Ok(value)
+    }
+}
+
+impl<'a> ValueDeserializer<'a> {
+    fn deserialize_value<'de, D>(&self, deserializer: D) -> Result<Box<dyn Reflect>, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         let type_name = self.registration.type_name();
 
+        if let Some(processor) = &self.context.config.processor {
+            if processor.claims(self.registration) {
+                let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+                return processor
+                    .deserialize(self.registration, self.context.registry, &mut erased)
+                    .map_err(Error::custom);
+            }
+        }
+
+        if self.registration.type_id() == TypeId::of::<dyn Reflect>() {
+            let value = deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
+                context: self.context,
+            })?;
+            // Keep what is needed to convert the value back to its concrete type in `FromReflect`
+            let from_reflect = self
+                .context
+                .registry
+                .get_with_name(value.type_name())
+                .and_then(|registration| registration.data::<ReflectFromReflect>());
+            return Ok(match from_reflect {
+                Some(from_reflect) => Box::new(DynamicTraitObject::new(
+                    value,
+                    from_reflect.clone(),
+                    ReflectCast::default(),
+                )),
+                None => value,
+            });
+        }
+
+        if let Some(trait_object) = self.registration.data::<ReflectTraitObject>() {
+            return deserializer.deserialize_map(TraitObjectVisitor {
+                trait_object,
+                registration: self.registration,
+                context: self.context,
+            });
+        }
+
+        if let Some(reflect_arc) = self.registration.data::<ReflectArc>() {
+            let registration = self
+                .context
+                .registry
+                .get(reflect_arc.value_type_id())
+                .ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "no registration found for the value inside `{type_name}`",
+                    ))
+                })?;
+            if self.context.config.shared_references {
+                return deserializer.deserialize_map(SharedVisitor {
+                    reflect_arc,
+                    arc_registration: self.registration,
+                    registration,
+                    context: self.context,
+                });
+            }
+            return ValueDeserializer {
+                registration,
+                context: self.context,
+                segment: None,
+            }
+            .deserialize(deserializer);
+        }
+
+        if let Some(serialize_bytes) = self.registration.data::<ReflectSerializeBytes>() {
+            let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+            return Ok(serialize_bytes.from_bytes(bytes));
+        }
+
+        let is_float =
+            [TypeId::of::<f32>(), TypeId::of::<f64>()].contains(&self.registration.type_id());
+        if is_float
+            && matches!(
+                self.context.config.non_finite_floats,
+                NonFiniteFloatPolicy::Null | NonFiniteFloatPolicy::String
+            )
+        {
+            let float = deserializer.deserialize_any(FloatVisitor)?;
+            return Ok(if self.registration.type_id() == TypeId::of::<f32>() {
+                Box::new(float as f32)
+            } else {
+                Box::new(float)
+            });
+        }
+
         // Handle both Value case and types that have a custom `ReflectDeserialize`
         if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
             let value = deserialize_reflect.deserialize(deserializer)?;
@@ -379,46 +1091,126 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
         }
 
         match self.registration.type_info() {
+            TypeInfo::Struct(struct_info)
+                if is_transparent_unit_struct(struct_info, self.registration, self.context) =>
+            {
+                deserializer.deserialize_unit_struct(struct_info.name(), UnitStructVisitor)?;
+                let mut dynamic_struct = DynamicStruct::default();
+                dynamic_struct.set_name(struct_info.type_name().to_string());
+                Ok(Box::new(dynamic_struct))
+            }
             TypeInfo::Struct(struct_info) => {
-                let mut dynamic_struct = deserializer.deserialize_struct(
-                    struct_info.name(),
-                    struct_info.field_names(),
-                    StructVisitor {
-                        struct_info,
-                        registration: self.registration,
-                        registry: self.registry,
-                    },
-                )?;
+                let visitor = StructVisitor {
+                    struct_info,
+                    registration: self.registration,
+                    context: self.context,
+                };
+                let mut dynamic_struct = if self.context.config.positional {
+                    let ignored_len = self
+                        .registration
+                        .data::<SerializationData>()
+                        .map(|data| data.len())
+                        .unwrap_or(0);
+                    let version_len =
+                        usize::from(self.registration.data::<ReflectVersion>().is_some());
+                    deserializer.deserialize_tuple(
+                        struct_info.field_len() - ignored_len + version_len,
+                        visitor,
+                    )?
+                } else {
+                    deserializer.deserialize_struct(
+                        struct_info.name(),
+                        struct_info.field_names(),
+                        visitor,
+                    )?
+                };
                 dynamic_struct.set_name(struct_info.type_name().to_string());
                 Ok(Box::new(dynamic_struct))
             }
+            TypeInfo::TupleStruct(tuple_struct_info)
+                if self.context.config.transparent_structs
+                    && tuple_struct_info.field_len() == 1
+                    && self
+                        .registration
+                        .data::<SerializationData>()
+                        .map(|data| data.is_empty())
+                        .unwrap_or(true) =>
+            {
+                let deserialize_fn = self
+                    .registration
+                    .data::<SerializationData>()
+                    .and_then(|data| data.deserialize_fn(0));
+                let value = match deserialize_fn {
+                    Some(deserialize_fn) => {
+                        DeserializeWith(deserialize_fn).deserialize(deserializer)?
+                    }
+                    None => {
+                        let field = tuple_struct_info.field_at(0).unwrap();
+                        ValueDeserializer {
+                            registration: get_registration(
+                                field.type_id(),
+                                field.type_name(),
+                                self.context.registry,
+                            )?,
+                            context: self.context,
+                            segment: Some(PathSegment::TupleField(0)),
+                        }
+                        .deserialize(deserializer)?
+                    }
+                };
+                let mut dynamic_tuple_struct = DynamicTupleStruct::default();
+                dynamic_tuple_struct.insert_boxed(value);
+                dynamic_tuple_struct.set_name(tuple_struct_info.type_name().to_string());
+                Ok(Box::new(dynamic_tuple_struct))
+            }
             TypeInfo::TupleStruct(tuple_struct_info) => {
                 let mut dynamic_tuple_struct = deserializer.deserialize_tuple_struct(
                     tuple_struct_info.name(),
                     tuple_struct_info.field_len(),
                     TupleStructVisitor {
                         tuple_struct_info,
-                        registry: self.registry,
+                        context: self.context,
                         registration: self.registration,
                     },
                 )?;
                 dynamic_tuple_struct.set_name(tuple_struct_info.type_name().to_string());
                 Ok(Box::new(dynamic_tuple_struct))
             }
+            TypeInfo::List(list_info) if list_info.item_type_id() == TypeId::of::<u8>() => {
+                let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+                let mut dynamic_list = bytes
+                    .into_iter()
+                    .map(|byte| Box::new(byte) as Box<dyn Reflect>)
+                    .collect::<DynamicList>();
+                dynamic_list.set_name(list_info.type_name().to_string());
+                Ok(Box::new(dynamic_list))
+            }
             TypeInfo::List(list_info) => {
                 let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
                     list_info,
-                    registry: self.registry,
+                    context: self.context,
                 })?;
                 dynamic_list.set_name(list_info.type_name().to_string());
                 Ok(Box::new(dynamic_list))
             }
+            TypeInfo::Array(array_info) if array_info.item_type_id() == TypeId::of::<u8>() => {
+                let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+                if bytes.len() != array_info.capacity() {
+                    return Err(Error::invalid_length(
+                        bytes.len(),
+                        &array_info.capacity().to_string().as_str(),
+                    ));
+                }
+                let mut dynamic_array = DynamicArray::from_vec(bytes);
+                dynamic_array.set_name(array_info.type_name().to_string());
+                Ok(Box::new(dynamic_array))
+            }
             TypeInfo::Array(array_info) => {
                 let mut dynamic_array = deserializer.deserialize_tuple(
                     array_info.capacity(),
                     ArrayVisitor {
                         array_info,
-                        registry: self.registry,
+                        context: self.context,
                     },
                 )?;
                 dynamic_array.set_name(array_info.type_name().to_string());
@@ -427,7 +1219,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
             TypeInfo::Map(map_info) => {
                 let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
                     map_info,
-                    registry: self.registry,
+                    context: self.context,
                 })?;
                 dynamic_map.set_name(map_info.type_name().to_string());
                 Ok(Box::new(dynamic_map))
@@ -437,7 +1229,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     tuple_info.field_len(),
                     TupleVisitor {
                         tuple_info,
-                        registry: self.registry,
+                        context: self.context,
                     },
                 )?;
                 dynamic_tuple.set_name(tuple_info.type_name().to_string());
@@ -448,8 +1240,13 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 let mut dynamic_enum = if type_name.starts_with("core::option::Option") {
                     deserializer.deserialize_option(OptionVisitor {
                         enum_info,
-                        registry: self.registry,
+                        context: self.context,
                     })?
+                } else if self.context.config.enum_representation != EnumRepresentation::External
+                    && !self.context.config.positional
+                {
+                    let content = Content::deserialize(deserializer)?;
+                    self.deserialize_tagged_enum(enum_info, content)?
                 } else {
                     deserializer.deserialize_enum(
                         enum_info.name(),
@@ -457,7 +1254,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                         EnumVisitor {
                             enum_info,
                             registration: self.registration,
-                            registry: self.registry,
+                            context: self.context,
                         },
                     )?
                 };
@@ -465,6 +1262,27 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 Ok(Box::new(dynamic_enum))
             }
             TypeInfo::Value(_) => {
+                // Types reflected through a representation are read as their `from` representation
+                if let Some(reflect_repr) = self.registration.data::<ReflectRepr>() {
+                    if let Some(repr_type_id) = reflect_repr.from_repr_type_id() {
+                        let registration =
+                            self.context.registry.get(repr_type_id).ok_or_else(|| {
+                                Error::custom(format_args!(
+                                    "no registration found for the representation of `{type_name}`",
+                                ))
+                            })?;
+                        let repr = ValueDeserializer {
+                            registration,
+                            context: self.context,
+                            segment: None,
+                        }
+                        .deserialize_value(deserializer)?;
+                        return reflect_repr.from_repr(&*repr).ok_or_else(|| {
+                            Error::custom(format_args!("invalid representation for `{type_name}`",))
+                        });
+                    }
+                }
+
                 // This case should already be handled
                 Err(de::Error::custom(format_args!(
                     "the TypeRegistration for {type_name} doesn't have ReflectDeserialize",
@@ -481,16 +1299,317 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
     }
 }
 
+impl<'a> ValueDeserializer<'a> {
+    /// Deserializes an enum written with an [`EnumRepresentation`] other than the external one,
+    /// from its content read ahead of knowing its variant.
+    fn deserialize_tagged_enum<E: Error>(
+        &self,
+        enum_info: &'static EnumInfo,
+        content: Content,
+    ) -> Result<DynamicEnum, E> {
+        let (variant_info, content) = match self.context.config.enum_representation {
+            EnumRepresentation::External => {
+                unreachable!("externally tagged enums are read directly")
+            }
+            EnumRepresentation::Internal { tag } => {
+                let Content::Map(mut entries) = content else {
+                    return Err(Error::custom(format_args!(
+                        "expected a map with a `{tag}` field"
+                    )));
+                };
+                let tag_index = entries
+                    .iter()
+                    .position(|(key, _)| key.as_str() == Some(tag))
+                    .ok_or_else(|| Error::missing_field(tag))?;
+                let (_, variant) = entries.remove(tag_index);
+                let variant_info = deserialize_variant_name(enum_info, variant)?;
+                let content = match variant_info {
+                    VariantInfo::Unit(_) => Content::Unit,
+                    _ => Content::Map(entries),
+                };
+                (variant_info, content)
+            }
+            EnumRepresentation::Adjacent {
+                tag,
+                content: content_field,
+            } => {
+                let Content::Map(entries) = content else {
+                    return Err(Error::custom(format_args!(
+                        "expected a map with a `{tag}` field"
+                    )));
+                };
+                let mut variant = None;
+                let mut variant_content = None;
+                for (key, value) in entries {
+                    match key.as_str() {
+                        Some(key) if key == tag => variant = Some(value),
+                        Some(key) if key == content_field => variant_content = Some(value),
+                        _ => {
+                            return Err(Error::custom(format_args!(
+                                "unknown field {key:?}, expected `{tag}` or `{content_field}`"
+                            )));
+                        }
+                    }
+                }
+                let variant = variant.ok_or_else(|| Error::missing_field(tag))?;
+                let variant_info = deserialize_variant_name(enum_info, variant)?;
+                let content = match (variant_info, variant_content) {
+                    (_, Some(content)) => content,
+                    (VariantInfo::Unit(_), None) => Content::Unit,
+                    (_, None) => return Err(Error::missing_field(content_field)),
+                };
+                (variant_info, content)
+            }
+            EnumRepresentation::Untagged => {
+                for variant_info in enum_info.iter() {
+                    if let Ok(value) =
+                        self.deserialize_variant_content::<E>(variant_info, content.clone())
+                    {
+                        let mut dynamic_enum = DynamicEnum::default();
+                        dynamic_enum.set_variant(variant_info.name(), value);
+                        return Ok(dynamic_enum);
+                    }
+                    // The error of a variant which did not match is discarded
+                    self.context.error_located.set(false);
+                }
+                return Err(Error::custom(format_args!(
+                    "data did not match any variant of untagged enum `{}`",
+                    enum_info.name()
+                )));
+            }
+        };
+
+        let value = self.deserialize_variant_content(variant_info, content)?;
+        let mut dynamic_enum = DynamicEnum::default();
+        dynamic_enum.set_variant(variant_info.name(), value);
+        Ok(dynamic_enum)
+    }
+
+    /// Deserializes the content of the given variant, written without its name.
+    fn deserialize_variant_content<E: Error>(
+        &self,
+        variant_info: &'static VariantInfo,
+        content: Content,
+    ) -> Result<DynamicVariant, E> {
+        let deserializer = ContentDeserializer::<E>::new(content);
+        Ok(match variant_info {
+            VariantInfo::Unit(..) => {
+                <()>::deserialize(deserializer)?;
+                DynamicVariant::Unit
+            }
+            VariantInfo::Struct(struct_info) => serde::Deserializer::deserialize_map(
+                deserializer,
+                StructVariantVisitor {
+                    struct_info,
+                    registration: self.registration,
+                    context: self.context,
+                },
+            )?
+            .into(),
+            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                let field = tuple_info.field_at(0).unwrap();
+                let registration =
+                    get_registration(field.type_id(), field.type_name(), self.context.registry)?;
+                let value = ValueDeserializer {
+                    registration,
+                    context: self.context,
+                    segment: Some(PathSegment::TupleField(0)),
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_tuple = DynamicTuple::default();
+                dynamic_tuple.insert_boxed(value);
+                dynamic_tuple.into()
+            }
+            VariantInfo::Tuple(tuple_info) => serde::Deserializer::deserialize_seq(
+                deserializer,
+                TupleVariantVisitor {
+                    tuple_info,
+                    registration: self.registration,
+                    context: self.context,
+                },
+            )?
+            .into(),
+        })
+    }
+}
+
+/// Reads the name of a variant of the given enum from content read ahead.
+fn deserialize_variant_name<E: Error>(
+    enum_info: &'static EnumInfo,
+    content: Content,
+) -> Result<&'static VariantInfo, E> {
+    VariantDeserializer { enum_info }.deserialize(ContentDeserializer::new(content))
+}
+
+/// A visitor for the value inside an `Arc`, when [shared references] are enabled.
+///
+/// [shared references]: DeserializerConfig::with_shared_references
+struct SharedVisitor<'a> {
+    reflect_arc: &'a ReflectArc,
+    arc_registration: &'a TypeRegistration,
+    registration: &'a TypeRegistration,
+    context: &'a DeserializerContext<'a>,
+}
+
+impl<'a, 'de> Visitor<'de> for SharedVisitor<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map containing either the reference ID and value of a shared value or a reference to it")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let key = map
+            .next_key::<String>()?
+            .ok_or_else(|| Error::invalid_length(0, &"at least one entry"))?;
+        match key.as_str() {
+            SHARED_REF => {
+                let id = map.next_value::<u64>()?;
+                let shared_values = self.context.shared_values.borrow();
+                let value = shared_values.get(&id).ok_or_else(|| {
+                    Error::custom(format_args!("no shared value found with reference ID {id}"))
+                })?;
+                Ok(value.clone_value())
+            }
+            SHARED_ID => {
+                let id = map.next_value::<u64>()?;
+                match map.next_key::<String>()? {
+                    Some(key) if key == SHARED_VALUE => {}
+                    Some(key) => return Err(Error::unknown_field(&key, &[SHARED_VALUE])),
+                    None => return Err(Error::missing_field(SHARED_VALUE)),
+                }
+                let value = map.next_value_seed(ValueDeserializer {
+                    registration: self.registration,
+                    context: self.context,
+                    segment: None,
+                })?;
+                let arc = self.reflect_arc.wrap(value.as_ref()).ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "the shared value could not be converted to `{}`",
+                        self.arc_registration.type_name()
+                    ))
+                })?;
+                self.context
+                    .shared_values
+                    .borrow_mut()
+                    .insert(id, arc.clone_value());
+                Ok(arc)
+            }
+            key => Err(Error::unknown_field(key, &[SHARED_ID, SHARED_REF])),
+        }
+    }
+}
+
+struct TraitObjectVisitor<'a> {
+    trait_object: &'a ReflectTraitObject,
+    registration: &'a TypeRegistration,
+    context: &'a DeserializerContext<'a>,
+}
+
+impl<'a, 'de> Visitor<'de> for TraitObjectVisitor<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map containing the tag and value of the reflected trait object")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let registration = match &self.context.config.type_indices {
+            Some(type_indices) => {
+                let index = map
+                    .next_key::<u32>()?
+                    .ok_or_else(|| Error::invalid_length(0, &"one entry"))?;
+                get_indexed_registration(index, type_indices, self.context.registry)?
+            }
+            None => {
+                let tag = map
+                    .next_key::<String>()?
+                    .ok_or_else(|| Error::invalid_length(0, &"one entry"))?;
+                self.context
+                    .registry
+                    .get_with_tag(&tag)
+                    .or_else(|| self.context.registry.get_with_name(&tag))
+                    .ok_or_else(|| {
+                        Error::custom(format_args!("No registration found for `{tag}`"))
+                    })?
+            }
+        };
+        let from_reflect = registration.data::<ReflectFromReflect>().ok_or_else(|| {
+            Error::custom(format_args!(
+                "type `{}` did not register ReflectFromReflect",
+                registration.type_name()
+            ))
+        })?;
+        let reflect_cast = registration
+            .data::<ReflectCast>()
+            .filter(|reflect_cast| self.trait_object.can_cast(reflect_cast))
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "type `{}` cannot be stored in `{}`",
+                    registration.type_name(),
+                    self.registration.type_name()
+                ))
+            })?;
+
+        let value = map.next_value_seed(ValueDeserializer {
+            registration,
+            context: self.context,
+            segment: None,
+        })?;
+        Ok(Box::new(DynamicTraitObject::new(
+            value,
+            from_reflect.clone(),
+            reflect_cast.clone(),
+        )))
+    }
+}
+
+/// Returns `true` if the given struct is a unit struct written as a unit value,
+/// as described in [`SerializerConfig::with_transparent_structs`].
+///
+/// [`SerializerConfig::with_transparent_structs`]: crate::serde::SerializerConfig::with_transparent_structs
+fn is_transparent_unit_struct(
+    struct_info: &StructInfo,
+    registration: &TypeRegistration,
+    context: &DeserializerContext,
+) -> bool {
+    context.config.transparent_structs
+        && struct_info.field_len() == 0
+        && struct_info.converted_fields().is_empty()
+        && registration.data::<ReflectVersion>().is_none()
+}
+
+/// A visitor for unit structs written as a unit value.
+struct UnitStructVisitor;
+
+impl<'de> Visitor<'de> for UnitStructVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected unit struct value")
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(())
+    }
+}
+
 struct StructVisitor<'a> {
     struct_info: &'static StructInfo,
     registration: &'a TypeRegistration,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     type Value = DynamicStruct;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("reflected struct value")
     }
 
@@ -498,39 +1617,116 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registry)
+        let serialization_data = self.registration.data::<SerializationData>();
+        let Some(reflect_version) = self.registration.data::<ReflectVersion>() else {
+            return visit_struct(
+                &mut map,
+                self.struct_info,
+                serialization_data,
+                self.context,
+                None,
+            );
+        };
+
+        // Data without a version predates the type having one
+        let mut version = 0;
+        let dynamic_struct = visit_struct(
+            &mut map,
+            self.struct_info,
+            serialization_data,
+            self.context,
+            Some(&mut version),
+        )?;
+        if version != reflect_version.version() {
+            return reflect_version
+                .migrate(dynamic_struct, version)
+                .map_err(|error| Error::custom(format_args!("cannot migrate data: {error}")));
+        }
+
+        // Unknown fields were collected in case they had to be migrated
+        match self.context.config.unknown_fields {
+            UnknownFieldPolicy::Deny => {
+                let mut names = (0..dynamic_struct.field_len())
+                    .filter_map(|index| dynamic_struct.name_at(index));
+                match names.find(|name| self.struct_info.get_field(name).is_none()) {
+                    Some(name) => Err(unknown_field(name, self.struct_info)),
+                    None => Ok(dynamic_struct),
+                }
+            }
+            UnknownFieldPolicy::Ignore => {
+                let mut output = DynamicStruct::default();
+                for (index, value) in dynamic_struct.iter_fields().enumerate() {
+                    let name = dynamic_struct.name_at(index).unwrap();
+                    if self.struct_info.get_field(name).is_some() {
+                        output.insert_boxed(name, value.clone_value());
+                    }
+                }
+                Ok(output)
+            }
+            UnknownFieldPolicy::Collect => Ok(dynamic_struct),
+        }
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut index = 0usize;
         let mut output = DynamicStruct::default();
 
-        let ignored_len = self
-            .registration
-            .data::<SerializationData>()
-            .map(|data| data.len())
-            .unwrap_or(0);
-        let field_len = self.struct_info.field_len().saturating_sub(ignored_len);
-
-        if field_len == 0 {
-            // Handle unit structs and ignored fields
-            return Ok(output);
+        if let Some(reflect_version) = self.registration.data::<ReflectVersion>() {
+            let version: u32 = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(0, &"a version followed by the fields"))?;
+            if version != reflect_version.version() {
+                return Err(Error::custom(format_args!(
+                    "cannot migrate data of version {version} written as a sequence, expected version {}",
+                    reflect_version.version()
+                )));
+            }
         }
 
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
-            registration: self
-                .struct_info
-                .get_field_registration(index, self.registry)?,
-            registry: self.registry,
-        })? {
+        // Fields skipped during serialization are not part of the sequence
+        let serialization_data = self.registration.data::<SerializationData>();
+        let indices = (0..self.struct_info.field_len()).filter(|index| {
+            let name = self.struct_info.field_at(*index).unwrap().name();
+            !matches!(
+                serialization_data,
+                Some(data) if data.is_ignored_field(*index) || data.is_ignored_name(name)
+            )
+        });
+
+        for index in indices {
             let name = self.struct_info.field_at(index).unwrap().name();
-            output.insert_boxed(name, value);
-            index += 1;
-            if index >= self.struct_info.field_len() {
-                break;
+            let value = match serialization_data.and_then(|data| data.named_deserialize_fn(name)) {
+                Some(deserialize_fn) => seq.next_element_seed(DeserializeWith(deserialize_fn))?,
+                None => seq.next_element_seed(ValueDeserializer {
+                    registration: self
+                        .struct_info
+                        .get_field_registration(index, self.context.registry)?,
+                    context: self.context,
+                    segment: Some(PathSegment::Field(name)),
+                })?,
+            };
+            match value {
+                Some(value) => output.insert_boxed(name, value),
+                None => return Ok(output),
+            }
+        }
+
+        // Converted fields are written after the others
+        for field in self.struct_info.converted_fields() {
+            let value = seq.next_element_seed(ValueDeserializer {
+                registration: get_registration(
+                    field.type_id(),
+                    field.type_name(),
+                    self.context.registry,
+                )?,
+                context: self.context,
+                segment: Some(PathSegment::Field(field.name())),
+            })?;
+            match value {
+                Some(value) => output.insert_boxed(field.name(), value),
+                None => break,
             }
         }
 
@@ -540,14 +1736,14 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
 
 struct TupleStructVisitor<'a> {
     tuple_struct_info: &'static TupleStructInfo,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
     registration: &'a TypeRegistration,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
     type Value = DynamicTupleStruct;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("reflected tuple struct value")
     }
 
@@ -558,11 +1754,8 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
         let mut index = 0usize;
         let mut tuple_struct = DynamicTupleStruct::default();
 
-        let ignored_len = self
-            .registration
-            .data::<SerializationData>()
-            .map(|data| data.len())
-            .unwrap_or(0);
+        let serialization_data = self.registration.data::<SerializationData>();
+        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
         let field_len = self
             .tuple_struct_info
             .field_len()
@@ -581,13 +1774,21 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
                     self.tuple_struct_info.type_name(),
                 ))
             })?;
-            get_registration(field.type_id(), field.type_name(), self.registry)
+            get_registration(field.type_id(), field.type_name(), self.context.registry)
         };
 
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
-            registration: get_field_registration(index)?,
-            registry: self.registry,
-        })? {
+        loop {
+            let value = match serialization_data.and_then(|data| data.deserialize_fn(index)) {
+                Some(deserialize_fn) => seq.next_element_seed(DeserializeWith(deserialize_fn))?,
+                None => seq.next_element_seed(ValueDeserializer {
+                    registration: get_field_registration(index)?,
+                    context: self.context,
+                    segment: Some(PathSegment::TupleField(index)),
+                })?,
+            };
+            let Some(value) = value else {
+                break;
+            };
             tuple_struct.insert_boxed(value);
             index += 1;
             if index >= self.tuple_struct_info.field_len() {
@@ -595,51 +1796,142 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
             }
         }
 
-        let ignored_len = self
-            .registration
-            .data::<SerializationData>()
-            .map(|data| data.len())
-            .unwrap_or(0);
-        if tuple_struct.field_len() != self.tuple_struct_info.field_len() - ignored_len {
-            return Err(Error::invalid_length(
-                tuple_struct.field_len(),
-                &self.tuple_struct_info.field_len().to_string().as_str(),
-            ));
+        if tuple_struct.field_len() != self.tuple_struct_info.field_len() - ignored_len {
+            return Err(Error::invalid_length(
+                tuple_struct.field_len(),
+                &self.tuple_struct_info.field_len().to_string().as_str(),
+            ));
+        }
+
+        Ok(tuple_struct)
+    }
+}
+
+struct TupleVisitor<'a> {
+    tuple_info: &'static TupleInfo,
+    context: &'a DeserializerContext<'a>,
+}
+
+impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
+    type Value = DynamicTuple;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("reflected tuple value")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        visit_tuple(&mut seq, self.tuple_info, self.context)
+    }
+}
+
+/// A visitor for floats which may have been written as a null value or a string,
+/// according to a [`NonFiniteFloatPolicy`].
+struct FloatVisitor;
+
+impl<'de> Visitor<'de> for FloatVisitor {
+    type Value = f64;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a float, a null value or one of `NaN`, `inf` and `-inf`")
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v as f64)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v as f64)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match v.parse::<f64>() {
+            Ok(float) if !float.is_finite() => Ok(float),
+            _ => Err(Error::invalid_value(de::Unexpected::Str(v), &self)),
         }
+    }
 
-        Ok(tuple_struct)
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(f64::NAN)
     }
-}
 
-struct TupleVisitor<'a> {
-    tuple_info: &'static TupleInfo,
-    registry: &'a TypeRegistry,
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(f64::NAN)
+    }
 }
 
-impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
-    type Value = DynamicTuple;
+/// A visitor for lists and arrays of `u8`, serialized as bytes.
+///
+/// Sequences of numbers are also accepted,
+/// for formats which do not have a byte string type.
+struct BytesVisitor;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter.write_str("reflected tuple value")
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("bytes")
     }
 
-    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
     where
-        V: SeqAccess<'de>,
+        E: Error,
+    {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registry)
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
     }
 }
 
 struct ArrayVisitor<'a> {
     array_info: &'static ArrayInfo,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
     type Value = DynamicArray;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("reflected array value")
     }
 
@@ -651,11 +1943,12 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
         let registration = get_registration(
             self.array_info.item_type_id(),
             self.array_info.item_type_name(),
-            self.registry,
+            self.context.registry,
         )?;
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+        while let Some(value) = seq.next_element_seed(ValueDeserializer {
             registration,
-            registry: self.registry,
+            context: self.context,
+            segment: Some(PathSegment::Index(vec.len())),
         })? {
             vec.push(value);
         }
@@ -673,13 +1966,13 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
 
 struct ListVisitor<'a> {
     list_info: &'static ListInfo,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
     type Value = DynamicList;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("reflected list value")
     }
 
@@ -691,11 +1984,12 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
         let registration = get_registration(
             self.list_info.item_type_id(),
             self.list_info.item_type_name(),
-            self.registry,
+            self.context.registry,
         )?;
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+        while let Some(value) = seq.next_element_seed(ValueDeserializer {
             registration,
-            registry: self.registry,
+            context: self.context,
+            segment: Some(PathSegment::Index(list.len())),
         })? {
             list.push_box(value);
         }
@@ -705,13 +1999,13 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
 
 struct MapVisitor<'a> {
     map_info: &'static MapInfo,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
     type Value = DynamicMap;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
         formatter.write_str("reflected map value")
     }
 
@@ -723,20 +2017,21 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
         let key_registration = get_registration(
             self.map_info.key_type_id(),
             self.map_info.key_type_name(),
-            self.registry,
+            self.context.registry,
         )?;
         let value_registration = get_registration(
             self.map_info.value_type_id(),
             self.map_info.value_type_name(),
-            self.registry,
+            self.context.registry,
         )?;
-        while let Some(key) = map.next_key_seed(TypedReflectDeserializer {
+        while let Some(key) = map.next_key_seed(MapKeyDeserializer {
             registration: key_registration,
-            registry: self.registry,
+            context: self.context,
         })? {
-            let value = map.next_value_seed(TypedReflectDeserializer {
+            let value = map.next_value_seed(ValueDeserializer {
                 registration: value_registration,
-                registry: self.registry,
+                context: self.context,
+                segment: Some(PathSegment::Key(format!("{key:?}"))),
             })?;
             dynamic_map.insert_boxed(key, value);
         }
@@ -745,16 +2040,47 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
     }
 }
 
+/// A deserializer for the keys of maps,
+/// which decodes them from strings if a [`MapKeyEncoder`] was configured.
+struct MapKeyDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    context: &'a DeserializerContext<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapKeyDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match &self.context.config.map_key_encoder {
+            Some(encoder) => {
+                let key = String::deserialize(deserializer)?;
+                encoder
+                    .decode(&key, self.registration, self.context.registry)
+                    .map_err(Error::custom)
+            }
+            None => ValueDeserializer {
+                registration: self.registration,
+                context: self.context,
+                segment: None,
+            }
+            .deserialize(deserializer),
+        }
+    }
+}
+
 struct EnumVisitor<'a> {
     enum_info: &'static EnumInfo,
     registration: &'a TypeRegistration,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
     type Value = DynamicEnum;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         formatter.write_str("reflected enum value")
     }
 
@@ -769,23 +2095,27 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
 
         let value: DynamicVariant = match variant_info {
             VariantInfo::Unit(..) => variant.unit_variant()?.into(),
-            VariantInfo::Struct(struct_info) => variant
-                .struct_variant(
-                    struct_info.field_names(),
-                    StructVariantVisitor {
-                        struct_info,
-                        registration: self.registration,
-                        registry: self.registry,
-                    },
-                )?
-                .into(),
+            VariantInfo::Struct(struct_info) => {
+                let visitor = StructVariantVisitor {
+                    struct_info,
+                    registration: self.registration,
+                    context: self.context,
+                };
+                if self.context.config.positional {
+                    variant.tuple_variant(struct_info.field_len(), visitor)?
+                } else {
+                    variant.struct_variant(struct_info.field_names(), visitor)?
+                }
+                .into()
+            }
             VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
                 let field = tuple_info.field_at(0).unwrap();
                 let registration =
-                    get_registration(field.type_id(), field.type_name(), self.registry)?;
-                let value = variant.newtype_variant_seed(TypedReflectDeserializer {
+                    get_registration(field.type_id(), field.type_name(), self.context.registry)?;
+                let value = variant.newtype_variant_seed(ValueDeserializer {
                     registration,
-                    registry: self.registry,
+                    context: self.context,
+                    segment: Some(PathSegment::TupleField(0)),
                 })?;
                 let mut dynamic_tuple = DynamicTuple::default();
                 dynamic_tuple.insert_boxed(value);
@@ -797,7 +2127,7 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                     TupleVariantVisitor {
                         tuple_info,
                         registration: self.registration,
-                        registry: self.registry,
+                        context: self.context,
                     },
                 )?
                 .into(),
@@ -863,13 +2193,13 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
 struct StructVariantVisitor<'a> {
     struct_info: &'static StructVariantInfo,
     registration: &'a TypeRegistration,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     type Value = DynamicStruct;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         formatter.write_str("reflected struct variant value")
     }
 
@@ -877,7 +2207,7 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registry)
+        visit_struct(&mut map, self.struct_info, None, self.context, None)
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -899,11 +2229,15 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
             return Ok(output);
         }
 
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+        while let Some(value) = seq.next_element_seed(ValueDeserializer {
             registration: self
                 .struct_info
-                .get_field_registration(index, self.registry)?,
-            registry: self.registry,
+                .get_field_registration(index, self.context.registry)?,
+            context: self.context,
+            segment: self
+                .struct_info
+                .field_at(index)
+                .map(|field| PathSegment::Field(field.name())),
         })? {
             let name = self.struct_info.field_at(index).unwrap().name();
             output.insert_boxed(name, value);
@@ -920,13 +2254,13 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
 struct TupleVariantVisitor<'a> {
     tuple_info: &'static TupleVariantInfo,
     registration: &'a TypeRegistration,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
     type Value = DynamicTuple;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         formatter.write_str("reflected tuple variant value")
     }
 
@@ -946,13 +2280,13 @@ impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
             return Ok(DynamicTuple::default());
         }
 
-        visit_tuple(&mut seq, self.tuple_info, self.registry)
+        visit_tuple(&mut seq, self.tuple_info, self.context)
     }
 }
 
 struct OptionVisitor<'a> {
     enum_info: &'static EnumInfo,
-    registry: &'a TypeRegistry,
+    context: &'a DeserializerContext<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
@@ -972,10 +2306,11 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
             VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
                 let field = tuple_info.field_at(0).unwrap();
                 let registration =
-                    get_registration(field.type_id(), field.type_name(), self.registry)?;
-                let de = TypedReflectDeserializer {
+                    get_registration(field.type_id(), field.type_name(), self.context.registry)?;
+                let de = ValueDeserializer {
                     registration,
-                    registry: self.registry,
+                    context: self.context,
+                    segment: None,
                 };
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
@@ -1000,40 +2335,225 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
     }
 }
 
+fn unknown_field<E: Error>(key: &str, info: &impl StructLikeInfo) -> E {
+    let fields = info.iter_fields().map(|field| field.name());
+    Error::custom(format_args!(
+        "unknown field `{}`, expected one of {:?}",
+        key,
+        ExpectedValues(fields.collect())
+    ))
+}
+
+/// Reads the fields of a struct or struct variant.
+///
+/// If `version` is given, it is set from the [version entry](ReflectVersion) if there is one,
+/// and unknown fields are collected regardless of the [`UnknownFieldPolicy`].
 fn visit_struct<'de, T, V>(
     map: &mut V,
     info: &'static T,
-    registry: &TypeRegistry,
+    serialization_data: Option<&SerializationData>,
+    context: &DeserializerContext,
+    mut version: Option<&mut u32>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
     V: MapAccess<'de>,
 {
+    let unknown_fields = match version {
+        Some(_) => UnknownFieldPolicy::Collect,
+        None => context.config.unknown_fields,
+    };
     let mut dynamic_struct = DynamicStruct::default();
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field = info.get_field(&key).ok_or_else(|| {
-            let fields = info.iter_fields().map(|field| field.name());
-            Error::custom(format_args!(
-                "unknown field `{}`, expected one of {:?}",
-                key,
-                ExpectedValues(fields.collect())
-            ))
-        })?;
-        let registration = get_registration(field.type_id(), field.type_name(), registry)?;
-        let value = map.next_value_seed(TypedReflectDeserializer {
-            registration,
-            registry,
-        })?;
+        if let (Some(version), VERSION_FIELD) = (&mut version, key.as_str()) {
+            **version = map.next_value()?;
+            continue;
+        }
+        let field = match (info.get_field(&key), unknown_fields) {
+            (Some(field), _) => field,
+            (None, UnknownFieldPolicy::Deny) => return Err(unknown_field(&key, info)),
+            (None, UnknownFieldPolicy::Ignore) => {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+            (None, UnknownFieldPolicy::Collect) => {
+                let value = map.next_value_seed(UnknownValueDeserializer)?;
+                dynamic_struct.insert_boxed(&key, value);
+                continue;
+            }
+        };
+        let index = info.get_field_index(field.name());
+        // Values of fields skipped during serialization are never read
+        let is_ignored = matches!(
+            (serialization_data, index),
+            (Some(data), Some(index))
+                if data.is_ignored_field(index) || data.is_ignored_name(field.name())
+        );
+        if is_ignored {
+            map.next_value::<IgnoredAny>()?;
+            continue;
+        }
+        let deserialize_fn =
+            serialization_data.and_then(|data| data.named_deserialize_fn(field.name()));
+        let value = match deserialize_fn {
+            Some(deserialize_fn) => map.next_value_seed(DeserializeWith(deserialize_fn))?,
+            None => map.next_value_seed(ValueDeserializer {
+                registration: get_registration(
+                    field.type_id(),
+                    field.type_name(),
+                    context.registry,
+                )?,
+                context,
+                segment: Some(PathSegment::Field(field.name())),
+            })?,
+        };
         dynamic_struct.insert_boxed(&key, value);
     }
 
+    if context.config.omit_none {
+        for field in info.iter_fields() {
+            let is_option = field.type_name().starts_with("core::option::Option");
+            if is_option && dynamic_struct.field(field.name()).is_none() {
+                let mut none = DynamicEnum::default();
+                none.set_variant("None", ());
+                none.set_name(field.type_name().to_string());
+                dynamic_struct.insert(field.name(), none);
+            }
+        }
+    }
+
     Ok(dynamic_struct)
 }
 
+/// Deserializes a field with the function given by its [`SerializationData`].
+struct DeserializeWith(DeserializeFieldFn);
+
+impl<'de> DeserializeSeed<'de> for DeserializeWith {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+        (self.0)(&mut erased).map_err(Error::custom)
+    }
+}
+
+/// A deserializer for values whose type is not known, such as [collected] unknown fields.
+///
+/// [collected]: UnknownFieldPolicy::Collect
+pub(super) struct UnknownValueDeserializer;
+
+impl<'de> DeserializeSeed<'de> for UnknownValueDeserializer {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(UnknownValueVisitor)
+    }
+}
+
+struct UnknownValueVisitor;
+
+impl<'de> Visitor<'de> for UnknownValueVisitor {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any self-describing value")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Box::new(v.to_string()))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Box::new(v.to_vec()))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(()))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(()))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        UnknownValueDeserializer.deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        UnknownValueDeserializer.deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        while let Some(value) = seq.next_element_seed(UnknownValueDeserializer)? {
+            list.push_box(value);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::new();
+        while let Some((key, value)) =
+            map.next_entry_seed(UnknownValueDeserializer, UnknownValueDeserializer)?
+        {
+            entries.push((key, value));
+        }
+
+        if entries.iter().all(|(key, _)| key.is::<String>()) {
+            let mut dynamic_struct = DynamicStruct::default();
+            for (key, value) in entries {
+                let name = key.take::<String>().unwrap_or_default();
+                dynamic_struct.insert_boxed(&name, value);
+            }
+            Ok(Box::new(dynamic_struct))
+        } else {
+            let mut dynamic_map = DynamicMap::default();
+            for (key, value) in entries {
+                dynamic_map.insert_boxed(key, value);
+            }
+            Ok(Box::new(dynamic_map))
+        }
+    }
+}
+
 fn visit_tuple<'de, T, V>(
     seq: &mut V,
     info: &T,
-    registry: &TypeRegistry,
+    context: &DeserializerContext,
 ) -> Result<DynamicTuple, V::Error>
 where
     T: TupleLikeInfo,
@@ -1046,12 +2566,13 @@ where
         let field = info.get_field(index).ok_or_else(|| {
             Error::invalid_length(index, &info.get_field_len().to_string().as_str())
         })?;
-        get_registration(field.type_id(), field.type_name(), registry)
+        get_registration(field.type_id(), field.type_name(), context.registry)
     };
 
-    while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+    while let Some(value) = seq.next_element_seed(ValueDeserializer {
         registration: get_field_registration(index)?,
-        registry,
+        context,
+        segment: Some(PathSegment::TupleField(index)),
     })? {
         tuple.insert_boxed(value);
         index += 1;
@@ -1072,11 +2593,40 @@ where
     Ok(tuple)
 }
 
+/// Returns the registration of `dyn Reflect`, the type of fields which can hold a value of any type,
+/// such as `Box<dyn Reflect>` fields marked with `#[reflect(dynamic)]`.
+///
+/// Such fields are deserialized with an [`UntypedReflectDeserializer`] by the [`TypedReflectDeserializer`].
+fn dynamic_field_registration() -> &'static TypeRegistration {
+    static REGISTRATION: OnceBox<TypeRegistration> = OnceBox::new();
+    REGISTRATION.get_or_init(|| Box::new(TypeRegistration::of_unsized::<dyn Reflect>()))
+}
+
+/// Returns the registration of the type with the given [index](TypeIndices).
+fn get_indexed_registration<'a, E: Error>(
+    index: u32,
+    type_indices: &TypeIndices,
+    registry: &'a TypeRegistry,
+) -> Result<&'a TypeRegistration, E> {
+    let type_name = type_indices
+        .type_name(index)
+        .ok_or_else(|| Error::custom(format_args!("no type found with index {index}")))?;
+    registry.get_with_name(type_name).ok_or_else(|| {
+        Error::custom(format_args!(
+            "no registration found for `{type_name}` with index {index}"
+        ))
+    })
+}
+
 fn get_registration<'a, E: Error>(
     type_id: TypeId,
     type_name: &str,
     registry: &'a TypeRegistry,
 ) -> Result<&'a TypeRegistration, E> {
+    if type_id == TypeId::of::<dyn Reflect>() {
+        return Ok(dynamic_field_registration());
+    }
+
     let registration = registry.get(type_id).ok_or_else(|| {
         Error::custom(format_args!("no registration found for type `{type_name}`",))
     })?;
@@ -1086,8 +2636,8 @@ fn get_registration<'a, E: Error>(
 #[cfg(test)]
 mod tests {
     use bincode::Options;
-    use std::any::TypeId;
-    use std::f32::consts::PI;
+    use core::any::TypeId;
+    use core::f32::consts::PI;
 
     use serde::de::DeserializeSeed;
     use serde::Deserialize;
@@ -1095,8 +2645,14 @@ mod tests {
     use bevy_utils::HashMap;
 
     use crate as bevy_reflect;
-    use crate::serde::{TypedReflectDeserializer, UntypedReflectDeserializer};
-    use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeRegistry};
+    use crate::serde::{
+        DeserializerConfig, ReflectApplyDeserializer, TypedReflectDeserializer, UnknownFieldPolicy,
+        UntypedReflectDeserializer,
+    };
+    use crate::{
+        Array, DynamicEnum, DynamicList, DynamicStruct, FromReflect, GetField, Reflect,
+        ReflectDeserialize, Struct, TypeRegistry,
+    };
 
     #[derive(Reflect, FromReflect, Debug, PartialEq)]
     struct MyStruct {
@@ -1335,6 +2891,160 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_handle_unknown_fields() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Foo {
+            bar: i32,
+        }
+
+        let input = r#"(
+            bar: 123,
+            removed: (
+                name: "Old",
+                scores: [1, -2],
+            ),
+        )"#;
+
+        let mut registry = get_registry();
+        registry.register::<Foo>();
+        let registration = registry.get(TypeId::of::<Foo>()).unwrap();
+        let deserialize = |unknown_fields| {
+            let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry)
+                .with_config(DeserializerConfig::default().with_unknown_fields(unknown_fields));
+            let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            reflect_deserializer.deserialize(&mut ron_deserializer)
+        };
+
+        let error = deserialize(UnknownFieldPolicy::Deny).unwrap_err();
+        assert!(error.to_string().contains("unknown field `removed`"));
+
+        let output = deserialize(UnknownFieldPolicy::Ignore).unwrap();
+        let output = output.downcast_ref::<DynamicStruct>().unwrap();
+        assert_eq!(1, output.field_len());
+
+        let output = deserialize(UnknownFieldPolicy::Collect).unwrap();
+        let dynamic_struct = output.downcast_ref::<DynamicStruct>().unwrap();
+        let removed = dynamic_struct
+            .field("removed")
+            .and_then(|removed| removed.downcast_ref::<DynamicStruct>())
+            .unwrap();
+        assert_eq!(
+            Some(&String::from("Old")),
+            removed.get_field::<String>("name")
+        );
+        let scores = removed.get_field::<DynamicList>("scores").unwrap();
+        assert_eq!(Some(&1_u64), scores.get(0).unwrap().downcast_ref::<u64>());
+        assert_eq!(Some(&-2_i64), scores.get(1).unwrap().downcast_ref::<i64>());
+
+        let output = <Foo as FromReflect>::from_reflect(output.as_ref()).unwrap();
+        assert_eq!(Foo { bar: 123 }, output);
+    }
+
+    #[test]
+    fn should_report_error_path() {
+        #[derive(Reflect, FromReflect)]
+        struct Item {
+            name: String,
+        }
+
+        #[derive(Reflect)]
+        struct Player {
+            inventory: Vec<Item>,
+        }
+
+        let mut registry = get_registry();
+        registry.register::<Item>();
+        registry.register::<Vec<Item>>();
+        registry.register::<Player>();
+        let registration = registry.get(TypeId::of::<Player>()).unwrap();
+
+        let input = r#"(
+            inventory: [
+                (name: "Sword"),
+                (name: 123),
+            ],
+        )"#;
+
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let error = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.ends_with(
+                "at `Player.inventory[1].name` (expected value `alloc::string::String`)"
+            ),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn should_apply_onto_existing_value() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Settings {
+            name: String,
+            volume: Volume,
+            keys: HashMap<String, usize>,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Volume {
+            music: f32,
+            effects: f32,
+        }
+
+        let mut registry = get_registry();
+        registry.register::<Settings>();
+        registry.register::<Volume>();
+        registry.register::<f32>();
+        registry.register::<HashMap<String, usize>>();
+
+        let mut settings = Settings {
+            name: String::from("Default"),
+            volume: Volume {
+                music: 0.5,
+                effects: 0.5,
+            },
+            keys: HashMap::from([(String::from("jump"), 1), (String::from("run"), 2)]),
+        };
+
+        let input = r#"(
+            volume: (effects: 1.0),
+            keys: {
+                "run": 3,
+                "crouch": 4,
+            },
+        )"#;
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        ReflectApplyDeserializer::new(&mut settings, &registry)
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let expected = Settings {
+            name: String::from("Default"),
+            volume: Volume {
+                music: 0.5,
+                effects: 1.0,
+            },
+            keys: HashMap::from([
+                (String::from("jump"), 1),
+                (String::from("run"), 3),
+                (String::from("crouch"), 4),
+            ]),
+        };
+        assert_eq!(expected, settings);
+
+        let mut ron_deserializer =
+            ron::de::Deserializer::from_str("(volume: (bass: 1.0))").unwrap();
+        let error = ReflectApplyDeserializer::new(&mut settings, &registry)
+            .deserialize(&mut ron_deserializer)
+            .unwrap_err();
+        assert!(error.to_string().contains("unknown field `bass`"));
+        assert_eq!(expected, settings);
+    }
+
     #[test]
     fn should_deserialize_option() {
         #[derive(Reflect, FromReflect, Debug, PartialEq)]
@@ -0,0 +1,462 @@
+use crate::serde::{ReflectSerializeWith, ReflectVersion, SerializationData, VERSION_FIELD};
+use crate::{
+    NamedField, ReflectSerialize, ReflectTraitObject, TypeInfo, TypeRegistration, TypeRegistry,
+    UnnamedField, VariantInfo,
+};
+use alloc::borrow::Cow;
+use core::any::TypeId;
+use core::fmt::Write;
+use serde_json::{json, Map, Value};
+use std::path::PathBuf;
+
+/// The version of JSON Schema written by [`json_schema`].
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Builds a [JSON Schema] describing the JSON written for values of a registered type
+/// by a [`TypedReflectSerializer`] with the default [`SerializerConfig`].
+///
+/// Structs, tuple structs and enums are described in the `$defs` of the schema,
+/// under their type name, and referred to from wherever they are used.
+/// Their doc comments, and those of their fields and variants, are attached as the
+/// `description` of their schemas, so editors and other tools can show them as help text.
+/// Doc comments are only available with the `documentation` feature.
+///
+/// Values which are serialized with their own [`Serialize`] implementation,
+/// other than primitives and strings, as well as fields with a [custom serialization function],
+/// are described by an empty schema, which accepts any value.
+/// So are the types which are not registered in `registry`.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, GetTypeRegistration};
+/// # use bevy_reflect::serde::json_schema;
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     level: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let schema = json_schema(&Player::get_type_registration(), &registry);
+/// let player = &schema["$defs"][std::any::type_name::<Player>()];
+/// assert_eq!("string", player["properties"]["name"]["type"]);
+/// assert_eq!("integer", player["properties"]["level"]["type"]);
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org/
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`SerializerConfig`]: crate::serde::SerializerConfig
+/// [`Serialize`]: serde::Serialize
+/// [custom serialization function]: SerializationData::with_named_serialize_fn
+pub fn json_schema(registration: &TypeRegistration, registry: &TypeRegistry) -> Value {
+    let mut builder = SchemaBuilder {
+        registry,
+        definitions: Map::new(),
+    };
+    let mut schema = match builder.registration_schema(registration) {
+        Value::Object(schema) => schema,
+        _ => Map::new(),
+    };
+    schema.insert(String::from("$schema"), Value::from(SCHEMA_DIALECT));
+    if !builder.definitions.is_empty() {
+        schema.insert(String::from("$defs"), Value::Object(builder.definitions));
+    }
+    Value::Object(schema)
+}
+
+/// Gathers the definitions of the structs, tuple structs and enums reachable from a type.
+struct SchemaBuilder<'a> {
+    registry: &'a TypeRegistry,
+    definitions: Map<String, Value>,
+}
+
+impl<'a> SchemaBuilder<'a> {
+    /// Returns the schema of a field or element of the given type.
+    fn type_schema(&mut self, type_id: TypeId) -> Value {
+        // Fields which can hold any value are written along with their type name
+        if type_id == TypeId::of::<dyn crate::Reflect>() {
+            return json!({ "type": "object", "minProperties": 1, "maxProperties": 1 });
+        }
+        if let Some(schema) = primitive_schema(type_id) {
+            return schema;
+        }
+        match self.registry.get(type_id) {
+            Some(registration) => self.registration_schema(registration),
+            None => json!({}),
+        }
+    }
+
+    fn registration_schema(&mut self, registration: &TypeRegistration) -> Value {
+        if let Some(schema) = primitive_schema(registration.type_id()) {
+            return schema;
+        }
+        let info = registration.type_info();
+        if registration.data::<ReflectTraitObject>().is_some() {
+            return describe(
+                json!({ "type": "object", "minProperties": 1, "maxProperties": 1 }),
+                type_docs(info),
+            );
+        }
+        if registration.data::<ReflectSerialize>().is_some()
+            || registration.data::<ReflectSerializeWith>().is_some()
+        {
+            return describe(json!({}), type_docs(info));
+        }
+
+        match info {
+            TypeInfo::List(info) => json!({
+                "type": "array",
+                "items": self.type_schema(info.item_type_id()),
+            }),
+            TypeInfo::Array(info) => json!({
+                "type": "array",
+                "items": self.type_schema(info.item_type_id()),
+                "minItems": info.capacity(),
+                "maxItems": info.capacity(),
+            }),
+            TypeInfo::Map(info) => json!({
+                "type": "object",
+                "additionalProperties": self.type_schema(info.value_type_id()),
+            }),
+            TypeInfo::Tuple(info) => {
+                let fields = info.iter().map(UnnamedField::type_id).collect::<Vec<_>>();
+                self.tuple_schema(&fields)
+            }
+            TypeInfo::Enum(info) if info.type_name().starts_with("core::option::Option") => {
+                let some = match info.variant("Some") {
+                    Some(VariantInfo::Tuple(variant)) => variant.field_at(0),
+                    _ => None,
+                };
+                let some = match some {
+                    Some(field) => self.type_schema(field.type_id()),
+                    None => json!({}),
+                };
+                json!({ "anyOf": [{ "type": "null" }, some] })
+            }
+            TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) | TypeInfo::Enum(_) => {
+                let type_name = info.type_name();
+                if !self.definitions.contains_key(type_name) {
+                    // Reserve the name first, so that recursive types refer to it
+                    self.definitions
+                        .insert(type_name.to_string(), Value::Bool(true));
+                    let definition = self.definition(registration);
+                    self.definitions.insert(type_name.to_string(), definition);
+                }
+                json!({ "$ref": definition_ref(type_name) })
+            }
+            TypeInfo::Value(info) => describe(json!({}), info.docs()),
+            TypeInfo::Dynamic(_) => json!({}),
+        }
+    }
+
+    /// Builds the schema of a struct, tuple struct or enum, to be placed in the `$defs`.
+    fn definition(&mut self, registration: &TypeRegistration) -> Value {
+        let data = registration.data::<SerializationData>();
+        let is_ignored = |index: usize, name: Option<&str>| {
+            matches!(
+                data,
+                Some(data) if data.is_ignored_field(index)
+                    || matches!(name, Some(name) if data.is_ignored_name(name))
+            )
+        };
+        let has_serialize_fn = |index: usize, name: Option<&str>| match (data, name) {
+            (Some(data), Some(name)) => data.named_serialize_fn(name).is_some(),
+            (Some(data), None) => data.serialize_fn(index).is_some(),
+            (None, _) => false,
+        };
+
+        match registration.type_info() {
+            TypeInfo::Struct(info) => {
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+                if registration.data::<ReflectVersion>().is_some() {
+                    properties.insert(
+                        VERSION_FIELD.to_string(),
+                        json!({ "type": "integer", "minimum": 0 }),
+                    );
+                    required.push(VERSION_FIELD);
+                }
+                for (index, field) in info.iter().enumerate() {
+                    if is_ignored(index, Some(field.name())) {
+                        continue;
+                    }
+                    let schema = if has_serialize_fn(index, Some(field.name())) {
+                        json!({})
+                    } else {
+                        self.type_schema(field.type_id())
+                    };
+                    properties.insert(field.name().to_string(), describe(schema, field.docs()));
+                    required.push(field.name());
+                }
+                describe(object_schema(properties, required), info.docs())
+            }
+            TypeInfo::TupleStruct(info) => {
+                let mut items = Vec::new();
+                for (index, field) in info.iter().enumerate() {
+                    if is_ignored(index, None) {
+                        continue;
+                    }
+                    let schema = if has_serialize_fn(index, None) {
+                        json!({})
+                    } else {
+                        self.type_schema(field.type_id())
+                    };
+                    items.push(describe(schema, field.docs()));
+                }
+                describe(array_schema(items), info.docs())
+            }
+            TypeInfo::Enum(info) => {
+                let variants = info
+                    .iter()
+                    .map(|variant| self.variant_schema(variant))
+                    .collect::<Vec<_>>();
+                describe(json!({ "oneOf": variants }), info.docs())
+            }
+            _ => json!({}),
+        }
+    }
+
+    /// Returns the schema of an externally tagged enum variant.
+    fn variant_schema(&mut self, variant: &VariantInfo) -> Value {
+        let content = match variant {
+            VariantInfo::Unit(info) => {
+                return describe(json!({ "const": info.name() }), info.docs());
+            }
+            VariantInfo::Tuple(info) if info.field_len() == 1 => {
+                let field = info.field_at(0).unwrap();
+                describe(self.type_schema(field.type_id()), field.docs())
+            }
+            VariantInfo::Tuple(info) => {
+                let fields = info.iter().map(UnnamedField::type_id).collect::<Vec<_>>();
+                self.tuple_schema(&fields)
+            }
+            VariantInfo::Struct(info) => {
+                let mut properties = Map::new();
+                for field in info.iter() {
+                    let schema = self.type_schema(field.type_id());
+                    properties.insert(field.name().to_string(), describe(schema, field.docs()));
+                }
+                let required = info.iter().map(NamedField::name).collect();
+                object_schema(properties, required)
+            }
+        };
+        let mut properties = Map::new();
+        properties.insert(variant.name().to_string(), content);
+        describe(
+            object_schema(properties, vec![variant.name()]),
+            variant.docs(),
+        )
+    }
+
+    fn tuple_schema(&mut self, fields: &[TypeId]) -> Value {
+        let items = fields
+            .iter()
+            .map(|type_id| self.type_schema(*type_id))
+            .collect();
+        array_schema(items)
+    }
+}
+
+/// Returns the schema of an object with exactly the given properties.
+fn object_schema(properties: Map<String, Value>, required: Vec<&str>) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Returns the schema of an array with exactly the given items, as written for tuples.
+fn array_schema(items: Vec<Value>) -> Value {
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+/// Returns the schema of the primitives and strings, as written by their `Serialize` impls.
+fn primitive_schema(type_id: TypeId) -> Option<Value> {
+    let is = |types: &[TypeId]| types.contains(&type_id);
+    let schema = if type_id == TypeId::of::<bool>() {
+        json!({ "type": "boolean" })
+    } else if is(&[
+        TypeId::of::<u8>(),
+        TypeId::of::<u16>(),
+        TypeId::of::<u32>(),
+        TypeId::of::<u64>(),
+        TypeId::of::<u128>(),
+        TypeId::of::<usize>(),
+    ]) {
+        json!({ "type": "integer", "minimum": 0 })
+    } else if is(&[
+        TypeId::of::<i8>(),
+        TypeId::of::<i16>(),
+        TypeId::of::<i32>(),
+        TypeId::of::<i64>(),
+        TypeId::of::<i128>(),
+        TypeId::of::<isize>(),
+    ]) {
+        json!({ "type": "integer" })
+    } else if is(&[TypeId::of::<f32>(), TypeId::of::<f64>()]) {
+        json!({ "type": "number" })
+    } else if type_id == TypeId::of::<char>() {
+        json!({ "type": "string", "minLength": 1, "maxLength": 1 })
+    } else if is(&[
+        TypeId::of::<String>(),
+        TypeId::of::<&'static str>(),
+        TypeId::of::<Cow<'static, str>>(),
+        TypeId::of::<PathBuf>(),
+    ]) {
+        json!({ "type": "string" })
+    } else {
+        return None;
+    };
+    Some(schema)
+}
+
+/// Returns the doc comment of a type.
+fn type_docs(info: &TypeInfo) -> Option<&str> {
+    match info {
+        TypeInfo::Struct(info) => info.docs(),
+        TypeInfo::TupleStruct(info) => info.docs(),
+        TypeInfo::Tuple(info) => info.docs(),
+        TypeInfo::List(info) => info.docs(),
+        TypeInfo::Array(info) => info.docs(),
+        TypeInfo::Map(info) => info.docs(),
+        TypeInfo::Enum(info) => info.docs(),
+        TypeInfo::Value(info) => info.docs(),
+        TypeInfo::Dynamic(info) => info.docs(),
+    }
+}
+
+/// Attaches a doc comment to a schema as its `description`.
+///
+/// The space left at the start of each line of a doc comment by `///` is removed.
+fn describe(mut schema: Value, docs: Option<&str>) -> Value {
+    if let (Value::Object(object), Some(docs)) = (&mut schema, docs) {
+        let description = docs
+            .lines()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        object.insert(String::from("description"), Value::from(description.trim()));
+    }
+    schema
+}
+
+/// Returns the `$ref` to the definition of the given type.
+///
+/// Type names are escaped as a JSON pointer, then percent-encoded to form a valid URI fragment.
+fn definition_ref(type_name: &str) -> String {
+    let mut reference = String::from("#/$defs/");
+    for byte in type_name.replace('~', "~0").replace('/', "~1").bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~:".contains(&byte) {
+            reference.push(byte as char);
+        } else {
+            write!(reference, "%{byte:02X}").unwrap();
+        }
+    }
+    reference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::json_schema;
+    use crate::{self as bevy_reflect, FromReflect, GetTypeRegistration, Reflect, TypeRegistry};
+    use serde_json::json;
+
+    /// A value which may be missing.
+    #[derive(Reflect, FromReflect)]
+    enum Slot {
+        /// Nothing in the slot.
+        Empty,
+        Item(String),
+        Stack {
+            count: u8,
+            item: String,
+        },
+    }
+
+    /// A container of items.
+    #[derive(Reflect)]
+    struct Inventory {
+        /// The slots of the inventory.
+        slots: Vec<Slot>,
+        #[reflect(skip_serializing)]
+        cache: u32,
+        owner: Option<(u32, f32)>,
+        hand: Slot,
+    }
+
+    #[test]
+    fn should_describe_registered_types() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Inventory>();
+        registry.register::<Slot>();
+        registry.register::<Vec<Slot>>();
+        registry.register::<Option<(u32, f32)>>();
+        registry.register::<(u32, f32)>();
+
+        let schema = json_schema(&Inventory::get_type_registration(), &registry);
+        let inventory_name = core::any::type_name::<Inventory>();
+        let slot_name = core::any::type_name::<Slot>();
+        let inventory = &schema["$defs"][inventory_name];
+        let slot = &schema["$defs"][slot_name];
+
+        assert_eq!(
+            "https://json-schema.org/draft/2020-12/schema",
+            schema["$schema"]
+        );
+        assert_eq!(json!(["slots", "owner", "hand"]), inventory["required"]);
+        assert!(inventory["properties"].get("cache").is_none());
+        assert_eq!(
+            json!({
+                "anyOf": [
+                    { "type": "null" },
+                    {
+                        "type": "array",
+                        "prefixItems": [{ "type": "integer", "minimum": 0 }, { "type": "number" }],
+                        "items": false,
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                ]
+            }),
+            inventory["properties"]["owner"]
+        );
+        // Types used in several places are defined once
+        assert_eq!(
+            inventory["properties"]["slots"]["items"]["$ref"],
+            inventory["properties"]["hand"]["$ref"]
+        );
+        assert_eq!(2, schema["$defs"].as_object().unwrap().len());
+
+        let variants = slot["oneOf"].as_array().unwrap();
+        assert_eq!("Empty", variants[0]["const"]);
+        assert_eq!(
+            json!({ "type": "string" }),
+            variants[1]["properties"]["Item"]
+        );
+        assert_eq!(
+            json!(["count", "item"]),
+            variants[2]["properties"]["Stack"]["required"]
+        );
+
+        #[cfg(feature = "documentation")]
+        {
+            assert_eq!("A container of items.", inventory["description"]);
+            assert_eq!(
+                "The slots of the inventory.",
+                inventory["properties"]["slots"]["description"]
+            );
+            assert_eq!("Nothing in the slot.", variants[0]["description"]);
+        }
+    }
+}
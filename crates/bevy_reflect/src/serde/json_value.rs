@@ -0,0 +1,150 @@
+use crate::serde::de::UnknownValueDeserializer;
+use crate::serde::{ReflectSerializer, TypedReflectSerializer, UntypedReflectDeserializer};
+use crate::{Reflect, ReflectRef, TypeRegistry, VariantType};
+use serde::de::{DeserializeSeed, Error};
+use serde_json::{Map, Value};
+
+/// Converts a reflected value to a [`serde_json::Value`], without writing it out as text.
+///
+/// If the type of the value is registered, it is written like by a [`ReflectSerializer`],
+/// as a map from its type name to its serialized form, which [`from_json_value`] reads back.
+///
+/// Otherwise, such as for dynamic values which do not represent a registered type,
+/// the value is written as a tree of its contents: structs and maps become objects,
+/// lists, arrays and tuples become arrays, and enums become either the name of their variant
+/// or an object from that name to the content of the variant.
+/// Contents of registered types within that tree are written like by a [`TypedReflectSerializer`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{DynamicStruct, TypeRegistry};
+/// # use bevy_reflect::serde::to_json_value;
+/// let mut value = DynamicStruct::default();
+/// value.insert("name", String::from("Ferris"));
+/// value.insert("level", 3u32);
+///
+/// let json = to_json_value(&value, &TypeRegistry::default()).unwrap();
+/// assert_eq!(serde_json::json!({"name": "Ferris", "level": 3}), json);
+/// ```
+pub fn to_json_value(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<Value, serde_json::Error> {
+    if registry.get_with_name(value.type_name()).is_some() {
+        serde_json::to_value(ReflectSerializer::new(value, registry))
+    } else {
+        to_json_tree(value, registry)
+    }
+}
+
+/// Reads a reflected value from a [`serde_json::Value`], without writing it out as text first.
+///
+/// A map from the name of a registered type to its serialized form, such as made by
+/// [`to_json_value`], is read like by the [`UntypedReflectDeserializer`].
+/// Any other value is read as a tree of dynamic values: numbers, strings and booleans become
+/// `i64`, `u64`, `f64`, `String` and `bool` values, arrays become [`DynamicList`]s,
+/// and objects become [`DynamicStruct`]s.
+///
+/// The value is borrowed, so its strings are only copied into the values which hold them.
+///
+/// [`DynamicList`]: crate::DynamicList
+/// [`DynamicStruct`]: crate::DynamicStruct
+pub fn from_json_value(
+    registry: &TypeRegistry,
+    value: &Value,
+) -> Result<Box<dyn Reflect>, serde_json::Error> {
+    match value {
+        Value::Object(map)
+            if map.len() == 1 && map.keys().all(|key| registry.get_with_name(key).is_some()) =>
+        {
+            UntypedReflectDeserializer::new(registry).deserialize(value)
+        }
+        _ => UnknownValueDeserializer.deserialize(value),
+    }
+}
+
+/// Writes a value whose type is not registered as a tree of its contents.
+fn to_json_tree(value: &dyn Reflect, registry: &TypeRegistry) -> Result<Value, serde_json::Error> {
+    if registry.get_with_name(value.type_name()).is_some() {
+        return serde_json::to_value(TypedReflectSerializer::new(value, registry));
+    }
+    let array = |values: &mut dyn Iterator<Item = &dyn Reflect>| {
+        values
+            .map(|value| to_json_tree(value, registry))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array)
+    };
+
+    Ok(match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let mut map = Map::with_capacity(value.field_len());
+            for index in 0..value.field_len() {
+                let name = value.name_at(index).unwrap().to_string();
+                map.insert(
+                    name,
+                    to_json_tree(value.field_at(index).unwrap(), registry)?,
+                );
+            }
+            Value::Object(map)
+        }
+        ReflectRef::TupleStruct(value) => array(&mut value.iter_fields())?,
+        ReflectRef::Tuple(value) => array(&mut value.iter_fields())?,
+        ReflectRef::List(value) => array(&mut value.iter())?,
+        ReflectRef::Array(value) => array(&mut value.iter())?,
+        ReflectRef::Map(value) => {
+            let mut map = Map::with_capacity(value.len());
+            for (key, value) in value.iter() {
+                let key = match to_json_tree(key, registry)? {
+                    Value::String(key) => key,
+                    key => key.to_string(),
+                };
+                map.insert(key, to_json_tree(value, registry)?);
+            }
+            Value::Object(map)
+        }
+        ReflectRef::Enum(value) => {
+            let content = match value.variant_type() {
+                VariantType::Unit => return Ok(Value::String(value.variant_name().to_string())),
+                VariantType::Tuple if value.field_len() == 1 => {
+                    to_json_tree(value.field_at(0).unwrap(), registry)?
+                }
+                VariantType::Tuple => array(&mut value.iter_fields().map(|field| field.value()))?,
+                VariantType::Struct => {
+                    let mut map = Map::with_capacity(value.field_len());
+                    for field in value.iter_fields() {
+                        let name = field.name().unwrap().to_string();
+                        map.insert(name, to_json_tree(field.value(), registry)?);
+                    }
+                    Value::Object(map)
+                }
+            };
+            let mut map = Map::with_capacity(1);
+            map.insert(value.variant_name().to_string(), content);
+            Value::Object(map)
+        }
+        ReflectRef::Value(value) => primitive_to_json(value).ok_or_else(|| {
+            serde_json::Error::custom(format_args!(
+                "no registration found for value of type `{}`",
+                value.type_name()
+            ))
+        })?,
+    })
+}
+
+/// Converts the primitive types which can be written to JSON without being registered.
+fn primitive_to_json(value: &dyn Reflect) -> Option<Value> {
+    macro_rules! try_primitives {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return serde_json::to_value(value).ok();
+                }
+            )*
+        };
+    }
+
+    try_primitives!(
+        bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, char, String
+    );
+    None
+}
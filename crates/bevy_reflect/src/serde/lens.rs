@@ -0,0 +1,264 @@
+use crate::serde::{ReflectApplyDeserializer, TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{
+    Access, ParsedPath, Reflect, ReflectPathError, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use alloc::string::{String, ToString};
+use thiserror::Error;
+
+/// An error returned when a [`ReflectLens`] cannot focus on its value.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LensError {
+    #[error("the lens expects a value of type `{expected}`, but found `{found}`")]
+    MismatchedRoot { expected: String, found: String },
+    #[error("no value was found at the path `{0}`")]
+    MissingValue(String),
+    #[error("the type of the value at the path `{0}` cannot be determined from its type info")]
+    UnknownType(String),
+    #[error("no registration found for `{0}`")]
+    NotRegistered(String),
+}
+
+/// A descriptor of a fragment of a value: the value at `path` within a value of `root_type`.
+///
+/// A lens can serialize and deserialize its fragment on its own, without the rest of the value,
+/// so that tools can persist or patch a part of a large value, such as just the graphics
+/// settings within the whole settings of a game.
+///
+/// Serializing and applying serialized data require a root value to focus on, and fail
+/// if it is not of the `root_type`. Deserializing a fragment without a root value requires
+/// the type of the fragment to be found from the [`TypeInfo`] of the root type,
+/// so fails if the path goes through a field of an enum, a map, or any dynamic type,
+/// or through any type which is not registered.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, serde::ReflectLens};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect)]
+/// struct Graphics {
+///     vsync: bool,
+///     scale: f32,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Settings {
+///     name: String,
+///     graphics: Graphics,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Settings>();
+/// registry.register::<Graphics>();
+///
+/// let mut settings = Settings {
+///     name: String::from("default"),
+///     graphics: Graphics { vsync: true, scale: 1.0 },
+/// };
+/// let lens = ReflectLens::of::<Settings>("graphics").unwrap();
+///
+/// let serializer = lens.serializer(&settings, &registry).unwrap();
+/// let fragment = ron::to_string(&serializer).unwrap();
+/// assert_eq!("(vsync:true,scale:1.0)", fragment);
+///
+/// let mut deserializer = ron::de::Deserializer::from_str("(scale: 2.0)").unwrap();
+/// lens.apply_deserializer(&mut settings, &registry)
+///     .unwrap()
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(2.0, settings.graphics.scale);
+/// assert!(settings.graphics.vsync);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReflectLens {
+    /// The full [type name] of the values this lens focuses on a part of.
+    ///
+    /// [type name]: std::any::type_name
+    pub root_type: String,
+    /// The path to the fragment within the root value.
+    pub path: ParsedPath,
+}
+
+impl ReflectLens {
+    pub fn new(root_type: impl Into<String>, path: ParsedPath) -> Self {
+        Self {
+            root_type: root_type.into(),
+            path,
+        }
+    }
+
+    /// Creates a lens focusing on the value at `path` within values of type `T`.
+    pub fn of<T: Reflect>(path: &str) -> Result<Self, ReflectPathError<'_>> {
+        Ok(Self::new(
+            core::any::type_name::<T>(),
+            ParsedPath::parse(path)?,
+        ))
+    }
+
+    /// Returns the fragment of `root` this lens focuses on.
+    pub fn focus<'r>(&self, root: &'r dyn Reflect) -> Result<&'r dyn Reflect, LensError> {
+        self.check_root(root)?;
+        self.path
+            .element(root)
+            .ok_or_else(|| LensError::MissingValue(self.path.to_string()))
+    }
+
+    /// Returns the fragment of `root` this lens focuses on, mutably.
+    pub fn focus_mut<'r>(
+        &self,
+        root: &'r mut dyn Reflect,
+    ) -> Result<&'r mut dyn Reflect, LensError> {
+        self.check_root(root)?;
+        self.path
+            .element_mut(root)
+            .ok_or_else(|| LensError::MissingValue(self.path.to_string()))
+    }
+
+    /// Returns a serializer for the fragment of `root` this lens focuses on.
+    pub fn serializer<'a>(
+        &self,
+        root: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+    ) -> Result<TypedReflectSerializer<'a>, LensError> {
+        Ok(TypedReflectSerializer::new(self.focus(root)?, registry))
+    }
+
+    /// Returns a deserializer applying data onto the fragment of `root` this lens focuses on,
+    /// leaving the rest of `root` untouched.
+    ///
+    /// As with [`ReflectApplyDeserializer`], the data may contain any subset of the fields
+    /// of the fragment.
+    pub fn apply_deserializer<'a>(
+        &self,
+        root: &'a mut dyn Reflect,
+        registry: &'a TypeRegistry,
+    ) -> Result<ReflectApplyDeserializer<'a>, LensError> {
+        Ok(ReflectApplyDeserializer::new(
+            self.focus_mut(root)?,
+            registry,
+        ))
+    }
+
+    /// Returns a deserializer reading a whole fragment on its own, without a root value.
+    pub fn deserializer<'a>(
+        &self,
+        registry: &'a TypeRegistry,
+    ) -> Result<TypedReflectDeserializer<'a>, LensError> {
+        Ok(TypedReflectDeserializer::new(
+            self.fragment_registration(registry)?,
+            registry,
+        ))
+    }
+
+    /// Finds the registration of the type of the fragment, following the path through type info.
+    fn fragment_registration<'a>(
+        &self,
+        registry: &'a TypeRegistry,
+    ) -> Result<&'a TypeRegistration, LensError> {
+        let get_registration = |type_name: &str| {
+            registry
+                .get_with_name(type_name)
+                .ok_or_else(|| LensError::NotRegistered(type_name.to_string()))
+        };
+
+        let mut registration = get_registration(&self.root_type)?;
+        for (depth, access) in self.path.accesses().iter().enumerate() {
+            let type_name = match (access, registration.type_info()) {
+                (Access::Field(name), TypeInfo::Struct(info)) => {
+                    info.field(name).map(|field| field.type_name())
+                }
+                (Access::TupleIndex(index), TypeInfo::TupleStruct(info)) => {
+                    info.field_at(*index).map(|field| field.type_name())
+                }
+                (Access::TupleIndex(index), TypeInfo::Tuple(info)) => {
+                    info.field_at(*index).map(|field| field.type_name())
+                }
+                (Access::ListIndex(_), TypeInfo::List(info)) => Some(info.item_type_name()),
+                (Access::ListIndex(_), TypeInfo::Array(info)) => Some(info.item_type_name()),
+                _ => None,
+            };
+            let type_name = type_name.ok_or_else(|| {
+                let path = ParsedPath::from(self.path.accesses()[..=depth].to_vec());
+                LensError::UnknownType(path.to_string())
+            })?;
+            registration = get_registration(type_name)?;
+        }
+        Ok(registration)
+    }
+
+    fn check_root(&self, root: &dyn Reflect) -> Result<(), LensError> {
+        if root.type_name() == self.root_type {
+            Ok(())
+        } else {
+            Err(LensError::MismatchedRoot {
+                expected: self.root_type.clone(),
+                found: root.type_name().to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LensError, ReflectLens};
+    use crate::{self as bevy_reflect, FromReflect, Reflect, TypeRegistry};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Keybind(String, u32);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Controls {
+        keybinds: Vec<Keybind>,
+        mode: Option<u8>,
+    }
+
+    #[test]
+    fn should_deserialize_fragment_without_root() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Controls>();
+        registry.register::<Vec<Keybind>>();
+        registry.register::<Keybind>();
+        registry.register::<String>();
+        registry.register::<Option<u8>>();
+
+        let lens = ReflectLens::of::<Controls>("keybinds[1]").unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(r#"("jump", 32)"#).unwrap();
+        let fragment = lens
+            .deserializer(&registry)
+            .unwrap()
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut controls = Controls {
+            keybinds: vec![
+                Keybind(String::from("walk"), 87),
+                Keybind(String::from("crouch"), 17),
+            ],
+            mode: None,
+        };
+        lens.focus_mut(&mut controls).unwrap().apply(&*fragment);
+        assert_eq!(Keybind(String::from("jump"), 32), controls.keybinds[1]);
+
+        assert_eq!(
+            lens.focus(&controls.keybinds).err(),
+            Some(LensError::MismatchedRoot {
+                expected: String::from(core::any::type_name::<Controls>()),
+                found: String::from(core::any::type_name::<Vec<Keybind>>()),
+            })
+        );
+        assert_eq!(
+            ReflectLens::of::<Controls>("keybinds[2]")
+                .unwrap()
+                .focus(&controls)
+                .err(),
+            Some(LensError::MissingValue(String::from("keybinds[2]")))
+        );
+        assert_eq!(
+            ReflectLens::of::<Controls>("mode.0")
+                .unwrap()
+                .deserializer(&registry)
+                .err(),
+            Some(LensError::UnknownType(String::from("mode.0")))
+        );
+    }
+}
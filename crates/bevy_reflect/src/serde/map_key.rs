@@ -0,0 +1,277 @@
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{Reflect, TypeRegistration, TypeRegistry};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::fmt::{self, Debug, Formatter};
+use serde::de::value::Error;
+use serde::de::{DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{Error as _, Impossible};
+use serde::{forward_to_deserialize_any, Serialize};
+
+/// Converts the keys of reflected maps to and from strings,
+/// for formats such as JSON which only allow string keys.
+///
+/// An encoder is used by the reflect serializers for every map key once set with
+/// [`SerializerConfig::with_map_key_encoder`] and [`DeserializerConfig::with_map_key_encoder`].
+/// Implement this trait to encode keys which [`StringKeyEncoder`] cannot handle,
+/// such as struct keys.
+///
+/// [`SerializerConfig::with_map_key_encoder`]: crate::serde::SerializerConfig::with_map_key_encoder
+/// [`DeserializerConfig::with_map_key_encoder`]: crate::serde::DeserializerConfig::with_map_key_encoder
+pub trait MapKeyEncoder: Send + Sync {
+    /// Converts the given map key to a string.
+    fn encode(&self, key: &dyn Reflect, registry: &TypeRegistry) -> Result<String, String>;
+
+    /// Converts a string created by [`encode`](MapKeyEncoder::encode)
+    /// back to a map key of the type of the given registration.
+    fn decode(
+        &self,
+        key: &str,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, String>;
+}
+
+impl Debug for dyn MapKeyEncoder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn MapKeyEncoder")
+    }
+}
+
+/// A [`MapKeyEncoder`] for keys which serialize as a single primitive,
+/// such as numbers, `bool`s, `char`s, strings, unit enum variants and newtypes around those.
+///
+/// Keys are written with their [`Display`] implementation and parsed back with [`FromStr`],
+/// so `1` becomes `"1"` and `"name"` stays `"name"`.
+/// Any other key causes an error.
+///
+/// [`Display`]: std::fmt::Display
+/// [`FromStr`]: std::str::FromStr
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StringKeyEncoder;
+
+impl MapKeyEncoder for StringKeyEncoder {
+    fn encode(&self, key: &dyn Reflect, registry: &TypeRegistry) -> Result<String, String> {
+        TypedReflectSerializer::new(key, registry)
+            .serialize(KeySerializer)
+            .map_err(|error| error.to_string())
+    }
+
+    fn decode(
+        &self,
+        key: &str,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, String> {
+        TypedReflectDeserializer::new(registration, registry)
+            .deserialize(KeyDeserializer(key))
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// A serializer writing a primitive value as a string, used by [`StringKeyEncoder`].
+struct KeySerializer;
+
+macro_rules! serialize_display {
+    ($($method:ident($ty:ty),)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                Ok(v.to_string())
+            }
+        )*
+    };
+}
+
+impl serde::Serializer for KeySerializer {
+    type Ok = String;
+    type Error = Error;
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    serialize_display! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(key_error("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(key_error("an option"))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<Self::Ok, Self::Error> {
+        Err(key_error("an option"))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(key_error("a unit"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(key_error("a unit struct"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(key_error("a newtype variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(key_error("a sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(key_error("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(key_error("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(key_error("a tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(key_error("a map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(key_error("a struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(key_error("a struct variant"))
+    }
+}
+
+fn key_error(kind: &str) -> Error {
+    Error::custom(format_args!("{kind} cannot be used as a string map key"))
+}
+
+/// A deserializer parsing a primitive value from a string, used by [`StringKeyEncoder`].
+struct KeyDeserializer<'a>(&'a str);
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let value = self.0.parse().map_err(|error| {
+                    Error::custom(format_args!("invalid map key `{}`: {}", self.0, error))
+                })?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'a, 'de> serde::Deserializer<'de> for KeyDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.0)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
@@ -1,16 +1,64 @@
+mod content;
 mod de;
+#[cfg(feature = "serde_json")]
+mod json_schema;
+#[cfg(feature = "serde_json")]
+mod json_value;
+mod lens;
+mod map_key;
+mod processor;
+#[cfg(feature = "ron")]
+mod ron_value;
+#[cfg(feature = "serde_roundtrip")]
+mod roundtrip;
 mod ser;
+#[cfg(feature = "toml")]
+mod toml_adapter;
 mod type_data;
+mod type_indices;
+mod version;
+#[cfg(feature = "serde_yaml")]
+mod yaml_adapter;
 
 pub use de::*;
+#[cfg(feature = "serde_json")]
+pub use json_schema::*;
+#[cfg(feature = "serde_json")]
+pub use json_value::*;
+pub use lens::*;
+pub use map_key::*;
+pub use processor::*;
+#[cfg(feature = "ron")]
+pub use ron_value::*;
+#[cfg(feature = "serde_roundtrip")]
+pub use roundtrip::*;
 pub use ser::*;
+#[cfg(feature = "toml")]
+pub use toml_adapter::*;
 pub use type_data::*;
+pub use type_indices::*;
+pub use version::*;
+#[cfg(feature = "serde_yaml")]
+pub use yaml_adapter::*;
+
+/// The key of the entry assigning a reference ID to a shared value,
+/// when shared references are enabled in the [`SerializerConfig`].
+const SHARED_ID: &str = "id";
+/// The key of the entry containing a shared value, after its [`SHARED_ID`] entry.
+const SHARED_VALUE: &str = "value";
+/// The key of the entry referring to a shared value written earlier by its reference ID.
+const SHARED_REF: &str = "ref";
+/// The name of the entry holding the version of a struct which has [`ReflectVersion`] type data.
+const VERSION_FIELD: &str = "__version";
 
 #[cfg(test)]
 mod tests {
     use crate::{self as bevy_reflect, DynamicTupleStruct};
     use crate::{
-        serde::{ReflectSerializer, UntypedReflectDeserializer},
+        serde::{
+            ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer,
+            UntypedReflectDeserializer,
+        },
         type_registry::TypeRegistry,
         DynamicStruct, Reflect,
     };
@@ -92,4 +140,1283 @@ mod tests {
             "Expected {expected:?} found {deserialized:?}"
         );
     }
+
+    #[test]
+    fn test_serialization_trait_objects() {
+        use crate::{reflect_trait, FromReflect, ReflectFromReflect};
+
+        #[reflect_trait]
+        trait Shape: Reflect {
+            fn area(&self) -> f32;
+        }
+
+        #[derive(Reflect, FromReflect)]
+        #[reflect(FromReflect)]
+        struct Square {
+            side: f32,
+        }
+
+        impl Shape for Square {
+            fn area(&self) -> f32 {
+                self.side * self.side
+            }
+        }
+
+        #[derive(Reflect, FromReflect)]
+        #[reflect(FromReflect)]
+        struct Rect(f32, f32);
+
+        impl Shape for Rect {
+            fn area(&self) -> f32 {
+                self.0 * self.1
+            }
+        }
+
+        #[derive(Reflect, FromReflect)]
+        struct Drawing {
+            shapes: Vec<Box<dyn Shape>>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Drawing>();
+        registry.register::<Vec<Box<dyn Shape>>>();
+        registry.register::<Box<dyn Shape>>();
+        registry.register::<Square>();
+        registry.register::<Rect>();
+        registry.register_trait_cast::<Square, ReflectShape>();
+        registry.register_trait_cast::<Rect, ReflectShape>();
+        registry.register_tag::<Square>("square");
+
+        let drawing = Drawing {
+            shapes: vec![Box::new(Square { side: 2.0 }), Box::new(Rect(2.0, 3.0))],
+        };
+
+        let serializer = ReflectSerializer::new(&drawing, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        let rect_name = core::any::type_name::<Rect>();
+        assert!(serialized.contains(&format!(
+            r#"shapes:[{{"square":(side:2.0)}},{{"{rect_name}":(2.0,3.0)}}]"#
+        )));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let mut deserialized = Drawing::from_reflect(value.as_ref()).unwrap();
+        let areas: Vec<f32> = deserialized
+            .shapes
+            .iter()
+            .map(|shape| shape.area())
+            .collect();
+        assert_eq!(vec![4.0, 6.0], areas);
+
+        // Applying a trait object holding a different type replaces the boxed value
+        let mut deserializer = ron::de::Deserializer::from_str(r#"{"square":(side:3.0)}"#).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(
+            registry
+                .get(core::any::TypeId::of::<Box<dyn Shape>>())
+                .unwrap(),
+            &registry,
+        );
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        deserialized.shapes[1].apply(value.as_ref());
+        assert!((*deserialized.shapes[1]).as_any().is::<Square>());
+        assert_eq!(9.0, deserialized.shapes[1].area());
+
+        let mut deserializer = ron::de::Deserializer::from_str(r#"{"circle":(1.0)}"#).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(
+            registry
+                .get(core::any::TypeId::of::<Box<dyn Shape>>())
+                .unwrap(),
+            &registry,
+        );
+        assert!(reflect_deserializer.deserialize(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn test_serialization_dynamic_fields() {
+        use crate::{FromReflect, ReflectFromReflect};
+
+        #[derive(Reflect, FromReflect)]
+        struct Foo {
+            #[reflect(dynamic)]
+            value: Box<dyn Reflect>,
+            other: Option<u8>,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        #[reflect(FromReflect)]
+        struct Bar(usize);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        registry.register::<Bar>();
+        registry.register::<Option<u8>>();
+        registry.register::<usize>();
+
+        let foo = Foo {
+            value: Box::new(Bar(123)),
+            other: None,
+        };
+
+        let serializer = ReflectSerializer::new(&foo, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        let bar_name = core::any::type_name::<Bar>();
+        assert!(serialized.contains(&format!(r#"value:{{"{bar_name}":(123)}}"#)));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let deserialized = Foo::from_reflect(value.as_ref()).unwrap();
+        assert_eq!(Some(&Bar(123)), deserialized.value.downcast_ref::<Bar>());
+    }
+
+    #[test]
+    fn test_serialization_shared_references() {
+        use crate::serde::{DeserializerConfig, SerializerConfig};
+        use crate::FromReflect;
+        use alloc::sync::Arc;
+
+        #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+        struct Node {
+            name: String,
+        }
+
+        #[derive(Reflect, FromReflect)]
+        struct Graph {
+            nodes: Vec<Arc<Node>>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Graph>();
+        registry.register::<Node>();
+        registry.register::<Arc<Node>>();
+        registry.register::<Vec<Arc<Node>>>();
+        registry.register::<String>();
+
+        let shared = Arc::new(Node {
+            name: String::from("shared"),
+        });
+        let other = Arc::new(Node {
+            name: String::from("other"),
+        });
+        let graph = Graph {
+            nodes: vec![shared.clone(), other, shared],
+        };
+
+        // Without the option, every `Arc` is written out in full
+        let serializer = ReflectSerializer::new(&graph, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains(r#"nodes:[(name:"shared"),(name:"other"),(name:"shared")]"#));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let deserialized = Graph::from_reflect(value.as_ref()).unwrap();
+        assert_eq!(graph.nodes, deserialized.nodes);
+        assert!(!Arc::ptr_eq(&deserialized.nodes[0], &deserialized.nodes[2]));
+
+        let serializer = ReflectSerializer::new(&graph, &registry)
+            .with_config(SerializerConfig::default().with_shared_references(true));
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains(
+            r#"nodes:[{"id":0,"value":(name:"shared")},{"id":1,"value":(name:"other")},{"ref":0}]"#
+        ));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry)
+            .with_config(DeserializerConfig::default().with_shared_references(true));
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let deserialized = Graph::from_reflect(value.as_ref()).unwrap();
+        assert_eq!(graph.nodes, deserialized.nodes);
+        assert!(Arc::ptr_eq(&deserialized.nodes[0], &deserialized.nodes[2]));
+        assert!(!Arc::ptr_eq(&deserialized.nodes[0], &deserialized.nodes[1]));
+    }
+
+    #[test]
+    fn test_serialization_positional() {
+        use crate::serde::{DeserializerConfig, SerializerConfig};
+        use crate::FromReflect;
+        use bincode::Options;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Player {
+            name: String,
+            #[reflect(skip_serializing, default)]
+            cached: u32,
+            state: State,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum State {
+            Idle,
+            Moving { speed: f32, angle: f32 },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<State>();
+        registry.register::<String>();
+        registry.register::<u32>();
+        registry.register::<f32>();
+
+        let player = Player {
+            name: String::from("Ferris"),
+            cached: 123,
+            state: State::Moving {
+                speed: 2.0,
+                angle: 0.5,
+            },
+        };
+        let registration = registry.get(core::any::TypeId::of::<Player>()).unwrap();
+
+        let serializer = TypedReflectSerializer::new(&player, &registry)
+            .with_config(SerializerConfig::default().with_positional(true));
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#"("Ferris",Moving(2.0,0.5))"#, serialized);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry)
+            .with_config(DeserializerConfig::default().with_positional(true));
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let deserialized = Player::from_reflect(value.as_ref()).unwrap();
+        let expected = Player {
+            name: String::from("Ferris"),
+            cached: 0,
+            state: State::Moving {
+                speed: 2.0,
+                angle: 0.5,
+            },
+        };
+        assert_eq!(expected, deserialized);
+
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry)
+            .with_config(DeserializerConfig::default().with_positional(true));
+        let value = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+        assert_eq!(expected, Player::from_reflect(value.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_serialization_bytes() {
+        use crate::serde::ReflectSerializeBytes;
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        #[reflect(SerializeBytes)]
+        struct Blob(Vec<u8>);
+
+        impl AsRef<[u8]> for Blob {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl From<Vec<u8>> for Blob {
+            fn from(bytes: Vec<u8>) -> Self {
+                Blob(bytes)
+            }
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Asset {
+            data: Vec<u8>,
+            hash: [u8; 4],
+            blob: Blob,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Asset>();
+        registry.register::<Blob>();
+        registry.register::<Vec<u8>>();
+        registry.register::<[u8; 4]>();
+
+        let asset = Asset {
+            data: vec![1, 2, 3],
+            hash: [0xde, 0xad, 0xbe, 0xef],
+            blob: Blob(vec![4, 5, 6]),
+        };
+
+        let serializer = ReflectSerializer::new(&asset, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains(r#"(data:"AQID",hash:"3q2+7w==",blob:"BAUG")"#));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let value = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(asset, Asset::from_reflect(value.as_ref()).unwrap());
+
+        let bytes = rmp_serde::to_vec(&serializer).unwrap();
+        let mut deserializer = rmp_serde::Deserializer::new(bytes.as_slice());
+        let value = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(asset, Asset::from_reflect(value.as_ref()).unwrap());
+
+        // Sequences of numbers are accepted as well
+        let input = r#"{
+            "bevy_reflect::serde::tests::test_serialization_bytes::Asset": (
+                data: [1, 2, 3],
+                hash: [222, 173, 190, 239],
+                blob: [4, 5, 6],
+            ),
+        }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(asset, Asset::from_reflect(value.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_serialization_repr() {
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect)]
+        struct SpanRepr {
+            start: u32,
+            len: u32,
+        }
+
+        #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+        #[reflect(from = "SpanRepr", into = "SpanRepr")]
+        struct Span {
+            start: u32,
+            end: u32,
+        }
+
+        impl TryFrom<SpanRepr> for Span {
+            type Error = ();
+
+            fn try_from(repr: SpanRepr) -> Result<Self, ()> {
+                let end = repr.start.checked_add(repr.len).ok_or(())?;
+                Ok(Span {
+                    start: repr.start,
+                    end,
+                })
+            }
+        }
+
+        impl From<Span> for SpanRepr {
+            fn from(span: Span) -> Self {
+                SpanRepr {
+                    start: span.start,
+                    len: span.end - span.start,
+                }
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Span>();
+        registry.register::<SpanRepr>();
+
+        let span = Span { start: 2, end: 5 };
+        let serializer = TypedReflectSerializer::new(&span, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!("(start:2,len:3)", serialized);
+
+        let registration = registry.get(core::any::TypeId::of::<Span>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let value = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(&span), value.downcast_ref::<Span>());
+
+        // The representation's conversion is checked when deserializing
+        let mut deserializer = ron::de::Deserializer::from_str("(start:4294967295,len:1)").unwrap();
+        assert!(TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .is_err());
+    }
+
+    #[test]
+    fn test_serialization_string_map_keys() {
+        use crate::serde::{DeserializerConfig, SerializerConfig, StringKeyEncoder};
+        use crate::FromReflect;
+        use bevy_utils::HashMap;
+
+        #[derive(Reflect, FromReflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        enum Slot {
+            Head,
+            Hand,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Inventory {
+            counts: HashMap<u32, usize>,
+            slots: HashMap<Slot, String>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Inventory>();
+        registry.register::<Slot>();
+        registry.register::<u32>();
+        registry.register::<usize>();
+        registry.register::<String>();
+        registry.register::<HashMap<u32, usize>>();
+        registry.register::<HashMap<Slot, String>>();
+
+        let inventory = Inventory {
+            counts: HashMap::from_iter([(7, 2)]),
+            slots: HashMap::from_iter([(Slot::Head, String::from("Helmet"))]),
+        };
+
+        let serializer = ReflectSerializer::new(&inventory, &registry)
+            .with_config(SerializerConfig::default().with_map_key_encoder(StringKeyEncoder));
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains(r#"(counts:{"7":2},slots:{"Head":"Helmet"})"#));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry)
+            .with_config(DeserializerConfig::default().with_map_key_encoder(StringKeyEncoder));
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(inventory, Inventory::from_reflect(value.as_ref()).unwrap());
+
+        let input = r#"{
+            "bevy_reflect::serde::tests::test_serialization_string_map_keys::Inventory": (
+                counts: {"seven": 2},
+                slots: {},
+            ),
+        }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry)
+            .with_config(DeserializerConfig::default().with_map_key_encoder(StringKeyEncoder));
+        let error = reflect_deserializer
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(error.to_string().contains("invalid map key `seven`"));
+    }
+
+    #[test]
+    fn test_serialization_omit_none() {
+        use crate::serde::{DeserializerConfig, SerializerConfig};
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Prefab {
+            name: Option<String>,
+            health: Option<u32>,
+            shape: Shape,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Shape {
+            Circle { radius: Option<f32> },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Prefab>();
+        registry.register::<Shape>();
+        registry.register::<Option<String>>();
+        registry.register::<Option<u32>>();
+        registry.register::<Option<f32>>();
+        registry.register::<String>();
+        registry.register::<u32>();
+
+        let prefab = Prefab {
+            name: None,
+            health: Some(10),
+            shape: Shape::Circle { radius: None },
+        };
+
+        let serializer = ReflectSerializer::new(&prefab, &registry)
+            .with_config(SerializerConfig::default().with_omit_none(true));
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains("(health:Some(10),shape:Circle())"));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry)
+            .with_config(DeserializerConfig::default().with_omit_none(true));
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(prefab, Prefab::from_reflect(value.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_serialization_non_finite_floats() {
+        use crate::serde::{DeserializerConfig, NonFiniteFloatPolicy, SerializerConfig};
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect, Debug)]
+        struct Range {
+            min: f32,
+            max: f64,
+            step: f64,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Range>();
+        registry.register::<f32>();
+        registry.register::<f64>();
+
+        let range = Range {
+            min: f32::NEG_INFINITY,
+            max: f64::NAN,
+            step: 0.5,
+        };
+        let roundtrip = |policy, expected: &str| {
+            let serializer = ReflectSerializer::new(&range, &registry)
+                .with_config(SerializerConfig::default().with_non_finite_floats(policy));
+            let serialized = ron::ser::to_string(&serializer).unwrap();
+            assert!(
+                serialized.contains(expected),
+                "unexpected output: {serialized}"
+            );
+
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+            let reflect_deserializer = UntypedReflectDeserializer::new(&registry)
+                .with_config(DeserializerConfig::default().with_non_finite_floats(policy));
+            let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+            Range::from_reflect(value.as_ref()).unwrap()
+        };
+
+        let output = roundtrip(
+            NonFiniteFloatPolicy::String,
+            r#"(min:"-inf",max:"NaN",step:0.5)"#,
+        );
+        assert_eq!(f32::NEG_INFINITY, output.min);
+        assert!(output.max.is_nan());
+        assert_eq!(0.5, output.step);
+
+        let output = roundtrip(NonFiniteFloatPolicy::Null, "(min:None,max:None,step:0.5)");
+        assert!(output.min.is_nan());
+        assert!(output.max.is_nan());
+        assert_eq!(0.5, output.step);
+
+        let serializer = ReflectSerializer::new(&range, &registry).with_config(
+            SerializerConfig::default().with_non_finite_floats(NonFiniteFloatPolicy::Error),
+        );
+        let error = ron::ser::to_string(&serializer).unwrap_err();
+        assert!(error.to_string().contains("non-finite float `-inf`"));
+    }
+
+    #[test]
+    fn test_serialization_type_indices() {
+        use crate::serde::{DeserializerConfig, SerializerConfig, TypeIndices};
+        use crate::FromReflect;
+        use alloc::sync::Arc;
+        use bincode::Options;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Position {
+            x: f32,
+            y: f32,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Velocity(f32);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Position>();
+        registry.register::<Velocity>();
+        registry.register::<f32>();
+
+        let mut other_registry = TypeRegistry::default();
+        other_registry.register::<f32>();
+        other_registry.register::<Velocity>();
+        other_registry.register::<Position>();
+
+        let type_indices = TypeIndices::new(&registry);
+        let other_type_indices = TypeIndices::new(&other_registry);
+        assert_eq!(type_indices.checksum(), other_type_indices.checksum());
+        let index = type_indices
+            .index_of(core::any::type_name::<Position>())
+            .unwrap();
+        assert_eq!(
+            Some(index),
+            other_type_indices.index_of(core::any::type_name::<Position>())
+        );
+
+        let position = Position { x: 1.0, y: 2.0 };
+        let config = SerializerConfig::default().with_type_indices(Arc::new(type_indices.clone()));
+        let serializer = ReflectSerializer::new(&position, &registry).with_config(config);
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let full_bytes = bincode::serialize(&ReflectSerializer::new(&position, &registry)).unwrap();
+        // The map length, the index and both fields
+        assert_eq!(8 + 4 + 4 + 4, bytes.len());
+        assert!(bytes.len() < full_bytes.len());
+
+        let config = DeserializerConfig::default().with_type_indices(Arc::new(other_type_indices));
+        let reflect_deserializer =
+            UntypedReflectDeserializer::new(&other_registry).with_config(config);
+        let value = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+        assert_eq!(position, Position::from_reflect(value.as_ref()).unwrap());
+
+        registry.register::<String>();
+        let name = String::from("unindexed");
+        let serializer = ReflectSerializer::new(&name, &registry)
+            .with_config(SerializerConfig::default().with_type_indices(Arc::new(type_indices)));
+        let error = bincode::serialize(&serializer).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("is not part of the type indices"));
+    }
+
+    #[test]
+    fn test_serialization_versions() {
+        use crate::serde::ReflectVersion;
+        use crate::{DynamicStruct, FromReflect, GetField, Struct};
+        use core::any::TypeId;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Player {
+            name: String,
+            health: f32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        let version = ReflectVersion::new(1).with_migration(0, |old| {
+            let mut new = DynamicStruct::default();
+            new.insert_boxed("name", old.field("name").unwrap().clone_value());
+            new.insert("health", *old.get_field::<u64>("hp").unwrap() as f32);
+            new
+        });
+        registry
+            .get_mut(TypeId::of::<Player>())
+            .unwrap()
+            .insert(version);
+
+        let player = Player {
+            name: String::from("Ferris"),
+            health: 2.5,
+        };
+        let serializer = ReflectSerializer::new(&player, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains("(__version:1,name:\"Ferris\",health:2.5)"));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(player, Player::from_reflect(value.as_ref()).unwrap());
+
+        // Written before the type had a version
+        let type_name = core::any::type_name::<Player>();
+        let old = format!(r#"{{"{type_name}":(name:"Ferris",hp:3)}}"#);
+        let mut deserializer = ron::de::Deserializer::from_str(&old).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let expected = Player {
+            name: String::from("Ferris"),
+            health: 3.0,
+        };
+        assert_eq!(expected, Player::from_reflect(value.as_ref()).unwrap());
+
+        let newer = format!(r#"{{"{type_name}":(__version:2,name:"Ferris")}}"#);
+        let mut deserializer = ron::de::Deserializer::from_str(&newer).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let error = reflect_deserializer
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("version 2 is newer than the current version 1"));
+    }
+
+    #[test]
+    fn test_serialization_with_functions() {
+        use crate::serde::ReflectApplyDeserializer;
+        use crate::FromReflect;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        // Stores the cells as runs of equal values
+        fn compress<S: Serializer>(cells: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut runs: Vec<(u8, usize)> = Vec::new();
+            for cell in cells {
+                match runs.last_mut() {
+                    Some((value, len)) if value == cell => *len += 1,
+                    _ => runs.push((*cell, 1)),
+                }
+            }
+            runs.serialize(serializer)
+        }
+
+        fn decompress<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+            let runs = Vec::<(u8, usize)>::deserialize(deserializer)?;
+            Ok(runs
+                .into_iter()
+                .flat_map(|(value, len)| vec![value; len])
+                .collect())
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Grid {
+            width: u32,
+            #[reflect(serialize_with = "compress", deserialize_with = "decompress")]
+            cells: Vec<u8>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Grid>();
+        registry.register::<u32>();
+
+        let grid = Grid {
+            width: 4,
+            cells: vec![0, 0, 0, 0, 1, 1, 0, 0],
+        };
+        let serializer = ReflectSerializer::new(&grid, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains("(width:4,cells:[(0,4),(1,2),(0,2)])"));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(grid, Grid::from_reflect(value.as_ref()).unwrap());
+
+        let mut applied = Grid {
+            width: 4,
+            cells: Vec::new(),
+        };
+        let mut deserializer = ron::de::Deserializer::from_str("(cells:[(2,3)])").unwrap();
+        ReflectApplyDeserializer::new(&mut applied, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(vec![2, 2, 2], applied.cells);
+    }
+
+    #[test]
+    fn test_serialization_with_functions_of_reordered_fields() {
+        use crate::FromReflect;
+        use serde::{Serialize, Serializer};
+
+        fn as_hex<S: Serializer>(color: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+            format!("#{color:06x}").serialize(serializer)
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Tint {
+            alpha: f32,
+            #[reflect(serialize_with = "as_hex")]
+            color: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Tint>();
+        registry.register::<f32>();
+
+        // The fields of a dynamic struct may be in any order
+        let mut tint = DynamicStruct::default();
+        tint.set_name(core::any::type_name::<Tint>().to_string());
+        tint.insert("color", 0xff8000_u32);
+        tint.insert("alpha", 0.5_f32);
+
+        let serializer = TypedReflectSerializer::new(&tint, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r##"(color:"#ff8000",alpha:0.5)"##, serialized);
+
+        let tint = Tint {
+            alpha: 0.5,
+            color: 0xff8000,
+        };
+        let serializer = TypedReflectSerializer::new(&tint, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r##"(alpha:0.5,color:"#ff8000")"##, serialized);
+    }
+
+    #[test]
+    fn test_serialization_ignored_field_names() {
+        use crate::serde::SerializationData;
+        use crate::FromReflect;
+        use core::any::TypeId;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Settings {
+            #[reflect(skip_serializing, default)]
+            cache: u32,
+            volume: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+        registry.register::<u32>();
+
+        let registration = registry.get(TypeId::of::<Settings>()).unwrap();
+        let data = registration.data::<SerializationData>().unwrap();
+        assert!(data.is_ignored_name("cache"));
+        assert!(!data.is_ignored_field(0));
+        assert_eq!(1, data.len());
+
+        let settings = Settings {
+            cache: 7,
+            volume: 3,
+        };
+        let serializer = ReflectSerializer::new(&settings, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains("(volume:3)"));
+
+        // Written by a build which still serialized the field
+        let mut deserializer = ron::de::Deserializer::from_str("(cache:5,volume:3)").unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let expected = Settings {
+            cache: 0,
+            volume: 3,
+        };
+        assert_eq!(expected, Settings::from_reflect(value.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_serialization_processor() {
+        use crate::serde::{ReflectSerializerProcessor, Serializable, SerializerConfig};
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect)]
+        struct Handle(Option<u64>);
+
+        #[derive(Reflect)]
+        struct Level {
+            sprites: Vec<Handle>,
+        }
+
+        struct HandlePaths;
+
+        impl ReflectSerializerProcessor for HandlePaths {
+            fn try_serialize<'a>(
+                &self,
+                value: &'a dyn Reflect,
+                _registry: &TypeRegistry,
+            ) -> Result<Option<Serializable<'a>>, String> {
+                match value.downcast_ref::<Handle>() {
+                    Some(Handle(Some(id))) => {
+                        Ok(Some(Serializable::Owned(Box::new(format!("{id}.png")))))
+                    }
+                    Some(Handle(None)) => Err(String::from("handle has no asset")),
+                    None => Ok(None),
+                }
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry.register::<Vec<Handle>>();
+
+        let level = Level {
+            sprites: vec![Handle(Some(1)), Handle(Some(2))],
+        };
+        let config = SerializerConfig::default().with_processor(HandlePaths);
+        let serializer = ReflectSerializer::new(&level, &registry).with_config(config.clone());
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains(r#"(sprites:["1.png","2.png"])"#));
+
+        let level = Level {
+            sprites: vec![Handle(None)],
+        };
+        let serializer = ReflectSerializer::new(&level, &registry).with_config(config);
+        let error = ron::ser::to_string(&serializer).unwrap_err();
+        assert!(error.to_string().contains("handle has no asset"));
+    }
+
+    #[test]
+    fn test_deserialization_processor() {
+        use crate::serde::{
+            DeserializerConfig, ReflectApplyDeserializer, ReflectDeserializerProcessor,
+        };
+        use crate::{FromReflect, TypeRegistration};
+        use core::any::TypeId;
+        use serde::Deserialize;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Handle(u64);
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Level {
+            sprites: Vec<Handle>,
+        }
+
+        struct HandlePaths;
+
+        impl ReflectDeserializerProcessor for HandlePaths {
+            fn claims(&self, registration: &TypeRegistration) -> bool {
+                registration.type_id() == TypeId::of::<Handle>()
+            }
+
+            fn deserialize(
+                &self,
+                _registration: &TypeRegistration,
+                _registry: &TypeRegistry,
+                deserializer: &mut dyn erased_serde::Deserializer,
+            ) -> Result<Box<dyn Reflect>, erased_serde::Error> {
+                let path = String::deserialize(deserializer)?;
+                let id = path.trim_end_matches(".png").parse();
+                Ok(Box::new(Handle(id.map_err(serde::de::Error::custom)?)))
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry.register::<Handle>();
+        registry.register::<Vec<Handle>>();
+        let registration = registry.get(TypeId::of::<Level>()).unwrap();
+
+        let config = DeserializerConfig::default().with_processor(HandlePaths);
+        let mut deserializer =
+            ron::de::Deserializer::from_str(r#"(sprites:["1.png","2.png"])"#).unwrap();
+        let reflect_deserializer =
+            TypedReflectDeserializer::new(registration, &registry).with_config(config.clone());
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let expected = Level {
+            sprites: vec![Handle(1), Handle(2)],
+        };
+        assert_eq!(expected, Level::from_reflect(value.as_ref()).unwrap());
+
+        let mut level = Level {
+            sprites: Vec::new(),
+        };
+        let mut deserializer = ron::de::Deserializer::from_str(r#"(sprites:["3.png"])"#).unwrap();
+        ReflectApplyDeserializer::new(&mut level, &registry)
+            .with_config(config)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(vec![Handle(3)], level.sprites);
+    }
+
+    #[test]
+    fn test_serialization_enum_representations() {
+        use crate::serde::{DeserializerConfig, EnumRepresentation, SerializerConfig};
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Label {
+            text: String,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Shape {
+            Point,
+            Circle { radius: f32 },
+            Named(Label),
+            Pair(u8, u8),
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Shape>();
+        registry.register::<Label>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        registry.register::<u8>();
+
+        let round_trip = |shape: &Shape, representation: EnumRepresentation| {
+            let config = SerializerConfig::default().with_enum_representation(representation);
+            let serializer = ReflectSerializer::new(shape, &registry).with_config(config);
+            let serialized = ron::ser::to_string(&serializer)?;
+
+            let config = DeserializerConfig::default().with_enum_representation(representation);
+            let reflect_deserializer =
+                UntypedReflectDeserializer::new(&registry).with_config(config);
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized)?;
+            let value = reflect_deserializer.deserialize(&mut deserializer)?;
+            assert_eq!(shape, &Shape::from_reflect(&*value).unwrap());
+            Ok::<_, ron::Error>(serialized)
+        };
+
+        let shapes = [
+            Shape::Point,
+            Shape::Circle { radius: 1.5 },
+            Shape::Named(Label {
+                text: String::from("origin"),
+            }),
+            Shape::Pair(1, 2),
+        ];
+        let internal = EnumRepresentation::Internal { tag: "kind" };
+        let adjacent = EnumRepresentation::Adjacent {
+            tag: "kind",
+            content: "value",
+        };
+        for representation in [
+            EnumRepresentation::External,
+            adjacent,
+            EnumRepresentation::Untagged,
+        ] {
+            for shape in &shapes {
+                round_trip(shape, representation).unwrap();
+            }
+        }
+        for shape in &shapes[..3] {
+            round_trip(shape, internal).unwrap();
+        }
+
+        let serialized = round_trip(&shapes[1], internal).unwrap();
+        assert!(serialized.contains(r#"(kind:"Circle",radius:1.5)"#));
+        let serialized = round_trip(&shapes[2], internal).unwrap();
+        assert!(serialized.contains(r#"{"kind":"Named","text":"origin"}"#));
+        let serialized = round_trip(&shapes[3], adjacent).unwrap();
+        assert!(serialized.contains(r#"(kind:"Pair",value:(1,2))"#));
+        let serialized = round_trip(&shapes[2], EnumRepresentation::Untagged).unwrap();
+        assert!(serialized.contains(r#"(text:"origin")"#));
+
+        let error = round_trip(&shapes[3], internal).unwrap_err();
+        assert!(error.to_string().contains("cannot serialize tuple variant"));
+    }
+
+    #[test]
+    fn test_serialization_transparent_structs() {
+        use crate::serde::{DeserializerConfig, ReflectApplyDeserializer, SerializerConfig};
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Meters(f32);
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Marker;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Ruler {
+            length: Meters,
+            marker: Marker,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Ruler>();
+        registry.register::<Meters>();
+        registry.register::<Marker>();
+        registry.register::<f32>();
+
+        let ruler = Ruler {
+            length: Meters(1.5),
+            marker: Marker,
+        };
+        let config = SerializerConfig::default().with_transparent_structs(true);
+        let serializer = ReflectSerializer::new(&ruler, &registry).with_config(config);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains("(length:1.5,marker:())"));
+
+        let config = DeserializerConfig::default().with_transparent_structs(true);
+        let reflect_deserializer =
+            UntypedReflectDeserializer::new(&registry).with_config(config.clone());
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(ruler, Ruler::from_reflect(&*value).unwrap());
+
+        let mut applied = Ruler {
+            length: Meters(0.0),
+            marker: Marker,
+        };
+        let mut deserializer = ron::de::Deserializer::from_str("(length:2.5,marker:())").unwrap();
+        ReflectApplyDeserializer::new(&mut applied, &registry)
+            .with_config(config)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Meters(2.5), applied.length);
+    }
+
+    #[test]
+    fn test_serialization_sorted_keys() {
+        use crate::serde::SerializerConfig;
+        use bevy_utils::HashMap;
+
+        #[derive(Reflect)]
+        struct Scores {
+            zeta: u8,
+            alpha: HashMap<i32, String>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Scores>();
+        registry.register::<HashMap<i32, String>>();
+        registry.register::<i32>();
+        registry.register::<String>();
+        registry.register::<u8>();
+
+        let mut alpha = HashMap::default();
+        for key in [10, -1, 2, 7, -30, 0] {
+            alpha.insert(key, key.to_string());
+        }
+        let scores = Scores { zeta: 1, alpha };
+        let config = SerializerConfig::default().with_sorted_keys(true);
+        let serializer = ReflectSerializer::new(&scores, &registry).with_config(config);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(
+            serialized.contains(r#"(alpha:{-30:"-30",-1:"-1",0:"0",2:"2",7:"7",10:"10"},zeta:1)"#)
+        );
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_ron_value_conversions() {
+        use crate::serde::{from_ron_value, to_ron_value};
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Shape {
+            Point,
+            Circle { radius: f32 },
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Drawing {
+            shapes: Vec<Shape>,
+            label: Option<String>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Drawing>();
+        registry.register::<Shape>();
+        registry.register::<Vec<Shape>>();
+        registry.register::<Option<String>>();
+        registry.register::<String>();
+        registry.register::<f32>();
+
+        let drawing = Drawing {
+            shapes: vec![Shape::Point, Shape::Circle { radius: 2.0 }],
+            label: Some(String::from("sketch")),
+        };
+        let value = to_ron_value(&drawing, &registry).unwrap();
+        let reflected = from_ron_value(&registry, &value).unwrap();
+        assert_eq!(drawing, Drawing::from_reflect(&*reflected).unwrap());
+
+        let input =
+            r#"{"my_crate::Drawing":(shapes:[{"Circle":(radius:1.5)},"Point"],label:None)}"#
+                .replace("my_crate::Drawing", core::any::type_name::<Drawing>());
+        let value: ron::Value = ron::from_str(&input).unwrap();
+        let reflected = from_ron_value(&registry, &value).unwrap();
+        let expected = Drawing {
+            shapes: vec![Shape::Circle { radius: 1.5 }, Shape::Point],
+            label: None,
+        };
+        assert_eq!(expected, Drawing::from_reflect(&*reflected).unwrap());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_value_conversions() {
+        use crate::serde::{from_json_value, to_json_value};
+        use crate::{FromReflect, GetField};
+        use serde_json::json;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Weapon {
+            Fists,
+            Sword { damage: u32 },
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Player {
+            name: String,
+            weapon: Weapon,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Weapon>();
+        registry.register::<String>();
+        registry.register::<u32>();
+
+        let player = Player {
+            name: String::from("Ferris"),
+            weapon: Weapon::Sword { damage: 4 },
+        };
+        let value = to_json_value(&player, &registry).unwrap();
+        assert_eq!(
+            json!({ core::any::type_name::<Player>(): {
+                "name": "Ferris",
+                "weapon": { "Sword": { "damage": 4 } },
+            }}),
+            value
+        );
+        let reflected = from_json_value(&registry, &value).unwrap();
+        assert_eq!(player, Player::from_reflect(&*reflected).unwrap());
+
+        // Unregistered values are written and read as trees of their contents
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("weapon", Weapon::Fists);
+        dynamic.insert("score", -3i64);
+        let value = to_json_value(&dynamic, &registry).unwrap();
+        assert_eq!(json!({ "weapon": "Fists", "score": -3 }), value);
+        let reflected = from_json_value(&registry, &value).unwrap();
+        let reflected = reflected.downcast_ref::<DynamicStruct>().unwrap();
+        assert_eq!(Some(&-3), reflected.get_field::<i64>("score"));
+        assert_eq!(
+            Some("Fists"),
+            reflected.get_field::<String>("weapon").map(String::as_str)
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_adapter() {
+        use crate::serde::{from_toml_str, to_toml_string};
+        use crate::FromReflect;
+        use bevy_utils::HashMap;
+        use core::any::TypeId;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Keys {
+            bindings: HashMap<u8, String>,
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Settings {
+            keys: Keys,
+            fullscreen: bool,
+            profile: Option<String>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+        registry.register::<Keys>();
+        registry.register::<HashMap<u8, String>>();
+        registry.register::<Option<String>>();
+        registry.register::<String>();
+        registry.register::<bool>();
+        registry.register::<u8>();
+
+        let mut bindings = HashMap::default();
+        bindings.insert(1, String::from("jump"));
+        let settings = Settings {
+            keys: Keys { bindings },
+            fullscreen: true,
+            profile: None,
+        };
+        let output = to_toml_string(&settings, &registry).unwrap();
+        assert_eq!("fullscreen = true\n[keys.bindings]\n1 = \"jump\"\n", output);
+        let registration = registry.get(TypeId::of::<Settings>()).unwrap();
+        let value = from_toml_str(registration, &registry, &output).unwrap();
+        assert_eq!(settings, Settings::from_reflect(&*value).unwrap());
+
+        let error = to_toml_string(&true, &registry).unwrap_err();
+        assert!(error.to_string().contains("must be a table"));
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    #[test]
+    fn test_yaml_adapter() {
+        use crate::serde::{from_yaml_str, to_yaml_string};
+        use crate::FromReflect;
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        enum Shape {
+            Point,
+            Circle { radius: f32 },
+        }
+
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        struct Level {
+            shapes: Vec<Shape>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry.register::<Shape>();
+        registry.register::<Vec<Shape>>();
+        registry.register::<f32>();
+
+        let level = Level {
+            shapes: vec![Shape::Point, Shape::Circle { radius: 2.0 }],
+        };
+        let output = to_yaml_string(&level, &registry).unwrap();
+        assert!(output.contains("- Point\n"));
+        assert!(output.contains("- !Circle\n"));
+        let value = from_yaml_str(&registry, &output).unwrap();
+        assert_eq!(level, Level::from_reflect(&*value).unwrap());
+
+        let error = from_yaml_str(&registry, "!my_game::Missing\nshapes: []\n").unwrap_err();
+        assert!(error.to_string().contains("no registration found"));
+    }
 }
@@ -0,0 +1,146 @@
+use crate::serde::Serializable;
+use crate::{Reflect, TypeRegistration, TypeRegistry};
+use alloc::{boxed::Box, string::String};
+use core::fmt::{self, Debug, Formatter};
+
+/// A hook letting callers of the reflect serializers take over the serialization
+/// of specific values, such as writing the path of an asset instead of its handle.
+///
+/// The processor is given every value before the serializer looks at it,
+/// including the fields and items of other values. Returning `Ok(None)` leaves the value
+/// to the serializer, which keeps serializing its contents as usual.
+///
+/// Set a processor with [`SerializerConfig::with_processor`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{ReflectSerializer, ReflectSerializerProcessor, Serializable, SerializerConfig};
+/// #[derive(Reflect)]
+/// struct Handle(u64);
+///
+/// #[derive(Reflect)]
+/// struct Player {
+///     sprite: Handle,
+/// }
+///
+/// struct HandlePaths;
+///
+/// impl ReflectSerializerProcessor for HandlePaths {
+///     fn try_serialize<'a>(
+///         &self,
+///         value: &'a dyn Reflect,
+///         _registry: &TypeRegistry,
+///     ) -> Result<Option<Serializable<'a>>, String> {
+///         let Some(handle) = value.downcast_ref::<Handle>() else {
+///             return Ok(None);
+///         };
+///         let path = format!("sprites/{}.png", handle.0);
+///         Ok(Some(Serializable::Owned(Box::new(path))))
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register::<Handle>();
+///
+/// let player = Player { sprite: Handle(3) };
+/// let config = SerializerConfig::default().with_processor(HandlePaths);
+/// let serializer = ReflectSerializer::new(&player, &registry).with_config(config);
+/// let output = ron::to_string(&serializer).unwrap();
+/// assert!(output.contains(r#"(sprite:"sprites/3.png")"#));
+/// ```
+///
+/// [`SerializerConfig::with_processor`]: crate::serde::SerializerConfig::with_processor
+pub trait ReflectSerializerProcessor: Send + Sync {
+    /// Returns the serializable form of the given value if this processor handles it,
+    /// or `None` to let the serializer handle it.
+    fn try_serialize<'a>(
+        &self,
+        value: &'a dyn Reflect,
+        registry: &TypeRegistry,
+    ) -> Result<Option<Serializable<'a>>, String>;
+}
+
+impl Debug for dyn ReflectSerializerProcessor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn ReflectSerializerProcessor")
+    }
+}
+
+/// A hook letting callers of the reflect deserializers take over the deserialization
+/// of specific types, such as loading an asset from its path to create its handle.
+///
+/// Before deserializing any value, including the fields and items of other values,
+/// the deserializer asks the processor whether it [claims](Self::claims) its type.
+/// Values of claimed types are created by [`deserialize`](Self::deserialize),
+/// while all others are deserialized as usual.
+///
+/// Set a processor with [`DeserializerConfig::with_processor`].
+///
+/// # Example
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistration, TypeRegistry};
+/// # use bevy_reflect::serde::{DeserializerConfig, ReflectDeserializerProcessor, UntypedReflectDeserializer};
+/// # use serde::de::{Deserialize, DeserializeSeed};
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// struct Handle(u64);
+///
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// struct Player {
+///     sprite: Handle,
+/// }
+///
+/// struct HandlePaths;
+///
+/// impl ReflectDeserializerProcessor for HandlePaths {
+///     fn claims(&self, registration: &TypeRegistration) -> bool {
+///         registration.type_id() == TypeId::of::<Handle>()
+///     }
+///
+///     fn deserialize(
+///         &self,
+///         _registration: &TypeRegistration,
+///         _registry: &TypeRegistry,
+///         deserializer: &mut dyn erased_serde::Deserializer,
+///     ) -> Result<Box<dyn Reflect>, erased_serde::Error> {
+///         let path = String::deserialize(deserializer)?;
+///         let id = path.trim_start_matches("sprites/").trim_end_matches(".png");
+///         let id = id.parse().map_err(serde::de::Error::custom)?;
+///         Ok(Box::new(Handle(id)))
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register::<Handle>();
+///
+/// let input = r#"{"my_crate::Player":(sprite:"sprites/3.png")}"#
+///     .replace("my_crate::Player", std::any::type_name::<Player>());
+/// let mut deserializer = ron::Deserializer::from_str(&input).unwrap();
+/// let config = DeserializerConfig::default().with_processor(HandlePaths);
+/// let reflect_deserializer = UntypedReflectDeserializer::new(&registry).with_config(config);
+/// let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+/// assert_eq!(Player { sprite: Handle(3) }, Player::from_reflect(&*value).unwrap());
+/// ```
+///
+/// [`DeserializerConfig::with_processor`]: crate::serde::DeserializerConfig::with_processor
+pub trait ReflectDeserializerProcessor: Send + Sync {
+    /// Returns `true` if values of the given type should be created by this processor.
+    fn claims(&self, registration: &TypeRegistration) -> bool;
+
+    /// Deserializes a value of the given type, which this processor [claims](Self::claims).
+    fn deserialize(
+        &self,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+        deserializer: &mut dyn erased_serde::Deserializer,
+    ) -> Result<Box<dyn Reflect>, erased_serde::Error>;
+}
+
+impl Debug for dyn ReflectDeserializerProcessor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn ReflectDeserializerProcessor")
+    }
+}
@@ -0,0 +1,103 @@
+use crate::serde::content::{Content, ContentDeserializer};
+use crate::serde::{ReflectSerializer, UntypedReflectDeserializer};
+use crate::{Reflect, TypeRegistry};
+use ron::value::{Float, Number};
+use serde::de::DeserializeSeed;
+
+/// Converts a reflected value to a [`ron::Value`], without writing it out as text.
+///
+/// The value is written like by a [`ReflectSerializer`], as a map from its type name to its
+/// serialized form, so that it can be read back with [`from_ron_value`].
+/// As a [`ron::Value`] cannot represent them, struct and enum names are left out,
+/// and enum variants become either their name or a map from their name to their content.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{from_ron_value, to_ron_value};
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register::<String>();
+///
+/// let player = Player { name: String::from("Ferris") };
+/// let value = to_ron_value(&player, &registry).unwrap();
+/// let reflected = from_ron_value(&registry, &value).unwrap();
+/// assert_eq!(player, Player::from_reflect(&*reflected).unwrap());
+/// ```
+pub fn to_ron_value(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<ron::Value, ron::Error> {
+    let content = Content::serialize(&ReflectSerializer::new(value, registry))
+        .map_err(|error| ron::Error::Message(error.to_string()))?;
+    Ok(content_to_ron(content))
+}
+
+/// Reads a reflected value from a [`ron::Value`], such as one made by [`to_ron_value`]
+/// or parsed from a RON file, without writing it out as text first.
+///
+/// Like the [`UntypedReflectDeserializer`], this expects a map from the type name of the value
+/// to its serialized form, and returns the dynamic equivalent of the value.
+///
+/// Parsing RON into a [`ron::Value`] drops the names of enum variants, such as in `Circle(radius: 1.0)`,
+/// so values parsed from text must write variants like [`to_ron_value`] does:
+/// `"Point"` or `{"Circle": (radius: 1.0)}`.
+pub fn from_ron_value(
+    registry: &TypeRegistry,
+    value: &ron::Value,
+) -> Result<Box<dyn Reflect>, ron::Error> {
+    // `ron::Value` cannot be deserialized into enums, unlike `Content`
+    let deserializer = ContentDeserializer::new(ron_to_content(value));
+    UntypedReflectDeserializer::new(registry).deserialize(deserializer)
+}
+
+fn content_to_ron(content: Content) -> ron::Value {
+    match content {
+        Content::Bool(v) => ron::Value::Bool(v),
+        Content::U64(v) => ron::Value::Number(Number::from(v)),
+        Content::I64(v) => ron::Value::Number(Number::Integer(v)),
+        Content::F64(v) => ron::Value::Number(Number::Float(Float::new(v))),
+        Content::Char(v) => ron::Value::Char(v),
+        Content::String(v) => ron::Value::String(v),
+        Content::Bytes(bytes) => ron::Value::Seq(
+            bytes
+                .into_iter()
+                .map(|byte| ron::Value::Number(Number::Integer(byte.into())))
+                .collect(),
+        ),
+        Content::None => ron::Value::Option(None),
+        Content::Some(v) => ron::Value::Option(Some(Box::new(content_to_ron(*v)))),
+        Content::Unit => ron::Value::Unit,
+        Content::Seq(items) => ron::Value::Seq(items.into_iter().map(content_to_ron).collect()),
+        Content::Map(entries) => ron::Value::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (content_to_ron(key), content_to_ron(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn ron_to_content(value: &ron::Value) -> Content {
+    match value {
+        ron::Value::Bool(v) => Content::Bool(*v),
+        ron::Value::Char(v) => Content::Char(*v),
+        ron::Value::Map(map) => Content::Map(
+            map.iter()
+                .map(|(key, value)| (ron_to_content(key), ron_to_content(value)))
+                .collect(),
+        ),
+        ron::Value::Number(Number::Integer(v)) => Content::I64(*v),
+        ron::Value::Number(Number::Float(v)) => Content::F64(v.get()),
+        ron::Value::Option(None) => Content::None,
+        ron::Value::Option(Some(v)) => Content::Some(Box::new(ron_to_content(v))),
+        ron::Value::String(v) => Content::String(v.clone()),
+        ron::Value::Seq(items) => Content::Seq(items.iter().map(ron_to_content).collect()),
+        ron::Value::Unit => Content::Unit,
+    }
+}
@@ -0,0 +1,368 @@
+use crate::serde::{ReflectSerializer, UntypedReflectDeserializer};
+use crate::std_traits::ReflectDefault;
+use crate::{
+    Reflect, ReflectDeserialize, ReflectFromReflect, ReflectSerialize, TypeRegistration,
+    TypeRegistry,
+};
+use bevy_utils::HashMap;
+use serde::de::DeserializeSeed;
+use std::any::{Any, TypeId};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// A serialization format used by [`RoundtripTester`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundtripFormat {
+    /// [RON](https://github.com/ron-rs/ron), a human-readable, self-describing format.
+    Ron,
+    /// JSON, a human-readable, self-describing format.
+    Json,
+    /// [Postcard](https://github.com/jamesmunns/postcard), a compact, non-self-describing format.
+    Postcard,
+}
+
+impl RoundtripFormat {
+    /// All supported formats.
+    pub const ALL: [RoundtripFormat; 3] = [
+        RoundtripFormat::Ron,
+        RoundtripFormat::Json,
+        RoundtripFormat::Postcard,
+    ];
+
+    /// Serializes `value` in this format and deserializes it again.
+    ///
+    /// The returned value is whatever the [`UntypedReflectDeserializer`] produces,
+    /// which is usually a dynamic type (e.g. [`DynamicStruct`]).
+    ///
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    pub fn roundtrip(
+        self,
+        value: &dyn Reflect,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, RoundtripError> {
+        let serializer = ReflectSerializer::new(value, registry);
+        let deserializer = UntypedReflectDeserializer::new(registry);
+        match self {
+            RoundtripFormat::Ron => {
+                let output = ron::to_string(&serializer).map_err(RoundtripError::serialize)?;
+                let mut ron_deserializer =
+                    ron::Deserializer::from_str(&output).map_err(RoundtripError::deserialize)?;
+                deserializer
+                    .deserialize(&mut ron_deserializer)
+                    .map_err(RoundtripError::deserialize)
+            }
+            RoundtripFormat::Json => {
+                let output =
+                    serde_json::to_string(&serializer).map_err(RoundtripError::serialize)?;
+                let mut json_deserializer = serde_json::Deserializer::from_str(&output);
+                deserializer
+                    .deserialize(&mut json_deserializer)
+                    .map_err(RoundtripError::deserialize)
+            }
+            RoundtripFormat::Postcard => {
+                let output =
+                    postcard::to_allocvec(&serializer).map_err(RoundtripError::serialize)?;
+                let mut postcard_deserializer = postcard::Deserializer::from_bytes(&output);
+                deserializer
+                    .deserialize(&mut postcard_deserializer)
+                    .map_err(RoundtripError::deserialize)
+            }
+        }
+    }
+}
+
+/// The ways a single round-trip can fail.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RoundtripError {
+    #[error("failed to serialize: {0}")]
+    Serialize(String),
+    #[error("failed to deserialize: {0}")]
+    Deserialize(String),
+    #[error("the deserialized value could not be converted with `FromReflect`")]
+    FromReflect,
+    #[error("the round-tripped value is not equal to the original (`reflect_partial_eq` returned {0:?})")]
+    Mismatch(Option<bool>),
+}
+
+impl RoundtripError {
+    fn serialize(error: impl Display) -> Self {
+        RoundtripError::Serialize(error.to_string())
+    }
+
+    fn deserialize(error: impl Display) -> Self {
+        RoundtripError::Deserialize(error.to_string())
+    }
+}
+
+/// A failed round-trip of a value of a particular type through a particular format.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("round-trip of `{type_name}` through {format:?} failed: {error}")]
+pub struct RoundtripFailure {
+    pub type_name: String,
+    pub format: RoundtripFormat,
+    pub error: RoundtripError,
+}
+
+/// The result of [`RoundtripTester::run`].
+#[derive(Debug, Default)]
+pub struct RoundtripReport {
+    /// The number of values which were round-tripped (once per format).
+    pub checked: usize,
+    /// The names of eligible types for which no values could be generated.
+    pub skipped: Vec<String>,
+    /// Every failed round-trip.
+    pub failures: Vec<RoundtripFailure>,
+}
+
+impl RoundtripReport {
+    /// Returns `true` if no round-trip failed.
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Panics, listing every failure, if any round-trip failed.
+    pub fn assert_ok(&self) {
+        assert!(self.is_ok(), "{self}");
+    }
+}
+
+impl Display for RoundtripReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} round-trips checked, {} failed",
+            self.checked,
+            self.failures.len()
+        )?;
+        for failure in &self.failures {
+            write!(f, "\n  {failure}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A differential test harness which checks that reflected values survive serde round-trips.
+///
+/// Every type in the registry with [`ReflectSerialize`], [`ReflectDeserialize`] and
+/// [`ReflectFromReflect`] type data is eligible. For each eligible type, values are taken from
+/// any samples or generators added to the tester, as well as from [`ReflectDefault`] if it is
+/// registered. Each value is serialized with a [`ReflectSerializer`], deserialized with an
+/// [`UntypedReflectDeserializer`], converted back with `FromReflect`, and compared to the
+/// original with [`Reflect::reflect_partial_eq`], which must return `Some(true)`.
+///
+/// This is exposed so that crates can run the same check over their own registries.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, ReflectDeserialize, ReflectFromReflect, ReflectSerialize, TypeRegistry};
+/// # use bevy_reflect::serde::RoundtripTester;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Reflect, FromReflect, Serialize, Deserialize, Clone, PartialEq)]
+/// #[reflect_value(Serialize, Deserialize, FromReflect, PartialEq)]
+/// struct Score(u32);
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Score>();
+///
+/// let report = RoundtripTester::new(&registry)
+///     .with_generator(16, |index| Score(index as u32 * 1000))
+///     .run();
+///
+/// report.assert_ok();
+/// assert_eq!(16 * 3, report.checked);
+/// ```
+pub struct RoundtripTester<'a> {
+    registry: &'a TypeRegistry,
+    formats: Vec<RoundtripFormat>,
+    samples: HashMap<TypeId, Vec<Box<dyn Reflect>>>,
+}
+
+impl<'a> RoundtripTester<'a> {
+    /// Creates a tester over the given registry, using every [`RoundtripFormat`].
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            formats: RoundtripFormat::ALL.to_vec(),
+            samples: HashMap::default(),
+        }
+    }
+
+    /// Only round-trip through the given formats.
+    pub fn with_formats(mut self, formats: impl IntoIterator<Item = RoundtripFormat>) -> Self {
+        self.formats = formats.into_iter().collect();
+        self
+    }
+
+    /// Adds a value to round-trip when checking its type.
+    pub fn with_sample<T: Reflect>(mut self, value: T) -> Self {
+        self.samples
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Box::new(value));
+        self
+    }
+
+    /// Adds `count` values produced by `generator`, which is given the index of each value.
+    ///
+    /// This is the hook for plugging in arbitrary value generation, such as a property-testing
+    /// or fuzzing library.
+    pub fn with_generator<T: Reflect>(
+        mut self,
+        count: usize,
+        mut generator: impl FnMut(usize) -> T,
+    ) -> Self {
+        let samples = self.samples.entry(TypeId::of::<T>()).or_default();
+        samples.extend((0..count).map(|index| Box::new(generator(index)) as Box<dyn Reflect>));
+        self
+    }
+
+    /// Round-trips values of every eligible type in the registry.
+    pub fn run(&self) -> RoundtripReport {
+        let mut report = RoundtripReport::default();
+        for registration in self.registry.iter() {
+            if registration.data::<ReflectSerialize>().is_none()
+                || registration.data::<ReflectDeserialize>().is_none()
+                || registration.data::<ReflectFromReflect>().is_none()
+            {
+                continue;
+            }
+
+            let default = registration
+                .data::<ReflectDefault>()
+                .map(ReflectDefault::default);
+            let samples = self
+                .samples
+                .get(&registration.type_id())
+                .into_iter()
+                .flatten()
+                .chain(&default)
+                .collect::<Vec<_>>();
+
+            if samples.is_empty() {
+                report.skipped.push(registration.type_name().to_owned());
+                continue;
+            }
+
+            for sample in samples {
+                self.check_with(registration, sample.as_ref(), &mut report);
+            }
+        }
+        report
+    }
+
+    /// Round-trips a single value through every format.
+    ///
+    /// The value's type must be registered, but need not be eligible for [`run`](Self::run):
+    /// if it has no [`ReflectFromReflect`] type data, the deserialized value is compared directly.
+    pub fn check(&self, value: &dyn Reflect) -> RoundtripReport {
+        let mut report = RoundtripReport::default();
+        match self.registry.get(Any::type_id(value.as_any())) {
+            Some(registration) => self.check_with(registration, value, &mut report),
+            None => report.skipped.push(value.type_name().to_owned()),
+        }
+        report
+    }
+
+    fn check_with(
+        &self,
+        registration: &TypeRegistration,
+        value: &dyn Reflect,
+        report: &mut RoundtripReport,
+    ) {
+        for &format in &self.formats {
+            report.checked += 1;
+            if let Err(error) = self.roundtrip_with(registration, value, format) {
+                report.failures.push(RoundtripFailure {
+                    type_name: registration.type_name().to_owned(),
+                    format,
+                    error,
+                });
+            }
+        }
+    }
+
+    fn roundtrip_with(
+        &self,
+        registration: &TypeRegistration,
+        value: &dyn Reflect,
+        format: RoundtripFormat,
+    ) -> Result<(), RoundtripError> {
+        let output = format.roundtrip(value, self.registry)?;
+        let output = match registration.data::<ReflectFromReflect>() {
+            Some(from_reflect) => from_reflect
+                .from_reflect(output.as_ref())
+                .ok_or(RoundtripError::FromReflect)?,
+            None => output,
+        };
+
+        match value.reflect_partial_eq(output.as_ref()) {
+            Some(true) => Ok(()),
+            result => Err(RoundtripError::Mismatch(result)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::FromReflect;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Reflect, FromReflect, Serialize, Deserialize, Clone, PartialEq, Default, Debug)]
+    #[reflect_value(Serialize, Deserialize, FromReflect, PartialEq, Default)]
+    struct Lossless {
+        name: String,
+        values: Vec<i64>,
+    }
+
+    /// Forgets its value when serialized.
+    #[derive(Reflect, FromReflect, Serialize, Deserialize, Clone, PartialEq, Default, Debug)]
+    #[reflect_value(Serialize, Deserialize, FromReflect, PartialEq)]
+    struct Lossy {
+        #[serde(skip)]
+        value: u8,
+    }
+
+    #[test]
+    fn should_roundtrip_registered_types() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Lossless>();
+
+        let report = RoundtripTester::new(&registry)
+            .with_sample(Lossless {
+                name: String::from("hello"),
+                values: vec![-1, 0, i64::MAX],
+            })
+            .run();
+
+        report.assert_ok();
+        // One sample plus the default value, through every format
+        assert_eq!(2 * RoundtripFormat::ALL.len(), report.checked);
+    }
+
+    #[test]
+    fn should_report_failed_roundtrips() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Lossy>();
+
+        let tester = RoundtripTester::new(&registry).with_formats([RoundtripFormat::Json]);
+
+        let report = tester.run();
+        assert_eq!(vec![std::any::type_name::<Lossy>()], report.skipped);
+
+        let report = tester
+            .with_generator(2, |index| Lossy { value: index as u8 })
+            .run();
+        assert_eq!(2, report.checked);
+        assert_eq!(
+            vec![RoundtripFailure {
+                type_name: std::any::type_name::<Lossy>().to_owned(),
+                format: RoundtripFormat::Json,
+                error: RoundtripError::Mismatch(Some(false)),
+            }],
+            report.failures
+        );
+    }
+}
@@ -1,7 +1,18 @@
+use crate::allocation_stats::{record_allocations, AllocationSource};
 use crate::{
-    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
-    TypeInfo, TypeRegistry, VariantInfo, VariantType,
+    Array, DynamicTraitObject, Enum, List, Map, Reflect, ReflectArc, ReflectRef, ReflectRepr,
+    ReflectSerialize, ReflectTraitObject, Struct, StructInfo, StructVariantInfo, Tuple,
+    TupleStruct, TypeInfo, TypeRegistry, VariantInfo, VariantType,
 };
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use bevy_utils::HashMap;
+use core::any::TypeId;
+use core::cell::RefCell;
 use serde::ser::{
     Error, SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct,
     SerializeTupleVariant,
@@ -11,7 +22,12 @@ use serde::{
     Serialize,
 };
 
-use super::SerializationData;
+use super::content::Content;
+use super::{
+    MapKeyEncoder, ReflectSerializeBytes, ReflectSerializeWith, ReflectSerializerProcessor,
+    ReflectVersion, SerializationData, SerializeFieldFn, TypeIndices, SHARED_ID, SHARED_REF,
+    SHARED_VALUE, VERSION_FIELD,
+};
 
 pub enum Serializable<'a> {
     Owned(Box<dyn erased_serde::Serialize + 'a>),
@@ -32,15 +48,107 @@ fn get_serializable<'a, E: serde::ser::Error>(
     reflect_value: &'a dyn Reflect,
     type_registry: &TypeRegistry,
 ) -> Result<Serializable<'a>, E> {
-    let reflect_serialize = type_registry
-        .get_type_data::<ReflectSerialize>(reflect_value.type_id())
+    let type_id = reflect_value.type_id();
+    if let Some(reflect_serialize) = type_registry.get_type_data::<ReflectSerialize>(type_id) {
+        return Ok(reflect_serialize.get_serializable(reflect_value));
+    }
+    let serialize_with = type_registry
+        .get_type_data::<ReflectSerializeWith>(type_id)
         .ok_or_else(|| {
             serde::ser::Error::custom(format_args!(
-                "Type '{}' did not register ReflectSerialize",
+                "Type '{}' did not register ReflectSerialize or ReflectSerializeWith",
                 reflect_value.type_name()
             ))
         })?;
-    Ok(reflect_serialize.get_serializable(reflect_value))
+    Ok(serialize_with.get_serializable(reflect_value))
+}
+
+/// Get the value held by a reflected trait object, such as a `Box<dyn MyTrait>`
+/// or a [`DynamicTraitObject`].
+///
+/// Returns `None` if the given value is not a trait object.
+fn get_trait_object_value<'a>(
+    reflect_value: &'a dyn Reflect,
+    type_registry: &TypeRegistry,
+) -> Option<&'a dyn Reflect> {
+    if let Some(dynamic) = reflect_value.downcast_ref::<DynamicTraitObject>() {
+        return Some(dynamic.value());
+    }
+    type_registry
+        .get_type_data::<ReflectTraitObject>(reflect_value.type_id())?
+        .get(reflect_value)
+}
+
+/// Returns `true` if a field of the given type can hold a value of any type,
+/// such as a `Box<dyn Reflect>` field marked with `#[reflect(dynamic)]`.
+fn is_dynamic_field(type_id: TypeId) -> bool {
+    type_id == TypeId::of::<dyn Reflect>()
+}
+
+/// Returns `true` if the field at `index` of the given variant is [dynamic](is_dynamic_field).
+fn is_dynamic_variant_field(variant_info: &VariantInfo, index: usize) -> bool {
+    let type_id = match variant_info {
+        VariantInfo::Struct(info) => info.field_at(index).map(|field| field.type_id()),
+        VariantInfo::Tuple(info) => info.field_at(index).map(|field| field.type_id()),
+        VariantInfo::Unit(_) => None,
+    };
+    matches!(type_id, Some(type_id) if is_dynamic_field(type_id))
+}
+
+/// Collects the items of a list or array of `u8`, so that it can be serialized as bytes.
+///
+/// Returns `None` if the items of the container are not `u8`s,
+/// or if its type cannot be found for a dynamic container.
+fn get_bytes<'a>(
+    container: &dyn Reflect,
+    mut items: impl Iterator<Item = &'a dyn Reflect>,
+    context: &SerializerContext,
+) -> Option<Vec<u8>> {
+    let type_info = match container.get_type_info() {
+        TypeInfo::Dynamic(_) => context
+            .registry
+            .get_with_name(container.type_name())?
+            .type_info(),
+        type_info => type_info,
+    };
+    let item_type_id = match type_info {
+        TypeInfo::List(list_info) => list_info.item_type_id(),
+        TypeInfo::Array(array_info) => array_info.item_type_id(),
+        _ => return None,
+    };
+    if item_type_id != TypeId::of::<u8>() {
+        return None;
+    }
+    items.try_fold(Vec::new(), |mut bytes, item| {
+        bytes.push(*item.downcast_ref::<u8>()?);
+        Some(bytes)
+    })
+}
+
+/// Returns the index to write instead of the given type name, if [type indices] are enabled.
+///
+/// [type indices]: SerializerConfig::with_type_indices
+fn get_type_index<E: Error>(
+    type_name: &str,
+    context: &SerializerContext,
+) -> Result<Option<u32>, E> {
+    let Some(type_indices) = &context.config.type_indices else {
+        return Ok(None);
+    };
+    type_indices.index_of(type_name).map(Some).ok_or_else(|| {
+        Error::custom(format_args!(
+            "type `{type_name}` is not part of the type indices"
+        ))
+    })
+}
+
+/// Returns `true` if the given value is an `Option::None`.
+fn is_none(value: &dyn Reflect) -> bool {
+    matches!(
+        value.reflect_ref(),
+        ReflectRef::Enum(value) if value.type_name().starts_with("core::option::Option")
+            && value.variant_name() == "None"
+    )
 }
 
 /// Get the underlying [`TypeInfo`] of a given type.
@@ -63,6 +171,222 @@ fn get_type_info<E: Error>(
     }
 }
 
+/// Options for serializing reflected values.
+///
+/// Data serialized with non-default options can only be read back by a deserializer
+/// given the matching [`DeserializerConfig`].
+///
+/// [`DeserializerConfig`]: crate::serde::DeserializerConfig
+#[derive(Clone, Debug, Default)]
+pub struct SerializerConfig {
+    shared_references: bool,
+    positional: bool,
+    omit_none: bool,
+    non_finite_floats: NonFiniteFloatPolicy,
+    map_key_encoder: Option<Arc<dyn MapKeyEncoder>>,
+    type_indices: Option<Arc<TypeIndices>>,
+    processor: Option<Arc<dyn ReflectSerializerProcessor>>,
+    enum_representation: EnumRepresentation,
+    transparent_structs: bool,
+    sorted_keys: bool,
+}
+
+impl SerializerConfig {
+    /// Sets whether values shared between several `Arc`s are written only once.
+    ///
+    /// When enabled, each `Arc` is serialized as a map. The first `Arc` pointing to a value
+    /// assigns it a reference ID, written in an `id` entry before the `value` itself,
+    /// and every other `Arc` pointing to it only contains a `ref` entry with that ID.
+    ///
+    /// This requires the `Arc<T>` types to be registered, as they are found through [`ReflectArc`].
+    pub fn with_shared_references(mut self, shared_references: bool) -> Self {
+        self.shared_references = shared_references;
+        self
+    }
+
+    /// Sets whether structs and struct variants are written as tuples of their fields,
+    /// in the order given by their [`TypeInfo`], rather than with the names of their fields.
+    ///
+    /// This keeps self-describing formats from writing every field name, and makes the output
+    /// of every format depend only on field order, as expected by non-self-describing formats
+    /// such as `bincode` or `postcard`. Fields [skipped during serialization] are left out,
+    /// so data written this way must be read back with the same set of fields.
+    ///
+    /// Values whose type is not known from their container, such as the root value of a
+    /// [`ReflectSerializer`] or the value of a `#[reflect(dynamic)]` field,
+    /// are still written along with their type name.
+    ///
+    /// [skipped during serialization]: crate::serde::SerializationData
+    pub fn with_positional(mut self, positional: bool) -> Self {
+        self.positional = positional;
+        self
+    }
+
+    /// Sets whether fields of structs and struct variants holding `Option::None` are left out.
+    ///
+    /// Data written this way must be read with [`DeserializerConfig::with_omit_none`]
+    /// for the missing fields to be deserialized as `None`.
+    /// This has no effect in [positional](SerializerConfig::with_positional) mode,
+    /// where every field is needed to know which one comes next.
+    ///
+    /// [`DeserializerConfig::with_omit_none`]: crate::serde::DeserializerConfig::with_omit_none
+    pub fn with_omit_none(mut self, omit_none: bool) -> Self {
+        self.omit_none = omit_none;
+        self
+    }
+
+    /// Sets how `f32` and `f64` values which are NaN or infinite are written.
+    ///
+    /// Defaults to [`NonFiniteFloatPolicy::Native`].
+    pub fn with_non_finite_floats(mut self, non_finite_floats: NonFiniteFloatPolicy) -> Self {
+        self.non_finite_floats = non_finite_floats;
+        self
+    }
+
+    /// Sets the encoder converting the keys of maps to strings,
+    /// for formats such as JSON which do not allow other keys.
+    ///
+    /// By default, keys are serialized like any other value.
+    pub fn with_map_key_encoder(mut self, encoder: impl MapKeyEncoder + 'static) -> Self {
+        self.map_key_encoder = Some(Arc::new(encoder));
+        self
+    }
+
+    /// Sets the indices to write instead of type names, such as for the value of a
+    /// [`ReflectSerializer`] or of a trait object, which may greatly reduce the size of the output.
+    ///
+    /// The data must be read with the same indices, which both ends of a network connection
+    /// may agree on by comparing their [checksums](TypeIndices::checksum).
+    /// Trying to serialize a value whose type is not part of the indices causes an error.
+    pub fn with_type_indices(mut self, type_indices: Arc<TypeIndices>) -> Self {
+        self.type_indices = Some(type_indices);
+        self
+    }
+
+    /// Sets the processor given the chance to serialize each value before the serializer does.
+    pub fn with_processor(mut self, processor: impl ReflectSerializerProcessor + 'static) -> Self {
+        self.processor = Some(Arc::new(processor));
+        self
+    }
+
+    /// Sets how the variant of enums is written alongside their content.
+    ///
+    /// Defaults to [`EnumRepresentation::External`]. `Option` is always written as an optional
+    /// value, and enums are always externally tagged in [positional](SerializerConfig::with_positional) mode.
+    pub fn with_enum_representation(mut self, enum_representation: EnumRepresentation) -> Self {
+        self.enum_representation = enum_representation;
+        self
+    }
+
+    /// Sets whether tuple structs with a single field are written as that field alone,
+    /// and unit structs as a unit value, such as `null` in JSON.
+    ///
+    /// This leaves out the `(value)` wrappers which make files edited by hand harder to read.
+    /// Tuple structs with a [skipped] field are still written as tuples,
+    /// as are unit structs with a [`ReflectVersion`].
+    ///
+    /// [skipped]: crate::serde::SerializationData
+    pub fn with_transparent_structs(mut self, transparent_structs: bool) -> Self {
+        self.transparent_structs = transparent_structs;
+        self
+    }
+
+    /// Sets whether the entries of maps are sorted by their serialized key,
+    /// and the fields of structs and struct variants by name.
+    ///
+    /// By default, map entries are written in the iteration order of the map,
+    /// which for hash maps changes from one run to the next, and fields in declaration order.
+    /// Sorting them makes the output of equal values byte for byte the same,
+    /// as needed to hash it or to keep the diffs of scene files in version control small.
+    /// Fields are still written in declaration order in [positional](SerializerConfig::with_positional) mode.
+    pub fn with_sorted_keys(mut self, sorted_keys: bool) -> Self {
+        self.sorted_keys = sorted_keys;
+        self
+    }
+}
+
+/// How the reflect serializers write the variant of an enum, matching the
+/// [enum representations] of serde's derive macros.
+///
+/// Every representation other than [`External`] needs a self-describing format
+/// to be deserialized, as the content of the enum must be read before knowing its variant.
+///
+/// [enum representations]: https://serde.rs/enum-representations.html
+/// [`External`]: EnumRepresentation::External
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EnumRepresentation {
+    /// Writes the content of the variant under its name, such as `{"Circle": {"radius": 1.0}}`.
+    #[default]
+    External,
+    /// Writes the variant name in the `tag` field, next to the fields of the variant,
+    /// such as `{"type": "Circle", "radius": 1.0}`.
+    ///
+    /// This only supports unit variants, struct variants, and newtype variants holding a struct.
+    Internal { tag: &'static str },
+    /// Writes the variant name in the `tag` field and its content in the `content` field,
+    /// such as `{"type": "Circle", "value": {"radius": 1.0}}`.
+    Adjacent {
+        tag: &'static str,
+        content: &'static str,
+    },
+    /// Writes only the content of the variant, such as `{"radius": 1.0}`.
+    ///
+    /// The deserializers try every variant in order and use the first one which matches,
+    /// so variants with the same content cannot be told apart.
+    Untagged,
+}
+
+/// How the reflect serializers write `f32` and `f64` values which are NaN or infinite,
+/// and how the deserializers read them back.
+///
+/// Many formats cannot represent these values: JSON writes them as `null`,
+/// which cannot be read back as a number. The [`Null`] and [`String`] policies need
+/// a self-describing format to be deserialized, as the kind of value is only known once read.
+///
+/// [`Null`]: NonFiniteFloatPolicy::Null
+/// [`String`]: NonFiniteFloatPolicy::String
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NonFiniteFloatPolicy {
+    /// Writes non-finite floats like any other, leaving them to the format.
+    #[default]
+    Native,
+    /// Fails with an error when a non-finite float is found.
+    Error,
+    /// Writes non-finite floats as a null value, which is read back as NaN.
+    ///
+    /// This loses the difference between NaN and the infinities.
+    Null,
+    /// Writes non-finite floats as the strings `NaN`, `inf` and `-inf`.
+    String,
+}
+
+/// Returns the given value as an `f64` if it is a non-finite `f32` or `f64`.
+fn get_non_finite_float(value: &dyn Reflect) -> Option<f64> {
+    let float = match value.downcast_ref::<f32>() {
+        Some(float) => f64::from(*float),
+        None => *value.downcast_ref::<f64>()?,
+    };
+    (!float.is_finite()).then_some(float)
+}
+
+/// The state shared by the serializers of a value and all of the values it contains.
+struct SerializerContext<'a> {
+    registry: &'a TypeRegistry,
+    config: &'a SerializerConfig,
+    /// The reference IDs of the shared values written so far, by address.
+    shared_ids: RefCell<HashMap<usize, u64>>,
+}
+
+impl<'a> SerializerContext<'a> {
+    fn new(registry: &'a TypeRegistry, config: &'a SerializerConfig) -> Self {
+        Self {
+            registry,
+            config,
+            shared_ids: RefCell::default(),
+        }
+    }
+}
+
 /// A general purpose serializer for reflected types.
 ///
 /// The serialized data will take the form of a map containing the following entries:
@@ -73,11 +397,22 @@ fn get_type_info<E: Error>(
 pub struct ReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    config: SerializerConfig,
 }
 
 impl<'a> ReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        ReflectSerializer { value, registry }
+        ReflectSerializer {
+            value,
+            registry,
+            config: SerializerConfig::default(),
+        }
+    }
+
+    /// Sets the options to serialize the value with.
+    pub fn with_config(mut self, config: SerializerConfig) -> Self {
+        self.config = config;
+        self
     }
 }
 
@@ -86,12 +421,12 @@ impl<'a> Serialize for ReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(1))?;
-        state.serialize_entry(
-            self.value.type_name(),
-            &TypedReflectSerializer::new(self.value, self.registry),
-        )?;
-        state.end()
+        let context = SerializerContext::new(self.registry, &self.config);
+        UntypedSerializer {
+            value: self.value,
+            context: &context,
+        }
+        .serialize(serializer)
     }
 }
 
@@ -100,11 +435,22 @@ impl<'a> Serialize for ReflectSerializer<'a> {
 pub struct TypedReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    config: SerializerConfig,
 }
 
 impl<'a> TypedReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        TypedReflectSerializer { value, registry }
+        TypedReflectSerializer {
+            value,
+            registry,
+            config: SerializerConfig::default(),
+        }
+    }
+
+    /// Sets the options to serialize the value with.
+    pub fn with_config(mut self, config: SerializerConfig) -> Self {
+        self.config = config;
+        self
     }
 }
 
@@ -113,8 +459,108 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let context = SerializerContext::new(self.registry, &self.config);
+        ValueSerializer::new(self.value, &context).serialize(serializer)
+    }
+}
+
+/// The serializer behind [`ReflectSerializer`], writing the type name of the value alongside it.
+struct UntypedSerializer<'a> {
+    value: &'a dyn Reflect,
+    context: &'a SerializerContext<'a>,
+}
+
+impl<'a> Serialize for UntypedSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        let value = ValueSerializer::new(self.value, self.context);
+        match get_type_index(self.value.type_name(), self.context)? {
+            Some(index) => state.serialize_entry(&index, &value)?,
+            None => state.serialize_entry(self.value.type_name(), &value)?,
+        }
+        state.end()
+    }
+}
+
+/// The serializer behind [`TypedReflectSerializer`], used for the value and everything it contains.
+struct ValueSerializer<'a> {
+    value: &'a dyn Reflect,
+    context: &'a SerializerContext<'a>,
+}
+
+impl<'a> ValueSerializer<'a> {
+    fn new(value: &'a dyn Reflect, context: &'a SerializerContext<'a>) -> Self {
+        Self { value, context }
+    }
+}
+
+impl<'a> Serialize for ValueSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let registry = self.context.registry;
+
+        if let Some(processor) = &self.context.config.processor {
+            let processed = processor
+                .try_serialize(self.value, registry)
+                .map_err(Error::custom)?;
+            if let Some(serializable) = processed {
+                return serializable.borrow().serialize(serializer);
+            }
+        }
+
+        if let Some(value) = get_trait_object_value(self.value, registry) {
+            return TraitObjectSerializer {
+                value,
+                context: self.context,
+            }
+            .serialize(serializer);
+        }
+
+        if let Some(reflect_arc) = registry.get_type_data::<ReflectArc>(self.value.type_id()) {
+            if let (Some(value), Some(address)) =
+                (reflect_arc.get(self.value), reflect_arc.address(self.value))
+            {
+                if self.context.config.shared_references {
+                    return SharedSerializer {
+                        value,
+                        address,
+                        context: self.context,
+                    }
+                    .serialize(serializer);
+                }
+                return ValueSerializer::new(value, self.context).serialize(serializer);
+            }
+        }
+
+        if let Some(bytes) = registry
+            .get_type_data::<ReflectSerializeBytes>(self.value.type_id())
+            .and_then(|serialize_bytes| serialize_bytes.as_bytes(self.value))
+        {
+            return serializer.serialize_bytes(bytes);
+        }
+
+        if let Some(float) = get_non_finite_float(self.value) {
+            match self.context.config.non_finite_floats {
+                NonFiniteFloatPolicy::Native => {}
+                NonFiniteFloatPolicy::Error => {
+                    return Err(Error::custom(format_args!(
+                        "cannot serialize non-finite float `{float}`"
+                    )));
+                }
+                NonFiniteFloatPolicy::Null => return serializer.serialize_none(),
+                NonFiniteFloatPolicy::String => {
+                    return serializer.serialize_str(&float.to_string());
+                }
+            }
+        }
+
         // Handle both Value case and types that have a custom `Serialize`
-        let serializable = get_serializable::<S::Error>(self.value, self.registry);
+        let serializable = get_serializable::<S::Error>(self.value, registry);
         if let Ok(serializable) = serializable {
             return serializable.borrow().serialize(serializer);
         }
@@ -122,44 +568,203 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
         match self.value.reflect_ref() {
             ReflectRef::Struct(value) => StructSerializer {
                 struct_value: value,
-                registry: self.registry,
+                context: self.context,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(value) => TupleStructSerializer {
                 tuple_struct: value,
-                registry: self.registry,
+                context: self.context,
             }
             .serialize(serializer),
             ReflectRef::Tuple(value) => TupleSerializer {
                 tuple: value,
-                registry: self.registry,
+                context: self.context,
             }
             .serialize(serializer),
             ReflectRef::List(value) => ListSerializer {
                 list: value,
-                registry: self.registry,
+                context: self.context,
             }
             .serialize(serializer),
             ReflectRef::Array(value) => ArraySerializer {
                 array: value,
-                registry: self.registry,
+                context: self.context,
             }
             .serialize(serializer),
             ReflectRef::Map(value) => MapSerializer {
                 map: value,
-                registry: self.registry,
+                context: self.context,
             }
             .serialize(serializer),
             ReflectRef::Enum(value) => EnumSerializer {
                 enum_value: value,
-                registry: self.registry,
+                context: self.context,
             }
             .serialize(serializer),
-            ReflectRef::Value(_) => Err(serializable.err().unwrap()),
+            ReflectRef::Value(value) => {
+                // Types reflected through a representation are written as their `into` representation
+                match registry
+                    .get_type_data::<ReflectRepr>(value.type_id())
+                    .and_then(|reflect_repr| reflect_repr.into_repr(value))
+                {
+                    Some(repr) => {
+                        record_allocations(AllocationSource::Serialize, 1);
+                        ValueSerializer::new(&*repr, self.context).serialize(serializer)
+                    }
+                    None => Err(serializable.err().unwrap()),
+                }
+            }
         }
     }
 }
 
+/// A serializer for the value held by a reflected trait object, such as a `Box<dyn MyTrait>`.
+///
+/// The serialized data will take the form of a map containing a single entry,
+/// from the [tag] of the value's type (or its _full_ type name if it has no tag)
+/// to the serialized value.
+///
+/// [tag]: crate::TypeRegistry::register_tag
+struct TraitObjectSerializer<'a> {
+    value: &'a dyn Reflect,
+    context: &'a SerializerContext<'a>,
+}
+
+impl<'a> Serialize for TraitObjectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let type_name = self.value.type_name();
+        let registration = self
+            .context
+            .registry
+            .get_with_name(type_name)
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no registration found for trait object value of type {type_name}",
+                ))
+            })?;
+
+        let mut state = serializer.serialize_map(Some(1))?;
+        let value = ValueSerializer::new(self.value, self.context);
+        match get_type_index(type_name, self.context)? {
+            Some(index) => state.serialize_entry(&index, &value)?,
+            None => state.serialize_entry(registration.tag().unwrap_or(type_name), &value)?,
+        }
+        state.end()
+    }
+}
+
+/// A serializer for the value inside an `Arc`, when [shared references] are enabled.
+///
+/// The first time the value is found, this writes a map with an `id` entry assigning it
+/// the next reference ID and a `value` entry, and a map with a single `ref` entry afterwards.
+///
+/// [shared references]: SerializerConfig::with_shared_references
+struct SharedSerializer<'a> {
+    value: &'a dyn Reflect,
+    address: usize,
+    context: &'a SerializerContext<'a>,
+}
+
+impl<'a> Serialize for SharedSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let existing_id = self.context.shared_ids.borrow().get(&self.address).copied();
+        if let Some(id) = existing_id {
+            let mut state = serializer.serialize_map(Some(1))?;
+            state.serialize_entry(SHARED_REF, &id)?;
+            return state.end();
+        }
+
+        let id = {
+            let mut shared_ids = self.context.shared_ids.borrow_mut();
+            let id = shared_ids.len() as u64;
+            shared_ids.insert(self.address, id);
+            id
+        };
+        let mut state = serializer.serialize_map(Some(2))?;
+        state.serialize_entry(SHARED_ID, &id)?;
+        state.serialize_entry(
+            SHARED_VALUE,
+            &ValueSerializer::new(self.value, self.context),
+        )?;
+        state.end()
+    }
+}
+
+/// A serializer for the value of a field.
+///
+/// As the type of a dynamic field's value cannot be known from the type info
+/// of its container, it is written along with its type name like in a [`ReflectSerializer`].
+struct FieldSerializer<'a> {
+    value: &'a dyn Reflect,
+    is_dynamic: bool,
+    serialize_fn: Option<SerializeFieldFn>,
+    context: &'a SerializerContext<'a>,
+}
+
+impl<'a> FieldSerializer<'a> {
+    fn new(value: &'a dyn Reflect, is_dynamic: bool, context: &'a SerializerContext<'a>) -> Self {
+        Self {
+            value,
+            is_dynamic,
+            serialize_fn: None,
+            context,
+        }
+    }
+
+    fn with_serialize_fn(mut self, serialize_fn: Option<SerializeFieldFn>) -> Self {
+        self.serialize_fn = serialize_fn;
+        self
+    }
+}
+
+impl<'a> Serialize for FieldSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if let Some(serialize_fn) = self.serialize_fn {
+            let custom: &dyn erased_serde::Serialize = &SerializeWith {
+                value: self.value,
+                serialize_fn,
+            };
+            custom.serialize(serializer)
+        } else if self.is_dynamic {
+            UntypedSerializer {
+                value: self.value,
+                context: self.context,
+            }
+            .serialize(serializer)
+        } else {
+            ValueSerializer::new(self.value, self.context).serialize(serializer)
+        }
+    }
+}
+
+/// Serializes a value with the function given by its [`SerializationData`]
+/// or [`ReflectSerializeWith`](super::ReflectSerializeWith).
+pub(super) struct SerializeWith<'a> {
+    pub(super) value: &'a dyn Reflect,
+    pub(super) serialize_fn: SerializeFieldFn,
+}
+
+impl<'a> erased_serde::Serialize for SerializeWith<'a> {
+    fn erased_serialize(
+        &self,
+        serializer: &mut dyn erased_serde::Serializer,
+    ) -> Result<
+        <&'static mut dyn erased_serde::Serializer as serde::Serializer>::Ok,
+        erased_serde::Error,
+    > {
+        (self.serialize_fn)(self.value, serializer)
+    }
+}
+
 pub struct ReflectValueSerializer<'a> {
     pub registry: &'a TypeRegistry,
     pub value: &'a dyn Reflect,
@@ -176,9 +781,9 @@ impl<'a> Serialize for ReflectValueSerializer<'a> {
     }
 }
 
-pub struct StructSerializer<'a> {
-    pub struct_value: &'a dyn Struct,
-    pub registry: &'a TypeRegistry,
+struct StructSerializer<'a> {
+    struct_value: &'a dyn Struct,
+    context: &'a SerializerContext<'a>,
 }
 
 impl<'a> Serialize for StructSerializer<'a> {
@@ -189,7 +794,7 @@ impl<'a> Serialize for StructSerializer<'a> {
         let type_info = get_type_info(
             self.struct_value.get_type_info(),
             self.struct_value.type_name(),
-            self.registry,
+            self.context.registry,
         )?;
 
         let struct_info = match type_info {
@@ -201,33 +806,119 @@ impl<'a> Serialize for StructSerializer<'a> {
             }
         };
 
-        let serialization_data = self
-            .registry
-            .get(type_info.type_id())
-            .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
-        let mut state = serializer.serialize_struct(
-            struct_info.name(),
-            self.struct_value.field_len() - ignored_len,
+        let registration = self.context.registry.get(type_info.type_id());
+        let serialization_data =
+            registration.and_then(|registration| registration.data::<SerializationData>());
+        let version = registration
+            .and_then(|registration| registration.data::<ReflectVersion>())
+            .map(ReflectVersion::version);
+        let converted = self.struct_value.converted_fields();
+        let mut fields = struct_fields(
+            self.struct_value,
+            &converted,
+            struct_info,
+            serialization_data,
+            self.context,
         )?;
+        if self.context.config.transparent_structs
+            && struct_info.field_len() == 0
+            && struct_info.converted_fields().is_empty()
+            && version.is_none()
+        {
+            return serializer.serialize_unit_struct(struct_info.name());
+        }
+        let field_len = fields.len() + usize::from(version.is_some());
 
-        for (index, value) in self.struct_value.iter_fields().enumerate() {
-            if serialization_data
-                .map(|data| data.is_ignored_field(index))
-                .unwrap_or(false)
-            {
+        if self.context.config.positional {
+            let mut state = serializer.serialize_tuple(field_len)?;
+            if let Some(version) = version {
+                state.serialize_element(&version)?;
+            }
+            for (_, field) in fields {
+                state.serialize_element(&field)?;
+            }
+            return state.end();
+        }
+
+        if self.context.config.sorted_keys {
+            fields.sort_by_key(|(name, _)| *name);
+        }
+        let omit_none = self.context.config.omit_none;
+        let omitted_len = fields
+            .iter()
+            .filter(|(_, field)| omit_none && is_none(field.value))
+            .count();
+        let mut state = serializer.serialize_struct(struct_info.name(), field_len - omitted_len)?;
+        if let Some(version) = version {
+            state.serialize_field(VERSION_FIELD, &version)?;
+        }
+        for (name, field) in fields {
+            if omit_none && is_none(field.value) {
+                state.skip_field(name)?;
                 continue;
             }
-            let key = struct_info.field_at(index).unwrap().name();
-            state.serialize_field(key, &TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_field(name, &field)?;
         }
         state.end()
     }
 }
 
-pub struct TupleStructSerializer<'a> {
-    pub tuple_struct: &'a dyn TupleStruct,
-    pub registry: &'a TypeRegistry,
+/// Returns the fields of a struct which are not skipped during serialization, along with their names.
+///
+/// Fields are matched with their info by name, as the fields of a dynamic struct
+/// may be in a different order than those of the type it represents.
+/// The [converted fields](Struct::converted_fields) of the struct, given as `converted`, come last.
+fn struct_fields<'a, E: Error>(
+    struct_value: &'a dyn Struct,
+    converted: &'a [(&'a str, Box<dyn Reflect>)],
+    struct_info: &'static StructInfo,
+    serialization_data: Option<&SerializationData>,
+    context: &'a SerializerContext<'a>,
+) -> Result<Vec<(&'static str, FieldSerializer<'a>)>, E> {
+    let mut fields = Vec::with_capacity(struct_value.field_len());
+    for (index, value) in struct_value.iter_fields().enumerate() {
+        let name = struct_value.name_at(index).unwrap();
+        // Dynamic structs hold the converted fields of the type they represent like any other field
+        let field_info = struct_info
+            .field(name)
+            .or_else(|| struct_info.converted_field(name))
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no field named `{name}` on struct `{}`",
+                    struct_info.type_name()
+                ))
+            })?;
+        let is_ignored = matches!(
+            serialization_data,
+            Some(data) if data.is_ignored_field(index) || data.is_ignored_name(name)
+        );
+        if is_ignored {
+            continue;
+        }
+        let is_dynamic = is_dynamic_field(field_info.type_id());
+        let serialize_fn = serialization_data.and_then(|data| data.named_serialize_fn(name));
+        let field =
+            FieldSerializer::new(value, is_dynamic, context).with_serialize_fn(serialize_fn);
+        fields.push((field_info.name(), field));
+    }
+    for (name, value) in converted {
+        let field_info = struct_info.converted_field(name).ok_or_else(|| {
+            Error::custom(format_args!(
+                "no converted field named `{name}` on struct `{}`",
+                struct_info.type_name()
+            ))
+        })?;
+        fields.push((
+            field_info.name(),
+            FieldSerializer::new(&**value, false, context),
+        ));
+    }
+    Ok(fields)
+}
+
+struct TupleStructSerializer<'a> {
+    tuple_struct: &'a dyn TupleStruct,
+    context: &'a SerializerContext<'a>,
 }
 
 impl<'a> Serialize for TupleStructSerializer<'a> {
@@ -238,7 +929,7 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
         let type_info = get_type_info(
             self.tuple_struct.get_type_info(),
             self.tuple_struct.type_name(),
-            self.registry,
+            self.context.registry,
         )?;
 
         let tuple_struct_info = match type_info {
@@ -251,10 +942,28 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
         };
 
         let serialization_data = self
+            .context
             .registry
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
         let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
+        if self.context.config.transparent_structs
+            && tuple_struct_info.field_len() == 1
+            && ignored_len == 0
+        {
+            let is_dynamic = matches!(
+                tuple_struct_info.field_at(0),
+                Some(field) if is_dynamic_field(field.type_id())
+            );
+            let serialize_fn = serialization_data.and_then(|data| data.serialize_fn(0));
+            return FieldSerializer::new(
+                self.tuple_struct.field(0).unwrap(),
+                is_dynamic,
+                self.context,
+            )
+            .with_serialize_fn(serialize_fn)
+            .serialize(serializer);
+        }
         let mut state = serializer.serialize_tuple_struct(
             tuple_struct_info.name(),
             self.tuple_struct.field_len() - ignored_len,
@@ -267,15 +976,23 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
             {
                 continue;
             }
-            state.serialize_field(&TypedReflectSerializer::new(value, self.registry))?;
+            let is_dynamic = matches!(
+                tuple_struct_info.field_at(index),
+                Some(field) if is_dynamic_field(field.type_id())
+            );
+            let serialize_fn = serialization_data.and_then(|data| data.serialize_fn(index));
+            state.serialize_field(
+                &FieldSerializer::new(value, is_dynamic, self.context)
+                    .with_serialize_fn(serialize_fn),
+            )?;
         }
         state.end()
     }
 }
 
-pub struct EnumSerializer<'a> {
-    pub enum_value: &'a dyn Enum,
-    pub registry: &'a TypeRegistry,
+struct EnumSerializer<'a> {
+    enum_value: &'a dyn Enum,
+    context: &'a SerializerContext<'a>,
 }
 
 impl<'a> Serialize for EnumSerializer<'a> {
@@ -286,7 +1003,7 @@ impl<'a> Serialize for EnumSerializer<'a> {
         let type_info = get_type_info(
             self.enum_value.get_type_info(),
             self.enum_value.type_name(),
-            self.registry,
+            self.context.registry,
         )?;
 
         let enum_info = match type_info {
@@ -311,6 +1028,39 @@ impl<'a> Serialize for EnumSerializer<'a> {
         let variant_type = self.enum_value.variant_type();
         let field_len = self.enum_value.field_len();
 
+        let config = self.context.config;
+        let is_option = self
+            .enum_value
+            .type_name()
+            .starts_with("core::option::Option");
+        if !is_option && !config.positional {
+            let content = VariantContentSerializer {
+                enum_value: self.enum_value,
+                variant_info,
+                context: self.context,
+            };
+            match config.enum_representation {
+                EnumRepresentation::External => {}
+                EnumRepresentation::Internal { tag } => {
+                    return content.serialize_internally_tagged(serializer, enum_name, tag);
+                }
+                EnumRepresentation::Adjacent {
+                    tag,
+                    content: content_field,
+                } => {
+                    let is_unit = variant_type == VariantType::Unit;
+                    let mut state =
+                        serializer.serialize_struct(enum_name, if is_unit { 1 } else { 2 })?;
+                    state.serialize_field(tag, variant_name)?;
+                    if !is_unit {
+                        state.serialize_field(content_field, &content)?;
+                    }
+                    return state.end();
+                }
+                EnumRepresentation::Untagged => return content.serialize(serializer),
+            }
+        }
+
         match variant_type {
             VariantType::Unit => {
                 if self
@@ -333,35 +1083,58 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     }
                 };
 
+                if self.context.config.positional {
+                    let mut state = serializer.serialize_tuple_variant(
+                        enum_name,
+                        variant_index,
+                        variant_name,
+                        field_len,
+                    )?;
+                    for (index, field) in self.enum_value.iter_fields().enumerate() {
+                        state.serialize_field(&FieldSerializer::new(
+                            field.value(),
+                            is_dynamic_variant_field(variant_info, index),
+                            self.context,
+                        ))?;
+                    }
+                    return state.end();
+                }
+
+                let fields = VariantContentSerializer {
+                    enum_value: self.enum_value,
+                    variant_info,
+                    context: self.context,
+                }
+                .struct_variant_fields(struct_info);
                 let mut state = serializer.serialize_struct_variant(
                     enum_name,
                     variant_index,
                     variant_name,
-                    field_len,
+                    fields.len(),
                 )?;
-                for (index, field) in self.enum_value.iter_fields().enumerate() {
-                    let field_info = struct_info.field_at(index).unwrap();
-                    state.serialize_field(
-                        field_info.name(),
-                        &TypedReflectSerializer::new(field.value(), self.registry),
-                    )?;
+                for (name, field) in fields {
+                    state.serialize_field(name, &field)?;
                 }
                 state.end()
             }
             VariantType::Tuple if field_len == 1 => {
-                let field = self.enum_value.field_at(0).unwrap();
+                let field = FieldSerializer::new(
+                    self.enum_value.field_at(0).unwrap(),
+                    is_dynamic_variant_field(variant_info, 0),
+                    self.context,
+                );
                 if self
                     .enum_value
                     .type_name()
                     .starts_with("core::option::Option")
                 {
-                    serializer.serialize_some(&TypedReflectSerializer::new(field, self.registry))
+                    serializer.serialize_some(&field)
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new(field, self.registry),
+                        &field,
                     )
                 }
             }
@@ -372,10 +1145,11 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     variant_name,
                     field_len,
                 )?;
-                for field in self.enum_value.iter_fields() {
-                    state.serialize_field(&TypedReflectSerializer::new(
+                for (index, field) in self.enum_value.iter_fields().enumerate() {
+                    state.serialize_field(&FieldSerializer::new(
                         field.value(),
-                        self.registry,
+                        is_dynamic_variant_field(variant_info, index),
+                        self.context,
                     ))?;
                 }
                 state.end()
@@ -384,9 +1158,154 @@ impl<'a> Serialize for EnumSerializer<'a> {
     }
 }
 
-pub struct TupleSerializer<'a> {
-    pub tuple: &'a dyn Tuple,
-    pub registry: &'a TypeRegistry,
+/// A serializer for the content of an enum variant without its name,
+/// used by the [`EnumRepresentation`]s which write the variant name separately, if at all.
+struct VariantContentSerializer<'a> {
+    enum_value: &'a dyn Enum,
+    variant_info: &'static VariantInfo,
+    context: &'a SerializerContext<'a>,
+}
+
+impl<'a> VariantContentSerializer<'a> {
+    fn field(&self, index: usize) -> FieldSerializer<'a> {
+        FieldSerializer::new(
+            self.enum_value.field_at(index).unwrap(),
+            is_dynamic_variant_field(self.variant_info, index),
+            self.context,
+        )
+    }
+
+    /// Returns the fields of a struct variant to write, along with their names.
+    fn struct_variant_fields(
+        &self,
+        struct_info: &'static StructVariantInfo,
+    ) -> Vec<(&'static str, FieldSerializer<'a>)> {
+        let omit_none = self.context.config.omit_none;
+        let mut fields: Vec<_> = self
+            .enum_value
+            .iter_fields()
+            .enumerate()
+            .filter(|(_, field)| !(omit_none && is_none(field.value())))
+            .map(|(index, _)| {
+                (
+                    struct_info.field_at(index).unwrap().name(),
+                    self.field(index),
+                )
+            })
+            .collect();
+        if self.context.config.sorted_keys {
+            fields.sort_by_key(|(name, _)| *name);
+        }
+        fields
+    }
+
+    /// Serializes the variant as a map holding its name in the `tag` field along with its fields.
+    fn serialize_internally_tagged<S: serde::Serializer>(
+        &self,
+        serializer: S,
+        enum_name: &'static str,
+        tag: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        let variant_name = self.variant_info.name();
+        match self.variant_info {
+            VariantInfo::Unit(_) => {
+                let mut state = serializer.serialize_struct(enum_name, 1)?;
+                state.serialize_field(tag, variant_name)?;
+                state.end()
+            }
+            VariantInfo::Struct(struct_info) => {
+                let fields = self.struct_variant_fields(struct_info);
+                let mut state = serializer.serialize_struct(enum_name, fields.len() + 1)?;
+                state.serialize_field(tag, variant_name)?;
+                for (name, field) in fields {
+                    state.serialize_field(name, &field)?;
+                }
+                state.end()
+            }
+            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                let value = self.enum_value.field_at(0).unwrap();
+                let ReflectRef::Struct(struct_value) = value.reflect_ref() else {
+                    return Err(Error::custom(format_args!(
+                        "cannot serialize newtype variant `{enum_name}::{variant_name}` with an internal tag, as it does not hold a struct",
+                    )));
+                };
+                let TypeInfo::Struct(struct_info) = get_type_info(
+                    struct_value.get_type_info(),
+                    struct_value.type_name(),
+                    self.context.registry,
+                )?
+                else {
+                    return Err(Error::custom(format_args!(
+                        "expected struct type for `{}`",
+                        struct_value.type_name(),
+                    )));
+                };
+                let serialization_data = self
+                    .context
+                    .registry
+                    .get(struct_info.type_id())
+                    .and_then(|registration| registration.data::<SerializationData>());
+                let omit_none = self.context.config.omit_none;
+                let converted = struct_value.converted_fields();
+                let mut fields = struct_fields(
+                    struct_value,
+                    &converted,
+                    struct_info,
+                    serialization_data,
+                    self.context,
+                )?;
+                if self.context.config.sorted_keys {
+                    fields.sort_by_key(|(name, _)| *name);
+                }
+                let mut state = serializer.serialize_map(None)?;
+                state.serialize_entry(tag, variant_name)?;
+                for (name, field) in fields {
+                    if !(omit_none && is_none(field.value)) {
+                        state.serialize_entry(name, &field)?;
+                    }
+                }
+                state.end()
+            }
+            VariantInfo::Tuple(_) => Err(Error::custom(format_args!(
+                "cannot serialize tuple variant `{enum_name}::{variant_name}` with an internal tag",
+            ))),
+        }
+    }
+}
+
+impl<'a> Serialize for VariantContentSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.variant_info {
+            VariantInfo::Unit(_) => serializer.serialize_unit(),
+            VariantInfo::Struct(struct_info) => {
+                let fields = self.struct_variant_fields(struct_info);
+                let mut state =
+                    serializer.serialize_struct(self.variant_info.name(), fields.len())?;
+                for (name, field) in fields {
+                    state.serialize_field(name, &field)?;
+                }
+                state.end()
+            }
+            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                self.field(0).serialize(serializer)
+            }
+            VariantInfo::Tuple(tuple_info) => {
+                let mut state = serializer.serialize_tuple(tuple_info.field_len())?;
+                for index in 0..tuple_info.field_len() {
+                    state.serialize_element(&self.field(index))?;
+                }
+                state.end()
+            }
+        }
+    }
+}
+
+struct TupleSerializer<'a> {
+    tuple: &'a dyn Tuple,
+    context: &'a SerializerContext<'a>,
 }
 
 impl<'a> Serialize for TupleSerializer<'a> {
@@ -397,15 +1316,15 @@ impl<'a> Serialize for TupleSerializer<'a> {
         let mut state = serializer.serialize_tuple(self.tuple.field_len())?;
 
         for value in self.tuple.iter_fields() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&ValueSerializer::new(value, self.context))?;
         }
         state.end()
     }
 }
 
-pub struct MapSerializer<'a> {
-    pub map: &'a dyn Map,
-    pub registry: &'a TypeRegistry,
+struct MapSerializer<'a> {
+    map: &'a dyn Map,
+    context: &'a SerializerContext<'a>,
 }
 
 impl<'a> Serialize for MapSerializer<'a> {
@@ -413,20 +1332,58 @@ impl<'a> Serialize for MapSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let mut entries: Vec<_> = self.map.iter().collect();
+        if self.context.config.sorted_keys {
+            sort_map_entries(&mut entries, self.context).map_err(Error::custom)?;
+        }
+
         let mut state = serializer.serialize_map(Some(self.map.len()))?;
-        for (key, value) in self.map.iter() {
-            state.serialize_entry(
-                &TypedReflectSerializer::new(key, self.registry),
-                &TypedReflectSerializer::new(value, self.registry),
-            )?;
+        for (key, value) in entries {
+            let value = ValueSerializer::new(value, self.context);
+            match &self.context.config.map_key_encoder {
+                Some(encoder) => {
+                    let key = encoder
+                        .encode(key, self.context.registry)
+                        .map_err(Error::custom)?;
+                    state.serialize_entry(&key, &value)?;
+                }
+                None => state.serialize_entry(&ValueSerializer::new(key, self.context), &value)?,
+            }
         }
         state.end()
     }
 }
 
-pub struct ListSerializer<'a> {
-    pub list: &'a dyn List,
-    pub registry: &'a TypeRegistry,
+/// Sorts the entries of a map by their serialized key,
+/// which is the string given by the [`MapKeyEncoder`] if there is one.
+fn sort_map_entries(
+    entries: &mut Vec<(&dyn Reflect, &dyn Reflect)>,
+    context: &SerializerContext,
+) -> Result<(), String> {
+    let mut sorted = entries
+        .drain(..)
+        .map(|(key, value)| {
+            let sort_key = match &context.config.map_key_encoder {
+                Some(encoder) => Content::String(encoder.encode(key, context.registry)?),
+                None => {
+                    // Shared values within keys are numbered apart from the output,
+                    // which only sees each key once
+                    let key_context = SerializerContext::new(context.registry, context.config);
+                    Content::serialize(&ValueSerializer::new(key, &key_context))
+                        .map_err(|error| error.to_string())?
+                }
+            };
+            Ok((sort_key, key, value))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    sorted.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+    entries.extend(sorted.into_iter().map(|(_, key, value)| (key, value)));
+    Ok(())
+}
+
+struct ListSerializer<'a> {
+    list: &'a dyn List,
+    context: &'a SerializerContext<'a>,
 }
 
 impl<'a> Serialize for ListSerializer<'a> {
@@ -434,17 +1391,24 @@ impl<'a> Serialize for ListSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        if let Some(bytes) = self.list.as_any().downcast_ref::<Vec<u8>>() {
+            return serializer.serialize_bytes(bytes);
+        }
+        if let Some(bytes) = get_bytes(self.list.as_reflect(), self.list.iter(), self.context) {
+            return serializer.serialize_bytes(&bytes);
+        }
+
         let mut state = serializer.serialize_seq(Some(self.list.len()))?;
         for value in self.list.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&ValueSerializer::new(value, self.context))?;
         }
         state.end()
     }
 }
 
-pub struct ArraySerializer<'a> {
-    pub array: &'a dyn Array,
-    pub registry: &'a TypeRegistry,
+struct ArraySerializer<'a> {
+    array: &'a dyn Array,
+    context: &'a SerializerContext<'a>,
 }
 
 impl<'a> Serialize for ArraySerializer<'a> {
@@ -452,9 +1416,13 @@ impl<'a> Serialize for ArraySerializer<'a> {
     where
         S: serde::Serializer,
     {
+        if let Some(bytes) = get_bytes(self.array.as_reflect(), self.array.iter(), self.context) {
+            return serializer.serialize_bytes(&bytes);
+        }
+
         let mut state = serializer.serialize_tuple(self.array.len())?;
         for value in self.array.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&ValueSerializer::new(value, self.context))?;
         }
         state.end()
     }
@@ -466,10 +1434,10 @@ mod tests {
     use crate::serde::ReflectSerializer;
     use crate::{FromReflect, Reflect, ReflectSerialize, TypeRegistry};
     use bevy_utils::HashMap;
+    use core::f32::consts::PI;
     use ron::extensions::Extensions;
     use ron::ser::PrettyConfig;
     use serde::Serialize;
-    use std::f32::consts::PI;
 
     #[derive(Reflect, Debug, PartialEq)]
     struct MyStruct {
@@ -0,0 +1,94 @@
+use crate::serde::{
+    DeserializerConfig, SerializerConfig, StringKeyEncoder, TypedReflectDeserializer,
+    TypedReflectSerializer,
+};
+use crate::{Reflect, TypeRegistration, TypeRegistry};
+use serde::de::DeserializeSeed;
+use serde::ser::Error;
+
+/// Returns the options used by [`to_toml_string`] to serialize reflected values as TOML.
+///
+/// As TOML has no null value, fields holding `Option::None` are left out,
+/// and as its keys are always strings, map keys are written with the [`StringKeyEncoder`].
+pub fn toml_serializer_config() -> SerializerConfig {
+    SerializerConfig::default()
+        .with_omit_none(true)
+        .with_map_key_encoder(StringKeyEncoder)
+}
+
+/// Returns the options used by [`from_toml_str`] to read reflected values written
+/// with the [`toml_serializer_config`].
+pub fn toml_deserializer_config() -> DeserializerConfig {
+    DeserializerConfig::default()
+        .with_omit_none(true)
+        .with_map_key_encoder(StringKeyEncoder)
+}
+
+/// Serializes a reflected value as a TOML document, such as a settings file.
+///
+/// The value is written without its type name, with the [`toml_serializer_config`].
+/// Since a TOML document is a table, the value must be a struct, a map,
+/// or another value serialized as a map. The values of each table are written
+/// before its nested tables, as TOML requires, whatever the order of the fields.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{from_toml_str, to_toml_string};
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// struct Audio {
+///     volume: f32,
+/// }
+///
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// struct Settings {
+///     audio: Audio,
+///     player_name: Option<String>,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Settings>();
+/// registry.register::<Audio>();
+/// registry.register::<Option<String>>();
+/// registry.register::<f32>();
+///
+/// let settings = Settings {
+///     audio: Audio { volume: 0.5 },
+///     player_name: None,
+/// };
+/// let output = to_toml_string(&settings, &registry).unwrap();
+/// assert_eq!("[audio]\nvolume = 0.5\n", output);
+///
+/// let registration = registry.get(std::any::TypeId::of::<Settings>()).unwrap();
+/// let value = from_toml_str(registration, &registry, &output).unwrap();
+/// assert_eq!(settings, Settings::from_reflect(&*value).unwrap());
+/// ```
+pub fn to_toml_string(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<String, toml::ser::Error> {
+    let serializer =
+        TypedReflectSerializer::new(value, registry).with_config(toml_serializer_config());
+    // Going through `toml::Value` sorts out which entries of a table must be written first
+    let document = toml::Value::try_from(serializer)?;
+    if !document.is_table() {
+        return Err(toml::ser::Error::custom(format_args!(
+            "a TOML document must be a table, but `{}` is not serialized as one",
+            value.type_name()
+        )));
+    }
+    toml::to_string(&document)
+}
+
+/// Deserializes a TOML document written by [`to_toml_string`] as a value of the given type.
+///
+/// This returns the dynamic equivalent of the value, like the [`TypedReflectDeserializer`].
+pub fn from_toml_str(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    input: &str,
+) -> Result<Box<dyn Reflect>, toml::de::Error> {
+    TypedReflectDeserializer::new(registration, registry)
+        .with_config(toml_deserializer_config())
+        .deserialize(&mut toml::Deserializer::new(input))
+}
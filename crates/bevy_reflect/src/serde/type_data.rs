@@ -1,9 +1,38 @@
-use std::collections::HashSet;
+use crate::serde::ser::SerializeWith;
+use crate::serde::Serializable;
+use crate::{FromType, Reflect};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_utils::{HashMap, HashSet};
+
+/// A function serializing a field in place of its reflected representation.
+///
+/// This is what `#[reflect(serialize_with = "path")]` registers, wrapping a function with the
+/// same signature as those used by serde's `#[serde(serialize_with = "path")]`.
+pub type SerializeFieldFn = fn(
+    value: &dyn Reflect,
+    serializer: &mut dyn erased_serde::Serializer,
+) -> Result<
+    <&'static mut dyn erased_serde::Serializer as serde::Serializer>::Ok,
+    erased_serde::Error,
+>;
+
+/// A function deserializing a field in place of its reflected representation.
+///
+/// This is what `#[reflect(deserialize_with = "path")]` registers, wrapping a function with the
+/// same signature as those used by serde's `#[serde(deserialize_with = "path")]`.
+pub type DeserializeFieldFn = fn(
+    deserializer: &mut dyn erased_serde::Deserializer,
+) -> Result<Box<dyn Reflect>, erased_serde::Error>;
 
 /// Contains data relevant to the automatic reflect powered serialization of a type
 #[derive(Debug, Clone)]
 pub struct SerializationData {
     ignored_field_indices: HashSet<usize>,
+    ignored_field_names: HashSet<&'static str>,
+    serialize_fns: HashMap<usize, SerializeFieldFn>,
+    deserialize_fns: HashMap<usize, DeserializeFieldFn>,
+    named_serialize_fns: HashMap<&'static str, SerializeFieldFn>,
+    named_deserialize_fns: HashMap<&'static str, DeserializeFieldFn>,
 }
 
 impl SerializationData {
@@ -13,8 +42,84 @@ impl SerializationData {
     pub fn new<I: Iterator<Item = usize>>(ignored_iter: I) -> Self {
         Self {
             ignored_field_indices: ignored_iter.collect(),
+            ignored_field_names: HashSet::new(),
+            serialize_fns: HashMap::new(),
+            deserialize_fns: HashMap::new(),
+            named_serialize_fns: HashMap::new(),
+            named_deserialize_fns: HashMap::new(),
         }
     }
+
+    /// Adds the fields with the given names to the fields ignored in serialization.
+    ///
+    /// Unlike indices, names stay valid when the fields of a struct are reordered,
+    /// so `#[derive(Reflect)]` uses these for structs with named fields.
+    pub fn with_ignored_names<I: IntoIterator<Item = &'static str>>(mut self, names: I) -> Self {
+        self.ignored_field_names.extend(names);
+        self
+    }
+
+    /// Sets the function used to serialize the field of a tuple struct at the given index.
+    ///
+    /// The function is passed the field's value, which it may downcast to the field's type.
+    /// Fields of structs with named fields use [`with_named_serialize_fn`](Self::with_named_serialize_fn).
+    pub fn with_serialize_fn(mut self, index: usize, serialize_fn: SerializeFieldFn) -> Self {
+        self.serialize_fns.insert(index, serialize_fn);
+        self
+    }
+
+    /// Sets the function used to deserialize the field of a tuple struct at the given index.
+    ///
+    /// The function must return a value of the field's type.
+    /// Fields of structs with named fields use [`with_named_deserialize_fn`](Self::with_named_deserialize_fn).
+    pub fn with_deserialize_fn(mut self, index: usize, deserialize_fn: DeserializeFieldFn) -> Self {
+        self.deserialize_fns.insert(index, deserialize_fn);
+        self
+    }
+
+    /// Sets the function used to serialize the field with the given name.
+    ///
+    /// Like ignored names, this stays valid when the fields of a struct are reordered,
+    /// or when serializing a dynamic struct whose fields are in a different order.
+    pub fn with_named_serialize_fn(
+        mut self,
+        name: &'static str,
+        serialize_fn: SerializeFieldFn,
+    ) -> Self {
+        self.named_serialize_fns.insert(name, serialize_fn);
+        self
+    }
+
+    /// Sets the function used to deserialize the field with the given name.
+    pub fn with_named_deserialize_fn(
+        mut self,
+        name: &'static str,
+        deserialize_fn: DeserializeFieldFn,
+    ) -> Self {
+        self.named_deserialize_fns.insert(name, deserialize_fn);
+        self
+    }
+
+    /// Returns the function used to serialize the tuple struct field at the given index, if it has one.
+    pub fn serialize_fn(&self, index: usize) -> Option<SerializeFieldFn> {
+        self.serialize_fns.get(&index).copied()
+    }
+
+    /// Returns the function used to deserialize the tuple struct field at the given index, if it has one.
+    pub fn deserialize_fn(&self, index: usize) -> Option<DeserializeFieldFn> {
+        self.deserialize_fns.get(&index).copied()
+    }
+
+    /// Returns the function used to serialize the field with the given name, if it has one.
+    pub fn named_serialize_fn(&self, name: &str) -> Option<SerializeFieldFn> {
+        self.named_serialize_fns.get(name).copied()
+    }
+
+    /// Returns the function used to deserialize the field with the given name, if it has one.
+    pub fn named_deserialize_fn(&self, name: &str) -> Option<DeserializeFieldFn> {
+        self.named_deserialize_fns.get(name).copied()
+    }
+
     /// Returns true if the given index corresponds to a field meant to be ignored in serialization.
     ///
     /// Indices start from 0 and ignored fields are skipped.
@@ -32,13 +137,115 @@ impl SerializationData {
         self.ignored_field_indices.contains(&index)
     }
 
+    /// Returns true if the field with the given name is meant to be ignored in serialization.
+    ///
+    /// Named fields may be ignored either by name or by index,
+    /// so serializers should check both [`is_ignored_field`](Self::is_ignored_field) and this.
+    pub fn is_ignored_name(&self, name: &str) -> bool {
+        self.ignored_field_names.contains(name)
+    }
+
     /// Returns the number of ignored fields.
+    ///
+    /// This assumes no field is ignored both by index and by name.
     pub fn len(&self) -> usize {
-        self.ignored_field_indices.len()
+        self.ignored_field_indices.len() + self.ignored_field_names.len()
     }
 
     /// Returns true if there are no ignored fields.
     pub fn is_empty(&self) -> bool {
-        self.ignored_field_indices.is_empty()
+        self.ignored_field_indices.is_empty() && self.ignored_field_names.is_empty()
+    }
+}
+
+/// Type data for serializing a type as a single byte string,
+/// using [`serialize_bytes`] and [`deserialize_byte_buf`].
+///
+/// Lists and arrays of `u8`, such as `Vec<u8>` and `[u8; N]`, are always serialized this way.
+/// Registering this for a newtype around bytes (e.g. with `#[reflect(SerializeBytes)]`)
+/// lets it be serialized the same way, instead of as a struct containing a list of numbers.
+///
+/// A `ReflectSerializeBytes` for type `T` can be obtained via [`FromType::from_type`]
+/// if `T` can be viewed as a byte slice and created from a `Vec<u8>`.
+///
+/// [`serialize_bytes`]: serde::Serializer::serialize_bytes
+/// [`deserialize_byte_buf`]: serde::Deserializer::deserialize_byte_buf
+#[derive(Clone)]
+pub struct ReflectSerializeBytes {
+    as_bytes: for<'a> fn(&'a dyn Reflect) -> Option<&'a [u8]>,
+    from_bytes: fn(Vec<u8>) -> Box<dyn Reflect>,
+}
+
+impl ReflectSerializeBytes {
+    /// Returns the bytes of the given value,
+    /// or `None` if it is not of the type this was created for.
+    pub fn as_bytes<'a>(&self, value: &'a dyn Reflect) -> Option<&'a [u8]> {
+        (self.as_bytes)(value)
+    }
+
+    /// Creates a value of the type this was created for from the given bytes.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_bytes(&self, bytes: Vec<u8>) -> Box<dyn Reflect> {
+        (self.from_bytes)(bytes)
+    }
+}
+
+impl<T: Reflect + AsRef<[u8]> + From<Vec<u8>>> FromType<T> for ReflectSerializeBytes {
+    fn from_type() -> Self {
+        Self {
+            as_bytes: |value| value.downcast_ref::<T>().map(AsRef::as_ref),
+            from_bytes: |bytes| Box::new(T::from(bytes)),
+        }
+    }
+}
+
+/// Type data serializing a type with a function, for types which do not implement
+/// serde's `Serialize` and cannot be serialized through their fields, such as opaque foreign types.
+///
+/// The reflect serializers use this when a type has no [`ReflectSerialize`].
+/// The function is passed values of the type it is registered for, which it may downcast.
+/// To deserialize the type, register a matching [`ReflectDeserialize`].
+///
+/// # Example
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{ReflectSerializeWith, ReflectSerializer};
+/// # use serde::Serializer;
+/// #[derive(Reflect, Clone)]
+/// #[reflect_value]
+/// struct Opaque(u32);
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Opaque>();
+/// registry
+///     .get_mut(TypeId::of::<Opaque>())
+///     .unwrap()
+///     .insert(ReflectSerializeWith {
+///         func: |value, serializer| {
+///             let value = value.downcast_ref::<Opaque>().unwrap();
+///             serializer.serialize_u32(value.0)
+///         },
+///     });
+///
+/// let serializer = ReflectSerializer::new(&Opaque(7), &registry);
+/// let output = ron::to_string(&serializer).unwrap();
+/// assert!(output.ends_with(":7}"));
+/// ```
+///
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+#[derive(Clone)]
+pub struct ReflectSerializeWith {
+    pub func: SerializeFieldFn,
+}
+
+impl ReflectSerializeWith {
+    /// Turn the value into a serializable representation
+    pub fn get_serializable<'a>(&self, value: &'a dyn Reflect) -> Serializable<'a> {
+        Serializable::Owned(Box::new(SerializeWith {
+            value,
+            serialize_fn: self.func,
+        }))
     }
 }
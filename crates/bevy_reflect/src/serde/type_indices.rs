@@ -0,0 +1,82 @@
+use crate::TypeRegistry;
+use alloc::vec::Vec;
+use bevy_utils::HashMap;
+
+/// A frozen numbering of the types in a [`TypeRegistry`],
+/// used to write small integers instead of type names.
+///
+/// Types are numbered in the order of their names, so two registries containing the same
+/// types produce the same indices, regardless of the order the types were registered in.
+/// This makes it possible for the two ends of a network connection to agree on the indices
+/// by comparing [checksums](TypeIndices::checksum), without sending the indices themselves.
+///
+/// Types registered after the indices were created are not part of them.
+///
+/// Set these on both sides with [`SerializerConfig::with_type_indices`]
+/// and [`DeserializerConfig::with_type_indices`].
+///
+/// [`SerializerConfig::with_type_indices`]: crate::serde::SerializerConfig::with_type_indices
+/// [`DeserializerConfig::with_type_indices`]: crate::serde::DeserializerConfig::with_type_indices
+#[derive(Clone, Debug)]
+pub struct TypeIndices {
+    type_names: Vec<&'static str>,
+    indices: HashMap<&'static str, u32>,
+}
+
+impl TypeIndices {
+    /// Numbers the types currently in the given registry.
+    pub fn new(registry: &TypeRegistry) -> Self {
+        let mut type_names: Vec<_> = registry
+            .iter()
+            .map(|registration| registration.type_name())
+            .collect();
+        type_names.sort_unstable();
+        let indices = type_names
+            .iter()
+            .enumerate()
+            .map(|(index, type_name)| (*type_name, index as u32))
+            .collect();
+        Self {
+            type_names,
+            indices,
+        }
+    }
+
+    /// Returns the index of the type with the given name, if it is part of these indices.
+    pub fn index_of(&self, type_name: &str) -> Option<u32> {
+        self.indices.get(type_name).copied()
+    }
+
+    /// Returns the name of the type with the given index, if there is one.
+    pub fn type_name(&self, index: u32) -> Option<&'static str> {
+        self.type_names.get(index as usize).copied()
+    }
+
+    /// Returns the number of indexed types.
+    pub fn len(&self) -> usize {
+        self.type_names.len()
+    }
+
+    /// Returns `true` if no types are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.type_names.is_empty()
+    }
+
+    /// Returns a checksum of the indexed type names, which is equal between
+    /// two `TypeIndices` if and only if (barring collisions) they assign the same indices.
+    ///
+    /// This is computed with the 64-bit FNV-1a hash, so it is stable
+    /// across platforms, compiler versions and runs.
+    pub fn checksum(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        self.type_names
+            .iter()
+            // Separate the names so that e.g. `ab`, `c` differs from `a`, `bc`
+            .flat_map(|type_name| type_name.bytes().chain([0]))
+            .fold(OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+            })
+    }
+}
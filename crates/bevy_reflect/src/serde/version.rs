@@ -0,0 +1,126 @@
+use crate::DynamicStruct;
+use alloc::{sync::Arc, vec::Vec};
+use bevy_utils::HashMap;
+use core::fmt::{self, Debug, Formatter};
+use thiserror::Error;
+
+type Migration = Arc<dyn Fn(DynamicStruct) -> DynamicStruct + Send + Sync>;
+
+/// Type data giving a struct a version number, along with the migrations to upgrade
+/// data written by older versions of the struct.
+///
+/// The reflect serializers write the version of structs which have this type data in a
+/// `__version` entry before their fields. When the deserializers read data of an older version,
+/// they apply the migrations from that version up to the current one, in order.
+/// Data without a version entry is treated as version 0, so that this can be added to
+/// a struct which was previously serialized without it.
+///
+/// Fields which are not part of the current version of the struct are kept in the
+/// [`DynamicStruct`] passed to the first migration, with the untyped values described by
+/// [`UnknownFieldPolicy::Collect`], so that removed or renamed fields can be converted.
+/// Fields which are part of the current version are deserialized with their current type.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{DynamicStruct, GetField, Reflect, Struct, TypeRegistry};
+/// # use bevy_reflect::serde::ReflectVersion;
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     health: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// // Version 0 stored the health as a whole number of `hp`
+/// let version = ReflectVersion::new(1).with_migration(0, |old| {
+///     let mut new = DynamicStruct::default();
+///     new.insert_boxed("name", old.field("name").unwrap().clone_value());
+///     new.insert("health", *old.get_field::<u64>("hp").unwrap() as f32);
+///     new
+/// });
+/// registry
+///     .get_mut(std::any::TypeId::of::<Player>())
+///     .unwrap()
+///     .insert(version);
+/// ```
+///
+/// [`UnknownFieldPolicy::Collect`]: crate::serde::UnknownFieldPolicy::Collect
+#[derive(Clone)]
+pub struct ReflectVersion {
+    version: u32,
+    migrations: HashMap<u32, Migration>,
+}
+
+impl ReflectVersion {
+    /// Creates type data with the given current version and no migrations.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: HashMap::default(),
+        }
+    }
+
+    /// Adds the migration from version `from` to version `from + 1`.
+    pub fn with_migration(
+        mut self,
+        from: u32,
+        migration: impl Fn(DynamicStruct) -> DynamicStruct + Send + Sync + 'static,
+    ) -> Self {
+        self.migrations.insert(from, Arc::new(migration));
+        self
+    }
+
+    /// Returns the current version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Applies the migrations upgrading the given value from version `from` to the current version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `from` is newer than the current version,
+    /// or if a migration is missing on the way to it.
+    pub fn migrate(
+        &self,
+        value: DynamicStruct,
+        from: u32,
+    ) -> Result<DynamicStruct, MigrationError> {
+        if from > self.version {
+            return Err(MigrationError::Newer {
+                version: from,
+                current: self.version,
+            });
+        }
+        (from..self.version).try_fold(value, |value, version| {
+            let migration = self
+                .migrations
+                .get(&version)
+                .ok_or(MigrationError::Missing { from: version })?;
+            Ok(migration(value))
+        })
+    }
+}
+
+impl Debug for ReflectVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut migrations: Vec<_> = self.migrations.keys().collect();
+        migrations.sort_unstable();
+        f.debug_struct("ReflectVersion")
+            .field("version", &self.version)
+            .field("migrations", &migrations)
+            .finish()
+    }
+}
+
+/// An error returned by [`ReflectVersion::migrate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum MigrationError {
+    /// The data was written by a newer version of the type than the current one.
+    #[error("version {version} is newer than the current version {current}")]
+    Newer { version: u32, current: u32 },
+    /// There is no migration from the given version to the next one.
+    #[error("no migration from version {from}")]
+    Missing { from: u32 },
+}
@@ -0,0 +1,112 @@
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{Reflect, TypeRegistry};
+use core::fmt::{self, Formatter};
+use serde::de::{EnumAccess, Error as _, VariantAccess, Visitor};
+use serde::ser::{Error as _, Serialize, Serializer};
+use serde::Deserializer;
+
+/// Serializes a reflected value as a YAML document, tagged with the name of its type.
+///
+/// Where the [`ReflectSerializer`] writes a map from the type name of the value to its content,
+/// this writes the content with a YAML tag instead, such as `!my_game::Player`,
+/// which [`from_yaml_str`] uses to find the type of the value.
+/// Enum variants are tagged the same way, such as `!Circle` for `Shape::Circle { radius: 1.0 }`.
+///
+/// As type names are written as tags, types whose names contain spaces,
+/// such as tuples, cannot be written this way.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{from_yaml_str, to_yaml_string};
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// struct Window {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Window>();
+/// registry.register::<u32>();
+///
+/// let window = Window { width: 1280, height: 720 };
+/// let output = to_yaml_string(&window, &registry).unwrap();
+/// assert!(output.starts_with(&format!("!{}", std::any::type_name::<Window>())));
+///
+/// let value = from_yaml_str(&registry, &output).unwrap();
+/// assert_eq!(window, Window::from_reflect(&*value).unwrap());
+/// ```
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub fn to_yaml_string(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<String, serde_yaml::Error> {
+    serde_yaml::to_string(&TaggedSerializer { value, registry })
+}
+
+/// Deserializes a YAML document written by [`to_yaml_string`],
+/// using its tag to find the type of the value.
+///
+/// This returns the dynamic equivalent of the value, like the [`UntypedReflectDeserializer`].
+///
+/// [`UntypedReflectDeserializer`]: crate::serde::UntypedReflectDeserializer
+pub fn from_yaml_str(
+    registry: &TypeRegistry,
+    input: &str,
+) -> Result<Box<dyn Reflect>, serde_yaml::Error> {
+    serde_yaml::Deserializer::from_str(input).deserialize_enum("", &[], TaggedVisitor { registry })
+}
+
+/// Serializes a value as a newtype variant named after its type,
+/// which `serde_yaml` writes as a tagged value.
+struct TaggedSerializer<'a> {
+    value: &'a dyn Reflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for TaggedSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registration = self
+            .registry
+            .get_with_name(self.value.type_name())
+            .ok_or_else(|| {
+                S::Error::custom(format_args!(
+                    "no registration found for type `{}`",
+                    self.value.type_name()
+                ))
+            })?;
+        serializer.serialize_newtype_variant(
+            "",
+            0,
+            registration.type_name(),
+            &TypedReflectSerializer::new(self.value, self.registry),
+        )
+    }
+}
+
+struct TaggedVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TaggedVisitor<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a value tagged with the name of its type")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (type_name, value) = data.variant::<String>()?;
+        let registration = self.registry.get_with_name(&type_name).ok_or_else(|| {
+            A::Error::custom(format_args!("no registration found for type `{type_name}`"))
+        })?;
+        value.newtype_variant_seed(TypedReflectDeserializer::new(registration, self.registry))
+    }
+}
@@ -0,0 +1,459 @@
+use crate::std_traits::ReflectDefault;
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Enum, Map, Reflect, ReflectRef, TypeInfo, TypeRegistry,
+    VariantInfo, VariantType,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::any::TypeId;
+
+/// Proposes smaller versions of reflected values, to find minimal failing inputs
+/// for property tests, such as those using values made by a [`ReflectGenerator`].
+///
+/// Each candidate returned by [`shrink`](Self::shrink) differs from the value in one place,
+/// by one of these steps, from the simplest to the smallest change:
+/// * replacing the value with the default of its type, if it has [`ReflectDefault`] type data,
+/// * switching an enum to an earlier variant, whose fields take their default values,
+/// * removing elements from lists and entries from maps,
+/// * moving numbers towards zero and shortening strings,
+/// * shrinking any field, element or map value in one of these ways.
+///
+/// Candidates are returned as dynamic values, which [`FromReflect`] can convert
+/// back to the concrete type.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, ReflectShrinker, TypeRegistry};
+/// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+/// struct Order {
+///     items: Vec<u32>,
+/// }
+///
+/// // Orders with an item above 10 fail the property
+/// let fails = |value: &dyn Reflect| {
+///     let order = Order::from_reflect(value).unwrap();
+///     order.items.iter().any(|item| *item > 10)
+/// };
+///
+/// let order = Order { items: vec![3, 97, 12, 5] };
+/// let minimal = ReflectShrinker::new(&TypeRegistry::default()).minimize(&order, fails);
+/// assert_eq!(Order { items: vec![12] }, Order::from_reflect(&*minimal).unwrap());
+/// ```
+///
+/// [`ReflectGenerator`]: crate::ReflectGenerator
+/// [`FromReflect`]: crate::FromReflect
+pub struct ReflectShrinker<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectShrinker<'a> {
+    /// Creates a shrinker which looks up default values and enum variants in the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+
+    /// Returns the candidates one step smaller than the given value, simplest first.
+    pub fn shrink(&self, value: &dyn Reflect) -> Vec<Box<dyn Reflect>> {
+        let mut candidates = Vec::new();
+        if let ReflectRef::Value(value) = value.reflect_ref() {
+            shrink_primitive(value, &mut candidates);
+            return candidates;
+        }
+
+        if let Some(default) = self.default_of(value.type_name()) {
+            if default.reflect_partial_eq(value) == Some(false) {
+                candidates.push(default);
+            }
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                let fields = value.iter_fields().collect::<Vec<_>>();
+                for fields in self.shrink_each(&fields) {
+                    let mut candidate = DynamicStruct::default();
+                    candidate.set_name(value.type_name().to_string());
+                    for (index, field) in fields.into_iter().enumerate() {
+                        candidate.insert_boxed(value.name_at(index).unwrap(), field);
+                    }
+                    candidates.push(Box::new(candidate));
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                let fields = value.iter_fields().collect::<Vec<_>>();
+                for fields in self.shrink_each(&fields) {
+                    let mut candidate = DynamicTupleStruct::default();
+                    candidate.set_name(value.type_name().to_string());
+                    fields
+                        .into_iter()
+                        .for_each(|field| candidate.insert_boxed(field));
+                    candidates.push(Box::new(candidate));
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                let fields = value.iter_fields().collect::<Vec<_>>();
+                for fields in self.shrink_each(&fields) {
+                    let mut candidate = DynamicTuple::default();
+                    candidate.set_name(value.type_name().to_string());
+                    fields
+                        .into_iter()
+                        .for_each(|field| candidate.insert_boxed(field));
+                    candidates.push(Box::new(candidate));
+                }
+            }
+            ReflectRef::List(value) => {
+                let items = value.iter().collect::<Vec<_>>();
+                let shorter_lists = shorter(&items).map(|items| {
+                    items
+                        .into_iter()
+                        .map(Reflect::clone_value)
+                        .collect::<Vec<_>>()
+                });
+                for items in shorter_lists.chain(self.shrink_each(&items)) {
+                    let mut candidate = items.into_iter().collect::<DynamicList>();
+                    candidate.set_name(value.type_name().to_string());
+                    candidates.push(Box::new(candidate));
+                }
+            }
+            ReflectRef::Array(value) => {
+                let items = value.iter().collect::<Vec<_>>();
+                for items in self.shrink_each(&items) {
+                    let mut candidate = DynamicArray::new(items.into_boxed_slice());
+                    candidate.set_name(value.type_name().to_string());
+                    candidates.push(Box::new(candidate));
+                }
+            }
+            ReflectRef::Map(value) => {
+                let keys = value.iter().map(|(key, _)| key).collect::<Vec<_>>();
+                let values = value.iter().map(|(_, value)| value).collect::<Vec<_>>();
+                let entries = |values: Vec<Box<dyn Reflect>>, kept: &[bool]| {
+                    let mut candidate = DynamicMap::default();
+                    candidate.set_name(value.type_name().to_string());
+                    for ((key, value), _) in
+                        keys.iter().zip(values).zip(kept).filter(|(_, kept)| **kept)
+                    {
+                        candidate.insert_boxed(key.clone_value(), value);
+                    }
+                    Box::new(candidate)
+                };
+
+                let all = vec![true; keys.len()];
+                let indices = (0..keys.len()).collect::<Vec<_>>();
+                for kept_indices in shorter(&indices) {
+                    let kept = (0..keys.len())
+                        .map(|index| kept_indices.contains(&index))
+                        .collect::<Vec<_>>();
+                    let values = values.iter().map(|value| value.clone_value()).collect();
+                    candidates.push(entries(values, &kept));
+                }
+                for values in self.shrink_each(&values) {
+                    candidates.push(entries(values, &all));
+                }
+            }
+            ReflectRef::Enum(value) => {
+                candidates.extend(self.earlier_variants(value));
+
+                let fields = value
+                    .iter_fields()
+                    .map(|field| field.value())
+                    .collect::<Vec<_>>();
+                for fields in self.shrink_each(&fields) {
+                    let variant = match value.variant_type() {
+                        VariantType::Struct => {
+                            let mut variant = DynamicStruct::default();
+                            for (field, value) in value.iter_fields().zip(fields) {
+                                variant.insert_boxed(field.name().unwrap(), value);
+                            }
+                            DynamicVariant::Struct(variant)
+                        }
+                        VariantType::Tuple => {
+                            let mut variant = DynamicTuple::default();
+                            fields
+                                .into_iter()
+                                .for_each(|field| variant.insert_boxed(field));
+                            DynamicVariant::Tuple(variant)
+                        }
+                        VariantType::Unit => DynamicVariant::Unit,
+                    };
+                    candidates.push(Box::new(DynamicEnum::new_with_index(
+                        value.type_name(),
+                        value.variant_index(),
+                        value.variant_name(),
+                        variant,
+                    )));
+                }
+            }
+            ReflectRef::Value(_) => unreachable!(),
+        }
+        candidates
+    }
+
+    /// Repeatedly replaces the value with the first of its [candidates](Self::shrink)
+    /// for which `fails` still returns `true`, until none do, and returns the result.
+    ///
+    /// `fails` should return `true` for the given value.
+    pub fn minimize(
+        &self,
+        value: &dyn Reflect,
+        mut fails: impl FnMut(&dyn Reflect) -> bool,
+    ) -> Box<dyn Reflect> {
+        let mut value = value.clone_value();
+        while let Some(smaller) = self
+            .shrink(&*value)
+            .into_iter()
+            .find(|candidate| fails(&**candidate))
+        {
+            value = smaller;
+        }
+        value
+    }
+
+    /// Returns the default value of the given type, if it is registered with [`ReflectDefault`].
+    fn default_of(&self, type_name: &str) -> Option<Box<dyn Reflect>> {
+        let registration = self.registry.get_with_name(type_name)?;
+        Some(registration.data::<ReflectDefault>()?.default())
+    }
+
+    /// Returns the default value of the type with the given [`TypeId`], if it has one.
+    fn default_of_type(&self, type_id: TypeId) -> Option<Box<dyn Reflect>> {
+        let registration = self.registry.get(type_id)?;
+        Some(registration.data::<ReflectDefault>()?.default())
+    }
+
+    /// Returns the variants of the enum before its current one, whose fields can all be defaulted.
+    fn earlier_variants(&self, value: &dyn Enum) -> Vec<Box<dyn Reflect>> {
+        let Some(registration) = self.registry.get_with_name(value.type_name()) else {
+            return Vec::new();
+        };
+        let TypeInfo::Enum(info) = registration.type_info() else {
+            return Vec::new();
+        };
+
+        let mut candidates = Vec::new();
+        for index in 0..value.variant_index().min(info.variant_len()) {
+            let variant = match info.variant_at(index).unwrap() {
+                VariantInfo::Struct(variant) => {
+                    let fields = variant
+                        .iter()
+                        .map(|field| Some((field.name(), self.default_of_type(field.type_id())?)));
+                    let Some(fields) = fields.collect::<Option<Vec<_>>>() else {
+                        continue;
+                    };
+                    let mut dynamic = DynamicStruct::default();
+                    for (name, field) in fields {
+                        dynamic.insert_boxed(name, field);
+                    }
+                    DynamicVariant::Struct(dynamic)
+                }
+                VariantInfo::Tuple(variant) => {
+                    let fields = variant
+                        .iter()
+                        .map(|field| self.default_of_type(field.type_id()));
+                    let Some(fields) = fields.collect::<Option<Vec<_>>>() else {
+                        continue;
+                    };
+                    let mut dynamic = DynamicTuple::default();
+                    fields
+                        .into_iter()
+                        .for_each(|field| dynamic.insert_boxed(field));
+                    DynamicVariant::Tuple(dynamic)
+                }
+                VariantInfo::Unit(_) => DynamicVariant::Unit,
+            };
+            candidates.push(Box::new(DynamicEnum::new_with_index(
+                value.type_name(),
+                index,
+                info.variant_names()[index],
+                variant,
+            )) as Box<dyn Reflect>);
+        }
+        candidates
+    }
+
+    /// Returns copies of the given values, each with one of them replaced by one of its candidates.
+    fn shrink_each(&self, values: &[&dyn Reflect]) -> Vec<Vec<Box<dyn Reflect>>> {
+        let mut shrunk = Vec::new();
+        for (index, value) in values.iter().enumerate() {
+            for candidate in self.shrink(*value) {
+                let mut candidate = Some(candidate);
+                shrunk.push(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(other, value)| {
+                            if other == index {
+                                candidate.take().unwrap()
+                            } else {
+                                value.clone_value()
+                            }
+                        })
+                        .collect(),
+                );
+            }
+        }
+        shrunk
+    }
+}
+
+/// Returns the shorter sequences made by removing elements: no elements,
+/// then either half, then all but one element.
+fn shorter<T: Copy>(items: &[T]) -> impl Iterator<Item = Vec<T>> + '_ {
+    let half = items.len() / 2;
+    let halves = if items.len() > 2 {
+        vec![items[..half].to_vec(), items[half..].to_vec()]
+    } else {
+        Vec::new()
+    };
+    let empty = (!items.is_empty()).then(Vec::new);
+    let without_one = (0..items.len()).filter(|_| items.len() > 1).map(|index| {
+        let mut items = items.to_vec();
+        items.remove(index);
+        items
+    });
+    empty.into_iter().chain(halves).chain(without_one)
+}
+
+/// Shrinks integers and floats towards zero, strings towards the empty string,
+/// and `true` to `false`.
+fn shrink_primitive(value: &dyn Reflect, candidates: &mut Vec<Box<dyn Reflect>>) {
+    macro_rules! shrink_integers {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    if *value != 0 {
+                        candidates.push(Box::new(0 as $ty));
+                    }
+                    if *value / 2 != 0 {
+                        candidates.push(Box::new(*value / 2));
+                    }
+                    return;
+                }
+            )*
+        };
+    }
+    macro_rules! shrink_floats {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    if *value != 0.0 {
+                        candidates.push(Box::new(0.0 as $ty));
+                    }
+                    // Casting truncates, and floats out of the range of `i64` are already whole
+                    let bound = i64::MAX as $ty;
+                    if (-bound..bound).contains(value) {
+                        let truncated = *value as i64 as $ty;
+                        if truncated != *value && truncated != 0.0 {
+                            candidates.push(Box::new(truncated));
+                        }
+                    }
+                    return;
+                }
+            )*
+        };
+    }
+
+    shrink_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    shrink_floats!(f32, f64);
+    if let Some(true) = value.downcast_ref::<bool>() {
+        candidates.push(Box::new(false));
+    } else if let Some(value) = value.downcast_ref::<String>() {
+        let chars = value.chars().collect::<Vec<_>>();
+        candidates.extend(
+            shorter(&chars).map(|chars| Box::new(chars.into_iter().collect::<String>()) as _),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReflectShrinker;
+    use crate::std_traits::ReflectDefault;
+    use crate::{self as bevy_reflect, FromReflect, Reflect, TypeRegistry};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq, Default)]
+    #[reflect(Default)]
+    enum Command {
+        #[default]
+        Wait,
+        Say(String),
+        Move {
+            x: i32,
+            y: i32,
+        },
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Script {
+        commands: Vec<Command>,
+        labels: HashMap<String, u8>,
+        speed: f32,
+    }
+
+    #[test]
+    fn should_shrink_to_minimal_counterexample() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Command>();
+        registry.register::<String>();
+        registry.register::<i32>();
+
+        // Scripts fail if they move further than 100 along x, or have a label "bug"
+        let fails = |value: &dyn Reflect| {
+            let script = Script::from_reflect(value).unwrap();
+            let moved = script
+                .commands
+                .iter()
+                .map(|command| match command {
+                    Command::Move { x, .. } => *x,
+                    _ => 0,
+                })
+                .sum::<i32>();
+            moved > 100 || script.labels.contains_key("bug")
+        };
+
+        let mut labels = HashMap::default();
+        labels.insert(String::from("start"), 3);
+        let script = Script {
+            commands: vec![
+                Command::Say(String::from("hello")),
+                Command::Move { x: 70, y: -5 },
+                Command::Wait,
+                Command::Move { x: 90, y: 12 },
+            ],
+            labels,
+            speed: 2.5,
+        };
+
+        let minimal = ReflectShrinker::new(&registry).minimize(&script, fails);
+        let minimal = Script::from_reflect(&*minimal).unwrap();
+        assert_eq!(
+            Script {
+                commands: vec![Command::Move { x: 17, y: 0 }, Command::Move { x: 90, y: 0 }],
+                labels: HashMap::default(),
+                speed: 0.0,
+            },
+            minimal
+        );
+
+        let command = Command::Move { x: 4, y: 0 };
+        let candidates = ReflectShrinker::new(&registry).shrink(&command);
+        let candidates = candidates
+            .iter()
+            .map(|candidate| Command::from_reflect(&**candidate).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Command::Wait,
+                Command::Wait,
+                Command::Say(String::new()),
+                Command::Move { x: 0, y: 0 },
+                Command::Move { x: 2, y: 0 },
+            ],
+            candidates
+        );
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{FromType, Reflect};
+use crate::{DynamicStruct, DynamicTupleStruct, FromType, Reflect, TypeInfo, TypeRegistry};
+use alloc::{boxed::Box, string::String};
+use core::any::TypeId;
+use core::ops::{Add, Mul, Neg};
+use thiserror::Error;
 
 /// A struct used to provide the default value of a type.
 ///
@@ -21,3 +25,145 @@ impl<T: Reflect + Default> FromType<T> for ReflectDefault {
         }
     }
 }
+
+/// An error returned when a default value cannot be built for a type.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DefaultValueError {
+    /// The type is not registered in the [`TypeRegistry`].
+    #[error("the type `{0}` is not registered")]
+    NotRegistered(&'static str),
+    /// The type has no [`ReflectDefault`], and is not a struct or tuple struct
+    /// whose fields could be defaulted instead.
+    #[error("the type `{0}` has no registered default")]
+    NoDefault(&'static str),
+    /// The enum has no variant with the requested name.
+    #[error("the enum `{type_name}` has no variant named `{variant}`")]
+    UnknownVariant {
+        type_name: &'static str,
+        variant: String,
+    },
+}
+
+/// Returns the default value of the registered type with the given [`TypeId`] and name.
+///
+/// The type's [`ReflectDefault`] is used if it has one.
+/// Otherwise, structs and tuple structs are built with [`DynamicStruct::from_type_info`]
+/// and [`DynamicTupleStruct::from_type_info`].
+pub(crate) fn default_value(
+    type_id: TypeId,
+    type_name: &'static str,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, DefaultValueError> {
+    let registration = registry
+        .get(type_id)
+        .ok_or(DefaultValueError::NotRegistered(type_name))?;
+    if let Some(default) = registration.data::<ReflectDefault>() {
+        return Ok(default.default());
+    }
+    match registration.type_info() {
+        TypeInfo::Struct(info) => Ok(Box::new(DynamicStruct::from_type_info(info, registry)?)),
+        TypeInfo::TupleStruct(info) => Ok(Box::new(DynamicTupleStruct::from_type_info(
+            info, registry,
+        )?)),
+        info => Err(DefaultValueError::NoDefault(info.type_name())),
+    }
+}
+
+/// An operation combining two reflected values of the same type into a new one.
+type BinaryOp = fn(&dyn Reflect, &dyn Reflect) -> Option<Box<dyn Reflect>>;
+
+/// A struct used to add two values of a type together with [`Add`].
+///
+/// This allows values to be combined without knowing their type,
+/// as is done when blending animations.
+///
+/// A [`ReflectAdd`] for type `T` can be obtained via [`FromType::from_type`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{std_traits::ReflectAdd, Reflect, TypeRegistry};
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<f32>();
+///
+/// let (a, b): (Box<dyn Reflect>, Box<dyn Reflect>) = (Box::new(1.5_f32), Box::new(2.0_f32));
+/// let add = registry.get_type_data::<ReflectAdd>(a.type_id()).unwrap();
+/// let sum = add.add(&*a, &*b).unwrap();
+/// assert_eq!(Some(&3.5), sum.downcast_ref::<f32>());
+/// ```
+#[derive(Clone)]
+pub struct ReflectAdd {
+    add: BinaryOp,
+}
+
+impl ReflectAdd {
+    /// Adds `b` to `a`.
+    ///
+    /// Returns `None` if either value is not of the type this was created for.
+    pub fn add(&self, a: &dyn Reflect, b: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.add)(a, b)
+    }
+}
+
+impl<T: Reflect + Clone + Add<Output = T>> FromType<T> for ReflectAdd {
+    fn from_type() -> Self {
+        ReflectAdd {
+            add: |a, b| {
+                let (a, b) = (a.downcast_ref::<T>()?, b.downcast_ref::<T>()?);
+                Some(Box::new(a.clone() + b.clone()))
+            },
+        }
+    }
+}
+
+/// A struct used to multiply two values of a type together with [`Mul`].
+///
+/// A [`ReflectMul`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectMul {
+    mul: BinaryOp,
+}
+
+impl ReflectMul {
+    /// Multiplies `a` by `b`.
+    ///
+    /// Returns `None` if either value is not of the type this was created for.
+    pub fn mul(&self, a: &dyn Reflect, b: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.mul)(a, b)
+    }
+}
+
+impl<T: Reflect + Clone + Mul<Output = T>> FromType<T> for ReflectMul {
+    fn from_type() -> Self {
+        ReflectMul {
+            mul: |a, b| {
+                let (a, b) = (a.downcast_ref::<T>()?, b.downcast_ref::<T>()?);
+                Some(Box::new(a.clone() * b.clone()))
+            },
+        }
+    }
+}
+
+/// A struct used to negate a value of a type with [`Neg`].
+///
+/// A [`ReflectNeg`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectNeg {
+    neg: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectNeg {
+    /// Negates `value`.
+    ///
+    /// Returns `None` if the value is not of the type this was created for.
+    pub fn neg(&self, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.neg)(value)
+    }
+}
+
+impl<T: Reflect + Clone + Neg<Output = T>> FromType<T> for ReflectNeg {
+    fn from_type() -> Self {
+        ReflectNeg {
+            neg: |value| Some(Box::new(-value.downcast_ref::<T>()?.clone())),
+        }
+    }
+}
@@ -1,12 +1,25 @@
+use crate::cycle::{debug_guarded, expect_guarded};
+use crate::debug_options::{debug_type_path, show_ignored_fields};
+use crate::inline_value::FieldValue;
+use crate::reflect::iter_partial_cmp;
+use crate::std_traits::{default_value, DefaultValueError};
+use crate::type_info::{fields_match_names, NameIndex, TypeCapabilities, TypeIdentity};
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    DynamicInfo, NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed,
+    DynamicInfo, NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypeRegistry,
+    Typed,
+};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
 };
 use bevy_utils::{Entry, HashMap};
-use std::fmt::{Debug, Formatter};
-use std::{
+use core::fmt::{Debug, Formatter};
+use core::{
     any::{Any, TypeId},
-    borrow::Cow,
+    cmp::Ordering,
     slice::Iter,
 };
 
@@ -64,6 +77,24 @@ pub trait Struct: Reflect {
     /// Returns an iterator over the values of the reflectable fields for this struct.
     fn iter_fields(&self) -> FieldIter;
 
+    /// Removes the field named `name` from the struct, returning its owned value.
+    ///
+    /// Only dynamic structs, such as [`DynamicStruct`], can have their fields removed,
+    /// so this returns `None` for any other struct, as well as when there is no such field.
+    fn take_field(&mut self, name: &str) -> Option<Box<dyn Reflect>> {
+        let _ = name;
+        None
+    }
+
+    /// Returns the values of the fields reflected through an owned conversion, paired with their names.
+    ///
+    /// Such fields, like those marked with `#[reflect(convert = "...")]`, are converted each time
+    /// this is called, so they cannot be borrowed with [`field`](Struct::field) and are not counted
+    /// by [`field_len`](Struct::field_len). Most structs have none.
+    fn converted_fields(&self) -> Vec<(&str, Box<dyn Reflect>)> {
+        Vec::new()
+    }
+
     /// Clones the struct into a [`DynamicStruct`].
     fn clone_dynamic(&self) -> DynamicStruct;
 }
@@ -72,11 +103,12 @@ pub trait Struct: Reflect {
 #[derive(Clone, Debug)]
 pub struct StructInfo {
     name: &'static str,
-    type_name: &'static str,
-    type_id: TypeId,
-    fields: Box<[NamedField]>,
-    field_names: Box<[&'static str]>,
-    field_indices: HashMap<&'static str, usize>,
+    ty: TypeIdentity,
+    capabilities: Option<TypeCapabilities>,
+    fields: Cow<'static, [NamedField]>,
+    field_names: NameIndex,
+    ignored_fields: &'static [(usize, &'static str)],
+    converted_fields: Cow<'static, [NamedField]>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -89,43 +121,163 @@ impl StructInfo {
     /// * `name`: The name of this struct (_without_ generics or lifetimes)
     /// * `fields`: The fields of this struct in the order they are defined
     ///
-    pub fn new<T: Reflect>(name: &'static str, fields: &[NamedField]) -> Self {
-        let field_indices = fields
-            .iter()
-            .enumerate()
-            .map(|(index, field)| (field.name(), index))
-            .collect::<HashMap<_, _>>();
-
-        let field_names = fields.iter().map(|field| field.name()).collect();
+    /// `T` need not implement [`Reflect`], so that types implementing [`NonSendReflect`]
+    /// can be described too.
+    ///
+    /// [`NonSendReflect`]: crate::NonSendReflect
+    pub fn new<T: 'static>(name: &'static str, fields: &[NamedField]) -> Self {
+        Self {
+            name,
+            ty: TypeIdentity::of::<T>(),
+            capabilities: None,
+            fields: Cow::Owned(fields.to_vec()),
+            field_names: NameIndex::new(fields.iter().map(NamedField::name)),
+            ignored_fields: &[],
+            converted_fields: Cow::Borrowed(&[]),
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
 
+    /// Create a new [`StructInfo`] from static data, so that it can be built in `const` contexts.
+    ///
+    /// This is used by `#[derive(Reflect)]` for non-generic structs.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The name of this struct (_without_ generics or lifetimes)
+    /// * `fields`: The fields of this struct in the order they are defined
+    /// * `field_names`: The names of the fields, in the same order
+    /// * `sorted_fields`: The names of the fields paired with their index, sorted by name
+    ///
+    /// # Panics
+    ///
+    /// Panics, or fails to compile in a `const` context, if the names do not match the fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{NamedField, StructInfo, TypeInfo};
+    /// struct Point {
+    ///     x: f32,
+    ///     label: String,
+    /// }
+    ///
+    /// static INFO: TypeInfo = TypeInfo::Struct(StructInfo::from_static::<Point>(
+    ///     "Point",
+    ///     &[NamedField::new::<f32>("x"), NamedField::new::<String>("label")],
+    ///     &["x", "label"],
+    ///     &[("label", 1), ("x", 0)],
+    /// ));
+    /// # use bevy_reflect::{Reflect, ReflectRef, ReflectMut, ReflectOwned};
+    /// # impl Reflect for Point {
+    /// #   fn type_name(&self) -> &str { todo!() }
+    /// #   fn get_type_info(&self) -> &'static TypeInfo { todo!() }
+    /// #   fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> { todo!() }
+    /// #   fn as_any(&self) -> &dyn std::any::Any { todo!() }
+    /// #   fn as_any_mut(&mut self) -> &mut dyn std::any::Any { todo!() }
+    /// #   fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> { todo!() }
+    /// #   fn as_reflect(&self) -> &dyn Reflect { todo!() }
+    /// #   fn as_reflect_mut(&mut self) -> &mut dyn Reflect { todo!() }
+    /// #   fn apply(&mut self, value: &dyn Reflect) { todo!() }
+    /// #   fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> { todo!() }
+    /// #   fn reflect_ref(&self) -> ReflectRef { todo!() }
+    /// #   fn reflect_mut(&mut self) -> ReflectMut { todo!() }
+    /// #   fn reflect_owned(self: Box<Self>) -> ReflectOwned { todo!() }
+    /// #   fn clone_value(&self) -> Box<dyn Reflect> { todo!() }
+    /// # }
+    ///
+    /// let TypeInfo::Struct(info) = &INFO else { unreachable!() };
+    /// assert_eq!(Some(1), info.index_of("label"));
+    /// assert!(info.field("x").unwrap().is::<f32>());
+    /// ```
+    pub const fn from_static<T: Reflect>(
+        name: &'static str,
+        fields: &'static [NamedField],
+        field_names: &'static [&'static str],
+        sorted_fields: &'static [(&'static str, usize)],
+    ) -> Self {
+        assert!(
+            fields_match_names(fields, field_names),
+            "field names must be given in the order of the fields"
+        );
         Self {
             name,
-            type_name: std::any::type_name::<T>(),
-            type_id: TypeId::of::<T>(),
-            fields: fields.to_vec().into_boxed_slice(),
-            field_names,
-            field_indices,
+            ty: TypeIdentity::of::<T>(),
+            capabilities: None,
+            fields: Cow::Borrowed(fields),
+            field_names: NameIndex::from_static(field_names, sorted_fields),
+            ignored_fields: &[],
+            converted_fields: Cow::Borrowed(&[]),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the [capabilities] of this struct.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn with_capabilities(mut self, capabilities: TypeCapabilities) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
     /// Sets the docstring for this struct.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
+    }
+
+    /// Sets the names of the fields which are not reflected, such as those marked with
+    /// `#[reflect(ignore)]`.
+    ///
+    /// Each name is paired with the number of reflected fields declared before it.
+    pub const fn with_ignored_fields(
+        mut self,
+        ignored_fields: &'static [(usize, &'static str)],
+    ) -> Self {
+        self.ignored_fields = ignored_fields;
+        self
+    }
+
+    /// The names of the fields which are not reflected, in the order they are defined.
+    ///
+    /// Each name is paired with the number of reflected fields declared before it.
+    pub fn ignored_fields(&self) -> &'static [(usize, &'static str)] {
+        self.ignored_fields
+    }
+
+    /// Sets the fields which are reflected through an owned conversion,
+    /// such as those marked with `#[reflect(convert = "...")]`.
+    ///
+    /// These fields are not part of [`field_len`](Self::field_len) and are only
+    /// reachable through [`Struct::converted_fields`].
+    pub fn with_converted_fields(mut self, converted_fields: &[NamedField]) -> Self {
+        self.converted_fields = Cow::Owned(converted_fields.to_vec());
+        self
+    }
+
+    /// Get the field reflected through an owned conversion with the given name.
+    pub fn converted_field(&self, name: &str) -> Option<&NamedField> {
+        self.converted_fields
+            .iter()
+            .find(|field| field.name() == name)
+    }
+
+    /// The fields which are reflected through an owned conversion, in the order they are defined.
+    pub fn converted_fields(&self) -> &[NamedField] {
+        &self.converted_fields
     }
 
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
-        &self.field_names
+        self.field_names.names()
     }
 
     /// Get the field with the given name.
     pub fn field(&self, name: &str) -> Option<&NamedField> {
-        self.field_indices
-            .get(name)
-            .map(|index| &self.fields[*index])
+        self.index_of(name).map(|index| &self.fields[index])
     }
 
     /// Get the field at the given index.
@@ -135,7 +287,7 @@ impl StructInfo {
 
     /// Get the index of the field with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
-        self.field_indices.get(name).copied()
+        self.field_names.index_of(name)
     }
 
     /// Iterate over the fields of this struct.
@@ -161,17 +313,24 @@ impl StructInfo {
     ///
     /// [type name]: std::any::type_name
     pub fn type_name(&self) -> &'static str {
-        self.type_name
+        self.ty.type_name()
     }
 
     /// The [`TypeId`] of the struct.
     pub fn type_id(&self) -> TypeId {
-        self.type_id
+        self.ty.type_id()
     }
 
     /// Check if the given type matches the struct type.
     pub fn is<T: Any>(&self) -> bool {
-        TypeId::of::<T>() == self.type_id
+        TypeId::of::<T>() == self.ty.type_id()
+    }
+
+    /// The [capabilities] of this struct, if they are known.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn capabilities(&self) -> Option<TypeCapabilities> {
+        self.capabilities
     }
 
     /// The docstring of this struct, if any.
@@ -216,6 +375,10 @@ impl<'a> ExactSizeIterator for FieldIter<'a> {}
 /// A convenience trait which combines fetching and downcasting of struct
 /// fields.
 ///
+/// It is also implemented for `dyn Reflect`, where it reaches the fields of any
+/// [struct](ReflectRef::Struct) or [enum](ReflectRef::Enum) by name, and additionally those of
+/// [tuple structs](ReflectRef::TupleStruct) and [tuples](ReflectRef::Tuple) by index.
+///
 /// # Example
 ///
 /// ```
@@ -231,6 +394,11 @@ impl<'a> ExactSizeIterator for FieldIter<'a> {}
 ///
 /// foo.get_field_mut::<String>("bar").unwrap().truncate(5);
 /// assert_eq!(foo.get_field::<String>("bar"), Some(&"Hello".to_string()));
+/// assert_eq!(foo.get_field_at::<String>(0), Some(&"Hello".to_string()));
+///
+/// let value: &dyn Reflect = &(1_u32, 2_u32);
+/// assert_eq!(value.get_field_at::<u32>(1), Some(&2));
+/// assert_eq!(value.get_field::<u32>("bar"), None);
 /// # }
 /// ```
 pub trait GetField {
@@ -241,6 +409,13 @@ pub trait GetField {
     /// Returns a mutable reference to the value of the field named `name`,
     /// downcast to `T`.
     fn get_field_mut<T: Reflect>(&mut self, name: &str) -> Option<&mut T>;
+
+    /// Returns a reference to the value of the field at `index`, downcast to `T`.
+    fn get_field_at<T: Reflect>(&self, index: usize) -> Option<&T>;
+
+    /// Returns a mutable reference to the value of the field at `index`,
+    /// downcast to `T`.
+    fn get_field_at_mut<T: Reflect>(&mut self, index: usize) -> Option<&mut T>;
 }
 
 impl<S: Struct> GetField for S {
@@ -252,6 +427,16 @@ impl<S: Struct> GetField for S {
         self.field_mut(name)
             .and_then(|value| value.downcast_mut::<T>())
     }
+
+    fn get_field_at<T: Reflect>(&self, index: usize) -> Option<&T> {
+        self.field_at(index)
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    fn get_field_at_mut<T: Reflect>(&mut self, index: usize) -> Option<&mut T> {
+        self.field_at_mut(index)
+            .and_then(|value| value.downcast_mut::<T>())
+    }
 }
 
 impl GetField for dyn Struct {
@@ -263,15 +448,76 @@ impl GetField for dyn Struct {
         self.field_mut(name)
             .and_then(|value| value.downcast_mut::<T>())
     }
+
+    fn get_field_at<T: Reflect>(&self, index: usize) -> Option<&T> {
+        self.field_at(index)
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    fn get_field_at_mut<T: Reflect>(&mut self, index: usize) -> Option<&mut T> {
+        self.field_at_mut(index)
+            .and_then(|value| value.downcast_mut::<T>())
+    }
+}
+
+impl GetField for dyn Reflect {
+    fn get_field<T: Reflect>(&self, name: &str) -> Option<&T> {
+        match self.reflect_ref() {
+            ReflectRef::Struct(value) => value.field(name),
+            ReflectRef::Enum(value) => value.field(name),
+            _ => None,
+        }
+        .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    fn get_field_mut<T: Reflect>(&mut self, name: &str) -> Option<&mut T> {
+        match self.reflect_mut() {
+            ReflectMut::Struct(value) => value.field_mut(name),
+            ReflectMut::Enum(value) => value.field_mut(name),
+            _ => None,
+        }
+        .and_then(|value| value.downcast_mut::<T>())
+    }
+
+    fn get_field_at<T: Reflect>(&self, index: usize) -> Option<&T> {
+        match self.reflect_ref() {
+            ReflectRef::Struct(value) => value.field_at(index),
+            ReflectRef::TupleStruct(value) => value.field(index),
+            ReflectRef::Tuple(value) => value.field(index),
+            ReflectRef::Enum(value) => value.field_at(index),
+            _ => None,
+        }
+        .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    fn get_field_at_mut<T: Reflect>(&mut self, index: usize) -> Option<&mut T> {
+        match self.reflect_mut() {
+            ReflectMut::Struct(value) => value.field_at_mut(index),
+            ReflectMut::TupleStruct(value) => value.field_mut(index),
+            ReflectMut::Tuple(value) => value.field_mut(index),
+            ReflectMut::Enum(value) => value.field_at_mut(index),
+            _ => None,
+        }
+        .and_then(|value| value.downcast_mut::<T>())
+    }
 }
 
 /// A struct type which allows fields to be added at runtime.
+///
+/// By default, each field is stored in its own [`Box`]. When [inline values] are enabled,
+/// fields which are small primitives, such as numbers and `bool`s, are instead stored in place,
+/// saving an allocation for each of them whenever they are inserted or the struct is cloned.
+///
+/// Clones of structs made by [`Struct::clone_dynamic`] have inline values enabled.
+///
+/// [inline values]: DynamicStruct::set_inline_values
 #[derive(Default)]
 pub struct DynamicStruct {
     name: String,
-    fields: Vec<Box<dyn Reflect>>,
+    fields: Vec<FieldValue>,
     field_names: Vec<Cow<'static, str>>,
     field_indices: HashMap<Cow<'static, str>, usize>,
+    inline_values: bool,
 }
 
 impl DynamicStruct {
@@ -285,10 +531,125 @@ impl DynamicStruct {
         self.name = name;
     }
 
+    /// Creates a struct of the type described by `info`, with every field set to its default.
+    ///
+    /// Field defaults come from the [`ReflectDefault`] registered for each field's type.
+    /// Fields whose types have no default, but are themselves structs or tuple structs,
+    /// are built in the same way from their own fields.
+    ///
+    /// This makes it possible to [apply] partially-specified data, such as that coming from a
+    /// script, on top of the result to get a complete struct which [`FromReflect`] accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field's type is not registered or cannot be defaulted.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{DynamicStruct, FromReflect, Reflect, StructInfo, TypeInfo, TypeRegistry, Typed};
+    /// #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    /// struct Player {
+    ///     name: String,
+    ///     health: u32,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<String>();
+    /// registry.register::<u32>();
+    ///
+    /// let TypeInfo::Struct(info) = Player::type_info() else { unreachable!() };
+    /// let mut player = DynamicStruct::from_type_info(info, &registry).unwrap();
+    ///
+    /// let mut patch = DynamicStruct::default();
+    /// patch.insert("health", 100_u32);
+    /// player.apply(&patch);
+    ///
+    /// let player = Player::from_reflect(&player).unwrap();
+    /// assert_eq!(Player { name: String::new(), health: 100 }, player);
+    /// ```
+    ///
+    /// [`ReflectDefault`]: crate::std_traits::ReflectDefault
+    /// [apply]: Reflect::apply
+    /// [`FromReflect`]: crate::FromReflect
+    pub fn from_type_info(
+        info: &StructInfo,
+        registry: &TypeRegistry,
+    ) -> Result<Self, DefaultValueError> {
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_name(info.type_name().to_string());
+        for field in info.iter() {
+            let value = default_value(field.type_id(), field.type_name(), registry)?;
+            dynamic.insert_boxed(field.name(), value);
+        }
+        Ok(dynamic)
+    }
+
+    /// Returns true if small primitive field values are stored in place, rather than boxed.
+    pub fn inline_values(&self) -> bool {
+        self.inline_values
+    }
+
+    /// Sets whether small primitive field values, such as numbers and `bool`s,
+    /// are stored in place rather than boxed.
+    ///
+    /// This applies to the existing fields as well as any inserted later,
+    /// and is kept by [`clone_dynamic`](Struct::clone_dynamic), so it is worth enabling
+    /// for structs which are cloned many times, such as those making up a scene.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{DynamicStruct, GetField, Struct};
+    /// let mut transform = DynamicStruct::default();
+    /// transform.set_inline_values(true);
+    /// transform.insert("x", 1.0_f32);
+    /// transform.insert("label", String::from("origin"));
+    ///
+    /// let clone = transform.clone_dynamic();
+    /// assert!(clone.inline_values());
+    /// assert_eq!(Some(&1.0), clone.get_field::<f32>("x"));
+    /// ```
+    pub fn set_inline_values(&mut self, inline: bool) {
+        self.inline_values = inline;
+        self.fields = core::mem::take(&mut self.fields)
+            .into_iter()
+            .map(|field| field.with_inline(inline))
+            .collect();
+    }
+
     /// Inserts a field named `name` with value `value` into the struct.
     ///
     /// If the field already exists, it is overwritten.
     pub fn insert_boxed(&mut self, name: &str, value: Box<dyn Reflect>) {
+        self.insert_field(name, FieldValue::from_boxed(value, self.inline_values));
+    }
+
+    /// Inserts a field named `name` with the typed value `value` into the struct.
+    ///
+    /// If the field already exists, it is overwritten.
+    pub fn insert<T: Reflect>(&mut self, name: &str, value: T) {
+        self.insert_field(name, FieldValue::new(value, self.inline_values));
+    }
+
+    /// Inserts a field named `name` with a clone of `value` into the struct.
+    ///
+    /// If inline values are enabled and `value` is a small primitive, it is copied in place
+    /// rather than cloned into a box with [`Reflect::clone_value`].
+    ///
+    /// If the field already exists, it is overwritten.
+    pub fn insert_cloned(&mut self, name: &str, value: &dyn Reflect) {
+        self.insert_field(name, FieldValue::cloned(value, self.inline_values));
+    }
+
+    /// Returns true if the field at `index` is stored in its own box, rather than inline.
+    pub(crate) fn is_field_boxed(&self, index: usize) -> bool {
+        matches!(self.fields.get(index), Some(FieldValue::Boxed(_)))
+    }
+
+    fn insert_field(&mut self, name: &str, value: FieldValue) {
+        if let Some(index) = self.field_indices.get(name) {
+            self.fields[*index] = value;
+            return;
+        }
         let name = Cow::Owned(name.to_string());
         match self.field_indices.entry(name) {
             Entry::Occupied(entry) => {
@@ -302,21 +663,45 @@ impl DynamicStruct {
         }
     }
 
-    /// Inserts a field named `name` with the typed value `value` into the struct.
+    /// Removes the field named `name`, returning its owned value if there was such a field.
     ///
-    /// If the field already exists, it is overwritten.
-    pub fn insert<T: Reflect>(&mut self, name: &str, value: T) {
-        if let Some(index) = self.field_indices.get(name) {
-            self.fields[*index] = Box::new(value);
-        } else {
-            self.insert_boxed(name, Box::new(value));
+    /// The fields after it move down by one index, keeping their order.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{DynamicStruct, Struct};
+    /// let mut player = DynamicStruct::default();
+    /// player.insert("name", String::from("Ferris"));
+    /// player.insert("health", 100_u32);
+    ///
+    /// let name = player.remove("name").unwrap();
+    /// assert_eq!(Some(&String::from("Ferris")), name.downcast_ref::<String>());
+    /// assert_eq!(Some(0), player.index_of("health"));
+    /// assert!(player.remove("name").is_none());
+    /// ```
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Reflect>> {
+        let index = self.field_indices.remove(name)?;
+        self.field_names.remove(index);
+        for moved_index in self.field_indices.values_mut() {
+            if *moved_index > index {
+                *moved_index -= 1;
+            }
         }
+        Some(self.fields.remove(index).into_boxed())
     }
 
     /// Gets the index of the field with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.field_indices.get(name).copied()
     }
+
+    /// Takes the struct apart into the owned values of its fields, in order.
+    pub(crate) fn into_values(self) -> Vec<Box<dyn Reflect>> {
+        self.fields
+            .into_iter()
+            .map(FieldValue::into_boxed)
+            .collect()
+    }
 }
 
 impl Struct for DynamicStruct {
@@ -324,13 +709,13 @@ impl Struct for DynamicStruct {
     fn field(&self, name: &str) -> Option<&dyn Reflect> {
         self.field_indices
             .get(name)
-            .map(|index| &*self.fields[*index])
+            .map(|index| self.fields[*index].as_reflect())
     }
 
     #[inline]
     fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
         if let Some(index) = self.field_indices.get(name) {
-            Some(&mut *self.fields[*index])
+            Some(self.fields[*index].as_reflect_mut())
         } else {
             None
         }
@@ -338,12 +723,12 @@ impl Struct for DynamicStruct {
 
     #[inline]
     fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
-        self.fields.get(index).map(|value| &**value)
+        self.fields.get(index).map(FieldValue::as_reflect)
     }
 
     #[inline]
     fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
-        self.fields.get_mut(index).map(|value| &mut **value)
+        self.fields.get_mut(index).map(FieldValue::as_reflect_mut)
     }
 
     #[inline]
@@ -364,17 +749,23 @@ impl Struct for DynamicStruct {
         }
     }
 
+    #[inline]
+    fn take_field(&mut self, name: &str) -> Option<Box<dyn Reflect>> {
+        self.remove(name)
+    }
+
     fn clone_dynamic(&self) -> DynamicStruct {
-        DynamicStruct {
+        expect_guarded(self.as_reflect(), "clone", || DynamicStruct {
             name: self.name.clone(),
             field_names: self.field_names.clone(),
             field_indices: self.field_indices.clone(),
             fields: self
                 .fields
                 .iter()
-                .map(|value| value.clone_value())
+                .map(|value| FieldValue::cloned(value.as_reflect(), self.inline_values))
                 .collect(),
-        }
+            inline_values: self.inline_values,
+        })
     }
 }
 
@@ -440,16 +831,7 @@ impl Reflect for DynamicStruct {
     }
 
     fn apply(&mut self, value: &dyn Reflect) {
-        if let ReflectRef::Struct(struct_value) = value.reflect_ref() {
-            for (i, value) in struct_value.iter_fields().enumerate() {
-                let name = struct_value.name_at(i).unwrap();
-                if let Some(v) = self.field_mut(name) {
-                    v.apply(value);
-                }
-            }
-        } else {
-            panic!("Attempted to apply non-struct type to struct type.");
-        }
+        expect_guarded(value, "apply", || struct_apply(self, value));
     }
 
     fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
@@ -461,7 +843,11 @@ impl Reflect for DynamicStruct {
         struct_partial_eq(self, value)
     }
 
-    fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        struct_partial_cmp(self, value)
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "DynamicStruct(")?;
         struct_debug(self, f)?;
         write!(f, ")")
@@ -469,11 +855,29 @@ impl Reflect for DynamicStruct {
 }
 
 impl Debug for DynamicStruct {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.debug(f)
     }
 }
 
+/// Collects named fields into a struct, where later fields overwrite earlier ones with the same name.
+impl<N: AsRef<str>> FromIterator<(N, Box<dyn Reflect>)> for DynamicStruct {
+    fn from_iter<I: IntoIterator<Item = (N, Box<dyn Reflect>)>>(fields: I) -> Self {
+        let mut dynamic_struct = Self::default();
+        dynamic_struct.extend(fields);
+        dynamic_struct
+    }
+}
+
+/// Inserts named fields into the struct, overwriting existing fields with the same name.
+impl<N: AsRef<str>> Extend<(N, Box<dyn Reflect>)> for DynamicStruct {
+    fn extend<I: IntoIterator<Item = (N, Box<dyn Reflect>)>>(&mut self, fields: I) {
+        for (name, value) in fields {
+            self.insert_boxed(name.as_ref(), value);
+        }
+    }
+}
+
 impl Typed for DynamicStruct {
     fn type_info() -> &'static TypeInfo {
         static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
@@ -481,6 +885,26 @@ impl Typed for DynamicStruct {
     }
 }
 
+/// Applies the fields of `b` to the fields of `a` with the same names.
+///
+/// Fields of `b` which `a` does not have are ignored.
+///
+/// # Panics
+///
+/// This function panics if `b` is not a struct.
+#[inline]
+pub fn struct_apply<S: Struct + ?Sized>(a: &mut S, b: &dyn Reflect) {
+    let ReflectRef::Struct(struct_value) = b.reflect_ref()  else {
+        panic!("Attempted to apply non-struct type to struct type.");
+    };
+    for (i, value) in struct_value.iter_fields().enumerate() {
+        let name = struct_value.name_at(i).unwrap();
+        if let Some(v) = a.field_mut(name) {
+            v.apply(value);
+        }
+    }
+}
+
 /// Compares a [`Struct`] with a [`Reflect`] value.
 ///
 /// Returns true if and only if all of the following are true:
@@ -489,20 +913,35 @@ impl Typed for DynamicStruct {
 ///   [`Reflect::reflect_partial_eq`] returns `Some(true)` for the two field
 ///   values.
 ///
+/// [Converted fields](Struct::converted_fields) are compared like any other field,
+/// whether they are converted in `a`, `b` or both.
+///
 /// Returns [`None`] if the comparison couldn't even be performed.
 #[inline]
-pub fn struct_partial_eq<S: Struct>(a: &S, b: &dyn Reflect) -> Option<bool> {
+pub fn struct_partial_eq<S: Struct + ?Sized>(a: &S, b: &dyn Reflect) -> Option<bool> {
     let ReflectRef::Struct(struct_value) = b.reflect_ref()  else {
         return Some(false);
     };
 
-    if a.field_len() != struct_value.field_len() {
+    let a_converted = a.converted_fields();
+    let b_converted = struct_value.converted_fields();
+    if a.field_len() + a_converted.len() != struct_value.field_len() + b_converted.len() {
         return Some(false);
     }
 
-    for (i, value) in struct_value.iter_fields().enumerate() {
-        let name = struct_value.name_at(i).unwrap();
-        if let Some(field_value) = a.field(name) {
+    let b_fields = (0..struct_value.field_len()).map(|i| {
+        (
+            struct_value.name_at(i).unwrap(),
+            struct_value.field_at(i).unwrap(),
+        )
+    });
+    let b_converted = b_converted.iter().map(|(name, value)| (*name, &**value));
+    for (name, value) in b_fields.chain(b_converted) {
+        let a_field = a.field(name).or_else(|| {
+            let (_, converted) = a_converted.iter().find(|(a_name, _)| *a_name == name)?;
+            Some(&**converted)
+        });
+        if let Some(field_value) = a_field {
             let eq_result = field_value.reflect_partial_eq(value);
             if let failed @ (Some(false) | None) = eq_result {
                 return failed;
@@ -515,6 +954,27 @@ pub fn struct_partial_eq<S: Struct>(a: &S, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Compares a [`Struct`] with a [`Reflect`] value lexicographically,
+/// visiting the fields in the order they appear in `a`.
+///
+/// Returns [`None`] if `b` is not a struct with the same fields as `a`,
+/// or if [`Reflect::reflect_partial_cmp`] returns `None` for a pair of fields compared.
+#[inline]
+pub fn struct_partial_cmp<S: Struct + ?Sized>(a: &S, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Struct(struct_value) = b.reflect_ref()  else {
+        return None;
+    };
+
+    if a.field_len() != struct_value.field_len() {
+        return None;
+    }
+
+    let b_fields = (0..a.field_len())
+        .map(|i| struct_value.field(a.name_at(i)?))
+        .collect::<Option<Vec<_>>>()?;
+    iter_partial_cmp(a.iter_fields(), b_fields.into_iter())
+}
+
 /// The default debug formatter for [`Struct`] types.
 ///
 /// # Example
@@ -535,14 +995,33 @@ pub fn struct_partial_eq<S: Struct>(a: &S, b: &dyn Reflect) -> Option<bool> {
 /// // }
 /// ```
 #[inline]
-pub fn struct_debug(dyn_struct: &dyn Struct, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut debug = f.debug_struct(dyn_struct.type_name());
-    for field_index in 0..dyn_struct.field_len() {
-        let field = dyn_struct.field_at(field_index).unwrap();
-        debug.field(
-            dyn_struct.name_at(field_index).unwrap(),
-            &field as &dyn Debug,
-        );
-    }
-    debug.finish()
+pub fn struct_debug(
+    dyn_struct: &dyn Struct,
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    debug_guarded(dyn_struct.as_reflect(), f, |f| {
+        let type_path = debug_type_path(dyn_struct.as_reflect());
+        let mut debug = f.debug_struct(type_path.as_deref().unwrap_or(dyn_struct.type_name()));
+        let mut ignored_fields = match dyn_struct.get_type_info() {
+            TypeInfo::Struct(info) if show_ignored_fields() => info.ignored_fields(),
+            _ => &[],
+        }
+        .iter()
+        .peekable();
+        for field_index in 0..=dyn_struct.field_len() {
+            while let Some((_, name)) = ignored_fields.next_if(|(index, _)| *index <= field_index) {
+                debug.field(name, &format_args!("<ignored>"));
+            }
+            if let Some(field) = dyn_struct.field_at(field_index) {
+                debug.field(
+                    dyn_struct.name_at(field_index).unwrap(),
+                    &field as &dyn Debug,
+                );
+            }
+        }
+        for (name, field) in dyn_struct.converted_fields() {
+            debug.field(name, &field as &dyn Debug);
+        }
+        debug.finish()
+    })
 }
@@ -0,0 +1,172 @@
+use crate::{guard_recursion, Reflect, ReflectRef};
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+/// Returns a hash of a value computed from its structure,
+/// for types which do not support [`Reflect::reflect_hash`].
+///
+/// Unlike [`Reflect::reflect_hash`], this only requires the hashes of the primitive
+/// values within the value, walking its fields, elements and entries
+/// to hash the value as a whole. The type name of each value is hashed along with its contents.
+/// As floats are hashed by their bits, with `-0.0` hashed as `0.0`, they are supported too.
+///
+/// The hash does not depend on whether the value is a concrete type or the dynamic
+/// equivalent of one, such as one made by [`Reflect::clone_value`], nor on the order
+/// of the entries of maps. It is not the same hash as [`Reflect::reflect_hash`] returns.
+///
+/// Returns `None` if a primitive value within the value cannot be hashed,
+/// or if the value [contains itself](crate::guard_recursion).
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{structural_hash, Reflect};
+/// #[derive(Reflect)]
+/// struct Light {
+///     color: [f32; 3],
+///     intensity: f32,
+/// }
+///
+/// let light = Light { color: [1.0, 0.5, 0.0], intensity: 800.0 };
+/// assert_eq!(None, light.reflect_hash());
+///
+/// let hash = structural_hash(&light).unwrap();
+/// assert_eq!(Some(hash), structural_hash(&*light.clone_value()));
+/// ```
+pub fn structural_hash(value: &dyn Reflect) -> Option<u64> {
+    guard_recursion(value, || {
+        let mut hasher = crate::ReflectHasher::default();
+        value.type_name().hash(&mut hasher);
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                value.field_len().hash(&mut hasher);
+                for (index, field) in value.iter_fields().enumerate() {
+                    value.name_at(index).hash(&mut hasher);
+                    hasher.write_u64(structural_hash(field)?);
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                value.field_len().hash(&mut hasher);
+                for field in value.iter_fields() {
+                    hasher.write_u64(structural_hash(field)?);
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                value.field_len().hash(&mut hasher);
+                for field in value.iter_fields() {
+                    hasher.write_u64(structural_hash(field)?);
+                }
+            }
+            ReflectRef::List(value) => {
+                value.len().hash(&mut hasher);
+                for item in value.iter() {
+                    hasher.write_u64(structural_hash(item)?);
+                }
+            }
+            ReflectRef::Array(value) => {
+                value.len().hash(&mut hasher);
+                for item in value.iter() {
+                    hasher.write_u64(structural_hash(item)?);
+                }
+            }
+            ReflectRef::Map(value) => {
+                // Sorting the hashes of the entries makes the hash independent of their order
+                let mut entries = value
+                    .iter()
+                    .map(|(key, value)| Some((structural_hash(key)?, structural_hash(value)?)))
+                    .collect::<Option<Vec<_>>>()?;
+                entries.sort_unstable();
+                entries.hash(&mut hasher);
+            }
+            ReflectRef::Enum(value) => {
+                value.variant_name().hash(&mut hasher);
+                value.variant_type().hash(&mut hasher);
+                value.field_len().hash(&mut hasher);
+                for field in value.iter_fields() {
+                    field.name().hash(&mut hasher);
+                    hasher.write_u64(structural_hash(field.value())?);
+                }
+            }
+            ReflectRef::Value(value) => {
+                let hash = if let Some(value) = value.downcast_ref::<f32>() {
+                    // `0.0 == -0.0`, so both must have the same hash
+                    (*value + 0.0).to_bits() as u64
+                } else if let Some(value) = value.downcast_ref::<f64>() {
+                    (*value + 0.0).to_bits()
+                } else {
+                    value.reflect_hash()?
+                };
+                hasher.write_u64(hash);
+            }
+        }
+        Some(hasher.finish())
+    })
+    .ok()
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::structural_hash;
+    use crate::{self as bevy_reflect, DynamicMap, Reflect};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Clone)]
+    struct Material {
+        name: String,
+        roughness: f32,
+        textures: HashMap<String, Vec<u8>>,
+        tint: Option<(f64, f64, f64)>,
+    }
+
+    #[derive(Reflect, Clone)]
+    #[reflect_value]
+    struct Opaque;
+
+    #[test]
+    fn should_hash_structurally() {
+        let mut textures = HashMap::default();
+        for (name, data) in [
+            ("albedo", vec![1, 2]),
+            ("normal", vec![3]),
+            ("mask", vec![]),
+        ] {
+            textures.insert(name.to_string(), data);
+        }
+        let material = Material {
+            name: String::from("rock"),
+            roughness: 0.0,
+            textures,
+            tint: Some((1.0, 0.5, 0.25)),
+        };
+        assert_eq!(None, material.reflect_hash());
+
+        let hash = structural_hash(&material).unwrap();
+        assert_eq!(Some(hash), structural_hash(&*material.clone_value()));
+
+        let mut negative_zero = material.clone();
+        negative_zero.roughness = -0.0;
+        assert_eq!(Some(hash), structural_hash(&negative_zero));
+
+        let (mut forward, mut backward) = (DynamicMap::default(), DynamicMap::default());
+        for (name, data) in material.textures.iter() {
+            forward.insert(name.clone(), data.clone());
+        }
+        for (name, data) in material
+            .textures
+            .iter()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+        {
+            backward.insert(name.clone(), data.clone());
+        }
+        assert_eq!(structural_hash(&forward), structural_hash(&backward));
+
+        let mut changed = material.clone();
+        changed.tint = Some((1.0, 0.5, 0.5));
+        assert_ne!(Some(hash), structural_hash(&changed));
+        changed.tint = None;
+        assert_ne!(Some(hash), structural_hash(&changed));
+        assert_eq!(None, structural_hash(&(material, Opaque)));
+    }
+}
@@ -0,0 +1,482 @@
+//! Helpers for snapshot tests of reflected values, and for comparing reflected values in tests.
+//!
+//! A snapshot test serializes a value and compares the output to a file written by an earlier
+//! run of the test, failing if they differ. When a change to the value is intended,
+//! set the `BEVY_REFLECT_UPDATE_SNAPSHOTS` environment variable while running the tests
+//! to overwrite the snapshot files instead. Missing snapshot files are always written.
+//!
+//! Snapshots are written by [`snapshot_string`], in a stable format which only changes
+//! when the value does: pretty-printed RON, with map entries and struct fields sorted by name.
+//!
+//! Reflected values can be compared with [`assert_reflect_eq!`](crate::assert_reflect_eq),
+//! which lists the paths at which they differ when the assertion fails.
+
+use crate::cycle::expect_guarded;
+use crate::serde::{ReflectSerializer, SerializerConfig};
+use crate::{debug_with_options, Reflect, ReflectDebugOptions, ReflectRef, TypeRegistry};
+use ron::ser::PrettyConfig;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The environment variable which makes snapshot tests overwrite their snapshot files.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "BEVY_REFLECT_UPDATE_SNAPSHOTS";
+
+/// Serializes a reflected value in the stable snapshot format.
+///
+/// The value is written like by a [`ReflectSerializer`], with [sorted keys],
+/// as pretty-printed RON with fixed settings, so that equal values always give the same output.
+///
+/// [sorted keys]: SerializerConfig::with_sorted_keys
+pub fn snapshot_string(value: &dyn Reflect, registry: &TypeRegistry) -> Result<String, ron::Error> {
+    let config = SerializerConfig::default().with_sorted_keys(true);
+    let serializer = ReflectSerializer::new(value, registry).with_config(config);
+    let pretty = PrettyConfig::new()
+        .indentor(String::from("    "))
+        .new_line(String::from("\n"))
+        .struct_names(false)
+        .separate_tuple_members(false)
+        .enumerate_arrays(false);
+    let mut output = ron::ser::to_string_pretty(&serializer, pretty)?;
+    output.push('\n');
+    Ok(output)
+}
+
+/// Compares the snapshot of a value to the snapshot file at the given path.
+///
+/// The file is written instead if it does not exist,
+/// or if the [`UPDATE_SNAPSHOTS_VAR`] environment variable is set.
+pub fn check_snapshot(
+    path: impl AsRef<Path>,
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), SnapshotError> {
+    let path = path.as_ref();
+    let actual = snapshot_string(value, registry)?;
+
+    if !path.exists() || std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, actual)?;
+        return Ok(());
+    }
+
+    // Files checked out on Windows may have gained `\r\n` line endings
+    let expected = std::fs::read_to_string(path)?.replace("\r\n", "\n");
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(SnapshotError::Mismatch {
+            path: path.to_path_buf(),
+            expected,
+            actual,
+        })
+    }
+}
+
+/// An error returned by [`check_snapshot`].
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    /// The value could not be serialized.
+    #[error("failed to serialize the snapshot: {0}")]
+    Serialize(#[from] ron::Error),
+    /// The snapshot file could not be read or written.
+    #[error("failed to access the snapshot file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The snapshot of the value differs from the snapshot file.
+    #[error(
+        "snapshot `{}` does not match, set `{UPDATE_SNAPSHOTS_VAR}` to update it\n--- expected\n{expected}--- actual\n{actual}",
+        path.display()
+    )]
+    Mismatch {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Asserts that the snapshot of a reflected value matches its snapshot file,
+/// as checked by [`check_snapshot`](crate::testing::check_snapshot).
+///
+/// Snapshot files are kept in the `snapshots` folder of the crate running the test,
+/// as `snapshots/<name>.ron`.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_reflect::{assert_reflect_snapshot, Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// struct Health {
+///     current: u32,
+///     max: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+/// registry.register::<u32>();
+///
+/// let health = Health { current: 3, max: 10 };
+/// assert_reflect_snapshot!("default_health", &health, &registry);
+/// ```
+#[macro_export]
+macro_rules! assert_reflect_snapshot {
+    ($name:expr, $value:expr, $registry:expr $(,)?) => {{
+        let path = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("snapshots")
+            .join(format!("{}.ron", $name));
+        if let Err(error) = $crate::testing::check_snapshot(path, $value, $registry) {
+            panic!("{}", error);
+        }
+    }};
+}
+
+/// A place where two reflected values differ, as found by [`reflect_diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflectDifference {
+    /// The path to the differing values, in the syntax of [`GetPath`](crate::GetPath),
+    /// or an empty string if the values differ as a whole.
+    pub path: String,
+    /// The debug output of the expected value, or `<missing>` if it has no value at this path.
+    pub expected: String,
+    /// The debug output of the actual value, or `<missing>` if it has no value at this path.
+    pub actual: String,
+}
+
+impl fmt::Display for ReflectDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "the root"
+        } else {
+            &self.path
+        };
+        write!(
+            f,
+            "at {path}:\n  expected: {}\n    actual: {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+/// Lists the places where `actual` differs from `expected`,
+/// as compared with [`Reflect::reflect_partial_eq`].
+///
+/// Returns an empty list if and only if the values are equal. Structs, tuple structs,
+/// tuples, lists, arrays, maps and enums in the same variant are compared element by element,
+/// so that only the innermost differing values are listed. Values which cannot be compared,
+/// such as those with different kinds or types, are listed as a whole.
+///
+/// The values are printed with [`ReflectDebugOptions`] limiting their size.
+///
+/// # Panics
+///
+/// Panics if either value contains itself.
+pub fn reflect_diff(expected: &dyn Reflect, actual: &dyn Reflect) -> Vec<ReflectDifference> {
+    let mut differences = Vec::new();
+    diff_values(String::new(), expected, actual, &mut differences);
+    differences
+}
+
+fn debug_string(value: Option<&dyn Reflect>) -> String {
+    let options = ReflectDebugOptions {
+        max_depth: Some(4),
+        max_elements: Some(16),
+        ..Default::default()
+    };
+    match value {
+        Some(value) => format!("{:?}", debug_with_options(value, options)),
+        None => String::from("<missing>"),
+    }
+}
+
+fn diff_values(
+    path: String,
+    expected: &dyn Reflect,
+    actual: &dyn Reflect,
+    differences: &mut Vec<ReflectDifference>,
+) {
+    if expected.reflect_partial_eq(actual) == Some(true) {
+        return;
+    }
+    let count = differences.len();
+    expect_guarded(expected, "diff", || {
+        diff_elements(&path, expected, actual, differences);
+    });
+    // Either the values cannot be compared element by element, or they differ
+    // in a way that their elements do not show, such as by their type name
+    if differences.len() == count {
+        differences.push(ReflectDifference {
+            path,
+            expected: debug_string(Some(expected)),
+            actual: debug_string(Some(actual)),
+        });
+    }
+}
+
+/// Compares the pairs of elements at the given paths, where either may be missing.
+fn diff_pairs<'a>(
+    pairs: impl Iterator<Item = (String, Option<&'a dyn Reflect>, Option<&'a dyn Reflect>)>,
+    differences: &mut Vec<ReflectDifference>,
+) {
+    for (path, expected, actual) in pairs {
+        match (expected, actual) {
+            (Some(expected), Some(actual)) => diff_values(path, expected, actual, differences),
+            (None, None) => {}
+            (expected, actual) => differences.push(ReflectDifference {
+                path,
+                expected: debug_string(expected),
+                actual: debug_string(actual),
+            }),
+        }
+    }
+}
+
+fn diff_elements(
+    path: &str,
+    expected: &dyn Reflect,
+    actual: &dyn Reflect,
+    differences: &mut Vec<ReflectDifference>,
+) {
+    if expected.type_name() != actual.type_name() {
+        return;
+    }
+    match (expected.reflect_ref(), actual.reflect_ref()) {
+        (ReflectRef::Struct(expected), ReflectRef::Struct(actual)) => {
+            let names = (0..expected.field_len())
+                .filter_map(|index| expected.name_at(index))
+                .chain(
+                    (0..actual.field_len())
+                        .filter_map(|index| actual.name_at(index))
+                        .filter(|name| expected.field(name).is_none()),
+                );
+            let pairs = names.map(|name| {
+                (
+                    format!("{path}.{name}"),
+                    expected.field(name),
+                    actual.field(name),
+                )
+            });
+            diff_pairs(pairs, differences);
+        }
+        (ReflectRef::TupleStruct(expected), ReflectRef::TupleStruct(actual)) => {
+            let len = expected.field_len().max(actual.field_len());
+            let pairs = (0..len).map(|index| {
+                (
+                    format!("{path}.{index}"),
+                    expected.field(index),
+                    actual.field(index),
+                )
+            });
+            diff_pairs(pairs, differences);
+        }
+        (ReflectRef::Tuple(expected), ReflectRef::Tuple(actual)) => {
+            let len = expected.field_len().max(actual.field_len());
+            let pairs = (0..len).map(|index| {
+                (
+                    format!("{path}.{index}"),
+                    expected.field(index),
+                    actual.field(index),
+                )
+            });
+            diff_pairs(pairs, differences);
+        }
+        (ReflectRef::List(expected), ReflectRef::List(actual)) => {
+            let len = expected.len().max(actual.len());
+            let pairs = (0..len).map(|index| {
+                (
+                    format!("{path}[{index}]"),
+                    expected.get(index),
+                    actual.get(index),
+                )
+            });
+            diff_pairs(pairs, differences);
+        }
+        (ReflectRef::Array(expected), ReflectRef::Array(actual)) => {
+            let len = expected.len().max(actual.len());
+            let pairs = (0..len).map(|index| {
+                (
+                    format!("{path}[{index}]"),
+                    expected.get(index),
+                    actual.get(index),
+                )
+            });
+            diff_pairs(pairs, differences);
+        }
+        (ReflectRef::Map(expected), ReflectRef::Map(actual)) => {
+            let keys = expected.iter().map(|(key, _)| key).chain(
+                actual
+                    .iter()
+                    .map(|(key, _)| key)
+                    .filter(|key| expected.get(*key).is_none()),
+            );
+            let pairs = keys.map(|key| {
+                (
+                    format!("{path}[{key:?}]"),
+                    expected.get(key),
+                    actual.get(key),
+                )
+            });
+            diff_pairs(pairs, differences);
+        }
+        (ReflectRef::Enum(expected), ReflectRef::Enum(actual))
+            if expected.variant_name() == actual.variant_name() =>
+        {
+            let len = expected.field_len().max(actual.field_len());
+            let pairs = (0..len).map(|index| {
+                let name = expected
+                    .name_at(index)
+                    .or_else(|| actual.name_at(index))
+                    .map_or_else(|| index.to_string(), str::to_string);
+                (
+                    format!("{path}.{name}"),
+                    expected.field_at(index),
+                    actual.field_at(index),
+                )
+            });
+            diff_pairs(pairs, differences);
+        }
+        _ => {}
+    }
+}
+
+/// Asserts that two reflected values are equal, as compared with
+/// [`Reflect::reflect_partial_eq`](crate::Reflect::reflect_partial_eq).
+///
+/// When they are not, the panic message lists each path at which they differ,
+/// as found by [`reflect_diff`](crate::testing::reflect_diff), rather than printing both values.
+///
+/// # Example
+/// ```should_panic
+/// # use bevy_reflect::{assert_reflect_eq, Reflect};
+/// #[derive(Reflect)]
+/// struct Inventory {
+///     gold: u32,
+///     items: Vec<String>,
+/// }
+///
+/// let expected = Inventory { gold: 10, items: vec![String::from("sword")] };
+/// let actual = Inventory { gold: 12, items: vec![String::from("sword")] };
+///
+/// // Panics with:
+/// // reflected values are not equal
+/// // at .gold:
+/// //   expected: 10
+/// //     actual: 12
+/// assert_reflect_eq!(&expected, &actual);
+/// ```
+#[macro_export]
+macro_rules! assert_reflect_eq {
+    ($expected:expr, $actual:expr $(,)?) => {{
+        let differences = $crate::testing::reflect_diff($expected, $actual);
+        if !differences.is_empty() {
+            let mut message = String::from("reflected values are not equal");
+            for difference in differences {
+                message.push_str(&format!("\n{}", difference));
+            }
+            panic!("{}", message);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_snapshot, reflect_diff, snapshot_string, ReflectDifference, SnapshotError};
+    use crate::{self as bevy_reflect, Reflect, TypeRegistry};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect)]
+    struct Inventory {
+        items: HashMap<String, u32>,
+        gold: u32,
+    }
+
+    #[test]
+    fn snapshot_is_stable() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Inventory>();
+        registry.register::<HashMap<String, u32>>();
+        registry.register::<String>();
+        registry.register::<u32>();
+
+        let mut items = HashMap::default();
+        for name in ["sword", "apple", "rope"] {
+            items.insert(name.to_string(), name.len() as u32);
+        }
+        let inventory = Inventory { items, gold: 12 };
+        let snapshot = snapshot_string(&inventory, &registry).unwrap();
+        let expected = format!(
+            "{{\n    \"{}\": (\n        gold: 12,\n        items: {{\n            \"apple\": 5,\n            \"rope\": 4,\n            \"sword\": 5,\n        }},\n    ),\n}}\n",
+            std::any::type_name::<Inventory>()
+        );
+        assert_eq!(expected, snapshot);
+
+        let path = std::env::temp_dir()
+            .join(format!("bevy_reflect_snapshot_{}", std::process::id()))
+            .join("inventory.ron");
+        check_snapshot(&path, &inventory, &registry).unwrap();
+        check_snapshot(&path, &inventory, &registry).unwrap();
+
+        let inventory = Inventory {
+            gold: 13,
+            ..inventory
+        };
+        let error = check_snapshot(&path, &inventory, &registry).unwrap_err();
+        assert!(matches!(error, SnapshotError::Mismatch { .. }));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn should_diff_nested_values() {
+        let mut expected = Inventory {
+            items: HashMap::default(),
+            gold: 12,
+        };
+        expected.items.insert(String::from("rope"), 1);
+        expected.items.insert(String::from("apple"), 2);
+        let mut actual = Inventory {
+            items: HashMap::default(),
+            gold: 12,
+        };
+        actual.items.insert(String::from("rope"), 3);
+        actual.items.insert(String::from("sword"), 1);
+
+        let mut differences = reflect_diff(&expected, &actual);
+        differences.sort_by(|a, b| a.path.cmp(&b.path));
+        let difference = |path: &str, expected: &str, actual: &str| ReflectDifference {
+            path: path.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        };
+        assert_eq!(
+            vec![
+                difference(".items[\"apple\"]", "2", "<missing>"),
+                difference(".items[\"rope\"]", "1", "3"),
+                difference(".items[\"sword\"]", "<missing>", "1"),
+            ],
+            differences
+        );
+
+        assert!(reflect_diff(&vec![1_u32], &vec![1_u32]).is_empty());
+        assert_eq!(
+            vec![difference("", "1", "1.0")],
+            reflect_diff(&1_u32, &1.0_f32)
+        );
+        assert_eq!(
+            vec![difference("[1]", "<missing>", "2")],
+            reflect_diff(&vec![1_u32], &vec![1_u32, 2])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at .gold:\n  expected: 1\n    actual: 2")]
+    fn assert_reflect_eq_should_list_differences() {
+        let expected = Inventory {
+            items: HashMap::default(),
+            gold: 1,
+        };
+        let actual = Inventory {
+            items: HashMap::default(),
+            gold: 2,
+        };
+        crate::assert_reflect_eq!(&expected, &actual);
+    }
+}
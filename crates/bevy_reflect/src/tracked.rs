@@ -0,0 +1,319 @@
+use crate::utility::GenericTypeInfoCell;
+use crate::{
+    FromReflect, FromType, GetTypeRegistration, Reflect, ReflectMut, ReflectOwned, ReflectRef,
+    TypeInfo, TypeRegistration, Typed, ValueInfo,
+};
+use alloc::boxed::Box;
+use core::any::Any;
+use core::cmp::Ordering;
+use core::fmt::Formatter;
+use core::ops::{Deref, DerefMut};
+
+/// A wrapper which records whenever its value is mutated, including through reflection.
+///
+/// The value is marked as changed whenever it is [applied] to, [set], or borrowed mutably,
+/// either with [`Reflect::reflect_mut`] (such as to call `field_mut`) or with [`DerefMut`].
+/// As borrowing the value mutably marks it as changed, it may be marked as changed
+/// even if its value stays the same.
+///
+/// `Tracked<T>` is reflected as the value it wraps, except for its type name and info.
+/// The change state can be queried without knowing `T` through the [`ReflectTracked`] type data,
+/// which lets reflection-driven UIs only refresh the values that changed.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, Tracked};
+/// #[derive(Reflect, Clone)]
+/// struct Settings {
+///     volume: f32,
+/// }
+///
+/// let mut settings = Tracked::new(Settings { volume: 0.5 });
+/// assert!(!settings.is_changed());
+///
+/// let reflected: &mut dyn Reflect = &mut settings;
+/// reflected.apply(&Settings { volume: 1.0 });
+/// assert!(settings.is_changed());
+/// assert_eq!(1, settings.change_tick());
+///
+/// settings.clear_changed();
+/// assert!(!settings.is_changed());
+/// assert_eq!(1.0, settings.volume);
+/// ```
+///
+/// [applied]: Reflect::apply
+/// [set]: Reflect::set
+#[derive(Clone, Debug, Default)]
+pub struct Tracked<T> {
+    value: T,
+    changed: bool,
+    change_tick: u64,
+}
+
+impl<T> Tracked<T> {
+    /// Wraps a value, which starts out unchanged.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            changed: false,
+            change_tick: 0,
+        }
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns true if the value has been mutated since it was wrapped
+    /// or since [`clear_changed`](Self::clear_changed) was last called.
+    pub fn is_changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Returns the number of times the value has been mutated since it was wrapped.
+    ///
+    /// Unlike [`is_changed`](Self::is_changed), this is never reset, so it may be compared
+    /// with the tick seen previously to check for changes without clearing them.
+    pub fn change_tick(&self) -> u64 {
+        self.change_tick
+    }
+
+    /// Marks the value as unchanged.
+    pub fn clear_changed(&mut self) {
+        self.changed = false;
+    }
+
+    /// Marks the value as changed.
+    pub fn set_changed(&mut self) {
+        self.changed = true;
+        self.change_tick += 1;
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.set_changed();
+        &mut self.value
+    }
+}
+
+/// Returns the value inside the given `Tracked<T>`, or the given value itself if it is not one.
+fn unwrap_tracked<T: Reflect + Typed + Clone>(value: &dyn Reflect) -> &dyn Reflect {
+    match value.downcast_ref::<Tracked<T>>() {
+        Some(tracked) => &tracked.value,
+        None => value,
+    }
+}
+
+impl<T: Reflect + Typed + Clone> Reflect for Tracked<T> {
+    fn type_name(&self) -> &str {
+        core::any::type_name::<Self>()
+    }
+
+    fn get_type_info(&self) -> &'static TypeInfo {
+        <Self as Typed>::type_info()
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        self.set_changed();
+        self.value.apply(unwrap_tracked::<T>(value));
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        self.value = match value.downcast::<Self>() {
+            Ok(tracked) => tracked.value,
+            Err(value) => value.take()?,
+        };
+        self.set_changed();
+        Ok(())
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        self.value.reflect_ref()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        self.set_changed();
+        self.value.reflect_mut()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        Box::new(self.value).reflect_owned()
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        self.value.reflect_hash()
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        self.value.reflect_partial_eq(unwrap_tracked::<T>(value))
+    }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        self.value.reflect_partial_cmp(unwrap_tracked::<T>(value))
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value.debug(f)
+    }
+}
+
+impl<T: Reflect + Typed + Clone> Typed for Tracked<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> GetTypeRegistration for Tracked<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectTracked>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> FromReflect for Tracked<T> {
+    /// Clones the given value if it is a `Tracked<T>`, keeping its change state,
+    /// and wraps it with `T::from_reflect` otherwise.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(tracked) = reflect.downcast_ref::<Self>() {
+            return Some(tracked.clone());
+        }
+        T::from_reflect(reflect).map(Tracked::new)
+    }
+}
+
+/// Type data for querying the change state of a [`Tracked`] value without knowing its type.
+///
+/// This is registered automatically for `Tracked<T>`.
+#[derive(Clone)]
+pub struct ReflectTracked {
+    is_changed: fn(&dyn Reflect) -> Option<bool>,
+    change_tick: fn(&dyn Reflect) -> Option<u64>,
+    clear_changed: fn(&mut dyn Reflect) -> bool,
+}
+
+impl ReflectTracked {
+    /// Returns whether the given value has changed, as [`Tracked::is_changed`] does.
+    ///
+    /// Returns `None` if the value is not the `Tracked` this was created for.
+    pub fn is_changed(&self, value: &dyn Reflect) -> Option<bool> {
+        (self.is_changed)(value)
+    }
+
+    /// Returns the change tick of the given value, as [`Tracked::change_tick`] does.
+    ///
+    /// Returns `None` if the value is not the `Tracked` this was created for.
+    pub fn change_tick(&self, value: &dyn Reflect) -> Option<u64> {
+        (self.change_tick)(value)
+    }
+
+    /// Marks the given value as unchanged, as [`Tracked::clear_changed`] does.
+    ///
+    /// Returns `false` if the value is not the `Tracked` this was created for.
+    pub fn clear_changed(&self, value: &mut dyn Reflect) -> bool {
+        (self.clear_changed)(value)
+    }
+}
+
+impl<T: Reflect + Typed + Clone> FromType<Tracked<T>> for ReflectTracked {
+    fn from_type() -> Self {
+        Self {
+            is_changed: |value| value.downcast_ref::<Tracked<T>>().map(Tracked::is_changed),
+            change_tick: |value| value.downcast_ref::<Tracked<T>>().map(Tracked::change_tick),
+            clear_changed: |value| {
+                value
+                    .downcast_mut::<Tracked<T>>()
+                    .map(Tracked::clear_changed)
+                    .is_some()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReflectTracked, Tracked};
+    use crate::{self as bevy_reflect, FromReflect, GetPath, Reflect, TypeRegistry};
+    use core::any::TypeId;
+
+    #[derive(Reflect, FromReflect, Clone, Debug, PartialEq)]
+    struct Slider {
+        value: f32,
+        label: String,
+    }
+
+    #[test]
+    fn should_track_reflected_changes() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Tracked<Slider>>();
+        let tracked = registry
+            .get_type_data::<ReflectTracked>(TypeId::of::<Tracked<Slider>>())
+            .unwrap()
+            .clone();
+
+        let mut slider = Tracked::new(Slider {
+            value: 0.5,
+            label: String::from("volume"),
+        });
+        assert_eq!(Some(false), tracked.is_changed(&slider));
+        assert_eq!(None, tracked.is_changed(&0.5_f32));
+
+        *slider.get_path_mut::<f32>("value").unwrap() = 0.75;
+        assert_eq!(Some(true), tracked.is_changed(&slider));
+        assert!(tracked.clear_changed(&mut slider));
+        assert_eq!(Some(false), tracked.is_changed(&slider));
+
+        let replacement = Slider {
+            value: 1.0,
+            label: String::from("gain"),
+        };
+        assert!(slider.set(Box::new(replacement.clone())).is_ok());
+        assert_eq!(Some(true), tracked.is_changed(&slider));
+        assert_eq!(Some(2), tracked.change_tick(&slider));
+        assert_eq!(Some(true), slider.reflect_partial_eq(&replacement));
+
+        let copy = Tracked::<Slider>::from_reflect(&replacement).unwrap();
+        assert!(!copy.is_changed());
+        assert_eq!(replacement, *copy);
+        assert!(slider.set(Box::new(1.0_f32)).is_err());
+    }
+}
@@ -0,0 +1,411 @@
+use crate::utility::{GenericTypeInfoCell, NonGenericTypeInfoCell};
+use crate::{
+    DynamicInfo, FromReflect, FromType, GetTypeRegistration, Reflect, ReflectCast,
+    ReflectFromReflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypeRegistration, Typed,
+    ValueInfo,
+};
+use alloc::boxed::Box;
+use core::any::Any;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+
+/// A trait object type, such as `dyn MyTrait`, whose boxed values can be reflected.
+///
+/// This implements [`Reflect`], [`FromReflect`] and [`GetTypeRegistration`] for `Box<dyn MyTrait>`,
+/// which allows polymorphic fields to be reflected and serialized.
+/// The reflect serializers write such a field as a map with a single entry,
+/// from the [tag] (or type name) of the concrete type to its value,
+/// and deserialize it into a [`DynamicTraitObject`] which [`FromReflect`] converts back into a box.
+///
+/// This is implemented automatically by [`#[reflect_trait]`][0] for traits with [`Reflect`] as a supertrait.
+/// Every concrete type stored in such a box must be registered along with its [`ReflectFromReflect`]
+/// type data and its trait cast, see [`TypeRegistry::register_trait_cast`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{reflect_trait, FromReflect, Reflect, ReflectFromReflect, TypeRegistry};
+/// # use bevy_reflect::serde::{ReflectSerializer, UntypedReflectDeserializer};
+/// # use serde::de::DeserializeSeed;
+/// #[reflect_trait]
+/// trait Shape: Reflect {
+///     fn area(&self) -> f32;
+/// }
+///
+/// #[derive(Reflect, FromReflect)]
+/// #[reflect(FromReflect)]
+/// struct Square(f32);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f32 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// #[derive(Reflect, FromReflect)]
+/// struct Drawing {
+///     shape: Box<dyn Shape>,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Drawing>();
+/// registry.register::<Box<dyn Shape>>();
+/// registry.register::<Square>();
+/// registry.register_trait_cast::<Square, ReflectShape>();
+/// registry.register_tag::<Square>("square");
+///
+/// let drawing = Drawing { shape: Box::new(Square(2.0)) };
+/// let ron = ron::to_string(&ReflectSerializer::new(&drawing, &registry)).unwrap();
+/// assert!(ron.contains(r#"shape:{"square":(2.0)}"#));
+///
+/// let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+/// let value = UntypedReflectDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// let drawing = Drawing::from_reflect(value.as_ref()).unwrap();
+/// assert_eq!(4.0, drawing.shape.area());
+/// ```
+///
+/// [tag]: crate::TypeRegistry::register_tag
+/// [0]: crate::reflect_trait
+/// [`TypeRegistry::register_trait_cast`]: crate::TypeRegistry::register_trait_cast
+pub trait TraitObject: Send + Sync + 'static {
+    /// Returns the value as a `&dyn Reflect`.
+    fn as_reflect_object(&self) -> &dyn Reflect;
+
+    /// Returns the value as a `&mut dyn Reflect`.
+    fn as_reflect_object_mut(&mut self) -> &mut dyn Reflect;
+
+    /// Returns the boxed value as a `Box<dyn Reflect>`.
+    fn into_reflect_object(self: Box<Self>) -> Box<dyn Reflect>;
+}
+
+/// Returns the value inside a boxed trait object or a [`DynamicTraitObject`],
+/// or the given value if it is neither.
+fn unwrap_trait_object<T: TraitObject + ?Sized>(value: &dyn Reflect) -> &dyn Reflect {
+    if let Some(boxed) = value.downcast_ref::<Box<T>>() {
+        boxed.as_reflect_object()
+    } else if let Some(dynamic) = value.downcast_ref::<DynamicTraitObject>() {
+        dynamic.value()
+    } else {
+        value
+    }
+}
+
+impl<T: TraitObject + ?Sized> Reflect for Box<T> {
+    fn type_name(&self) -> &str {
+        core::any::type_name::<Self>()
+    }
+
+    fn get_type_info(&self) -> &'static TypeInfo {
+        <Self as Typed>::type_info()
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        // A dynamic trait object may hold a different concrete type, replacing the whole value
+        if let Some(dynamic) = value.downcast_ref::<DynamicTraitObject>() {
+            if dynamic.value().type_name() != self.as_reflect_object().type_name() {
+                *self = dynamic.construct().unwrap_or_else(|| {
+                    panic!(
+                        "Attempted to apply a `{}` to `{}`, but it could not be converted.",
+                        dynamic.value().type_name(),
+                        core::any::type_name::<Self>(),
+                    )
+                });
+                return;
+            }
+        }
+        self.as_reflect_object_mut()
+            .apply(unwrap_trait_object::<T>(value));
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        self.as_reflect_object().reflect_ref()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        self.as_reflect_object_mut().reflect_mut()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        (*self).into_reflect_object().reflect_owned()
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        self.as_reflect_object().clone_value()
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        self.as_reflect_object().reflect_hash()
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        self.as_reflect_object()
+            .reflect_partial_eq(unwrap_trait_object::<T>(value))
+    }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        self.as_reflect_object()
+            .reflect_partial_cmp(unwrap_trait_object::<T>(value))
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.as_reflect_object().debug(f)
+    }
+}
+
+impl<T: TraitObject + ?Sized> Typed for Box<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+    }
+}
+
+impl<T: TraitObject + ?Sized> GetTypeRegistration for Box<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectTraitObject>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl<T: TraitObject + ?Sized> FromReflect for Box<T> {
+    /// Converts a [`DynamicTraitObject`], such as one returned by the reflect deserializers.
+    ///
+    /// Other values, including other boxes, cannot be converted,
+    /// as the type of the value they hold is not known statically.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect.downcast_ref::<DynamicTraitObject>()?.construct()
+    }
+}
+
+/// Type data for boxed trait objects, such as `Box<dyn MyTrait>`.
+///
+/// This is registered automatically for boxes of any [`TraitObject`],
+/// and tells the reflect (de)serializers to write the tag of the boxed value alongside it.
+#[derive(Clone)]
+pub struct ReflectTraitObject {
+    get: fn(&dyn Reflect) -> Option<&dyn Reflect>,
+    can_cast: fn(&ReflectCast) -> bool,
+}
+
+impl ReflectTraitObject {
+    /// Returns the value inside the given box, or `None` if it is not the box this was created for.
+    pub fn get<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn Reflect> {
+        (self.get)(value)
+    }
+
+    /// Returns `true` if values with the given [`ReflectCast`] can be stored in the box.
+    pub fn can_cast(&self, reflect_cast: &ReflectCast) -> bool {
+        (self.can_cast)(reflect_cast)
+    }
+}
+
+impl<T: TraitObject + ?Sized> FromType<Box<T>> for ReflectTraitObject {
+    fn from_type() -> Self {
+        Self {
+            get: |value| {
+                value
+                    .downcast_ref::<Box<T>>()
+                    .map(|boxed| boxed.as_reflect_object())
+            },
+            can_cast: |reflect_cast| reflect_cast.can_cast::<T>(),
+        }
+    }
+}
+
+/// A dynamic representation of a boxed trait object, such as `Box<dyn MyTrait>`.
+///
+/// This holds the (possibly dynamic) value along with the type data needed to
+/// convert it into its concrete type and cast that to the trait object,
+/// as the reflect deserializers cannot construct the box themselves.
+/// Use [`FromReflect`] to convert it into the box.
+pub struct DynamicTraitObject {
+    value: Box<dyn Reflect>,
+    from_reflect: ReflectFromReflect,
+    reflect_cast: ReflectCast,
+}
+
+impl DynamicTraitObject {
+    /// Creates a dynamic trait object from a value and the type data of its concrete type.
+    pub fn new(
+        value: Box<dyn Reflect>,
+        from_reflect: ReflectFromReflect,
+        reflect_cast: ReflectCast,
+    ) -> Self {
+        Self {
+            value,
+            from_reflect,
+            reflect_cast,
+        }
+    }
+
+    /// Returns the value held by this trait object.
+    pub fn value(&self) -> &dyn Reflect {
+        self.value.as_ref()
+    }
+
+    /// Returns the value held by this trait object, consuming it.
+    pub fn into_value(self) -> Box<dyn Reflect> {
+        self.value
+    }
+
+    /// Converts the value to its concrete type.
+    ///
+    /// Returns `None` if the value cannot be converted.
+    pub fn construct_reflect(&self) -> Option<Box<dyn Reflect>> {
+        self.from_reflect.from_reflect(self.value.as_ref())
+    }
+
+    /// Converts the value to its concrete type and casts it to the trait object `T`.
+    ///
+    /// Returns `None` if the value cannot be converted or its type cannot be cast to `T`.
+    pub fn construct<T: ?Sized + 'static>(&self) -> Option<Box<T>> {
+        self.reflect_cast.cast_boxed(self.construct_reflect()?).ok()
+    }
+
+    /// Clones the trait object, cloning its value with [`Reflect::clone_value`].
+    pub fn clone_dynamic(&self) -> Self {
+        Self {
+            value: self.value.clone_value(),
+            from_reflect: self.from_reflect.clone(),
+            reflect_cast: self.reflect_cast.clone(),
+        }
+    }
+}
+
+impl Reflect for DynamicTraitObject {
+    #[inline]
+    fn type_name(&self) -> &str {
+        self.value.type_name()
+    }
+
+    #[inline]
+    fn get_type_info(&self) -> &'static TypeInfo {
+        <Self as Typed>::type_info()
+    }
+
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone_dynamic())
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef {
+        self.value.reflect_ref()
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut {
+        self.value.reflect_mut()
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        self.value.reflect_owned()
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        match value.downcast_ref::<Self>() {
+            Some(dynamic) => self.value.apply(dynamic.value()),
+            None => self.value.apply(value),
+        }
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        match value.downcast_ref::<Self>() {
+            Some(dynamic) => self.value.reflect_partial_eq(dynamic.value()),
+            None => self.value.reflect_partial_eq(value),
+        }
+    }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        match value.downcast_ref::<Self>() {
+            Some(dynamic) => self.value.reflect_partial_cmp(dynamic.value()),
+            None => self.value.reflect_partial_cmp(value),
+        }
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DynamicTraitObject(")?;
+        self.value.debug(f)?;
+        write!(f, ")")
+    }
+}
+
+impl Debug for DynamicTraitObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.debug(f)
+    }
+}
+
+impl Typed for DynamicTraitObject {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_set(|| TypeInfo::Dynamic(DynamicInfo::new::<Self>()))
+    }
+}
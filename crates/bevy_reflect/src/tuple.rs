@@ -1,11 +1,17 @@
+use crate::cycle::{debug_guarded, expect_guarded};
+use crate::debug_options::write_type_path;
+use crate::inline_value::FieldValue;
+use crate::reflect::iter_partial_cmp;
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
     DynamicInfo, FromReflect, GetTypeRegistration, Reflect, ReflectMut, ReflectOwned, ReflectRef,
     TypeInfo, TypeRegistration, Typed, UnnamedField,
 };
-use std::any::{Any, TypeId};
-use std::fmt::{Debug, Formatter};
-use std::slice::Iter;
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::any::{Any, TypeId};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use core::slice::Iter;
 
 /// A reflected Rust tuple.
 ///
@@ -147,7 +153,7 @@ impl TupleInfo {
     ///
     pub fn new<T: Reflect>(fields: &[UnnamedField]) -> Self {
         Self {
-            type_name: std::any::type_name::<T>(),
+            type_name: core::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
             #[cfg(feature = "documentation")]
@@ -201,10 +207,13 @@ impl TupleInfo {
 }
 
 /// A tuple which allows fields to be added at runtime.
+///
+/// Fields which are small primitives, such as numbers and `bool`s, are stored in place,
+/// and other fields are each stored in their own [`Box`].
 #[derive(Default, Debug)]
 pub struct DynamicTuple {
     name: String,
-    fields: Vec<Box<dyn Reflect>>,
+    fields: Vec<FieldValue>,
 }
 
 impl DynamicTuple {
@@ -224,16 +233,27 @@ impl DynamicTuple {
 
     /// Appends an element with value `value` to the tuple.
     pub fn insert_boxed(&mut self, value: Box<dyn Reflect>) {
-        self.fields.push(value);
+        self.fields.push(FieldValue::from_boxed(value, true));
         self.generate_name();
     }
 
     /// Appends a typed element with value `value` to the tuple.
     pub fn insert<T: Reflect>(&mut self, value: T) {
-        self.insert_boxed(Box::new(value));
+        self.fields.push(FieldValue::new(value, true));
+        self.generate_name();
+    }
+
+    /// Appends an element with a clone of `value` to the tuple.
+    pub fn insert_cloned(&mut self, value: &dyn Reflect) {
+        self.fields.push(FieldValue::cloned(value, true));
         self.generate_name();
     }
 
+    /// Returns true if the field at `index` is stored in its own box, rather than inline.
+    pub(crate) fn is_field_boxed(&self, index: usize) -> bool {
+        matches!(self.fields.get(index), Some(FieldValue::Boxed(_)))
+    }
+
     fn generate_name(&mut self) {
         let name = &mut self.name;
         name.clear();
@@ -242,7 +262,7 @@ impl DynamicTuple {
             if i > 0 {
                 name.push_str(", ");
             }
-            name.push_str(field.type_name());
+            name.push_str(field.as_reflect().type_name());
         }
         name.push(')');
     }
@@ -251,12 +271,12 @@ impl DynamicTuple {
 impl Tuple for DynamicTuple {
     #[inline]
     fn field(&self, index: usize) -> Option<&dyn Reflect> {
-        self.fields.get(index).map(|field| &**field)
+        self.fields.get(index).map(FieldValue::as_reflect)
     }
 
     #[inline]
     fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
-        self.fields.get_mut(index).map(|field| &mut **field)
+        self.fields.get_mut(index).map(FieldValue::as_reflect_mut)
     }
 
     #[inline]
@@ -275,18 +295,21 @@ impl Tuple for DynamicTuple {
     #[inline]
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
         self.fields
+            .into_iter()
+            .map(FieldValue::into_boxed)
+            .collect()
     }
 
     #[inline]
     fn clone_dynamic(&self) -> DynamicTuple {
-        DynamicTuple {
+        expect_guarded(self.as_reflect(), "clone", || DynamicTuple {
             name: self.name.clone(),
             fields: self
                 .fields
                 .iter()
-                .map(|value| value.clone_value())
+                .map(|value| FieldValue::cloned(value.as_reflect(), true))
                 .collect(),
-        }
+        })
     }
 }
 
@@ -364,7 +387,11 @@ impl Reflect for DynamicTuple {
         tuple_partial_eq(self, value)
     }
 
-    fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        tuple_partial_cmp(self, value)
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "DynamicTuple(")?;
         tuple_debug(self, f)?;
         write!(f, ")")
@@ -385,15 +412,17 @@ impl Typed for DynamicTuple {
 /// This function panics if `b` is not a tuple.
 #[inline]
 pub fn tuple_apply<T: Tuple>(a: &mut T, b: &dyn Reflect) {
-    if let ReflectRef::Tuple(tuple) = b.reflect_ref() {
-        for (i, value) in tuple.iter_fields().enumerate() {
-            if let Some(v) = a.field_mut(i) {
-                v.apply(value);
+    expect_guarded(b, "apply", || {
+        if let ReflectRef::Tuple(tuple) = b.reflect_ref() {
+            for (i, value) in tuple.iter_fields().enumerate() {
+                if let Some(v) = a.field_mut(i) {
+                    v.apply(value);
+                }
             }
+        } else {
+            panic!("Attempted to apply non-Tuple type to Tuple type.");
         }
-    } else {
-        panic!("Attempted to apply non-Tuple type to Tuple type.");
-    }
+    });
 }
 
 /// Compares a [`Tuple`] with a [`Reflect`] value.
@@ -424,6 +453,23 @@ pub fn tuple_partial_eq<T: Tuple>(a: &T, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Compares a [`Tuple`] with a [`Reflect`] value lexicographically.
+///
+/// Returns [`None`] if `b` is not a tuple with the same number of elements as `a`,
+/// or if [`Reflect::reflect_partial_cmp`] returns `None` for a pair of elements compared.
+#[inline]
+pub fn tuple_partial_cmp<T: Tuple>(a: &T, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Tuple(b) = b.reflect_ref() else {
+        return None;
+    };
+
+    if a.field_len() != b.field_len() {
+        return None;
+    }
+
+    iter_partial_cmp(a.iter_fields(), b.iter_fields())
+}
+
 /// The default debug formatter for [`Tuple`] types.
 ///
 /// # Example
@@ -442,12 +488,15 @@ pub fn tuple_partial_eq<T: Tuple>(a: &T, b: &dyn Reflect) -> Option<bool> {
 /// // )
 /// ```
 #[inline]
-pub fn tuple_debug(dyn_tuple: &dyn Tuple, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut debug = f.debug_tuple("");
-    for field in dyn_tuple.iter_fields() {
-        debug.field(&field as &dyn Debug);
-    }
-    debug.finish()
+pub fn tuple_debug(dyn_tuple: &dyn Tuple, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    debug_guarded(dyn_tuple.as_reflect(), f, |f| {
+        write_type_path(dyn_tuple.as_reflect(), f)?;
+        let mut debug = f.debug_tuple("");
+        for field in dyn_tuple.iter_fields() {
+            debug.field(&field as &dyn Debug);
+        }
+        debug.finish()
+    })
 }
 
 macro_rules! impl_reflect_tuple {
@@ -496,7 +545,7 @@ macro_rules! impl_reflect_tuple {
                     name: String::default(),
                     fields: self
                         .iter_fields()
-                        .map(|value| value.clone_value())
+                        .map(|value| FieldValue::cloned(value, true))
                         .collect(),
                 };
                 dyn_tuple.generate_name();
@@ -506,7 +555,7 @@ macro_rules! impl_reflect_tuple {
 
         impl<$($name: Reflect),*> Reflect for ($($name,)*) {
             fn type_name(&self) -> &str {
-                std::any::type_name::<Self>()
+                core::any::type_name::<Self>()
             }
 
             fn get_type_info(&self) -> &'static TypeInfo {
@@ -565,6 +614,10 @@ macro_rules! impl_reflect_tuple {
             fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
                 crate::tuple_partial_eq(self, value)
             }
+
+            fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<core::cmp::Ordering> {
+                crate::tuple_partial_cmp(self, value)
+            }
         }
 
         impl <$($name: Reflect),*> Typed for ($($name,)*) {
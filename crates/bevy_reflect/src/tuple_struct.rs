@@ -1,10 +1,24 @@
+use crate::cycle::{debug_guarded, expect_guarded};
+use crate::debug_options::debug_type_path;
+use crate::inline_value::FieldValue;
+use crate::reflect::iter_partial_cmp;
+use crate::std_traits::{default_value, DefaultValueError};
+use crate::type_info::{TypeCapabilities, TypeIdentity};
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed, UnnamedField,
+    DynamicInfo, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypeRegistry, Typed,
+    UnnamedField,
 };
-use std::any::{Any, TypeId};
-use std::fmt::{Debug, Formatter};
-use std::slice::Iter;
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::{Any, TypeId};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use core::slice::Iter;
 
 /// A reflected Rust tuple struct.
 ///
@@ -44,6 +58,15 @@ pub trait TupleStruct: Reflect {
     /// Returns an iterator over the values of the tuple struct's fields.
     fn iter_fields(&self) -> TupleStructFieldIter;
 
+    /// Drain the fields of this tuple struct to get a vector of owned values.
+    ///
+    /// By default, the fields are cloned with [`Reflect::clone_value`].
+    /// [`DynamicTupleStruct`] moves them out instead, as do tuple structs deriving [`Reflect`]
+    /// with `#[reflect(drain)]`, which cannot be used on types implementing [`Drop`].
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.iter_fields().map(Reflect::clone_value).collect()
+    }
+
     /// Clones the struct into a [`DynamicTupleStruct`].
     fn clone_dynamic(&self) -> DynamicTupleStruct;
 }
@@ -52,9 +75,9 @@ pub trait TupleStruct: Reflect {
 #[derive(Clone, Debug)]
 pub struct TupleStructInfo {
     name: &'static str,
-    type_name: &'static str,
-    type_id: TypeId,
-    fields: Box<[UnnamedField]>,
+    ty: TypeIdentity,
+    capabilities: Option<TypeCapabilities>,
+    fields: Cow<'static, [UnnamedField]>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -67,21 +90,57 @@ impl TupleStructInfo {
     /// * `name`: The name of this struct (_without_ generics or lifetimes)
     /// * `fields`: The fields of this struct in the order they are defined
     ///
-    pub fn new<T: Reflect>(name: &'static str, fields: &[UnnamedField]) -> Self {
+    /// `T` need not implement [`Reflect`], so that types implementing [`NonSendReflect`]
+    /// can be described too.
+    ///
+    /// [`NonSendReflect`]: crate::NonSendReflect
+    pub fn new<T: 'static>(name: &'static str, fields: &[UnnamedField]) -> Self {
+        Self {
+            name,
+            ty: TypeIdentity::of::<T>(),
+            capabilities: None,
+            fields: Cow::Owned(fields.to_vec()),
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
+    /// Create a new [`TupleStructInfo`] from static data, so that it can be built in `const` contexts.
+    ///
+    /// This is used by `#[derive(Reflect)]` for non-generic tuple structs.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The name of this struct (_without_ generics or lifetimes)
+    /// * `fields`: The fields of this struct in the order they are defined
+    ///
+    pub const fn from_static<T: Reflect>(
+        name: &'static str,
+        fields: &'static [UnnamedField],
+    ) -> Self {
         Self {
             name,
-            type_name: std::any::type_name::<T>(),
-            type_id: TypeId::of::<T>(),
-            fields: fields.to_vec().into_boxed_slice(),
+            ty: TypeIdentity::of::<T>(),
+            capabilities: None,
+            fields: Cow::Borrowed(fields),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the [capabilities] of this tuple struct.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn with_capabilities(mut self, capabilities: TypeCapabilities) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
     /// Sets the docstring for this struct.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
-        Self { docs, ..self }
+    pub const fn with_docs(mut self, docs: Option<&'static str>) -> Self {
+        self.docs = docs;
+        self
     }
 
     /// Get the field at the given index.
@@ -112,17 +171,24 @@ impl TupleStructInfo {
     ///
     /// [type name]: std::any::type_name
     pub fn type_name(&self) -> &'static str {
-        self.type_name
+        self.ty.type_name()
     }
 
     /// The [`TypeId`] of the tuple struct.
     pub fn type_id(&self) -> TypeId {
-        self.type_id
+        self.ty.type_id()
     }
 
     /// Check if the given type matches the tuple struct type.
     pub fn is<T: Any>(&self) -> bool {
-        TypeId::of::<T>() == self.type_id
+        TypeId::of::<T>() == self.ty.type_id()
+    }
+
+    /// The [capabilities] of this tuple struct, if they are known.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn capabilities(&self) -> Option<TypeCapabilities> {
+        self.capabilities
     }
 
     /// The docstring of this struct, if any.
@@ -217,10 +283,13 @@ impl GetTupleStructField for dyn TupleStruct {
 }
 
 /// A tuple struct which allows fields to be added at runtime.
+///
+/// Fields which are small primitives, such as numbers and `bool`s, are stored in place,
+/// and other fields are each stored in their own [`Box`].
 #[derive(Default)]
 pub struct DynamicTupleStruct {
     name: String,
-    fields: Vec<Box<dyn Reflect>>,
+    fields: Vec<FieldValue>,
 }
 
 impl DynamicTupleStruct {
@@ -234,26 +303,57 @@ impl DynamicTupleStruct {
         self.name = name;
     }
 
+    /// Creates a tuple struct of the type described by `info`, with every field set to its default.
+    ///
+    /// Fields are defaulted in the same way as by [`DynamicStruct::from_type_info`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field's type is not registered or cannot be defaulted.
+    ///
+    /// [`DynamicStruct::from_type_info`]: crate::DynamicStruct::from_type_info
+    pub fn from_type_info(
+        info: &TupleStructInfo,
+        registry: &TypeRegistry,
+    ) -> Result<Self, DefaultValueError> {
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.set_name(info.type_name().to_string());
+        for field in info.iter() {
+            dynamic.insert_boxed(default_value(field.type_id(), field.type_name(), registry)?);
+        }
+        Ok(dynamic)
+    }
+
     /// Appends an element with value `value` to the tuple struct.
     pub fn insert_boxed(&mut self, value: Box<dyn Reflect>) {
-        self.fields.push(value);
+        self.fields.push(FieldValue::from_boxed(value, true));
     }
 
     /// Appends a typed element with value `value` to the tuple struct.
     pub fn insert<T: Reflect>(&mut self, value: T) {
-        self.insert_boxed(Box::new(value));
+        self.fields.push(FieldValue::new(value, true));
+    }
+
+    /// Appends an element with a clone of `value` to the tuple struct.
+    pub fn insert_cloned(&mut self, value: &dyn Reflect) {
+        self.fields.push(FieldValue::cloned(value, true));
+    }
+
+    /// Returns true if the field at `index` is stored in its own box, rather than inline.
+    pub(crate) fn is_field_boxed(&self, index: usize) -> bool {
+        matches!(self.fields.get(index), Some(FieldValue::Boxed(_)))
     }
 }
 
 impl TupleStruct for DynamicTupleStruct {
     #[inline]
     fn field(&self, index: usize) -> Option<&dyn Reflect> {
-        self.fields.get(index).map(|field| &**field)
+        self.fields.get(index).map(FieldValue::as_reflect)
     }
 
     #[inline]
     fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
-        self.fields.get_mut(index).map(|field| &mut **field)
+        self.fields.get_mut(index).map(FieldValue::as_reflect_mut)
     }
 
     #[inline]
@@ -269,15 +369,23 @@ impl TupleStruct for DynamicTupleStruct {
         }
     }
 
+    #[inline]
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.fields
+            .into_iter()
+            .map(FieldValue::into_boxed)
+            .collect()
+    }
+
     fn clone_dynamic(&self) -> DynamicTupleStruct {
-        DynamicTupleStruct {
+        expect_guarded(self.as_reflect(), "clone", || DynamicTupleStruct {
             name: self.name.clone(),
             fields: self
                 .fields
                 .iter()
-                .map(|value| value.clone_value())
+                .map(|value| FieldValue::cloned(value.as_reflect(), true))
                 .collect(),
-        }
+        })
     }
 }
 
@@ -343,15 +451,7 @@ impl Reflect for DynamicTupleStruct {
     }
 
     fn apply(&mut self, value: &dyn Reflect) {
-        if let ReflectRef::TupleStruct(tuple_struct) = value.reflect_ref() {
-            for (i, value) in tuple_struct.iter_fields().enumerate() {
-                if let Some(v) = self.field_mut(i) {
-                    v.apply(value);
-                }
-            }
-        } else {
-            panic!("Attempted to apply non-TupleStruct type to TupleStruct type.");
-        }
+        expect_guarded(value, "apply", || tuple_struct_apply(self, value));
     }
 
     fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
@@ -363,7 +463,11 @@ impl Reflect for DynamicTupleStruct {
         tuple_struct_partial_eq(self, value)
     }
 
-    fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        tuple_struct_partial_cmp(self, value)
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "DynamicTupleStruct(")?;
         tuple_struct_debug(self, f)?;
         write!(f, ")")
@@ -371,7 +475,7 @@ impl Reflect for DynamicTupleStruct {
 }
 
 impl Debug for DynamicTupleStruct {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.debug(f)
     }
 }
@@ -383,6 +487,25 @@ impl Typed for DynamicTupleStruct {
     }
 }
 
+/// Applies the fields of `b` to the fields of `a` with the same indices.
+///
+/// Fields of `b` beyond the length of `a` are ignored.
+///
+/// # Panics
+///
+/// This function panics if `b` is not a tuple struct.
+#[inline]
+pub fn tuple_struct_apply<S: TupleStruct + ?Sized>(a: &mut S, b: &dyn Reflect) {
+    let ReflectRef::TupleStruct(tuple_struct) = b.reflect_ref() else {
+        panic!("Attempted to apply non-TupleStruct type to TupleStruct type.");
+    };
+    for (i, value) in tuple_struct.iter_fields().enumerate() {
+        if let Some(v) = a.field_mut(i) {
+            v.apply(value);
+        }
+    }
+}
+
 /// Compares a [`TupleStruct`] with a [`Reflect`] value.
 ///
 /// Returns true if and only if all of the following are true:
@@ -392,7 +515,7 @@ impl Typed for DynamicTupleStruct {
 ///
 /// Returns [`None`] if the comparison couldn't even be performed.
 #[inline]
-pub fn tuple_struct_partial_eq<S: TupleStruct>(a: &S, b: &dyn Reflect) -> Option<bool> {
+pub fn tuple_struct_partial_eq<S: TupleStruct + ?Sized>(a: &S, b: &dyn Reflect) -> Option<bool> {
     let ReflectRef::TupleStruct(tuple_struct) = b.reflect_ref() else {
         return Some(false);
     };
@@ -415,6 +538,26 @@ pub fn tuple_struct_partial_eq<S: TupleStruct>(a: &S, b: &dyn Reflect) -> Option
     Some(true)
 }
 
+/// Compares a [`TupleStruct`] with a [`Reflect`] value lexicographically, field by field.
+///
+/// Returns [`None`] if `b` is not a tuple struct with the same number of fields as `a`,
+/// or if [`Reflect::reflect_partial_cmp`] returns `None` for a pair of fields compared.
+#[inline]
+pub fn tuple_struct_partial_cmp<S: TupleStruct + ?Sized>(
+    a: &S,
+    b: &dyn Reflect,
+) -> Option<Ordering> {
+    let ReflectRef::TupleStruct(tuple_struct) = b.reflect_ref() else {
+        return None;
+    };
+
+    if a.field_len() != tuple_struct.field_len() {
+        return None;
+    }
+
+    iter_partial_cmp(a.iter_fields(), tuple_struct.iter_fields())
+}
+
 /// The default debug formatter for [`TupleStruct`] types.
 ///
 /// # Example
@@ -435,11 +578,14 @@ pub fn tuple_struct_partial_eq<S: TupleStruct>(a: &S, b: &dyn Reflect) -> Option
 #[inline]
 pub fn tuple_struct_debug(
     dyn_tuple_struct: &dyn TupleStruct,
-    f: &mut std::fmt::Formatter<'_>,
-) -> std::fmt::Result {
-    let mut debug = f.debug_tuple(dyn_tuple_struct.type_name());
-    for field in dyn_tuple_struct.iter_fields() {
-        debug.field(&field as &dyn Debug);
-    }
-    debug.finish()
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    debug_guarded(dyn_tuple_struct.as_reflect(), f, |f| {
+        let type_path = debug_type_path(dyn_tuple_struct.as_reflect());
+        let mut debug = f.debug_tuple(type_path.as_deref().unwrap_or(dyn_tuple_struct.type_name()));
+        for field in dyn_tuple_struct.iter_fields() {
+            debug.field(&field as &dyn Debug);
+        }
+        debug.finish()
+    })
 }
@@ -1,7 +1,10 @@
 use crate::{
-    ArrayInfo, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo,
+    ArrayInfo, EnumInfo, ListInfo, MapInfo, NamedField, Reflect, StructInfo, TupleInfo,
+    TupleStructInfo,
 };
-use std::any::{Any, TypeId};
+use alloc::{borrow::Cow, vec::Vec};
+use core::any::{Any, TypeId};
+use core::fmt::{Debug, Formatter};
 
 /// A static accessor to compile-time type information.
 ///
@@ -17,7 +20,8 @@ use std::any::{Any, TypeId};
 ///
 /// Implementors may have difficulty in generating a reference to [`TypeInfo`] with a static
 /// lifetime. Luckily, this crate comes with some [utility] structs, to make generating these
-/// statics much simpler.
+/// statics much simpler. The info of non-generic types can also be stored in a `static` directly,
+/// as it can be built in `const` contexts with constructors such as [`StructInfo::from_static`].
 ///
 /// # Example
 ///
@@ -149,6 +153,22 @@ impl TypeInfo {
         TypeId::of::<T>() == self.type_id()
     }
 
+    /// The [capabilities] of the underlying type, if they are known.
+    ///
+    /// They are collected by `#[derive(Reflect)]` and `impl_reflect_value!`, and are never
+    /// known for tuples, lists, arrays, maps and dynamic types.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn capabilities(&self) -> Option<TypeCapabilities> {
+        match self {
+            Self::Struct(info) => info.capabilities(),
+            Self::TupleStruct(info) => info.capabilities(),
+            Self::Enum(info) => info.capabilities(),
+            Self::Value(info) => info.capabilities(),
+            _ => None,
+        }
+    }
+
     /// The docstring of the underlying type, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -166,6 +186,38 @@ impl TypeInfo {
     }
 }
 
+/// Which of the marker traits and properties relevant to storing and sharing values
+/// a type has, so that they can be checked at runtime.
+///
+/// For generic types, a trait is only reported if the bounds of the type guarantee it,
+/// such as `T: Copy` for `Foo<T>` to be reported as [`Copy`].
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, Typed};
+/// #[derive(Reflect, Clone, Copy)]
+/// struct Position(f32, f32);
+///
+/// let capabilities = Position::type_info().capabilities().unwrap();
+/// assert!(capabilities.is_copy);
+/// assert!(!capabilities.needs_drop);
+///
+/// let capabilities = String::type_info().capabilities().unwrap();
+/// assert!(!capabilities.is_copy);
+/// assert!(capabilities.needs_drop);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TypeCapabilities {
+    /// Whether the type implements [`Send`].
+    pub is_send: bool,
+    /// Whether the type implements [`Sync`].
+    pub is_sync: bool,
+    /// Whether the type implements [`Copy`].
+    pub is_copy: bool,
+    /// Whether values of the type need to be dropped, as reported by [`std::mem::needs_drop`].
+    pub needs_drop: bool,
+}
+
 /// A container for compile-time info related to general value types, including primitives.
 ///
 /// This typically represents a type which cannot be broken down any further. This is often
@@ -176,43 +228,59 @@ impl TypeInfo {
 /// it _as_ a struct. It therefore makes more sense to represent it as a [`ValueInfo`].
 #[derive(Debug, Clone)]
 pub struct ValueInfo {
-    type_name: &'static str,
-    type_id: TypeId,
+    ty: TypeIdentity,
+    capabilities: Option<TypeCapabilities>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl ValueInfo {
-    pub fn new<T: Reflect + ?Sized>() -> Self {
+    pub const fn new<T: Reflect + ?Sized>() -> Self {
         Self {
-            type_name: std::any::type_name::<T>(),
-            type_id: TypeId::of::<T>(),
+            ty: TypeIdentity::of::<T>(),
+            capabilities: None,
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the [capabilities] of this value.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn with_capabilities(mut self, capabilities: TypeCapabilities) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
     /// Sets the docstring for this value.
     #[cfg(feature = "documentation")]
-    pub fn with_docs(self, doc: Option<&'static str>) -> Self {
-        Self { docs: doc, ..self }
+    pub const fn with_docs(mut self, doc: Option<&'static str>) -> Self {
+        self.docs = doc;
+        self
     }
 
     /// The [type name] of the value.
     ///
     /// [type name]: std::any::type_name
     pub fn type_name(&self) -> &'static str {
-        self.type_name
+        self.ty.type_name()
     }
 
     /// The [`TypeId`] of the value.
     pub fn type_id(&self) -> TypeId {
-        self.type_id
+        self.ty.type_id()
     }
 
     /// Check if the given type matches the value type.
     pub fn is<T: Any>(&self) -> bool {
-        TypeId::of::<T>() == self.type_id
+        TypeId::of::<T>() == self.ty.type_id()
+    }
+
+    /// The [capabilities] of this value, if they are known.
+    ///
+    /// [capabilities]: TypeCapabilities
+    pub const fn capabilities(&self) -> Option<TypeCapabilities> {
+        self.capabilities
     }
 
     /// The docstring of this dynamic value, if any.
@@ -241,7 +309,7 @@ pub struct DynamicInfo {
 impl DynamicInfo {
     pub fn new<T: Reflect>() -> Self {
         Self {
-            type_name: std::any::type_name::<T>(),
+            type_name: core::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -277,3 +345,153 @@ impl DynamicInfo {
         self.docs
     }
 }
+
+/// The [`TypeId`] and [type name] of a type, in a form which can be built in `const` contexts.
+///
+/// Neither [`TypeId::of`] nor [`std::any::type_name`] can be called in a `const fn`,
+/// so this stores the functions returning them instead.
+///
+/// [type name]: std::any::type_name
+#[derive(Clone, Copy)]
+pub(crate) struct TypeIdentity {
+    type_id: fn() -> TypeId,
+    type_name: fn() -> &'static str,
+}
+
+impl TypeIdentity {
+    pub(crate) const fn of<T: ?Sized + 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>,
+            type_name: core::any::type_name::<T>,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn type_id(&self) -> TypeId {
+        (self.type_id)()
+    }
+
+    #[inline]
+    pub(crate) fn type_name(&self) -> &'static str {
+        (self.type_name)()
+    }
+}
+
+impl Debug for TypeIdentity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.type_name())
+    }
+}
+
+/// The names of the fields of a struct, or the variants of an enum, in order,
+/// along with a copy sorted by name to look up their indices.
+///
+/// This can either be built at runtime or, by passing the sorted names in,
+/// in `const` contexts.
+#[derive(Clone, Debug)]
+pub(crate) struct NameIndex {
+    names: Cow<'static, [&'static str]>,
+    sorted: Cow<'static, [(&'static str, usize)]>,
+}
+
+impl NameIndex {
+    pub(crate) fn new(names: impl Iterator<Item = &'static str>) -> Self {
+        let names: Vec<_> = names.collect();
+        let mut sorted: Vec<_> = names.iter().copied().zip(0..).collect();
+        sorted.sort_unstable();
+        Self {
+            names: Cow::Owned(names),
+            sorted: Cow::Owned(sorted),
+        }
+    }
+
+    /// Creates an index from `names` in order and `sorted`, the names paired with
+    /// their indices and sorted by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sorted` does not match `names`, which fails compilation when
+    /// called in a `const` context.
+    pub(crate) const fn from_static(
+        names: &'static [&'static str],
+        sorted: &'static [(&'static str, usize)],
+    ) -> Self {
+        assert!(
+            names.len() == sorted.len(),
+            "every name must be given in sorted order"
+        );
+        let mut i = 0;
+        while i < sorted.len() {
+            let (name, index) = sorted[i];
+            assert!(
+                index < names.len() && str_eq(names[index], name),
+                "sorted names must be paired with their index"
+            );
+            assert!(
+                i == 0 || !str_lt(name, sorted[i - 1].0),
+                "names must be sorted"
+            );
+            i += 1;
+        }
+        Self {
+            names: Cow::Borrowed(names),
+            sorted: Cow::Borrowed(sorted),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+
+    pub(crate) fn index_of(&self, name: &str) -> Option<usize> {
+        self.sorted
+            .binary_search_by(|(other, _)| (*other).cmp(name))
+            .ok()
+            .map(|i| self.sorted[i].1)
+    }
+}
+
+/// Returns whether `names` are the names of `fields`, in the same order, in a `const` context.
+pub(crate) const fn fields_match_names(fields: &[NamedField], names: &[&str]) -> bool {
+    if fields.len() != names.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < fields.len() {
+        if !str_eq(fields[i].name(), names[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Compares two strings for equality in a `const` context.
+pub(crate) const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns whether `a` sorts before `b`, in a `const` context.
+const fn str_lt(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
@@ -0,0 +1,318 @@
+use crate::utility::GenericTypePathCell;
+use alloc::{boxed::Box, collections::VecDeque, format, string::String, vec, vec::Vec};
+use bevy_utils::{HashMap, HashSet};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "std")]
+use parking_lot::Mutex;
+#[cfg(not(feature = "std"))]
+use spin::{Lazy, Mutex};
+
+/// A stable, explicit path to a type.
+///
+/// Unlike [`std::any::type_name`], whose output is unspecified and may change between
+/// compiler versions, the paths returned by this trait are chosen by the implementor.
+/// This makes them suitable for identifying types across builds, such as in serialized data.
+///
+/// This trait is implemented automatically by `#[derive(Reflect)]`,
+/// using the module the type is defined in, and the paths of the type's parameters.
+/// The paths of generic types, such as `Vec<T>`, are built the first time they are requested,
+/// and kept for the rest of the program by the [type path interner].
+/// It can be implemented for foreign types with the [`impl_type_path!`] macro,
+/// which does not require the type to implement [`Reflect`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::TypePath;
+/// assert_eq!("alloc::string::String", String::type_path());
+/// assert_eq!("String", String::short_type_path());
+/// assert_eq!(Some("alloc"), String::crate_name());
+/// assert_eq!(Some("alloc::string"), String::module_path());
+///
+/// assert_eq!("core::time::Duration", std::time::Duration::type_path());
+/// assert_eq!(Some("core::time"), std::time::Duration::module_path());
+///
+/// assert_eq!("u32", u32::type_path());
+/// assert_eq!(None, u32::crate_name());
+/// ```
+///
+/// [type path interner]: intern_type_path
+/// [`Reflect`]: crate::Reflect
+pub trait TypePath: 'static {
+    /// Returns the fully qualified path of the type, such as `alloc::string::String`.
+    fn type_path() -> &'static str;
+
+    /// Returns the path of the type without its module, such as `String`.
+    fn short_type_path() -> &'static str;
+
+    /// Returns the name of the type, if it is a named type.
+    fn type_ident() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the name of the crate the type is in, if it is in one.
+    fn crate_name() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the path of the module the type is in, if it is in one.
+    fn module_path() -> Option<&'static str> {
+        None
+    }
+}
+
+/// An object-safe version of [`TypePath`].
+///
+/// This is automatically implemented for every type implementing [`TypePath`],
+/// and can be retrieved from a `dyn Reflect` with [`Reflect::get_type_path`].
+///
+/// [`Reflect::get_type_path`]: crate::Reflect::get_type_path
+pub trait DynamicTypePath {
+    /// See [`TypePath::type_path`].
+    fn dyn_type_path(&self) -> &'static str;
+
+    /// See [`TypePath::short_type_path`].
+    fn dyn_short_type_path(&self) -> &'static str;
+
+    /// See [`TypePath::type_ident`].
+    fn dyn_type_ident(&self) -> Option<&'static str>;
+
+    /// See [`TypePath::crate_name`].
+    fn dyn_crate_name(&self) -> Option<&'static str>;
+
+    /// See [`TypePath::module_path`].
+    fn dyn_module_path(&self) -> Option<&'static str>;
+}
+
+impl<T: TypePath> DynamicTypePath for T {
+    #[inline]
+    fn dyn_type_path(&self) -> &'static str {
+        T::type_path()
+    }
+
+    #[inline]
+    fn dyn_short_type_path(&self) -> &'static str {
+        T::short_type_path()
+    }
+
+    #[inline]
+    fn dyn_type_ident(&self) -> Option<&'static str> {
+        T::type_ident()
+    }
+
+    #[inline]
+    fn dyn_crate_name(&self) -> Option<&'static str> {
+        T::crate_name()
+    }
+
+    #[inline]
+    fn dyn_module_path(&self) -> Option<&'static str> {
+        T::module_path()
+    }
+}
+
+/// Implements [`TypePath`] for a (non-generic) type with an explicitly given path.
+///
+/// The type path can be given in one of three forms:
+/// * `impl_type_path!(::my_crate::my_module::MyType)` for a type at the given path.
+///   The first segment is taken to be the crate name.
+/// * `impl_type_path!((in my_crate::my_module) MyType)` for a type which is in scope,
+///   but whose canonical path differs from the one it is imported with.
+/// * `impl_type_path!(my_type)` for a primitive type, which has no crate or module.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{impl_type_path, TypePath};
+/// mod foreign {
+///     pub mod inner {
+///         pub struct Foo;
+///     }
+///     pub use inner::Foo;
+/// }
+///
+/// use foreign::Foo;
+///
+/// impl_type_path!((in foreign::inner) Foo);
+///
+/// assert_eq!("foreign::inner::Foo", Foo::type_path());
+/// assert_eq!(Some("foreign"), Foo::crate_name());
+/// ```
+#[macro_export]
+macro_rules! impl_type_path {
+    ($ident:ident) => {
+        impl $crate::TypePath for $ident {
+            fn type_path() -> &'static str {
+                ::core::stringify!($ident)
+            }
+
+            fn short_type_path() -> &'static str {
+                ::core::stringify!($ident)
+            }
+
+            fn type_ident() -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(::core::stringify!($ident))
+            }
+        }
+    };
+    (:: $krate:ident $(:: $segment:ident)+) => {
+        $crate::impl_type_path!(@split [$krate] $($segment)+);
+    };
+    ((in $krate:ident $(:: $module:ident)*) $ident:ident) => {
+        $crate::impl_type_path!(@impl $ident, $krate $(:: $module)*, $ident);
+    };
+    // Splits the path segments into the crate and module path, and the type's name.
+    (@split [$krate:ident $($module:ident)*] $ident:ident) => {
+        $crate::impl_type_path!(@impl ::$krate $(::$module)*::$ident, $krate $(:: $module)*, $ident);
+    };
+    (@split [$($module:ident)+] $next:ident $($rest:ident)+) => {
+        $crate::impl_type_path!(@split [$($module)+ $next] $($rest)+);
+    };
+    (@impl $ty:ty, $krate:ident $(:: $module:ident)*, $ident:ident) => {
+        impl $crate::TypePath for $ty {
+            fn type_path() -> &'static str {
+                ::core::concat!(
+                    ::core::stringify!($krate),
+                    $("::", ::core::stringify!($module),)*
+                    "::",
+                    ::core::stringify!($ident)
+                )
+            }
+
+            fn short_type_path() -> &'static str {
+                ::core::stringify!($ident)
+            }
+
+            fn type_ident() -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(::core::stringify!($ident))
+            }
+
+            fn crate_name() -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(::core::stringify!($krate))
+            }
+
+            fn module_path() -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(::core::concat!(
+                    ::core::stringify!($krate),
+                    $("::", ::core::stringify!($module),)*
+                ))
+            }
+        }
+    };
+}
+
+impl_type_path!(bool);
+impl_type_path!(char);
+impl_type_path!(u8);
+impl_type_path!(u16);
+impl_type_path!(u32);
+impl_type_path!(u64);
+impl_type_path!(u128);
+impl_type_path!(usize);
+impl_type_path!(i8);
+impl_type_path!(i16);
+impl_type_path!(i32);
+impl_type_path!(i64);
+impl_type_path!(i128);
+impl_type_path!(isize);
+impl_type_path!(f32);
+impl_type_path!(f64);
+impl_type_path!(str);
+impl_type_path!((in alloc::string) String);
+#[cfg(feature = "std")]
+impl_type_path!(::std::path::PathBuf);
+impl_type_path!((in core::time) Duration);
+
+/// The type paths which have been built at runtime, and leaked to be used as `&'static str`s.
+static INTERNED_TYPE_PATHS: Lazy<Mutex<HashSet<&'static str>>> = Lazy::new(Default::default);
+
+/// Returns a `'static` copy of `path`, shared with every other caller interning the same path.
+///
+/// This is used for the paths of generic types, which are built at runtime.
+/// Each distinct path is only ever leaked once, so the memory used is bounded by the number of
+/// distinct types whose paths are requested, and can be checked with [`interned_type_paths`].
+/// To build and intern the path of a type up front, rather than the first time it is requested,
+/// register it with [`TypeRegistry::register_type_path`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::intern_type_path;
+/// let path = intern_type_path("my_crate::Foo<u32>");
+/// assert!(std::ptr::eq(path, intern_type_path(&String::from("my_crate::Foo<u32>"))));
+/// ```
+///
+/// [`TypeRegistry::register_type_path`]: crate::TypeRegistry::register_type_path
+pub fn intern_type_path(path: &str) -> &'static str {
+    let mut paths = INTERNED_TYPE_PATHS.lock();
+    if let Some(interned) = paths.get(path) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(path.into());
+    paths.insert(interned);
+    interned
+}
+
+/// Statistics on the type paths stored by [`intern_type_path`], returned by [`interned_type_paths`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InternedTypePaths {
+    /// The number of distinct paths interned.
+    pub count: usize,
+    /// The total length of the interned paths, in bytes.
+    pub bytes: usize,
+}
+
+/// Returns the number and total size of the type paths interned so far.
+///
+/// As interned paths are never freed, these only ever grow, by one path per generic type
+/// whose path is requested. Checking them over time shows whether new types keep being created.
+pub fn interned_type_paths() -> InternedTypePaths {
+    let paths = INTERNED_TYPE_PATHS.lock();
+    InternedTypePaths {
+        count: paths.len(),
+        bytes: paths.iter().map(|path| path.len()).sum(),
+    }
+}
+
+/// Implements [`TypePath`] for a generic type from the standard library,
+/// building its paths from the paths of its type parameters.
+macro_rules! impl_generic_type_path {
+    ($ident:ident < $($param:ident),+ >, $krate:literal, $module:literal) => {
+        impl<$($param: TypePath),+> TypePath for $ident<$($param),+> {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let params: Vec<&str> = vec![$($param::type_path()),+];
+                    format!("{}::{}<{}>", $module, stringify!($ident), params.join(", "))
+                })
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let params: Vec<&str> = vec![$($param::short_type_path()),+];
+                    format!("{}<{}>", stringify!($ident), params.join(", "))
+                })
+            }
+
+            fn type_ident() -> Option<&'static str> {
+                Some(stringify!($ident))
+            }
+
+            fn crate_name() -> Option<&'static str> {
+                Some($krate)
+            }
+
+            fn module_path() -> Option<&'static str> {
+                Some($module)
+            }
+        }
+    };
+}
+
+impl_generic_type_path!(Option<T>, "core", "core::option");
+impl_generic_type_path!(Vec<T>, "alloc", "alloc::vec");
+impl_generic_type_path!(VecDeque<T>, "alloc", "alloc::collections::vec_deque");
+impl_generic_type_path!(HashMap<K, V>, "hashbrown", "hashbrown::map");
@@ -1,29 +1,64 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, Typed};
-use bevy_ptr::{Ptr, PtrMut};
-use bevy_utils::{HashMap, HashSet};
+use crate::utility::NonGenericTypeInfoCell;
+use crate::{
+    array_apply, array_hash, array_partial_cmp, array_partial_eq, serde::Serializable, Array,
+    ArrayIter, DynamicArray, DynamicInfo, NonSendReflect, Reflect, ReflectCast, ReflectMut,
+    ReflectOwned, ReflectRef, TraitCast, TypeInfo, TypePath, Typed,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use bevy_ptr::{OwningPtr, Ptr, PtrMut};
+use bevy_utils::{HashMap, HashSet, TypeIdMap};
+use core::{
+    alloc::Layout,
+    any::{Any, TypeId},
+    cmp::Ordering,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::Deref,
+    ptr::NonNull,
+};
 use downcast_rs::{impl_downcast, Downcast};
-use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(feature = "std")]
+use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use serde::Deserialize;
-use std::{any::TypeId, fmt::Debug, sync::Arc};
+use thiserror::Error;
 
 /// A registry of reflected types.
+#[derive(Clone)]
 pub struct TypeRegistry {
-    registrations: HashMap<TypeId, TypeRegistration>,
+    registrations: TypeIdMap<TypeRegistration>,
     short_name_to_id: HashMap<String, TypeId>,
     full_name_to_id: HashMap<String, TypeId>,
+    type_path_to_id: HashMap<&'static str, TypeId>,
+    #[cfg(feature = "ffi")]
+    type_path_hash_to_path: HashMap<u64, &'static str>,
+    #[cfg(feature = "ffi")]
+    ambiguous_type_path_hashes: HashSet<u64>,
+    tag_to_id: HashMap<String, TypeId>,
     ambiguous_names: HashSet<String>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
 // need a TypeRegistry ref
 /// A synchronized wrapper around a [`TypeRegistry`].
+///
+/// For workloads which read the registry far more often than they change it, such as
+/// serializing many values, [`freeze`](Self::freeze) returns a snapshot which can be
+/// read without taking the lock.
+#[cfg(feature = "std")]
 #[derive(Clone, Default)]
 pub struct TypeRegistryArc {
     pub internal: Arc<RwLock<TypeRegistry>>,
+    frozen: Arc<Mutex<Option<FrozenTypeRegistry>>>,
 }
 
+#[cfg(feature = "std")]
 impl Debug for TypeRegistryArc {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.internal.read().full_name_to_id.keys().fmt(f)
     }
 }
@@ -48,6 +83,12 @@ impl TypeRegistry {
             registrations: Default::default(),
             short_name_to_id: Default::default(),
             full_name_to_id: Default::default(),
+            type_path_to_id: Default::default(),
+            #[cfg(feature = "ffi")]
+            type_path_hash_to_path: Default::default(),
+            #[cfg(feature = "ffi")]
+            ambiguous_type_path_hashes: Default::default(),
+            tag_to_id: Default::default(),
             ambiguous_names: Default::default(),
         }
     }
@@ -85,6 +126,14 @@ impl TypeRegistry {
         self.add_registration(T::get_type_registration());
     }
 
+    /// Registers the type `T`, which may not be [`Send`] or [`Sync`],
+    /// so that its dynamic copies can be serialized and deserialized.
+    ///
+    /// See [`NonSendReflect`] for details.
+    pub fn register_non_send<T: NonSendReflect>(&mut self) {
+        self.add_registration(TypeRegistration::of_non_send::<T>());
+    }
+
     /// Registers the type described by `registration`.
     pub fn add_registration(&mut self, registration: TypeRegistration) {
         if self.registrations.contains_key(&registration.type_id()) {
@@ -104,10 +153,94 @@ impl TypeRegistry {
         }
         self.full_name_to_id
             .insert(registration.type_name().to_string(), registration.type_id());
+        if let Some(tag) = registration.tag() {
+            self.tag_to_id
+                .insert(tag.to_string(), registration.type_id());
+        }
         self.registrations
             .insert(registration.type_id(), registration);
     }
 
+    /// Registers the [`TypePath`] of the type `T`, so that it can be looked up by that path.
+    ///
+    /// This does not require `T` to be reflected, or registered with [`TypeRegistry::register`].
+    /// If it is, its [`TypeRegistration`] can then be retrieved with [`TypeRegistry::get_with_type_path`].
+    ///
+    /// The path of a generic type is built and [interned] here if it has not been already,
+    /// so registering such types up front keeps the interning out of later lookups.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::any::TypeId;
+    /// use bevy_reflect::{impl_type_path, TypeRegistry};
+    ///
+    /// struct Foo;
+    /// impl_type_path!((in my_crate) Foo);
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register_type_path::<Foo>();
+    /// assert_eq!(Some(TypeId::of::<Foo>()), type_registry.get_type_id_with_type_path("my_crate::Foo"));
+    /// ```
+    ///
+    /// [interned]: crate::intern_type_path
+    pub fn register_type_path<T: TypePath + ?Sized>(&mut self) {
+        self.type_path_to_id
+            .insert(T::type_path(), TypeId::of::<T>());
+        #[cfg(feature = "ffi")]
+        self.register_type_path_hash(crate::ffi::type_path_hash(T::type_path()), T::type_path());
+    }
+
+    /// Indexes `type_path` under `hash`.
+    ///
+    /// Hashes shared by different paths are ambiguous and no longer index any path.
+    #[cfg(feature = "ffi")]
+    pub(crate) fn register_type_path_hash(&mut self, hash: u64, type_path: &'static str) {
+        if self.ambiguous_type_path_hashes.contains(&hash) {
+            return;
+        }
+        match self.type_path_hash_to_path.get(&hash) {
+            Some(path) if *path != type_path => {
+                self.type_path_hash_to_path.remove(&hash);
+                self.ambiguous_type_path_hashes.insert(hash);
+            }
+            _ => {
+                self.type_path_hash_to_path.insert(hash, type_path);
+            }
+        }
+    }
+
+    /// Registers `tag` as the tag of type `T`, replacing any tag it had before.
+    ///
+    /// Tags are short, stable names used in place of the type name when serializing
+    /// values of `T` inside boxed [trait objects], such as `Box<dyn MyTrait>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered, or if `tag` is already the tag of another type.
+    ///
+    /// [trait objects]: crate::TraitObject
+    pub fn register_tag<T: Reflect + 'static>(&mut self, tag: impl Into<String>) {
+        let tag = tag.into();
+        let type_id = TypeId::of::<T>();
+        if let Some(other) = self.tag_to_id.get(&tag).filter(|id| **id != type_id) {
+            panic!(
+                "attempted to register the tag `{tag}` for type `{T}`, but it is already the tag of `{other}`",
+                T = core::any::type_name::<T>(),
+                other = self.registrations[other].type_name(),
+            );
+        }
+        let registration = self.registrations.get_mut(&type_id).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_tag` for type `{T}` without registering `{T}` first",
+                T = core::any::type_name::<T>(),
+            )
+        });
+        if let Some(old_tag) = registration.tag.replace(tag.clone()) {
+            self.tag_to_id.remove(&old_tag);
+        }
+        self.tag_to_id.insert(tag, type_id);
+    }
+
     /// Registers the type data `D` for type `T`.
     ///
     /// Most of the time [`TypeRegistry::register`] can be used instead to register a type you derived [`Reflect`] for.
@@ -128,13 +261,79 @@ impl TypeRegistry {
         let data = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
             panic!(
                 "attempted to call `TypeRegistry::register_type_data` for type `{T}` with data `{D}` without registering `{T}` first",
-                T = std::any::type_name::<T>(),
-                D = std::any::type_name::<D>(),
+                T = core::any::type_name::<T>(),
+                D = core::any::type_name::<D>(),
             )
         });
         data.insert(D::from_type());
     }
 
+    /// Registers the trait-casting type data `D` (such as `ReflectMyTrait`) for type `T`,
+    /// and adds its caster to `T`'s [`ReflectCast`] type data.
+    ///
+    /// This allows values of type `T` to be cast to `D::Target` (such as `dyn MyTrait`)
+    /// with [`TypeRegistry::cast_ref`], [`TypeRegistry::cast_mut`] and [`TypeRegistry::cast_boxed`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    pub fn register_trait_cast<T: Reflect + 'static, D: TraitCast + FromType<T>>(&mut self) {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_trait_cast` for type `{T}` with data `{D}` without registering `{T}` first",
+                T = core::any::type_name::<T>(),
+                D = core::any::type_name::<D>(),
+            )
+        });
+        let data = D::from_type();
+        let caster = data.caster();
+        registration.insert(data);
+        match registration.data_mut::<ReflectCast>() {
+            Some(reflect_cast) => reflect_cast.insert(caster),
+            None => {
+                let mut reflect_cast = ReflectCast::default();
+                reflect_cast.insert(caster);
+                registration.insert(reflect_cast);
+            }
+        }
+    }
+
+    /// Casts a `&dyn Reflect` to the trait object `T` (such as `dyn MyTrait`),
+    /// using the [`ReflectCast`] type data of the value's type.
+    ///
+    /// Returns `None` if the value's type has not been registered or cannot be cast to `T`.
+    pub fn cast_ref<'a, T: ?Sized + 'static>(&self, value: &'a dyn Reflect) -> Option<&'a T> {
+        // `Downcast::as_any` is also in scope, which would apply to the reference itself
+        self.get_type_data::<ReflectCast>(Any::type_id(Reflect::as_any(value)))?
+            .cast_ref(value)
+    }
+
+    /// Casts a `&mut dyn Reflect` to the trait object `T` (such as `dyn MyTrait`),
+    /// using the [`ReflectCast`] type data of the value's type.
+    ///
+    /// Returns `None` if the value's type has not been registered or cannot be cast to `T`.
+    pub fn cast_mut<'a, T: ?Sized + 'static>(
+        &self,
+        value: &'a mut dyn Reflect,
+    ) -> Option<&'a mut T> {
+        self.get_type_data::<ReflectCast>(Any::type_id(Reflect::as_any(value)))?
+            .cast_mut(value)
+    }
+
+    /// Casts a `Box<dyn Reflect>` to the trait object `T` (such as `dyn MyTrait`),
+    /// using the [`ReflectCast`] type data of the value's type.
+    ///
+    /// Returns the original box if the value's type has not been registered or cannot be cast to `T`.
+    pub fn cast_boxed<T: ?Sized + 'static>(
+        &self,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<T>, Box<dyn Reflect>> {
+        match self.get_type_data::<ReflectCast>(Any::type_id(Reflect::as_any(&*value))) {
+            Some(reflect_cast) => reflect_cast.cast_boxed(value),
+            None => Err(value),
+        }
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [`TypeId`].
     ///
@@ -176,6 +375,46 @@ impl TypeRegistry {
             .and_then(move |id| self.get_mut(id))
     }
 
+    /// Returns the [`TypeId`] of the type with the given [type path].
+    ///
+    /// If no type with the given path has been registered with
+    /// [`TypeRegistry::register_type_path`], returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_type_id_with_type_path(&self, type_path: &str) -> Option<TypeId> {
+        self.type_path_to_id.get(type_path).copied()
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given [type path].
+    ///
+    /// If the type's path has not been registered with [`TypeRegistry::register_type_path`],
+    /// or the type itself has not been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
+        self.get_type_id_with_type_path(type_path)
+            .and_then(|id| self.get(id))
+    }
+
+    /// Returns the path registered with [`TypeRegistry::register_type_path`] whose
+    /// [hash](crate::ffi::type_path_hash) is `hash`.
+    ///
+    /// If no path or more than one path has this hash, returns `None`.
+    #[cfg(feature = "ffi")]
+    pub(crate) fn get_type_path_with_hash(&self, hash: u64) -> Option<&'static str> {
+        self.type_path_hash_to_path.get(&hash).copied()
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given tag.
+    ///
+    /// If no type has been registered with the given tag using [`TypeRegistry::register_tag`],
+    /// returns `None`.
+    pub fn get_with_tag(&self, tag: &str) -> Option<&TypeRegistration> {
+        self.tag_to_id.get(tag).and_then(|id| self.get(*id))
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with
     /// the given short name.
     ///
@@ -245,6 +484,7 @@ impl TypeRegistry {
     }
 }
 
+#[cfg(feature = "std")]
 impl TypeRegistryArc {
     /// Takes a read lock on the underlying [`TypeRegistry`].
     pub fn read(&self) -> RwLockReadGuard<'_, TypeRegistry> {
@@ -252,8 +492,76 @@ impl TypeRegistryArc {
     }
 
     /// Takes a write lock on the underlying [`TypeRegistry`].
+    ///
+    /// This discards the snapshot kept by [`freeze`](Self::freeze), so that the next
+    /// call to it includes any changes made through the returned guard.
     pub fn write(&self) -> RwLockWriteGuard<'_, TypeRegistry> {
-        self.internal.write()
+        let registry = self.internal.write();
+        *self.frozen.lock() = None;
+        registry
+    }
+
+    /// Returns an immutable snapshot of the underlying [`TypeRegistry`].
+    ///
+    /// The snapshot is made by cloning the registry the first time this is called,
+    /// and shared by later calls until the registry is changed through [`write`](Self::write).
+    /// Changes made by locking [`internal`](Self::internal) directly are not noticed.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{Reflect, TypeRegistryArc};
+    /// # use std::any::TypeId;
+    /// #[derive(Reflect)]
+    /// struct Player;
+    ///
+    /// let registry = TypeRegistryArc::default();
+    /// let frozen = registry.freeze();
+    /// assert!(frozen.get(TypeId::of::<Player>()).is_none());
+    ///
+    /// registry.write().register::<Player>();
+    /// // The earlier snapshot is unchanged, but a new one is made
+    /// assert!(frozen.get(TypeId::of::<Player>()).is_none());
+    /// assert!(registry.freeze().get(TypeId::of::<Player>()).is_some());
+    /// ```
+    pub fn freeze(&self) -> FrozenTypeRegistry {
+        // Lock the registry first, as `write` does, so that a snapshot cannot be made
+        // between a write and the discarding of the previous snapshot
+        let registry = self.internal.read();
+        self.frozen
+            .lock()
+            .get_or_insert_with(|| FrozenTypeRegistry::from(registry.clone()))
+            .clone()
+    }
+}
+
+/// An immutable, shared snapshot of a [`TypeRegistry`], made by [`TypeRegistryArc::freeze`].
+///
+/// This dereferences to the [`TypeRegistry`], and is cheap to clone, so it can be handed
+/// to serializers and other threads which only need to read the registry.
+#[derive(Clone)]
+pub struct FrozenTypeRegistry {
+    registry: Arc<TypeRegistry>,
+}
+
+impl From<TypeRegistry> for FrozenTypeRegistry {
+    fn from(registry: TypeRegistry) -> Self {
+        Self {
+            registry: Arc::new(registry),
+        }
+    }
+}
+
+impl Deref for FrozenTypeRegistry {
+    type Target = TypeRegistry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.registry
+    }
+}
+
+impl Debug for FrozenTypeRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.registry.full_name_to_id.keys().fmt(f)
     }
 }
 
@@ -272,15 +580,19 @@ impl TypeRegistryArc {
 /// [1]: crate::Reflect
 pub struct TypeRegistration {
     short_name: String,
-    data: HashMap<TypeId, Box<dyn TypeData>>,
+    tag: Option<String>,
+    data: TypeIdMap<Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
+    layout: Option<Layout>,
+    drop_fn: Option<unsafe fn(OwningPtr<'_>)>,
 }
 
 impl Debug for TypeRegistration {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("TypeRegistration")
             .field("short_name", &self.short_name)
             .field("type_info", &self.type_info)
+            .field("layout", &self.layout)
             .finish()
     }
 }
@@ -328,11 +640,42 @@ impl TypeRegistration {
 
     /// Creates type registration information for `T`.
     pub fn of<T: Reflect + Typed>() -> Self {
-        let type_name = std::any::type_name::<T>();
         Self {
-            data: HashMap::default(),
+            layout: Some(Layout::new::<T>()),
+            drop_fn: drop_fn::<T>(),
+            ..Self::of_unsized::<T>()
+        }
+    }
+
+    /// Creates type registration information for `T`, which may be unsized, such as `dyn Reflect`.
+    ///
+    /// The registration has no [layout](Self::layout) or [drop function](Self::drop_fn),
+    /// even if `T` is sized.
+    pub fn of_unsized<T: Reflect + Typed + ?Sized>() -> Self {
+        let type_name = core::any::type_name::<T>();
+        Self {
+            data: TypeIdMap::default(),
             short_name: bevy_utils::get_short_name(type_name),
+            tag: None,
             type_info: T::type_info(),
+            layout: None,
+            drop_fn: None,
+        }
+    }
+
+    /// Creates type registration information for `T`, from its [`NonSendReflect`] implementation.
+    ///
+    /// The registration has no type data, as the type data of reflected types
+    /// operates on `dyn Reflect` values, which `T` cannot be turned into.
+    pub fn of_non_send<T: NonSendReflect>() -> Self {
+        let type_info = T::non_send_type_info();
+        Self {
+            data: TypeIdMap::default(),
+            short_name: bevy_utils::get_short_name(type_info.type_name()),
+            tag: None,
+            type_info,
+            layout: Some(Layout::new::<T>()),
+            drop_fn: drop_fn::<T>(),
         }
     }
 
@@ -343,17 +686,62 @@ impl TypeRegistration {
         &self.short_name
     }
 
+    /// Returns the tag of the type, if one has been registered with [`TypeRegistry::register_tag`].
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
     /// Returns the [name] of the type.
     ///
     /// [name]: std::any::type_name
     pub fn type_name(&self) -> &'static str {
         self.type_info.type_name()
     }
+
+    /// Returns the [`Layout`] of the type, or `None` if it was registered
+    /// with [`TypeRegistration::of_unsized`].
+    ///
+    /// Together with the [drop function](Self::drop_fn), this allows storage for values
+    /// of the type to be managed without knowing it at compile time.
+    pub fn layout(&self) -> Option<Layout> {
+        self.layout
+    }
+
+    /// Returns a function which drops the value of the type pointed to,
+    /// or `None` if the type does not need to be dropped or has no [layout](Self::layout).
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{Reflect, TypeRegistration};
+    /// # use bevy_ptr::OwningPtr;
+    /// # use std::alloc::Layout;
+    /// let registration = TypeRegistration::of::<String>();
+    /// assert_eq!(Some(Layout::new::<String>()), registration.layout());
+    /// let drop_fn = registration.drop_fn().unwrap();
+    ///
+    /// // SAFE: the pointer is to a `String`, which is not used afterwards
+    /// OwningPtr::make(String::from("dropped"), |ptr| unsafe { drop_fn(ptr) });
+    ///
+    /// assert!(TypeRegistration::of::<u32>().drop_fn().is_none());
+    /// assert!(TypeRegistration::of_unsized::<dyn Reflect>().layout().is_none());
+    /// ```
+    pub fn drop_fn(&self) -> Option<unsafe fn(OwningPtr<'_>)> {
+        self.drop_fn
+    }
+}
+
+/// Returns a function dropping a value of type `T`, if it needs to be dropped.
+fn drop_fn<T>() -> Option<unsafe fn(OwningPtr<'_>)> {
+    unsafe fn drop_value<T>(ptr: OwningPtr<'_>) {
+        ptr.drop_as::<T>();
+    }
+
+    core::mem::needs_drop::<T>().then_some(drop_value::<T> as unsafe fn(OwningPtr<'_>))
 }
 
 impl Clone for TypeRegistration {
     fn clone(&self) -> Self {
-        let mut data = HashMap::default();
+        let mut data = TypeIdMap::default();
         for (id, type_data) in &self.data {
             data.insert(*id, (*type_data).clone_type_data());
         }
@@ -361,7 +749,10 @@ impl Clone for TypeRegistration {
         TypeRegistration {
             data,
             short_name: self.short_name.clone(),
+            tag: self.tag.clone(),
             type_info: self.type_info,
+            layout: self.layout,
+            drop_fn: self.drop_fn,
         }
     }
 }
@@ -404,7 +795,7 @@ impl<T: Reflect + erased_serde::Serialize> FromType<T> for ReflectSerialize {
         ReflectSerialize {
             get_serializable: |value| {
                 let value = value.downcast_ref::<T>().unwrap_or_else(|| {
-                    panic!("ReflectSerialize::get_serialize called with type `{}`, even though it was created for `{}`", value.type_name(), std::any::type_name::<T>())
+                    panic!("ReflectSerialize::get_serialize called with type `{}`, even though it was created for `{}`", value.type_name(), core::any::type_name::<T>())
                 });
                 Serializable::Borrowed(value)
             },
@@ -487,16 +878,105 @@ impl<T: for<'a> Deserialize<'a> + Reflect> FromType<T> for ReflectDeserialize {
 #[derive(Clone)]
 pub struct ReflectFromPtr {
     type_id: TypeId,
+    type_name: &'static str,
+    size: usize,
     to_reflect: for<'a> unsafe fn(Ptr<'a>) -> &'a dyn Reflect,
     to_reflect_mut: for<'a> unsafe fn(PtrMut<'a>) -> &'a mut dyn Reflect,
 }
 
+/// An error returned by the checked methods of [`ReflectFromPtr`] when it was constructed
+/// for another type than the one pointed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("expected a pointer to a value of type `{expected}`")]
+pub struct ReflectFromPtrError {
+    /// The name of the type the [`ReflectFromPtr`] was constructed for.
+    pub expected: &'static str,
+    /// The [`TypeId`] of the type pointed to.
+    pub found: TypeId,
+}
+
 impl ReflectFromPtr {
     /// Returns the [`TypeId`] that the [`ReflectFromPtr`] was constructed for
     pub fn type_id(&self) -> TypeId {
         self.type_id
     }
 
+    /// Returns an error unless `type_id` is the one the [`ReflectFromPtr`] was constructed for.
+    fn check(&self, type_id: TypeId) -> Result<(), ReflectFromPtrError> {
+        if type_id == self.type_id {
+            Ok(())
+        } else {
+            Err(ReflectFromPtrError {
+                expected: self.type_name,
+                found: type_id,
+            })
+        }
+    }
+
+    /// Like [`as_reflect_ptr`](Self::as_reflect_ptr), but returns an error rather than causing
+    /// undefined behavior if the [`ReflectFromPtr`] was constructed for another type than
+    /// the one identified by `type_id`.
+    ///
+    /// This is still `unsafe`: only the [`ReflectFromPtr`] is checked against `type_id`,
+    /// while nothing checks that `val` actually points to a value of that type.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to a value of the type identified by `type_id`.
+    pub unsafe fn as_reflect_checked<'a>(
+        &self,
+        val: Ptr<'a>,
+        type_id: TypeId,
+    ) -> Result<&'a dyn Reflect, ReflectFromPtrError> {
+        self.check(type_id)?;
+        Ok((self.to_reflect)(val))
+    }
+
+    /// The mutable equivalent of [`as_reflect_checked`](Self::as_reflect_checked).
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to a value of the type identified by `type_id`.
+    pub unsafe fn as_reflect_mut_checked<'a>(
+        &self,
+        val: PtrMut<'a>,
+        type_id: TypeId,
+    ) -> Result<&'a mut dyn Reflect, ReflectFromPtrError> {
+        self.check(type_id)?;
+        Ok((self.to_reflect_mut)(val))
+    }
+
+    /// Returns a view of the `len` values starting at `val`, which can be accessed by index
+    /// or as a reflected [`Array`].
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to a slice of `len` values of the type that the
+    /// [`ReflectFromPtr`] was constructed for.
+    pub unsafe fn as_reflect_slice<'a>(&self, val: Ptr<'a>, len: usize) -> ReflectSlice<'a> {
+        ReflectSlice {
+            array: SliceArray::new(self, NonNull::new_unchecked(val.as_ptr()), len, false),
+            marker: PhantomData,
+        }
+    }
+
+    /// The mutable equivalent of [`as_reflect_slice`](Self::as_reflect_slice).
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to a slice of `len` values of the type that the
+    /// [`ReflectFromPtr`] was constructed for.
+    pub unsafe fn as_reflect_slice_mut<'a>(
+        &self,
+        val: PtrMut<'a>,
+        len: usize,
+    ) -> ReflectSliceMut<'a> {
+        ReflectSliceMut {
+            array: SliceArray::new(self, NonNull::new_unchecked(val.as_ptr()), len, true),
+            marker: PhantomData,
+        }
+    }
+
     /// # Safety
     ///
     /// `val` must be a pointer to value of the type that the [`ReflectFromPtr`] was constructed for.
@@ -517,7 +997,9 @@ impl ReflectFromPtr {
 impl<T: Reflect> FromType<T> for ReflectFromPtr {
     fn from_type() -> Self {
         ReflectFromPtr {
-            type_id: std::any::TypeId::of::<T>(),
+            type_id: core::any::TypeId::of::<T>(),
+            type_name: core::any::type_name::<T>(),
+            size: core::mem::size_of::<T>(),
             to_reflect: |ptr| {
                 // SAFE: only called from `as_reflect`, where the `ptr` is guaranteed to be of type `T`,
                 // and `as_reflect_ptr`, where the caller promises to call it with type `T`
@@ -532,11 +1014,288 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
     }
 }
 
+/// A view of a slice of values whose type is only known to a [`ReflectFromPtr`],
+/// returned by [`ReflectFromPtr::as_reflect_slice`].
+///
+/// The values can be accessed as a reflected [`Array`] through [`as_array`](Self::as_array).
+#[derive(Clone, Copy)]
+pub struct ReflectSlice<'a> {
+    array: SliceArray,
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'a> ReflectSlice<'a> {
+    /// Returns a reference to the value at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a dyn Reflect> {
+        // SAFE: the values are borrowed for `'a`
+        unsafe { self.array.item(index) }
+    }
+
+    /// Returns the number of values in the slice.
+    pub fn len(&self) -> usize {
+        self.array.len
+    }
+
+    /// Returns `true` if the slice contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.array.len == 0
+    }
+
+    /// Returns an iterator over the values of the slice.
+    pub fn iter(&self) -> impl Iterator<Item = &'a dyn Reflect> {
+        let slice = *self;
+        (0..self.array.len).filter_map(move |index| slice.get(index))
+    }
+
+    /// Returns the slice as a reflected [`Array`].
+    ///
+    /// The array cannot be modified, as the slice is borrowed immutably.
+    pub fn as_array(&self) -> &dyn Array {
+        &self.array
+    }
+
+    /// Clones the values of the slice into a [`DynamicArray`].
+    pub fn clone_dynamic(&self) -> DynamicArray {
+        self.array.clone_dynamic()
+    }
+}
+
+/// A mutable view of a slice of values whose type is only known to a [`ReflectFromPtr`],
+/// returned by [`ReflectFromPtr::as_reflect_slice_mut`].
+///
+/// Like [`ReflectSlice`], the values can be accessed as a reflected [`Array`],
+/// through [`as_array_mut`](Self::as_array_mut).
+pub struct ReflectSliceMut<'a> {
+    array: SliceArray,
+    marker: PhantomData<&'a mut ()>,
+}
+
+impl<'a> ReflectSliceMut<'a> {
+    /// Returns an immutable view of the slice.
+    pub fn as_ref(&self) -> ReflectSlice<'_> {
+        ReflectSlice {
+            array: SliceArray {
+                to_reflect_mut: None,
+                ..self.array
+            },
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.array.get(index)
+    }
+
+    /// Returns a mutable reference to the value at `index`, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.array.get_mut(index)
+    }
+
+    /// Returns the number of values in the slice.
+    pub fn len(&self) -> usize {
+        self.array.len
+    }
+
+    /// Returns `true` if the slice contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.array.len == 0
+    }
+
+    /// Returns the slice as a reflected [`Array`].
+    pub fn as_array(&self) -> &dyn Array {
+        &self.array
+    }
+
+    /// Returns the slice as a mutable reflected [`Array`].
+    pub fn as_array_mut(&mut self) -> &mut dyn Array {
+        &mut self.array
+    }
+
+    /// Clones the values of the slice into a [`DynamicArray`].
+    pub fn clone_dynamic(&self) -> DynamicArray {
+        self.array.clone_dynamic()
+    }
+}
+
+/// The reflected [`Array`] behind [`ReflectSlice`] and [`ReflectSliceMut`].
+///
+/// [`Reflect`] requires `'static`, so the lifetime of the slice is erased here.
+/// This type is private so that it is only ever reached through a reference borrowed from
+/// one of the views: it cannot be named to be downcast to, nor boxed, so it never outlives the slice.
+#[derive(Clone, Copy)]
+struct SliceArray {
+    ptr: NonNull<u8>,
+    len: usize,
+    size: usize,
+    to_reflect: for<'b> unsafe fn(Ptr<'b>) -> &'b dyn Reflect,
+    /// `None` for immutable slices.
+    to_reflect_mut: Option<for<'b> unsafe fn(PtrMut<'b>) -> &'b mut dyn Reflect>,
+}
+
+// SAFE: the values of the slice implement `Reflect`, so they are `Send` and `Sync` themselves
+unsafe impl Send for SliceArray {}
+unsafe impl Sync for SliceArray {}
+
+impl SliceArray {
+    /// Creates the array for the `len` values starting at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `len` values of the type `from_ptr` was constructed for,
+    /// which are borrowed mutably if `mutable` is set.
+    unsafe fn new(from_ptr: &ReflectFromPtr, ptr: NonNull<u8>, len: usize, mutable: bool) -> Self {
+        Self {
+            ptr,
+            len,
+            size: from_ptr.size,
+            to_reflect: from_ptr.to_reflect,
+            to_reflect_mut: mutable.then_some(from_ptr.to_reflect_mut),
+        }
+    }
+
+    /// Returns a reference to the value at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// The values must be borrowed for `'a`.
+    unsafe fn item<'a>(&self, index: usize) -> Option<&'a dyn Reflect> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: `index` is in bounds, and `as_reflect_slice` requires the values to be
+        // of the type `to_reflect` was created for
+        Some((self.to_reflect)(
+            Ptr::new(self.ptr).byte_add(index * self.size),
+        ))
+    }
+}
+
+impl Array for SliceArray {
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        // SAFE: this array is only reachable while the values are borrowed
+        unsafe { self.item(index) }
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        let to_reflect_mut = self.to_reflect_mut?;
+        if index >= self.len {
+            return None;
+        }
+        // SAFE: `index` is in bounds, and `as_reflect_slice_mut` requires the values to be
+        // of the type `to_reflect_mut` was created for and to be borrowed mutably
+        Some(unsafe { to_reflect_mut(PtrMut::new(self.ptr).byte_add(index * self.size)) })
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn iter(&self) -> ArrayIter {
+        ArrayIter {
+            array: self,
+            index: 0,
+        }
+    }
+
+    /// The values stay in the slice, so they are cloned.
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.iter().map(Reflect::clone_value).collect()
+    }
+
+    fn clone_dynamic(&self) -> DynamicArray {
+        DynamicArray::new(self.iter().map(Reflect::clone_value).collect())
+    }
+}
+
+impl Reflect for SliceArray {
+    fn type_name(&self) -> &str {
+        core::any::type_name::<Self>()
+    }
+
+    fn get_type_info(&self) -> &'static TypeInfo {
+        <Self as Typed>::type_info()
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        array_apply(self, value);
+    }
+
+    /// The slice cannot be replaced, so this always fails.
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        Err(value)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Array(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Array(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Array(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        array_hash(self)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        array_partial_eq(self, value)
+    }
+
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        array_partial_cmp(self, value)
+    }
+}
+
+impl Typed for SliceArray {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_set(|| TypeInfo::Dynamic(DynamicInfo::new::<Self>()))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration};
+    use crate::{
+        DynamicArray, GetTypeRegistration, ReflectFromPtr, ReflectFromPtrError, ReflectRef,
+        TypeRegistration, TypeRegistryArc,
+    };
+    use alloc::sync::Arc;
     use bevy_ptr::{Ptr, PtrMut};
     use bevy_utils::HashMap;
+    use core::any::TypeId;
 
     use crate as bevy_reflect;
     use crate::Reflect;
@@ -554,7 +1313,7 @@ mod test {
         // not required in this situation because we no nobody messed with the TypeRegistry,
         // but in the general case somebody could have replaced the ReflectFromPtr with an
         // instance for another type, so then we'd need to check that the type is the expected one
-        assert_eq!(reflect_from_ptr.type_id(), std::any::TypeId::of::<Foo>());
+        assert_eq!(reflect_from_ptr.type_id(), core::any::TypeId::of::<Foo>());
 
         let mut value = Foo { a: 1.0 };
         {
@@ -582,6 +1341,88 @@ mod test {
         }
     }
 
+    #[test]
+    fn should_check_reflect_from_ptr_type() {
+        let registration = <f32 as GetTypeRegistration>::get_type_registration();
+        let reflect_from_ptr = registration.data::<ReflectFromPtr>().unwrap();
+
+        let mut value = 1.0_f32;
+        // SAFETY: `value` is of the type identified by the given `TypeId`
+        let result = unsafe {
+            reflect_from_ptr.as_reflect_mut_checked(PtrMut::from(&mut value), TypeId::of::<f32>())
+        };
+        result.unwrap().apply(&2.0_f32);
+        assert_eq!(2.0, value);
+
+        let other = 1_u32;
+        // SAFETY: `other` is of the type identified by the given `TypeId`
+        let result =
+            unsafe { reflect_from_ptr.as_reflect_checked(Ptr::from(&other), TypeId::of::<u32>()) };
+        assert_eq!(
+            Some(ReflectFromPtrError {
+                expected: "f32",
+                found: TypeId::of::<u32>(),
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn should_view_slices_through_reflect_from_ptr() {
+        let registration = <u16 as GetTypeRegistration>::get_type_registration();
+        let reflect_from_ptr = registration.data::<ReflectFromPtr>().unwrap();
+
+        let mut values = [1_u16, 2, 3];
+        // SAFETY: `values` holds 3 values of the type `reflect_from_ptr` was constructed for
+        let mut slice =
+            unsafe { reflect_from_ptr.as_reflect_slice_mut(PtrMut::from(&mut values), 3) };
+        slice.get_mut(2).unwrap().apply(&4_u16);
+        assert!(slice.get_mut(3).is_none());
+
+        let slice = slice.as_ref();
+        assert_eq!(3, slice.len());
+        let collected = slice
+            .iter()
+            .map(|value| *value.downcast_ref::<u16>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 4], collected);
+        assert!(slice
+            .clone_dynamic()
+            .reflect_partial_eq(&[1_u16, 2, 4])
+            .unwrap());
+        assert!(slice.as_array().reflect_partial_eq(&[1_u16, 2, 4]).unwrap());
+    }
+
+    #[test]
+    fn should_reflect_slices_as_arrays() {
+        let registration = <u16 as GetTypeRegistration>::get_type_registration();
+        let reflect_from_ptr = registration.data::<ReflectFromPtr>().unwrap();
+
+        let mut values = [1_u16, 2, 3];
+        // SAFETY: `values` holds 3 values of the type `reflect_from_ptr` was constructed for
+        let mut slice =
+            unsafe { reflect_from_ptr.as_reflect_slice_mut(PtrMut::from(&mut values), 3) };
+
+        let array = slice.as_array_mut();
+        array.set_element(0, Box::new(5_u16)).unwrap();
+        array.swap_elements(1, 2).unwrap();
+        assert!(array.set_element(0, Box::new(5_u32)).is_err());
+        array.apply(&DynamicArray::from_vec(vec![6_u16, 7, 8]));
+        assert!(matches!(array.reflect_ref(), ReflectRef::Array(array) if array.len() == 3));
+
+        let clone = slice.as_array().clone_value();
+        assert!(clone.reflect_partial_eq(&[6_u16, 7, 8]).unwrap());
+        assert_eq!([6, 7, 8], values);
+
+        // SAFETY: `values` holds 3 values of the type `reflect_from_ptr` was constructed for
+        let slice = unsafe { reflect_from_ptr.as_reflect_slice(Ptr::from(&values), 3) };
+        assert_eq!(
+            Some(&7),
+            slice.as_array().get(1).unwrap().downcast_ref::<u16>()
+        );
+        assert!(slice.as_array().get(3).is_none());
+    }
+
     #[test]
     fn test_property_type_registration() {
         assert_eq!(
@@ -614,4 +1455,24 @@ mod test {
             "Option<HashMap<Option<String>, (String, Option<String>)>>"
         );
     }
+
+    #[test]
+    fn should_share_frozen_registry_until_written() {
+        #[derive(Reflect)]
+        struct Foo;
+
+        let registry = TypeRegistryArc::default();
+        let frozen = registry.freeze();
+        assert!(Arc::ptr_eq(&frozen.registry, &registry.freeze().registry));
+        assert!(Arc::ptr_eq(
+            &frozen.registry,
+            &registry.clone().freeze().registry
+        ));
+
+        registry.write().register::<Foo>();
+        let refrozen = registry.freeze();
+        assert!(!Arc::ptr_eq(&frozen.registry, &refrozen.registry));
+        assert!(refrozen.get(core::any::TypeId::of::<Foo>()).is_some());
+        assert!(frozen.get(core::any::TypeId::of::<Foo>()).is_none());
+    }
 }
@@ -1,10 +1,13 @@
 //! Helpers for working with Bevy reflection.
 
 use crate::TypeInfo;
-use bevy_utils::HashMap;
+use alloc::{boxed::Box, string::String};
+use bevy_utils::NoOpHasher;
+use core::any::{Any, TypeId};
+use core::hash::{Hash, Hasher};
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
 use once_cell::race::OnceBox;
-use parking_lot::RwLock;
-use std::any::{Any, TypeId};
 
 /// A container for [`TypeInfo`] over non-generic types, allowing instances to be stored statically.
 ///
@@ -77,6 +80,10 @@ impl NonGenericTypeInfoCell {
 /// This is specifically meant for use with generic types. If your type isn't generic,
 /// then use [`NonGenericTypeInfoCell`] instead as it should be much more performant.
 ///
+/// Looking up the [`TypeInfo`] of a type never takes a lock, so that many threads may do so
+/// at once without contending. The [`TypeInfo`] of each type is stored once, in an
+/// append-only map keyed by [`TypeId`], which is freed when the cell is dropped.
+///
 /// ## Example
 ///
 /// ```
@@ -114,36 +121,233 @@ impl NonGenericTypeInfoCell {
 /// #   fn clone_value(&self) -> Box<dyn Reflect> { todo!() }
 /// # }
 /// ```
-pub struct GenericTypeInfoCell(OnceBox<RwLock<HashMap<TypeId, &'static TypeInfo>>>);
+pub struct GenericTypeInfoCell(TypeIdCell<TypeInfo>);
 
 impl GenericTypeInfoCell {
     /// Initialize a [`GenericTypeInfoCell`] for generic types.
     pub const fn new() -> Self {
-        Self(OnceBox::new())
+        Self(TypeIdCell::new())
     }
 
     /// Returns a reference to the [`TypeInfo`] stored in the cell.
     ///
     /// This method will then return the correct [`TypeInfo`] reference for the given type `T`.
     /// If there is no [`TypeInfo`] found, a new one will be generated from the given function.
+    ///
+    /// If several threads look up the same type for the first time at once, the function
+    /// may be called by each of them, but only one of the results is kept and returned to all.
     pub fn get_or_insert<T, F>(&self, f: F) -> &TypeInfo
     where
         T: Any + ?Sized,
         F: FnOnce() -> TypeInfo,
     {
-        let type_id = TypeId::of::<T>();
-        // let mapping = self.0.get_or_init(|| Box::new(RwLock::default()));
-        let mapping = self.0.get_or_init(Box::default);
-        if let Some(info) = mapping.read().get(&type_id) {
-            return info;
+        self.0.get_or_insert(TypeId::of::<T>(), f)
+    }
+}
+
+/// A container for the [type paths] of generic types, allowing them to be stored statically.
+///
+/// The path of each monomorphization of a generic type has to be built at runtime,
+/// from the paths of its type parameters. This cell builds it once per type, and stores it in
+/// the [type path interner], so that identical paths share the same memory.
+/// As with [`GenericTypeInfoCell`], looking up a path which has already been built
+/// never takes a lock.
+///
+/// ## Example
+///
+/// ```
+/// use bevy_reflect::{utility::GenericTypePathCell, TypePath};
+///
+/// struct Wrapper<T>(T);
+///
+/// impl<T: TypePath> TypePath for Wrapper<T> {
+///     fn type_path() -> &'static str {
+///         static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///         CELL.get_or_insert::<Self, _>(|| format!("my_crate::Wrapper<{}>", T::type_path()))
+///     }
+///
+///     fn short_type_path() -> &'static str {
+///         static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///         CELL.get_or_insert::<Self, _>(|| format!("Wrapper<{}>", T::short_type_path()))
+///     }
+/// }
+///
+/// assert_eq!("my_crate::Wrapper<u32>", Wrapper::<u32>::type_path());
+/// assert_eq!("Wrapper<String>", Wrapper::<String>::short_type_path());
+/// ```
+///
+/// [type paths]: crate::TypePath
+/// [type path interner]: crate::intern_type_path
+pub struct GenericTypePathCell(TypeIdCell<&'static str>);
+
+impl GenericTypePathCell {
+    /// Initialize a [`GenericTypePathCell`] for generic types.
+    pub const fn new() -> Self {
+        Self(TypeIdCell::new())
+    }
+
+    /// Returns the interned path stored in the cell for the type `T`.
+    ///
+    /// If there is none, the path is built with the given function and interned.
+    pub fn get_or_insert<T, F>(&self, f: F) -> &'static str
+    where
+        T: Any + ?Sized,
+        F: FnOnce() -> String,
+    {
+        self.0
+            .get_or_insert(TypeId::of::<T>(), || crate::intern_type_path(&f()))
+    }
+}
+
+/// The number of buckets in the map of a [`TypeIdCell`].
+///
+/// Most generic types are only used with a handful of type parameters,
+/// so this keeps the lists short without taking up much memory per type.
+const BUCKETS: usize = 32;
+
+/// An append-only map from [`TypeId`]s to values, which can be read without locking.
+///
+/// Each bucket is a list of entries, which new entries are atomically prepended to.
+/// Entries are never modified or removed once inserted, until the cell is dropped.
+struct TypeIdCell<V>(OnceBox<[AtomicPtr<TypeIdNode<V>>; BUCKETS]>);
+
+/// An entry in a bucket of a [`TypeIdCell`], which links to the entry
+/// inserted into the bucket before it.
+struct TypeIdNode<V> {
+    type_id: TypeId,
+    value: V,
+    next: *mut TypeIdNode<V>,
+}
+
+impl<V: Send + Sync> TypeIdCell<V> {
+    const fn new() -> Self {
+        Self(OnceBox::new())
+    }
+
+    fn get_or_insert(&self, type_id: TypeId, f: impl FnOnce() -> V) -> &V {
+        let buckets = self
+            .0
+            .get_or_init(|| Box::new(core::array::from_fn(|_| AtomicPtr::default())));
+        let bucket = &buckets[bucket_index(type_id)];
+
+        let mut head = bucket.load(Ordering::Acquire);
+        if let Some(value) = find_value(head, ptr::null_mut(), type_id) {
+            return value;
+        }
+
+        let node = Box::into_raw(Box::new(TypeIdNode {
+            type_id,
+            value: f(),
+            next: head,
+        }));
+        loop {
+            match bucket.compare_exchange_weak(head, node, Ordering::AcqRel, Ordering::Acquire) {
+                // SAFETY: The node has just been published, and is only freed when the cell is dropped.
+                Ok(_) => return unsafe { &(*node).value },
+                Err(current) => {
+                    // Other types were inserted into the bucket in the meantime, maybe this one
+                    if let Some(value) = find_value(current, head, type_id) {
+                        // SAFETY: The node was never published, so is only owned here.
+                        drop(unsafe { Box::from_raw(node) });
+                        return value;
+                    }
+                    head = current;
+                    // SAFETY: The node was never published, so is only accessed here.
+                    unsafe { (*node).next = head };
+                }
+            }
+        }
+    }
+}
+
+// SAFETY: Values are only ever shared by reference once inserted, and dropped with the cell.
+unsafe impl<V: Send + Sync> Sync for TypeIdCell<V> {}
+// SAFETY: The cell owns its values.
+unsafe impl<V: Send + Sync> Send for TypeIdCell<V> {}
+
+/// Returns the bucket of a [`TypeIdCell`] the given type is stored in.
+fn bucket_index(type_id: TypeId) -> usize {
+    // Type ids are already hashes, so are spread across the buckets as they are
+    let mut hasher = NoOpHasher::default();
+    type_id.hash(&mut hasher);
+    hasher.finish() as usize % BUCKETS
+}
+
+/// Searches the entries of a bucket from `node` up to, but not including, `end`.
+fn find_value<'a, V>(
+    mut node: *mut TypeIdNode<V>,
+    end: *mut TypeIdNode<V>,
+    type_id: TypeId,
+) -> Option<&'a V> {
+    while node != end {
+        // SAFETY: Published nodes are never modified, and live as long as the cell.
+        let entry = unsafe { &*node };
+        if entry.type_id == type_id {
+            return Some(&entry.value);
+        }
+        node = entry.next;
+    }
+    None
+}
+
+impl<V> Drop for TypeIdCell<V> {
+    fn drop(&mut self) {
+        let Some(buckets) = self.0.get() else {
+            return;
+        };
+        for bucket in buckets {
+            let mut node = bucket.load(Ordering::Acquire);
+            while !node.is_null() {
+                // SAFETY: The cell is no longer shared, and each node is only reachable once.
+                let entry = unsafe { Box::from_raw(node) };
+                node = entry.next;
+            }
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GenericTypeInfoCell;
+    use crate::{TypeInfo, Typed};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn should_store_type_info_once_per_type() {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        fn info<T: Typed>() -> &'static TypeInfo {
+            CELL.get_or_insert::<T, _>(|| {
+                CALLS.fetch_add(1, Ordering::Relaxed);
+                T::type_info().clone()
+            })
+        }
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    [
+                        info::<Vec<u8>>() as *const TypeInfo as usize,
+                        info::<Vec<u16>>() as *const TypeInfo as usize,
+                        info::<Option<String>>() as *const TypeInfo as usize,
+                    ]
+                })
+            })
+            .collect();
+        let infos: Vec<_> = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect();
+
+        assert!(infos.iter().all(|info| *info == infos[0]));
+        assert!(info::<Vec<u16>>().is::<Vec<u16>>());
+        assert!(info::<Option<String>>().is::<Option<String>>());
+        assert!(CALLS.load(Ordering::Relaxed) >= 3);
 
-        mapping.write().entry(type_id).or_insert_with(|| {
-            // We leak here in order to obtain a `&'static` reference.
-            // Otherwise, we won't be able to return a reference due to the `RwLock`.
-            // This should be okay, though, since we expect it to remain statically
-            // available over the course of the application.
-            Box::leak(Box::new(f()))
-        })
+        // Cells which are not static free their type info when dropped
+        let cell = GenericTypeInfoCell::new();
+        let info = cell.get_or_insert::<Vec<u32>, _>(|| Vec::<u32>::type_info().clone());
+        assert!(info.is::<Vec<u32>>());
     }
 }
@@ -0,0 +1,432 @@
+use crate::{
+    visit, Access, FromType, ParsedPath, Reflect, ReflectRef, TypeRegistry, VisitContext,
+    VisitControl,
+};
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+/// A single problem found when validating a value.
+///
+/// The [path](Self::path) of the error is relative to the value which was validated.
+/// Validators return errors with an empty path for the value itself, and [`validate_recursive`]
+/// prefixes them with the path to that value from the root.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{}{message}", fmt_path(.path))]
+pub struct ValidationError {
+    path: ParsedPath,
+    message: Cow<'static, str>,
+}
+
+fn fmt_path(path: &ParsedPath) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!("`{path}`: ")
+    }
+}
+
+impl ValidationError {
+    /// Creates an error for the validated value itself, described by `message`.
+    pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            path: ParsedPath::default(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for the value at `path` within the validated value, described by `message`.
+    pub fn at(path: impl Into<ParsedPath>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// The path to the invalid value.
+    pub fn path(&self) -> &ParsedPath {
+        &self.path
+    }
+
+    /// The description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Moves the error to the value at `prefix`, prepending it to the error's path.
+    fn prefixed(self, prefix: &ParsedPath) -> Self {
+        let accesses = prefix
+            .accesses()
+            .iter()
+            .chain(self.path.accesses())
+            .cloned()
+            .collect::<Vec<_>>();
+        Self {
+            path: accesses.into(),
+            message: self.message,
+        }
+    }
+}
+
+/// Every problem found when validating a value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Error)]
+#[error("{}", fmt_errors(.0))]
+pub struct ValidationErrors(Vec<ValidationError>);
+
+fn fmt_errors(errors: &[ValidationError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl ValidationErrors {
+    /// Adds an error to the collection.
+    pub fn push(&mut self, error: ValidationError) {
+        self.0.push(error);
+    }
+
+    /// Returns the number of errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the errors, in the order they were found.
+    pub fn iter(&self) -> impl Iterator<Item = &ValidationError> {
+        self.0.iter()
+    }
+
+    /// Returns `Ok` if there are no errors, and otherwise returns the errors.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Adds the errors of `result`, if any, with their paths prefixed by `prefix`.
+    fn extend_prefixed(&mut self, result: Result<(), ValidationErrors>, prefix: &ParsedPath) {
+        if let Err(errors) = result {
+            self.0
+                .extend(errors.0.into_iter().map(|error| error.prefixed(prefix)));
+        }
+    }
+}
+
+impl From<ValidationError> for ValidationErrors {
+    fn from(error: ValidationError) -> Self {
+        Self(vec![error])
+    }
+}
+
+impl IntoIterator for ValidationErrors {
+    type Item = ValidationError;
+    type IntoIter = alloc::vec::IntoIter<ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// A function checking that a reflected value is valid.
+pub type ValidateFn = fn(&dyn Reflect) -> Result<(), ValidationErrors>;
+
+/// A trait for types whose values can be checked for problems beyond what their type allows,
+/// such as a minimum which is larger than a maximum.
+///
+/// Implementing this trait allows a type to register [`ReflectValidate`] with `#[reflect(Validate)]`.
+pub trait Validate {
+    /// Checks that the value is valid, returning every problem found otherwise.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+/// Type data used to validate values of a type, which is run by [`validate_recursive`].
+///
+/// This can be registered with `#[reflect(Validate)]` for types implementing [`Validate`],
+/// or created from any [validation function](ValidateFn) with [`ReflectValidate::new`]
+/// and inserted into the type's registration.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{validate_recursive, Reflect, ReflectValidate, TypeRegistry, Validate, ValidationError, ValidationErrors};
+/// #[derive(Reflect)]
+/// #[reflect(Validate)]
+/// struct Spawner {
+///     min: u32,
+///     max: u32,
+/// }
+///
+/// impl Validate for Spawner {
+///     fn validate(&self) -> Result<(), ValidationErrors> {
+///         if self.min > self.max {
+///             return Err(ValidationError::new("`min` is larger than `max`").into());
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Spawner>();
+///
+/// let spawner = Spawner { min: 10, max: 5 };
+/// let errors = validate_recursive(&spawner, &registry).unwrap_err();
+/// assert_eq!("`min` is larger than `max`", errors.to_string());
+/// ```
+#[derive(Clone)]
+pub struct ReflectValidate {
+    validate: ValidateFn,
+}
+
+impl ReflectValidate {
+    /// Creates type data which validates values with the given function.
+    pub fn new(validate: ValidateFn) -> Self {
+        Self { validate }
+    }
+
+    /// Checks that `value` is valid, returning every problem found otherwise.
+    ///
+    /// Only the value itself is checked, not the values within it,
+    /// which are checked by [`validate_recursive`].
+    pub fn validate(&self, value: &dyn Reflect) -> Result<(), ValidationErrors> {
+        (self.validate)(value)
+    }
+}
+
+impl<T: Validate + Reflect> FromType<T> for ReflectValidate {
+    fn from_type() -> Self {
+        Self {
+            validate: |value| match value.downcast_ref::<T>() {
+                Some(value) => value.validate(),
+                None => Err(ValidationError::new(format!(
+                    "expected a value of type `{}` but found `{}`",
+                    core::any::type_name::<T>(),
+                    value.type_name()
+                ))
+                .into()),
+            },
+        }
+    }
+}
+
+/// Type data holding the validation functions of the fields of a struct or tuple struct.
+///
+/// This is registered by `#[derive(Reflect)]` for types with fields marked
+/// `#[reflect(validate = "path::to::function")]`, where the function takes a reference to the
+/// field and returns a `Result<(), E>`, with `E` being either [`ValidationError`] or
+/// [`ValidationErrors`]. Fields are identified by their index among the reflected fields of the type.
+///
+/// ```
+/// # use bevy_reflect::{validate_recursive, Reflect, TypeRegistry, ValidationError};
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(validate = "not_empty")]
+///     name: String,
+/// }
+///
+/// fn not_empty(name: &str) -> Result<(), ValidationError> {
+///     if name.is_empty() {
+///         return Err(ValidationError::new("the name is empty"));
+///     }
+///     Ok(())
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let player = Player { name: String::new() };
+/// let errors = validate_recursive(&player, &registry).unwrap_err();
+/// assert_eq!("`name`: the name is empty", errors.to_string());
+/// ```
+#[derive(Clone, Default)]
+pub struct FieldValidators {
+    validators: HashMap<usize, ValidateFn>,
+}
+
+impl FieldValidators {
+    /// Sets the validation function of the field at the given index.
+    pub fn with_validator(mut self, index: usize, validate: ValidateFn) -> Self {
+        self.validators.insert(index, validate);
+        self
+    }
+
+    /// Returns the validation function of the field at the given index, if it has one.
+    pub fn get(&self, index: usize) -> Option<ValidateFn> {
+        self.validators.get(&index).copied()
+    }
+
+    /// Returns the number of fields with a validation function.
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// Returns `true` if no field has a validation function.
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+}
+
+/// Validates a reflected value and every value within it,
+/// using the [`ReflectValidate`] and [`FieldValidators`] type data registered for their types.
+///
+/// Every problem found is returned, with its path from `value`.
+/// Values whose types are not registered, such as dynamic values, are not validated themselves,
+/// though the values within them still are.
+pub fn validate_recursive(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::default();
+    visit(value, &mut |value: &dyn Reflect, context: &VisitContext| {
+        let Some(registration) = registry.get(value.as_any().type_id()) else {
+            return VisitControl::Continue;
+        };
+        if let Some(validate) = registration.data::<ReflectValidate>() {
+            errors.extend_prefixed(validate.validate(value), context.path());
+        }
+        if let Some(validators) = registration.data::<FieldValidators>() {
+            let fields = (0..).map_while(|index| match value.reflect_ref() {
+                ReflectRef::Struct(value) => Some((
+                    Access::Field(value.name_at(index)?.to_string()),
+                    value.field_at(index)?,
+                )),
+                ReflectRef::TupleStruct(value) => {
+                    Some((Access::TupleIndex(index), value.field(index)?))
+                }
+                _ => None,
+            });
+            for (index, (access, field)) in fields.enumerate() {
+                if let Some(validate) = validators.get(index) {
+                    let mut path = context.path().clone();
+                    path.push(access);
+                    errors.extend_prefixed(validate(field), &path);
+                }
+            }
+        }
+        VisitControl::Continue
+    });
+    errors.into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_recursive, ReflectValidate, Validate, ValidationError, ValidationErrors};
+    use crate::{self as bevy_reflect, FromReflect, ParsedPath, Reflect, TypeRegistry};
+    use core::any::TypeId;
+
+    #[derive(Reflect)]
+    #[reflect(Validate)]
+    struct Level {
+        #[reflect(validate = "not_empty")]
+        name: String,
+        waves: Vec<Wave>,
+    }
+
+    impl Validate for Level {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            if self.waves.is_empty() {
+                return Err(ValidationError::at(
+                    ParsedPath::parse("waves").unwrap(),
+                    "a level needs at least one wave",
+                )
+                .into());
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Reflect, FromReflect)]
+    struct Wave(
+        #[reflect(ignore)] (),
+        u32,
+        #[reflect(validate = "positive")] f32,
+    );
+
+    fn not_empty(name: &str) -> Result<(), ValidationError> {
+        if name.is_empty() {
+            return Err(ValidationError::new("must not be empty"));
+        }
+        Ok(())
+    }
+
+    fn positive(value: &f32) -> Result<(), ValidationErrors> {
+        if *value <= 0.0 {
+            return Err(ValidationError::new("must be positive").into());
+        }
+        Ok(())
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry.register::<Wave>();
+        registry
+    }
+
+    #[test]
+    fn should_collect_errors_with_paths() {
+        let registry = registry();
+
+        let level = Level {
+            name: String::from("cave"),
+            waves: vec![Wave((), 3, 1.0)],
+        };
+        assert_eq!(Ok(()), validate_recursive(&level, &registry));
+
+        let level = Level {
+            name: String::new(),
+            waves: Vec::new(),
+        };
+        let errors = validate_recursive(&level, &registry).unwrap_err();
+        assert_eq!(
+            "`waves`: a level needs at least one wave; `name`: must not be empty",
+            errors.to_string()
+        );
+
+        let level = Level {
+            name: String::from("cave"),
+            waves: vec![Wave((), 3, 1.0), Wave((), 5, -2.0)],
+        };
+        let errors = validate_recursive(&level, &registry).unwrap_err();
+        let paths = errors
+            .iter()
+            .map(|error| error.path().to_string())
+            .collect::<Vec<_>>();
+        // Ignored fields do not count towards the index of a validated field
+        assert_eq!(vec!["waves[1].1"], paths);
+        assert_eq!("must be positive", errors.iter().next().unwrap().message());
+    }
+
+    #[test]
+    fn should_validate_with_registered_functions() {
+        let mut registry = registry();
+        registry
+            .get_mut(TypeId::of::<u32>())
+            .unwrap()
+            .insert(ReflectValidate::new(|value| {
+                match value.downcast_ref::<u32>() {
+                    Some(0) => Err(ValidationError::new("must not be zero").into()),
+                    _ => Ok(()),
+                }
+            }));
+
+        let level = Level {
+            name: String::from("cave"),
+            waves: vec![Wave((), 0, 1.0)],
+        };
+        let errors = validate_recursive(&level, &registry).unwrap_err();
+        assert_eq!("`waves[0].0`: must not be zero", errors.to_string());
+    }
+}
@@ -0,0 +1,384 @@
+use crate::{Access, ParsedPath, Reflect, ReflectKind, ReflectMut, ReflectRef};
+use alloc::{string::ToString, vec::Vec};
+
+/// What [`visit`] and [`visit_mut`] should do after visiting a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VisitControl {
+    /// Go on to visit the fields, elements or entries of the value.
+    #[default]
+    Continue,
+    /// Do not visit the fields, elements or entries of the value,
+    /// but go on to visit the rest of the tree.
+    SkipChildren,
+    /// Stop the walk entirely.
+    Stop,
+}
+
+/// Where a value visited by [`visit`] or [`visit_mut`] is within the value being walked.
+pub struct VisitContext<'a> {
+    path: &'a ParsedPath,
+    depth: usize,
+    kind: ReflectKind,
+    key: Option<&'a dyn Reflect>,
+}
+
+impl<'a> VisitContext<'a> {
+    /// The path from the root value to the visited value.
+    ///
+    /// As map entries cannot be accessed by a path, the values of a map
+    /// have the same path as the map itself, along with their [key](Self::key).
+    pub fn path(&self) -> &'a ParsedPath {
+        self.path
+    }
+
+    /// How deeply the visited value is nested, where the root value is at depth `0`.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The [kind](ReflectKind) of the visited value.
+    pub fn kind(&self) -> ReflectKind {
+        self.kind
+    }
+
+    /// The key of the visited value, if it is the value of a map entry.
+    pub fn key(&self) -> Option<&'a dyn Reflect> {
+        self.key
+    }
+}
+
+/// A visitor of every value within a reflected value, passed to [`visit`].
+///
+/// This is implemented for closures taking the same arguments as [`ReflectVisitor::visit`].
+pub trait ReflectVisitor {
+    /// Visits a single value, returning whether to walk into it.
+    fn visit(&mut self, value: &dyn Reflect, context: &VisitContext) -> VisitControl;
+}
+
+impl<F: FnMut(&dyn Reflect, &VisitContext) -> VisitControl> ReflectVisitor for F {
+    fn visit(&mut self, value: &dyn Reflect, context: &VisitContext) -> VisitControl {
+        self(value, context)
+    }
+}
+
+/// A visitor of every value within a reflected value, which may modify them,
+/// passed to [`visit_mut`].
+///
+/// This is implemented for closures taking the same arguments as [`ReflectVisitorMut::visit`].
+pub trait ReflectVisitorMut {
+    /// Visits a single value, returning whether to walk into it.
+    ///
+    /// The value is walked into after it is visited,
+    /// so any changes made to it here are seen by the rest of the walk.
+    fn visit(&mut self, value: &mut dyn Reflect, context: &VisitContext) -> VisitControl;
+}
+
+impl<F: FnMut(&mut dyn Reflect, &VisitContext) -> VisitControl> ReflectVisitorMut for F {
+    fn visit(&mut self, value: &mut dyn Reflect, context: &VisitContext) -> VisitControl {
+        self(value, context)
+    }
+}
+
+/// Walks a reflected value and everything within it,
+/// calling the visitor on each value before its fields, elements and entries.
+///
+/// Returns `false` if the walk was [stopped](VisitControl::Stop) by the visitor.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{visit, Reflect, ReflectKind, VisitContext, VisitControl};
+/// #[derive(Reflect)]
+/// struct Level {
+///     name: String,
+///     spawns: Vec<(f32, f32)>,
+/// }
+///
+/// let level = Level {
+///     name: String::from("cave"),
+///     spawns: vec![(1.0, 2.0)],
+/// };
+///
+/// let mut paths = Vec::new();
+/// visit(&level, &mut |_: &dyn Reflect, context: &VisitContext| {
+///     paths.push(context.path().to_string());
+///     if context.kind() == ReflectKind::Tuple {
+///         VisitControl::SkipChildren
+///     } else {
+///         VisitControl::Continue
+///     }
+/// });
+/// assert_eq!(vec!["", "name", "spawns", "spawns[0]"], paths);
+/// ```
+pub fn visit<V: ReflectVisitor + ?Sized>(value: &dyn Reflect, visitor: &mut V) -> bool {
+    walk(value, visitor, &mut ParsedPath::default(), 0, None)
+}
+
+/// Walks a reflected value and everything within it mutably,
+/// calling the visitor on each value before its fields, elements and entries.
+///
+/// Returns `false` if the walk was [stopped](VisitControl::Stop) by the visitor.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{visit_mut, Reflect, VisitContext, VisitControl};
+/// let mut scores = vec![(String::from("a"), 1_u32), (String::from("b"), 2)];
+/// visit_mut(&mut scores, &mut |value: &mut dyn Reflect, _: &VisitContext| {
+///     if let Some(score) = value.downcast_mut::<u32>() {
+///         *score *= 10;
+///     }
+///     VisitControl::Continue
+/// });
+/// assert_eq!(20, scores[1].1);
+/// ```
+pub fn visit_mut<V: ReflectVisitorMut + ?Sized>(value: &mut dyn Reflect, visitor: &mut V) -> bool {
+    walk_mut(value, visitor, &mut ParsedPath::default(), 0, None)
+}
+
+fn walk<V: ReflectVisitor + ?Sized>(
+    value: &dyn Reflect,
+    visitor: &mut V,
+    path: &mut ParsedPath,
+    depth: usize,
+    key: Option<&dyn Reflect>,
+) -> bool {
+    let reflect_ref = value.reflect_ref();
+    let context = VisitContext {
+        path,
+        depth,
+        kind: reflect_ref.kind(),
+        key,
+    };
+    match visitor.visit(value, &context) {
+        VisitControl::Continue => {}
+        VisitControl::SkipChildren => return true,
+        VisitControl::Stop => return false,
+    }
+
+    let mut walk_child =
+        |access: Option<Access>, child: &dyn Reflect, key: Option<&dyn Reflect>| {
+            let pushed = access.map(|access| path.push(access)).is_some();
+            let result = walk(child, visitor, path, depth + 1, key);
+            if pushed {
+                path.pop();
+            }
+            result
+        };
+
+    match reflect_ref {
+        ReflectRef::Struct(value) => value.iter_fields().enumerate().all(|(index, field)| {
+            let name = value.name_at(index).unwrap().to_string();
+            walk_child(Some(Access::Field(name)), field, None)
+        }),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .enumerate()
+            .all(|(index, field)| walk_child(Some(Access::TupleIndex(index)), field, None)),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .enumerate()
+            .all(|(index, field)| walk_child(Some(Access::TupleIndex(index)), field, None)),
+        ReflectRef::List(value) => value
+            .iter()
+            .enumerate()
+            .all(|(index, item)| walk_child(Some(Access::ListIndex(index)), item, None)),
+        ReflectRef::Array(value) => value
+            .iter()
+            .enumerate()
+            .all(|(index, item)| walk_child(Some(Access::ListIndex(index)), item, None)),
+        ReflectRef::Map(value) => value
+            .iter()
+            .all(|(key, value)| walk_child(None, value, Some(key))),
+        ReflectRef::Enum(value) => value.iter_fields().enumerate().all(|(index, field)| {
+            let access = match field.name() {
+                Some(name) => Access::Field(name.to_string()),
+                None => Access::TupleIndex(index),
+            };
+            walk_child(Some(access), field.value(), None)
+        }),
+        ReflectRef::Value(_) => true,
+    }
+}
+
+fn walk_mut<V: ReflectVisitorMut + ?Sized>(
+    value: &mut dyn Reflect,
+    visitor: &mut V,
+    path: &mut ParsedPath,
+    depth: usize,
+    key: Option<&dyn Reflect>,
+) -> bool {
+    let context = VisitContext {
+        path,
+        depth,
+        kind: value.reflect_ref().kind(),
+        key,
+    };
+    match visitor.visit(value, &context) {
+        VisitControl::Continue => {}
+        VisitControl::SkipChildren => return true,
+        VisitControl::Stop => return false,
+    }
+
+    let mut walk_child =
+        |access: Option<Access>, child: &mut dyn Reflect, key: Option<&dyn Reflect>| {
+            let pushed = access.map(|access| path.push(access)).is_some();
+            let result = walk_mut(child, visitor, path, depth + 1, key);
+            if pushed {
+                path.pop();
+            }
+            result
+        };
+
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => (0..value.field_len()).all(|index| {
+            let name = value.name_at(index).unwrap().to_string();
+            let field = value.field_at_mut(index).unwrap();
+            walk_child(Some(Access::Field(name)), field, None)
+        }),
+        ReflectMut::TupleStruct(value) => (0..value.field_len()).all(|index| {
+            let field = value.field_mut(index).unwrap();
+            walk_child(Some(Access::TupleIndex(index)), field, None)
+        }),
+        ReflectMut::Tuple(value) => (0..value.field_len()).all(|index| {
+            let field = value.field_mut(index).unwrap();
+            walk_child(Some(Access::TupleIndex(index)), field, None)
+        }),
+        ReflectMut::List(value) => (0..value.len()).all(|index| {
+            let item = value.get_mut(index).unwrap();
+            walk_child(Some(Access::ListIndex(index)), item, None)
+        }),
+        ReflectMut::Array(value) => (0..value.len()).all(|index| {
+            let item = value.get_mut(index).unwrap();
+            walk_child(Some(Access::ListIndex(index)), item, None)
+        }),
+        ReflectMut::Map(value) => {
+            // The keys are cloned, as a map's values can only be borrowed mutably one at a time
+            let keys = value
+                .iter()
+                .map(|(key, _)| key.clone_value())
+                .collect::<Vec<_>>();
+            keys.iter().all(|key| {
+                let value = value.get_mut(&**key).unwrap();
+                walk_child(None, value, Some(&**key))
+            })
+        }
+        ReflectMut::Enum(value) => (0..value.field_len()).all(|index| {
+            let access = match value.name_at(index) {
+                Some(name) => Access::Field(name.to_string()),
+                None => Access::TupleIndex(index),
+            };
+            let field = value.field_at_mut(index).unwrap();
+            walk_child(Some(access), field, None)
+        }),
+        ReflectMut::Value(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{visit, visit_mut, VisitContext, VisitControl};
+    use crate::{self as bevy_reflect, FromReflect, ParsedPath, Reflect, ReflectKind};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+    }
+
+    #[derive(Reflect)]
+    struct Scene {
+        shapes: Vec<Shape>,
+        tags: HashMap<String, u32>,
+        origin: (f32, f32),
+    }
+
+    #[test]
+    fn should_visit_with_paths() {
+        let mut tags = HashMap::default();
+        tags.insert(String::from("layer"), 3);
+        let mut scene = Scene {
+            shapes: vec![Shape::Circle { radius: 1.0 }, Shape::Rect(2.0, 3.0)],
+            tags,
+            origin: (0.0, 0.0),
+        };
+
+        let mut nodes = Vec::new();
+        assert!(visit(
+            &scene,
+            &mut |_: &dyn Reflect, context: &VisitContext| {
+                let key = context
+                    .key()
+                    .map(|key| key.downcast_ref::<String>().unwrap().clone());
+                nodes.push((
+                    context.path().to_string(),
+                    context.depth(),
+                    context.kind(),
+                    key,
+                ));
+                VisitControl::Continue
+            }
+        ));
+        let node = |path: &str, depth, kind, key: Option<&str>| {
+            (path.to_string(), depth, kind, key.map(String::from))
+        };
+        assert_eq!(
+            vec![
+                node("", 0, ReflectKind::Struct, None),
+                node("shapes", 1, ReflectKind::List, None),
+                node("shapes[0]", 2, ReflectKind::Enum, None),
+                node("shapes[0].radius", 3, ReflectKind::Value, None),
+                node("shapes[1]", 2, ReflectKind::Enum, None),
+                node("shapes[1].0", 3, ReflectKind::Value, None),
+                node("shapes[1].1", 3, ReflectKind::Value, None),
+                node("tags", 1, ReflectKind::Map, None),
+                node("tags", 2, ReflectKind::Value, Some("layer")),
+                node("origin", 1, ReflectKind::Tuple, None),
+                node("origin.0", 2, ReflectKind::Value, None),
+                node("origin.1", 2, ReflectKind::Value, None),
+            ],
+            nodes
+        );
+
+        let mut visited = 0;
+        assert!(!visit(
+            &scene,
+            &mut |_: &dyn Reflect, context: &VisitContext| {
+                visited += 1;
+                match context.kind() {
+                    ReflectKind::List => VisitControl::SkipChildren,
+                    ReflectKind::Map => VisitControl::Stop,
+                    _ => VisitControl::Continue,
+                }
+            }
+        ));
+        assert_eq!(3, visited);
+
+        let mut paths = Vec::new();
+        visit_mut(
+            &mut scene,
+            &mut |value: &mut dyn Reflect, context: &VisitContext| {
+                if let Some(value) = value.downcast_mut::<f32>() {
+                    *value += 1.0;
+                    paths.push(context.path().clone());
+                } else if let Some(value) = value.downcast_mut::<u32>() {
+                    *value += 1;
+                }
+                VisitControl::Continue
+            },
+        );
+        assert_eq!(5, paths.len());
+        for path in &paths {
+            let value = path.element(&scene).unwrap().downcast_ref::<f32>().unwrap();
+            assert!(*value >= 1.0);
+        }
+        assert_eq!(
+            Some(&4.0),
+            ParsedPath::parse("shapes[1].1")
+                .unwrap()
+                .element(&scene)
+                .unwrap()
+                .downcast_ref::<f32>()
+        );
+        assert_eq!(Some(&4), scene.tags.get("layer"));
+    }
+}
@@ -0,0 +1,12 @@
+use bevy_reflect::Reflect;
+
+// Reason: `#[reflect(drain)]` moves the fields out, which is not possible for types implementing `Drop`
+#[derive(Reflect)]
+#[reflect(drain)]
+struct Handle(String);
+
+impl Drop for Handle {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
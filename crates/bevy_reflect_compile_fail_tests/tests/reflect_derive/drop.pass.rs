@@ -0,0 +1,11 @@
+use bevy_reflect::Reflect;
+
+// Types implementing `Drop` can derive `Reflect`, as their fields are only moved out with `#[reflect(drain)]`
+#[derive(Reflect)]
+struct Handle(String);
+
+impl Drop for Handle {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use bevy_reflect::Reflect;
+
+// Enums implementing `Drop` can derive `Reflect`, as their fields are only moved out with `#[reflect(drain)]`
+#[derive(Reflect)]
+enum Handle {
+    Named(String),
+    Unnamed,
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -26,5 +26,5 @@
 /// ```
 #[inline]
 pub fn default<T: Default>() -> T {
-    std::default::Default::default()
+    core::default::Default::default()
 }
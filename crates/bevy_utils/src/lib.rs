@@ -4,13 +4,18 @@
 
 #![warn(missing_docs)]
 #![warn(clippy::undocumented_unsafe_blocks)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 #[allow(missing_docs)]
 pub mod prelude {
     pub use crate::default;
 }
 
+#[cfg(feature = "std")]
 pub mod futures;
+#[cfg(feature = "std")]
 pub mod label;
 mod short_names;
 pub use short_names::get_short_name;
@@ -23,26 +28,29 @@ pub use ahash::AHasher;
 pub use default::default;
 pub use float_ord::*;
 pub use hashbrown;
+#[cfg(feature = "std")]
 pub use instant::{Duration, Instant};
+#[cfg(feature = "std")]
 pub use tracing;
 pub use uuid::Uuid;
 
 use ahash::RandomState;
-use hashbrown::hash_map::RawEntryMut;
-use std::{
+use core::{
+    any::TypeId,
     fmt::Debug,
-    future::Future,
     hash::{BuildHasher, Hash, Hasher},
     marker::PhantomData,
     ops::Deref,
-    pin::Pin,
 };
+use hashbrown::hash_map::RawEntryMut;
+#[cfg(feature = "std")]
+use std::{future::Future, pin::Pin};
 
 /// An owned and dynamically typed Future used when you can't statically type your result or need to add some indirection.
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
 pub type BoxedFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
 pub type BoxedFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
 
 /// A shortcut alias for [`hashbrown::hash_map::Entry`].
@@ -52,7 +60,7 @@ pub type Entry<'a, K, V> = hashbrown::hash_map::Entry<'a, K, V, RandomState>;
 #[derive(Debug, Clone, Default)]
 pub struct FixedState;
 
-impl std::hash::BuildHasher for FixedState {
+impl BuildHasher for FixedState {
     type Hasher = AHasher;
 
     #[inline]
@@ -151,7 +159,7 @@ impl<V: PartialEq, H> PartialEq for Hashed<V, H> {
 }
 
 impl<V: Debug, H> Debug for Hashed<V, H> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Hashed")
             .field("hash", &self.hash)
             .field("value", &self.value)
@@ -207,6 +215,49 @@ impl Hasher for PassHasher {
     }
 }
 
+/// A [`BuildHasher`] that results in a [`NoOpHasher`].
+#[derive(Default, Clone, Copy)]
+pub struct NoOpHash;
+
+impl BuildHasher for NoOpHash {
+    type Hasher = NoOpHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        NoOpHasher::default()
+    }
+}
+
+/// A hasher which passes `u64`s through unchanged, for keys which are already
+/// well-distributed hashes, such as [`TypeId`].
+///
+/// Unlike [`PassHasher`], this does not panic when given anything else,
+/// but folds it into the hash with a much weaker mix.
+#[derive(Debug, Default)]
+pub struct NoOpHasher {
+    hash: u64,
+}
+
+impl Hasher for NoOpHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash = bytes.iter().fold(self.hash, |hash, byte| {
+            hash.rotate_left(8).wrapping_add(*byte as u64)
+        });
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.hash = i;
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// A [`HashMap`] keyed by [`TypeId`], using [`NoOpHash`] as type ids are already hashes.
+pub type TypeIdMap<V> = hashbrown::HashMap<TypeId, V, NoOpHash>;
+
 /// A [`HashMap`] pre-configured to use [`Hashed`] keys and [`PassHash`] passthrough hashing.
 pub type PreHashMap<K, V> = hashbrown::HashMap<Hashed<K>, V, PassHash>;
 